@@ -30,6 +30,14 @@ pub fn bad_request_type_url() -> String {
     error_type_url("bad_request")
 }
 
+pub fn unauthorized_type_url() -> String {
+    error_type_url("unauthorized")
+}
+
+pub fn forbidden_type_url() -> String {
+    error_type_url("forbidden")
+}
+
 pub fn resource_not_found() -> HttpResponse {
     let error_response = GenericErrorResponse::new(
         not_found_type_url(),
@@ -40,6 +48,28 @@ pub fn resource_not_found() -> HttpResponse {
     HttpResponse::NotFound().json(error_response)
 }
 
+pub fn unauthorized() -> HttpResponse {
+    let error_response = GenericErrorResponse::new(
+        unauthorized_type_url(),
+        "Unauthorized".to_string(),
+        StatusCode::UNAUTHORIZED.as_u16(),
+    );
+
+    HttpResponse::Unauthorized()
+        .append_header(("WWW-Authenticate", "Bearer"))
+        .json(error_response)
+}
+
+pub fn forbidden() -> HttpResponse {
+    let error_response = GenericErrorResponse::new(
+        forbidden_type_url(),
+        "Forbidden".to_string(),
+        StatusCode::FORBIDDEN.as_u16(),
+    );
+
+    HttpResponse::Forbidden().json(error_response)
+}
+
 pub fn rest_business_error(title: &str, code: &str) -> HttpResponse {
     let error_response = GenericErrorResponse::new(
         (BASE_URL.clone() + "/" + code)
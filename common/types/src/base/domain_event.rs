@@ -1,4 +1,7 @@
-use std::fmt::{Debug, Display, Formatter};
+use std::{
+    fmt::{Debug, Display, Formatter},
+    str::FromStr,
+};
 
 use derive_new::new;
 use serde::{Deserialize, Serialize};
@@ -28,6 +31,14 @@ impl Display for EventId {
     }
 }
 
+impl FromStr for EventId {
+    type Err = uuid::Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Uuid::parse_str(s).map(Self)
+    }
+}
+
 #[enum_delegate::register]
 pub trait DomainEventTrait {}
 
@@ -63,6 +74,13 @@ mod test {
         )
     }
 
+    #[test]
+    fn event_id_is_parsed_back() {
+        let id = EventId::new();
+        assert_eq!(id.to_string().parse::<EventId>(), Ok(id));
+        assert!("not an id".parse::<EventId>().is_err());
+    }
+
     #[derive(new)]
     struct EmptyEvent {
         #[new(value = "DomainEvent::default()")]
@@ -2,19 +2,36 @@ use serde_derive::{Deserialize, Serialize};
 
 use crate::{
     base::ValueObject,
-    common::CreateAddressError::{EmptyString, NonPositiveBuilding},
+    common::{
+        CreateAddressError::{
+            DeliveryInstructionsTooLong, EmptyApartment, EmptyCity, EmptyDeliveryInstructions,
+            EmptyStreet, InvalidPostalCode, NonPositiveBuilding,
+        },
+        GeoLocation,
+    },
 };
 
 /// `Address` Value Object
-#[derive(Debug, Clone, Eq, PartialEq, Default, Serialize, Deserialize)]
+#[derive(Debug, Clone, Eq, PartialEq, Hash, Default, Serialize, Deserialize)]
 pub struct Address {
     street: String,
     building: i16,
+    #[serde(default)]
+    city: String,
+    #[serde(default)]
+    postal_code: String,
+    apartment: Option<String>,
+    floor: Option<i16>,
+    delivery_instructions: Option<String>,
+    location: Option<GeoLocation>,
 }
 
 impl ValueObject for Address {}
 
 impl Address {
+    /// Max length of [Address] delivery instructions
+    pub const MAX_DELIVERY_INSTRUCTIONS_LENGTH: usize = 500;
+
     /// Get street name from [Address]
     pub fn street_to_string(&self) -> String {
         self.street.clone()
@@ -23,72 +40,234 @@ impl Address {
     pub fn building_to_i16(&self) -> i16 {
         self.building
     }
+    /// Get city from [Address]
+    pub fn city_to_string(&self) -> String {
+        self.city.clone()
+    }
+    /// Get postal code from [Address]
+    pub fn postal_code_to_string(&self) -> String {
+        self.postal_code.clone()
+    }
+    /// Get apartment from [Address]
+    pub fn apartment_to_string(&self) -> Option<String> {
+        self.apartment.clone()
+    }
+    /// Get floor from [Address]
+    pub fn floor_to_i16(&self) -> Option<i16> {
+        self.floor
+    }
+    /// Get delivery instructions from [Address]
+    pub fn delivery_instructions_to_string(&self) -> Option<String> {
+        self.delivery_instructions.clone()
+    }
+    /// Get coordinates of [Address]
+    pub fn location(&self) -> Option<GeoLocation> {
+        self.location
+    }
+
+    /// Set apartment, the value is trimmed
+    pub fn with_apartment(self, apartment: &str) -> Result<Self, CreateAddressError> {
+        match apartment.trim() {
+            "" => Err(EmptyApartment),
+            apartment => Ok(Self {
+                apartment: Some(apartment.to_owned()),
+                ..self
+            }),
+        }
+    }
+    /// Set floor, negative values are underground floors
+    pub fn with_floor(self, floor: i16) -> Self {
+        Self {
+            floor: Some(floor),
+            ..self
+        }
+    }
+    /// Set delivery instructions, the value is trimmed
+    pub fn with_delivery_instructions(
+        self,
+        instructions: &str,
+    ) -> Result<Self, CreateAddressError> {
+        match instructions.trim() {
+            "" => Err(EmptyDeliveryInstructions),
+            x if x.chars().count() > Self::MAX_DELIVERY_INSTRUCTIONS_LENGTH => {
+                Err(DeliveryInstructionsTooLong)
+            }
+            instructions => Ok(Self {
+                delivery_instructions: Some(instructions.to_owned()),
+                ..self
+            }),
+        }
+    }
+    /// Set coordinates
+    pub fn with_location(self, location: GeoLocation) -> Self {
+        Self {
+            location: Some(location),
+            ..self
+        }
+    }
 }
 
-impl TryFrom<(&str, i16)> for Address {
+/// Create [Address] from street, building number, city and postal code, strings are trimmed
+impl TryFrom<(&str, i16, &str, &str)> for Address {
     type Error = CreateAddressError;
 
-    fn try_from(value: (&str, i16)) -> Result<Self, Self::Error> {
-        match value {
-            (x, _) if x.is_empty() || x == " " => Err(EmptyString),
-            (_, ..=0) => Err(NonPositiveBuilding),
+    fn try_from(value: (&str, i16, &str, &str)) -> Result<Self, Self::Error> {
+        let (street, building, city, postal_code) =
+            (value.0.trim(), value.1, value.2.trim(), value.3.trim());
+        match (street, building, city, postal_code) {
+            ("", ..) => Err(EmptyStreet),
+            (_, ..=0, ..) => Err(NonPositiveBuilding),
+            (_, _, "", _) => Err(EmptyCity),
+            (.., x) if !is_valid_postal_code(x) => Err(InvalidPostalCode),
             _ => Ok(Self {
-                street: value.0.to_owned(),
-                building: value.1,
+                street: street.to_owned(),
+                building,
+                city: city.to_owned(),
+                postal_code: postal_code.to_owned(),
+                ..Default::default()
             }),
         }
     }
 }
 
+fn is_valid_postal_code(value: &str) -> bool {
+    (3..=10).contains(&value.len())
+        && value.chars().any(|c| c.is_ascii_alphanumeric())
+        && value
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == ' ' || c == '-')
+}
+
 /// Number of errors for [Address]
 #[derive(Debug, Eq, PartialEq)]
 pub enum CreateAddressError {
-    /// Empty string error
-    EmptyString,
+    /// Street name is empty or contains only whitespaces
+    EmptyStreet,
     /// Building number less or equal than Zero
     NonPositiveBuilding,
+    /// City is empty or contains only whitespaces
+    EmptyCity,
+    /// Postal code is not 3 to 10 latin letters, digits, spaces or hyphens
+    InvalidPostalCode,
+    /// Apartment is empty or contains only whitespaces
+    EmptyApartment,
+    /// Delivery instructions are empty or contain only whitespaces
+    EmptyDeliveryInstructions,
+    /// Delivery instructions are longer than [Address::MAX_DELIVERY_INSTRUCTIONS_LENGTH]
+    DeliveryInstructionsTooLong,
 }
 
 #[cfg(test)]
 mod test {
     use fake::{
-        faker::address::en::{BuildingNumber, StreetName},
+        faker::address::en::{BuildingNumber, CityName, StreetName, ZipCode},
         Fake,
     };
     use rstest::rstest;
 
     use super::*;
 
+    fn address() -> Address {
+        Address::try_from(("Street", 15, "City", "10115")).unwrap()
+    }
+
     #[test]
     fn create_address_success() {
         let street = &*StreetName().fake::<String>();
+        let city = &*CityName().fake::<String>();
+        let postal_code = &*ZipCode().fake::<String>();
         let str_building_number = BuildingNumber().fake::<String>();
-        let building = if let Ok(unwrapped_value) = str_building_number.parse::<i16>() {
-            unwrapped_value
-        } else {
-            i16::MAX
-        };
+        let building = str_building_number.parse::<i16>().unwrap_or(i16::MAX);
 
-        let result = Address::try_from((street, building));
+        let result = Address::try_from((street, building, city, postal_code));
 
         assert!(result.is_ok());
         let result = result.unwrap();
         assert_eq!(result.building_to_i16(), building);
         assert_eq!(result.street_to_string(), street);
+        assert_eq!(result.city_to_string(), city);
+        assert_eq!(result.postal_code_to_string(), postal_code);
+        assert_eq!(result.apartment_to_string(), None);
+        assert_eq!(result.floor_to_i16(), None);
+        assert_eq!(result.delivery_instructions_to_string(), None);
+        assert_eq!(result.location(), None);
+    }
+
+    #[test]
+    fn create_address_values_are_trimmed() {
+        let result = Address::try_from(("  Street ", 15, " City  ", " SW1A 1AA ")).unwrap();
+
+        assert_eq!(result.street_to_string(), "Street");
+        assert_eq!(result.city_to_string(), "City");
+        assert_eq!(result.postal_code_to_string(), "SW1A 1AA");
     }
 
     #[rstest]
-    fn create_address_empty_string(#[values("", " ")] value: &str) {
-        let result = Address::try_from((value, 15_i16));
+    fn create_address_empty_street(#[values("", " ", "  ", "\t\n")] value: &str) {
+        let result = Address::try_from((value, 15_i16, "City", "10115"));
 
         assert!(result.is_err());
-        assert_eq!(result.unwrap_err(), EmptyString);
+        assert_eq!(result.unwrap_err(), EmptyStreet);
     }
 
     #[rstest]
     fn create_address_non_positive_building(#[values(0, - 1)] value: i16) {
-        let result = Address::try_from(("Street", value));
+        let result = Address::try_from(("Street", value, "City", "10115"));
         assert!(result.is_err());
         assert_eq!(result.unwrap_err(), NonPositiveBuilding)
     }
+
+    #[rstest]
+    fn create_address_empty_city(#[values("", "  ")] value: &str) {
+        let result = Address::try_from(("Street", 15, value, "10115"));
+        assert_eq!(result.unwrap_err(), EmptyCity)
+    }
+
+    #[rstest]
+    fn create_address_invalid_postal_code(
+        #[values("", "   ", "12", "12345678901", "101#15", "- -")] value: &str,
+    ) {
+        let result = Address::try_from(("Street", 15, "City", value));
+        assert_eq!(result.unwrap_err(), InvalidPostalCode)
+    }
+
+    #[test]
+    fn add_optional_details_success() {
+        let location = GeoLocation::try_from((52.52, 13.405)).unwrap();
+
+        let result = address()
+            .with_apartment(" 12B ")
+            .unwrap()
+            .with_floor(-1)
+            .with_delivery_instructions("  Ring twice ")
+            .unwrap()
+            .with_location(location);
+
+        assert_eq!(result.apartment_to_string(), Some("12B".to_string()));
+        assert_eq!(result.floor_to_i16(), Some(-1));
+        assert_eq!(
+            result.delivery_instructions_to_string(),
+            Some("Ring twice".to_string())
+        );
+        assert_eq!(result.location(), Some(location));
+    }
+
+    #[rstest]
+    fn add_empty_apartment(#[values("", "  ")] value: &str) {
+        let result = address().with_apartment(value);
+        assert_eq!(result.unwrap_err(), EmptyApartment)
+    }
+
+    #[rstest]
+    fn add_empty_delivery_instructions(#[values("", "  ")] value: &str) {
+        let result = address().with_delivery_instructions(value);
+        assert_eq!(result.unwrap_err(), EmptyDeliveryInstructions)
+    }
+
+    #[test]
+    fn add_too_long_delivery_instructions() {
+        let value = "a".repeat(Address::MAX_DELIVERY_INSTRUCTIONS_LENGTH + 1);
+        let result = address().with_delivery_instructions(&value);
+        assert_eq!(result.unwrap_err(), DeliveryInstructionsTooLong)
+    }
 }
@@ -0,0 +1,123 @@
+use std::hash::{Hash, Hasher};
+
+use serde_derive::{Deserialize, Serialize};
+
+use crate::{
+    base::ValueObject,
+    common::CreateGeoLocationError::{InvalidLatitude, InvalidLongitude},
+};
+
+/// Mean Earth radius used for distance calculation
+const EARTH_RADIUS_METERS: f64 = 6_371_000.0;
+
+/// `GeoLocation` Value Object, WGS 84 coordinates in degrees
+#[derive(Debug, Copy, Clone, PartialEq, Default, Serialize, Deserialize)]
+pub struct GeoLocation {
+    latitude: f64,
+    longitude: f64,
+}
+
+impl ValueObject for GeoLocation {}
+
+// Coordinates are always finite, so bitwise comparison is consistent with `PartialEq`
+impl Eq for GeoLocation {}
+
+impl Hash for GeoLocation {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.latitude.to_bits().hash(state);
+        self.longitude.to_bits().hash(state);
+    }
+}
+
+impl GeoLocation {
+    /// Get latitude from [GeoLocation]
+    pub fn latitude_to_f64(&self) -> f64 {
+        self.latitude
+    }
+    /// Get longitude from [GeoLocation]
+    pub fn longitude_to_f64(&self) -> f64 {
+        self.longitude
+    }
+    /// Great-circle distance in meters (haversine formula)
+    pub fn distance_meters_to(&self, other: &GeoLocation) -> f64 {
+        let d_lat = (other.latitude - self.latitude).to_radians();
+        let d_lon = (other.longitude - self.longitude).to_radians();
+        let a = (d_lat / 2.0).sin().powi(2)
+            + self.latitude.to_radians().cos()
+                * other.latitude.to_radians().cos()
+                * (d_lon / 2.0).sin().powi(2);
+        2.0 * EARTH_RADIUS_METERS * a.sqrt().asin()
+    }
+}
+
+impl TryFrom<(f64, f64)> for GeoLocation {
+    type Error = CreateGeoLocationError;
+
+    fn try_from((latitude, longitude): (f64, f64)) -> Result<Self, Self::Error> {
+        match (latitude, longitude) {
+            (lat, _) if !(-90.0..=90.0).contains(&lat) => Err(InvalidLatitude),
+            (_, lon) if !(-180.0..=180.0).contains(&lon) => Err(InvalidLongitude),
+            // `+ 0.0` turns `-0.0` into `0.0`, which keeps `Hash` consistent with `Eq`
+            _ => Ok(Self {
+                latitude: latitude + 0.0,
+                longitude: longitude + 0.0,
+            }),
+        }
+    }
+}
+
+/// Number of errors for [GeoLocation]
+#[derive(Debug, Eq, PartialEq)]
+pub enum CreateGeoLocationError {
+    /// Latitude is not a number in range -90..=90
+    InvalidLatitude,
+    /// Longitude is not a number in range -180..=180
+    InvalidLongitude,
+}
+
+#[cfg(test)]
+mod test {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn create_location_success() {
+        let result = GeoLocation::try_from((52.52, 13.405));
+
+        let location = result.unwrap();
+        assert_eq!(location.latitude_to_f64(), 52.52);
+        assert_eq!(location.longitude_to_f64(), 13.405);
+    }
+
+    #[rstest]
+    fn create_location_invalid_latitude(#[values(-90.1, 90.1, f64::NAN)] value: f64) {
+        let result = GeoLocation::try_from((value, 0.0));
+        assert_eq!(result, Err(InvalidLatitude));
+    }
+
+    #[rstest]
+    fn create_location_invalid_longitude(#[values(-180.1, 180.1, f64::INFINITY)] value: f64) {
+        let result = GeoLocation::try_from((0.0, value));
+        assert_eq!(result, Err(InvalidLongitude));
+    }
+
+    #[test]
+    fn negative_zero_is_equal_to_zero() {
+        let location = GeoLocation::try_from((-0.0, -0.0)).unwrap();
+
+        assert_eq!(location, GeoLocation::try_from((0.0, 0.0)).unwrap());
+        assert!(location.latitude_to_f64().is_sign_positive());
+    }
+
+    #[test]
+    fn distance_between_locations() {
+        let berlin = GeoLocation::try_from((52.5200, 13.4050)).unwrap();
+        let potsdam = GeoLocation::try_from((52.3906, 13.0645)).unwrap();
+
+        let distance = berlin.distance_meters_to(&potsdam);
+
+        assert!((distance - 27_000.0).abs() < 1_000.0, "{distance}");
+        assert_eq!(berlin.distance_meters_to(&berlin), 0.0);
+    }
+}
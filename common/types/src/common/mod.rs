@@ -1,5 +1,7 @@
 mod address;
 mod count;
+mod geo_location;
 
 pub use address::*;
 pub use count::*;
+pub use geo_location::*;
@@ -1,8 +1,44 @@
-use std::sync::LazyLock;
+use std::sync::{Arc, LazyLock};
 
 use common::types::base::{AM, AMTrait};
+use domain::{menu::meal_events::MealEventEnum, order::customer_order_events::ShopOrderEventEnum};
+use postgres_persistence::{
+    database_start::establish_connection,
+    postgres_shop_order_repository::PostgresShopOrderRepository,
+};
+use usecase::{
+    inventory::rules::release_portions_after_cancel_rule::ReleasePortionsAfterCancelRule,
+    menu::{
+        menu_cache::MenuCache,
+        rules::{
+            invalidate_menu_cache_rule::InvalidateMenuCacheRule,
+            remove_meal_media_rule::RemoveMealMediaRule,
+        },
+    },
+    order::{
+        order_event_feed::OrderEventFeed,
+        rules::{
+            broadcast_order_events_rule::BroadcastOrderEventsRule,
+            export_order_after_checkout_rule::ExportOrderAfterCheckoutRule,
+        },
+    },
+    webhook::rules::deliver_webhooks_rule::DeliverWebhooksRule,
+};
 
-use crate::event::kafka_event_publisher_impl::KafkaEventPublisherImpl;
+use crate::{
+    configuration::{
+        export_configuration::{FILE_ORDER_EXPORTER, RABBIT_ORDER_EXPORTER},
+        messaging_configuration::{RABBITMQ_MEAL_EVENT_PUBLISHER, RABBITMQ_ORDER_EVENT_PUBLISHER},
+        persistence_configuration::{
+            MEAL_STOCK_REPOSITORY, MEDIA_STORAGE, WEBHOOK_SUBSCRIPTION_REPOSITORY,
+        },
+        webhook_configuration::WEBHOOK_DELIVERY,
+    },
+    event::{
+        event_publisher_impl::EventPublisherImpl,
+        kafka_event_publisher_impl::KafkaEventPublisherImpl,
+    },
+};
 
 // pub static EVENT_PUBLISHER: LazyLock<AM<EventPublisherImpl<DomainEventEnum>>> =
 //     LazyLock::new(|| AM::new_am(EventPublisherImpl::default()));
@@ -10,3 +46,66 @@ use crate::event::kafka_event_publisher_impl::KafkaEventPublisherImpl;
 /// `EventPublisher` dependency injection
 pub(super) static EVENT_PUBLISHER: LazyLock<AM<KafkaEventPublisherImpl>> =
     LazyLock::new(|| AM::new_am(KafkaEventPublisherImpl::default()));
+
+/// Menu pages shared by the menu use cases, dropped on every change of the menu
+pub(super) static MENU_CACHE: LazyLock<AM<MenuCache>> =
+    LazyLock::new(|| AM::new_am(MenuCache::default()));
+
+/// Meal events are handled in-process first and then sent to Kafka and RabbitMQ
+pub(super) static MEAL_EVENT_PUBLISHER: LazyLock<AM<EventPublisherImpl<MealEventEnum>>> =
+    LazyLock::new(meal_event_publisher);
+
+fn meal_event_publisher() -> AM<EventPublisherImpl<MealEventEnum>> {
+    let mut publisher = EventPublisherImpl::with_delegate(EVENT_PUBLISHER.clone());
+    publisher.add_delegate(RABBITMQ_MEAL_EVENT_PUBLISHER.clone());
+    publisher.register_listener(InvalidateMenuCacheRule::after_meal_added(
+        MENU_CACHE.clone(),
+    ));
+    publisher.register_listener(InvalidateMenuCacheRule::after_meal_removed(
+        MENU_CACHE.clone(),
+    ));
+    publisher.register_listener(InvalidateMenuCacheRule::after_meal_availability_changed(
+        MENU_CACHE.clone(),
+    ));
+    publisher.register_listener(RemoveMealMediaRule::new(MEDIA_STORAGE.clone()));
+    AM::new_am(publisher)
+}
+
+/// Live feed of the order events for the kitchen terminals and the order tracking
+pub(super) static ORDER_EVENT_FEED: LazyLock<Arc<OrderEventFeed>> =
+    LazyLock::new(|| Arc::new(OrderEventFeed::default()));
+
+/// Order events are handled in-process first and then sent to Kafka and RabbitMQ
+pub(super) static ORDER_EVENT_PUBLISHER: LazyLock<AM<EventPublisherImpl<ShopOrderEventEnum>>> =
+    LazyLock::new(order_event_publisher);
+
+fn order_event_publisher() -> AM<EventPublisherImpl<ShopOrderEventEnum>> {
+    let mut publisher = EventPublisherImpl::with_delegate(EVENT_PUBLISHER.clone());
+    publisher.add_delegate(RABBITMQ_ORDER_EVENT_PUBLISHER.clone());
+    // The rule reads orders with its own repository, the shared one publishes through this publisher
+    let order_extractor = AM::new_am(PostgresShopOrderRepository::new(
+        establish_connection(),
+        EVENT_PUBLISHER.clone(),
+    ));
+    publisher.register_listener(ReleasePortionsAfterCancelRule::new(
+        order_extractor,
+        MEAL_STOCK_REPOSITORY.clone(),
+        MEAL_STOCK_REPOSITORY.clone(),
+    ));
+    publisher.register_listener(ExportOrderAfterCheckoutRule::new(
+        RABBIT_ORDER_EXPORTER.clone(),
+    ));
+    publisher.register_listener(ExportOrderAfterCheckoutRule::new(
+        FILE_ORDER_EXPORTER.clone(),
+    ));
+    for rule in BroadcastOrderEventsRule::for_all_events(ORDER_EVENT_FEED.clone()) {
+        publisher.register_listener(rule);
+    }
+    for rule in DeliverWebhooksRule::for_all_events(
+        WEBHOOK_SUBSCRIPTION_REPOSITORY.clone(),
+        WEBHOOK_DELIVERY.clone(),
+    ) {
+        publisher.register_listener(rule);
+    }
+    AM::new_am(publisher)
+}
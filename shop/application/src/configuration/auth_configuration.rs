@@ -0,0 +1,49 @@
+use std::{env, fs, sync::LazyLock, time::Duration};
+
+use actix_web::web::Data;
+use dotenvy::dotenv;
+use rest::auth::jwt_authenticator::JwtAuthenticator;
+use telnet::session::{
+    telnet_session::TelnetSessionSettings, telnet_user_store::ConfiguredTelnetUserStore,
+};
+
+/// Validates bearer tokens signed with the `JWT_SECRET` key, forced before the HTTP server starts
+pub(super) static JWT_AUTHENTICATOR: LazyLock<Data<JwtAuthenticator>> = LazyLock::new(|| {
+    dotenv().ok();
+    let secret = env::var("JWT_SECRET").expect("JWT_SECRET is not configured");
+    Data::new(JwtAuthenticator::new(secret.as_bytes()).expect("Wrong JWT_SECRET configured"))
+});
+
+/// Operators of the telnet console, `login:argon2-hash:role` lines of the `TELNET_USERS_FILE`
+pub(super) static TELNET_USER_STORE: LazyLock<ConfiguredTelnetUserStore> = LazyLock::new(|| {
+    dotenv().ok();
+    let path = env::var("TELNET_USERS_FILE").expect("TELNET_USERS_FILE is not configured");
+    fs::read_to_string(path)
+        .expect("Error reading the telnet users file")
+        .parse()
+        .expect("Wrong telnet users configured")
+});
+
+/// Idle timeout and login attempts of the telnet sessions, defaults are used when not configured
+pub(super) static TELNET_SESSION_SETTINGS: LazyLock<TelnetSessionSettings> = LazyLock::new(|| {
+    dotenv().ok();
+    let defaults = TelnetSessionSettings::default();
+    TelnetSessionSettings {
+        idle_timeout: env::var("TELNET_IDLE_TIMEOUT_SECONDS")
+            .map(|seconds| {
+                Duration::from_secs(
+                    seconds
+                        .parse()
+                        .expect("Wrong telnet idle timeout configured"),
+                )
+            })
+            .unwrap_or(defaults.idle_timeout),
+        max_login_attempts: env::var("TELNET_MAX_LOGIN_ATTEMPTS")
+            .map(|attempts| {
+                attempts
+                    .parse()
+                    .expect("Wrong telnet login attempts configured")
+            })
+            .unwrap_or(defaults.max_login_attempts),
+    }
+});
@@ -0,0 +1,34 @@
+use std::{env, sync::LazyLock};
+
+use domain::order::value_objects::opening_hours::OpeningHours;
+use dotenvy::dotenv;
+use time::{Duration, Time, format_description};
+
+/// Opening hours (UTC) used to build scheduled delivery slots
+pub(super) static OPENING_HOURS: LazyLock<OpeningHours> = LazyLock::new(|| {
+    dotenv().ok();
+    let slot_minutes = env::var("DELIVERY_SLOT_MINUTES")
+        .unwrap()
+        .parse::<i64>()
+        .expect("Wrong delivery slot length configured");
+    OpeningHours::try_from((
+        time_variable("DELIVERY_OPENS_AT"),
+        time_variable("DELIVERY_CLOSES_AT"),
+        Duration::minutes(slot_minutes),
+    ))
+    .expect("Wrong opening hours configured")
+});
+/// How many orders can be scheduled for the same delivery slot
+pub(super) static DELIVERY_SLOT_CAPACITY: LazyLock<usize> = LazyLock::new(|| {
+    dotenv().ok();
+    env::var("DELIVERY_SLOT_CAPACITY")
+        .unwrap()
+        .parse::<usize>()
+        .expect("Wrong delivery slot capacity configured")
+});
+
+fn time_variable(name: &str) -> Time {
+    let format = format_description::parse_borrowed::<2>("[hour]:[minute]").unwrap();
+    Time::parse(&env::var(name).unwrap(), &format)
+        .unwrap_or_else(|_| panic!("Variable '{name}' must be in HH:MM format"))
+}
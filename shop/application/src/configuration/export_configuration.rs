@@ -0,0 +1,30 @@
+use std::{env, path::PathBuf, sync::LazyLock};
+
+use common::types::base::{AM, AMTrait};
+use dotenvy::dotenv;
+use file_order_exporter::file_order_exporter::FileOrderExporter;
+
+use crate::{
+    configuration::messaging_configuration::{EXPORTED_ORDER_ROUTE, RABBITMQ_MESSAGE_PUBLISHER},
+    event::{
+        rabbit_message_publisher::RabbitMessagePublisher,
+        rabbit_order_exporter::RabbitOrderExporter,
+    },
+};
+
+/// Sends the placed orders to RabbitMQ
+pub(super) static RABBIT_ORDER_EXPORTER: LazyLock<AM<RabbitOrderExporter<RabbitMessagePublisher>>> =
+    LazyLock::new(|| {
+        AM::new_am(RabbitOrderExporter::new(
+            &*RABBITMQ_MESSAGE_PUBLISHER,
+            EXPORTED_ORDER_ROUTE.clone(),
+        ))
+    });
+
+/// Appends the placed orders to the JSON lines file read by the accounting
+pub(super) static FILE_ORDER_EXPORTER: LazyLock<AM<FileOrderExporter>> = LazyLock::new(|| {
+    dotenv().ok();
+    AM::new_am(FileOrderExporter::new(PathBuf::from(
+        env::var("ORDER_EXPORT_FILE").unwrap(),
+    )))
+});
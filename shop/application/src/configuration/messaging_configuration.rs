@@ -1,15 +1,55 @@
-#![allow(dead_code)]
 use std::{env, sync::LazyLock};
 
+use common::types::base::{AM, AMTrait};
 use dotenvy::dotenv;
 
-use crate::event::rabbit_message_publisher::RabbitMessagePublisher;
+use crate::event::{
+    integration_event_publisher::IntegrationEventPublisher,
+    integration_message_publisher::MessageRoute, rabbit_message_publisher::RabbitMessagePublisher,
+};
 
+/// One connection to RabbitMQ shared by everything sending integration messages
 pub(super) static RABBITMQ_MESSAGE_PUBLISHER: LazyLock<RabbitMessagePublisher> =
-    LazyLock::new(RabbitMessagePublisher::new);
-pub(crate) static RABBITMQ_ADDRESS: LazyLock<String> = LazyLock::new(|| {
+    LazyLock::new(|| RabbitMessagePublisher::new(RABBITMQ_ADDRESS.clone()));
+static RABBITMQ_ADDRESS: LazyLock<String> = LazyLock::new(|| {
     dotenv().ok();
     env::var("RABBITMQ_ADDRESS").unwrap()
 });
-pub(crate) static RABBITMQ_QUEUE_NAME: LazyLock<String> =
-    LazyLock::new(|| "meal_queue".to_string());
+
+pub(super) static EXPORTED_ORDER_ROUTE: LazyLock<MessageRoute> =
+    LazyLock::new(|| message_route("EXPORTED_ORDER"));
+
+/// Sends the meal events to RabbitMQ next to Kafka
+pub(super) static RABBITMQ_MEAL_EVENT_PUBLISHER: LazyLock<
+    AM<IntegrationEventPublisher<RabbitMessagePublisher>>,
+> = LazyLock::new(|| {
+    AM::new_am(IntegrationEventPublisher::new(
+        &*RABBITMQ_MESSAGE_PUBLISHER,
+        message_route("MEAL_EVENT"),
+    ))
+});
+
+/// Sends the order events to RabbitMQ next to Kafka
+pub(super) static RABBITMQ_ORDER_EVENT_PUBLISHER: LazyLock<
+    AM<IntegrationEventPublisher<RabbitMessagePublisher>>,
+> = LazyLock::new(|| {
+    AM::new_am(IntegrationEventPublisher::new(
+        &*RABBITMQ_MESSAGE_PUBLISHER,
+        message_route("ORDER_EVENT"),
+    ))
+});
+
+/// Reads `RABBITMQ_<message type>_EXCHANGE`, `RABBITMQ_<message type>_ROUTING_KEY` and
+/// `RABBITMQ_<message type>_QUEUE`
+fn message_route(message_type: &str) -> MessageRoute {
+    dotenv().ok();
+    let variable = |name: &str| {
+        let name = format!("RABBITMQ_{message_type}_{name}");
+        env::var(&name).unwrap_or_else(|_| panic!("Variable '{name}' is not set"))
+    };
+    MessageRoute::new(
+        variable("EXCHANGE"),
+        variable("ROUTING_KEY"),
+        variable("QUEUE"),
+    )
+}
@@ -1,4 +1,7 @@
 pub mod application_configuration;
+pub mod auth_configuration;
+pub mod delivery_configuration;
+pub mod export_configuration;
 pub mod messaging_configuration;
 pub mod persistence_configuration;
 pub mod swagger_configuration;
@@ -6,3 +9,4 @@ pub mod telnet_api_configuration;
 pub mod telnet_server_configuration;
 pub mod use_case_configuration;
 pub mod web_api_configuration;
+pub mod webhook_configuration;
@@ -1,40 +1,87 @@
-use std::sync::LazyLock;
+use std::{env, path::PathBuf, sync::LazyLock};
 
-use common::types::base::{AM, AMTrait};
-use in_memory_persistence::order::{
-    in_memory_incremental_shop_order_id_generator::InMemoryIncrementalShopOrderIdGenerator,
-    in_memory_shop_order_repository::InMemoryShopOrderRepository,
+use common::{
+    common_rest::BASE_URL,
+    types::base::{AM, AMTrait},
 };
+use in_memory_persistence::{
+    customer::in_memory_customer_id_generator::InMemoryCustomerIdGenerator,
+    order::in_memory_incremental_shop_order_id_generator::InMemoryIncrementalShopOrderIdGenerator,
+    webhook::in_memory_webhook_subscription_id_generator::InMemoryWebhookSubscriptionIdGenerator,
+};
+use local_media_storage::local_media_storage::LocalMediaStorage;
 use postgres_persistence::{
-    database_start::establish_connection, postgres_meal_id_generator::PostgresMealIdGenerator,
+    database_start::establish_connection, postgres_customer_repository::PostgresCustomerRepository,
+    postgres_failed_webhook_delivery_repository::PostgresFailedWebhookDeliveryRepository,
+    postgres_meal_id_generator::PostgresMealIdGenerator,
     postgres_meal_repository::PostgresMealRepository,
+    postgres_meal_stock_repository::PostgresMealStockRepository,
+    postgres_shop_order_repository::PostgresShopOrderRepository,
+    postgres_webhook_subscription_repository::PostgresWebhookSubscriptionRepository,
 };
+use rest::endpoint_url::MEDIA;
 
-use crate::configuration::application_configuration::EVENT_PUBLISHER;
+use crate::configuration::application_configuration::{
+    EVENT_PUBLISHER, MEAL_EVENT_PUBLISHER, ORDER_EVENT_PUBLISHER,
+};
 
 pub type ORepository = OrderRepository;
-type OrderRepository = InMemoryShopOrderRepository;
+type OrderRepository = PostgresShopOrderRepository;
 #[allow(dead_code)]
 type OrderIdGenerator = InMemoryIncrementalShopOrderIdGenerator;
 type MealIdGenerator = PostgresMealIdGenerator;
 type MealRepository = PostgresMealRepository;
+type MealStockRepository = PostgresMealStockRepository;
+type CustomerIdGenerator = InMemoryCustomerIdGenerator;
+type CustomerRepository = PostgresCustomerRepository;
+type MealMediaStorage = LocalMediaStorage;
+type WebhookSubscriptionIdGenerator = InMemoryWebhookSubscriptionIdGenerator;
+type WebhookSubscriptionRepository = PostgresWebhookSubscriptionRepository;
+type FailedWebhookDeliveryRepository = PostgresFailedWebhookDeliveryRepository;
 
 pub(super) static MEAL_ID_GENERATOR: LazyLock<AM<MealIdGenerator>> =
     LazyLock::new(meal_id_generator);
 /// `MealRepository` dependency injection
 pub(super) static MEAL_REPOSITORY: LazyLock<AM<MealRepository>> = LazyLock::new(meal_repository);
+pub(super) static MEAL_STOCK_REPOSITORY: LazyLock<AM<MealStockRepository>> =
+    LazyLock::new(meal_stock_repository);
 
 #[allow(dead_code)]
 pub(super) static ORDER_ID_GENERATOR: LazyLock<AM<OrderIdGenerator>> =
     LazyLock::new(order_id_generator);
 pub(super) static ORDER_REPOSITORY: LazyLock<AM<OrderRepository>> = LazyLock::new(order_repository);
 
+pub(super) static CUSTOMER_ID_GENERATOR: LazyLock<AM<CustomerIdGenerator>> =
+    LazyLock::new(customer_id_generator);
+/// `CustomerRepository` dependency injection
+pub(super) static CUSTOMER_REPOSITORY: LazyLock<AM<CustomerRepository>> =
+    LazyLock::new(customer_repository);
+
+pub(super) static WEBHOOK_SUBSCRIPTION_ID_GENERATOR: LazyLock<AM<WebhookSubscriptionIdGenerator>> =
+    LazyLock::new(webhook_subscription_id_generator);
+pub(super) static WEBHOOK_SUBSCRIPTION_REPOSITORY: LazyLock<AM<WebhookSubscriptionRepository>> =
+    LazyLock::new(webhook_subscription_repository);
+/// Dead letters of the webhooks
+pub(super) static FAILED_WEBHOOK_DELIVERY_REPOSITORY: LazyLock<
+    AM<FailedWebhookDeliveryRepository>,
+> = LazyLock::new(failed_webhook_delivery_repository);
+
+/// Meal images, served by the web api as static files
+pub(super) static MEDIA_STORAGE: LazyLock<AM<MealMediaStorage>> = LazyLock::new(media_storage);
+
 fn meal_id_generator() -> AM<MealIdGenerator> {
     AM::new_am(MealIdGenerator::new(establish_connection()))
 }
 
 fn meal_repository() -> AM<MealRepository> {
     AM::new_am(MealRepository::new(
+        establish_connection(),
+        MEAL_EVENT_PUBLISHER.clone(),
+    ))
+}
+
+fn meal_stock_repository() -> AM<MealStockRepository> {
+    AM::new_am(MealStockRepository::new(
         establish_connection(),
         EVENT_PUBLISHER.clone(),
     ))
@@ -45,5 +92,48 @@ fn order_id_generator() -> AM<OrderIdGenerator> {
 }
 
 pub fn order_repository() -> AM<ORepository> {
-    AM::new_am(ORepository::new(EVENT_PUBLISHER.clone()))
+    AM::new_am(ORepository::new(
+        establish_connection(),
+        ORDER_EVENT_PUBLISHER.clone(),
+    ))
+}
+
+fn customer_id_generator() -> AM<CustomerIdGenerator> {
+    AM::new_am(CustomerIdGenerator::new())
+}
+
+fn customer_repository() -> AM<CustomerRepository> {
+    AM::new_am(CustomerRepository::new(
+        establish_connection(),
+        EVENT_PUBLISHER.clone(),
+    ))
+}
+
+fn webhook_subscription_id_generator() -> AM<WebhookSubscriptionIdGenerator> {
+    AM::new_am(WebhookSubscriptionIdGenerator::new())
+}
+
+fn webhook_subscription_repository() -> AM<WebhookSubscriptionRepository> {
+    AM::new_am(WebhookSubscriptionRepository::new(
+        establish_connection(),
+        EVENT_PUBLISHER.clone(),
+    ))
+}
+
+fn failed_webhook_delivery_repository() -> AM<FailedWebhookDeliveryRepository> {
+    AM::new_am(FailedWebhookDeliveryRepository::new(establish_connection()))
+}
+
+/// Directory of the meal images
+pub(super) fn media_root() -> PathBuf {
+    env::var("MEDIA_ROOT")
+        .expect("Variable 'MEDIA_ROOT' not found")
+        .into()
+}
+
+fn media_storage() -> AM<MealMediaStorage> {
+    AM::new_am(
+        MealMediaStorage::open(media_root(), format!("{}{MEDIA}", *BASE_URL))
+            .expect("Error reading the media root"),
+    )
 }
@@ -1,40 +1,142 @@
 use common::common_rest::{GenericErrorResponse, ValidationError};
 use rest::{
-    menu::{add_meal_to_menu_endpoint::AddMealToMenuRestRequest, meal_model::MealModel},
-    order::order_model::{AddressModel, OrderItemModel, OrderModel},
+    auth::role_model::RoleModel,
+    customer::{
+        customer_model::{CustomerModel, CustomerPreferencesModel, SavedAddressModel},
+        register_customer_endpoint::RegisterCustomerRestRequest,
+        update_customer_endpoint::UpdateCustomerRestRequest,
+    },
+    inventory::set_meal_portions_endpoint::SetMealPortionsRestRequest,
+    menu::{
+        add_meal_to_menu_endpoint::AddMealToMenuRestRequest,
+        change_meal_availability_endpoint::ChangeMealAvailabilityRestRequest,
+        meal_model::{
+            AvailabilityScheduleModel, AvailabilityWindowModel, MealModel, NutritionFactsModel,
+        },
+        meal_row_model::{MealRowModel, MenuImportReportModel, RejectedMealRowModel},
+        upload_meal_image_endpoint::UploadMealImageRestRequest,
+    },
+    order::{
+        kitchen_display_model::{
+            KitchenCommandAckModel, KitchenCommandModel, KitchenCommandTypeModel,
+            KitchenDisplayMessage, KitchenOrderModel,
+        },
+        order_model::{
+            AddressModel, AvailableDeliverySlotModel, DeliverySlotModel, FulfilmentModel,
+            LastOrderStateModel, OrderItemModel, OrderModel, OrderStateChangedModel,
+            OrderStateModel,
+        },
+    },
+    webhook::{
+        subscribe_webhook_endpoint::SubscribeWebhookRestRequest,
+        webhook_model::{FailedWebhookDeliveryModel, WebhookSubscriptionModel},
+    },
+};
+use utoipa::{
+    Modify, OpenApi,
+    openapi::security::{HttpAuthScheme, HttpBuilder, SecurityScheme},
 };
-use utoipa::OpenApi;
 
 #[derive(OpenApi)]
 #[openapi(
     info(title = "Rust DDD Example", description = "API Documentation"),
+    modifiers(&SecurityAddon),
     paths(
         rest::menu::get_health_status::get_health_status,
         rest::menu::add_meal_to_menu_endpoint::add_meal_to_menu_endpoint,
         rest::menu::get_meal_by_id_endpoint::get_meal_by_id_endpoint,
         rest::menu::get_menu_endpoint::get_menu_endpoint,
         rest::menu::remove_meal_from_menu_endpoint::remove_meal_from_menu_endpoint,
+        rest::menu::search_menu_endpoint::search_menu_endpoint,
+        rest::menu::upload_meal_image_endpoint::upload_meal_image_endpoint,
+        rest::menu::import_menu_endpoint::import_menu_endpoint,
+        rest::menu::export_menu_endpoint::export_menu_endpoint,
+        rest::menu::change_meal_availability_endpoint::change_meal_availability_endpoint,
+        rest::inventory::set_meal_portions_endpoint::set_meal_portions_endpoint,
         rest::order::get_orders_endpoint::get_orders_endpoint,
         rest::order::get_order_by_id_endpoint::get_order_by_id_endpoint,
+        rest::order::get_order_events_endpoint::get_order_events_endpoint,
         rest::order::cancel_order_endpoint::cancel_order_endpoint,
         rest::order::confirm_order_endpoint::confirm_order_endpoint,
+        rest::order::get_available_delivery_slots_endpoint::get_available_delivery_slots_endpoint,
+        rest::order::get_customer_orders_endpoint::get_customer_orders_endpoint,
+        rest::order::get_last_order_state_endpoint::get_last_order_state_endpoint,
+        rest::order::kitchen_display_endpoint::kitchen_display_endpoint,
+        rest::customer::register_customer_endpoint::register_customer_endpoint,
+        rest::customer::get_customer_by_id_endpoint::get_customer_by_id_endpoint,
+        rest::customer::update_customer_endpoint::update_customer_endpoint,
+        rest::customer::remove_customer_endpoint::remove_customer_endpoint,
+        rest::customer::add_customer_address_endpoint::add_customer_address_endpoint,
+        rest::customer::remove_customer_address_endpoint::remove_customer_address_endpoint,
+        rest::webhook::subscribe_webhook_endpoint::subscribe_webhook_endpoint,
+        rest::webhook::get_webhook_subscriptions_endpoint::get_webhook_subscriptions_endpoint,
+        rest::webhook::remove_webhook_subscription_endpoint::remove_webhook_subscription_endpoint,
+        rest::webhook::get_failed_webhook_deliveries_endpoint::get_failed_webhook_deliveries_endpoint,
     ),
     components(
         schemas(
             AddMealToMenuRestRequest,
+            SetMealPortionsRestRequest,
+            UploadMealImageRestRequest,
+            ChangeMealAvailabilityRestRequest,
             MealModel,
+            NutritionFactsModel,
+            AvailabilityScheduleModel,
+            AvailabilityWindowModel,
+            MealRowModel,
+            MenuImportReportModel,
+            RejectedMealRowModel,
             GenericErrorResponse,
             ValidationError,
             OrderModel,
             OrderItemModel,
-            AddressModel
+            FulfilmentModel,
+            AddressModel,
+            DeliverySlotModel,
+            AvailableDeliverySlotModel,
+            OrderStateModel,
+            LastOrderStateModel,
+            OrderStateChangedModel,
+            KitchenDisplayMessage,
+            KitchenOrderModel,
+            KitchenCommandModel,
+            KitchenCommandTypeModel,
+            KitchenCommandAckModel,
+            RegisterCustomerRestRequest,
+            UpdateCustomerRestRequest,
+            CustomerModel,
+            SavedAddressModel,
+            CustomerPreferencesModel,
+            SubscribeWebhookRestRequest,
+            WebhookSubscriptionModel,
+            FailedWebhookDeliveryModel,
+            RoleModel
         ),
-        responses(MealModel, GenericErrorResponse, OrderModel)
+        responses(MealModel, GenericErrorResponse, OrderModel, CustomerModel)
     ),
     tags(
                 (name = "Health", description = "Health check"),
                 (name = "Meal", description = "All about Meal"),
-                (name = "Order", description = "Operations with Order")
+                (name = "Order", description = "Operations with Order"),
+                (name = "Customer", description = "Customer profile and saved addresses"),
+                (name = "Webhook", description = "Order callbacks to the partners")
     )
 )]
 pub(crate) struct ApiDoc;
+
+struct SecurityAddon;
+
+impl Modify for SecurityAddon {
+    fn modify(&self, openapi: &mut utoipa::openapi::OpenApi) {
+        let components = openapi.components.get_or_insert_with(Default::default);
+        components.add_security_scheme(
+            "bearerAuth",
+            SecurityScheme::Http(
+                HttpBuilder::new()
+                    .scheme(HttpAuthScheme::Bearer)
+                    .bearer_format("JWT")
+                    .build(),
+            ),
+        );
+    }
+}
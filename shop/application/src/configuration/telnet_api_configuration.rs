@@ -1,16 +1,56 @@
-use std::error::Error;
+use std::{error::Error, sync::LazyLock};
 
-use futures_lite::StreamExt;
 use futures_util::sink::SinkExt;
-use log::info;
 use nectar::{TelnetCodec, event::TelnetEvent};
-use telnet::menu::{get_health_command::get_health_command, get_menu_command::get_menu_command};
+use telnet::{
+    application_telnet_command::TelnetCommandRegistry,
+    menu::{
+        add_meal_to_menu_command::AddMealToMenuCommand, get_health_command::GetHealthCommand,
+        get_meal_by_id_command::GetMealByIdCommand, get_menu_command::GetMenuCommand,
+        remove_meal_from_menu_command::RemoveMealFromMenuCommand,
+        search_menu_command::SearchMenuCommand,
+    },
+    order::{
+        cancel_order_command::CancelOrderCommand, complete_order_command::CompleteOrderCommand,
+        confirm_order_command::ConfirmOrderCommand, get_order_by_id_command::GetOrderByIdCommand,
+        get_orders_command::GetOrdersCommand, watch_orders_command::WatchOrdersCommand,
+    },
+    session::telnet_session::TelnetSession,
+};
 use tokio::net::TcpStream;
 use tokio_util::codec::Framed;
 
-use super::use_case_configuration::GET_MENU_USE_CASE;
+use super::{
+    application_configuration::ORDER_EVENT_FEED,
+    auth_configuration::{TELNET_SESSION_SETTINGS, TELNET_USER_STORE},
+    use_case_configuration::{
+        ADD_MEAL_TO_MENU_USE_CASE, CANCEL_ORDER_USECASE, COMPLETE_ORDER_USECASE,
+        CONFIRM_ORDER_USECASE, GET_MEAL_BY_ID_USE_CASE, GET_MENU_USE_CASE, GET_ORDER_BY_ID,
+        GET_ORDERS_USECASE, REMOVE_MEAL_FROM_MENU_USECASE, SEARCH_MENU_USE_CASE,
+    },
+};
 
-pub(super) async fn handle_telnet_client(stream: TcpStream) -> Result<(), Box<dyn Error>> {
+static TELNET_COMMANDS: LazyLock<TelnetCommandRegistry> = LazyLock::new(|| {
+    TelnetCommandRegistry::default()
+        .register(GetHealthCommand)
+        .register(GetMenuCommand::new(GET_MENU_USE_CASE.clone()))
+        .register(SearchMenuCommand::new(SEARCH_MENU_USE_CASE.clone()))
+        .register(GetMealByIdCommand::new(GET_MEAL_BY_ID_USE_CASE.clone()))
+        .register(AddMealToMenuCommand::new(ADD_MEAL_TO_MENU_USE_CASE.clone()))
+        .register(RemoveMealFromMenuCommand::new(
+            REMOVE_MEAL_FROM_MENU_USECASE.clone(),
+        ))
+        .register(GetOrdersCommand::new(GET_ORDERS_USECASE.clone()))
+        .register(GetOrderByIdCommand::new(GET_ORDER_BY_ID.clone()))
+        .register(ConfirmOrderCommand::new(CONFIRM_ORDER_USECASE.clone()))
+        .register(CancelOrderCommand::new(CANCEL_ORDER_USECASE.clone()))
+        .register(CompleteOrderCommand::new(COMPLETE_ORDER_USECASE.clone()))
+        .register(WatchOrdersCommand::new(ORDER_EVENT_FEED.clone()))
+});
+
+pub(super) async fn handle_telnet_client(
+    stream: TcpStream,
+) -> Result<(), Box<dyn Error + Send + Sync>> {
     // We construct a 'Frame', which is just a wrapper around the underlying
     // stream that is decoded by the `nectar::TelnetCodec`.
     let mut frame = Framed::new(stream, TelnetCodec::new(1024));
@@ -18,39 +58,30 @@ pub(super) async fn handle_telnet_client(stream: TcpStream) -> Result<(), Box<dy
     // Let's send a friendly welcome message to anyone who connects!
     frame
         .send(TelnetEvent::Message(
-            "\nWelcome to the nectar telnet server!\nYou can exit by typing \"quit\".\n"
-                .to_string(),
+            "\nWelcome to the nectar telnet server!\nPlease log in.\n".to_string(),
+        ))
+        .await?;
+
+    let mut session = TelnetSession::new(*TELNET_SESSION_SETTINGS);
+    if !session.login(&*TELNET_USER_STORE, &mut frame).await? {
+        return Ok(());
+    }
+    frame
+        .send(TelnetEvent::Message(
+            "Type \"help\" to list the commands, you can exit by typing \"quit\".\n".to_string(),
         ))
         .await?;
 
-    // In a real application, you would want to handle Some(Err(_)) and None
-    // variants, but for this example we'll be succinct for simplicities sake.
-    while let Some(Ok(msg)) = frame.next().await {
-        match msg {
-            TelnetEvent::Message(string) => {
-                match string.as_str() {
-                    "quit" => {
-                        break;
-                    }
-                    "check health" => {
-                        info!("Checking health by Telnet");
-                        get_health_command(&mut frame).await?;
-                    }
-                    "get menu" => {
-                        info!("Getting menu by Telnet");
-                        get_menu_command(GET_MENU_USE_CASE.clone(), &mut frame).await?;
-                    }
-                    // // ...or just echo back whatever the user has said!
-                    _ => {
-                        frame
-                            .send(TelnetEvent::Message(format!("You said: {}\n", string)))
-                            .await?;
-                    }
-                }
-                // We can check for commands...
+    // The session ends when the client is gone or idle for too long
+    while let Some(line) = session.next_line(&mut frame).await? {
+        match line.trim() {
+            "quit" => break,
+            "" => continue,
+            line => {
+                TELNET_COMMANDS
+                    .execute(line, &mut session, &mut frame)
+                    .await?
             }
-            // We break here to close to connection.
-            _ => break,
         }
     }
 
@@ -3,22 +3,45 @@ use std::sync::LazyLock;
 use actix_web::web::Data;
 use common::types::base::{AM, AMTrait};
 use usecase::{
+    customer::{
+        invariant::customer_already_exists_uses_customer_extractor::CustomerAlreadyExistsUsesCustomerExtractor,
+        scenario::{
+            AddCustomerAddressUseCase, GetCustomerByIdUseCase, RegisterCustomerUseCase,
+            RemoveCustomerAddressUseCase, RemoveCustomerUseCase, UpdateCustomerUseCase,
+        },
+    },
+    inventory::scenarios::SetMealPortionsUseCase,
     menu::{
         invariant::meal_already_exists_uses_meal_extractor::MealAlreadyExistsUsesMealExtractor,
         scenario::{
-            AddMealToMenuUseCase, GetMealByIdUseCase, GetMenuUseCase, RemoveMealFromMenuUseCase,
+            AddMealToMenuUseCase, ChangeMealAvailabilityUseCase, ExportMenuUseCase,
+            GetMealByIdUseCase, GetMenuUseCase, ImportMenuUseCase, RemoveMealFromMenuUseCase,
+            SearchMenuUseCase, UploadMealImageUseCase,
         },
     },
     order::scenarios::{
-        CancelOrderUseCase, ConfirmOrderUseCase, GetOrderByIdUseCase, GetOrdersUseCase,
+        CancelOrderUseCase, CompleteOrderUseCase, ConfirmOrderUseCase, GetActiveOrdersUseCase,
+        GetAvailableDeliverySlotsUseCase, GetCustomerOrdersUseCase, GetLastOrderStateUseCase,
+        GetOrderByIdUseCase, GetOrdersUseCase,
+    },
+    webhook::scenarios::{
+        GetFailedWebhookDeliveriesUseCase, GetWebhookSubscriptionsUseCase,
+        RemoveWebhookSubscriptionUseCase, SubscribeWebhookUseCase,
     },
 };
 
-use crate::configuration::persistence_configuration::{
-    MEAL_ID_GENERATOR, MEAL_REPOSITORY, ORDER_REPOSITORY, ORepository,
+use crate::configuration::{
+    application_configuration::MENU_CACHE,
+    delivery_configuration::{DELIVERY_SLOT_CAPACITY, OPENING_HOURS},
+    persistence_configuration::{
+        CUSTOMER_ID_GENERATOR, CUSTOMER_REPOSITORY, FAILED_WEBHOOK_DELIVERY_REPOSITORY,
+        MEAL_ID_GENERATOR, MEAL_REPOSITORY, MEAL_STOCK_REPOSITORY, MEDIA_STORAGE, ORDER_REPOSITORY,
+        ORepository, WEBHOOK_SUBSCRIPTION_ID_GENERATOR, WEBHOOK_SUBSCRIPTION_REPOSITORY,
+    },
 };
 
 const GET_ORDERS_MAX_SIZE: usize = 10;
+const GET_MENU_MAX_SIZE: usize = 50;
 
 pub(super) static ADD_MEAL_TO_MENU_USE_CASE: LazyLock<Data<AM<AddMealToMenuUseCase>>> =
     LazyLock::new(|| Data::new(add_meal_to_menu_use_case()).clone());
@@ -28,16 +51,68 @@ pub(super) static GET_MENU_USE_CASE: LazyLock<Data<AM<GetMenuUseCase>>> =
     LazyLock::new(|| Data::new(get_menu_use_case()).clone());
 pub(super) static REMOVE_MEAL_FROM_MENU_USECASE: LazyLock<Data<AM<RemoveMealFromMenuUseCase>>> =
     LazyLock::new(|| Data::new(remove_meal_from_menu_usecase()).clone());
+pub(super) static SEARCH_MENU_USE_CASE: LazyLock<Data<AM<SearchMenuUseCase>>> =
+    LazyLock::new(|| Data::new(search_menu_use_case()).clone());
+pub(super) static IMPORT_MENU_USE_CASE: LazyLock<Data<AM<ImportMenuUseCase>>> =
+    LazyLock::new(|| Data::new(import_menu_use_case()));
+pub(super) static EXPORT_MENU_USE_CASE: LazyLock<Data<AM<ExportMenuUseCase>>> =
+    LazyLock::new(|| Data::new(export_menu_use_case()));
+pub(super) static UPLOAD_MEAL_IMAGE_USE_CASE: LazyLock<Data<AM<UploadMealImageUseCase>>> =
+    LazyLock::new(|| Data::new(upload_meal_image_use_case()));
+pub(super) static SET_MEAL_PORTIONS_USE_CASE: LazyLock<Data<AM<SetMealPortionsUseCase>>> =
+    LazyLock::new(|| Data::new(set_meal_portions_use_case()));
+pub(super) static CHANGE_MEAL_AVAILABILITY_USE_CASE: LazyLock<
+    Data<AM<ChangeMealAvailabilityUseCase>>,
+> = LazyLock::new(|| Data::new(change_meal_availability_use_case()));
 pub(super) static CANCEL_ORDER_USECASE: LazyLock<
     Data<AM<CancelOrderUseCase<ORepository, ORepository>>>,
 > = LazyLock::new(|| Data::new(cancel_order_usecase().clone()));
 pub(super) static CONFIRM_ORDER_USECASE: LazyLock<
     Data<AM<ConfirmOrderUseCase<ORepository, ORepository>>>,
 > = LazyLock::new(|| Data::new(confirm_order_usecase().clone()));
+pub(super) static COMPLETE_ORDER_USECASE: LazyLock<Data<AM<CompleteOrderUseCase>>> =
+    LazyLock::new(|| Data::new(complete_order_usecase()));
 pub(super) static GET_ORDER_BY_ID: LazyLock<Data<AM<GetOrderByIdUseCase<ORepository>>>> =
     LazyLock::new(|| Data::new(get_order_by_id_usecase().clone()));
+pub(super) static GET_ACTIVE_ORDERS_USECASE: LazyLock<
+    Data<AM<GetActiveOrdersUseCase<ORepository>>>,
+> = LazyLock::new(|| Data::new(get_active_orders_usecase()));
 pub(super) static GET_ORDERS_USECASE: LazyLock<Data<AM<GetOrdersUseCase<ORepository>>>> =
     LazyLock::new(|| Data::new(get_orders_usecase().clone()));
+pub(super) static GET_AVAILABLE_DELIVERY_SLOTS_USECASE: LazyLock<
+    Data<AM<GetAvailableDeliverySlotsUseCase>>,
+> = LazyLock::new(|| Data::new(get_available_delivery_slots_usecase().clone()));
+pub(super) static GET_CUSTOMER_ORDERS_USECASE: LazyLock<
+    Data<AM<GetCustomerOrdersUseCase<ORepository>>>,
+> = LazyLock::new(|| Data::new(get_customer_orders_usecase().clone()));
+pub(super) static GET_LAST_ORDER_STATE_USECASE: LazyLock<Data<AM<GetLastOrderStateUseCase>>> =
+    LazyLock::new(|| Data::new(get_last_order_state_usecase().clone()));
+
+pub(super) static REGISTER_CUSTOMER_USE_CASE: LazyLock<Data<AM<RegisterCustomerUseCase>>> =
+    LazyLock::new(|| Data::new(register_customer_use_case()));
+pub(super) static GET_CUSTOMER_BY_ID_USE_CASE: LazyLock<Data<AM<GetCustomerByIdUseCase>>> =
+    LazyLock::new(|| Data::new(get_customer_by_id_use_case()));
+pub(super) static UPDATE_CUSTOMER_USE_CASE: LazyLock<Data<AM<UpdateCustomerUseCase>>> =
+    LazyLock::new(|| Data::new(update_customer_use_case()));
+pub(super) static REMOVE_CUSTOMER_USE_CASE: LazyLock<Data<AM<RemoveCustomerUseCase>>> =
+    LazyLock::new(|| Data::new(remove_customer_use_case()));
+pub(super) static ADD_CUSTOMER_ADDRESS_USE_CASE: LazyLock<Data<AM<AddCustomerAddressUseCase>>> =
+    LazyLock::new(|| Data::new(add_customer_address_use_case()));
+pub(super) static REMOVE_CUSTOMER_ADDRESS_USE_CASE: LazyLock<
+    Data<AM<RemoveCustomerAddressUseCase>>,
+> = LazyLock::new(|| Data::new(remove_customer_address_use_case()));
+
+pub(super) static SUBSCRIBE_WEBHOOK_USE_CASE: LazyLock<Data<AM<SubscribeWebhookUseCase>>> =
+    LazyLock::new(|| Data::new(subscribe_webhook_use_case()));
+pub(super) static GET_WEBHOOK_SUBSCRIPTIONS_USE_CASE: LazyLock<
+    Data<AM<GetWebhookSubscriptionsUseCase>>,
+> = LazyLock::new(|| Data::new(get_webhook_subscriptions_use_case()));
+pub(super) static REMOVE_WEBHOOK_SUBSCRIPTION_USE_CASE: LazyLock<
+    Data<AM<RemoveWebhookSubscriptionUseCase>>,
+> = LazyLock::new(|| Data::new(remove_webhook_subscription_use_case()));
+pub(super) static GET_FAILED_WEBHOOK_DELIVERIES_USE_CASE: LazyLock<
+    Data<AM<GetFailedWebhookDeliveriesUseCase>>,
+> = LazyLock::new(|| Data::new(get_failed_webhook_deliveries_use_case()));
 
 fn add_meal_to_menu_use_case() -> AM<AddMealToMenuUseCase> {
     let rule = MealAlreadyExistsUsesMealExtractor::new(MEAL_REPOSITORY.clone());
@@ -51,12 +126,18 @@ fn add_meal_to_menu_use_case() -> AM<AddMealToMenuUseCase> {
 }
 
 fn get_meal_by_id_use_case() -> AM<GetMealByIdUseCase> {
-    let usecase = GetMealByIdUseCase::new(MEAL_REPOSITORY.clone());
+    let usecase = GetMealByIdUseCase::new(MEAL_REPOSITORY.clone(), MEDIA_STORAGE.clone());
     AM::new_am(usecase)
 }
 
 fn get_menu_use_case() -> AM<GetMenuUseCase> {
-    let usecase = GetMenuUseCase::new(MEAL_REPOSITORY.clone());
+    let usecase = GetMenuUseCase::new(
+        MEAL_REPOSITORY.clone(),
+        MENU_CACHE.clone(),
+        MEAL_STOCK_REPOSITORY.clone(),
+        MEDIA_STORAGE.clone(),
+        || GET_MENU_MAX_SIZE + 1,
+    );
     AM::new_am(usecase)
 }
 
@@ -65,6 +146,47 @@ fn remove_meal_from_menu_usecase() -> AM<RemoveMealFromMenuUseCase> {
     AM::new_am(usecase)
 }
 
+fn search_menu_use_case() -> AM<SearchMenuUseCase> {
+    let usecase = SearchMenuUseCase::new(MEAL_REPOSITORY.clone(), MEDIA_STORAGE.clone());
+    AM::new_am(usecase)
+}
+
+fn import_menu_use_case() -> AM<ImportMenuUseCase> {
+    let rule = MealAlreadyExistsUsesMealExtractor::new(MEAL_REPOSITORY.clone());
+
+    let usecase = ImportMenuUseCase::new(
+        MEAL_REPOSITORY.clone(),
+        MEAL_ID_GENERATOR.clone(),
+        AM::new_am(rule),
+    );
+    AM::new_am(usecase)
+}
+
+fn export_menu_use_case() -> AM<ExportMenuUseCase> {
+    let usecase = ExportMenuUseCase::new(MEAL_REPOSITORY.clone());
+    AM::new_am(usecase)
+}
+
+fn upload_meal_image_use_case() -> AM<UploadMealImageUseCase> {
+    let usecase = UploadMealImageUseCase::new(MEAL_REPOSITORY.clone(), MEDIA_STORAGE.clone());
+    AM::new_am(usecase)
+}
+
+fn change_meal_availability_use_case() -> AM<ChangeMealAvailabilityUseCase> {
+    let usecase =
+        ChangeMealAvailabilityUseCase::new(MEAL_REPOSITORY.clone(), MEAL_REPOSITORY.clone());
+    AM::new_am(usecase)
+}
+
+fn set_meal_portions_use_case() -> AM<SetMealPortionsUseCase> {
+    let usecase = SetMealPortionsUseCase::new(
+        MEAL_REPOSITORY.clone(),
+        MEAL_STOCK_REPOSITORY.clone(),
+        MEAL_STOCK_REPOSITORY.clone(),
+    );
+    AM::new_am(usecase)
+}
+
 fn cancel_order_usecase() -> AM<CancelOrderUseCase<ORepository, ORepository>> {
     let usecase = CancelOrderUseCase::new(ORDER_REPOSITORY.clone(), ORDER_REPOSITORY.clone());
     AM::new_am(usecase)
@@ -74,12 +196,112 @@ fn confirm_order_usecase() -> AM<ConfirmOrderUseCase<ORepository, ORepository>>
     AM::new_am(usecase)
 }
 
+fn complete_order_usecase() -> AM<CompleteOrderUseCase> {
+    let usecase = CompleteOrderUseCase::new(ORDER_REPOSITORY.clone(), ORDER_REPOSITORY.clone());
+    AM::new_am(usecase)
+}
+
 fn get_order_by_id_usecase() -> AM<GetOrderByIdUseCase<ORepository>> {
     let usecase = GetOrderByIdUseCase::new(ORDER_REPOSITORY.clone());
     AM::new_am(usecase)
 }
 
+fn get_active_orders_usecase() -> AM<GetActiveOrdersUseCase<ORepository>> {
+    let usecase = GetActiveOrdersUseCase::new(ORDER_REPOSITORY.clone());
+    AM::new_am(usecase)
+}
+
 fn get_orders_usecase() -> AM<GetOrdersUseCase<ORepository>> {
     let usecase = GetOrdersUseCase::new(ORDER_REPOSITORY.clone(), || GET_ORDERS_MAX_SIZE + 1);
     AM::new_am(usecase)
 }
+
+fn get_customer_orders_usecase() -> AM<GetCustomerOrdersUseCase<ORepository>> {
+    let usecase =
+        GetCustomerOrdersUseCase::new(ORDER_REPOSITORY.clone(), || GET_ORDERS_MAX_SIZE + 1);
+    AM::new_am(usecase)
+}
+
+fn get_last_order_state_usecase() -> AM<GetLastOrderStateUseCase> {
+    let usecase = GetLastOrderStateUseCase::new(ORDER_REPOSITORY.clone());
+    AM::new_am(usecase)
+}
+
+fn get_available_delivery_slots_usecase() -> AM<GetAvailableDeliverySlotsUseCase> {
+    let usecase = GetAvailableDeliverySlotsUseCase::new(
+        ORDER_REPOSITORY.clone(),
+        *OPENING_HOURS,
+        *DELIVERY_SLOT_CAPACITY,
+    );
+    AM::new_am(usecase)
+}
+
+fn register_customer_use_case() -> AM<RegisterCustomerUseCase> {
+    let rule = CustomerAlreadyExistsUsesCustomerExtractor::new(CUSTOMER_REPOSITORY.clone());
+    let usecase = RegisterCustomerUseCase::new(
+        CUSTOMER_REPOSITORY.clone(),
+        CUSTOMER_ID_GENERATOR.clone(),
+        AM::new_am(rule),
+    );
+    AM::new_am(usecase)
+}
+
+fn get_customer_by_id_use_case() -> AM<GetCustomerByIdUseCase> {
+    let usecase = GetCustomerByIdUseCase::new(CUSTOMER_REPOSITORY.clone());
+    AM::new_am(usecase)
+}
+
+fn update_customer_use_case() -> AM<UpdateCustomerUseCase> {
+    let rule = CustomerAlreadyExistsUsesCustomerExtractor::new(CUSTOMER_REPOSITORY.clone());
+    let usecase = UpdateCustomerUseCase::new(
+        CUSTOMER_REPOSITORY.clone(),
+        CUSTOMER_REPOSITORY.clone(),
+        AM::new_am(rule),
+    );
+    AM::new_am(usecase)
+}
+
+fn remove_customer_use_case() -> AM<RemoveCustomerUseCase> {
+    let usecase =
+        RemoveCustomerUseCase::new(CUSTOMER_REPOSITORY.clone(), CUSTOMER_REPOSITORY.clone());
+    AM::new_am(usecase)
+}
+
+fn add_customer_address_use_case() -> AM<AddCustomerAddressUseCase> {
+    let usecase =
+        AddCustomerAddressUseCase::new(CUSTOMER_REPOSITORY.clone(), CUSTOMER_REPOSITORY.clone());
+    AM::new_am(usecase)
+}
+
+fn remove_customer_address_use_case() -> AM<RemoveCustomerAddressUseCase> {
+    let usecase =
+        RemoveCustomerAddressUseCase::new(CUSTOMER_REPOSITORY.clone(), CUSTOMER_REPOSITORY.clone());
+    AM::new_am(usecase)
+}
+
+fn subscribe_webhook_use_case() -> AM<SubscribeWebhookUseCase> {
+    let usecase = SubscribeWebhookUseCase::new(
+        WEBHOOK_SUBSCRIPTION_REPOSITORY.clone(),
+        WEBHOOK_SUBSCRIPTION_ID_GENERATOR.clone(),
+    );
+    AM::new_am(usecase)
+}
+
+fn get_webhook_subscriptions_use_case() -> AM<GetWebhookSubscriptionsUseCase> {
+    let usecase = GetWebhookSubscriptionsUseCase::new(WEBHOOK_SUBSCRIPTION_REPOSITORY.clone());
+    AM::new_am(usecase)
+}
+
+fn remove_webhook_subscription_use_case() -> AM<RemoveWebhookSubscriptionUseCase> {
+    let usecase = RemoveWebhookSubscriptionUseCase::new(
+        WEBHOOK_SUBSCRIPTION_REPOSITORY.clone(),
+        WEBHOOK_SUBSCRIPTION_REPOSITORY.clone(),
+    );
+    AM::new_am(usecase)
+}
+
+fn get_failed_webhook_deliveries_use_case() -> AM<GetFailedWebhookDeliveriesUseCase> {
+    let usecase =
+        GetFailedWebhookDeliveriesUseCase::new(FAILED_WEBHOOK_DELIVERY_REPOSITORY.clone());
+    AM::new_am(usecase)
+}
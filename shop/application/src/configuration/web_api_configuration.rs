@@ -1,56 +1,105 @@
-use std::{env, net::IpAddr};
+use std::{env, net::IpAddr, sync::LazyLock};
 
 use actix_cors::Cors;
+use actix_files::Files;
 use actix_web::{
     App, HttpServer,
     http::{Uri, header},
-    middleware::Logger,
+    middleware::{Logger, from_fn},
+    web::Data,
 };
 use log::info;
 use rest::{
+    auth::jwt_middleware::jwt_authentication,
+    customer::{
+        add_customer_address_endpoint::add_customer_address_endpoint_config,
+        get_customer_by_id_endpoint::get_customer_by_id_endpoint_config,
+        register_customer_endpoint::register_customer_endpoint_config,
+        remove_customer_address_endpoint::remove_customer_address_endpoint_config,
+        remove_customer_endpoint::remove_customer_endpoint_config,
+        update_customer_endpoint::update_customer_endpoint_config,
+    },
+    endpoint_url::MEDIA,
+    inventory::set_meal_portions_endpoint::set_meal_portions_endpoint_config,
     menu::{
         add_meal_to_menu_endpoint::add_meal_to_menu_endpoint_config,
+        change_meal_availability_endpoint::change_meal_availability_endpoint_config,
+        export_menu_endpoint::export_menu_endpoint_config,
         get_health_status::get_health_status_config,
         get_meal_by_id_endpoint::get_meal_by_id_endpoint_config,
         get_menu_endpoint::get_menu_endpoint_config,
+        import_menu_endpoint::import_menu_endpoint_config,
         remove_meal_from_menu_endpoint::remove_meal_from_menu_endpoint_config,
+        search_menu_endpoint::search_menu_endpoint_config,
+        upload_meal_image_endpoint::upload_meal_image_endpoint_config,
     },
     order::{
         cancel_order_endpoint::cancel_order_endpoint_config,
         confirm_order_endpoint::confirm_order_endpoint_config,
+        get_available_delivery_slots_endpoint::get_available_delivery_slots_endpoint_config,
+        get_customer_orders_endpoint::get_customer_orders_endpoint_config,
+        get_last_order_state_endpoint::get_last_order_state_endpoint_config,
         get_order_by_id_endpoint::get_order_by_id_endpoint_config,
+        get_order_events_endpoint::get_order_events_endpoint_config,
         get_orders_endpoint::get_orders_endpoint_config,
+        kitchen_display_endpoint::kitchen_display_endpoint_config,
+    },
+    webhook::{
+        get_failed_webhook_deliveries_endpoint::get_failed_webhook_deliveries_endpoint_config,
+        get_webhook_subscriptions_endpoint::get_webhook_subscriptions_endpoint_config,
+        remove_webhook_subscription_endpoint::remove_webhook_subscription_endpoint_config,
+        subscribe_webhook_endpoint::subscribe_webhook_endpoint_config,
     },
 };
-use serde::{Deserialize, Serialize};
 use tokio::{task, task::JoinHandle};
 use usecase::{
+    customer::scenario::{
+        AddCustomerAddressUseCase, GetCustomerByIdUseCase, RegisterCustomerUseCase,
+        RemoveCustomerAddressUseCase, RemoveCustomerUseCase, UpdateCustomerUseCase,
+    },
+    inventory::scenarios::SetMealPortionsUseCase,
     menu::scenario::{
-        AddMealToMenuUseCase, GetMealByIdUseCase, GetMenuUseCase, RemoveMealFromMenuUseCase,
+        AddMealToMenuUseCase, ChangeMealAvailabilityUseCase, ExportMenuUseCase, GetMealByIdUseCase,
+        GetMenuUseCase, ImportMenuUseCase, RemoveMealFromMenuUseCase, SearchMenuUseCase,
+        UploadMealImageUseCase,
     },
     order::scenarios::{
-        CancelOrderUseCase, ConfirmOrderUseCase, GetOrderByIdUseCase, GetOrdersUseCase,
+        CancelOrderUseCase, CompleteOrderUseCase, ConfirmOrderUseCase, GetActiveOrdersUseCase,
+        GetAvailableDeliverySlotsUseCase, GetCustomerOrdersUseCase, GetLastOrderStateUseCase,
+        GetOrderByIdUseCase, GetOrdersUseCase,
+    },
+    webhook::scenarios::{
+        GetFailedWebhookDeliveriesUseCase, GetWebhookSubscriptionsUseCase,
+        RemoveWebhookSubscriptionUseCase, SubscribeWebhookUseCase,
     },
 };
-use utoipa::{OpenApi, ToSchema};
+use utoipa::OpenApi;
 use utoipa_swagger_ui::SwaggerUi;
 
 use crate::configuration::{
-    persistence_configuration::ORepository,
+    application_configuration::ORDER_EVENT_FEED,
+    auth_configuration::JWT_AUTHENTICATOR,
+    persistence_configuration::{MEDIA_STORAGE, ORepository, media_root},
     swagger_configuration::ApiDoc,
     use_case_configuration::{
-        ADD_MEAL_TO_MENU_USE_CASE, CANCEL_ORDER_USECASE, CONFIRM_ORDER_USECASE,
+        ADD_CUSTOMER_ADDRESS_USE_CASE, ADD_MEAL_TO_MENU_USE_CASE, CANCEL_ORDER_USECASE,
+        CHANGE_MEAL_AVAILABILITY_USE_CASE, COMPLETE_ORDER_USECASE, CONFIRM_ORDER_USECASE,
+        EXPORT_MENU_USE_CASE, GET_ACTIVE_ORDERS_USECASE, GET_AVAILABLE_DELIVERY_SLOTS_USECASE,
+        GET_CUSTOMER_BY_ID_USE_CASE, GET_CUSTOMER_ORDERS_USECASE,
+        GET_FAILED_WEBHOOK_DELIVERIES_USE_CASE, GET_LAST_ORDER_STATE_USECASE,
         GET_MEAL_BY_ID_USE_CASE, GET_MENU_USE_CASE, GET_ORDER_BY_ID, GET_ORDERS_USECASE,
-        REMOVE_MEAL_FROM_MENU_USECASE,
+        GET_WEBHOOK_SUBSCRIPTIONS_USE_CASE, IMPORT_MENU_USE_CASE, REGISTER_CUSTOMER_USE_CASE,
+        REMOVE_CUSTOMER_ADDRESS_USE_CASE, REMOVE_CUSTOMER_USE_CASE, REMOVE_MEAL_FROM_MENU_USECASE,
+        REMOVE_WEBHOOK_SUBSCRIPTION_USE_CASE, SEARCH_MENU_USE_CASE, SET_MEAL_PORTIONS_USE_CASE,
+        SUBSCRIBE_WEBHOOK_USE_CASE, UPDATE_CUSTOMER_USE_CASE, UPLOAD_MEAL_IMAGE_USE_CASE,
     },
 };
 
-#[derive(Serialize, Deserialize, Clone, ToSchema)]
-pub struct TokenClaims {
-    id: i32,
-}
-
 pub(crate) fn web_api_backend_startup() -> JoinHandle<()> {
+    // Refuse to start with a missing or weak signing key instead of failing the first request
+    LazyLock::force(&JWT_AUTHENTICATOR);
+    // The stored image names are read before any request, not on the executor
+    LazyLock::force(&MEDIA_STORAGE);
     task::spawn(async {
         let http_host_url = env::var("HTTP_HOST_URL").unwrap();
         info!("Starting HTTP server at {}", http_host_url);
@@ -68,11 +117,21 @@ pub(crate) fn web_api_backend_startup() -> JoinHandle<()> {
                     SwaggerUi::new("/swagger-ui/{_:.*}")
                         .url("/api-docs/openapi.json", openapi.clone()),
                 )
+                .service(Files::new(MEDIA, media_root()))
                 .configure(get_health_status_config)
                 .configure(add_meal_to_menu_endpoint_config::<AddMealToMenuUseCase>)
+                // Before the meal by id route, which would take "search" or "export" for an id
+                .configure(search_menu_endpoint_config::<SearchMenuUseCase>)
+                .configure(export_menu_endpoint_config::<ExportMenuUseCase>)
+                .configure(import_menu_endpoint_config::<ImportMenuUseCase>)
                 .configure(get_meal_by_id_endpoint_config::<GetMealByIdUseCase>)
                 .configure(get_menu_endpoint_config::<GetMenuUseCase>)
                 .configure(remove_meal_from_menu_endpoint_config::<RemoveMealFromMenuUseCase>)
+                .configure(set_meal_portions_endpoint_config::<SetMealPortionsUseCase>)
+                .configure(upload_meal_image_endpoint_config::<UploadMealImageUseCase>)
+                .configure(
+                    change_meal_availability_endpoint_config::<ChangeMealAvailabilityUseCase>,
+                )
                 .configure(
                     cancel_order_endpoint_config::<CancelOrderUseCase<ORepository, ORepository>>,
                 )
@@ -80,15 +139,78 @@ pub(crate) fn web_api_backend_startup() -> JoinHandle<()> {
                     confirm_order_endpoint_config::<ConfirmOrderUseCase<ORepository, ORepository>>,
                 )
                 .configure(get_order_by_id_endpoint_config::<GetOrderByIdUseCase<ORepository>>)
+                .configure(
+                    get_order_events_endpoint_config::<GetOrderByIdUseCase<ORepository>>,
+                )
                 .configure(get_orders_endpoint_config::<GetOrdersUseCase<ORepository>>)
+                .configure(
+                    kitchen_display_endpoint_config::<
+                        GetActiveOrdersUseCase<ORepository>,
+                        ConfirmOrderUseCase<ORepository, ORepository>,
+                        CompleteOrderUseCase,
+                    >,
+                )
+                .configure(
+                    get_customer_orders_endpoint_config::<GetCustomerOrdersUseCase<ORepository>>,
+                )
+                .configure(get_last_order_state_endpoint_config::<GetLastOrderStateUseCase>)
+                .configure(
+                    get_available_delivery_slots_endpoint_config::<
+                        GetAvailableDeliverySlotsUseCase,
+                    >,
+                )
+                .configure(register_customer_endpoint_config::<RegisterCustomerUseCase>)
+                .configure(get_customer_by_id_endpoint_config::<GetCustomerByIdUseCase>)
+                .configure(update_customer_endpoint_config::<UpdateCustomerUseCase>)
+                .configure(remove_customer_endpoint_config::<RemoveCustomerUseCase>)
+                .configure(add_customer_address_endpoint_config::<AddCustomerAddressUseCase>)
+                .configure(
+                    remove_customer_address_endpoint_config::<RemoveCustomerAddressUseCase>,
+                )
+                .configure(subscribe_webhook_endpoint_config::<SubscribeWebhookUseCase>)
+                .configure(
+                    get_webhook_subscriptions_endpoint_config::<GetWebhookSubscriptionsUseCase>,
+                )
+                .configure(
+                    get_failed_webhook_deliveries_endpoint_config::<
+                        GetFailedWebhookDeliveriesUseCase,
+                    >,
+                )
+                .configure(
+                    remove_webhook_subscription_endpoint_config::<RemoveWebhookSubscriptionUseCase>,
+                )
                 .app_data(ADD_MEAL_TO_MENU_USE_CASE.clone())
                 .app_data(GET_MEAL_BY_ID_USE_CASE.clone())
                 .app_data(GET_MENU_USE_CASE.clone())
                 .app_data(REMOVE_MEAL_FROM_MENU_USECASE.clone())
+                .app_data(SEARCH_MENU_USE_CASE.clone())
+                .app_data(SET_MEAL_PORTIONS_USE_CASE.clone())
+                .app_data(UPLOAD_MEAL_IMAGE_USE_CASE.clone())
+                .app_data(CHANGE_MEAL_AVAILABILITY_USE_CASE.clone())
+                .app_data(IMPORT_MENU_USE_CASE.clone())
+                .app_data(EXPORT_MENU_USE_CASE.clone())
                 .app_data(CANCEL_ORDER_USECASE.clone())
                 .app_data(CONFIRM_ORDER_USECASE.clone())
                 .app_data(GET_ORDER_BY_ID.clone())
                 .app_data(GET_ORDERS_USECASE.clone())
+                .app_data(GET_ACTIVE_ORDERS_USECASE.clone())
+                .app_data(COMPLETE_ORDER_USECASE.clone())
+                .app_data(GET_AVAILABLE_DELIVERY_SLOTS_USECASE.clone())
+                .app_data(GET_CUSTOMER_ORDERS_USECASE.clone())
+                .app_data(GET_LAST_ORDER_STATE_USECASE.clone())
+                .app_data(REGISTER_CUSTOMER_USE_CASE.clone())
+                .app_data(GET_CUSTOMER_BY_ID_USE_CASE.clone())
+                .app_data(UPDATE_CUSTOMER_USE_CASE.clone())
+                .app_data(REMOVE_CUSTOMER_USE_CASE.clone())
+                .app_data(ADD_CUSTOMER_ADDRESS_USE_CASE.clone())
+                .app_data(REMOVE_CUSTOMER_ADDRESS_USE_CASE.clone())
+                .app_data(SUBSCRIBE_WEBHOOK_USE_CASE.clone())
+                .app_data(GET_WEBHOOK_SUBSCRIPTIONS_USE_CASE.clone())
+                .app_data(REMOVE_WEBHOOK_SUBSCRIPTION_USE_CASE.clone())
+                .app_data(GET_FAILED_WEBHOOK_DELIVERIES_USE_CASE.clone())
+                .app_data(JWT_AUTHENTICATOR.clone())
+                .app_data(Data::from(ORDER_EVENT_FEED.clone()))
+                .wrap(from_fn(jwt_authentication))
                 .wrap(
                     Cors::default()
                         .allowed_origin(&http_host_url)
@@ -98,6 +220,7 @@ pub(crate) fn web_api_backend_startup() -> JoinHandle<()> {
                             header::ACCEPT,
                             header::LOCATION,
                             header::CONTENT_TYPE,
+                            header::HeaderName::from_static("last-event-id"),
                         ])
                         .supports_credentials()
                         .max_age(3600),
@@ -0,0 +1,39 @@
+use std::{
+    env,
+    sync::{Arc, LazyLock},
+    time::Duration,
+};
+
+use dotenvy::dotenv;
+use http_webhook_sender::http_webhook_sender::HttpWebhookSender;
+use usecase::webhook::webhook_delivery::{WebhookDelivery, WebhookRetryPolicy};
+
+use crate::configuration::persistence_configuration::FAILED_WEBHOOK_DELIVERY_REPOSITORY;
+
+/// Sends the webhooks of the order events, the ones which run out of attempts go to the dead letters
+pub(super) static WEBHOOK_DELIVERY: LazyLock<Arc<WebhookDelivery>> = LazyLock::new(|| {
+    dotenv().ok();
+    let sender = HttpWebhookSender::new(Duration::from_secs(seconds_variable(
+        "WEBHOOK_TIMEOUT_SECONDS",
+    )));
+    let retry_policy = WebhookRetryPolicy::new(
+        env::var("WEBHOOK_MAX_ATTEMPTS")
+            .unwrap()
+            .parse::<u32>()
+            .expect("Wrong webhook attempts configured"),
+        Duration::from_secs(seconds_variable("WEBHOOK_INITIAL_BACKOFF_SECONDS")),
+        Duration::from_secs(seconds_variable("WEBHOOK_MAX_BACKOFF_SECONDS")),
+    );
+    Arc::new(WebhookDelivery::new(
+        Arc::new(sender),
+        FAILED_WEBHOOK_DELIVERY_REPOSITORY.clone(),
+        retry_policy,
+    ))
+});
+
+fn seconds_variable(name: &str) -> u64 {
+    env::var(name)
+        .unwrap()
+        .parse::<u64>()
+        .unwrap_or_else(|_| panic!("Variable '{name}' must be a number of seconds"))
+}
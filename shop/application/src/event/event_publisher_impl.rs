@@ -16,16 +16,33 @@ use log::info;
 
 type ListenerVec<Event> = Vec<AM<dyn DomainEventListener<Event> + Send>>;
 
+/// Dispatches events to the in-process listeners and then hands them over to the delegates
 #[derive(new, Debug, Default, Clone)]
-pub(crate) struct EventPublisherImpl<Event: Debug> {
+pub(crate) struct EventPublisherImpl<Event: Debug + DomainEventTrait> {
     listener_map: HashMap<Discriminant<Event>, ListenerVec<Event>>,
+    #[new(default)]
+    delegates: Vec<AM<dyn DomainEventPublisher<Event>>>,
 }
 
 impl<Event> EventPublisherImpl<Event>
 where
-    Event: Debug + Clone + Hash + Eq,
+    Event: Debug + Clone + Hash + Eq + DomainEventTrait,
 {
-    fn register_listener(&mut self, listener: impl DomainEventListener<Event> + 'static) {
+    pub(crate) fn with_delegate(delegate: AM<dyn DomainEventPublisher<Event>>) -> Self {
+        Self {
+            listener_map: HashMap::new(),
+            delegates: vec![delegate],
+        }
+    }
+
+    pub(crate) fn add_delegate(&mut self, delegate: AM<dyn DomainEventPublisher<Event>>) {
+        self.delegates.push(delegate);
+    }
+
+    pub(crate) fn register_listener(
+        &mut self,
+        listener: impl DomainEventListener<Event> + 'static,
+    ) {
         let event_type = listener.event_type();
         let entry = self.listener_map.entry(event_type).or_default();
         entry.push(AM::new_am(listener));
@@ -58,6 +75,9 @@ where
                     .await;
             }
         }
+        for delegate in &self.delegates {
+            delegate.lock().await.publish(events).await;
+        }
     }
 }
 
@@ -171,7 +191,7 @@ mod test {
         name: String,
     }
 
-    impl<Event: Debug> EventPublisherImpl<Event> {
+    impl<Event: Debug + DomainEventTrait> EventPublisherImpl<Event> {
         fn get_listener(&self, event_type: Event) -> &AM<dyn DomainEventListener<Event> + Send> {
             let result = self.listener_map.get(&discriminant(&event_type)).unwrap();
             result.first().unwrap()
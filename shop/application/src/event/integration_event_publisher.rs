@@ -0,0 +1,53 @@
+use std::fmt::Debug;
+
+use async_trait::async_trait;
+use common::{
+    events::DomainEventPublisher,
+    types::base::{DomainEventTrait, EventId},
+};
+use derive_new::new;
+use domain::{menu::meal_events::MealEventEnum, order::customer_order_events::ShopOrderEventEnum};
+use serde::Serialize;
+use tracing::error;
+
+use crate::event::integration_message_publisher::{IntegrationMessagePublisher, MessageRoute};
+
+/// Event whose ID is sent as the message ID
+pub(crate) trait IdentifiedEvent {
+    fn event_id(&self) -> &EventId;
+}
+
+impl IdentifiedEvent for MealEventEnum {
+    fn event_id(&self) -> &EventId {
+        MealEventEnum::event_id(self)
+    }
+}
+
+impl IdentifiedEvent for ShopOrderEventEnum {
+    fn event_id(&self) -> &EventId {
+        ShopOrderEventEnum::event_id(self)
+    }
+}
+
+/// Hands the domain events over to the message broker, every event as a separate message
+#[derive(new, Debug)]
+pub(crate) struct IntegrationEventPublisher<P: IntegrationMessagePublisher + 'static> {
+    publisher: &'static P,
+    route: MessageRoute,
+}
+
+#[async_trait]
+impl<Event, P> DomainEventPublisher<Event> for IntegrationEventPublisher<P>
+where
+    Event: DomainEventTrait + IdentifiedEvent + Serialize + Debug + Sync,
+    P: IntegrationMessagePublisher + Debug + Send + Sync + 'static,
+{
+    async fn publish(&mut self, events: &[Event]) {
+        for event in events {
+            let message_id = event.event_id().to_string();
+            if let Err(e) = self.publisher.send(&self.route, &message_id, event).await {
+                error!(?event, route = ?self.route, "Event is not sent: {e}");
+            }
+        }
+    }
+}
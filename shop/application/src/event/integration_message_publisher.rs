@@ -1,11 +1,25 @@
+use std::error::Error;
+
 use async_trait::async_trait;
+use derive_new::new;
 use serde::Serialize;
 
+/// Exchange and routing key a type of message is published with, and the queue keeping the
+/// messages for their consumers
+#[derive(new, Debug, Clone, PartialEq, Eq, Hash)]
+pub(crate) struct MessageRoute {
+    pub(crate) exchange: String,
+    pub(crate) routing_key: String,
+    pub(crate) queue: String,
+}
+
 #[async_trait]
-#[allow(dead_code)]
 pub(crate) trait IntegrationMessagePublisher {
+    /// A message may be delivered more than once, consumers recognize it by `message_id`
     async fn send(
         &self,
+        route: &MessageRoute,
+        message_id: &str,
         message: impl Serialize + Send + Sync,
-    ) -> Result<(), Box<dyn std::error::Error>>;
+    ) -> Result<(), Box<dyn Error + Send + Sync>>;
 }
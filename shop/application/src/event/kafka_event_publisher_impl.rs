@@ -6,7 +6,11 @@ use std::{
 use async_trait::async_trait;
 use common::events::DomainEventPublisher;
 use derive_new::new;
-use domain::{menu::meal_events::MealEventEnum, order::customer_order_events::ShopOrderEventEnum};
+use domain::{
+    customer::customer_events::CustomerEventEnum, inventory::meal_stock_events::MealStockEventEnum,
+    menu::meal_events::MealEventEnum, order::customer_order_events::ShopOrderEventEnum,
+    webhook::webhook_subscription_events::WebhookSubscriptionEventEnum,
+};
 use rdkafka::{
     ClientConfig,
     producer::{BaseProducer, BaseRecord},
@@ -29,6 +33,9 @@ impl Debug for KafkaEventPublisherImpl {
 
 pub(crate) const MEAL_TOPIC_NAME: &str = "meal_topic";
 pub(super) const ORDER_TOPIC_NAME: &str = "order_topic";
+pub(super) const CUSTOMER_TOPIC_NAME: &str = "customer_topic";
+pub(super) const INVENTORY_TOPIC_NAME: &str = "inventory_topic";
+pub(super) const WEBHOOK_TOPIC_NAME: &str = "webhook_topic";
 
 #[async_trait]
 impl DomainEventPublisher<MealEventEnum> for KafkaEventPublisherImpl {
@@ -60,6 +67,51 @@ impl DomainEventPublisher<ShopOrderEventEnum> for KafkaEventPublisherImpl {
     }
 }
 
+#[async_trait]
+impl DomainEventPublisher<CustomerEventEnum> for KafkaEventPublisherImpl {
+    async fn publish(&mut self, events: &[CustomerEventEnum]) {
+        for event in events {
+            let payload = serde_json::to_string(event).unwrap();
+            let msg = BaseRecord::to(CUSTOMER_TOPIC_NAME)
+                .key(&[1, 2, 3, 4])
+                .payload(&payload);
+            self.producer
+                .send(msg)
+                .expect("Something is wrong with sending to Kafka");
+        }
+    }
+}
+
+#[async_trait]
+impl DomainEventPublisher<MealStockEventEnum> for KafkaEventPublisherImpl {
+    async fn publish(&mut self, events: &[MealStockEventEnum]) {
+        for event in events {
+            let payload = serde_json::to_string(event).unwrap();
+            let msg = BaseRecord::to(INVENTORY_TOPIC_NAME)
+                .key(&[1, 2, 3, 4])
+                .payload(&payload);
+            self.producer
+                .send(msg)
+                .expect("Something is wrong with sending to Kafka");
+        }
+    }
+}
+
+#[async_trait]
+impl DomainEventPublisher<WebhookSubscriptionEventEnum> for KafkaEventPublisherImpl {
+    async fn publish(&mut self, events: &[WebhookSubscriptionEventEnum]) {
+        for event in events {
+            let payload = serde_json::to_string(event).unwrap();
+            let msg = BaseRecord::to(WEBHOOK_TOPIC_NAME)
+                .key(&[1, 2, 3, 4])
+                .payload(&payload);
+            self.producer
+                .send(msg)
+                .expect("Something is wrong with sending to Kafka");
+        }
+    }
+}
+
 impl Default for KafkaEventPublisherImpl {
     fn default() -> Self {
         let kafka_address = env::var("KAFKA_ADDRESS")
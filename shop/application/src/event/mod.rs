@@ -1,6 +1,8 @@
 pub(super) mod event_publisher_impl;
+pub(super) mod integration_event_publisher;
 pub(super) mod integration_message_publisher;
 
 pub(super) mod kafka_event_publisher_impl;
 
 pub(super) mod rabbit_message_publisher;
+pub(super) mod rabbit_order_exporter;
@@ -1,61 +1,191 @@
-use std::error::Error;
+use std::{collections::HashSet, error::Error};
 
 use async_trait::async_trait;
 use derive_new::new;
 use lapin::{
-    BasicProperties, Connection, ConnectionProperties,
-    options::{BasicPublishOptions, QueueDeclareOptions},
+    BasicProperties, Channel, Connection, ConnectionProperties, ErrorKind, ExchangeKind,
+    options::{
+        BasicPublishOptions, ConfirmSelectOptions, ExchangeDeclareOptions, QueueBindOptions,
+        QueueDeclareOptions,
+    },
     types::FieldTable,
 };
 use serde::Serialize;
-use tracing::info;
+use tokio::sync::Mutex;
+use tracing::{info, warn};
 
-#[cfg(not(test))]
-use crate::configuration::messaging_configuration::{RABBITMQ_ADDRESS, RABBITMQ_QUEUE_NAME};
-use crate::event::integration_message_publisher::IntegrationMessagePublisher;
-#[cfg(test)]
-use crate::test_fixtures::{RABBITMQ_ADDRESS, RABBITMQ_QUEUE_NAME};
+use crate::event::integration_message_publisher::{IntegrationMessagePublisher, MessageRoute};
 
+/// Publishes the messages with publisher confirms over a connection kept between the messages.
+/// A broken connection is replaced by a new one on the next message, and a message which failed
+/// because of it is sent once more. The broker may have received it before, so consumers have to
+/// drop the duplicates by the message ID.
 #[derive(new, Debug)]
-pub(crate) struct RabbitMessagePublisher;
+pub(crate) struct RabbitMessagePublisher {
+    address: String,
+    #[new(default)]
+    connection: Mutex<Option<RabbitConnection>>,
+}
 
-#[async_trait]
-impl IntegrationMessagePublisher for RabbitMessagePublisher {
-    async fn send(&self, message: impl Serialize + Send + Sync) -> Result<(), Box<dyn Error>> {
-        let conn = Connection::connect(&RABBITMQ_ADDRESS, ConnectionProperties::default()).await?;
+#[derive(Debug)]
+struct RabbitConnection {
+    connection: Connection,
+    channel: Channel,
+    declared_routes: HashSet<MessageRoute>,
+}
+
+impl RabbitConnection {
+    async fn open(address: &str) -> lapin::Result<Self> {
+        let connection = Connection::connect(address, ConnectionProperties::default()).await?;
+        let channel = connection.create_channel().await?;
+        channel
+            .confirm_select(ConfirmSelectOptions::default())
+            .await?;
         info!("Publisher connected");
+        Ok(Self {
+            connection,
+            channel,
+            declared_routes: HashSet::new(),
+        })
+    }
 
-        let channel = conn.create_channel().await?;
-        info!(state=?conn.status().state());
+    fn is_connected(&self) -> bool {
+        self.connection.status().connected() && self.channel.status().connected()
+    }
 
-        let queue_name: &String = &RABBITMQ_QUEUE_NAME;
-        let queue = channel
+    /// The queue is bound before the first message, so the messages are kept until a consumer
+    /// reads them
+    async fn declare_route(&mut self, route: &MessageRoute) -> lapin::Result<()> {
+        if self.declared_routes.contains(route) {
+            return Ok(());
+        }
+        self.channel
             .queue_declare(
-                queue_name,
-                QueueDeclareOptions::default(),
+                &route.queue,
+                QueueDeclareOptions {
+                    durable: true,
+                    ..QueueDeclareOptions::default()
+                },
                 FieldTable::default(),
             )
             .await?;
-        info!(state=?conn.status().state());
-        info!(?queue, "Declared queue");
-
-        let sent_message = serde_json::to_string(&message)?;
+        // The default exchange always exists, can't be declared and routes by the queue name
+        if !route.exchange.is_empty() {
+            self.channel
+                .exchange_declare(
+                    &route.exchange,
+                    ExchangeKind::Topic,
+                    ExchangeDeclareOptions {
+                        durable: true,
+                        ..ExchangeDeclareOptions::default()
+                    },
+                    FieldTable::default(),
+                )
+                .await?;
+            self.channel
+                .queue_bind(
+                    &route.queue,
+                    &route.exchange,
+                    &route.routing_key,
+                    QueueBindOptions::default(),
+                    FieldTable::default(),
+                )
+                .await?;
+        }
+        info!(?route, "Declared route");
+        self.declared_routes.insert(route.clone());
+        Ok(())
+    }
 
-        let payload = sent_message.as_bytes();
-        channel
+    async fn publish(
+        &mut self,
+        route: &MessageRoute,
+        message_id: &str,
+        payload: &[u8],
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        self.declare_route(route).await?;
+        let confirmation = self
+            .channel
             .basic_publish(
-                "",
-                queue_name,
+                &route.exchange,
+                &route.routing_key,
                 BasicPublishOptions::default(),
                 payload,
-                BasicProperties::default(),
+                BasicProperties::default()
+                    .with_message_id(message_id.into())
+                    .with_content_type("application/json".into())
+                    .with_delivery_mode(2),
             )
+            .await?
             .await?;
+        if confirmation.is_nack() {
+            return Err(format!(
+                "Message to '{}' with key '{}' is rejected by the broker",
+                route.exchange, route.routing_key
+            )
+            .into());
+        }
+        Ok(())
+    }
+}
 
-        info!(?sent_message, " [x] Sent message");
+impl RabbitMessagePublisher {
+    async fn publish(
+        &self,
+        connection: &mut Option<RabbitConnection>,
+        route: &MessageRoute,
+        message_id: &str,
+        payload: &[u8],
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let current = match connection.take() {
+            Some(current) if current.is_connected() => current,
+            _ => RabbitConnection::open(&self.address).await?,
+        };
+        connection
+            .insert(current)
+            .publish(route, message_id, payload)
+            .await
+    }
+}
 
-        conn.close(0, "").await?;
+/// Only a lost connection or channel is worth a new connection, the broker would reject the
+/// message the same way again otherwise
+fn is_connection_failure(e: &(dyn Error + Send + Sync + 'static)) -> bool {
+    e.downcast_ref::<lapin::Error>().is_some_and(|e| {
+        matches!(
+            e.kind(),
+            ErrorKind::IOError(_)
+                | ErrorKind::InvalidChannel(_)
+                | ErrorKind::InvalidChannelState(..)
+                | ErrorKind::InvalidConnectionState(_)
+                | ErrorKind::MissingHeartbeatError
+        )
+    })
+}
 
+#[async_trait]
+impl IntegrationMessagePublisher for RabbitMessagePublisher {
+    async fn send(
+        &self,
+        route: &MessageRoute,
+        message_id: &str,
+        message: impl Serialize + Send + Sync,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let payload = serde_json::to_vec(&message)?;
+        let mut connection = self.connection.lock().await;
+        if let Err(e) = self
+            .publish(&mut connection, route, message_id, &payload)
+            .await
+        {
+            if !is_connection_failure(&*e) {
+                return Err(e);
+            }
+            warn!(?route, message_id, "Publishing failed, reconnecting: {e}");
+            *connection = None;
+            self.publish(&mut connection, route, message_id, &payload)
+                .await?;
+        }
+        info!(?route, message_id, " [x] Sent message");
         Ok(())
     }
 }
@@ -65,79 +195,188 @@ mod test {
     use std::str::from_utf8;
 
     use futures_lite::StreamExt;
-    use lapin::options::{BasicAckOptions, BasicConsumeOptions};
+    use lapin::{
+        Consumer,
+        options::{BasicAckOptions, BasicConsumeOptions},
+    };
     use serde::Deserialize;
 
     use super::*;
-    use crate::test_fixtures::TestRabbitMq;
+    use crate::test_fixtures::{RABBITMQ_ADDRESS, RABBITMQ_QUEUE_NAME, TestRabbitMq};
+
+    const TEST_EXCHANGE: &str = "test_exchange";
+    const TEST_ROUTING_KEY: &str = "test.message";
 
     #[tokio::test]
-    async fn message_sent_successfully() -> Result<(), Box<dyn Error>> {
+    async fn message_sent_successfully() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let _rmq = TestRabbitMq::new().await;
+        let mut receiver = MockReceiver::bind().await?;
+
+        let sent_message = SimpleDto::new("bar".to_string());
+        let publisher = RabbitMessagePublisher::new(RABBITMQ_ADDRESS.clone());
+        publisher.send(&route(), "1", sent_message.clone()).await?;
+
+        assert_eq!(
+            receiver.receive().await?,
+            (Some("1".to_string()), sent_message)
+        );
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn message_kept_until_consumer_comes() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let _rmq = TestRabbitMq::new().await;
+        let publisher = RabbitMessagePublisher::new(RABBITMQ_ADDRESS.clone());
+
+        publisher
+            .send(&route(), "1", SimpleDto::new("early".to_string()))
+            .await?;
+        let mut receiver = MockReceiver::bind().await?;
+
+        assert_eq!(receiver.receive().await?.1.foo, "early");
+        Ok(())
+    }
+
+    #[tokio::test]
+    async fn refused_message_not_retried() {
         let rmq = TestRabbitMq::new().await;
-        let _ = rmq.conn().await?;
+        let publisher = RabbitMessagePublisher::new(RABBITMQ_ADDRESS.clone());
+        let route = route();
+        // The exchange of the route exists with another type, so the broker refuses the declaration
+        let connection = rmq.conn().await.unwrap();
+        let channel = connection.create_channel().await.unwrap();
+        channel
+            .exchange_declare(
+                &route.exchange,
+                ExchangeKind::Direct,
+                ExchangeDeclareOptions::default(),
+                FieldTable::default(),
+            )
+            .await
+            .unwrap();
 
-        let sent_message = SimpleDto::new();
+        let error = publisher
+            .send(&route, "1", SimpleDto::new("bar".to_string()))
+            .await
+            .unwrap_err();
 
-        let publisher = RabbitMessagePublisher::new();
-        publisher.send(sent_message.clone()).await?;
+        assert!(!is_connection_failure(&*error));
+    }
 
-        let receiver = MockReceiver::new();
-        let received_message = receiver.receive().await?;
+    #[tokio::test]
+    async fn connection_reused_and_restored() -> Result<(), Box<dyn Error + Send + Sync>> {
+        let _rmq = TestRabbitMq::new().await;
+        let mut receiver = MockReceiver::bind().await?;
+        let publisher = RabbitMessagePublisher::new(RABBITMQ_ADDRESS.clone());
 
-        assert_eq!(sent_message, received_message);
+        publisher
+            .send(&route(), "1", SimpleDto::new("first".to_string()))
+            .await?;
+        publisher
+            .connection
+            .lock()
+            .await
+            .as_ref()
+            .unwrap()
+            .connection
+            .close(0, "")
+            .await?;
+        publisher
+            .send(&route(), "2", SimpleDto::new("second".to_string()))
+            .await?;
 
+        assert_eq!(receiver.receive().await?.1.foo, "first");
+        assert_eq!(receiver.receive().await?.1.foo, "second");
         Ok(())
     }
 
+    fn route() -> MessageRoute {
+        MessageRoute::new(
+            TEST_EXCHANGE.to_string(),
+            TEST_ROUTING_KEY.to_string(),
+            RABBITMQ_QUEUE_NAME.clone(),
+        )
+    }
+
     #[derive(new, Serialize, Deserialize, Debug, PartialEq, Clone)]
     struct SimpleDto {
-        #[new(value = "String::from(\"bar\")")]
         foo: String,
     }
 
-    #[derive(new)]
-    struct MockReceiver {}
+    struct MockReceiver {
+        #[allow(dead_code)]
+        connection: Connection,
+        consumer: Consumer,
+    }
 
     impl MockReceiver {
-        async fn receive(&self) -> Result<SimpleDto, Box<dyn Error>> {
+        async fn bind() -> Result<Self, Box<dyn Error + Send + Sync>> {
             let queue_name: &String = &RABBITMQ_QUEUE_NAME;
-            let conn =
+            let connection =
                 Connection::connect(&RABBITMQ_ADDRESS, ConnectionProperties::default()).await?;
-            let channel = conn.create_channel().await?;
+            let channel = connection.create_channel().await?;
             info!("Consumer connected");
 
+            channel
+                .exchange_declare(
+                    TEST_EXCHANGE,
+                    ExchangeKind::Topic,
+                    ExchangeDeclareOptions {
+                        durable: true,
+                        ..ExchangeDeclareOptions::default()
+                    },
+                    FieldTable::default(),
+                )
+                .await?;
             let queue = channel
                 .queue_declare(
                     queue_name,
-                    QueueDeclareOptions::default(),
+                    QueueDeclareOptions {
+                        durable: true,
+                        ..QueueDeclareOptions::default()
+                    },
                     FieldTable::default(),
                 )
                 .await?;
-            info!(state=?conn.status().state());
             info!(?queue, "Declared queue for consumer");
+            channel
+                .queue_bind(
+                    queue_name,
+                    TEST_EXCHANGE,
+                    TEST_ROUTING_KEY,
+                    QueueBindOptions::default(),
+                    FieldTable::default(),
+                )
+                .await?;
 
-            let mut consumer = channel
+            let consumer = channel
                 .basic_consume(
                     queue_name,
                     "consumer",
                     BasicConsumeOptions::default(),
                     FieldTable::default(),
                 )
-                .await
-                .expect("basic_consume");
-
-            let delivery = consumer.next().await.unwrap();
-            let mut message = SimpleDto::new();
-            if let Ok(delivery) = delivery {
-                message = serde_json::from_str(from_utf8(&delivery.data)?)?;
-                info!(" [x] Received {:?}", &message);
-                delivery
-                    .ack(BasicAckOptions::default())
-                    .await
-                    .expect("basic_ack");
-            }
-            conn.close(0, "").await?;
-            Ok(message)
+                .await?;
+            Ok(Self {
+                connection,
+                consumer,
+            })
+        }
+
+        /// The message ID and the message
+        async fn receive(
+            &mut self,
+        ) -> Result<(Option<String>, SimpleDto), Box<dyn Error + Send + Sync>> {
+            let delivery = self.consumer.next().await.unwrap()?;
+            let message: SimpleDto = serde_json::from_str(from_utf8(&delivery.data)?)?;
+            info!(" [x] Received {:?}", &message);
+            let message_id = delivery
+                .properties
+                .message_id()
+                .as_ref()
+                .map(|id| id.to_string());
+            delivery.ack(BasicAckOptions::default()).await?;
+            Ok((message_id, message))
         }
     }
 }
@@ -0,0 +1,43 @@
+use std::fmt::Debug;
+
+use async_trait::async_trait;
+use derive_new::new;
+use domain::{
+    cart::value_objects::customer_id::CustomerId, menu::value_objects::price::Price,
+    order::value_objects::shop_order_id::ShopOrderId,
+};
+use usecase::order::{
+    dto::exported_order::ExportedOrder,
+    providers::order_exporter::{OrderExportError, OrderExporter},
+};
+
+use crate::event::integration_message_publisher::{IntegrationMessagePublisher, MessageRoute};
+
+/// Sends every exported order as an integration message
+#[derive(new, Debug)]
+pub(crate) struct RabbitOrderExporter<P: IntegrationMessagePublisher + 'static> {
+    publisher: &'static P,
+    route: MessageRoute,
+}
+
+#[async_trait]
+impl<P: IntegrationMessagePublisher + Debug + Send + Sync + 'static> OrderExporter
+    for RabbitOrderExporter<P>
+{
+    async fn export_order(
+        &mut self,
+        id: ShopOrderId,
+        customer_id: CustomerId,
+        total_price: Price,
+    ) -> Result<(), OrderExportError> {
+        // An order is exported once, so its ID identifies the message
+        self.publisher
+            .send(
+                &self.route,
+                &id.to_i64().to_string(),
+                ExportedOrder::new(id, customer_id, total_price),
+            )
+            .await
+            .map_err(|e| OrderExportError::ExportFailed(e.to_string()))
+    }
+}
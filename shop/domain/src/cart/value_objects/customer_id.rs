@@ -1,3 +1,5 @@
+use std::fmt::Debug;
+
 use common::types::base::ValueObject;
 use derive_more::Display;
 use derive_new::new;
@@ -10,6 +12,16 @@ use uuid::Uuid;
 #[non_exhaustive]
 pub struct CustomerId(#[new(value = "Uuid::new_v4()")] Uuid);
 
+impl CustomerId {
+    pub fn to_uuid(&self) -> Uuid {
+        self.0
+    }
+}
+
+pub trait CustomerIdGenerator: Debug + Send {
+    fn generate(&mut self) -> CustomerId;
+}
+
 impl From<Uuid> for CustomerId {
     fn from(value: Uuid) -> Self {
         Self(value)
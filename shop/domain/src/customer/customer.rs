@@ -0,0 +1,387 @@
+use common::types::{
+    base::{AM, DomainEntity, DomainEntityTrait, Version},
+    common::Address,
+    errors::BusinessError,
+};
+use derive_getters::Getters;
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    cart::value_objects::customer_id::{CustomerId, CustomerIdGenerator},
+    customer::{
+        customer_already_exists::CustomerAlreadyExists,
+        customer_events::{
+            CustomerAddressAddedDomainEvent, CustomerAddressRemovedDomainEvent, CustomerEventEnum,
+            CustomerRegisteredDomainEvent, CustomerRemovedDomainEvent, CustomerUpdatedDomainEvent,
+        },
+        value_objects::{
+            customer_name::CustomerName,
+            customer_preferences::CustomerPreferences,
+            phone_number::PhoneNumber,
+            saved_address::{SavedAddress, SavedAddressId},
+        },
+    },
+};
+
+#[derive(new, Debug, Clone, PartialEq, Default, Serialize, Deserialize, Getters)]
+pub struct Customer {
+    #[getter(skip)]
+    entity_params: DomainEntity<CustomerId, CustomerEventEnum>,
+    name: CustomerName,
+    phone: PhoneNumber,
+    #[new(default)]
+    addresses: Vec<SavedAddress>,
+    #[new(default)]
+    preferences: CustomerPreferences,
+    #[new(value = "false")]
+    removed: bool,
+}
+
+impl Customer {
+    pub fn with_all_args(
+        entity_params: DomainEntity<CustomerId, CustomerEventEnum>,
+        name: CustomerName,
+        phone: PhoneNumber,
+        addresses: Vec<SavedAddress>,
+        preferences: CustomerPreferences,
+        removed: bool,
+    ) -> Self {
+        Self {
+            entity_params,
+            name,
+            phone,
+            addresses,
+            preferences,
+            removed,
+        }
+    }
+
+    pub async fn register(
+        id_generator: AM<dyn CustomerIdGenerator>,
+        customer_exists: AM<dyn CustomerAlreadyExists>,
+        name: CustomerName,
+        phone: PhoneNumber,
+    ) -> Result<Customer, CustomerError> {
+        if customer_exists.lock().await.invoke(&phone).await {
+            Err(CustomerError::AlreadyExistsWithSamePhoneError)
+        } else {
+            let id = id_generator.lock().await.generate();
+            let mut customer =
+                Customer::new(DomainEntity::new(id, Version::default()), name, phone);
+            customer.add_event(CustomerRegisteredDomainEvent::new(id).into());
+            Ok(customer)
+        }
+    }
+
+    /// Replace contact details and preferences, the phone must stay unique among customers
+    pub async fn update(
+        &mut self,
+        customer_exists: AM<dyn CustomerAlreadyExists>,
+        name: CustomerName,
+        phone: PhoneNumber,
+        preferences: CustomerPreferences,
+    ) -> Result<(), CustomerError> {
+        if let Some(address_id) = &preferences.default_address_id
+            && self.saved_address(address_id).is_none()
+        {
+            return Err(CustomerError::SavedAddressNotFound);
+        }
+        if self.phone != phone && customer_exists.lock().await.invoke(&phone).await {
+            return Err(CustomerError::AlreadyExistsWithSamePhoneError);
+        }
+        if self.name != name || self.phone != phone || self.preferences != preferences {
+            self.name = name;
+            self.phone = phone;
+            self.preferences = preferences;
+            self.add_event(CustomerUpdatedDomainEvent::new(*self.id()).into());
+        }
+        Ok(())
+    }
+
+    pub fn add_address(&mut self, address: Address) -> SavedAddressId {
+        let address_id = SavedAddressId::new();
+        self.addresses.push(SavedAddress::new(address_id, address));
+        self.add_event(CustomerAddressAddedDomainEvent::new(*self.id(), address_id).into());
+        address_id
+    }
+
+    /// Removing the default address also clears it from preferences
+    pub fn remove_address(&mut self, address_id: &SavedAddressId) -> Result<(), CustomerError> {
+        let index = self
+            .addresses
+            .iter()
+            .position(|it| &it.id == address_id)
+            .ok_or(CustomerError::SavedAddressNotFound)?;
+        self.addresses.remove(index);
+        if self.preferences.default_address_id.as_ref() == Some(address_id) {
+            self.preferences.default_address_id = None;
+        }
+        self.add_event(CustomerAddressRemovedDomainEvent::new(*self.id(), *address_id).into());
+        Ok(())
+    }
+
+    pub fn saved_address(&self, address_id: &SavedAddressId) -> Option<&Address> {
+        self.addresses
+            .iter()
+            .find(|it| &it.id == address_id)
+            .map(|it| &it.address)
+    }
+
+    pub fn visible(&self) -> bool {
+        !self.removed
+    }
+
+    pub fn remove(&mut self) {
+        if !self.removed {
+            self.removed = true;
+            self.add_event(CustomerRemovedDomainEvent::new(*self.id()).into())
+        }
+    }
+
+    pub fn id(&self) -> &CustomerId {
+        self.entity_params.id()
+    }
+
+    pub fn version(&self) -> &Version {
+        self.entity_params.version()
+    }
+
+    pub(self) fn add_event(&mut self, event: CustomerEventEnum) {
+        self.entity_params.add_event(event)
+    }
+
+    pub fn pop_events(&mut self) -> Vec<CustomerEventEnum> {
+        self.entity_params.pop_events()
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum CustomerError {
+    AlreadyExistsWithSamePhoneError,
+    SavedAddressNotFound,
+}
+
+impl BusinessError for CustomerError {}
+
+#[cfg(test)]
+mod tests {
+    use common::types::base::AMTrait;
+
+    use super::*;
+    use crate::test_fixtures::{
+        TestCustomerAlreadyExists, TestCustomerIdGenerator, rnd_address, rnd_customer,
+        rnd_customer_name, rnd_phone_number, rnd_removed_customer,
+    };
+
+    #[tokio::test]
+    async fn register_success() {
+        let id_generator = AM::new_am(TestCustomerIdGenerator::new());
+        let name = rnd_customer_name();
+        let phone = rnd_phone_number();
+
+        let result = Customer::register(
+            id_generator.clone(),
+            AM::new_am(TestCustomerAlreadyExists::new()),
+            name.clone(),
+            phone.clone(),
+        )
+        .await;
+
+        let mut customer = result.unwrap();
+        let id = id_generator.lock().await.customer_id;
+        assert_eq!(customer.id(), &id);
+        assert_eq!(customer.name(), &name);
+        assert_eq!(customer.phone(), &phone);
+        assert!(customer.addresses().is_empty());
+        assert_eq!(customer.preferences(), &CustomerPreferences::default());
+        assert!(customer.visible());
+        let events: Vec<CustomerRegisteredDomainEvent> = customer
+            .pop_events()
+            .into_iter()
+            .map(|it| it.try_into().unwrap())
+            .collect();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events.first().unwrap().customer_id, id);
+    }
+
+    #[tokio::test]
+    async fn register_already_exists_with_same_phone() {
+        let result = Customer::register(
+            AM::new_am(TestCustomerIdGenerator::new()),
+            AM::new_am(TestCustomerAlreadyExists { value: true }),
+            rnd_customer_name(),
+            rnd_phone_number(),
+        )
+        .await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            CustomerError::AlreadyExistsWithSamePhoneError
+        );
+    }
+
+    #[tokio::test]
+    async fn update_success() {
+        let mut customer = rnd_customer();
+        let address_id = customer.add_address(rnd_address());
+        customer.pop_events();
+        let name = rnd_customer_name();
+        let phone = rnd_phone_number();
+        let preferences = CustomerPreferences::new(Some(address_id), true);
+
+        let result = customer
+            .update(
+                AM::new_am(TestCustomerAlreadyExists::new()),
+                name.clone(),
+                phone.clone(),
+                preferences,
+            )
+            .await;
+
+        assert!(result.is_ok());
+        assert_eq!(customer.name(), &name);
+        assert_eq!(customer.phone(), &phone);
+        assert_eq!(customer.preferences(), &preferences);
+        let events: Vec<CustomerUpdatedDomainEvent> = customer
+            .pop_events()
+            .into_iter()
+            .map(|it| it.try_into().unwrap())
+            .collect();
+        assert_eq!(events.len(), 1);
+        assert_eq!(&events.first().unwrap().customer_id, customer.id());
+    }
+
+    #[tokio::test]
+    async fn update_without_changes() {
+        let mut customer = rnd_customer();
+
+        let result = customer
+            .update(
+                AM::new_am(TestCustomerAlreadyExists { value: true }),
+                customer.name().clone(),
+                customer.phone().clone(),
+                *customer.preferences(),
+            )
+            .await;
+
+        assert!(result.is_ok());
+        assert!(customer.pop_events().is_empty());
+    }
+
+    #[tokio::test]
+    async fn update_phone_belongs_to_another_customer() {
+        let mut customer = rnd_customer();
+        let phone = customer.phone().clone();
+
+        let result = customer
+            .update(
+                AM::new_am(TestCustomerAlreadyExists { value: true }),
+                rnd_customer_name(),
+                rnd_phone_number(),
+                CustomerPreferences::default(),
+            )
+            .await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            CustomerError::AlreadyExistsWithSamePhoneError
+        );
+        assert_eq!(customer.phone(), &phone);
+        assert!(customer.pop_events().is_empty());
+    }
+
+    #[tokio::test]
+    async fn update_default_address_not_found() {
+        let mut customer = rnd_customer();
+
+        let result = customer
+            .update(
+                AM::new_am(TestCustomerAlreadyExists::new()),
+                rnd_customer_name(),
+                rnd_phone_number(),
+                CustomerPreferences::new(Some(SavedAddressId::new()), false),
+            )
+            .await;
+
+        assert_eq!(result.unwrap_err(), CustomerError::SavedAddressNotFound);
+        assert!(customer.pop_events().is_empty());
+    }
+
+    #[test]
+    fn add_address_success() {
+        let mut customer = rnd_customer();
+        let address = rnd_address();
+
+        let address_id = customer.add_address(address.clone());
+
+        assert_eq!(customer.saved_address(&address_id), Some(&address));
+        let events: Vec<CustomerAddressAddedDomainEvent> = customer
+            .pop_events()
+            .into_iter()
+            .map(|it| it.try_into().unwrap())
+            .collect();
+        assert_eq!(events.len(), 1);
+        assert_eq!(&events.first().unwrap().customer_id, customer.id());
+        assert_eq!(events.first().unwrap().address_id, address_id);
+    }
+
+    #[test]
+    fn remove_address_success() {
+        let mut customer = rnd_customer();
+        let address_id = customer.add_address(rnd_address());
+        customer.preferences.default_address_id = Some(address_id);
+        customer.pop_events();
+
+        let result = customer.remove_address(&address_id);
+
+        assert!(result.is_ok());
+        assert_eq!(customer.saved_address(&address_id), None);
+        assert_eq!(customer.preferences().default_address_id, None);
+        let events: Vec<CustomerAddressRemovedDomainEvent> = customer
+            .pop_events()
+            .into_iter()
+            .map(|it| it.try_into().unwrap())
+            .collect();
+        assert_eq!(events.len(), 1);
+        assert_eq!(&events.first().unwrap().customer_id, customer.id());
+        assert_eq!(events.first().unwrap().address_id, address_id);
+    }
+
+    #[test]
+    fn remove_address_not_found() {
+        let mut customer = rnd_customer();
+
+        let result = customer.remove_address(&SavedAddressId::new());
+
+        assert_eq!(result.unwrap_err(), CustomerError::SavedAddressNotFound);
+        assert!(customer.pop_events().is_empty());
+    }
+
+    #[test]
+    fn remove_success() {
+        let mut customer = rnd_customer();
+
+        customer.remove();
+
+        assert!(customer.removed());
+        assert!(!customer.visible());
+        let events: Vec<CustomerRemovedDomainEvent> = customer
+            .pop_events()
+            .into_iter()
+            .map(|it| it.try_into().unwrap())
+            .collect();
+        assert_eq!(events.len(), 1);
+        assert_eq!(&events.first().unwrap().customer_id, customer.id());
+    }
+
+    #[test]
+    fn remove_already_removed() {
+        let mut customer = rnd_removed_customer();
+
+        customer.remove();
+
+        assert!(customer.removed());
+        assert!(customer.pop_events().is_empty());
+    }
+}
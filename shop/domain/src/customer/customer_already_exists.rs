@@ -0,0 +1,8 @@
+use std::fmt::Debug;
+
+use crate::customer::value_objects::phone_number::PhoneNumber;
+
+#[async_trait::async_trait]
+pub trait CustomerAlreadyExists: Debug + Send {
+    async fn invoke(&mut self, phone: &PhoneNumber) -> bool;
+}
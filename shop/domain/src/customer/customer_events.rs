@@ -0,0 +1,57 @@
+use common::types::base::{DomainEvent, DomainEventTrait};
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+use smart_default::SmartDefault;
+
+use crate::{
+    cart::value_objects::customer_id::CustomerId,
+    customer::value_objects::saved_address::SavedAddressId,
+};
+
+#[derive(new, Debug, Clone, PartialEq, Serialize, Deserialize, Hash, Eq, Default)]
+pub struct CustomerRegisteredDomainEvent {
+    #[new(value = "DomainEvent::default()")]
+    domain_event_params: DomainEvent,
+    pub customer_id: CustomerId,
+}
+
+#[derive(new, Debug, Clone, PartialEq, Serialize, Deserialize, Hash, Eq, Default)]
+pub struct CustomerUpdatedDomainEvent {
+    #[new(value = "DomainEvent::default()")]
+    domain_event_params: DomainEvent,
+    pub customer_id: CustomerId,
+}
+
+#[derive(new, Debug, Clone, PartialEq, Serialize, Deserialize, Hash, Eq, Default)]
+pub struct CustomerAddressAddedDomainEvent {
+    #[new(value = "DomainEvent::default()")]
+    domain_event_params: DomainEvent,
+    pub customer_id: CustomerId,
+    pub address_id: SavedAddressId,
+}
+
+#[derive(new, Debug, Clone, PartialEq, Serialize, Deserialize, Hash, Eq, Default)]
+pub struct CustomerAddressRemovedDomainEvent {
+    #[new(value = "DomainEvent::default()")]
+    domain_event_params: DomainEvent,
+    pub customer_id: CustomerId,
+    pub address_id: SavedAddressId,
+}
+
+#[derive(new, Debug, Clone, PartialEq, Serialize, Deserialize, Hash, Eq, Default)]
+pub struct CustomerRemovedDomainEvent {
+    #[new(value = "DomainEvent::default()")]
+    domain_event_params: DomainEvent,
+    pub customer_id: CustomerId,
+}
+
+#[enum_delegate::implement(DomainEventTrait)]
+#[derive(PartialEq, Debug, Clone, SmartDefault, Serialize, Deserialize, Hash, Eq)]
+pub enum CustomerEventEnum {
+    #[default]
+    CustomerRegisteredDomainEvent(CustomerRegisteredDomainEvent),
+    CustomerUpdatedDomainEvent(CustomerUpdatedDomainEvent),
+    CustomerAddressAddedDomainEvent(CustomerAddressAddedDomainEvent),
+    CustomerAddressRemovedDomainEvent(CustomerAddressRemovedDomainEvent),
+    CustomerRemovedDomainEvent(CustomerRemovedDomainEvent),
+}
@@ -0,0 +1,76 @@
+use common::types::base::{DomainEntity, Version};
+
+use crate::{
+    cart::value_objects::customer_id::CustomerId,
+    customer::{
+        customer::Customer,
+        value_objects::{
+            customer_name::CustomerName, customer_preferences::CustomerPreferences,
+            phone_number::PhoneNumber, saved_address::SavedAddress,
+        },
+    },
+};
+
+pub struct CustomerRestorer {}
+
+impl CustomerRestorer {
+    pub fn restore_customer(
+        id: CustomerId,
+        name: CustomerName,
+        phone: PhoneNumber,
+        addresses: Vec<SavedAddress>,
+        preferences: CustomerPreferences,
+        removed: bool,
+        version: Version,
+    ) -> Customer {
+        Customer::with_all_args(
+            DomainEntity::new(id, version),
+            name,
+            phone,
+            addresses,
+            preferences,
+            removed,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        customer::value_objects::saved_address::SavedAddressId,
+        test_fixtures::{
+            rnd_address, rnd_customer_id, rnd_customer_name, rnd_phone_number, version,
+        },
+    };
+
+    #[test]
+    fn restore_customer_success() {
+        let id = rnd_customer_id();
+        let name = rnd_customer_name();
+        let phone = rnd_phone_number();
+        let address_id = SavedAddressId::new();
+        let addresses = vec![SavedAddress::new(address_id, rnd_address())];
+        let preferences = CustomerPreferences::new(Some(address_id), true);
+        let version = version();
+
+        let mut customer = CustomerRestorer::restore_customer(
+            id,
+            name.clone(),
+            phone.clone(),
+            addresses.clone(),
+            preferences,
+            true,
+            version,
+        );
+
+        assert_eq!(customer.id(), &id);
+        assert_eq!(customer.name(), &name);
+        assert_eq!(customer.phone(), &phone);
+        assert_eq!(customer.addresses(), &addresses);
+        assert_eq!(customer.preferences(), &preferences);
+        assert!(customer.removed());
+        assert_eq!(customer.version(), &version);
+        assert!(customer.pop_events().is_empty());
+    }
+}
@@ -0,0 +1,6 @@
+#[allow(clippy::module_inception)]
+pub mod customer;
+pub mod customer_already_exists;
+pub mod customer_events;
+pub mod customer_restorer;
+pub mod value_objects;
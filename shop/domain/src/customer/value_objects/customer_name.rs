@@ -0,0 +1,64 @@
+use common::types::{base::ValueObject, errors::BusinessError};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, Default, Display)]
+#[non_exhaustive]
+pub struct CustomerName(String);
+
+impl CustomerName {
+    pub const MAX_LENGTH: usize = 100;
+}
+
+impl TryFrom<&str> for CustomerName {
+    type Error = CreateCustomerNameError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value.trim() {
+            "" => Err(Self::Error::EmptyCustomerNameError),
+            x if x.chars().count() > Self::MAX_LENGTH => Err(Self::Error::TooLongCustomerNameError),
+            x => Ok(Self(x.to_string())),
+        }
+    }
+}
+
+impl ValueObject for CustomerName {}
+
+#[derive(Debug, PartialEq)]
+pub enum CreateCustomerNameError {
+    EmptyCustomerNameError,
+    TooLongCustomerNameError,
+}
+
+impl BusinessError for CreateCustomerNameError {}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn create_name_success() {
+        let result = CustomerName::try_from("  John Smith ");
+
+        assert_eq!(result.unwrap().to_string(), "John Smith");
+    }
+
+    #[rstest]
+    fn create_name_empty_string(#[values("", " ", "  \t")] input: &str) {
+        let result = CustomerName::try_from(input);
+
+        assert_eq!(result, Err(CreateCustomerNameError::EmptyCustomerNameError));
+    }
+
+    #[test]
+    fn create_name_too_long() {
+        let result = CustomerName::try_from("a".repeat(CustomerName::MAX_LENGTH + 1).as_str());
+
+        assert_eq!(
+            result,
+            Err(CreateCustomerNameError::TooLongCustomerNameError)
+        );
+    }
+}
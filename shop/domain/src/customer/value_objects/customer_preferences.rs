@@ -0,0 +1,15 @@
+use common::types::base::ValueObject;
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::customer::value_objects::saved_address::SavedAddressId;
+
+#[derive(new, Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, Default)]
+pub struct CustomerPreferences {
+    /// Saved address suggested first at checkout
+    pub default_address_id: Option<SavedAddressId>,
+    /// Customer agreed to receive promotional messages
+    pub receive_promotions: bool,
+}
+
+impl ValueObject for CustomerPreferences {}
@@ -0,0 +1,4 @@
+pub mod customer_name;
+pub mod customer_preferences;
+pub mod phone_number;
+pub mod saved_address;
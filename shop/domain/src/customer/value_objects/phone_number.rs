@@ -0,0 +1,72 @@
+use common::types::{base::ValueObject, errors::BusinessError};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+/// Phone number normalized to `+` followed by 7 to 15 digits
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, Default, Display)]
+#[non_exhaustive]
+pub struct PhoneNumber(String);
+
+impl TryFrom<&str> for PhoneNumber {
+    type Error = CreatePhoneNumberError;
+
+    /// Spaces, dashes and parentheses are dropped, the leading `+` is optional
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let value = value.trim();
+        let digits: String = value
+            .strip_prefix('+')
+            .unwrap_or(value)
+            .chars()
+            .filter(|c| !matches!(c, ' ' | '-' | '(' | ')'))
+            .collect();
+        if (7..=15).contains(&digits.len()) && digits.chars().all(|c| c.is_ascii_digit()) {
+            Ok(Self(format!("+{digits}")))
+        } else {
+            Err(Self::Error::InvalidPhoneNumberError)
+        }
+    }
+}
+
+impl ValueObject for PhoneNumber {}
+
+#[derive(Debug, PartialEq)]
+pub enum CreatePhoneNumberError {
+    InvalidPhoneNumberError,
+}
+
+impl BusinessError for CreatePhoneNumberError {}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case("+49 30 1234567", "+49301234567")]
+    #[case("8 (999) 123-45-67", "+89991234567")]
+    #[case(" 1234567 ", "+1234567")]
+    fn create_phone_number_success(#[case] input: &str, #[case] expected: &str) {
+        let result = PhoneNumber::try_from(input);
+
+        assert_eq!(result.unwrap().to_string(), expected);
+    }
+
+    #[rstest]
+    fn create_phone_number_invalid(
+        #[values(
+            "",
+            "  ",
+            "+",
+            "123456",
+            "1234567890123456",
+            "+49 30 12a4567",
+            "++4930123456"
+        )]
+        input: &str,
+    ) {
+        let result = PhoneNumber::try_from(input);
+
+        assert_eq!(result, Err(CreatePhoneNumberError::InvalidPhoneNumberError));
+    }
+}
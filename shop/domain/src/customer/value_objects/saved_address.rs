@@ -0,0 +1,49 @@
+use common::types::{base::ValueObject, common::Address};
+use derive_more::Display;
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(
+    new, Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, Default, Display,
+)]
+#[non_exhaustive]
+pub struct SavedAddressId(#[new(value = "Uuid::new_v4()")] Uuid);
+
+impl SavedAddressId {
+    pub fn to_uuid(&self) -> Uuid {
+        self.0
+    }
+}
+
+impl From<Uuid> for SavedAddressId {
+    fn from(value: Uuid) -> Self {
+        Self(value)
+    }
+}
+
+impl TryFrom<&str> for SavedAddressId {
+    type Error = SavedAddressIdError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Uuid::parse_str(value)
+            .map(Self)
+            .map_err(|_| Self::Error::IdGenerationError)
+    }
+}
+
+impl ValueObject for SavedAddressId {}
+
+#[derive(Debug, PartialEq)]
+pub enum SavedAddressIdError {
+    IdGenerationError,
+}
+
+/// Address the customer stored for later checkouts
+#[derive(new, Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
+pub struct SavedAddress {
+    pub id: SavedAddressId,
+    pub address: Address,
+}
+
+impl ValueObject for SavedAddress {}
@@ -0,0 +1,202 @@
+use common::types::{
+    base::{DomainEntity, DomainEntityTrait, Version},
+    common::Count,
+    errors::BusinessError,
+};
+use derive_getters::Getters;
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    inventory::meal_stock_events::{
+        MealPortionsReleasedDomainEvent, MealPortionsReservedDomainEvent, MealSoldOutDomainEvent,
+        MealStockCreatedDomainEvent, MealStockEventEnum, MealStockReplenishedDomainEvent,
+    },
+    menu::value_objects::meal_id::MealId,
+};
+
+/// Portions of a meal which can still be ordered.
+/// A meal without a stock is not limited in portions
+#[derive(new, Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
+pub struct MealStock {
+    #[getter(skip)]
+    pub(crate) entity_params: DomainEntity<MealId, MealStockEventEnum>,
+    pub(crate) available: Count,
+}
+
+impl MealStock {
+    pub fn create(meal_id: MealId, portions: Count) -> Self {
+        let mut stock = MealStock::new(DomainEntity::new(meal_id, Version::default()), portions);
+        stock
+            .entity_params
+            .add_event(MealStockCreatedDomainEvent::new(meal_id, portions).into());
+        stock
+    }
+
+    /// Sets the number of available portions, e.g. after the kitchen has cooked a new batch
+    pub fn replenish(&mut self, portions: Count) {
+        if self.available != portions {
+            self.available = portions;
+            let meal_id = *self.meal_id();
+            self.entity_params
+                .add_event(MealStockReplenishedDomainEvent::new(meal_id, portions).into());
+        }
+    }
+
+    pub fn has_portions(&self, count: &Count) -> bool {
+        self.available.to_i32() >= count.to_i32()
+    }
+
+    pub fn is_sold_out(&self) -> bool {
+        self.available.is_min()
+    }
+
+    pub fn reserve(&mut self, count: Count) -> Result<(), MealStockError> {
+        if !self.has_portions(&count) {
+            return Err(MealStockError::NotEnoughPortions);
+        }
+        self.available = Count::try_from(self.available.to_i32() - count.to_i32()).unwrap();
+        let meal_id = *self.meal_id();
+        self.entity_params
+            .add_event(MealPortionsReservedDomainEvent::new(meal_id, count).into());
+        if self.is_sold_out() {
+            self.entity_params
+                .add_event(MealSoldOutDomainEvent::new(meal_id).into());
+        }
+        Ok(())
+    }
+
+    /// Returns portions of a cancelled order back to the stock
+    pub fn release(&mut self, count: Count) {
+        self.available =
+            Count::try_from(self.available.to_i32().saturating_add(count.to_i32())).unwrap();
+        let meal_id = *self.meal_id();
+        self.entity_params
+            .add_event(MealPortionsReleasedDomainEvent::new(meal_id, count).into());
+    }
+
+    pub fn meal_id(&self) -> &MealId {
+        self.entity_params.id()
+    }
+
+    pub fn version(&self) -> &Version {
+        self.entity_params.version()
+    }
+
+    pub fn pop_events(&mut self) -> Vec<MealStockEventEnum> {
+        self.entity_params.pop_events()
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum MealStockError {
+    NotEnoughPortions,
+}
+
+impl BusinessError for MealStockError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{rnd_meal_id, rnd_meal_stock};
+
+    fn count(value: i32) -> Count {
+        Count::try_from(value).unwrap()
+    }
+
+    #[test]
+    fn create_stock() {
+        let meal_id = rnd_meal_id();
+        let mut stock = MealStock::create(meal_id, count(5));
+
+        assert_eq!(stock.meal_id(), &meal_id);
+        assert_eq!(stock.available(), &count(5));
+        assert_eq!(stock.version(), &Version::default().next());
+        let events = stock.pop_events();
+        assert_eq!(events.len(), 1);
+        assert!(matches!(
+            &events[0],
+            MealStockEventEnum::MealStockCreatedDomainEvent(e)
+                if e.meal_id == meal_id && e.portions == count(5)
+        ));
+    }
+
+    #[test]
+    fn reserve_portions() {
+        let mut stock = rnd_meal_stock(count(5));
+
+        stock.reserve(count(2)).unwrap();
+
+        assert_eq!(stock.available(), &count(3));
+        let events = stock.pop_events();
+        assert_eq!(events.len(), 1);
+        assert!(matches!(
+            &events[0],
+            MealStockEventEnum::MealPortionsReservedDomainEvent(e) if e.count == count(2)
+        ));
+    }
+
+    #[test]
+    fn reserve_last_portions_emits_sold_out() {
+        let mut stock = rnd_meal_stock(count(2));
+
+        stock.reserve(count(2)).unwrap();
+
+        assert!(stock.is_sold_out());
+        let events = stock.pop_events();
+        assert_eq!(events.len(), 2);
+        assert!(matches!(
+            &events[1],
+            MealStockEventEnum::MealSoldOutDomainEvent(e) if &e.meal_id == stock.meal_id()
+        ));
+    }
+
+    #[test]
+    fn reserve_more_than_available() {
+        let mut stock = rnd_meal_stock(count(1));
+        let version = *stock.version();
+
+        let result = stock.reserve(count(2));
+
+        assert_eq!(result, Err(MealStockError::NotEnoughPortions));
+        assert_eq!(stock.available(), &count(1));
+        assert_eq!(stock.version(), &version);
+        assert!(stock.pop_events().is_empty());
+    }
+
+    #[test]
+    fn release_portions() {
+        let mut stock = rnd_meal_stock(count(0));
+
+        stock.release(count(3));
+
+        assert_eq!(stock.available(), &count(3));
+        assert!(!stock.is_sold_out());
+        let events = stock.pop_events();
+        assert!(matches!(
+            &events[0],
+            MealStockEventEnum::MealPortionsReleasedDomainEvent(e) if e.count == count(3)
+        ));
+    }
+
+    #[test]
+    fn replenish_stock() {
+        let mut stock = rnd_meal_stock(count(0));
+
+        stock.replenish(count(10));
+
+        assert_eq!(stock.available(), &count(10));
+        assert_eq!(stock.pop_events().len(), 1);
+    }
+
+    #[test]
+    fn replenish_with_same_portions_changes_nothing() {
+        let mut stock = rnd_meal_stock(count(10));
+        let version = *stock.version();
+
+        stock.replenish(count(10));
+
+        assert_eq!(stock.version(), &version);
+        assert!(stock.pop_events().is_empty());
+    }
+}
@@ -0,0 +1,90 @@
+use common::types::{
+    base::{DomainEventTrait, EventId},
+    common::Count,
+};
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+use smart_default::SmartDefault;
+use time::OffsetDateTime;
+
+use crate::menu::value_objects::meal_id::MealId;
+
+#[derive(new, Debug, Clone, PartialEq, Serialize, Deserialize, Hash, Eq, SmartDefault)]
+pub struct MealStockCreatedDomainEvent {
+    #[new(value = "EventId::new()")]
+    #[default(Default::default())]
+    pub id: EventId,
+    #[default(Default::default())]
+    pub meal_id: MealId,
+    #[default(Count::one())]
+    pub portions: Count,
+    #[new(value = "OffsetDateTime::now_utc()")]
+    #[default(_code = "OffsetDateTime::now_utc()")]
+    pub created: OffsetDateTime,
+}
+
+#[derive(new, Debug, Clone, PartialEq, Serialize, Deserialize, Hash, Eq, SmartDefault)]
+pub struct MealStockReplenishedDomainEvent {
+    #[new(value = "EventId::new()")]
+    #[default(Default::default())]
+    pub id: EventId,
+    #[default(Default::default())]
+    pub meal_id: MealId,
+    #[default(Count::one())]
+    pub portions: Count,
+    #[new(value = "OffsetDateTime::now_utc()")]
+    #[default(_code = "OffsetDateTime::now_utc()")]
+    pub created: OffsetDateTime,
+}
+
+#[derive(new, Debug, Clone, PartialEq, Serialize, Deserialize, Hash, Eq, SmartDefault)]
+pub struct MealPortionsReservedDomainEvent {
+    #[new(value = "EventId::new()")]
+    #[default(Default::default())]
+    pub id: EventId,
+    #[default(Default::default())]
+    pub meal_id: MealId,
+    #[default(Count::one())]
+    pub count: Count,
+    #[new(value = "OffsetDateTime::now_utc()")]
+    #[default(_code = "OffsetDateTime::now_utc()")]
+    pub created: OffsetDateTime,
+}
+
+#[derive(new, Debug, Clone, PartialEq, Serialize, Deserialize, Hash, Eq, SmartDefault)]
+pub struct MealPortionsReleasedDomainEvent {
+    #[new(value = "EventId::new()")]
+    #[default(Default::default())]
+    pub id: EventId,
+    #[default(Default::default())]
+    pub meal_id: MealId,
+    #[default(Count::one())]
+    pub count: Count,
+    #[new(value = "OffsetDateTime::now_utc()")]
+    #[default(_code = "OffsetDateTime::now_utc()")]
+    pub created: OffsetDateTime,
+}
+
+/// The last available portion of the meal has been reserved
+#[derive(new, Debug, Clone, PartialEq, Serialize, Deserialize, Hash, Eq, SmartDefault)]
+pub struct MealSoldOutDomainEvent {
+    #[new(value = "EventId::new()")]
+    #[default(Default::default())]
+    pub id: EventId,
+    #[default(Default::default())]
+    pub meal_id: MealId,
+    #[new(value = "OffsetDateTime::now_utc()")]
+    #[default(_code = "OffsetDateTime::now_utc()")]
+    pub created: OffsetDateTime,
+}
+
+#[enum_delegate::implement(DomainEventTrait)]
+#[derive(PartialEq, Debug, Clone, SmartDefault, Serialize, Deserialize, Hash, Eq)]
+pub enum MealStockEventEnum {
+    #[default]
+    MealStockCreatedDomainEvent(MealStockCreatedDomainEvent),
+    MealStockReplenishedDomainEvent(MealStockReplenishedDomainEvent),
+    MealPortionsReservedDomainEvent(MealPortionsReservedDomainEvent),
+    MealPortionsReleasedDomainEvent(MealPortionsReleasedDomainEvent),
+    MealSoldOutDomainEvent(MealSoldOutDomainEvent),
+}
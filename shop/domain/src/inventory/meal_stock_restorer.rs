@@ -0,0 +1,49 @@
+use common::types::{
+    base::{DomainEntity, Version},
+    common::Count,
+};
+
+use crate::{
+    inventory::{meal_stock::MealStock, meal_stock_events::MealStockEventEnum},
+    menu::value_objects::meal_id::MealId,
+};
+
+pub struct MealStockRestorer {}
+
+impl MealStockRestorer {
+    pub fn restore_meal_stock(
+        meal_id: &MealId,
+        available: &Count,
+        version: &Version,
+        events: Vec<MealStockEventEnum>,
+    ) -> MealStock {
+        MealStock::new(
+            DomainEntity::with_events(*meal_id, *version, events),
+            *available,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::test_fixtures::rnd_count;
+
+    use super::*;
+    use crate::test_fixtures::{rnd_meal_id, version};
+
+    #[test]
+    #[allow(non_snake_case)]
+    fn restore_meal_stock__success() {
+        let meal_id = rnd_meal_id();
+        let available = rnd_count();
+        let version = version();
+
+        let mut stock =
+            MealStockRestorer::restore_meal_stock(&meal_id, &available, &version, vec![]);
+
+        assert_eq!(stock.meal_id(), &meal_id);
+        assert_eq!(stock.available(), &available);
+        assert_eq!(stock.version(), &version);
+        assert!(stock.pop_events().is_empty());
+    }
+}
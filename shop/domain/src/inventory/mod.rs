@@ -0,0 +1,3 @@
+pub mod meal_stock;
+pub mod meal_stock_events;
+pub mod meal_stock_restorer;
@@ -1,8 +1,11 @@
 #![allow(special_module_name)]
 
 pub mod cart;
+pub mod customer;
+pub mod inventory;
 pub mod menu;
 pub mod order;
+pub mod webhook;
 
 #[cfg(any(test, feature = "testing"))]
 pub mod test_fixtures;
@@ -7,14 +7,21 @@ use common::types::{
 use derive_getters::Getters;
 use derive_new::new;
 use serde::{Deserialize, Serialize};
+use time::OffsetDateTime;
 
 use crate::menu::{
     meal_already_exists::MealAlreadyExists,
-    meal_events::{MealAddedToMenuDomainEvent, MealEventEnum, MealRemovedFromMenuDomainEvent},
+    meal_events::{
+        MealAddedToMenuDomainEvent, MealAvailabilityChangedDomainEvent, MealEventEnum,
+        MealRemovedFromMenuDomainEvent,
+    },
     value_objects::{
+        allergens::Allergens,
+        availability_schedule::AvailabilitySchedule,
         meal_description::MealDescription,
         meal_id::{MealId, MealIdGenerator},
         meal_name::MealName,
+        nutrition_facts::NutritionFacts,
         price::Price,
     },
 };
@@ -26,16 +33,24 @@ pub struct Meal {
     name: MealName,
     description: MealDescription,
     price: Price,
+    allergens: Allergens,
+    nutrition_facts: NutritionFacts,
+    #[new(default)]
+    availability_schedule: Option<AvailabilitySchedule>,
     #[new(value = "false")]
     removed: bool,
 }
 
 impl Meal {
+    #[allow(clippy::too_many_arguments)]
     pub fn with_all_args(
         entity_params: DomainEntity<MealId, MealEventEnum>,
         name: MealName,
         description: MealDescription,
         price: Price,
+        allergens: Allergens,
+        nutrition_facts: NutritionFacts,
+        availability_schedule: Option<AvailabilitySchedule>,
         removed: bool,
     ) -> Self {
         Self {
@@ -43,6 +58,9 @@ impl Meal {
             name,
             description,
             price,
+            allergens,
+            nutrition_facts,
+            availability_schedule,
             removed,
         }
     }
@@ -52,6 +70,8 @@ impl Meal {
         name: MealName,
         description: MealDescription,
         price: Price,
+        allergens: Allergens,
+        nutrition_facts: NutritionFacts,
     ) -> Result<Meal, MealError> {
         if meal_exists.lock().await.invoke(&name).await {
             Err(MealError::AlreadyExistsWithSameNameError)
@@ -64,6 +84,8 @@ impl Meal {
                 name,
                 description,
                 price,
+                allergens,
+                nutrition_facts,
             );
             meal.entity_params
                 .add_event(MealAddedToMenuDomainEvent::new(id).into());
@@ -84,6 +106,22 @@ impl Meal {
         }
     }
 
+    /// A meal without a schedule can be ordered at any time
+    pub fn is_available_at(&self, at: &OffsetDateTime) -> bool {
+        self.availability_schedule
+            .as_ref()
+            .is_none_or(|schedule| schedule.is_available_at(at))
+    }
+
+    pub fn change_availability_schedule(&mut self, schedule: Option<AvailabilitySchedule>) {
+        if self.availability_schedule != schedule {
+            self.availability_schedule = schedule;
+            let id = self.entity_params.id;
+            self.entity_params
+                .add_event(MealAvailabilityChangedDomainEvent::new(id).into())
+        }
+    }
+
     pub fn id(&self) -> &MealId {
         self.entity_params.id()
     }
@@ -114,7 +152,8 @@ mod tests {
 
     use super::*;
     use crate::test_fixtures::{
-        rnd_meal, rnd_meal_description, rnd_meal_id, rnd_meal_name, rnd_price, rnd_removed_meal,
+        rnd_allergens, rnd_availability_schedule, rnd_meal, rnd_meal_description, rnd_meal_id,
+        rnd_meal_name, rnd_nutrition_facts, rnd_price, rnd_removed_meal,
     };
 
     #[derive(Debug, new, Default)]
@@ -151,12 +190,16 @@ mod tests {
         let name = rnd_meal_name();
         let description = rnd_meal_description();
         let price = rnd_price();
+        let allergens = rnd_allergens();
+        let nutrition_facts = rnd_nutrition_facts();
         let result = Meal::add_meal_to_menu(
             id_generator.clone(),
             meal_exists,
             name.to_owned(),
             description.to_owned(),
             price.to_owned(),
+            allergens.to_owned(),
+            nutrition_facts.to_owned(),
         )
         .await;
 
@@ -165,6 +208,8 @@ mod tests {
         assert_eq!(*test_meal.name(), name);
         assert_eq!(*test_meal.description(), description);
         assert_eq!(*test_meal.price(), price);
+        assert_eq!(*test_meal.allergens(), allergens);
+        assert_eq!(*test_meal.nutrition_facts(), nutrition_facts);
         assert!(test_meal.visible());
 
         let popped_events = test_meal.pop_events();
@@ -185,8 +230,16 @@ mod tests {
         let name = rnd_meal_name();
         let description = rnd_meal_description();
         let price = rnd_price();
-        let result =
-            Meal::add_meal_to_menu(id_generator, meal_exists, name, description, price).await;
+        let result = Meal::add_meal_to_menu(
+            id_generator,
+            meal_exists,
+            name,
+            description,
+            price,
+            rnd_allergens(),
+            rnd_nutrition_facts(),
+        )
+        .await;
 
         assert_eq!(
             result.unwrap_err(),
@@ -224,4 +277,47 @@ mod tests {
         let popped_events = test_meal.pop_events();
         assert!(popped_events.is_empty());
     }
+
+    #[test]
+    fn change_availability_schedule__success() {
+        let mut test_meal = rnd_meal();
+        let schedule = rnd_availability_schedule();
+
+        test_meal.change_availability_schedule(Some(schedule.clone()));
+
+        assert_eq!(test_meal.availability_schedule(), &Some(schedule));
+        let popped_events = test_meal.pop_events();
+        assert_eq!(popped_events.len(), 1);
+        assert!(matches!(
+            popped_events.first().unwrap(),
+            MealEventEnum::MealAvailabilityChangedDomainEvent(event) if &event.meal_id == test_meal.id()
+        ));
+    }
+
+    #[test]
+    fn change_availability_schedule__same_schedule() {
+        let mut test_meal = rnd_meal();
+
+        test_meal.change_availability_schedule(None);
+
+        assert!(test_meal.pop_events().is_empty());
+    }
+
+    #[test]
+    fn is_available_at__without_schedule() {
+        assert!(rnd_meal().is_available_at(&OffsetDateTime::now_utc()));
+    }
+
+    #[test]
+    fn is_available_at__with_schedule() {
+        let mut test_meal = rnd_meal();
+        let schedule = rnd_availability_schedule();
+        let now = OffsetDateTime::now_utc();
+        test_meal.change_availability_schedule(Some(schedule.clone()));
+
+        assert_eq!(
+            test_meal.is_available_at(&now),
+            schedule.is_available_at(&now)
+        );
+    }
 }
@@ -30,10 +30,33 @@ pub struct MealRemovedFromMenuDomainEvent {
     pub created: OffsetDateTime,
 }
 
+#[derive(new, Debug, Clone, PartialEq, Serialize, Deserialize, Hash, Eq, SmartDefault)]
+pub struct MealAvailabilityChangedDomainEvent {
+    #[new(value = "EventId::new()")]
+    #[default(Default::default())]
+    pub id: EventId,
+    #[default(Default::default())]
+    pub meal_id: MealId,
+    #[new(value = "OffsetDateTime::now_utc()")]
+    #[default(_code = "OffsetDateTime::now_utc()")]
+    pub created: OffsetDateTime,
+}
+
 #[enum_delegate::implement(DomainEventTrait)]
 #[derive(PartialEq, Debug, Clone, SmartDefault, Serialize, Deserialize, Hash, Eq)]
 pub enum MealEventEnum {
     #[default]
     MealRemovedFromMenuDomainEvent(MealRemovedFromMenuDomainEvent),
     MealAddedToMenuDomainEvent(MealAddedToMenuDomainEvent),
+    MealAvailabilityChangedDomainEvent(MealAvailabilityChangedDomainEvent),
+}
+
+impl MealEventEnum {
+    pub fn event_id(&self) -> &EventId {
+        match self {
+            Self::MealRemovedFromMenuDomainEvent(e) => &e.id,
+            Self::MealAddedToMenuDomainEvent(e) => &e.id,
+            Self::MealAvailabilityChangedDomainEvent(e) => &e.id,
+        }
+    }
 }
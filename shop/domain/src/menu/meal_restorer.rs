@@ -3,17 +3,25 @@ use common::types::base::{DomainEntity, Version};
 use super::{meal::Meal, value_objects::meal_name::MealName};
 use crate::menu::{
     meal_events::MealEventEnum,
-    value_objects::{meal_description::MealDescription, meal_id::MealId, price::Price},
+    value_objects::{
+        allergens::Allergens, availability_schedule::AvailabilitySchedule,
+        meal_description::MealDescription, meal_id::MealId, nutrition_facts::NutritionFacts,
+        price::Price,
+    },
 };
 
 pub struct MealRestorer {}
 
 impl MealRestorer {
+    #[allow(clippy::too_many_arguments)]
     pub fn restore_meal(
         id: &MealId,
         name: &MealName,
         description: &MealDescription,
         price: &Price,
+        allergens: &Allergens,
+        nutrition_facts: &NutritionFacts,
+        availability_schedule: &Option<AvailabilitySchedule>,
         removed: bool,
         version: &Version,
         events: Vec<MealEventEnum>,
@@ -23,6 +31,9 @@ impl MealRestorer {
             name.clone(),
             description.clone(),
             price.clone(),
+            allergens.clone(),
+            nutrition_facts.clone(),
+            availability_schedule.clone(),
             removed,
         )
     }
@@ -32,7 +43,8 @@ impl MealRestorer {
 mod tests {
     use super::*;
     use crate::test_fixtures::{
-        rnd_meal_description, rnd_meal_id, rnd_meal_name, rnd_price, version,
+        rnd_allergens, rnd_availability_schedule, rnd_meal_description, rnd_meal_id, rnd_meal_name,
+        rnd_nutrition_facts, rnd_price, version,
     };
 
     #[test]
@@ -42,14 +54,30 @@ mod tests {
         let name = &rnd_meal_name();
         let description = &rnd_meal_description();
         let price = &rnd_price();
+        let allergens = &rnd_allergens();
+        let nutrition_facts = &rnd_nutrition_facts();
+        let availability_schedule = &Some(rnd_availability_schedule());
         let removed = &true;
         let version = &version();
 
-        let mut meal: Meal =
-            MealRestorer::restore_meal(mealId, name, description, price, *removed, version, vec![]);
+        let mut meal: Meal = MealRestorer::restore_meal(
+            mealId,
+            name,
+            description,
+            price,
+            allergens,
+            nutrition_facts,
+            availability_schedule,
+            *removed,
+            version,
+            vec![],
+        );
 
         assert_eq!(meal.id(), mealId);
         assert_eq!(meal.name(), name);
+        assert_eq!(meal.allergens(), allergens);
+        assert_eq!(meal.nutrition_facts(), nutrition_facts);
+        assert_eq!(meal.availability_schedule(), availability_schedule);
         assert_eq!(meal.removed(), removed);
         assert_eq!(meal.version(), version);
         assert_eq!(meal.pop_events().len(), 0)
@@ -0,0 +1,173 @@
+use std::collections::BTreeSet;
+
+use common::types::{base::ValueObject, errors::BusinessError};
+use serde::{Deserialize, Serialize};
+
+/// Allergens which have to be declared on the menu
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+pub enum Allergen {
+    Gluten,
+    Crustaceans,
+    Eggs,
+    Fish,
+    Peanuts,
+    Soybeans,
+    Milk,
+    Nuts,
+    Celery,
+    Mustard,
+    Sesame,
+    Sulphites,
+    Lupin,
+    Molluscs,
+}
+
+impl Allergen {
+    pub const ALL: [Allergen; 14] = [
+        Self::Gluten,
+        Self::Crustaceans,
+        Self::Eggs,
+        Self::Fish,
+        Self::Peanuts,
+        Self::Soybeans,
+        Self::Milk,
+        Self::Nuts,
+        Self::Celery,
+        Self::Mustard,
+        Self::Sesame,
+        Self::Sulphites,
+        Self::Lupin,
+        Self::Molluscs,
+    ];
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            Self::Gluten => "gluten",
+            Self::Crustaceans => "crustaceans",
+            Self::Eggs => "eggs",
+            Self::Fish => "fish",
+            Self::Peanuts => "peanuts",
+            Self::Soybeans => "soybeans",
+            Self::Milk => "milk",
+            Self::Nuts => "nuts",
+            Self::Celery => "celery",
+            Self::Mustard => "mustard",
+            Self::Sesame => "sesame",
+            Self::Sulphites => "sulphites",
+            Self::Lupin => "lupin",
+            Self::Molluscs => "molluscs",
+        }
+    }
+}
+
+impl TryFrom<&str> for Allergen {
+    type Error = CreateAllergensError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::ALL
+            .into_iter()
+            .find(|allergen| allergen.code() == value)
+            .ok_or_else(|| Self::Error::UnknownAllergen(value.to_string()))
+    }
+}
+
+/// Allergens contained in a meal, an empty set means the meal has none of them
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct Allergens(BTreeSet<Allergen>);
+
+impl Allergens {
+    pub fn contains(&self, allergen: &Allergen) -> bool {
+        self.0.contains(allergen)
+    }
+
+    pub fn contains_any(&self, other: &Allergens) -> bool {
+        !self.0.is_disjoint(&other.0)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = &Allergen> {
+        self.0.iter()
+    }
+
+    /// Codes of the allergens in a stable order
+    pub fn to_codes(&self) -> Vec<String> {
+        self.0
+            .iter()
+            .map(|allergen| allergen.code().to_string())
+            .collect()
+    }
+}
+
+impl FromIterator<Allergen> for Allergens {
+    fn from_iter<T: IntoIterator<Item = Allergen>>(iter: T) -> Self {
+        Self(iter.into_iter().collect())
+    }
+}
+
+impl<S: AsRef<str>> TryFrom<&[S]> for Allergens {
+    type Error = CreateAllergensError;
+
+    fn try_from(codes: &[S]) -> Result<Self, Self::Error> {
+        codes
+            .iter()
+            .map(|code| Allergen::try_from(code.as_ref()))
+            .collect()
+    }
+}
+
+impl ValueObject for Allergens {}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum CreateAllergensError {
+    UnknownAllergen(String),
+}
+
+impl BusinessError for CreateAllergensError {}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create_allergens__success() {
+        let allergens = Allergens::try_from(["milk", "gluten", "milk"].as_slice()).unwrap();
+
+        assert!(allergens.contains(&Allergen::Milk));
+        assert!(allergens.contains(&Allergen::Gluten));
+        assert_eq!(allergens.to_codes(), vec!["gluten", "milk"]);
+    }
+
+    #[test]
+    fn create_allergens__unknown_allergen() {
+        let result = Allergens::try_from(["milk", "chocolate"].as_slice());
+
+        assert_eq!(
+            result,
+            Err(CreateAllergensError::UnknownAllergen(
+                "chocolate".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn every_allergen_is_restored_from_its_code() {
+        for allergen in Allergen::ALL {
+            assert_eq!(Allergen::try_from(allergen.code()), Ok(allergen));
+        }
+    }
+
+    #[test]
+    fn contains_any() {
+        let allergens: Allergens = [Allergen::Milk, Allergen::Eggs].into_iter().collect();
+        let excluded: Allergens = [Allergen::Eggs, Allergen::Fish].into_iter().collect();
+
+        assert!(allergens.contains_any(&excluded));
+        assert!(!allergens.contains_any(&[Allergen::Fish].into_iter().collect()));
+        assert!(!allergens.contains_any(&Allergens::default()));
+    }
+}
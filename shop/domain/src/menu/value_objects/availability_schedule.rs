@@ -0,0 +1,207 @@
+use common::types::{base::ValueObject, errors::BusinessError};
+use serde::{Deserialize, Serialize};
+use time::{OffsetDateTime, Time, Weekday};
+use time_tz::{OffsetDateTimeExt, Tz, timezones};
+
+/// Days of the week and a time range when a meal can be ordered. A window which ends
+/// before it starts crosses midnight, e.g. Friday 22:00 - 02:00 lasts until Saturday night
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct AvailabilityWindow {
+    days: Vec<Weekday>,
+    from: Time,
+    to: Time,
+}
+
+impl AvailabilityWindow {
+    pub fn try_new(
+        mut days: Vec<Weekday>,
+        from: Time,
+        to: Time,
+    ) -> Result<Self, CreateAvailabilityScheduleError> {
+        if days.is_empty() {
+            return Err(CreateAvailabilityScheduleError::NoDays);
+        }
+        if from == to {
+            return Err(CreateAvailabilityScheduleError::EmptyWindow);
+        }
+        days.sort_by_key(|day| day.number_from_monday());
+        days.dedup();
+        Ok(Self { days, from, to })
+    }
+
+    /// Days on which the window starts, from Monday to Sunday
+    pub fn days(&self) -> &[Weekday] {
+        &self.days
+    }
+
+    pub fn from(&self) -> &Time {
+        &self.from
+    }
+
+    pub fn to(&self) -> &Time {
+        &self.to
+    }
+
+    fn contains(&self, day: Weekday, time: Time) -> bool {
+        if self.from < self.to {
+            self.days.contains(&day) && self.from <= time && time < self.to
+        } else {
+            (self.days.contains(&day) && self.from <= time)
+                || (self.days.contains(&day.previous()) && time < self.to)
+        }
+    }
+}
+
+/// Windows in the local time of a time zone, a meal can be ordered when any of them is open
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[non_exhaustive]
+pub struct AvailabilitySchedule {
+    /// IANA name of the time zone, e.g. `Europe/Berlin`
+    time_zone: String,
+    windows: Vec<AvailabilityWindow>,
+}
+
+impl AvailabilitySchedule {
+    pub fn try_new(
+        time_zone: &str,
+        windows: Vec<AvailabilityWindow>,
+    ) -> Result<Self, CreateAvailabilityScheduleError> {
+        if timezones::get_by_name(time_zone).is_none() {
+            return Err(CreateAvailabilityScheduleError::UnknownTimeZone(
+                time_zone.to_string(),
+            ));
+        }
+        if windows.is_empty() {
+            return Err(CreateAvailabilityScheduleError::NoWindows);
+        }
+        Ok(Self {
+            time_zone: time_zone.to_string(),
+            windows,
+        })
+    }
+
+    pub fn time_zone(&self) -> &str {
+        &self.time_zone
+    }
+
+    pub fn windows(&self) -> &[AvailabilityWindow] {
+        &self.windows
+    }
+
+    pub fn is_available_at(&self, at: &OffsetDateTime) -> bool {
+        let local = at.to_timezone(self.tz());
+        self.windows
+            .iter()
+            .any(|window| window.contains(local.weekday(), local.time()))
+    }
+
+    fn tz(&self) -> &'static Tz {
+        timezones::get_by_name(&self.time_zone).unwrap_or(timezones::db::UTC)
+    }
+}
+
+impl ValueObject for AvailabilitySchedule {}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum CreateAvailabilityScheduleError {
+    UnknownTimeZone(String),
+    NoWindows,
+    NoDays,
+    EmptyWindow,
+}
+
+impl BusinessError for CreateAvailabilityScheduleError {}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use time::format_description::well_known::Rfc3339;
+
+    use super::*;
+
+    fn time(hour: u8, minute: u8) -> Time {
+        Time::from_hms(hour, minute, 0).unwrap()
+    }
+
+    fn at(date_time: &str) -> OffsetDateTime {
+        OffsetDateTime::parse(date_time, &Rfc3339).unwrap()
+    }
+
+    fn breakfast() -> AvailabilitySchedule {
+        let window = AvailabilityWindow::try_new(
+            vec![Weekday::Friday, Weekday::Monday, Weekday::Monday],
+            time(7, 0),
+            time(11, 0),
+        )
+        .unwrap();
+        AvailabilitySchedule::try_new("Europe/Berlin", vec![window]).unwrap()
+    }
+
+    #[test]
+    fn create_window__days_are_sorted_and_distinct() {
+        let schedule = breakfast();
+        assert_eq!(
+            schedule.windows()[0].days(),
+            &[Weekday::Monday, Weekday::Friday]
+        );
+    }
+
+    #[test]
+    fn create_window__no_days() {
+        let result = AvailabilityWindow::try_new(vec![], time(7, 0), time(11, 0));
+        assert_eq!(result, Err(CreateAvailabilityScheduleError::NoDays));
+    }
+
+    #[test]
+    fn create_window__empty() {
+        let result = AvailabilityWindow::try_new(vec![Weekday::Monday], time(7, 0), time(7, 0));
+        assert_eq!(result, Err(CreateAvailabilityScheduleError::EmptyWindow));
+    }
+
+    #[test]
+    fn create_schedule__unknown_time_zone() {
+        let window =
+            AvailabilityWindow::try_new(vec![Weekday::Monday], time(7, 0), time(11, 0)).unwrap();
+        let result = AvailabilitySchedule::try_new("Mars/Olympus", vec![window]);
+        assert_eq!(
+            result,
+            Err(CreateAvailabilityScheduleError::UnknownTimeZone(
+                "Mars/Olympus".to_string()
+            ))
+        );
+    }
+
+    #[test]
+    fn create_schedule__no_windows() {
+        let result = AvailabilitySchedule::try_new("Europe/Berlin", vec![]);
+        assert_eq!(result, Err(CreateAvailabilityScheduleError::NoWindows));
+    }
+
+    #[test]
+    fn is_available_at__local_time_is_used() {
+        let schedule = breakfast();
+
+        // Monday 2024-06-03, Berlin is UTC+2 in summer
+        assert!(schedule.is_available_at(&at("2024-06-03T05:00:00Z")));
+        assert!(schedule.is_available_at(&at("2024-06-03T08:59:00Z")));
+        assert!(!schedule.is_available_at(&at("2024-06-03T09:00:00Z")));
+        assert!(!schedule.is_available_at(&at("2024-06-03T04:59:00Z")));
+        // Tuesday
+        assert!(!schedule.is_available_at(&at("2024-06-04T06:00:00Z")));
+    }
+
+    #[test]
+    fn is_available_at__window_crosses_midnight() {
+        let window =
+            AvailabilityWindow::try_new(vec![Weekday::Friday], time(22, 0), time(2, 0)).unwrap();
+        let schedule = AvailabilitySchedule::try_new("UTC", vec![window]).unwrap();
+
+        // Friday 2024-06-07
+        assert!(!schedule.is_available_at(&at("2024-06-07T01:00:00Z")));
+        assert!(schedule.is_available_at(&at("2024-06-07T23:00:00Z")));
+        assert!(schedule.is_available_at(&at("2024-06-08T01:59:00Z")));
+        assert!(!schedule.is_available_at(&at("2024-06-08T02:00:00Z")));
+        assert!(!schedule.is_available_at(&at("2024-06-08T23:00:00Z")));
+    }
+}
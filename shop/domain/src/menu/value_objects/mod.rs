@@ -1,4 +1,7 @@
+pub mod allergens;
+pub mod availability_schedule;
 pub mod meal_description;
 pub mod meal_id;
 pub mod meal_name;
+pub mod nutrition_facts;
 pub mod price;
@@ -0,0 +1,100 @@
+use bigdecimal::{BigDecimal, Zero};
+use common::types::{base::ValueObject, errors::BusinessError};
+use derive_getters::Getters;
+use serde::{Deserialize, Serialize};
+
+/// Energy and macronutrients of one portion of a meal
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Default, Serialize, Deserialize, Getters)]
+#[non_exhaustive]
+pub struct NutritionFacts {
+    /// Kilocalories
+    calories: i32,
+    /// Grams of proteins
+    proteins: BigDecimal,
+    /// Grams of fats
+    fats: BigDecimal,
+    /// Grams of carbohydrates
+    carbohydrates: BigDecimal,
+}
+
+impl NutritionFacts {
+    /// Macronutrients are measured to tenths of a gram
+    pub const SCALE: i64 = 1;
+
+    pub fn try_new(
+        calories: i32,
+        proteins: BigDecimal,
+        fats: BigDecimal,
+        carbohydrates: BigDecimal,
+    ) -> Result<Self, CreateNutritionFactsError> {
+        if calories < 0 {
+            return Err(CreateNutritionFactsError::NegativeValue);
+        }
+        Ok(Self {
+            calories,
+            proteins: Self::grams(proteins)?,
+            fats: Self::grams(fats)?,
+            carbohydrates: Self::grams(carbohydrates)?,
+        })
+    }
+
+    fn grams(value: BigDecimal) -> Result<BigDecimal, CreateNutritionFactsError> {
+        let scale = value.normalized().into_bigint_and_exponent().1;
+        match &value {
+            _ if scale > Self::SCALE => Err(CreateNutritionFactsError::InvalidScale),
+            _ if value < BigDecimal::zero() => Err(CreateNutritionFactsError::NegativeValue),
+            _ => Ok(value.with_scale(Self::SCALE)),
+        }
+    }
+}
+
+impl ValueObject for NutritionFacts {}
+
+#[derive(Debug, PartialEq, Clone)]
+pub enum CreateNutritionFactsError {
+    NegativeValue,
+    InvalidScale,
+}
+
+impl BusinessError for CreateNutritionFactsError {}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+
+    fn grams(value: &str) -> BigDecimal {
+        BigDecimal::from_str(value).unwrap()
+    }
+
+    #[test]
+    fn create_nutrition_facts__success() {
+        let facts =
+            NutritionFacts::try_new(450, grams("25"), grams("12.5"), grams("60.1")).unwrap();
+
+        assert_eq!(facts.calories(), &450);
+        assert_eq!(facts.proteins(), &grams("25.0"));
+        assert_eq!(facts.fats(), &grams("12.5"));
+        assert_eq!(facts.carbohydrates(), &grams("60.1"));
+    }
+
+    #[test]
+    fn create_nutrition_facts__negative_calories() {
+        let result = NutritionFacts::try_new(-1, grams("1"), grams("1"), grams("1"));
+        assert_eq!(result, Err(CreateNutritionFactsError::NegativeValue));
+    }
+
+    #[test]
+    fn create_nutrition_facts__negative_grams() {
+        let result = NutritionFacts::try_new(100, grams("1"), grams("-0.1"), grams("1"));
+        assert_eq!(result, Err(CreateNutritionFactsError::NegativeValue));
+    }
+
+    #[test]
+    fn create_nutrition_facts__invalid_scale() {
+        let result = NutritionFacts::try_new(100, grams("1"), grams("1"), grams("1.25"));
+        assert_eq!(result, Err(CreateNutritionFactsError::InvalidScale));
+    }
+}
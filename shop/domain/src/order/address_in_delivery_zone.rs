@@ -0,0 +1,9 @@
+use std::fmt::Debug;
+
+use async_trait::async_trait;
+use common::types::common::Address;
+
+#[async_trait]
+pub trait AddressInDeliveryZone: Debug + Send {
+    async fn invoke(&mut self, address: &Address) -> bool;
+}
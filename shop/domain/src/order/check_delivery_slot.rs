@@ -0,0 +1,17 @@
+use std::fmt::Debug;
+
+use async_trait::async_trait;
+
+use crate::order::value_objects::delivery_slot::DeliverySlot;
+
+#[async_trait]
+pub trait CheckDeliverySlot: Debug + Send {
+    async fn invoke(&mut self, slot: &DeliverySlot) -> Result<(), DeliverySlotError>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DeliverySlotError {
+    AlreadyStarted,
+    OutsideOpeningHours,
+    NoCapacityLeft,
+}
@@ -1,11 +1,14 @@
-use common::types::base::{DomainEvent, DomainEventTrait};
+use common::types::base::{DomainEvent, DomainEventTrait, EventId};
 use derive_new::new;
 use serde_derive::{Deserialize, Serialize};
 use smart_default::SmartDefault;
 
 use crate::{
-    cart::value_objects::customer_id::CustomerId, menu::value_objects::price::Price,
-    order::value_objects::shop_order_id::ShopOrderId,
+    cart::value_objects::customer_id::CustomerId,
+    menu::value_objects::price::Price,
+    order::value_objects::{
+        delivery_slot::DeliverySlot, fulfilment::Fulfilment, shop_order_id::ShopOrderId,
+    },
 };
 #[derive(new, Debug, Clone, PartialEq, Serialize, Deserialize, Hash, Eq, Default)]
 pub struct ShopOrderCreatedDomainEvent {
@@ -14,6 +17,8 @@ pub struct ShopOrderCreatedDomainEvent {
     pub order_id: ShopOrderId,
     pub for_customer: CustomerId,
     pub total_price: Price,
+    pub fulfilment: Fulfilment,
+    pub delivery_slot: Option<DeliverySlot>,
 }
 
 #[derive(new, Debug, Clone, PartialEq, Serialize, Deserialize, Hash, Eq, Default)]
@@ -37,6 +42,13 @@ pub struct ShopOrderCancelledDomainEvent {
     pub order_id: ShopOrderId,
 }
 
+#[derive(new, Debug, Clone, PartialEq, Serialize, Deserialize, Hash, Eq, Default)]
+pub struct ShopOrderReadyForPickupDomainEvent {
+    #[new(value = "DomainEvent::default()")]
+    domain_event_params: DomainEvent,
+    pub order_id: ShopOrderId,
+}
+
 #[derive(new, Debug, Clone, PartialEq, Serialize, Deserialize, Hash, Eq, Default)]
 pub struct ShopOrderPaidDomainEvent {
     #[new(value = "DomainEvent::default()")]
@@ -46,11 +58,37 @@ pub struct ShopOrderPaidDomainEvent {
 
 #[enum_delegate::implement(DomainEventTrait)]
 #[derive(PartialEq, Debug, Clone, Serialize, Deserialize, Hash, Eq, SmartDefault)]
+#[allow(clippy::large_enum_variant)]
 pub enum ShopOrderEventEnum {
     #[default]
     ShopOrderCreatedDomainEvent(ShopOrderCreatedDomainEvent),
     ShopOrderCompletedDomainEvent(ShopOrderCompletedDomainEvent),
     ShopOrderConfirmedDomainEvent(ShopOrderConfirmedDomainEvent),
     ShopOrderCancelledDomainEvent(ShopOrderCancelledDomainEvent),
+    ShopOrderReadyForPickupDomainEvent(ShopOrderReadyForPickupDomainEvent),
     ShopOrderPaidDomainEvent(ShopOrderPaidDomainEvent),
 }
+
+impl ShopOrderEventEnum {
+    pub fn event_id(&self) -> &EventId {
+        match self {
+            Self::ShopOrderCreatedDomainEvent(e) => &e.domain_event_params.id,
+            Self::ShopOrderCompletedDomainEvent(e) => &e.domain_event_params.id,
+            Self::ShopOrderConfirmedDomainEvent(e) => &e.domain_event_params.id,
+            Self::ShopOrderCancelledDomainEvent(e) => &e.domain_event_params.id,
+            Self::ShopOrderReadyForPickupDomainEvent(e) => &e.domain_event_params.id,
+            Self::ShopOrderPaidDomainEvent(e) => &e.domain_event_params.id,
+        }
+    }
+
+    pub fn order_id(&self) -> ShopOrderId {
+        match self {
+            Self::ShopOrderCreatedDomainEvent(e) => e.order_id,
+            Self::ShopOrderCompletedDomainEvent(e) => e.order_id,
+            Self::ShopOrderConfirmedDomainEvent(e) => e.order_id,
+            Self::ShopOrderCancelledDomainEvent(e) => e.order_id,
+            Self::ShopOrderReadyForPickupDomainEvent(e) => e.order_id,
+            Self::ShopOrderPaidDomainEvent(e) => e.order_id,
+        }
+    }
+}
@@ -0,0 +1,11 @@
+use std::fmt::Debug;
+
+use async_trait::async_trait;
+use common::types::common::Address;
+
+use crate::menu::value_objects::price::Price;
+
+#[async_trait]
+pub trait GetDeliveryFee: Debug + Send {
+    async fn invoke(&self, delivery_to: &Address) -> Price;
+}
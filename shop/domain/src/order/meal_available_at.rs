@@ -0,0 +1,12 @@
+use std::fmt::Debug;
+
+use async_trait::async_trait;
+use time::OffsetDateTime;
+
+use crate::menu::value_objects::meal_id::MealId;
+
+/// The availability schedule of the meal allows to order it for the given time
+#[async_trait]
+pub trait MealAvailableAt: Debug + Send {
+    async fn invoke(&mut self, meal_id: &MealId, at: &OffsetDateTime) -> bool;
+}
@@ -1,6 +1,11 @@
+pub mod address_in_delivery_zone;
+pub mod check_delivery_slot;
 pub mod customer_has_active_order;
 pub mod customer_order_events;
+pub mod get_delivery_fee;
 pub mod get_meal_price;
+pub mod meal_available_at;
+pub mod reserve_meal_portions;
 pub mod shop_order;
 pub mod shop_order_restorer;
 pub mod value_objects;
@@ -0,0 +1,16 @@
+use std::{collections::HashMap, fmt::Debug};
+
+use async_trait::async_trait;
+use common::types::common::Count;
+
+use crate::menu::value_objects::meal_id::MealId;
+
+/// Reserves portions of all the meals at once or none of them
+#[async_trait]
+pub trait ReserveMealPortions: Debug + Send {
+    async fn invoke(&mut self, meals: &HashMap<MealId, Count>) -> Result<(), MealUnavailable>;
+}
+
+/// The meal has not enough portions left
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct MealUnavailable(pub MealId);
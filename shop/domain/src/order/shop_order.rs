@@ -5,7 +5,7 @@ use std::{
 
 use common::types::{
     base::{AM, DomainEntity, DomainEntityTrait, Version},
-    common::{Address, Count},
+    common::Count,
 };
 use derive_getters::Getters;
 use derive_new::new;
@@ -17,37 +17,60 @@ use crate::{
     cart::{cart::Cart, value_objects::customer_id::CustomerId},
     menu::value_objects::{meal_id::MealId, price::Price},
     order::{
+        address_in_delivery_zone::AddressInDeliveryZone,
+        check_delivery_slot::{CheckDeliverySlot, DeliverySlotError},
         customer_has_active_order::CustomerHasActiveOrder,
         customer_order_events::{
             ShopOrderCancelledDomainEvent, ShopOrderCompletedDomainEvent,
             ShopOrderConfirmedDomainEvent, ShopOrderCreatedDomainEvent, ShopOrderEventEnum,
-            ShopOrderPaidDomainEvent,
+            ShopOrderPaidDomainEvent, ShopOrderReadyForPickupDomainEvent,
         },
+        get_delivery_fee::GetDeliveryFee,
         get_meal_price::GetMealPrice,
-        shop_order::OrderState::{Cancelled, Completed, Confirmed, Paid, WaitingForPayment},
-        value_objects::shop_order_id::{ShopOrderId, ShopOrderIdGenerator},
+        meal_available_at::MealAvailableAt,
+        reserve_meal_portions::{MealUnavailable, ReserveMealPortions},
+        shop_order::OrderState::{
+            Cancelled, Completed, Confirmed, Paid, ReadyForPickup, WaitingForPayment,
+        },
+        value_objects::{
+            delivery_slot::DeliverySlot,
+            fulfilment::Fulfilment,
+            shop_order_id::{ShopOrderId, ShopOrderIdGenerator},
+        },
     },
 };
 
 #[derive(new, Debug, Clone, PartialEq, Serialize, Deserialize, SmartDefault, Getters)]
+#[allow(clippy::too_many_arguments)]
 pub struct ShopOrder {
     #[getter(skip)]
     pub(crate) entity_params: DomainEntity<ShopOrderId, ShopOrderEventEnum>,
     #[default(OffsetDateTime::now_utc())]
     pub(crate) created: OffsetDateTime,
     pub(crate) for_customer: CustomerId,
-    pub(crate) address: Address,
+    pub(crate) fulfilment: Fulfilment,
+    #[default(Price::zero())]
+    pub(crate) delivery_fee: Price,
+    /// Requested delivery time, `None` means as soon as possible
+    pub(crate) delivery_slot: Option<DeliverySlot>,
     pub(crate) order_items: HashSet<OrderItem>,
     pub(crate) state: OrderState,
 }
 
 impl ShopOrder {
+    #[allow(clippy::too_many_arguments)]
     pub async fn checkout(
         cart: Cart,
         id_generator: AM<dyn ShopOrderIdGenerator>,
         customer_has_active_order: AM<dyn CustomerHasActiveOrder>,
-        address: Address,
+        fulfilment: Fulfilment,
+        delivery_slot: Option<DeliverySlot>,
         get_meal_price: AM<dyn GetMealPrice>,
+        meal_available_at: AM<dyn MealAvailableAt>,
+        reserve_meal_portions: AM<dyn ReserveMealPortions>,
+        get_delivery_fee: AM<dyn GetDeliveryFee>,
+        check_delivery_slot: AM<dyn CheckDeliverySlot>,
+        address_in_delivery_zone: AM<dyn AddressInDeliveryZone>,
     ) -> Result<ShopOrder, CheckoutError> {
         if customer_has_active_order
             .lock()
@@ -57,26 +80,64 @@ impl ShopOrder {
         {
             return Err(CheckoutError::AlreadyHasActiveOrder);
         }
+        if let Fulfilment::Delivery(address) = &fulfilment
+            && !address_in_delivery_zone.lock().await.invoke(address).await
+        {
+            return Err(CheckoutError::AddressOutsideDeliveryZone);
+        }
+        if let Some(slot) = &delivery_slot {
+            check_delivery_slot.lock().await.invoke(slot).await?;
+        }
         let meals = cart.meals();
         if !meals.is_empty() {
             let mut set = HashSet::new();
+            // Scheduled orders get the meals at the start of the slot
+            let ordered_for = delivery_slot
+                .map(|slot| *slot.start())
+                .unwrap_or_else(OffsetDateTime::now_utc);
 
             for (meal_id, count) in meals {
+                if !meal_available_at
+                    .lock()
+                    .await
+                    .invoke(meal_id, &ordered_for)
+                    .await
+                {
+                    return Err(CheckoutError::MealOutsideAvailabilityWindow(*meal_id));
+                }
                 let price = get_meal_price.lock().await.invoke(meal_id).await;
                 set.insert(OrderItem::new(*meal_id, price, *count));
             }
+            // The last check, portions stay reserved only for a created order
+            reserve_meal_portions.lock().await.invoke(meals).await?;
+            // Pickup orders are collected by the customer, so there is nothing to charge for
+            let delivery_fee = match &fulfilment {
+                Fulfilment::Delivery(address) => {
+                    get_delivery_fee.lock().await.invoke(address).await
+                }
+                Fulfilment::Pickup(_) => Price::zero(),
+            };
             let id = id_generator.lock().await.generate();
             let mut shop_order = ShopOrder::new(
                 DomainEntity::new(id, Default::default()),
                 OffsetDateTime::now_utc(),
                 *cart.for_customer(),
-                address,
+                fulfilment.clone(),
+                delivery_fee,
+                delivery_slot,
                 set,
                 Default::default(),
             );
             let total_price = shop_order.total_price();
             shop_order.add_event(
-                ShopOrderCreatedDomainEvent::new(id, *cart.for_customer(), total_price).into(),
+                ShopOrderCreatedDomainEvent::new(
+                    id,
+                    *cart.for_customer(),
+                    total_price,
+                    fulfilment,
+                    delivery_slot,
+                )
+                .into(),
             );
             Ok(shop_order)
         } else {
@@ -105,6 +166,13 @@ impl ShopOrder {
         )
     }
 
+    pub fn mark_ready_for_pickup(&mut self) -> Result<(), InvalidState> {
+        self.change_state(
+            OrderState::new_ready_for_pickup(),
+            ShopOrderReadyForPickupDomainEvent::new(*self.id()).into(),
+        )
+    }
+
     pub fn cancel(&mut self) -> Result<(), InvalidState> {
         self.change_state(
             OrderState::new_cancelled(),
@@ -119,7 +187,7 @@ impl ShopOrder {
     ) -> Result<(), InvalidState> {
         if self.state == new_state {
             Ok(())
-        } else if self.state.can_change_to(&new_state) {
+        } else if self.state.can_change_to(&new_state, &self.fulfilment) {
             self.state = new_state;
             self.add_event(event);
             Ok(())
@@ -131,7 +199,7 @@ impl ShopOrder {
         self.order_items
             .iter()
             .map(|it| it.price.multiple(it.count))
-            .fold(Price::zero(), |acc, it| acc.add(it))
+            .fold(self.delivery_fee.clone(), |acc, it| acc.add(it))
     }
 
     pub fn is_active(&self) -> bool {
@@ -140,6 +208,7 @@ impl ShopOrder {
             Completed(value) => *value,
             Confirmed(value) => *value,
             Paid(value) => *value,
+            ReadyForPickup(value) => *value,
             WaitingForPayment(value) => *value,
         }
     }
@@ -186,14 +255,20 @@ pub enum OrderState {
     Completed(#[new(value = "false")] bool),
     Confirmed(#[new(value = "true")] bool),
     Paid(#[new(value = "true")] bool),
+    ReadyForPickup(#[new(value = "true")] bool),
     #[default]
     WaitingForPayment(#[new(value = "true")] bool),
 }
 
 impl OrderState {
-    pub fn can_change_to(&self, state: &OrderState) -> bool {
+    pub fn can_change_to(&self, state: &OrderState, fulfilment: &Fulfilment) -> bool {
         match self {
-            Confirmed(_) => matches!(state, Completed(_)),
+            Confirmed(_) => match fulfilment {
+                Fulfilment::Delivery(_) => matches!(state, Completed(_)),
+                Fulfilment::Pickup(_) => matches!(state, ReadyForPickup(_)),
+            },
+
+            ReadyForPickup(_) => matches!(state, Completed(_)),
 
             Paid(_) => {
                 matches!(state, Confirmed(_) | Cancelled(_))
@@ -211,6 +286,7 @@ impl OrderState {
             Completed(value) => *value,
             Confirmed(value) => *value,
             Paid(value) => *value,
+            ReadyForPickup(value) => *value,
             WaitingForPayment(value) => *value,
         }
     }
@@ -220,6 +296,22 @@ impl OrderState {
 pub enum CheckoutError {
     EmptyCart,
     AlreadyHasActiveOrder,
+    AddressOutsideDeliveryZone,
+    DeliverySlotUnavailable(DeliverySlotError),
+    MealUnavailable(MealId),
+    MealOutsideAvailabilityWindow(MealId),
+}
+
+impl From<DeliverySlotError> for CheckoutError {
+    fn from(value: DeliverySlotError) -> Self {
+        Self::DeliverySlotUnavailable(value)
+    }
+}
+
+impl From<MealUnavailable> for CheckoutError {
+    fn from(value: MealUnavailable) -> Self {
+        Self::MealUnavailable(value.0)
+    }
 }
 
 #[derive(Debug, PartialEq)]
@@ -236,11 +328,19 @@ mod tests {
 
     use async_trait::async_trait;
     use bigdecimal::{BigDecimal, num_bigint::BigInt};
-    use common::{test_fixtures::rnd_count, types::base::AMTrait};
+    use common::{
+        test_fixtures::rnd_count,
+        types::{base::AMTrait, common::Address},
+    };
 
     use super::*;
-    use crate::test_fixtures::{
-        order_with_state, rnd_address, rnd_cart, rnd_meal_id, rnd_order, rnd_order_id, rnd_price,
+    use crate::{
+        order::value_objects::pickup_point_id::PickupPointId,
+        test_fixtures::{
+            order_with_fulfilment_and_state, order_with_state, rnd_address, rnd_cart,
+            rnd_delivery_slot, rnd_fulfilment, rnd_meal_id, rnd_order, rnd_order_id,
+            rnd_pickup_point_id, rnd_price,
+        },
     };
 
     #[tokio::test]
@@ -250,7 +350,8 @@ mod tests {
         let meal_id = rnd_meal_id();
         let count = rnd_count();
         let price = rnd_price();
-        let address = rnd_address();
+        let fulfilment = Fulfilment::Delivery(rnd_address());
+        let delivery_fee = rnd_price();
 
         let get_meal_price = AM::new_am(HashMapStoragePriceProvider::default());
         get_meal_price
@@ -265,8 +366,14 @@ mod tests {
             cart.clone(),
             id_generator.clone(),
             AM::new_am(MockCustomerHasActiveOrder::new(false)),
-            address.clone(),
+            fulfilment.clone(),
+            None,
             get_meal_price.clone(),
+            AM::new_am(MockMealAvailableAt::default()),
+            AM::new_am(MockReserveMealPortions::default()),
+            AM::new_am(FixedDeliveryFee::new(delivery_fee.clone())),
+            AM::new_am(MockCheckDeliverySlot::new(Ok(()))),
+            AM::new_am(MockAddressInDeliveryZone::new(true)),
         )
         .await;
 
@@ -275,10 +382,12 @@ mod tests {
         assert_eq!(order.for_customer(), cart.for_customer());
         assert_eq!(
             order.order_items(),
-            &HashSet::from([OrderItem::new(meal_id, price, count)])
+            &HashSet::from([OrderItem::new(meal_id, price.clone(), count)])
         );
         assert_eq!(order.id(), &id);
-        assert_eq!(order.address(), &address);
+        assert_eq!(order.fulfilment(), &fulfilment);
+        assert_eq!(order.delivery_fee(), &delivery_fee);
+        assert_eq!(order.total_price(), price.multiple(count).add(delivery_fee));
         assert!(matches!(order.state(), WaitingForPayment(_)));
         let events: Vec<ShopOrderCreatedDomainEvent> = order
             .pop_events()
@@ -290,6 +399,175 @@ mod tests {
         assert_eq!(event.order_id, id);
         assert_eq!(&event.for_customer, cart.for_customer());
         assert_eq!(event.total_price, order.total_price());
+        assert_eq!(event.fulfilment, fulfilment);
+        assert_eq!(event.delivery_slot, None);
+    }
+
+    #[tokio::test]
+    async fn checkout_scheduled_delivery() {
+        let id_generator = AM::new_am(MockOrderIdGenerator::default());
+        let meal_id = rnd_meal_id();
+        let delivery_slot = rnd_delivery_slot();
+
+        let get_meal_price = AM::new_am(HashMapStoragePriceProvider::default());
+        get_meal_price
+            .lock()
+            .await
+            .storage
+            .insert(meal_id, rnd_price());
+        let mut cart = rnd_cart();
+        cart.meals.insert(meal_id, rnd_count());
+
+        let check_delivery_slot = AM::new_am(MockCheckDeliverySlot::new(Ok(())));
+        let meal_available_at = AM::new_am(MockMealAvailableAt::default());
+        let result = ShopOrder::checkout(
+            cart.clone(),
+            id_generator.clone(),
+            AM::new_am(MockCustomerHasActiveOrder::new(false)),
+            rnd_fulfilment(),
+            Some(delivery_slot),
+            get_meal_price.clone(),
+            meal_available_at.clone(),
+            AM::new_am(MockReserveMealPortions::default()),
+            AM::new_am(FixedDeliveryFee::new(rnd_price())),
+            check_delivery_slot.clone(),
+            AM::new_am(MockAddressInDeliveryZone::new(true)),
+        )
+        .await;
+
+        let mut order = result.unwrap();
+
+        assert_eq!(order.delivery_slot(), &Some(delivery_slot));
+        assert_eq!(check_delivery_slot.lock().await.slot, Some(delivery_slot));
+        assert_eq!(
+            meal_available_at.lock().await.at,
+            Some(*delivery_slot.start())
+        );
+        let events: Vec<ShopOrderCreatedDomainEvent> = order
+            .pop_events()
+            .iter()
+            .map(|it| it.clone().try_into().unwrap())
+            .collect();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events.first().unwrap().delivery_slot, Some(delivery_slot));
+    }
+
+    #[tokio::test]
+    async fn checkout_delivery_slot_unavailable() {
+        let id_generator = AM::new_am(MockOrderIdGenerator::default());
+        let meal_id = rnd_meal_id();
+
+        let get_meal_price = AM::new_am(HashMapStoragePriceProvider::default());
+        get_meal_price
+            .lock()
+            .await
+            .storage
+            .insert(meal_id, rnd_price());
+        let mut cart = rnd_cart();
+        cart.meals.insert(meal_id, rnd_count());
+
+        let result = ShopOrder::checkout(
+            cart.clone(),
+            id_generator.clone(),
+            AM::new_am(MockCustomerHasActiveOrder::new(false)),
+            rnd_fulfilment(),
+            Some(rnd_delivery_slot()),
+            get_meal_price.clone(),
+            AM::new_am(MockMealAvailableAt::default()),
+            AM::new_am(MockReserveMealPortions::default()),
+            AM::new_am(FixedDeliveryFee::new(rnd_price())),
+            AM::new_am(MockCheckDeliverySlot::new(Err(
+                DeliverySlotError::NoCapacityLeft,
+            ))),
+            AM::new_am(MockAddressInDeliveryZone::new(true)),
+        )
+        .await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            CheckoutError::DeliverySlotUnavailable(DeliverySlotError::NoCapacityLeft)
+        );
+    }
+
+    #[tokio::test]
+    async fn checkout_address_outside_delivery_zone() {
+        let id_generator = AM::new_am(MockOrderIdGenerator::default());
+        let meal_id = rnd_meal_id();
+
+        let get_meal_price = AM::new_am(HashMapStoragePriceProvider::default());
+        get_meal_price
+            .lock()
+            .await
+            .storage
+            .insert(meal_id, rnd_price());
+        let mut cart = rnd_cart();
+        cart.meals.insert(meal_id, rnd_count());
+
+        let result = ShopOrder::checkout(
+            cart.clone(),
+            id_generator.clone(),
+            AM::new_am(MockCustomerHasActiveOrder::new(false)),
+            Fulfilment::Delivery(rnd_address()),
+            None,
+            get_meal_price.clone(),
+            AM::new_am(MockMealAvailableAt::default()),
+            AM::new_am(MockReserveMealPortions::default()),
+            AM::new_am(FixedDeliveryFee::new(rnd_price())),
+            AM::new_am(MockCheckDeliverySlot::new(Ok(()))),
+            AM::new_am(MockAddressInDeliveryZone::new(false)),
+        )
+        .await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            CheckoutError::AddressOutsideDeliveryZone
+        );
+    }
+
+    #[tokio::test]
+    async fn checkout_pickup_without_delivery_fee() {
+        let id_generator = AM::new_am(MockOrderIdGenerator::default());
+        let meal_id = rnd_meal_id();
+        let count = rnd_count();
+        let price = rnd_price();
+        let fulfilment = Fulfilment::Pickup(rnd_pickup_point_id());
+
+        let get_meal_price = AM::new_am(HashMapStoragePriceProvider::default());
+        get_meal_price
+            .lock()
+            .await
+            .storage
+            .insert(meal_id, price.clone());
+        let mut cart = rnd_cart();
+        cart.meals.insert(meal_id, count);
+
+        let result = ShopOrder::checkout(
+            cart.clone(),
+            id_generator.clone(),
+            AM::new_am(MockCustomerHasActiveOrder::new(false)),
+            fulfilment.clone(),
+            None,
+            get_meal_price.clone(),
+            AM::new_am(MockMealAvailableAt::default()),
+            AM::new_am(MockReserveMealPortions::default()),
+            AM::new_am(FixedDeliveryFee::new(rnd_price())),
+            AM::new_am(MockCheckDeliverySlot::new(Ok(()))),
+            AM::new_am(MockAddressInDeliveryZone::new(false)),
+        )
+        .await;
+
+        let mut order = result.unwrap();
+
+        assert_eq!(order.fulfilment(), &fulfilment);
+        assert_eq!(order.delivery_fee(), &Price::zero());
+        assert_eq!(order.total_price(), price.multiple(count));
+        let events: Vec<ShopOrderCreatedDomainEvent> = order
+            .pop_events()
+            .iter()
+            .map(|it| it.clone().try_into().unwrap())
+            .collect();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events.first().unwrap().fulfilment, fulfilment);
     }
 
     #[tokio::test]
@@ -298,7 +576,6 @@ mod tests {
         let meal_id = rnd_meal_id();
         let count = rnd_count();
         let price = rnd_price();
-        let address = rnd_address();
 
         let meal_price_only_for_special_meal = AM::new_am(HashMapStoragePriceProvider::default());
         meal_price_only_for_special_meal
@@ -314,8 +591,14 @@ mod tests {
             cart.clone(),
             id_generator.clone(),
             AM::new_am(MockCustomerHasActiveOrder::new(true)),
-            address.clone(),
+            Fulfilment::Delivery(rnd_address()),
+            None,
             meal_price_only_for_special_meal.clone(),
+            AM::new_am(MockMealAvailableAt::default()),
+            AM::new_am(MockReserveMealPortions::default()),
+            AM::new_am(FixedDeliveryFee::new(rnd_price())),
+            AM::new_am(MockCheckDeliverySlot::new(Ok(()))),
+            AM::new_am(MockAddressInDeliveryZone::new(true)),
         )
         .await;
 
@@ -323,6 +606,107 @@ mod tests {
         assert_eq!(result.unwrap_err(), CheckoutError::AlreadyHasActiveOrder);
     }
 
+    #[tokio::test]
+    async fn checkout_reserves_meal_portions() {
+        let meal_id = rnd_meal_id();
+        let count = rnd_count();
+        let get_meal_price = AM::new_am(HashMapStoragePriceProvider::default());
+        get_meal_price
+            .lock()
+            .await
+            .storage
+            .insert(meal_id, rnd_price());
+        let mut cart = rnd_cart();
+        cart.meals.insert(meal_id, count);
+        let reserve_meal_portions = AM::new_am(MockReserveMealPortions::default());
+
+        let result = ShopOrder::checkout(
+            cart.clone(),
+            AM::new_am(MockOrderIdGenerator::default()),
+            AM::new_am(MockCustomerHasActiveOrder::new(false)),
+            rnd_fulfilment(),
+            None,
+            get_meal_price.clone(),
+            AM::new_am(MockMealAvailableAt::default()),
+            reserve_meal_portions.clone(),
+            AM::new_am(FixedDeliveryFee::new(rnd_price())),
+            AM::new_am(MockCheckDeliverySlot::new(Ok(()))),
+            AM::new_am(MockAddressInDeliveryZone::new(true)),
+        )
+        .await;
+
+        assert!(result.is_ok());
+        assert_eq!(
+            reserve_meal_portions.lock().await.meals,
+            Some(HashMap::from([(meal_id, count)]))
+        );
+    }
+
+    #[tokio::test]
+    async fn checkout_meal_unavailable() {
+        let meal_id = rnd_meal_id();
+        let get_meal_price = AM::new_am(HashMapStoragePriceProvider::default());
+        get_meal_price
+            .lock()
+            .await
+            .storage
+            .insert(meal_id, rnd_price());
+        let mut cart = rnd_cart();
+        cart.meals.insert(meal_id, rnd_count());
+
+        let result = ShopOrder::checkout(
+            cart.clone(),
+            AM::new_am(MockOrderIdGenerator::default()),
+            AM::new_am(MockCustomerHasActiveOrder::new(false)),
+            rnd_fulfilment(),
+            None,
+            get_meal_price.clone(),
+            AM::new_am(MockMealAvailableAt::default()),
+            AM::new_am(MockReserveMealPortions::new(Some(meal_id))),
+            AM::new_am(FixedDeliveryFee::new(rnd_price())),
+            AM::new_am(MockCheckDeliverySlot::new(Ok(()))),
+            AM::new_am(MockAddressInDeliveryZone::new(true)),
+        )
+        .await;
+
+        assert_eq!(result.unwrap_err(), CheckoutError::MealUnavailable(meal_id));
+    }
+
+    #[tokio::test]
+    async fn checkout_meal_outside_availability_window() {
+        let meal_id = rnd_meal_id();
+        let get_meal_price = AM::new_am(HashMapStoragePriceProvider::default());
+        get_meal_price
+            .lock()
+            .await
+            .storage
+            .insert(meal_id, rnd_price());
+        let mut cart = rnd_cart();
+        cart.meals.insert(meal_id, rnd_count());
+        let reserve_meal_portions = AM::new_am(MockReserveMealPortions::default());
+
+        let result = ShopOrder::checkout(
+            cart.clone(),
+            AM::new_am(MockOrderIdGenerator::default()),
+            AM::new_am(MockCustomerHasActiveOrder::new(false)),
+            rnd_fulfilment(),
+            None,
+            get_meal_price.clone(),
+            AM::new_am(MockMealAvailableAt::new(Some(meal_id))),
+            reserve_meal_portions.clone(),
+            AM::new_am(FixedDeliveryFee::new(rnd_price())),
+            AM::new_am(MockCheckDeliverySlot::new(Ok(()))),
+            AM::new_am(MockAddressInDeliveryZone::new(true)),
+        )
+        .await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            CheckoutError::MealOutsideAvailabilityWindow(meal_id)
+        );
+        assert_eq!(reserve_meal_portions.lock().await.meals, None);
+    }
+
     #[tokio::test]
     async fn checkout_empty_cart() {
         let id_generator = AM::new_am(MockOrderIdGenerator::default());
@@ -337,8 +721,14 @@ mod tests {
             cart.clone(),
             id_generator.clone(),
             AM::new_am(MockCustomerHasActiveOrder::new(false)),
-            rnd_address(),
+            Fulfilment::Delivery(rnd_address()),
+            None,
             get_meal_price.clone(),
+            AM::new_am(MockMealAvailableAt::default()),
+            AM::new_am(MockReserveMealPortions::default()),
+            AM::new_am(FixedDeliveryFee::new(rnd_price())),
+            AM::new_am(MockCheckDeliverySlot::new(Ok(()))),
+            AM::new_am(MockAddressInDeliveryZone::new(true)),
         )
         .await;
 
@@ -352,6 +742,7 @@ mod tests {
             OrderState::new_waiting_for_payment(),
             OrderState::new_confirmed(),
             OrderState::new_paid(),
+            OrderState::new_ready_for_pickup(),
         ];
 
         states.iter().for_each(|it| {
@@ -389,6 +780,79 @@ mod tests {
         assert!(order.pop_events().is_empty());
     }
 
+    #[test]
+    fn complete_pickup_order_success() {
+        let mut order = order_with_fulfilment_and_state(
+            Fulfilment::Pickup(rnd_pickup_point_id()),
+            OrderState::new_ready_for_pickup(),
+        );
+        assert!(order.complete().is_ok());
+        assert!(matches!(order.state(), Completed(_)));
+        let event: Vec<ShopOrderCompletedDomainEvent> = order
+            .pop_events()
+            .iter()
+            .map(|it| it.clone().try_into().unwrap())
+            .collect();
+        assert_eq!(event.len(), 1);
+        assert_eq!(&event.first().unwrap().order_id, order.id());
+    }
+
+    #[test]
+    fn complete_pickup_order_not_ready_for_pickup() {
+        let mut order = order_with_fulfilment_and_state(
+            Fulfilment::Pickup(rnd_pickup_point_id()),
+            OrderState::new_confirmed(),
+        );
+        assert_eq!(order.complete().unwrap_err(), InvalidState);
+        assert!(matches!(order.state(), Confirmed(_)));
+        assert!(order.pop_events().is_empty());
+    }
+
+    #[test]
+    fn mark_ready_for_pickup_success() {
+        let mut order = order_with_fulfilment_and_state(
+            Fulfilment::Pickup(PickupPointId::try_from(1).unwrap()),
+            OrderState::new_confirmed(),
+        );
+        assert!(order.mark_ready_for_pickup().is_ok());
+        assert!(matches!(order.state(), ReadyForPickup(_)));
+        let event: Vec<ShopOrderReadyForPickupDomainEvent> = order
+            .pop_events()
+            .iter()
+            .map(|it| it.clone().try_into().unwrap())
+            .collect();
+        assert_eq!(event.len(), 1);
+        assert_eq!(&event.first().unwrap().order_id, order.id());
+    }
+
+    #[test]
+    fn mark_ready_for_pickup_delivery_order() {
+        let mut order = order_with_state(OrderState::new_confirmed());
+        assert_eq!(order.mark_ready_for_pickup().unwrap_err(), InvalidState);
+        assert!(matches!(order.state(), Confirmed(_)));
+        assert!(order.pop_events().is_empty());
+    }
+
+    #[test]
+    fn mark_ready_for_pickup_invalid_state() {
+        let states = [
+            OrderState::new_waiting_for_payment(),
+            OrderState::new_paid(),
+            OrderState::new_completed(),
+            OrderState::new_cancelled(),
+        ];
+
+        states.iter().for_each(|state| {
+            let mut order = order_with_fulfilment_and_state(
+                Fulfilment::Pickup(rnd_pickup_point_id()),
+                state.clone(),
+            );
+            assert_eq!(order.mark_ready_for_pickup().unwrap_err(), InvalidState);
+            assert_eq!(order.state(), state);
+            assert!(order.pop_events().is_empty())
+        });
+    }
+
     #[test]
     fn complete_order_invalid_state() {
         let states = [
@@ -573,6 +1037,78 @@ mod tests {
         }
     }
 
+    #[derive(new, Default, Debug)]
+    struct MockMealAvailableAt {
+        unavailable: Option<MealId>,
+        #[new(default)]
+        at: Option<OffsetDateTime>,
+    }
+
+    #[async_trait]
+    impl MealAvailableAt for MockMealAvailableAt {
+        async fn invoke(&mut self, meal_id: &MealId, at: &OffsetDateTime) -> bool {
+            self.at = Some(*at);
+            self.unavailable != Some(*meal_id)
+        }
+    }
+
+    #[derive(new, Default, Debug)]
+    struct MockReserveMealPortions {
+        unavailable: Option<MealId>,
+        #[new(default)]
+        meals: Option<HashMap<MealId, Count>>,
+    }
+
+    #[async_trait]
+    impl ReserveMealPortions for MockReserveMealPortions {
+        async fn invoke(&mut self, meals: &HashMap<MealId, Count>) -> Result<(), MealUnavailable> {
+            self.meals = Some(meals.clone());
+            match self.unavailable {
+                Some(meal_id) => Err(MealUnavailable(meal_id)),
+                None => Ok(()),
+            }
+        }
+    }
+
+    #[derive(new, Debug)]
+    struct FixedDeliveryFee {
+        fee: Price,
+    }
+
+    #[async_trait]
+    impl GetDeliveryFee for FixedDeliveryFee {
+        async fn invoke(&self, _delivery_to: &Address) -> Price {
+            self.fee.clone()
+        }
+    }
+
+    #[derive(new, Debug)]
+    struct MockCheckDeliverySlot {
+        result: Result<(), DeliverySlotError>,
+        #[new(default)]
+        slot: Option<DeliverySlot>,
+    }
+
+    #[async_trait]
+    impl CheckDeliverySlot for MockCheckDeliverySlot {
+        async fn invoke(&mut self, slot: &DeliverySlot) -> Result<(), DeliverySlotError> {
+            self.slot = Some(*slot);
+            self.result
+        }
+    }
+
+    #[derive(new, Debug)]
+    struct MockAddressInDeliveryZone {
+        inside: bool,
+    }
+
+    #[async_trait]
+    impl AddressInDeliveryZone for MockAddressInDeliveryZone {
+        async fn invoke(&mut self, _address: &Address) -> bool {
+            self.inside
+        }
+    }
+
     #[derive(SmartDefault, Debug)]
     struct MockOrderIdGenerator {
         #[default(rnd_order_id())]
@@ -1,27 +1,30 @@
 use std::collections::HashSet;
 
-use common::types::{
-    base::{DomainEntity, Version},
-    common::Address,
-};
+use common::types::base::{DomainEntity, Version};
 use time::OffsetDateTime;
 
 use crate::{
     cart::value_objects::customer_id::CustomerId,
+    menu::value_objects::price::Price,
     order::{
         shop_order::{OrderItem, OrderState, ShopOrder},
-        value_objects::shop_order_id::ShopOrderId,
+        value_objects::{
+            delivery_slot::DeliverySlot, fulfilment::Fulfilment, shop_order_id::ShopOrderId,
+        },
     },
 };
 
 pub struct ShopOrderRestorer {}
 
 impl ShopOrderRestorer {
+    #[allow(clippy::too_many_arguments)]
     pub fn restore_order(
         id: ShopOrderId,
         created: OffsetDateTime,
         for_customer: CustomerId,
-        address: Address,
+        fulfilment: Fulfilment,
+        delivery_fee: Price,
+        delivery_slot: Option<DeliverySlot>,
         order_items: HashSet<OrderItem>,
         state: OrderState,
         version: Version,
@@ -30,7 +33,9 @@ impl ShopOrderRestorer {
             entity_params: DomainEntity::new(id, version),
             created,
             for_customer,
-            address,
+            fulfilment,
+            delivery_fee,
+            delivery_slot,
             order_items,
             state,
         }
@@ -40,7 +45,10 @@ impl ShopOrderRestorer {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::test_fixtures::{rnd_address, rnd_customer_id, rnd_order_id, rnd_order_item};
+    use crate::test_fixtures::{
+        rnd_customer_id, rnd_delivery_slot, rnd_order_id, rnd_order_item, rnd_pickup_point_id,
+        rnd_price,
+    };
 
     #[test]
     fn restore_user_success() {
@@ -51,13 +59,17 @@ mod tests {
         let items = HashSet::from([item.clone()]);
         let state = OrderState::new_completed();
         let version = Version::default();
-        let address = rnd_address();
+        let fulfilment = Fulfilment::Pickup(rnd_pickup_point_id());
+        let delivery_fee = rnd_price();
+        let delivery_slot = Some(rnd_delivery_slot());
 
         let mut order = ShopOrderRestorer::restore_order(
             id,
             created,
             customer_id,
-            address.clone(),
+            fulfilment.clone(),
+            delivery_fee.clone(),
+            delivery_slot,
             items.clone(),
             state.clone(),
             version,
@@ -66,7 +78,9 @@ mod tests {
         assert_eq!(order.id(), &id);
         assert_eq!(order.created(), &created);
         assert_eq!(order.for_customer(), &customer_id);
-        assert_eq!(order.address(), &address);
+        assert_eq!(order.fulfilment(), &fulfilment);
+        assert_eq!(order.delivery_fee(), &delivery_fee);
+        assert_eq!(order.delivery_slot(), &delivery_slot);
         assert_eq!(order.order_items().len(), 1);
         let order_item = order.order_items().iter().next().unwrap().clone();
         assert_eq!(order_item.price, item.price);
@@ -0,0 +1,74 @@
+use common::types::{base::ValueObject, errors::BusinessError};
+use serde_derive::{Deserialize, Serialize};
+use time::OffsetDateTime;
+
+/// Time window in which a scheduled order has to be delivered
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, Ord, PartialOrd)]
+#[non_exhaustive]
+pub struct DeliverySlot {
+    start: OffsetDateTime,
+    end: OffsetDateTime,
+}
+
+impl DeliverySlot {
+    pub fn start(&self) -> &OffsetDateTime {
+        &self.start
+    }
+
+    pub fn end(&self) -> &OffsetDateTime {
+        &self.end
+    }
+
+    pub fn has_started(&self) -> bool {
+        self.start <= OffsetDateTime::now_utc()
+    }
+}
+
+impl TryFrom<(OffsetDateTime, OffsetDateTime)> for DeliverySlot {
+    type Error = CreateDeliverySlotError;
+
+    fn try_from((start, end): (OffsetDateTime, OffsetDateTime)) -> Result<Self, Self::Error> {
+        if start < end {
+            Ok(Self { start, end })
+        } else {
+            Err(Self::Error::EndIsNotAfterStart)
+        }
+    }
+}
+
+impl ValueObject for DeliverySlot {}
+
+#[derive(Debug, PartialEq)]
+pub enum CreateDeliverySlotError {
+    EndIsNotAfterStart,
+}
+
+impl BusinessError for CreateDeliverySlotError {}
+
+#[cfg(test)]
+mod tests {
+    use time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn create_slot_success() {
+        let start = OffsetDateTime::now_utc();
+        let end = start + Duration::minutes(30);
+
+        let slot = DeliverySlot::try_from((start, end)).unwrap();
+
+        assert_eq!(slot.start(), &start);
+        assert_eq!(slot.end(), &end);
+        assert!(slot.has_started());
+    }
+
+    #[test]
+    fn end_is_not_after_start() {
+        let start = OffsetDateTime::now_utc();
+
+        let result = DeliverySlot::try_from((start, start - Duration::minutes(30)));
+
+        assert_eq!(result, Err(CreateDeliverySlotError::EndIsNotAfterStart));
+    }
+}
@@ -0,0 +1,132 @@
+use common::types::{base::ValueObject, common::GeoLocation, errors::BusinessError};
+
+/// Area the shop delivers to
+#[derive(Debug, Clone, PartialEq)]
+#[non_exhaustive]
+pub enum DeliveryZone {
+    Radius {
+        center: GeoLocation,
+        radius_meters: f64,
+    },
+    /// Vertices in order, the last one is connected to the first
+    Polygon(Vec<GeoLocation>),
+}
+
+impl DeliveryZone {
+    pub fn radius(
+        center: GeoLocation,
+        radius_meters: f64,
+    ) -> Result<Self, CreateDeliveryZoneError> {
+        if radius_meters.is_finite() && radius_meters > 0.0 {
+            Ok(Self::Radius {
+                center,
+                radius_meters,
+            })
+        } else {
+            Err(CreateDeliveryZoneError::NonPositiveRadius)
+        }
+    }
+
+    pub fn polygon(vertices: Vec<GeoLocation>) -> Result<Self, CreateDeliveryZoneError> {
+        if vertices.len() >= 3 {
+            Ok(Self::Polygon(vertices))
+        } else {
+            Err(CreateDeliveryZoneError::TooFewVertices)
+        }
+    }
+
+    pub fn contains(&self, location: &GeoLocation) -> bool {
+        match self {
+            Self::Radius {
+                center,
+                radius_meters,
+            } => center.distance_meters_to(location) <= *radius_meters,
+            Self::Polygon(vertices) => polygon_contains(vertices, location),
+        }
+    }
+}
+
+/// Ray casting on plain lat/lon, good enough for city sized zones
+fn polygon_contains(vertices: &[GeoLocation], location: &GeoLocation) -> bool {
+    let (x, y) = (location.longitude_to_f64(), location.latitude_to_f64());
+    let mut inside = false;
+    let mut j = vertices.len() - 1;
+    for i in 0..vertices.len() {
+        let (xi, yi) = (
+            vertices[i].longitude_to_f64(),
+            vertices[i].latitude_to_f64(),
+        );
+        let (xj, yj) = (
+            vertices[j].longitude_to_f64(),
+            vertices[j].latitude_to_f64(),
+        );
+        if (yi > y) != (yj > y) && x < (xj - xi) * (y - yi) / (yj - yi) + xi {
+            inside = !inside;
+        }
+        j = i;
+    }
+    inside
+}
+
+impl ValueObject for DeliveryZone {}
+
+#[derive(Debug, PartialEq)]
+pub enum CreateDeliveryZoneError {
+    NonPositiveRadius,
+    TooFewVertices,
+}
+
+impl BusinessError for CreateDeliveryZoneError {}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    fn location(latitude: f64, longitude: f64) -> GeoLocation {
+        GeoLocation::try_from((latitude, longitude)).unwrap()
+    }
+
+    fn square() -> DeliveryZone {
+        DeliveryZone::polygon(vec![
+            location(52.0, 13.0),
+            location(52.0, 14.0),
+            location(53.0, 14.0),
+            location(53.0, 13.0),
+        ])
+        .unwrap()
+    }
+
+    #[test]
+    fn radius_contains() {
+        let zone = DeliveryZone::radius(location(52.52, 13.405), 5_000.0).unwrap();
+
+        assert!(zone.contains(&location(52.52, 13.405)));
+        assert!(zone.contains(&location(52.54, 13.42)));
+        assert!(!zone.contains(&location(52.40, 13.05)));
+    }
+
+    #[test]
+    fn polygon_contains() {
+        let zone = square();
+
+        assert!(zone.contains(&location(52.5, 13.5)));
+        assert!(!zone.contains(&location(51.5, 13.5)));
+        assert!(!zone.contains(&location(52.5, 14.5)));
+    }
+
+    #[rstest]
+    fn non_positive_radius(#[values(0.0, -1.0, f64::NAN, f64::INFINITY)] radius: f64) {
+        let result = DeliveryZone::radius(location(52.52, 13.405), radius);
+
+        assert_eq!(result, Err(CreateDeliveryZoneError::NonPositiveRadius));
+    }
+
+    #[test]
+    fn too_few_vertices() {
+        let result = DeliveryZone::polygon(vec![location(52.0, 13.0), location(53.0, 14.0)]);
+
+        assert_eq!(result, Err(CreateDeliveryZoneError::TooFewVertices));
+    }
+}
@@ -0,0 +1,71 @@
+use common::types::{base::ValueObject, common::Address};
+use serde_derive::{Deserialize, Serialize};
+use smart_default::SmartDefault;
+
+use crate::order::value_objects::pickup_point_id::PickupPointId;
+
+/// The way an order is handed over to the customer
+#[derive(Debug, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, SmartDefault)]
+pub enum Fulfilment {
+    /// Courier delivery to the address
+    #[default]
+    Delivery(Address),
+    /// Customer collects the order at the pickup point
+    Pickup(PickupPointId),
+}
+
+impl Fulfilment {
+    pub fn is_delivery(&self) -> bool {
+        matches!(self, Fulfilment::Delivery(_))
+    }
+
+    pub fn is_pickup(&self) -> bool {
+        matches!(self, Fulfilment::Pickup(_))
+    }
+
+    /// Delivery address, absent for pickup orders
+    pub fn address(&self) -> Option<&Address> {
+        match self {
+            Fulfilment::Delivery(address) => Some(address),
+            Fulfilment::Pickup(_) => None,
+        }
+    }
+
+    /// Pickup point, absent for delivery orders
+    pub fn pickup_point_id(&self) -> Option<&PickupPointId> {
+        match self {
+            Fulfilment::Delivery(_) => None,
+            Fulfilment::Pickup(pickup_point_id) => Some(pickup_point_id),
+        }
+    }
+}
+
+impl ValueObject for Fulfilment {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{rnd_address, rnd_pickup_point_id};
+
+    #[test]
+    fn delivery() {
+        let address = rnd_address();
+        let fulfilment = Fulfilment::Delivery(address.clone());
+
+        assert!(fulfilment.is_delivery());
+        assert!(!fulfilment.is_pickup());
+        assert_eq!(fulfilment.address(), Some(&address));
+        assert_eq!(fulfilment.pickup_point_id(), None);
+    }
+
+    #[test]
+    fn pickup() {
+        let pickup_point_id = rnd_pickup_point_id();
+        let fulfilment = Fulfilment::Pickup(pickup_point_id);
+
+        assert!(fulfilment.is_pickup());
+        assert!(!fulfilment.is_delivery());
+        assert_eq!(fulfilment.address(), None);
+        assert_eq!(fulfilment.pickup_point_id(), Some(&pickup_point_id));
+    }
+}
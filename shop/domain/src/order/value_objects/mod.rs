@@ -1 +1,6 @@
+pub mod delivery_slot;
+pub mod delivery_zone;
+pub mod fulfilment;
+pub mod opening_hours;
+pub mod pickup_point_id;
 pub mod shop_order_id;
@@ -0,0 +1,162 @@
+use common::types::{base::ValueObject, errors::BusinessError};
+use time::{Date, Duration, Time, UtcOffset};
+
+use crate::order::value_objects::delivery_slot::DeliverySlot;
+
+/// Daily delivery hours (UTC) split into slots of the same length
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub struct OpeningHours {
+    opens: Time,
+    closes: Time,
+    slot_length: Duration,
+}
+
+impl OpeningHours {
+    pub fn opens(&self) -> &Time {
+        &self.opens
+    }
+
+    pub fn closes(&self) -> &Time {
+        &self.closes
+    }
+
+    pub fn slot_length(&self) -> &Duration {
+        &self.slot_length
+    }
+
+    /// All slots of the day, the last one ends not later than closing time
+    pub fn slots_on(&self, date: Date) -> Vec<DeliverySlot> {
+        let closes = date.with_time(self.closes).assume_utc();
+        let mut start = date.with_time(self.opens).assume_utc();
+        let mut slots = vec![];
+        while start + self.slot_length <= closes {
+            let end = start + self.slot_length;
+            slots.push(DeliverySlot::try_from((start, end)).unwrap());
+            start = end;
+        }
+        slots
+    }
+
+    pub fn has_slot(&self, slot: &DeliverySlot) -> bool {
+        let date = slot.start().to_offset(UtcOffset::UTC).date();
+        self.slots_on(date).contains(slot)
+    }
+}
+
+impl TryFrom<(Time, Time, Duration)> for OpeningHours {
+    type Error = CreateOpeningHoursError;
+
+    fn try_from((opens, closes, slot_length): (Time, Time, Duration)) -> Result<Self, Self::Error> {
+        if opens >= closes {
+            Err(Self::Error::ClosesBeforeOpens)
+        } else if !slot_length.is_positive() {
+            Err(Self::Error::NonPositiveSlotLength)
+        } else if slot_length > closes - opens {
+            Err(Self::Error::SlotLongerThanOpeningHours)
+        } else {
+            Ok(Self {
+                opens,
+                closes,
+                slot_length,
+            })
+        }
+    }
+}
+
+impl ValueObject for OpeningHours {}
+
+#[derive(Debug, PartialEq)]
+pub enum CreateOpeningHoursError {
+    ClosesBeforeOpens,
+    NonPositiveSlotLength,
+    SlotLongerThanOpeningHours,
+}
+
+impl BusinessError for CreateOpeningHoursError {}
+
+#[cfg(test)]
+mod tests {
+    use time::Month;
+
+    use super::*;
+
+    fn hm(hour: u8, minute: u8) -> Time {
+        Time::from_hms(hour, minute, 0).unwrap()
+    }
+
+    fn opening_hours() -> OpeningHours {
+        OpeningHours::try_from((hm(10, 0), hm(12, 0), Duration::minutes(45))).unwrap()
+    }
+
+    fn date() -> Date {
+        Date::from_calendar_date(2030, Month::March, 15).unwrap()
+    }
+
+    fn slot(date: Date, start: Time, end: Time) -> DeliverySlot {
+        DeliverySlot::try_from((
+            date.with_time(start).assume_utc(),
+            date.with_time(end).assume_utc(),
+        ))
+        .unwrap()
+    }
+
+    #[test]
+    fn slots_fit_into_opening_hours() {
+        let slots = opening_hours().slots_on(date());
+
+        assert_eq!(
+            slots,
+            vec![
+                slot(date(), hm(10, 0), hm(10, 45)),
+                slot(date(), hm(10, 45), hm(11, 30)),
+            ]
+        );
+    }
+
+    #[test]
+    fn has_slot() {
+        let opening_hours = opening_hours();
+
+        assert!(opening_hours.has_slot(&slot(date(), hm(10, 45), hm(11, 30))));
+        assert!(!opening_hours.has_slot(&slot(date(), hm(10, 30), hm(11, 15))));
+        assert!(!opening_hours.has_slot(&slot(date(), hm(11, 30), hm(12, 15))));
+    }
+
+    #[test]
+    fn has_slot_in_another_offset() {
+        let offset = UtcOffset::from_hms(3, 0, 0).unwrap();
+        let utc_slot = slot(date(), hm(10, 0), hm(10, 45));
+        let local_slot = DeliverySlot::try_from((
+            utc_slot.start().to_offset(offset),
+            utc_slot.end().to_offset(offset),
+        ))
+        .unwrap();
+
+        assert!(opening_hours().has_slot(&local_slot));
+    }
+
+    #[test]
+    fn closes_before_opens() {
+        let result = OpeningHours::try_from((hm(12, 0), hm(10, 0), Duration::minutes(30)));
+
+        assert_eq!(result, Err(CreateOpeningHoursError::ClosesBeforeOpens));
+    }
+
+    #[test]
+    fn non_positive_slot_length() {
+        let result = OpeningHours::try_from((hm(10, 0), hm(12, 0), Duration::ZERO));
+
+        assert_eq!(result, Err(CreateOpeningHoursError::NonPositiveSlotLength));
+    }
+
+    #[test]
+    fn slot_longer_than_opening_hours() {
+        let result = OpeningHours::try_from((hm(10, 0), hm(12, 0), Duration::hours(3)));
+
+        assert_eq!(
+            result,
+            Err(CreateOpeningHoursError::SlotLongerThanOpeningHours)
+        );
+    }
+}
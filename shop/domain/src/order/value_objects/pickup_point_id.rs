@@ -0,0 +1,58 @@
+use serde_derive::{Deserialize, Serialize};
+
+#[derive(
+    Debug, Copy, Clone, PartialEq, Serialize, Deserialize, Hash, Eq, Default, Ord, PartialOrd,
+)]
+#[non_exhaustive]
+pub struct PickupPointId(i64);
+
+impl PickupPointId {
+    pub fn to_i64(&self) -> i64 {
+        self.0
+    }
+}
+
+impl TryFrom<i64> for PickupPointId {
+    type Error = PickupPointIdError;
+
+    fn try_from(value: i64) -> Result<Self, Self::Error> {
+        match value {
+            0..=i64::MAX => Ok(Self(value)),
+            _ => Err(Self::Error::NegativeValue),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum PickupPointIdError {
+    NegativeValue,
+}
+
+#[cfg(test)]
+mod tests {
+    use rand::random_range;
+
+    use super::*;
+
+    #[test]
+    fn check_equality() {
+        let id: i64 = random_range(0..i64::MAX);
+
+        let pickup_point_id_1 = PickupPointId::try_from(id).unwrap();
+        let pickup_point_id_2 = PickupPointId::try_from(id).unwrap();
+        assert_eq!(pickup_point_id_1, pickup_point_id_2);
+        assert_eq!(pickup_point_id_1.to_i64(), id);
+    }
+
+    #[test]
+    fn wrong_id_value() {
+        let id = random_range(i64::MIN..0);
+
+        let pickup_point_id = PickupPointId::try_from(id);
+
+        assert_eq!(
+            pickup_point_id.unwrap_err(),
+            PickupPointIdError::NegativeValue
+        );
+    }
+}
@@ -12,36 +12,89 @@ use common::{
 use derive_new::new;
 use fake::{
     Fake,
-    faker::{address::en::StreetName, name::raw::*},
+    faker::{
+        address::en::{CityName, StreetName, ZipCode},
+        name::raw::*,
+    },
     locales::*,
 };
 use rand::random_range;
 use serde::{Deserialize, Serialize};
-use time::OffsetDateTime;
+use time::{Duration, OffsetDateTime, Time, Weekday};
 
 use crate::{
     cart::{
         cart::Cart,
         cart_restorer::CartRestorer,
-        value_objects::{cart_id::CartId, customer_id::CustomerId},
+        value_objects::{
+            cart_id::CartId,
+            customer_id::{CustomerId, CustomerIdGenerator},
+        },
+    },
+    customer::{
+        customer::Customer,
+        customer_already_exists::CustomerAlreadyExists,
+        customer_restorer::CustomerRestorer,
+        value_objects::{
+            customer_name::CustomerName, customer_preferences::CustomerPreferences,
+            phone_number::PhoneNumber,
+        },
     },
+    inventory::{meal_stock::MealStock, meal_stock_restorer::MealStockRestorer},
     menu::{
         meal::Meal,
         meal_already_exists::MealAlreadyExists,
         meal_restorer::MealRestorer,
         value_objects::{
-            meal_description::MealDescription, meal_id::MealId, meal_name::MealName, price::Price,
+            allergens::{Allergen, Allergens},
+            availability_schedule::{AvailabilitySchedule, AvailabilityWindow},
+            meal_description::MealDescription,
+            meal_id::MealId,
+            meal_name::MealName,
+            nutrition_facts::NutritionFacts,
+            price::Price,
         },
     },
     order::{
         shop_order::{OrderItem, OrderState, ShopOrder},
-        value_objects::shop_order_id::ShopOrderId,
+        value_objects::{
+            delivery_slot::DeliverySlot, fulfilment::Fulfilment, pickup_point_id::PickupPointId,
+            shop_order_id::ShopOrderId,
+        },
+    },
+    webhook::{
+        value_objects::{
+            webhook_event_type::WebhookEventType,
+            webhook_secret::WebhookSecret,
+            webhook_subscription_id::{WebhookSubscriptionId, WebhookSubscriptionIdGenerator},
+            webhook_url::WebhookUrl,
+        },
+        webhook_subscription::WebhookSubscription,
+        webhook_subscription_restorer::WebhookSubscriptionRestorer,
     },
 };
 
 pub fn rnd_address() -> Address {
-    Address::try_from((&*StreetName().fake::<String>(), random_range(0..i16::MAX)))
-        .expect("Address should be right")
+    Address::try_from((
+        &*StreetName().fake::<String>(),
+        random_range(1..i16::MAX),
+        &*CityName().fake::<String>(),
+        &*ZipCode().fake::<String>(),
+    ))
+    .expect("Address should be right")
+}
+
+pub fn rnd_pickup_point_id() -> PickupPointId {
+    PickupPointId::try_from(random_range(0..i64::MAX)).unwrap()
+}
+
+pub fn rnd_fulfilment() -> Fulfilment {
+    Fulfilment::Delivery(rnd_address())
+}
+
+pub fn rnd_delivery_slot() -> DeliverySlot {
+    let start = OffsetDateTime::now_utc() + Duration::hours(random_range(1..240));
+    DeliverySlot::try_from((start, start + Duration::minutes(30))).unwrap()
 }
 
 pub fn rnd_meal_id() -> MealId {
@@ -64,6 +117,48 @@ pub fn rnd_price() -> Price {
     Price::try_from(price.to_bigdecimal().with_scale(Price::SCALE)).unwrap()
 }
 
+pub fn rnd_allergens() -> Allergens {
+    Allergen::ALL
+        .into_iter()
+        .filter(|_| random_range(0..4) == 0)
+        .collect()
+}
+
+pub fn rnd_nutrition_facts() -> NutritionFacts {
+    let grams = || BigDecimal::from(random_range(0..1000)) / BigDecimal::from(10);
+    NutritionFacts::try_new(random_range(0..2000), grams(), grams(), grams()).unwrap()
+}
+
+/// One window of a few hours on random days
+pub fn rnd_availability_schedule() -> AvailabilitySchedule {
+    let mut weekday = Weekday::Monday.nth_next(random_range(0..7));
+    let days = (0..random_range(1..7))
+        .map(|_| {
+            weekday = weekday.next();
+            weekday
+        })
+        .collect();
+    let from = random_range(0..20);
+    let window = AvailabilityWindow::try_new(
+        days,
+        Time::from_hms(from, 0, 0).unwrap(),
+        Time::from_hms(from + random_range(1..4), 0, 0).unwrap(),
+    )
+    .unwrap();
+    let time_zone = ["UTC", "Europe/Berlin", "Asia/Tokyo", "America/New_York"][random_range(0..4)];
+    AvailabilitySchedule::try_new(time_zone, vec![window]).unwrap()
+}
+
+/// Open all day except on the day of `at` in UTC
+pub fn availability_schedule_closed_at(at: &OffsetDateTime) -> AvailabilitySchedule {
+    let closed_on = at.to_offset(time::UtcOffset::UTC).weekday();
+    let days = (1..7).map(|n| closed_on.nth_next(n)).collect();
+    let window =
+        AvailabilityWindow::try_new(days, Time::MIDNIGHT, Time::from_hms(23, 59, 59).unwrap())
+            .unwrap();
+    AvailabilitySchedule::try_new("UTC", vec![window]).unwrap()
+}
+
 pub fn version() -> Version {
     Version::default()
 }
@@ -74,6 +169,9 @@ pub fn rnd_meal() -> Meal {
         &rnd_meal_name(),
         &rnd_meal_description(),
         &rnd_price(),
+        &rnd_allergens(),
+        &rnd_nutrition_facts(),
+        &None,
         false,
         &Version::default(),
         vec![],
@@ -86,12 +184,19 @@ pub fn rnd_removed_meal() -> Meal {
         &rnd_meal_name(),
         &rnd_meal_description(),
         &rnd_price(),
+        &rnd_allergens(),
+        &rnd_nutrition_facts(),
+        &None,
         true,
         &Version::default(),
         vec![],
     )
 }
 
+pub fn rnd_meal_stock(available: Count) -> MealStock {
+    MealStockRestorer::restore_meal_stock(&rnd_meal_id(), &available, &Version::default(), vec![])
+}
+
 #[derive(new, Debug, Clone, PartialEq, Serialize, Deserialize, Hash, Eq)]
 pub struct TestEvent {}
 
@@ -101,6 +206,75 @@ pub fn rnd_customer_id() -> CustomerId {
     CustomerId::new()
 }
 
+pub fn rnd_customer_name() -> CustomerName {
+    CustomerName::try_from(Name(EN).fake::<String>().as_str()).unwrap()
+}
+
+pub fn rnd_phone_number() -> PhoneNumber {
+    PhoneNumber::try_from(
+        format!(
+            "+{}",
+            random_range(1_000_000_000_u64..1_000_000_000_000_000)
+        )
+        .as_str(),
+    )
+    .unwrap()
+}
+
+pub fn rnd_customer() -> Customer {
+    CustomerRestorer::restore_customer(
+        rnd_customer_id(),
+        rnd_customer_name(),
+        rnd_phone_number(),
+        vec![],
+        CustomerPreferences::default(),
+        false,
+        version(),
+    )
+}
+
+pub fn rnd_removed_customer() -> Customer {
+    CustomerRestorer::restore_customer(
+        rnd_customer_id(),
+        rnd_customer_name(),
+        rnd_phone_number(),
+        vec![],
+        CustomerPreferences::default(),
+        true,
+        version(),
+    )
+}
+
+pub fn rnd_webhook_subscription_id() -> WebhookSubscriptionId {
+    WebhookSubscriptionId::new()
+}
+
+pub fn rnd_webhook_url() -> WebhookUrl {
+    WebhookUrl::try_from(
+        format!(
+            "https://partner-{}.example.com/webhooks",
+            random_range(0..u32::MAX)
+        )
+        .as_str(),
+    )
+    .unwrap()
+}
+
+pub fn rnd_webhook_secret() -> WebhookSecret {
+    WebhookSecret::try_from(format!("{:032x}", random_range(0..u128::MAX)).as_str()).unwrap()
+}
+
+pub fn rnd_webhook_subscription(event_types: Vec<WebhookEventType>) -> WebhookSubscription {
+    WebhookSubscriptionRestorer::restore_webhook_subscription(
+        rnd_webhook_subscription_id(),
+        rnd_webhook_url(),
+        rnd_webhook_secret(),
+        event_types,
+        false,
+        version(),
+    )
+}
+
 pub fn rnd_cart_id() -> CartId {
     CartId::try_from(random_range(0..i64::MAX)).unwrap()
 }
@@ -151,7 +325,9 @@ pub fn rnd_order(order_items: HashSet<OrderItem>) -> ShopOrder {
         DomainEntity::new(rnd_order_id(), Default::default()),
         OffsetDateTime::now_utc(),
         rnd_customer_id(),
-        rnd_address(),
+        Fulfilment::Delivery(rnd_address()),
+        Price::zero(),
+        None,
         order_items,
         OrderState::new_completed(),
     )
@@ -162,7 +338,9 @@ pub fn rnd_order_with_customer_id(customer_id: CustomerId) -> ShopOrder {
         DomainEntity::new(rnd_order_id(), Default::default()),
         OffsetDateTime::now_utc(),
         customer_id,
-        rnd_address(),
+        Fulfilment::Delivery(rnd_address()),
+        Price::zero(),
+        None,
         [rnd_order_item()].into(),
         OrderState::new_completed(),
     )
@@ -173,18 +351,42 @@ pub fn rnd_order_with_id(id: ShopOrderId) -> ShopOrder {
         DomainEntity::new(id, Default::default()),
         OffsetDateTime::now_utc(),
         rnd_customer_id(),
-        rnd_address(),
+        Fulfilment::Delivery(rnd_address()),
+        Price::zero(),
+        None,
         [rnd_order_item()].into(),
         OrderState::new_completed(),
     )
 }
 
 pub fn order_with_state(state: OrderState) -> ShopOrder {
+    order_with_fulfilment_and_state(Fulfilment::Delivery(rnd_address()), state)
+}
+
+pub fn order_with_delivery_slot_and_state(
+    delivery_slot: DeliverySlot,
+    state: OrderState,
+) -> ShopOrder {
+    ShopOrder::new(
+        DomainEntity::new(rnd_order_id(), Default::default()),
+        OffsetDateTime::now_utc(),
+        rnd_customer_id(),
+        Fulfilment::Delivery(rnd_address()),
+        Price::zero(),
+        Some(delivery_slot),
+        HashSet::from([rnd_order_item()]),
+        state,
+    )
+}
+
+pub fn order_with_fulfilment_and_state(fulfilment: Fulfilment, state: OrderState) -> ShopOrder {
     ShopOrder::new(
         DomainEntity::new(rnd_order_id(), Default::default()),
         OffsetDateTime::now_utc(),
         rnd_customer_id(),
-        rnd_address(),
+        fulfilment,
+        Price::zero(),
+        None,
         HashSet::from([rnd_order_item()]),
         state,
     )
@@ -202,3 +404,40 @@ impl MealAlreadyExists for TestMealAlreadyExists {
         self.value
     }
 }
+
+#[derive(Debug, new, Default, Clone, Copy)]
+pub struct TestCustomerAlreadyExists {
+    #[new(value = "false")]
+    pub value: bool,
+}
+
+#[async_trait]
+impl CustomerAlreadyExists for TestCustomerAlreadyExists {
+    async fn invoke(&mut self, _phone: &PhoneNumber) -> bool {
+        self.value
+    }
+}
+
+#[derive(Debug, new, Default, Clone, Copy)]
+pub struct TestCustomerIdGenerator {
+    #[new(value = "rnd_customer_id()")]
+    pub customer_id: CustomerId,
+}
+
+impl CustomerIdGenerator for TestCustomerIdGenerator {
+    fn generate(&mut self) -> CustomerId {
+        self.customer_id
+    }
+}
+
+#[derive(Debug, new, Default, Clone, Copy)]
+pub struct TestWebhookSubscriptionIdGenerator {
+    #[new(value = "rnd_webhook_subscription_id()")]
+    pub subscription_id: WebhookSubscriptionId,
+}
+
+impl WebhookSubscriptionIdGenerator for TestWebhookSubscriptionIdGenerator {
+    fn generate(&mut self) -> WebhookSubscriptionId {
+        self.subscription_id
+    }
+}
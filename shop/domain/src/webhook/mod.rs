@@ -0,0 +1,4 @@
+pub mod value_objects;
+pub mod webhook_subscription;
+pub mod webhook_subscription_events;
+pub mod webhook_subscription_restorer;
@@ -0,0 +1,4 @@
+pub mod webhook_event_type;
+pub mod webhook_secret;
+pub mod webhook_subscription_id;
+pub mod webhook_url;
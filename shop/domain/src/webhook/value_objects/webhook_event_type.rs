@@ -0,0 +1,102 @@
+use common::types::{base::ValueObject, errors::BusinessError};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+
+use crate::order::customer_order_events::ShopOrderEventEnum;
+
+/// Order events a partner can subscribe to
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Hash, Display)]
+pub enum WebhookEventType {
+    #[display("order.created")]
+    OrderCreated,
+    #[display("order.paid")]
+    OrderPaid,
+    #[display("order.confirmed")]
+    OrderConfirmed,
+    #[display("order.ready_for_pickup")]
+    OrderReadyForPickup,
+    #[display("order.completed")]
+    OrderCompleted,
+    #[display("order.cancelled")]
+    OrderCancelled,
+}
+
+impl WebhookEventType {
+    pub const ALL: [Self; 6] = [
+        Self::OrderCreated,
+        Self::OrderPaid,
+        Self::OrderConfirmed,
+        Self::OrderReadyForPickup,
+        Self::OrderCompleted,
+        Self::OrderCancelled,
+    ];
+
+    pub fn of(event: &ShopOrderEventEnum) -> Self {
+        match event {
+            ShopOrderEventEnum::ShopOrderCreatedDomainEvent(_) => Self::OrderCreated,
+            ShopOrderEventEnum::ShopOrderPaidDomainEvent(_) => Self::OrderPaid,
+            ShopOrderEventEnum::ShopOrderConfirmedDomainEvent(_) => Self::OrderConfirmed,
+            ShopOrderEventEnum::ShopOrderReadyForPickupDomainEvent(_) => Self::OrderReadyForPickup,
+            ShopOrderEventEnum::ShopOrderCompletedDomainEvent(_) => Self::OrderCompleted,
+            ShopOrderEventEnum::ShopOrderCancelledDomainEvent(_) => Self::OrderCancelled,
+        }
+    }
+}
+
+impl TryFrom<&str> for WebhookEventType {
+    type Error = CreateWebhookEventTypeError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Self::ALL
+            .into_iter()
+            .find(|it| it.to_string() == value)
+            .ok_or(Self::Error::UnknownEventTypeError)
+    }
+}
+
+impl ValueObject for WebhookEventType {}
+
+#[derive(Debug, PartialEq)]
+pub enum CreateWebhookEventTypeError {
+    UnknownEventTypeError,
+}
+
+impl BusinessError for CreateWebhookEventTypeError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        order::customer_order_events::ShopOrderReadyForPickupDomainEvent,
+        test_fixtures::rnd_order_id,
+    };
+
+    #[test]
+    fn parse_all_event_types() {
+        for event_type in WebhookEventType::ALL {
+            let result = WebhookEventType::try_from(event_type.to_string().as_str());
+
+            assert_eq!(result, Ok(event_type));
+        }
+    }
+
+    #[test]
+    fn unknown_event_type() {
+        let result = WebhookEventType::try_from("order.eaten");
+
+        assert_eq!(
+            result,
+            Err(CreateWebhookEventTypeError::UnknownEventTypeError)
+        );
+    }
+
+    #[test]
+    fn event_type_of_order_event() {
+        let event = ShopOrderReadyForPickupDomainEvent::new(rnd_order_id()).into();
+
+        assert_eq!(
+            WebhookEventType::of(&event),
+            WebhookEventType::OrderReadyForPickup
+        );
+    }
+}
@@ -0,0 +1,85 @@
+use std::fmt::{Debug, Formatter};
+
+use common::types::{base::ValueObject, errors::BusinessError};
+use serde::{Deserialize, Serialize};
+
+/// Key the webhook payloads are signed with, shared with the partner
+#[derive(Clone, Serialize, Deserialize, PartialEq, Eq, Hash, Default)]
+#[non_exhaustive]
+pub struct WebhookSecret(String);
+
+impl WebhookSecret {
+    pub const MIN_LENGTH: usize = 16;
+
+    pub fn as_bytes(&self) -> &[u8] {
+        self.0.as_bytes()
+    }
+
+    pub fn to_string_value(&self) -> String {
+        self.0.clone()
+    }
+}
+
+/// The secret is kept out of the logs
+impl Debug for WebhookSecret {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("WebhookSecret(***)")
+    }
+}
+
+impl TryFrom<&str> for WebhookSecret {
+    type Error = CreateWebhookSecretError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        if value.chars().count() < Self::MIN_LENGTH {
+            Err(Self::Error::TooShortSecretError)
+        } else if value.chars().any(char::is_whitespace) {
+            Err(Self::Error::InvalidSecretError)
+        } else {
+            Ok(Self(value.to_string()))
+        }
+    }
+}
+
+impl ValueObject for WebhookSecret {}
+
+#[derive(Debug, PartialEq)]
+pub enum CreateWebhookSecretError {
+    TooShortSecretError,
+    InvalidSecretError,
+}
+
+impl BusinessError for CreateWebhookSecretError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn create_secret_success() {
+        let result = WebhookSecret::try_from("0123456789abcdef");
+
+        assert_eq!(result.unwrap().to_string_value(), "0123456789abcdef");
+    }
+
+    #[test]
+    fn create_secret_too_short() {
+        let result = WebhookSecret::try_from("0123456789abcde");
+
+        assert_eq!(result, Err(CreateWebhookSecretError::TooShortSecretError));
+    }
+
+    #[test]
+    fn create_secret_with_whitespace() {
+        let result = WebhookSecret::try_from("0123456789 abcdef");
+
+        assert_eq!(result, Err(CreateWebhookSecretError::InvalidSecretError));
+    }
+
+    #[test]
+    fn secret_is_not_printed() {
+        let secret = WebhookSecret::try_from("0123456789abcdef").unwrap();
+
+        assert_eq!(format!("{secret:?}"), "WebhookSecret(***)");
+    }
+}
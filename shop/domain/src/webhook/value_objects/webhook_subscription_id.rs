@@ -0,0 +1,67 @@
+use std::fmt::Debug;
+
+use common::types::base::ValueObject;
+use derive_more::Display;
+use derive_new::new;
+use serde_derive::{Deserialize, Serialize};
+use uuid::Uuid;
+
+#[derive(
+    new, Debug, Clone, Deserialize, Serialize, PartialEq, Default, Eq, Hash, Copy, Display,
+)]
+#[non_exhaustive]
+pub struct WebhookSubscriptionId(#[new(value = "Uuid::new_v4()")] Uuid);
+
+impl WebhookSubscriptionId {
+    pub fn to_uuid(&self) -> Uuid {
+        self.0
+    }
+}
+
+pub trait WebhookSubscriptionIdGenerator: Debug + Send {
+    fn generate(&mut self) -> WebhookSubscriptionId;
+}
+
+impl From<Uuid> for WebhookSubscriptionId {
+    fn from(value: Uuid) -> Self {
+        Self(value)
+    }
+}
+
+impl TryFrom<&str> for WebhookSubscriptionId {
+    type Error = WebhookSubscriptionIdError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        Uuid::parse_str(value)
+            .map(Self)
+            .map_err(|_| Self::Error::InvalidIdError)
+    }
+}
+
+impl ValueObject for WebhookSubscriptionId {}
+
+#[derive(Debug, PartialEq)]
+pub enum WebhookSubscriptionIdError {
+    InvalidIdError,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_id() {
+        let id = WebhookSubscriptionId::new();
+
+        let parsed = WebhookSubscriptionId::try_from(id.to_string().as_str());
+
+        assert_eq!(parsed, Ok(id));
+    }
+
+    #[test]
+    fn wrong_id_value() {
+        let parsed = WebhookSubscriptionId::try_from("not an id");
+
+        assert_eq!(parsed, Err(WebhookSubscriptionIdError::InvalidIdError));
+    }
+}
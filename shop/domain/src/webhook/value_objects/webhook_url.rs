@@ -0,0 +1,60 @@
+use common::types::{base::ValueObject, errors::BusinessError};
+use derive_more::Display;
+use serde::{Deserialize, Serialize};
+use url::Url;
+
+/// Absolute `http` or `https` address the webhooks are posted to
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash, Default, Display)]
+#[non_exhaustive]
+pub struct WebhookUrl(String);
+
+impl TryFrom<&str> for WebhookUrl {
+    type Error = CreateWebhookUrlError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match Url::parse(value.trim()) {
+            Ok(url) if matches!(url.scheme(), "http" | "https") && url.has_host() => {
+                Ok(Self(url.to_string()))
+            }
+            _ => Err(Self::Error::InvalidWebhookUrlError),
+        }
+    }
+}
+
+impl ValueObject for WebhookUrl {}
+
+#[derive(Debug, PartialEq)]
+pub enum CreateWebhookUrlError {
+    InvalidWebhookUrlError,
+}
+
+impl BusinessError for CreateWebhookUrlError {}
+
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest]
+    #[case(
+        "https://partner.example.com/hooks",
+        "https://partner.example.com/hooks"
+    )]
+    #[case(" http://127.0.0.1:8080 ", "http://127.0.0.1:8080/")]
+    fn create_url_success(#[case] input: &str, #[case] expected: &str) {
+        let result = WebhookUrl::try_from(input);
+
+        assert_eq!(result.unwrap().to_string(), expected);
+    }
+
+    #[rstest]
+    fn create_url_invalid(
+        #[values("", "partner.example.com", "ftp://partner.example.com", "mailto:a@b.c")]
+        input: &str,
+    ) {
+        let result = WebhookUrl::try_from(input);
+
+        assert_eq!(result, Err(CreateWebhookUrlError::InvalidWebhookUrlError));
+    }
+}
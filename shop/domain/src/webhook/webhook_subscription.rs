@@ -0,0 +1,215 @@
+use common::types::{
+    base::{AM, DomainEntity, DomainEntityTrait, Version},
+    errors::BusinessError,
+};
+use derive_getters::Getters;
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+
+use crate::webhook::{
+    value_objects::{
+        webhook_event_type::WebhookEventType,
+        webhook_secret::WebhookSecret,
+        webhook_subscription_id::{WebhookSubscriptionId, WebhookSubscriptionIdGenerator},
+        webhook_url::WebhookUrl,
+    },
+    webhook_subscription_events::{
+        WebhookSubscriptionCreatedDomainEvent, WebhookSubscriptionEventEnum,
+        WebhookSubscriptionRemovedDomainEvent,
+    },
+};
+
+/// Partner endpoint which is notified about the order events of the chosen types
+#[derive(new, Debug, Clone, PartialEq, Serialize, Deserialize, Getters)]
+pub struct WebhookSubscription {
+    #[getter(skip)]
+    entity_params: DomainEntity<WebhookSubscriptionId, WebhookSubscriptionEventEnum>,
+    url: WebhookUrl,
+    secret: WebhookSecret,
+    event_types: Vec<WebhookEventType>,
+    #[new(value = "false")]
+    removed: bool,
+}
+
+impl WebhookSubscription {
+    pub fn with_all_args(
+        entity_params: DomainEntity<WebhookSubscriptionId, WebhookSubscriptionEventEnum>,
+        url: WebhookUrl,
+        secret: WebhookSecret,
+        event_types: Vec<WebhookEventType>,
+        removed: bool,
+    ) -> Self {
+        Self {
+            entity_params,
+            url,
+            secret,
+            event_types,
+            removed,
+        }
+    }
+
+    /// Repeated event types are kept once
+    pub async fn subscribe(
+        id_generator: AM<dyn WebhookSubscriptionIdGenerator>,
+        url: WebhookUrl,
+        secret: WebhookSecret,
+        event_types: Vec<WebhookEventType>,
+    ) -> Result<WebhookSubscription, WebhookSubscriptionError> {
+        let mut unique_event_types: Vec<WebhookEventType> = vec![];
+        for event_type in event_types {
+            if !unique_event_types.contains(&event_type) {
+                unique_event_types.push(event_type);
+            }
+        }
+        if unique_event_types.is_empty() {
+            return Err(WebhookSubscriptionError::NoEventTypesError);
+        }
+        let id = id_generator.lock().await.generate();
+        let mut subscription = WebhookSubscription::new(
+            DomainEntity::new(id, Version::default()),
+            url,
+            secret,
+            unique_event_types,
+        );
+        subscription.add_event(WebhookSubscriptionCreatedDomainEvent::new(id).into());
+        Ok(subscription)
+    }
+
+    pub fn is_subscribed_to(&self, event_type: &WebhookEventType) -> bool {
+        self.visible() && self.event_types.contains(event_type)
+    }
+
+    pub fn visible(&self) -> bool {
+        !self.removed
+    }
+
+    pub fn remove(&mut self) {
+        if !self.removed {
+            self.removed = true;
+            self.add_event(WebhookSubscriptionRemovedDomainEvent::new(*self.id()).into())
+        }
+    }
+
+    pub fn id(&self) -> &WebhookSubscriptionId {
+        self.entity_params.id()
+    }
+
+    pub fn version(&self) -> &Version {
+        self.entity_params.version()
+    }
+
+    pub(self) fn add_event(&mut self, event: WebhookSubscriptionEventEnum) {
+        self.entity_params.add_event(event)
+    }
+
+    pub fn pop_events(&mut self) -> Vec<WebhookSubscriptionEventEnum> {
+        self.entity_params.pop_events()
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub enum WebhookSubscriptionError {
+    NoEventTypesError,
+}
+
+impl BusinessError for WebhookSubscriptionError {}
+
+#[cfg(test)]
+mod tests {
+    use common::types::base::AMTrait;
+
+    use super::*;
+    use crate::test_fixtures::{
+        TestWebhookSubscriptionIdGenerator, rnd_webhook_secret, rnd_webhook_subscription,
+        rnd_webhook_url,
+    };
+
+    #[tokio::test]
+    async fn subscribe_success() {
+        let id_generator = AM::new_am(TestWebhookSubscriptionIdGenerator::new());
+        let url = rnd_webhook_url();
+        let secret = rnd_webhook_secret();
+
+        let result = WebhookSubscription::subscribe(
+            id_generator.clone(),
+            url.clone(),
+            secret.clone(),
+            vec![
+                WebhookEventType::OrderPaid,
+                WebhookEventType::OrderCancelled,
+                WebhookEventType::OrderPaid,
+            ],
+        )
+        .await;
+
+        let mut subscription = result.unwrap();
+        let id = id_generator.lock().await.subscription_id;
+        assert_eq!(subscription.id(), &id);
+        assert_eq!(subscription.url(), &url);
+        assert_eq!(subscription.secret(), &secret);
+        assert_eq!(
+            subscription.event_types(),
+            &vec![
+                WebhookEventType::OrderPaid,
+                WebhookEventType::OrderCancelled
+            ]
+        );
+        assert!(subscription.visible());
+        let events: Vec<WebhookSubscriptionCreatedDomainEvent> = subscription
+            .pop_events()
+            .into_iter()
+            .map(|it| it.try_into().unwrap())
+            .collect();
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].subscription_id, id);
+    }
+
+    #[tokio::test]
+    async fn subscribe_without_event_types() {
+        let result = WebhookSubscription::subscribe(
+            AM::new_am(TestWebhookSubscriptionIdGenerator::new()),
+            rnd_webhook_url(),
+            rnd_webhook_secret(),
+            vec![],
+        )
+        .await;
+
+        assert_eq!(result, Err(WebhookSubscriptionError::NoEventTypesError));
+    }
+
+    #[test]
+    fn subscribed_only_to_chosen_event_types() {
+        let subscription = rnd_webhook_subscription(vec![WebhookEventType::OrderPaid]);
+
+        assert!(subscription.is_subscribed_to(&WebhookEventType::OrderPaid));
+        assert!(!subscription.is_subscribed_to(&WebhookEventType::OrderCreated));
+    }
+
+    #[test]
+    fn remove_success() {
+        let mut subscription = rnd_webhook_subscription(vec![WebhookEventType::OrderPaid]);
+
+        subscription.remove();
+
+        assert!(!subscription.visible());
+        assert!(!subscription.is_subscribed_to(&WebhookEventType::OrderPaid));
+        let events: Vec<WebhookSubscriptionRemovedDomainEvent> = subscription
+            .pop_events()
+            .into_iter()
+            .map(|it| it.try_into().unwrap())
+            .collect();
+        assert_eq!(events.len(), 1);
+        assert_eq!(&events[0].subscription_id, subscription.id());
+    }
+
+    #[test]
+    fn remove_already_removed() {
+        let mut subscription = rnd_webhook_subscription(vec![WebhookEventType::OrderPaid]);
+        subscription.remove();
+        subscription.pop_events();
+
+        subscription.remove();
+
+        assert!(subscription.pop_events().is_empty());
+    }
+}
@@ -0,0 +1,28 @@
+use common::types::base::{DomainEvent, DomainEventTrait};
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+use smart_default::SmartDefault;
+
+use crate::webhook::value_objects::webhook_subscription_id::WebhookSubscriptionId;
+
+#[derive(new, Debug, Clone, PartialEq, Serialize, Deserialize, Hash, Eq, Default)]
+pub struct WebhookSubscriptionCreatedDomainEvent {
+    #[new(value = "DomainEvent::default()")]
+    domain_event_params: DomainEvent,
+    pub subscription_id: WebhookSubscriptionId,
+}
+
+#[derive(new, Debug, Clone, PartialEq, Serialize, Deserialize, Hash, Eq, Default)]
+pub struct WebhookSubscriptionRemovedDomainEvent {
+    #[new(value = "DomainEvent::default()")]
+    domain_event_params: DomainEvent,
+    pub subscription_id: WebhookSubscriptionId,
+}
+
+#[enum_delegate::implement(DomainEventTrait)]
+#[derive(PartialEq, Debug, Clone, SmartDefault, Serialize, Deserialize, Hash, Eq)]
+pub enum WebhookSubscriptionEventEnum {
+    #[default]
+    WebhookSubscriptionCreatedDomainEvent(WebhookSubscriptionCreatedDomainEvent),
+    WebhookSubscriptionRemovedDomainEvent(WebhookSubscriptionRemovedDomainEvent),
+}
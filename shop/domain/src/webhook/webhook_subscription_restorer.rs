@@ -0,0 +1,62 @@
+use common::types::base::{DomainEntity, Version};
+
+use crate::webhook::{
+    value_objects::{
+        webhook_event_type::WebhookEventType, webhook_secret::WebhookSecret,
+        webhook_subscription_id::WebhookSubscriptionId, webhook_url::WebhookUrl,
+    },
+    webhook_subscription::WebhookSubscription,
+};
+
+pub struct WebhookSubscriptionRestorer {}
+
+impl WebhookSubscriptionRestorer {
+    pub fn restore_webhook_subscription(
+        id: WebhookSubscriptionId,
+        url: WebhookUrl,
+        secret: WebhookSecret,
+        event_types: Vec<WebhookEventType>,
+        removed: bool,
+        version: Version,
+    ) -> WebhookSubscription {
+        WebhookSubscription::with_all_args(
+            DomainEntity::new(id, version),
+            url,
+            secret,
+            event_types,
+            removed,
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_fixtures::{rnd_webhook_secret, rnd_webhook_url, version};
+
+    #[test]
+    fn restore_webhook_subscription_success() {
+        let id = WebhookSubscriptionId::new();
+        let url = rnd_webhook_url();
+        let secret = rnd_webhook_secret();
+        let event_types = vec![WebhookEventType::OrderCompleted];
+        let version = version();
+
+        let mut subscription = WebhookSubscriptionRestorer::restore_webhook_subscription(
+            id,
+            url.clone(),
+            secret.clone(),
+            event_types.clone(),
+            true,
+            version,
+        );
+
+        assert_eq!(subscription.id(), &id);
+        assert_eq!(subscription.url(), &url);
+        assert_eq!(subscription.secret(), &secret);
+        assert_eq!(subscription.event_types(), &event_types);
+        assert!(subscription.removed());
+        assert_eq!(subscription.version(), &version);
+        assert!(subscription.pop_events().is_empty());
+    }
+}
@@ -0,0 +1,109 @@
+use std::{io, path::PathBuf};
+
+use async_trait::async_trait;
+use derive_new::new;
+use domain::{
+    cart::value_objects::customer_id::CustomerId, menu::value_objects::price::Price,
+    order::value_objects::shop_order_id::ShopOrderId,
+};
+use tokio::{fs::OpenOptions, io::AsyncWriteExt};
+use usecase::order::{
+    dto::exported_order::ExportedOrder,
+    providers::order_exporter::{OrderExportError, OrderExporter},
+};
+
+/// Appends every exported order to `path` as a line of JSON for the accounting to pick up
+#[derive(new, Debug)]
+pub struct FileOrderExporter {
+    path: PathBuf,
+}
+
+#[async_trait]
+impl OrderExporter for FileOrderExporter {
+    async fn export_order(
+        &mut self,
+        id: ShopOrderId,
+        customer_id: CustomerId,
+        total_price: Price,
+    ) -> Result<(), OrderExportError> {
+        let mut line = serde_json::to_string(&ExportedOrder::new(id, customer_id, total_price))
+            .map_err(|e| OrderExportError::ExportFailed(e.to_string()))?;
+        line.push('\n');
+        self.append(line.as_bytes())
+            .await
+            .map_err(|e| OrderExportError::ExportFailed(format!("{}: {e}", self.path.display())))
+    }
+}
+
+impl FileOrderExporter {
+    async fn append(&self, line: &[u8]) -> io::Result<()> {
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .await?;
+        // A single write keeps the lines whole when several exporters share the file, the flush
+        // waits for it to finish so its error is reported
+        file.write_all(line).await?;
+        file.flush().await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{env::temp_dir, fs};
+
+    use domain::test_fixtures::*;
+    use uuid::Uuid;
+
+    use super::*;
+
+    fn path() -> PathBuf {
+        temp_dir().join(format!("{}.jsonl", Uuid::new_v4()))
+    }
+
+    #[tokio::test]
+    async fn orders_appended_as_json_lines() {
+        let path = path();
+        let mut exporter = FileOrderExporter::new(path.clone());
+        let first = (rnd_order_id(), rnd_customer_id(), rnd_price());
+        let second = (rnd_order_id(), rnd_customer_id(), rnd_price());
+
+        exporter
+            .export_order(first.0, first.1, first.2.clone())
+            .await
+            .unwrap();
+        exporter
+            .export_order(second.0, second.1, second.2.clone())
+            .await
+            .unwrap();
+
+        let content = fs::read_to_string(&path).unwrap();
+        let lines: Vec<ExportedOrder> = content
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(
+            lines,
+            vec![
+                ExportedOrder::new(first.0, first.1, first.2),
+                ExportedOrder::new(second.0, second.1, second.2),
+            ]
+        );
+        fs::remove_file(path).unwrap();
+    }
+
+    #[tokio::test]
+    async fn missing_directory() {
+        let path = temp_dir()
+            .join(Uuid::new_v4().to_string())
+            .join("orders.jsonl");
+        let mut exporter = FileOrderExporter::new(path);
+
+        let result = exporter
+            .export_order(rnd_order_id(), rnd_customer_id(), rnd_price())
+            .await;
+
+        assert!(matches!(result, Err(OrderExportError::ExportFailed(_))));
+    }
+}
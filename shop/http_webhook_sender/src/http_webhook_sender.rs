@@ -0,0 +1,192 @@
+use std::time::Duration;
+
+use async_trait::async_trait;
+use reqwest::{Client, header::CONTENT_TYPE};
+use usecase::webhook::{
+    access::webhook_sender::{WebhookSendError, WebhookSender},
+    dto::webhook_request::WebhookRequest,
+    webhook_signature::SIGNATURE_HEADER,
+};
+
+/// Header with the type of the event, e.g. `order.paid`
+pub const EVENT_HEADER: &str = "X-Webhook-Event";
+/// Header with the id of the event, stays the same across the retries so partners can dedupe
+pub const DELIVERY_HEADER: &str = "X-Webhook-Delivery";
+
+/// Posts the webhooks as JSON over HTTP
+#[derive(Debug, Clone)]
+pub struct HttpWebhookSender {
+    client: Client,
+}
+
+impl HttpWebhookSender {
+    /// A partner not answering within `timeout` counts as a failed attempt
+    pub fn new(timeout: Duration) -> Self {
+        Self {
+            client: Client::builder()
+                .timeout(timeout)
+                .build()
+                .expect("Error creating HTTP client"),
+        }
+    }
+}
+
+#[async_trait]
+impl WebhookSender for HttpWebhookSender {
+    async fn send(&self, request: &WebhookRequest) -> Result<(), WebhookSendError> {
+        let response = self
+            .client
+            .post(request.url.to_string())
+            .header(CONTENT_TYPE, "application/json")
+            .header(SIGNATURE_HEADER, &request.signature)
+            .header(EVENT_HEADER, request.event_type.to_string())
+            .header(DELIVERY_HEADER, request.event_id.to_string())
+            .body(request.payload.clone())
+            .send()
+            .await
+            .map_err(|e| WebhookSendError::RequestFailed(e.to_string()))?;
+
+        let status = response.status();
+        if status.is_success() {
+            Ok(())
+        } else {
+            Err(WebhookSendError::UnexpectedStatus(status.as_u16()))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use common::types::base::{AM, AMTrait};
+    use domain::{
+        test_fixtures::rnd_webhook_subscription_id, webhook::value_objects::webhook_url::WebhookUrl,
+    };
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+        task::JoinHandle,
+    };
+    use usecase::{
+        test_fixtures::{MockFailedWebhookDeliveryPersister, rnd_webhook_request},
+        webhook::webhook_delivery::{WebhookDelivery, WebhookRetryPolicy},
+    };
+
+    use super::*;
+
+    /// Answers the requests with `statuses` in turn and hands back what was received
+    async fn stub(statuses: Vec<&'static str>) -> (WebhookUrl, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/hooks", listener.local_addr().unwrap());
+        let handle = tokio::spawn(async move {
+            let mut requests = vec![];
+            for status in statuses {
+                let (mut socket, _) = listener.accept().await.unwrap();
+                let mut received = Vec::new();
+                let mut buf = [0; 1024];
+                while !is_complete(&received) {
+                    let n = socket.read(&mut buf).await.unwrap();
+                    if n == 0 {
+                        break;
+                    }
+                    received.extend_from_slice(&buf[..n]);
+                }
+                let response =
+                    format!("HTTP/1.1 {status}\r\nconnection: close\r\ncontent-length: 0\r\n\r\n");
+                socket.write_all(response.as_bytes()).await.unwrap();
+                requests.push(String::from_utf8(received).unwrap());
+            }
+            requests
+        });
+        (WebhookUrl::try_from(url.as_str()).unwrap(), handle)
+    }
+
+    fn is_complete(received: &[u8]) -> bool {
+        let text = String::from_utf8_lossy(received);
+        let Some((head, body)) = text.split_once("\r\n\r\n") else {
+            return false;
+        };
+        let content_length = head
+            .lines()
+            .find_map(|line| {
+                line.to_lowercase()
+                    .strip_prefix("content-length:")
+                    .map(|it| it.trim().to_string())
+            })
+            .and_then(|it| it.parse::<usize>().ok())
+            .unwrap_or(0);
+        body.len() >= content_length
+    }
+
+    fn sender() -> HttpWebhookSender {
+        HttpWebhookSender::new(Duration::from_secs(5))
+    }
+
+    #[tokio::test]
+    async fn signed_payload_posted() {
+        let (url, stub) = stub(vec!["204 No Content"]).await;
+        let mut request = rnd_webhook_request();
+        request.url = url;
+
+        let result = sender().send(&request).await;
+
+        assert_eq!(result, Ok(()));
+        let received = stub.await.unwrap().remove(0).to_lowercase();
+        assert!(received.starts_with("post /hooks http/1.1"));
+        assert!(received.contains("content-type: application/json"));
+        assert!(received.contains(&format!(
+            "x-webhook-signature: {}",
+            request.signature.to_lowercase()
+        )));
+        assert!(received.contains(&format!("x-webhook-event: {}", request.event_type)));
+        assert!(received.contains(&format!("x-webhook-delivery: {}", request.event_id)));
+        assert!(received.ends_with(&format!("\r\n\r\n{}", request.payload)));
+    }
+
+    #[tokio::test]
+    async fn error_status() {
+        let (url, stub) = stub(vec!["500 Internal Server Error"]).await;
+        let mut request = rnd_webhook_request();
+        request.url = url;
+
+        let result = sender().send(&request).await;
+
+        assert_eq!(result, Err(WebhookSendError::UnexpectedStatus(500)));
+        stub.await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn delivery_retried_until_accepted() {
+        let (url, stub) = stub(vec!["503 Service Unavailable", "200 OK"]).await;
+        let mut request = rnd_webhook_request();
+        request.url = url;
+        let persister = AM::new_am(MockFailedWebhookDeliveryPersister::new());
+        let delivery = WebhookDelivery::new(
+            Arc::new(sender()),
+            persister.clone(),
+            WebhookRetryPolicy::new(3, Duration::from_millis(1), Duration::from_millis(1)),
+        );
+
+        let delivered = delivery
+            .deliver(rnd_webhook_subscription_id(), request)
+            .await;
+
+        assert!(delivered);
+        assert_eq!(stub.await.unwrap().len(), 2);
+        persister.lock().await.verify_empty();
+    }
+
+    #[tokio::test]
+    async fn connection_refused() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/hooks", listener.local_addr().unwrap());
+        drop(listener);
+        let mut request = rnd_webhook_request();
+        request.url = WebhookUrl::try_from(url.as_str()).unwrap();
+
+        let result = sender().send(&request).await;
+
+        assert!(matches!(result, Err(WebhookSendError::RequestFailed(_))));
+    }
+}
@@ -0,0 +1,24 @@
+use derive_new::new;
+use domain::cart::value_objects::customer_id::{CustomerId, CustomerIdGenerator};
+
+#[derive(Debug, new)]
+pub struct InMemoryCustomerIdGenerator {}
+
+impl CustomerIdGenerator for InMemoryCustomerIdGenerator {
+    fn generate(&mut self) -> CustomerId {
+        CustomerId::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ids_are_unique() {
+        let mut generator = InMemoryCustomerIdGenerator::new();
+        let customer_id1 = generator.generate();
+        let customer_id2 = generator.generate();
+        assert_ne!(customer_id1, customer_id2);
+    }
+}
@@ -0,0 +1,144 @@
+use std::{collections::HashMap, fmt::Debug};
+
+use async_trait::async_trait;
+use common::{events::DomainEventPublisher, types::base::AM};
+use derivative::Derivative;
+use derive_new::new;
+use domain::{
+    cart::value_objects::customer_id::CustomerId,
+    customer::{
+        customer::Customer, customer_events::CustomerEventEnum,
+        value_objects::phone_number::PhoneNumber,
+    },
+};
+use usecase::customer::access::{
+    customer_extractor::CustomerExtractor, customer_persister::CustomerPersister,
+};
+
+#[derive(new, Clone, Derivative, Debug)]
+pub struct InMemoryCustomerRepository {
+    pub event_publisher: AM<dyn DomainEventPublisher<CustomerEventEnum>>,
+    #[new(value = "HashMap::new()")]
+    pub storage: HashMap<CustomerId, Customer>,
+}
+
+#[async_trait]
+impl CustomerPersister for InMemoryCustomerRepository {
+    async fn save(&mut self, mut customer: Customer) {
+        self.event_publisher
+            .lock()
+            .await
+            .publish(&customer.pop_events())
+            .await;
+        self.storage.insert(*customer.id(), customer);
+    }
+}
+
+impl CustomerExtractor for InMemoryCustomerRepository {
+    fn get_by_id(&mut self, id: &CustomerId) -> Option<Customer> {
+        self.storage.get(id).map(|customer| customer.to_owned())
+    }
+
+    fn get_by_phone(&mut self, phone: &PhoneNumber) -> Option<Customer> {
+        self.storage
+            .values()
+            .find(|customer| customer.visible() && customer.phone() == phone)
+            .map(|customer| customer.to_owned())
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use common::types::base::AMTrait;
+    use domain::{customer::customer_events::CustomerRemovedDomainEvent, test_fixtures::*};
+
+    use super::*;
+    use crate::test_fixtures::*;
+
+    #[tokio::test]
+    async fn saving_customer__customer_doesnt_exist() {
+        let event_publisher = AM::new_am(TestEventPublisher::new());
+        let storage_binding = event_publisher.clone();
+        let mut repository = InMemoryCustomerRepository::new(event_publisher);
+        let customer = customer_with_events();
+
+        repository.save(customer.clone()).await;
+
+        let stored_customer = repository.storage.get(customer.id()).unwrap();
+        assert_eq!(&customer, stored_customer);
+
+        let storage = &storage_binding.lock().await.storage;
+        assert_eq!(storage.len(), 1);
+
+        let event: CustomerRemovedDomainEvent =
+            storage.first().unwrap().to_owned().try_into().unwrap();
+        assert_eq!(event.customer_id, *customer.id());
+    }
+
+    #[tokio::test]
+    async fn saving_customer__customer_exists() {
+        let existing_customer = rnd_customer();
+
+        let event_publisher = AM::new_am(TestEventPublisher::new());
+        let mut repository = InMemoryCustomerRepository::new(event_publisher);
+        repository
+            .storage
+            .insert(*existing_customer.id(), existing_customer.clone());
+
+        let mut updated_customer = existing_customer.clone();
+        updated_customer.add_address(rnd_address());
+        repository.save(updated_customer.clone()).await;
+
+        assert_eq!(repository.storage.len(), 1);
+        let stored_customer = repository.storage.get(existing_customer.id()).unwrap();
+        assert_eq!(stored_customer.addresses(), updated_customer.addresses());
+    }
+
+    #[test]
+    fn get_by_id__customer_exists() {
+        let existing_customer = rnd_customer();
+        let event_publisher = AM::new_am(TestEventPublisher::new());
+        let mut repository = InMemoryCustomerRepository::new(event_publisher);
+        repository
+            .storage
+            .insert(*existing_customer.id(), existing_customer.clone());
+
+        let customer = repository.get_by_id(existing_customer.id()).unwrap();
+        assert_eq!(customer, existing_customer);
+    }
+
+    #[test]
+    fn get_by_id__customer_doesnt_exist() {
+        let event_publisher = AM::new_am(TestEventPublisher::new());
+        let mut repository = InMemoryCustomerRepository::new(event_publisher);
+        let customer = repository.get_by_id(&rnd_customer_id());
+        assert!(customer.is_none());
+    }
+
+    #[test]
+    fn get_by_phone__success() {
+        let existing_customer = rnd_customer();
+        let event_publisher = AM::new_am(TestEventPublisher::new());
+        let mut repository = InMemoryCustomerRepository::new(event_publisher);
+        repository
+            .storage
+            .insert(*existing_customer.id(), existing_customer.clone());
+
+        let customer = repository.get_by_phone(existing_customer.phone()).unwrap();
+        assert_eq!(customer, existing_customer);
+    }
+
+    #[test]
+    fn get_by_phone__removed_is_not_returned() {
+        let removed_customer = rnd_removed_customer();
+        let event_publisher = AM::new_am(TestEventPublisher::new());
+        let mut repository = InMemoryCustomerRepository::new(event_publisher);
+        repository
+            .storage
+            .insert(*removed_customer.id(), removed_customer.clone());
+
+        let customer = repository.get_by_phone(removed_customer.phone());
+        assert!(customer.is_none());
+    }
+}
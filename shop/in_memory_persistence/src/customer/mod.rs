@@ -0,0 +1,2 @@
+pub mod in_memory_customer_id_generator;
+pub mod in_memory_customer_repository;
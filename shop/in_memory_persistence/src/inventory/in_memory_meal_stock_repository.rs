@@ -0,0 +1,96 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use common::{events::DomainEventPublisher, types::base::AM};
+use derivative::Derivative;
+use derive_new::new;
+use domain::{
+    inventory::{meal_stock::MealStock, meal_stock_events::MealStockEventEnum},
+    menu::value_objects::meal_id::MealId,
+};
+use usecase::inventory::access::{
+    meal_stock_extractor::MealStockExtractor, meal_stock_persister::MealStockPersister,
+};
+
+#[derive(new, Clone, Derivative, Debug)]
+pub struct InMemoryMealStockRepository {
+    pub event_publisher: AM<dyn DomainEventPublisher<MealStockEventEnum>>,
+    #[new(value = "HashMap::new()")]
+    pub storage: HashMap<MealId, MealStock>,
+}
+
+#[async_trait]
+impl MealStockPersister for InMemoryMealStockRepository {
+    async fn save(&mut self, mut stock: MealStock) {
+        self.event_publisher
+            .lock()
+            .await
+            .publish(&stock.pop_events())
+            .await;
+        self.storage.insert(*stock.meal_id(), stock);
+    }
+}
+
+impl MealStockExtractor for InMemoryMealStockRepository {
+    fn get_by_meal_id(&mut self, meal_id: &MealId) -> Option<MealStock> {
+        self.storage.get(meal_id).cloned()
+    }
+
+    fn get_by_meal_ids(&mut self, meal_ids: &[MealId]) -> Vec<MealStock> {
+        meal_ids
+            .iter()
+            .filter_map(|meal_id| self.storage.get(meal_id).cloned())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use common::types::{base::AMTrait, common::Count};
+    use domain::{inventory::meal_stock_events::MealStockCreatedDomainEvent, test_fixtures::*};
+
+    use super::*;
+    use crate::test_fixtures::TestEventPublisher;
+
+    #[tokio::test]
+    async fn saving_stock_publishes_events() {
+        let event_publisher = AM::new_am(TestEventPublisher::new());
+        let mut repository = InMemoryMealStockRepository::new(event_publisher.clone());
+        let stock = MealStock::create(rnd_meal_id(), Count::one());
+
+        repository.save(stock.clone()).await;
+
+        assert_eq!(
+            repository
+                .get_by_meal_id(stock.meal_id())
+                .unwrap()
+                .available(),
+            &Count::one()
+        );
+        let storage = &event_publisher.lock().await.storage;
+        assert_eq!(storage.len(), 1);
+        let event: MealStockCreatedDomainEvent = storage[0].clone().try_into().unwrap();
+        assert_eq!(event.meal_id, *stock.meal_id());
+    }
+
+    #[test]
+    fn get_by_meal_id__stock_doesnt_exist() {
+        let mut repository =
+            InMemoryMealStockRepository::new(AM::new_am(TestEventPublisher::new()));
+
+        assert!(repository.get_by_meal_id(&rnd_meal_id()).is_none());
+    }
+
+    #[tokio::test]
+    async fn get_by_meal_ids__untracked_meals_skipped() {
+        let mut repository =
+            InMemoryMealStockRepository::new(AM::new_am(TestEventPublisher::new()));
+        let stock = rnd_meal_stock(Count::one());
+        repository.save(stock.clone()).await;
+
+        let stocks = repository.get_by_meal_ids(&[*stock.meal_id(), rnd_meal_id()]);
+
+        assert_eq!(stocks, vec![stock]);
+    }
+}
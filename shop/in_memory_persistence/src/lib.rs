@@ -1,7 +1,10 @@
 #![allow(special_module_name)]
 
 pub mod cart;
+pub mod customer;
+pub mod inventory;
 pub mod menu;
 pub mod order;
 #[cfg(test)]
 mod test_fixtures;
+pub mod webhook;
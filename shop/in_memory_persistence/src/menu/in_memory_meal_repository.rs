@@ -1,4 +1,4 @@
-use std::{collections::HashMap, fmt::Debug};
+use std::{cmp::Reverse, collections::HashMap, fmt::Debug};
 
 use async_trait::async_trait;
 use common::{events::DomainEventPublisher, types::base::AM};
@@ -7,9 +7,13 @@ use derive_new::new;
 use domain::menu::{
     meal::Meal,
     meal_events::MealEventEnum,
-    value_objects::{meal_id::MealId, meal_name::MealName},
+    value_objects::{allergens::Allergens, meal_id::MealId, meal_name::MealName},
+};
+use usecase::menu::access::{
+    meal_extractor::MealExtractor,
+    meal_persister::MealPersister,
+    meal_searcher::{MealSearcher, search_words},
 };
-use usecase::menu::access::{meal_extractor::MealExtractor, meal_persister::MealPersister};
 
 #[derive(new, Clone, Derivative, Debug)]
 pub struct InMemoryMealRepository {
@@ -42,12 +46,61 @@ impl MealExtractor for InMemoryMealRepository {
             .find(|value| value.name() == name)
     }
 
-    fn get_all(&mut self) -> Vec<Meal> {
+    fn get_all(
+        &mut self,
+        start_id: &MealId,
+        limit: usize,
+        excluded_allergens: &Allergens,
+    ) -> Vec<Meal> {
         let storage: &HashMap<MealId, Meal> = &self.storage;
-        storage
-            .iter()
-            .filter(|&(&_k, ref v)| !v.to_owned().removed())
-            .map(|(&_k, v)| v.to_owned())
+        let mut meals: Vec<Meal> = storage
+            .values()
+            .filter(|meal| !meal.removed() && meal.id().to_i64() >= start_id.to_i64())
+            .filter(|meal| !meal.allergens().contains_any(excluded_allergens))
+            .map(|meal| meal.to_owned())
+            .collect();
+        meals.sort_by_key(|meal| meal.id().to_i64());
+        meals.truncate(limit);
+        meals
+    }
+}
+
+/// Substitute for the full-text index: every searched word has to prefix a word of the meal,
+/// a match in the name weighs more than a match in the description
+impl MealSearcher for InMemoryMealRepository {
+    fn search(&mut self, text: &str, limit: usize) -> Vec<Meal> {
+        let words = search_words(text);
+        if words.is_empty() {
+            return vec![];
+        }
+
+        let mut found: Vec<(usize, &Meal)> = self
+            .storage
+            .values()
+            .filter(|meal| !meal.removed())
+            .filter_map(|meal| {
+                let name_words = search_words(&meal.name().to_string());
+                let description_words = search_words(&meal.description().to_string());
+                let mut rank = 0;
+                for word in &words {
+                    let in_name = name_words.iter().any(|it| it.starts_with(word.as_str()));
+                    let in_description = description_words
+                        .iter()
+                        .any(|it| it.starts_with(word.as_str()));
+                    match (in_name, in_description) {
+                        (false, false) => return None,
+                        (true, _) => rank += 2,
+                        (false, true) => rank += 1,
+                    }
+                }
+                Some((rank, meal))
+            })
+            .collect();
+        found.sort_by_key(|(rank, meal)| (Reverse(*rank), meal.id().to_i64()));
+        found
+            .into_iter()
+            .take(limit)
+            .map(|(_, meal)| meal.to_owned())
             .collect()
     }
 }
@@ -58,7 +111,16 @@ mod tests {
     use std::any::{type_name, type_name_of_val};
 
     use common::types::base::AMTrait;
-    use domain::{menu::meal_events::MealRemovedFromMenuDomainEvent, test_fixtures::*};
+    use domain::{
+        menu::{
+            meal_events::MealRemovedFromMenuDomainEvent,
+            meal_restorer::MealRestorer,
+            value_objects::{
+                allergens::Allergen, meal_description::MealDescription, meal_name::MealName,
+            },
+        },
+        test_fixtures::*,
+    };
 
     use super::*;
     use crate::test_fixtures::*;
@@ -152,7 +214,7 @@ mod tests {
     fn get_all_meals__repository_is_empty() {
         let event_publisher = AM::new_am(TestEventPublisher::new());
         let mut repository = InMemoryMealRepository::new(event_publisher);
-        let meals = repository.get_all();
+        let meals = repository.get_all(&MealId::default(), 10, &Allergens::default());
         assert!(meals.is_empty());
     }
 
@@ -165,7 +227,7 @@ mod tests {
             .storage
             .insert(*stored_meal.id(), stored_meal.clone());
 
-        let meals = repository.get_all();
+        let meals = repository.get_all(&MealId::default(), 10, &Allergens::default());
         assert_eq!(meals.first().unwrap(), &stored_meal);
     }
 
@@ -176,7 +238,129 @@ mod tests {
         let stored_meal = rnd_removed_meal();
         repository.storage.insert(*stored_meal.id(), stored_meal);
 
-        let meals = repository.get_all();
+        let meals = repository.get_all(&MealId::default(), 10, &Allergens::default());
         assert!(meals.is_empty());
     }
+
+    #[test]
+    fn get_all_meals__excluded_allergens_are_not_returned() {
+        let event_publisher = AM::new_am(TestEventPublisher::new());
+        let mut repository = InMemoryMealRepository::new(event_publisher);
+        let with_milk = meal_with_allergens(1, &[Allergen::Milk, Allergen::Eggs]);
+        let without_milk = meal_with_allergens(2, &[Allergen::Eggs]);
+        repository.storage.insert(*with_milk.id(), with_milk);
+        repository
+            .storage
+            .insert(*without_milk.id(), without_milk.clone());
+
+        let excluded = [Allergen::Milk].into_iter().collect();
+        let meals = repository.get_all(&MealId::default(), 10, &excluded);
+
+        assert_eq!(meals, vec![without_milk]);
+    }
+
+    #[test]
+    fn get_all_meals__page_starts_at_start_id_ordered_by_id() {
+        let event_publisher = AM::new_am(TestEventPublisher::new());
+        let mut repository = InMemoryMealRepository::new(event_publisher);
+        for id in [4, 1, 3, 2] {
+            let meal = meal_with_id(id);
+            repository.storage.insert(*meal.id(), meal);
+        }
+
+        let meals = repository.get_all(&MealId::try_from(2).unwrap(), 2, &Allergens::default());
+
+        let ids: Vec<i64> = meals.iter().map(|meal| meal.id().to_i64()).collect();
+        assert_eq!(ids, vec![2, 3]);
+    }
+
+    fn meal_with_id(id: i64) -> Meal {
+        meal_with_allergens(id, &[])
+    }
+
+    fn meal_with_allergens(id: i64, allergens: &[Allergen]) -> Meal {
+        MealRestorer::restore_meal(
+            &MealId::try_from(id).unwrap(),
+            &rnd_meal_name(),
+            &rnd_meal_description(),
+            &rnd_price(),
+            &allergens.iter().copied().collect(),
+            &rnd_nutrition_facts(),
+            &None,
+            false,
+            &Default::default(),
+            vec![],
+        )
+    }
+
+    fn meal_with_text(name: &str, description: &str) -> Meal {
+        MealRestorer::restore_meal(
+            &rnd_meal_id(),
+            &MealName::try_from(name).unwrap(),
+            &MealDescription::try_from(description).unwrap(),
+            &rnd_price(),
+            &rnd_allergens(),
+            &rnd_nutrition_facts(),
+            &None,
+            false,
+            &Default::default(),
+            vec![],
+        )
+    }
+
+    #[test]
+    fn search__name_match_ranked_above_description_match() {
+        let event_publisher = AM::new_am(TestEventPublisher::new());
+        let mut repository = InMemoryMealRepository::new(event_publisher);
+        let in_description = meal_with_text("Burger", "Served with chicken wings");
+        let in_name = meal_with_text("Chicken curry", "Spicy");
+        let not_matching = meal_with_text("Salad", "Fresh vegetables");
+        for meal in [&in_description, &in_name, &not_matching] {
+            repository.storage.insert(*meal.id(), meal.clone());
+        }
+
+        let meals = repository.search("chick", 10);
+
+        assert_eq!(meals, vec![in_name, in_description]);
+    }
+
+    #[test]
+    fn search__every_word_has_to_match() {
+        let event_publisher = AM::new_am(TestEventPublisher::new());
+        let mut repository = InMemoryMealRepository::new(event_publisher);
+        let both = meal_with_text("Spicy chicken", "Hot");
+        let one = meal_with_text("Chicken soup", "Warm");
+        for meal in [&both, &one] {
+            repository.storage.insert(*meal.id(), meal.clone());
+        }
+
+        let meals = repository.search("CHICKEN, spicy", 10);
+
+        assert_eq!(meals, vec![both]);
+    }
+
+    #[test]
+    fn search__removed_is_not_returned() {
+        let event_publisher = AM::new_am(TestEventPublisher::new());
+        let mut repository = InMemoryMealRepository::new(event_publisher);
+        let stored_meal = rnd_removed_meal();
+        let text = stored_meal.name().to_string();
+        repository.storage.insert(*stored_meal.id(), stored_meal);
+
+        let meals = repository.search(&text, 10);
+        assert!(meals.is_empty());
+    }
+
+    #[test]
+    fn search__limit_is_applied() {
+        let event_publisher = AM::new_am(TestEventPublisher::new());
+        let mut repository = InMemoryMealRepository::new(event_publisher);
+        for _ in 0..3 {
+            let meal = meal_with_text("Pizza", "Cheese");
+            repository.storage.insert(*meal.id(), meal);
+        }
+
+        let meals = repository.search("pizza", 2);
+        assert_eq!(meals.len(), 2);
+    }
 }
@@ -6,12 +6,14 @@ use derive_new::new;
 use domain::{
     cart::value_objects::customer_id::CustomerId,
     order::{
-        customer_order_events::ShopOrderEventEnum, shop_order::ShopOrder,
-        value_objects::shop_order_id::ShopOrderId,
+        customer_order_events::ShopOrderEventEnum,
+        shop_order::ShopOrder,
+        value_objects::{delivery_slot::DeliverySlot, shop_order_id::ShopOrderId},
     },
 };
 use usecase::order::access::{
-    shop_order_extractor::ShopOrderExtractor, shop_order_persister::ShopOrderPersister,
+    shop_order_criteria::ShopOrderCriteria, shop_order_extractor::ShopOrderExtractor,
+    shop_order_persister::ShopOrderPersister,
 };
 
 #[derive(new, Clone, Derivative, Debug)]
@@ -48,20 +50,56 @@ impl ShopOrderExtractor for InMemoryShopOrderRepository {
             .cloned()
     }
 
-    fn get_all(&mut self, start_id: &ShopOrderId, limit: usize) -> Vec<ShopOrder> {
+    fn get_all(
+        &mut self,
+        criteria: &ShopOrderCriteria,
+        start_id: &ShopOrderId,
+        limit: usize,
+    ) -> Vec<ShopOrder> {
+        let cursor = self.storage.get(start_id);
+        let mut orders: Vec<&ShopOrder> = self
+            .storage
+            .values()
+            .filter(|order| criteria.matches(order))
+            .filter(|order| criteria.is_from_cursor(order, start_id, cursor))
+            .collect();
+        orders.sort_by(|o1, o2| criteria.compare(o1, o2));
+        orders.into_iter().take(limit).cloned().collect()
+    }
+
+    fn get_all_active(&mut self) -> Vec<ShopOrder> {
+        let mut orders: Vec<ShopOrder> = self
+            .storage
+            .values()
+            .filter(|order| order.is_active())
+            .cloned()
+            .collect();
+        orders.sort_by(|o1, o2| o1.id().cmp(o2.id()));
+        orders
+    }
+
+    fn count_active_by_delivery_slot(&mut self, slot: &DeliverySlot) -> usize {
         self.storage
-            .range(start_id..)
-            .take(limit)
-            .map(|(_, order)| order.to_owned())
-            .collect()
+            .values()
+            .filter(|order| order.is_active() && order.delivery_slot() == &Some(*slot))
+            .count()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use common::types::base::AMTrait;
-    use domain::{order::customer_order_events::ShopOrderCompletedDomainEvent, test_fixtures::*};
+    use domain::{
+        menu::value_objects::price::Price,
+        order::{
+            customer_order_events::ShopOrderCompletedDomainEvent, shop_order::OrderState,
+            shop_order_restorer::ShopOrderRestorer, value_objects::fulfilment::Fulfilment,
+        },
+        test_fixtures::*,
+    };
+    use time::{Duration, OffsetDateTime};
     use tokio::test;
+    use usecase::order::access::shop_order_criteria::{ShopOrderSortField, SortDirection};
 
     use super::*;
     use crate::test_fixtures::{TestEventPublisher, order_with_events};
@@ -165,7 +203,7 @@ mod tests {
         let order_id = rnd_order_id();
         let event_publisher = AM::new_am(TestEventPublisher::new());
         let mut repository = InMemoryShopOrderRepository::new(event_publisher.clone());
-        let order = repository.get_all(&order_id, 100);
+        let order = repository.get_all(&ShopOrderCriteria::default(), &order_id, 100);
         assert!(order.is_empty());
     }
 
@@ -182,7 +220,11 @@ mod tests {
             repository.storage.insert(*order.id(), order);
         }
 
-        let result = repository.get_all(&ShopOrderId::try_from(3).unwrap(), limit);
+        let result = repository.get_all(
+            &ShopOrderCriteria::default(),
+            &ShopOrderId::try_from(3).unwrap(),
+            limit,
+        );
 
         assert_eq!(result.len(), limit);
         assert_eq!(result.first().unwrap().id().to_i64(), 3);
@@ -202,10 +244,123 @@ mod tests {
             repository.storage.insert(*order.id(), order);
         }
 
-        let result = repository.get_all(&ShopOrderId::try_from(0).unwrap(), limit);
+        let result = repository.get_all(
+            &ShopOrderCriteria::default(),
+            &ShopOrderId::try_from(0).unwrap(),
+            limit,
+        );
 
         assert_eq!(result.len(), collection_size as usize);
         assert_eq!(result.first().unwrap().id().to_i64(), 0);
         assert_eq!(result.last().unwrap().id().to_i64(), 4);
     }
+
+    #[test]
+    async fn get_all_for_customer_skips_orders_of_other_customers() {
+        let customer_id = rnd_customer_id();
+
+        let event_publisher = AM::new_am(TestEventPublisher::new());
+        let mut repository = InMemoryShopOrderRepository::new(event_publisher.clone());
+
+        let mut own_ids = vec![];
+        for _ in 0..5 {
+            let own_order = rnd_order_with_customer_id(customer_id);
+            own_ids.push(*own_order.id());
+            repository.storage.insert(*own_order.id(), own_order);
+            let other_order = rnd_order_with_customer_id(rnd_customer_id());
+            repository.storage.insert(*other_order.id(), other_order);
+        }
+        own_ids.sort();
+
+        let result = repository.get_all(
+            &ShopOrderCriteria::for_customer(customer_id),
+            &own_ids[1],
+            3,
+        );
+
+        let result_ids: Vec<ShopOrderId> = result.iter().map(|order| *order.id()).collect();
+        assert_eq!(result_ids, own_ids[1..4].to_vec());
+    }
+
+    #[test]
+    async fn get_all_filtered_by_state_and_sorted_by_created_desc() {
+        let event_publisher = AM::new_am(TestEventPublisher::new());
+        let mut repository = InMemoryShopOrderRepository::new(event_publisher.clone());
+
+        let now = OffsetDateTime::now_utc();
+        let mut paid = vec![];
+        for i in 0..4 {
+            let order = ShopOrderRestorer::restore_order(
+                ShopOrderId::try_from(i).unwrap(),
+                now + Duration::minutes(i),
+                rnd_customer_id(),
+                Fulfilment::Pickup(rnd_pickup_point_id()),
+                Price::zero(),
+                None,
+                [rnd_order_item()].into(),
+                OrderState::new_paid(),
+                Default::default(),
+            );
+            paid.push(*order.id());
+            repository.storage.insert(*order.id(), order);
+        }
+        let cancelled = order_with_state(OrderState::new_cancelled());
+        repository.storage.insert(*cancelled.id(), cancelled);
+
+        let criteria = ShopOrderCriteria {
+            state: Some(OrderState::new_paid()),
+            sort_by: ShopOrderSortField::Created,
+            direction: SortDirection::Desc,
+            ..Default::default()
+        };
+        let first_page = repository.get_all(&criteria, &rnd_order_id(), 3);
+        let next_page = repository.get_all(&criteria, first_page[2].id(), 3);
+
+        let first_ids: Vec<ShopOrderId> = first_page.iter().map(|order| *order.id()).collect();
+        assert_eq!(first_ids, vec![paid[3], paid[2], paid[1]]);
+        let next_ids: Vec<ShopOrderId> = next_page.iter().map(|order| *order.id()).collect();
+        assert_eq!(next_ids, vec![paid[1], paid[0]]);
+    }
+
+    #[test]
+    async fn count_active_by_delivery_slot() {
+        let slot = rnd_delivery_slot();
+        let next_slot =
+            DeliverySlot::try_from((*slot.end(), *slot.end() + Duration::minutes(30))).unwrap();
+        let orders = [
+            order_with_delivery_slot_and_state(slot, OrderState::new_paid()),
+            order_with_delivery_slot_and_state(slot, OrderState::new_confirmed()),
+            order_with_delivery_slot_and_state(slot, OrderState::new_cancelled()),
+            order_with_delivery_slot_and_state(next_slot, OrderState::new_paid()),
+            order_with_state(OrderState::new_paid()),
+        ];
+
+        let event_publisher = AM::new_am(TestEventPublisher::new());
+        let mut repository = InMemoryShopOrderRepository::new(event_publisher.clone());
+        for order in orders {
+            repository.storage.insert(*order.id(), order);
+        }
+
+        assert_eq!(repository.count_active_by_delivery_slot(&slot), 2);
+    }
+
+    #[test]
+    async fn get_all_active() {
+        let orders = [
+            order_with_state(OrderState::new_ready_for_pickup()),
+            order_with_state(OrderState::new_completed()),
+            order_with_state(OrderState::new_paid()),
+            order_with_state(OrderState::new_cancelled()),
+        ];
+
+        let event_publisher = AM::new_am(TestEventPublisher::new());
+        let mut repository = InMemoryShopOrderRepository::new(event_publisher.clone());
+        for order in orders.clone() {
+            repository.storage.insert(*order.id(), order);
+        }
+
+        let mut expected = vec![orders[0].clone(), orders[2].clone()];
+        expected.sort_by(|o1, o2| o1.id().cmp(o2.id()));
+        assert_eq!(repository.get_all_active(), expected);
+    }
 }
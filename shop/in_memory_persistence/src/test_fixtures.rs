@@ -4,7 +4,10 @@ use std::fmt::Debug;
 use async_trait::async_trait;
 use common::{events::DomainEventPublisher, types::base::DomainEventTrait};
 use derive_new::new;
-use domain::{cart::cart::Cart, menu::meal::Meal, order::shop_order::ShopOrder, test_fixtures::*};
+use domain::{
+    cart::cart::Cart, customer::customer::Customer, menu::meal::Meal, order::shop_order::ShopOrder,
+    test_fixtures::*,
+};
 use usecase::test_fixtures::order_ready_for_complete;
 
 pub fn meal_with_events() -> Meal {
@@ -19,6 +22,12 @@ pub fn cart_with_events() -> Cart {
     cart
 }
 
+pub fn customer_with_events() -> Customer {
+    let mut customer = rnd_customer();
+    customer.remove();
+    customer
+}
+
 pub fn order_with_events() -> ShopOrder {
     let mut order = order_ready_for_complete();
 
@@ -0,0 +1,47 @@
+use async_trait::async_trait;
+use derive_new::new;
+use usecase::webhook::{
+    access::{
+        failed_webhook_delivery_extractor::FailedWebhookDeliveryExtractor,
+        failed_webhook_delivery_persister::FailedWebhookDeliveryPersister,
+    },
+    dto::failed_webhook_delivery::FailedWebhookDelivery,
+};
+
+#[derive(new, Clone, Debug, Default)]
+pub struct InMemoryFailedWebhookDeliveryRepository {
+    #[new(default)]
+    pub storage: Vec<FailedWebhookDelivery>,
+}
+
+#[async_trait]
+impl FailedWebhookDeliveryPersister for InMemoryFailedWebhookDeliveryRepository {
+    async fn save(&mut self, delivery: FailedWebhookDelivery) {
+        self.storage.push(delivery);
+    }
+}
+
+impl FailedWebhookDeliveryExtractor for InMemoryFailedWebhookDeliveryRepository {
+    fn get_all(&mut self) -> Vec<FailedWebhookDelivery> {
+        self.storage.iter().rev().cloned().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use usecase::test_fixtures::rnd_failed_webhook_delivery;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn most_recent_failures_first() {
+        let first = rnd_failed_webhook_delivery();
+        let second = rnd_failed_webhook_delivery();
+        let mut repository = InMemoryFailedWebhookDeliveryRepository::new();
+
+        repository.save(first.clone()).await;
+        repository.save(second.clone()).await;
+
+        assert_eq!(repository.get_all(), vec![second, first]);
+    }
+}
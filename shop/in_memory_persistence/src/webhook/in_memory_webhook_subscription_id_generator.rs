@@ -0,0 +1,26 @@
+use derive_new::new;
+use domain::webhook::value_objects::webhook_subscription_id::{
+    WebhookSubscriptionId, WebhookSubscriptionIdGenerator,
+};
+
+#[derive(Debug, new)]
+pub struct InMemoryWebhookSubscriptionIdGenerator {}
+
+impl WebhookSubscriptionIdGenerator for InMemoryWebhookSubscriptionIdGenerator {
+    fn generate(&mut self) -> WebhookSubscriptionId {
+        WebhookSubscriptionId::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ids_are_unique() {
+        let mut generator = InMemoryWebhookSubscriptionIdGenerator::new();
+        let id1 = generator.generate();
+        let id2 = generator.generate();
+        assert_ne!(id1, id2);
+    }
+}
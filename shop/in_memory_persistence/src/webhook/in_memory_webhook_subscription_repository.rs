@@ -0,0 +1,120 @@
+use std::{collections::HashMap, fmt::Debug};
+
+use async_trait::async_trait;
+use common::{events::DomainEventPublisher, types::base::AM};
+use derive_new::new;
+use domain::webhook::{
+    value_objects::webhook_subscription_id::WebhookSubscriptionId,
+    webhook_subscription::WebhookSubscription,
+    webhook_subscription_events::WebhookSubscriptionEventEnum,
+};
+use usecase::webhook::access::{
+    webhook_subscription_extractor::WebhookSubscriptionExtractor,
+    webhook_subscription_persister::WebhookSubscriptionPersister,
+};
+
+#[derive(new, Clone, Debug)]
+pub struct InMemoryWebhookSubscriptionRepository {
+    pub event_publisher: AM<dyn DomainEventPublisher<WebhookSubscriptionEventEnum>>,
+    #[new(value = "HashMap::new()")]
+    pub storage: HashMap<WebhookSubscriptionId, WebhookSubscription>,
+}
+
+#[async_trait]
+impl WebhookSubscriptionPersister for InMemoryWebhookSubscriptionRepository {
+    async fn save(&mut self, mut subscription: WebhookSubscription) {
+        self.event_publisher
+            .lock()
+            .await
+            .publish(&subscription.pop_events())
+            .await;
+        self.storage.insert(*subscription.id(), subscription);
+    }
+}
+
+impl WebhookSubscriptionExtractor for InMemoryWebhookSubscriptionRepository {
+    fn get_by_id(&mut self, id: &WebhookSubscriptionId) -> Option<WebhookSubscription> {
+        self.storage.get(id).map(|it| it.to_owned())
+    }
+
+    fn get_all(&mut self) -> Vec<WebhookSubscription> {
+        self.storage
+            .values()
+            .filter(|it| it.visible())
+            .map(|it| it.to_owned())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+#[allow(non_snake_case)]
+mod tests {
+    use common::types::base::AMTrait;
+    use domain::{
+        test_fixtures::*,
+        webhook::{
+            value_objects::webhook_event_type::WebhookEventType,
+            webhook_subscription_events::WebhookSubscriptionRemovedDomainEvent,
+        },
+    };
+
+    use super::*;
+    use crate::test_fixtures::*;
+
+    fn subscription() -> WebhookSubscription {
+        rnd_webhook_subscription(vec![WebhookEventType::OrderPaid])
+    }
+
+    #[tokio::test]
+    async fn saving_subscription__events_are_published() {
+        let event_publisher = AM::new_am(TestEventPublisher::new());
+        let mut repository = InMemoryWebhookSubscriptionRepository::new(event_publisher.clone());
+        let mut subscription = subscription();
+        subscription.remove();
+
+        repository.save(subscription.clone()).await;
+
+        let stored = repository.storage.get(subscription.id()).unwrap();
+        assert!(!stored.visible());
+        let storage = &event_publisher.lock().await.storage;
+        assert_eq!(storage.len(), 1);
+        let event: WebhookSubscriptionRemovedDomainEvent =
+            storage.first().unwrap().to_owned().try_into().unwrap();
+        assert_eq!(&event.subscription_id, subscription.id());
+    }
+
+    #[test]
+    fn get_by_id__subscription_exists() {
+        let existing = subscription();
+        let mut repository =
+            InMemoryWebhookSubscriptionRepository::new(AM::new_am(TestEventPublisher::new()));
+        repository.storage.insert(*existing.id(), existing.clone());
+
+        assert_eq!(repository.get_by_id(existing.id()), Some(existing));
+    }
+
+    #[test]
+    fn get_by_id__subscription_doesnt_exist() {
+        let mut repository =
+            InMemoryWebhookSubscriptionRepository::new(AM::new_am(TestEventPublisher::new()));
+
+        assert!(
+            repository
+                .get_by_id(&rnd_webhook_subscription_id())
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn get_all__removed_are_not_returned() {
+        let existing = subscription();
+        let mut removed = subscription();
+        removed.remove();
+        let mut repository =
+            InMemoryWebhookSubscriptionRepository::new(AM::new_am(TestEventPublisher::new()));
+        repository.storage.insert(*existing.id(), existing.clone());
+        repository.storage.insert(*removed.id(), removed);
+
+        assert_eq!(repository.get_all(), vec![existing]);
+    }
+}
@@ -0,0 +1,3 @@
+pub mod in_memory_failed_webhook_delivery_repository;
+pub mod in_memory_webhook_subscription_id_generator;
+pub mod in_memory_webhook_subscription_repository;
@@ -0,0 +1,252 @@
+use std::{
+    collections::HashMap,
+    fs,
+    io::{self, ErrorKind},
+    path::{Path, PathBuf},
+};
+
+use actix_web::http::Uri;
+use async_trait::async_trait;
+use domain::menu::value_objects::meal_id::MealId;
+use time::OffsetDateTime;
+use usecase::menu::access::media_storage::{ImageFormat, MediaStorage, MediaStorageError};
+
+/// Keeps the images of each meal in its own directory under `root`,
+/// the directory is expected to be served as static files at `base_url`
+#[derive(Debug)]
+pub struct LocalMediaStorage {
+    root: PathBuf,
+    base_url: String,
+    /// Stored file names of every meal in the stored order, kept in step with the directories
+    file_names: HashMap<MealId, Vec<String>>,
+}
+
+impl LocalMediaStorage {
+    /// Reads the names of the already stored images once, so the menu is served without
+    /// listing the directories. Meant to be called on startup, before requests are handled.
+    pub fn open(root: PathBuf, base_url: String) -> Result<Self, MediaStorageError> {
+        let mut file_names = HashMap::new();
+        let entries = match fs::read_dir(&root) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == ErrorKind::NotFound => {
+                return Ok(Self {
+                    root,
+                    base_url,
+                    file_names,
+                });
+            }
+            Err(e) => return Err(storage_error(&root, e)),
+        };
+        for entry in entries {
+            let dir = entry.map_err(|e| storage_error(&root, e))?.path();
+            // Anything but the meal directories is left alone
+            let Some(meal_id) = dir
+                .file_name()
+                .and_then(|name| name.to_str())
+                .and_then(|name| name.parse::<i64>().ok())
+                .and_then(|id| MealId::try_from(id).ok())
+            else {
+                continue;
+            };
+            if !dir.is_dir() {
+                continue;
+            }
+            let mut names = fs::read_dir(&dir)
+                .and_then(|entries| {
+                    entries
+                        .map(|entry| {
+                            entry.map(|entry| entry.file_name().to_string_lossy().into_owned())
+                        })
+                        .collect::<io::Result<Vec<String>>>()
+                })
+                .map_err(|e| storage_error(&dir, e))?;
+            // Stored time keeps the names ordered
+            names.sort();
+            file_names.insert(meal_id, names);
+        }
+        Ok(Self {
+            root,
+            base_url,
+            file_names,
+        })
+    }
+
+    fn meal_dir(&self, meal_id: &MealId) -> PathBuf {
+        self.root.join(meal_id.to_string())
+    }
+
+    fn url(&self, meal_id: &MealId, file_name: &str) -> Uri {
+        format!(
+            "{}/{meal_id}/{file_name}",
+            self.base_url.trim_end_matches('/')
+        )
+        .parse()
+        .expect("Invalid media url")
+    }
+}
+
+fn storage_error(path: &Path, e: io::Error) -> MediaStorageError {
+    MediaStorageError::StorageFailed(format!("{}: {e}", path.display()))
+}
+
+#[async_trait]
+impl MediaStorage for LocalMediaStorage {
+    async fn store(
+        &mut self,
+        meal_id: &MealId,
+        format: ImageFormat,
+        content: &[u8],
+    ) -> Result<Uri, MediaStorageError> {
+        let dir = self.meal_dir(meal_id);
+        tokio::fs::create_dir_all(&dir)
+            .await
+            .map_err(|e| storage_error(&dir, e))?;
+        // Stored time keeps the names unique and ordered
+        let file_name = format!(
+            "{}.{}",
+            OffsetDateTime::now_utc().unix_timestamp_nanos(),
+            format.extension()
+        );
+        let path = dir.join(&file_name);
+        tokio::fs::write(&path, content)
+            .await
+            .map_err(|e| storage_error(&path, e))?;
+        let url = self.url(meal_id, &file_name);
+        self.file_names.entry(*meal_id).or_default().push(file_name);
+        Ok(url)
+    }
+
+    fn get_urls(&mut self, meal_id: &MealId) -> Vec<Uri> {
+        self.file_names
+            .get(meal_id)
+            .map(|file_names| {
+                file_names
+                    .iter()
+                    .map(|file_name| self.url(meal_id, file_name))
+                    .collect()
+            })
+            .unwrap_or_default()
+    }
+
+    async fn remove_all(&mut self, meal_id: &MealId) -> Result<(), MediaStorageError> {
+        let dir = self.meal_dir(meal_id);
+        match tokio::fs::remove_dir_all(&dir).await {
+            Err(e) if e.kind() != ErrorKind::NotFound => Err(storage_error(&dir, e)),
+            _ => {
+                self.file_names.remove(meal_id);
+                Ok(())
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env::temp_dir;
+
+    use domain::test_fixtures::rnd_meal_id;
+    use uuid::Uuid;
+
+    use super::*;
+
+    const BASE_URL: &str = "http://localhost/media/";
+
+    fn root() -> PathBuf {
+        temp_dir().join(Uuid::new_v4().to_string())
+    }
+
+    fn storage() -> LocalMediaStorage {
+        LocalMediaStorage::open(root(), BASE_URL.to_string()).unwrap()
+    }
+
+    #[tokio::test]
+    async fn image_stored() {
+        let mut storage = storage();
+        let meal_id = rnd_meal_id();
+
+        let url = storage
+            .store(&meal_id, ImageFormat::Png, &[1, 2, 3])
+            .await
+            .unwrap();
+
+        assert!(
+            url.to_string()
+                .starts_with(&format!("http://localhost/media/{meal_id}/"))
+        );
+        assert!(url.path().ends_with(".png"));
+        let file_name = url.path().rsplit('/').next().unwrap();
+        let content = fs::read(storage.meal_dir(&meal_id).join(file_name)).unwrap();
+        assert_eq!(content, vec![1, 2, 3]);
+        assert_eq!(storage.get_urls(&meal_id), vec![url]);
+    }
+
+    #[tokio::test]
+    async fn urls_are_in_stored_order() {
+        let mut storage = storage();
+        let meal_id = rnd_meal_id();
+
+        let first = storage
+            .store(&meal_id, ImageFormat::Jpeg, &[1])
+            .await
+            .unwrap();
+        let second = storage
+            .store(&meal_id, ImageFormat::Webp, &[2])
+            .await
+            .unwrap();
+
+        assert_eq!(storage.get_urls(&meal_id), vec![first, second]);
+        assert!(storage.get_urls(&rnd_meal_id()).is_empty());
+    }
+
+    #[tokio::test]
+    async fn stored_images_found_after_reopen() {
+        let root = root();
+        let mut storage = LocalMediaStorage::open(root.clone(), BASE_URL.to_string()).unwrap();
+        let meal_id = rnd_meal_id();
+        let first = storage
+            .store(&meal_id, ImageFormat::Jpeg, &[1])
+            .await
+            .unwrap();
+        let second = storage
+            .store(&meal_id, ImageFormat::Png, &[2])
+            .await
+            .unwrap();
+        fs::write(root.join("README"), "not a meal").unwrap();
+
+        let mut reopened = LocalMediaStorage::open(root, BASE_URL.to_string()).unwrap();
+
+        assert_eq!(reopened.get_urls(&meal_id), vec![first, second]);
+    }
+
+    #[tokio::test]
+    async fn all_images_removed() {
+        let mut storage = storage();
+        let meal_id = rnd_meal_id();
+        storage
+            .store(&meal_id, ImageFormat::Jpeg, &[1])
+            .await
+            .unwrap();
+
+        storage.remove_all(&meal_id).await.unwrap();
+
+        assert!(storage.get_urls(&meal_id).is_empty());
+        assert!(!storage.meal_dir(&meal_id).exists());
+        // Removing the media of a meal without images is fine
+        storage.remove_all(&meal_id).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn root_is_a_file() {
+        let root = root();
+        let mut storage = LocalMediaStorage::open(root.clone(), BASE_URL.to_string()).unwrap();
+        fs::write(&root, "not a directory").unwrap();
+
+        let result = storage.store(&rnd_meal_id(), ImageFormat::Png, &[1]).await;
+
+        assert!(matches!(result, Err(MediaStorageError::StorageFailed(_))));
+        assert!(matches!(
+            LocalMediaStorage::open(root, BASE_URL.to_string()),
+            Err(MediaStorageError::StorageFailed(_))
+        ));
+    }
+}
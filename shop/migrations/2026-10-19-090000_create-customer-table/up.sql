@@ -0,0 +1,13 @@
+-- Your SQL goes here
+
+CREATE TABLE IF NOT EXISTS shop.customer (
+    id UUID PRIMARY KEY NOT NULL,
+    name VarChar NOT NULL,
+    phone VarChar NOT NULL,
+    addresses JSONB NOT NULL,
+    preferences JSONB NOT NULL,
+    removed BOOLEAN NOT NULL,
+    version BIGINT NOT NULL
+);
+
+CREATE UNIQUE INDEX IF NOT EXISTS customer_phone_unique ON shop.customer (phone) WHERE NOT removed;
\ No newline at end of file
@@ -0,0 +1,20 @@
+-- Your SQL goes here
+
+CREATE TABLE IF NOT EXISTS shop.shop_order (
+    id BIGINT PRIMARY KEY NOT NULL,
+    created TIMESTAMPTZ NOT NULL,
+    customer_id UUID NOT NULL,
+    fulfilment JSONB NOT NULL,
+    delivery_fee NUMERIC NOT NULL,
+    delivery_slot_start TIMESTAMPTZ,
+    delivery_slot_end TIMESTAMPTZ,
+    items JSONB NOT NULL,
+    state VarChar NOT NULL,
+    total NUMERIC NOT NULL,
+    version BIGINT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS shop_order_customer_created ON shop.shop_order (customer_id, created);
+CREATE INDEX IF NOT EXISTS shop_order_state_created ON shop.shop_order (state, created);
+CREATE INDEX IF NOT EXISTS shop_order_total ON shop.shop_order (total);
+CREATE INDEX IF NOT EXISTS shop_order_delivery_slot ON shop.shop_order (delivery_slot_start, delivery_slot_end);
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+DROP INDEX shop.meal_search_vector;
+ALTER TABLE shop.meal DROP COLUMN search_vector
\ No newline at end of file
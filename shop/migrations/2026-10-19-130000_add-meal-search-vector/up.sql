@@ -0,0 +1,9 @@
+-- Your SQL goes here
+
+-- Maintained by Postgres, queried with raw SQL only, so it is left out of schema.rs
+ALTER TABLE shop.meal ADD COLUMN IF NOT EXISTS search_vector TSVECTOR GENERATED ALWAYS AS (
+    setweight(to_tsvector('english'::regconfig, name), 'A') ||
+    setweight(to_tsvector('english'::regconfig, coalesce(description, '')), 'B')
+) STORED;
+
+CREATE INDEX IF NOT EXISTS meal_search_vector ON shop.meal USING GIN (search_vector);
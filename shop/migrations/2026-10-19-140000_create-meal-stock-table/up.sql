@@ -0,0 +1,7 @@
+-- Your SQL goes here
+
+CREATE TABLE IF NOT EXISTS shop.meal_stock (
+    meal_id BIGINT PRIMARY KEY NOT NULL,
+    available INT NOT NULL,
+    version BIGINT NOT NULL
+);
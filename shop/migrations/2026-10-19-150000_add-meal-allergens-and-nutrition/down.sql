@@ -0,0 +1,7 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE shop.meal
+    DROP COLUMN allergens,
+    DROP COLUMN calories,
+    DROP COLUMN proteins,
+    DROP COLUMN fats,
+    DROP COLUMN carbohydrates
\ No newline at end of file
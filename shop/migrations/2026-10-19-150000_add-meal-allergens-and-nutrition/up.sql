@@ -0,0 +1,9 @@
+-- Your SQL goes here
+
+-- Meals added before get no allergens and zero nutrition facts until they are re-entered
+ALTER TABLE shop.meal
+    ADD COLUMN IF NOT EXISTS allergens TEXT[] NOT NULL DEFAULT '{}',
+    ADD COLUMN IF NOT EXISTS calories INT NOT NULL DEFAULT 0,
+    ADD COLUMN IF NOT EXISTS proteins NUMERIC(6, 1) NOT NULL DEFAULT 0,
+    ADD COLUMN IF NOT EXISTS fats NUMERIC(6, 1) NOT NULL DEFAULT 0,
+    ADD COLUMN IF NOT EXISTS carbohydrates NUMERIC(6, 1) NOT NULL DEFAULT 0;
@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+ALTER TABLE shop.meal
+    DROP COLUMN availability_schedule
\ No newline at end of file
@@ -0,0 +1,5 @@
+-- Your SQL goes here
+
+-- NULL means the meal can be ordered at any time
+ALTER TABLE shop.meal
+    ADD COLUMN IF NOT EXISTS availability_schedule JSONB;
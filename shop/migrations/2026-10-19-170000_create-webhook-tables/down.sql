@@ -0,0 +1,3 @@
+-- This file should undo anything in `up.sql`
+DROP TABLE shop.failed_webhook_delivery;
+DROP TABLE shop.webhook_subscription
\ No newline at end of file
@@ -0,0 +1,23 @@
+-- Your SQL goes here
+
+CREATE TABLE IF NOT EXISTS shop.webhook_subscription (
+    id UUID PRIMARY KEY NOT NULL,
+    url VarChar NOT NULL,
+    secret VarChar NOT NULL,
+    event_types TEXT[] NOT NULL,
+    removed BOOLEAN NOT NULL,
+    version BIGINT NOT NULL
+);
+
+-- Dead letters of the webhooks which couldn't be delivered after all the attempts
+CREATE TABLE IF NOT EXISTS shop.failed_webhook_delivery (
+    id BIGSERIAL PRIMARY KEY NOT NULL,
+    subscription_id UUID NOT NULL,
+    event_id UUID NOT NULL,
+    event_type VarChar NOT NULL,
+    url VarChar NOT NULL,
+    payload TEXT NOT NULL,
+    attempts INT NOT NULL,
+    last_error VarChar NOT NULL,
+    failed_at TIMESTAMPTZ NOT NULL
+);
@@ -0,0 +1,63 @@
+use common::types::base::{DomainEntity, Version};
+use diesel::prelude::*;
+use domain::{
+    cart::value_objects::customer_id::CustomerId,
+    customer::{
+        customer::Customer,
+        value_objects::{customer_name::CustomerName, phone_number::PhoneNumber},
+    },
+};
+use serde::*;
+use uuid::Uuid;
+
+#[derive(
+    Insertable,
+    Identifiable,
+    Queryable,
+    QueryableByName,
+    Selectable,
+    AsChangeset,
+    Serialize,
+    Deserialize,
+    Clone,
+    Debug,
+)]
+#[diesel(primary_key(id))]
+#[diesel(table_name = crate::schema::shop::customer)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct CustomerDbDto {
+    pub id: Uuid,
+    pub name: String,
+    pub phone: String,
+    pub addresses: serde_json::Value,
+    pub preferences: serde_json::Value,
+    pub removed: bool,
+    pub version: i64,
+}
+
+impl From<Customer> for CustomerDbDto {
+    fn from(value: Customer) -> Self {
+        Self {
+            id: value.id().to_uuid(),
+            name: value.name().to_string(),
+            phone: value.phone().to_string(),
+            addresses: serde_json::to_value(value.addresses()).unwrap(),
+            preferences: serde_json::to_value(value.preferences()).unwrap(),
+            removed: *value.removed(),
+            version: value.version().to_i64(),
+        }
+    }
+}
+
+impl From<CustomerDbDto> for Customer {
+    fn from(value: CustomerDbDto) -> Self {
+        Self::with_all_args(
+            DomainEntity::new(CustomerId::from(value.id), Version::from(value.version)),
+            CustomerName::try_from(value.name.as_str()).unwrap(),
+            PhoneNumber::try_from(value.phone.as_str()).unwrap(),
+            serde_json::from_value(value.addresses).unwrap(),
+            serde_json::from_value(value.preferences).unwrap(),
+            value.removed,
+        )
+    }
+}
@@ -0,0 +1,69 @@
+use diesel::prelude::*;
+use domain::webhook::value_objects::{
+    webhook_event_type::WebhookEventType, webhook_subscription_id::WebhookSubscriptionId,
+    webhook_url::WebhookUrl,
+};
+use serde::*;
+use time::OffsetDateTime;
+use usecase::webhook::dto::failed_webhook_delivery::FailedWebhookDelivery;
+use uuid::Uuid;
+
+#[derive(Queryable, QueryableByName, Selectable, Serialize, Deserialize, Clone, Debug)]
+#[diesel(table_name = crate::schema::shop::failed_webhook_delivery)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct FailedWebhookDeliveryDbDto {
+    pub id: i64,
+    pub subscription_id: Uuid,
+    pub event_id: Uuid,
+    pub event_type: String,
+    pub url: String,
+    pub payload: String,
+    pub attempts: i32,
+    pub last_error: String,
+    pub failed_at: OffsetDateTime,
+}
+
+/// Dead letter without the id, which is assigned by the database
+#[derive(Insertable, Serialize, Deserialize, Clone, Debug)]
+#[diesel(table_name = crate::schema::shop::failed_webhook_delivery)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct NewFailedWebhookDeliveryDbDto {
+    pub subscription_id: Uuid,
+    pub event_id: Uuid,
+    pub event_type: String,
+    pub url: String,
+    pub payload: String,
+    pub attempts: i32,
+    pub last_error: String,
+    pub failed_at: OffsetDateTime,
+}
+
+impl From<FailedWebhookDelivery> for NewFailedWebhookDeliveryDbDto {
+    fn from(value: FailedWebhookDelivery) -> Self {
+        Self {
+            subscription_id: value.subscription_id.to_uuid(),
+            event_id: Uuid::parse_str(&value.event_id.to_string()).unwrap(),
+            event_type: value.event_type.to_string(),
+            url: value.url.to_string(),
+            payload: value.payload,
+            attempts: value.attempts as i32,
+            last_error: value.last_error,
+            failed_at: value.failed_at,
+        }
+    }
+}
+
+impl From<FailedWebhookDeliveryDbDto> for FailedWebhookDelivery {
+    fn from(value: FailedWebhookDeliveryDbDto) -> Self {
+        Self::new(
+            WebhookSubscriptionId::from(value.subscription_id),
+            value.event_id.to_string().parse().unwrap(),
+            WebhookEventType::try_from(value.event_type.as_str()).unwrap(),
+            WebhookUrl::try_from(value.url.as_str()).unwrap(),
+            value.payload,
+            value.attempts as u32,
+            value.last_error,
+            value.failed_at,
+        )
+    }
+}
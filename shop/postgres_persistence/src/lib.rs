@@ -1,7 +1,17 @@
 #![allow(special_module_name)]
 
+pub mod customer_db_dto;
 pub mod database_start;
+pub mod failed_webhook_delivery_db_dto;
 pub mod meal_db_dto;
+pub mod meal_stock_db_dto;
+pub mod postgres_customer_repository;
+pub mod postgres_failed_webhook_delivery_repository;
 pub mod postgres_meal_id_generator;
 pub mod postgres_meal_repository;
+pub mod postgres_meal_stock_repository;
+pub mod postgres_shop_order_repository;
+pub mod postgres_webhook_subscription_repository;
 pub mod schema;
+pub mod shop_order_db_dto;
+pub mod webhook_subscription_db_dto;
@@ -4,7 +4,8 @@ use diesel::prelude::*;
 use domain::menu::{
     meal::Meal,
     value_objects::{
-        meal_description::MealDescription, meal_id::MealId, meal_name::MealName, price::Price,
+        allergens::Allergens, meal_description::MealDescription, meal_id::MealId,
+        meal_name::MealName, nutrition_facts::NutritionFacts, price::Price,
     },
 };
 use serde::*;
@@ -31,6 +32,12 @@ pub struct MealDbDto {
     pub price: BigDecimal,
     pub removed: bool,
     pub version: i64,
+    pub allergens: Vec<String>,
+    pub calories: i32,
+    pub proteins: BigDecimal,
+    pub fats: BigDecimal,
+    pub carbohydrates: BigDecimal,
+    pub availability_schedule: Option<serde_json::Value>,
 }
 
 impl From<Meal> for MealDbDto {
@@ -42,6 +49,15 @@ impl From<Meal> for MealDbDto {
             price: value.price().to_bigdecimal(),
             removed: *value.removed(),
             version: value.version().to_i64(),
+            allergens: value.allergens().to_codes(),
+            calories: *value.nutrition_facts().calories(),
+            proteins: value.nutrition_facts().proteins().to_owned(),
+            fats: value.nutrition_facts().fats().to_owned(),
+            carbohydrates: value.nutrition_facts().carbohydrates().to_owned(),
+            availability_schedule: value
+                .availability_schedule()
+                .as_ref()
+                .map(|schedule| serde_json::to_value(schedule).unwrap()),
         }
     }
 }
@@ -56,6 +72,17 @@ impl From<MealDbDto> for Meal {
             MealName::try_from(value.name.as_str()).unwrap(),
             MealDescription::try_from(value.description.unwrap().as_str()).unwrap(),
             Price::try_from(value.price).unwrap(),
+            Allergens::try_from(value.allergens.as_slice()).unwrap(),
+            NutritionFacts::try_new(
+                value.calories,
+                value.proteins,
+                value.fats,
+                value.carbohydrates,
+            )
+            .unwrap(),
+            value
+                .availability_schedule
+                .map(|schedule| serde_json::from_value(schedule).unwrap()),
             value.removed,
         )
     }
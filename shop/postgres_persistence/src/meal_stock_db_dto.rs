@@ -0,0 +1,50 @@
+use common::types::{
+    base::{DomainEntity, Version},
+    common::Count,
+};
+use diesel::prelude::*;
+use domain::{inventory::meal_stock::MealStock, menu::value_objects::meal_id::MealId};
+use serde::*;
+
+#[derive(
+    Insertable,
+    Identifiable,
+    Queryable,
+    QueryableByName,
+    Selectable,
+    AsChangeset,
+    Serialize,
+    Deserialize,
+    Clone,
+    Debug,
+)]
+#[diesel(primary_key(meal_id))]
+#[diesel(table_name = crate::schema::shop::meal_stock)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct MealStockDbDto {
+    pub meal_id: i64,
+    pub available: i32,
+    pub version: i64,
+}
+
+impl From<MealStock> for MealStockDbDto {
+    fn from(value: MealStock) -> Self {
+        Self {
+            meal_id: value.meal_id().to_i64(),
+            available: value.available().to_i32(),
+            version: value.version().to_i64(),
+        }
+    }
+}
+
+impl From<MealStockDbDto> for MealStock {
+    fn from(value: MealStockDbDto) -> Self {
+        Self::new(
+            DomainEntity::new(
+                MealId::try_from(value.meal_id).unwrap(),
+                Version::from(value.version),
+            ),
+            Count::try_from(value.available).unwrap(),
+        )
+    }
+}
@@ -0,0 +1,101 @@
+use async_trait::async_trait;
+use common::{events::DomainEventPublisher, types::base::AM};
+use derivative::Derivative;
+use derive_new::new;
+use diesel::{ExpressionMethods, PgConnection, QueryDsl, RunQueryDsl, SelectableHelper};
+use domain::{
+    cart::value_objects::customer_id::CustomerId,
+    customer::{
+        customer::Customer, customer_events::CustomerEventEnum,
+        value_objects::phone_number::PhoneNumber,
+    },
+};
+use usecase::customer::access::{
+    customer_extractor::CustomerExtractor, customer_persister::CustomerPersister,
+};
+
+use crate::{customer_db_dto::CustomerDbDto, schema::shop::customer::dsl::*};
+
+#[derive(Derivative, new)]
+#[derivative(Debug)]
+pub struct PostgresCustomerRepository {
+    #[derivative(Debug = "ignore")]
+    pub connection: PgConnection,
+    pub event_publisher: AM<dyn DomainEventPublisher<CustomerEventEnum>>,
+}
+
+impl PostgresCustomerRepository {
+    fn update(&mut self, customer_param: Customer) {
+        let connection = &mut self.connection;
+        let new_customer = CustomerDbDto::from(customer_param.clone());
+        let customer_id = customer_param.id().to_uuid();
+        let previous_version = customer_param.version().previous().to_i64();
+
+        let updated = diesel::update(customer)
+            .filter(id.eq(customer_id))
+            .filter(version.eq(previous_version))
+            .set(&new_customer)
+            .execute(connection)
+            .unwrap_or(0);
+        if updated == 0 {
+            panic!(
+                "Customer #{} [version = {}] is outdated",
+                customer_id,
+                customer_param.version().to_i64()
+            )
+        }
+    }
+
+    fn insert(&mut self, customer_param: Customer) {
+        let connection = &mut self.connection;
+        let new_customer = CustomerDbDto::from(customer_param);
+        diesel::insert_into(customer)
+            .values(&new_customer)
+            .returning(CustomerDbDto::as_returning())
+            .get_result(connection)
+            .expect("Error saving new customer");
+    }
+}
+
+#[async_trait]
+impl CustomerPersister for PostgresCustomerRepository {
+    async fn save(&mut self, mut customer_param: Customer) {
+        let events = customer_param.pop_events();
+        if !events.is_empty() {
+            let registered = events.iter().any(|event| {
+                matches!(event, CustomerEventEnum::CustomerRegisteredDomainEvent(x) if &x.customer_id == customer_param.id())
+            });
+            if registered {
+                self.insert(customer_param);
+            } else {
+                self.update(customer_param);
+            }
+            self.event_publisher.lock().await.publish(&events).await;
+        }
+    }
+}
+
+impl CustomerExtractor for PostgresCustomerRepository {
+    fn get_by_id(&mut self, customer_id: &CustomerId) -> Option<Customer> {
+        let connection = &mut self.connection;
+        let result = customer
+            .find(customer_id.to_uuid())
+            .select(CustomerDbDto::as_select())
+            .get_result(connection)
+            .ok()?;
+
+        Some(Customer::from(result))
+    }
+
+    fn get_by_phone(&mut self, customer_phone: &PhoneNumber) -> Option<Customer> {
+        let connection = &mut self.connection;
+        let result = customer
+            .filter(phone.eq(customer_phone.to_string()))
+            .filter(removed.eq(false))
+            .select(CustomerDbDto::as_select())
+            .first(connection)
+            .ok()?;
+
+        Some(Customer::from(result))
+    }
+}
@@ -0,0 +1,48 @@
+use async_trait::async_trait;
+use derivative::Derivative;
+use derive_new::new;
+use diesel::{ExpressionMethods, PgConnection, QueryDsl, RunQueryDsl, SelectableHelper};
+use usecase::webhook::{
+    access::{
+        failed_webhook_delivery_extractor::FailedWebhookDeliveryExtractor,
+        failed_webhook_delivery_persister::FailedWebhookDeliveryPersister,
+    },
+    dto::failed_webhook_delivery::FailedWebhookDelivery,
+};
+
+use crate::{
+    failed_webhook_delivery_db_dto::{FailedWebhookDeliveryDbDto, NewFailedWebhookDeliveryDbDto},
+    schema::shop::failed_webhook_delivery::dsl::*,
+};
+
+#[derive(Derivative, new)]
+#[derivative(Debug)]
+pub struct PostgresFailedWebhookDeliveryRepository {
+    #[derivative(Debug = "ignore")]
+    pub connection: PgConnection,
+}
+
+#[async_trait]
+impl FailedWebhookDeliveryPersister for PostgresFailedWebhookDeliveryRepository {
+    async fn save(&mut self, delivery: FailedWebhookDelivery) {
+        let connection = &mut self.connection;
+        diesel::insert_into(failed_webhook_delivery)
+            .values(&NewFailedWebhookDeliveryDbDto::from(delivery))
+            .execute(connection)
+            .expect("Error saving failed webhook delivery");
+    }
+}
+
+impl FailedWebhookDeliveryExtractor for PostgresFailedWebhookDeliveryRepository {
+    fn get_all(&mut self) -> Vec<FailedWebhookDelivery> {
+        let connection = &mut self.connection;
+        failed_webhook_delivery
+            .order((failed_at.desc(), id.desc()))
+            .select(FailedWebhookDeliveryDbDto::as_select())
+            .load(connection)
+            .expect("Error loading failed webhook deliveries")
+            .into_iter()
+            .map(FailedWebhookDelivery::from)
+            .collect()
+    }
+}
@@ -2,13 +2,23 @@ use async_trait::async_trait;
 use common::{events::DomainEventPublisher, types::base::AM};
 use derivative::Derivative;
 use derive_new::new;
-use diesel::{ExpressionMethods, PgConnection, QueryDsl, RunQueryDsl, SelectableHelper};
+use diesel::{
+    ExpressionMethods, PgArrayExpressionMethods, PgConnection, QueryDsl, RunQueryDsl,
+    SelectableHelper,
+    dsl::not,
+    sql_query,
+    sql_types::{BigInt, Text},
+};
 use domain::menu::{
     meal::Meal,
     meal_events::MealEventEnum,
-    value_objects::{meal_id::MealId, meal_name::MealName},
+    value_objects::{allergens::Allergens, meal_id::MealId, meal_name::MealName},
+};
+use usecase::menu::access::{
+    meal_extractor::MealExtractor,
+    meal_persister::MealPersister,
+    meal_searcher::{MealSearcher, search_words},
 };
-use usecase::menu::access::{meal_extractor::MealExtractor, meal_persister::MealPersister};
 
 use crate::{meal_db_dto::MealDbDto, schema::shop::meal::dsl::*};
 
@@ -112,17 +122,59 @@ impl MealExtractor for PostgresMealRepository {
         }
     }
 
-    fn get_all(&mut self) -> Vec<Meal> {
+    fn get_all(
+        &mut self,
+        start_id: &MealId,
+        limit: usize,
+        excluded_allergens: &Allergens,
+    ) -> Vec<Meal> {
         use super::schema::shop::meal::dsl::*;
         let connection = &mut self.connection;
 
-        let result = meal.select(MealDbDto::as_select()).load(connection);
+        let result = meal
+            .filter(removed.eq(false))
+            .filter(id.ge(start_id.to_i64()))
+            .filter(not(allergens.overlaps_with(excluded_allergens.to_codes())))
+            .order_by(id.asc())
+            .limit(limit as i64)
+            .select(MealDbDto::as_select())
+            .load(connection);
 
         result
             .unwrap()
-            .iter()
-            .filter(|meal_res_iter| !meal_res_iter.removed)
-            .map(|meal_res_iter| Meal::from(meal_res_iter.clone()))
+            .into_iter()
+            .map(Meal::from)
             .collect::<Vec<Meal>>()
     }
 }
+
+impl MealSearcher for PostgresMealRepository {
+    fn search(&mut self, text: &str, limit: usize) -> Vec<Meal> {
+        let words = search_words(text);
+        if words.is_empty() {
+            return vec![];
+        }
+        // Every word is a prefix, e.g. "chick wings" -> "chick:* & wings:*"
+        let ts_query = words
+            .iter()
+            .map(|word| format!("{word}:*"))
+            .collect::<Vec<_>>()
+            .join(" & ");
+        let connection = &mut self.connection;
+
+        sql_query(
+            "SELECT id, name, description, removed, price, version, \
+             allergens, calories, proteins, fats, carbohydrates, availability_schedule \
+             FROM shop.meal WHERE NOT removed AND search_vector @@ to_tsquery('english', $1) \
+             ORDER BY ts_rank(search_vector, to_tsquery('english', $1)) DESC, id \
+             LIMIT $2",
+        )
+        .bind::<Text, _>(ts_query)
+        .bind::<BigInt, _>(limit as i64)
+        .load::<MealDbDto>(connection)
+        .unwrap()
+        .into_iter()
+        .map(Meal::from)
+        .collect()
+    }
+}
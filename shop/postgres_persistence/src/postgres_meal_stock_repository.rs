@@ -0,0 +1,98 @@
+use async_trait::async_trait;
+use common::{events::DomainEventPublisher, types::base::AM};
+use derivative::Derivative;
+use derive_new::new;
+use diesel::{ExpressionMethods, PgConnection, QueryDsl, RunQueryDsl, SelectableHelper};
+use domain::{
+    inventory::{meal_stock::MealStock, meal_stock_events::MealStockEventEnum},
+    menu::value_objects::meal_id::MealId,
+};
+use usecase::inventory::access::{
+    meal_stock_extractor::MealStockExtractor, meal_stock_persister::MealStockPersister,
+};
+
+use crate::{meal_stock_db_dto::MealStockDbDto, schema::shop::meal_stock::dsl::*};
+
+#[derive(Derivative, new)]
+#[derivative(Debug)]
+pub struct PostgresMealStockRepository {
+    #[derivative(Debug = "ignore")]
+    pub connection: PgConnection,
+    pub event_publisher: AM<dyn DomainEventPublisher<MealStockEventEnum>>,
+}
+
+impl PostgresMealStockRepository {
+    fn update(&mut self, stock: MealStock) {
+        let connection = &mut self.connection;
+        let new_stock = MealStockDbDto::from(stock.clone());
+        let stock_meal_id = stock.meal_id().to_i64();
+        let previous_version = stock.version().previous().to_i64();
+
+        let updated = diesel::update(meal_stock)
+            .filter(meal_id.eq(stock_meal_id))
+            .filter(version.eq(previous_version))
+            .set(&new_stock)
+            .execute(connection)
+            .unwrap_or(0);
+        if updated == 0 {
+            panic!(
+                "Stock of meal #{} [version = {}] is outdated",
+                stock_meal_id,
+                stock.version().to_i64()
+            )
+        }
+    }
+
+    fn insert(&mut self, stock: MealStock) {
+        let connection = &mut self.connection;
+        let new_stock = MealStockDbDto::from(stock);
+        diesel::insert_into(meal_stock)
+            .values(&new_stock)
+            .returning(MealStockDbDto::as_returning())
+            .get_result(connection)
+            .expect("Error saving new meal stock");
+    }
+}
+
+#[async_trait]
+impl MealStockPersister for PostgresMealStockRepository {
+    async fn save(&mut self, mut stock: MealStock) {
+        let events = stock.pop_events();
+        if !events.is_empty() {
+            let created = events.iter().any(|event| {
+                matches!(event, MealStockEventEnum::MealStockCreatedDomainEvent(x) if &x.meal_id == stock.meal_id())
+            });
+            if created {
+                self.insert(stock);
+            } else {
+                self.update(stock);
+            }
+            self.event_publisher.lock().await.publish(&events).await;
+        }
+    }
+}
+
+impl MealStockExtractor for PostgresMealStockRepository {
+    fn get_by_meal_id(&mut self, stock_meal_id: &MealId) -> Option<MealStock> {
+        let connection = &mut self.connection;
+        let result = meal_stock
+            .find(stock_meal_id.to_i64())
+            .select(MealStockDbDto::as_select())
+            .get_result(connection)
+            .ok()?;
+
+        Some(MealStock::from(result))
+    }
+
+    fn get_by_meal_ids(&mut self, meal_ids: &[MealId]) -> Vec<MealStock> {
+        let connection = &mut self.connection;
+        meal_stock
+            .filter(meal_id.eq_any(meal_ids.iter().map(MealId::to_i64)))
+            .select(MealStockDbDto::as_select())
+            .load(connection)
+            .expect("Error loading meal stocks")
+            .into_iter()
+            .map(MealStock::from)
+            .collect()
+    }
+}
@@ -0,0 +1,217 @@
+use async_trait::async_trait;
+use common::{events::DomainEventPublisher, types::base::AM};
+use derivative::Derivative;
+use derive_new::new;
+use diesel::{
+    BoolExpressionMethods, ExpressionMethods, PgConnection, QueryDsl, RunQueryDsl, SelectableHelper,
+};
+use domain::{
+    cart::value_objects::customer_id::CustomerId,
+    order::{
+        customer_order_events::ShopOrderEventEnum,
+        shop_order::ShopOrder,
+        value_objects::{delivery_slot::DeliverySlot, shop_order_id::ShopOrderId},
+    },
+};
+use usecase::order::access::{
+    shop_order_criteria::{ShopOrderCriteria, ShopOrderSortField, SortDirection},
+    shop_order_extractor::ShopOrderExtractor,
+    shop_order_persister::ShopOrderPersister,
+};
+
+use crate::{
+    schema::shop::shop_order::dsl::*,
+    shop_order_db_dto::{ACTIVE_STATES, ShopOrderDbDto, state_to_db},
+};
+
+#[derive(Derivative, new)]
+#[derivative(Debug)]
+pub struct PostgresShopOrderRepository {
+    #[derivative(Debug = "ignore")]
+    pub connection: PgConnection,
+    pub event_publisher: AM<dyn DomainEventPublisher<ShopOrderEventEnum>>,
+}
+
+impl PostgresShopOrderRepository {
+    fn update(&mut self, order: ShopOrder) {
+        let connection = &mut self.connection;
+        let new_order = ShopOrderDbDto::from(order.clone());
+        let order_id = order.id().to_i64();
+        let previous_version = order.version().previous().to_i64();
+
+        let updated = diesel::update(shop_order)
+            .filter(id.eq(order_id))
+            .filter(version.eq(previous_version))
+            .set(&new_order)
+            .execute(connection)
+            .unwrap_or(0);
+        if updated == 0 {
+            panic!(
+                "Shop order #{} [version = {}] is outdated",
+                order_id,
+                order.version().to_i64()
+            )
+        }
+    }
+
+    fn insert(&mut self, order: ShopOrder) {
+        let connection = &mut self.connection;
+        let new_order = ShopOrderDbDto::from(order);
+        diesel::insert_into(shop_order)
+            .values(&new_order)
+            .returning(ShopOrderDbDto::as_returning())
+            .get_result(connection)
+            .expect("Error saving new shop order");
+    }
+}
+
+#[async_trait]
+impl ShopOrderPersister for PostgresShopOrderRepository {
+    async fn save(&mut self, mut order: ShopOrder) {
+        let events = order.pop_events();
+        if !events.is_empty() {
+            let is_new = events.iter().any(|event| {
+                matches!(event, ShopOrderEventEnum::ShopOrderCreatedDomainEvent(x) if &x.order_id == order.id())
+            });
+            if is_new {
+                self.insert(order);
+            } else {
+                self.update(order);
+            }
+            self.event_publisher.lock().await.publish(&events).await;
+        }
+    }
+}
+
+impl ShopOrderExtractor for PostgresShopOrderRepository {
+    fn get_by_id(&mut self, order_id: &ShopOrderId) -> Option<ShopOrder> {
+        let connection = &mut self.connection;
+        let result = shop_order
+            .find(order_id.to_i64())
+            .select(ShopOrderDbDto::as_select())
+            .get_result(connection)
+            .ok()?;
+
+        Some(ShopOrder::from(result))
+    }
+
+    fn get_last_order(&mut self, for_customer: &CustomerId) -> Option<ShopOrder> {
+        let connection = &mut self.connection;
+        let result = shop_order
+            .filter(customer_id.eq(for_customer.to_uuid()))
+            .order_by(created.desc())
+            .select(ShopOrderDbDto::as_select())
+            .first(connection)
+            .ok()?;
+
+        Some(ShopOrder::from(result))
+    }
+
+    fn get_all(
+        &mut self,
+        criteria: &ShopOrderCriteria,
+        start_id: &ShopOrderId,
+        limit: usize,
+    ) -> Vec<ShopOrder> {
+        let cursor = self.get_by_id(start_id).map(ShopOrderDbDto::from);
+        let mut query = shop_order.into_boxed();
+
+        if let Some(order_state) = &criteria.state {
+            query = query.filter(state.eq(state_to_db(order_state)));
+        }
+        if let Some(for_customer) = &criteria.customer_id {
+            query = query.filter(customer_id.eq(for_customer.to_uuid()));
+        }
+        if let Some(from) = criteria.created_from {
+            query = query.filter(created.ge(from));
+        }
+        if let Some(to) = criteria.created_to {
+            query = query.filter(created.lt(to));
+        }
+        if let Some(min_total) = &criteria.min_total {
+            query = query.filter(total.ge(min_total.to_bigdecimal()));
+        }
+
+        // Keyset pagination: the page starts at the cursor order in the requested ordering
+        query = match (criteria.sort_by, criteria.direction, cursor) {
+            (ShopOrderSortField::Id, SortDirection::Asc, _) => {
+                query.filter(id.ge(start_id.to_i64()))
+            }
+            (ShopOrderSortField::Id, SortDirection::Desc, _) => {
+                query.filter(id.le(start_id.to_i64()))
+            }
+            (_, _, None) => query,
+            (ShopOrderSortField::Created, SortDirection::Asc, Some(c)) => query.filter(
+                created
+                    .gt(c.created)
+                    .or(created.eq(c.created).and(id.ge(c.id))),
+            ),
+            (ShopOrderSortField::Created, SortDirection::Desc, Some(c)) => query.filter(
+                created
+                    .lt(c.created)
+                    .or(created.eq(c.created).and(id.le(c.id))),
+            ),
+            (ShopOrderSortField::Total, SortDirection::Asc, Some(c)) => query.filter(
+                total
+                    .gt(c.total.clone())
+                    .or(total.eq(c.total).and(id.ge(c.id))),
+            ),
+            (ShopOrderSortField::Total, SortDirection::Desc, Some(c)) => query.filter(
+                total
+                    .lt(c.total.clone())
+                    .or(total.eq(c.total).and(id.le(c.id))),
+            ),
+        };
+
+        query = match (criteria.sort_by, criteria.direction) {
+            (ShopOrderSortField::Id, SortDirection::Asc) => query.order_by(id.asc()),
+            (ShopOrderSortField::Id, SortDirection::Desc) => query.order_by(id.desc()),
+            (ShopOrderSortField::Created, SortDirection::Asc) => {
+                query.order_by(created.asc()).then_order_by(id.asc())
+            }
+            (ShopOrderSortField::Created, SortDirection::Desc) => {
+                query.order_by(created.desc()).then_order_by(id.desc())
+            }
+            (ShopOrderSortField::Total, SortDirection::Asc) => {
+                query.order_by(total.asc()).then_order_by(id.asc())
+            }
+            (ShopOrderSortField::Total, SortDirection::Desc) => {
+                query.order_by(total.desc()).then_order_by(id.desc())
+            }
+        };
+
+        let connection = &mut self.connection;
+        query
+            .limit(limit as i64)
+            .select(ShopOrderDbDto::as_select())
+            .load(connection)
+            .unwrap()
+            .into_iter()
+            .map(ShopOrder::from)
+            .collect()
+    }
+
+    fn get_all_active(&mut self) -> Vec<ShopOrder> {
+        let connection = &mut self.connection;
+        shop_order
+            .filter(state.eq_any(ACTIVE_STATES))
+            .order_by(id.asc())
+            .select(ShopOrderDbDto::as_select())
+            .load(connection)
+            .unwrap()
+            .into_iter()
+            .map(ShopOrder::from)
+            .collect()
+    }
+
+    fn count_active_by_delivery_slot(&mut self, slot: &DeliverySlot) -> usize {
+        let connection = &mut self.connection;
+        shop_order
+            .filter(delivery_slot_start.eq(slot.start()))
+            .filter(delivery_slot_end.eq(slot.end()))
+            .filter(state.eq_any(ACTIVE_STATES))
+            .count()
+            .get_result::<i64>(connection)
+            .unwrap() as usize
+    }
+}
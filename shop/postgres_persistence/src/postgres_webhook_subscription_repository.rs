@@ -0,0 +1,106 @@
+use async_trait::async_trait;
+use common::{events::DomainEventPublisher, types::base::AM};
+use derivative::Derivative;
+use derive_new::new;
+use diesel::{ExpressionMethods, PgConnection, QueryDsl, RunQueryDsl, SelectableHelper};
+use domain::webhook::{
+    value_objects::webhook_subscription_id::WebhookSubscriptionId,
+    webhook_subscription::WebhookSubscription,
+    webhook_subscription_events::WebhookSubscriptionEventEnum,
+};
+use usecase::webhook::access::{
+    webhook_subscription_extractor::WebhookSubscriptionExtractor,
+    webhook_subscription_persister::WebhookSubscriptionPersister,
+};
+
+use crate::{
+    schema::shop::webhook_subscription::dsl::*,
+    webhook_subscription_db_dto::WebhookSubscriptionDbDto,
+};
+
+#[derive(Derivative, new)]
+#[derivative(Debug)]
+pub struct PostgresWebhookSubscriptionRepository {
+    #[derivative(Debug = "ignore")]
+    pub connection: PgConnection,
+    pub event_publisher: AM<dyn DomainEventPublisher<WebhookSubscriptionEventEnum>>,
+}
+
+impl PostgresWebhookSubscriptionRepository {
+    fn update(&mut self, subscription: WebhookSubscription) {
+        let connection = &mut self.connection;
+        let new_subscription = WebhookSubscriptionDbDto::from(subscription.clone());
+        let subscription_id = subscription.id().to_uuid();
+        let previous_version = subscription.version().previous().to_i64();
+
+        let updated = diesel::update(webhook_subscription)
+            .filter(id.eq(subscription_id))
+            .filter(version.eq(previous_version))
+            .set(&new_subscription)
+            .execute(connection)
+            .unwrap_or(0);
+        if updated == 0 {
+            panic!(
+                "Webhook subscription #{} [version = {}] is outdated",
+                subscription_id,
+                subscription.version().to_i64()
+            )
+        }
+    }
+
+    fn insert(&mut self, subscription: WebhookSubscription) {
+        let connection = &mut self.connection;
+        let new_subscription = WebhookSubscriptionDbDto::from(subscription);
+        diesel::insert_into(webhook_subscription)
+            .values(&new_subscription)
+            .returning(WebhookSubscriptionDbDto::as_returning())
+            .get_result(connection)
+            .expect("Error saving new webhook subscription");
+    }
+}
+
+#[async_trait]
+impl WebhookSubscriptionPersister for PostgresWebhookSubscriptionRepository {
+    async fn save(&mut self, mut subscription: WebhookSubscription) {
+        let events = subscription.pop_events();
+        if !events.is_empty() {
+            let created = events.iter().any(|event| {
+                matches!(event, WebhookSubscriptionEventEnum::WebhookSubscriptionCreatedDomainEvent(x) if &x.subscription_id == subscription.id())
+            });
+            if created {
+                self.insert(subscription);
+            } else {
+                self.update(subscription);
+            }
+            self.event_publisher.lock().await.publish(&events).await;
+        }
+    }
+}
+
+impl WebhookSubscriptionExtractor for PostgresWebhookSubscriptionRepository {
+    fn get_by_id(
+        &mut self,
+        subscription_id: &WebhookSubscriptionId,
+    ) -> Option<WebhookSubscription> {
+        let connection = &mut self.connection;
+        let result = webhook_subscription
+            .find(subscription_id.to_uuid())
+            .select(WebhookSubscriptionDbDto::as_select())
+            .get_result(connection)
+            .ok()?;
+
+        Some(WebhookSubscription::from(result))
+    }
+
+    fn get_all(&mut self) -> Vec<WebhookSubscription> {
+        let connection = &mut self.connection;
+        webhook_subscription
+            .filter(removed.eq(false))
+            .select(WebhookSubscriptionDbDto::as_select())
+            .load(connection)
+            .expect("Error loading webhook subscriptions")
+            .into_iter()
+            .map(WebhookSubscription::from)
+            .collect()
+    }
+}
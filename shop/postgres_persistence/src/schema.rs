@@ -1,6 +1,32 @@
 // @generated automatically by Diesel CLI.
 
 pub mod shop {
+    diesel::table! {
+        shop.customer (id) {
+            id -> Uuid,
+            name -> Varchar,
+            phone -> Varchar,
+            addresses -> Jsonb,
+            preferences -> Jsonb,
+            removed -> Bool,
+            version -> Int8,
+        }
+    }
+
+    diesel::table! {
+        shop.failed_webhook_delivery (id) {
+            id -> Int8,
+            subscription_id -> Uuid,
+            event_id -> Uuid,
+            event_type -> Varchar,
+            url -> Varchar,
+            payload -> Text,
+            attempts -> Int4,
+            last_error -> Varchar,
+            failed_at -> Timestamptz,
+        }
+    }
+
     diesel::table! {
         shop.meal (id) {
             id -> Int8,
@@ -9,6 +35,56 @@ pub mod shop {
             removed -> Bool,
             price -> Numeric,
             version -> Int8,
+            allergens -> Array<Text>,
+            calories -> Int4,
+            proteins -> Numeric,
+            fats -> Numeric,
+            carbohydrates -> Numeric,
+            availability_schedule -> Nullable<Jsonb>,
+        }
+    }
+
+    diesel::table! {
+        shop.meal_stock (meal_id) {
+            meal_id -> Int8,
+            available -> Int4,
+            version -> Int8,
+        }
+    }
+
+    diesel::table! {
+        shop.shop_order (id) {
+            id -> Int8,
+            created -> Timestamptz,
+            customer_id -> Uuid,
+            fulfilment -> Jsonb,
+            delivery_fee -> Numeric,
+            delivery_slot_start -> Nullable<Timestamptz>,
+            delivery_slot_end -> Nullable<Timestamptz>,
+            items -> Jsonb,
+            state -> Varchar,
+            total -> Numeric,
+            version -> Int8,
         }
     }
+
+    diesel::table! {
+        shop.webhook_subscription (id) {
+            id -> Uuid,
+            url -> Varchar,
+            secret -> Varchar,
+            event_types -> Array<Text>,
+            removed -> Bool,
+            version -> Int8,
+        }
+    }
+
+    diesel::allow_tables_to_appear_in_same_query!(
+        customer,
+        failed_webhook_delivery,
+        meal,
+        meal_stock,
+        shop_order,
+        webhook_subscription,
+    );
 }
@@ -0,0 +1,114 @@
+use bigdecimal::BigDecimal;
+use common::types::base::Version;
+use diesel::prelude::*;
+use domain::{
+    cart::value_objects::customer_id::CustomerId,
+    menu::value_objects::price::Price,
+    order::{
+        shop_order::{OrderState, ShopOrder},
+        shop_order_restorer::ShopOrderRestorer,
+        value_objects::{delivery_slot::DeliverySlot, shop_order_id::ShopOrderId},
+    },
+};
+use serde::*;
+use time::OffsetDateTime;
+use uuid::Uuid;
+
+/// Values of the `state` column of orders which are still in progress
+pub const ACTIVE_STATES: [&str; 4] = [
+    "waiting_for_payment",
+    "paid",
+    "confirmed",
+    "ready_for_pickup",
+];
+
+#[derive(
+    Insertable,
+    Identifiable,
+    Queryable,
+    QueryableByName,
+    Selectable,
+    AsChangeset,
+    Serialize,
+    Deserialize,
+    Clone,
+    Debug,
+)]
+#[diesel(primary_key(id))]
+#[diesel(table_name = crate::schema::shop::shop_order)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+#[diesel(treat_none_as_null = true)]
+pub struct ShopOrderDbDto {
+    pub id: i64,
+    pub created: OffsetDateTime,
+    pub customer_id: Uuid,
+    pub fulfilment: serde_json::Value,
+    pub delivery_fee: BigDecimal,
+    pub delivery_slot_start: Option<OffsetDateTime>,
+    pub delivery_slot_end: Option<OffsetDateTime>,
+    pub items: serde_json::Value,
+    pub state: String,
+    pub total: BigDecimal,
+    pub version: i64,
+}
+
+impl From<ShopOrder> for ShopOrderDbDto {
+    fn from(value: ShopOrder) -> Self {
+        Self {
+            id: value.id().to_i64(),
+            created: *value.created(),
+            customer_id: value.for_customer().to_uuid(),
+            fulfilment: serde_json::to_value(value.fulfilment()).unwrap(),
+            delivery_fee: value.delivery_fee().to_bigdecimal(),
+            delivery_slot_start: value.delivery_slot().map(|it| *it.start()),
+            delivery_slot_end: value.delivery_slot().map(|it| *it.end()),
+            items: serde_json::to_value(value.order_items()).unwrap(),
+            state: state_to_db(value.state()).to_string(),
+            total: value.total_price().to_bigdecimal(),
+            version: value.version().to_i64(),
+        }
+    }
+}
+
+impl From<ShopOrderDbDto> for ShopOrder {
+    fn from(value: ShopOrderDbDto) -> Self {
+        let delivery_slot = match (value.delivery_slot_start, value.delivery_slot_end) {
+            (Some(start), Some(end)) => Some(DeliverySlot::try_from((start, end)).unwrap()),
+            _ => None,
+        };
+        ShopOrderRestorer::restore_order(
+            ShopOrderId::try_from(value.id).unwrap(),
+            value.created,
+            CustomerId::from(value.customer_id),
+            serde_json::from_value(value.fulfilment).unwrap(),
+            Price::try_from(value.delivery_fee).unwrap(),
+            delivery_slot,
+            serde_json::from_value(value.items).unwrap(),
+            state_from_db(&value.state),
+            Version::from(value.version),
+        )
+    }
+}
+
+pub fn state_to_db(state: &OrderState) -> &'static str {
+    match state {
+        OrderState::WaitingForPayment(_) => "waiting_for_payment",
+        OrderState::Paid(_) => "paid",
+        OrderState::Confirmed(_) => "confirmed",
+        OrderState::ReadyForPickup(_) => "ready_for_pickup",
+        OrderState::Completed(_) => "completed",
+        OrderState::Cancelled(_) => "cancelled",
+    }
+}
+
+fn state_from_db(state: &str) -> OrderState {
+    match state {
+        "waiting_for_payment" => OrderState::new_waiting_for_payment(),
+        "paid" => OrderState::new_paid(),
+        "confirmed" => OrderState::new_confirmed(),
+        "ready_for_pickup" => OrderState::new_ready_for_pickup(),
+        "completed" => OrderState::new_completed(),
+        "cancelled" => OrderState::new_cancelled(),
+        _ => panic!("Unknown order state '{state}'"),
+    }
+}
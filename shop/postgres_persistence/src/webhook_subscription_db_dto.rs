@@ -0,0 +1,70 @@
+use common::types::base::Version;
+use diesel::prelude::*;
+use domain::webhook::{
+    value_objects::{
+        webhook_event_type::WebhookEventType, webhook_secret::WebhookSecret,
+        webhook_subscription_id::WebhookSubscriptionId, webhook_url::WebhookUrl,
+    },
+    webhook_subscription::WebhookSubscription,
+    webhook_subscription_restorer::WebhookSubscriptionRestorer,
+};
+use serde::*;
+use uuid::Uuid;
+
+#[derive(
+    Insertable,
+    Identifiable,
+    Queryable,
+    QueryableByName,
+    Selectable,
+    AsChangeset,
+    Serialize,
+    Deserialize,
+    Clone,
+    Debug,
+)]
+#[diesel(primary_key(id))]
+#[diesel(table_name = crate::schema::shop::webhook_subscription)]
+#[diesel(check_for_backend(diesel::pg::Pg))]
+pub struct WebhookSubscriptionDbDto {
+    pub id: Uuid,
+    pub url: String,
+    pub secret: String,
+    pub event_types: Vec<String>,
+    pub removed: bool,
+    pub version: i64,
+}
+
+impl From<WebhookSubscription> for WebhookSubscriptionDbDto {
+    fn from(value: WebhookSubscription) -> Self {
+        Self {
+            id: value.id().to_uuid(),
+            url: value.url().to_string(),
+            secret: value.secret().to_string_value(),
+            event_types: value
+                .event_types()
+                .iter()
+                .map(|event_type| event_type.to_string())
+                .collect(),
+            removed: *value.removed(),
+            version: value.version().to_i64(),
+        }
+    }
+}
+
+impl From<WebhookSubscriptionDbDto> for WebhookSubscription {
+    fn from(value: WebhookSubscriptionDbDto) -> Self {
+        WebhookSubscriptionRestorer::restore_webhook_subscription(
+            WebhookSubscriptionId::from(value.id),
+            WebhookUrl::try_from(value.url.as_str()).unwrap(),
+            WebhookSecret::try_from(value.secret.as_str()).unwrap(),
+            value
+                .event_types
+                .iter()
+                .map(|event_type| WebhookEventType::try_from(event_type.as_str()).unwrap())
+                .collect(),
+            value.removed,
+            Version::from(value.version),
+        )
+    }
+}
@@ -0,0 +1,155 @@
+#![allow(non_snake_case)]
+
+use common::types::base::{AM, AMTrait};
+use diesel_migrations::MigrationHarness;
+use domain::{
+    customer::customer_events::{CustomerEventEnum, CustomerRegisteredDomainEvent},
+    test_fixtures::*,
+};
+use postgres_persistence::{
+    database_start::MIGRATIONS, postgres_customer_repository::PostgresCustomerRepository,
+};
+use usecase::customer::access::{
+    customer_extractor::CustomerExtractor, customer_persister::CustomerPersister,
+};
+
+use crate::test_fixtures::{MockCustomerEventPublisher, TestDb, rnd_new_customer};
+
+mod test_fixtures;
+
+#[tokio::test]
+async fn save_new_instance() {
+    let customer = rnd_new_customer().await;
+
+    let db = TestDb::new().await;
+    let mut conn = db.conn();
+
+    conn.run_pending_migrations(MIGRATIONS).unwrap();
+
+    let publisher = AM::new_am(MockCustomerEventPublisher::default());
+    let mut repository = PostgresCustomerRepository::new(conn, publisher.clone());
+    repository.save(customer.clone()).await;
+
+    let events = &publisher.lock().await.events;
+    assert_eq!(events.len(), 1);
+    assert!(matches!(
+        events.first().unwrap(),
+        CustomerEventEnum::CustomerRegisteredDomainEvent(CustomerRegisteredDomainEvent { customer_id, .. }) if customer_id == customer.id()
+    ));
+
+    let result = repository.get_by_id(customer.id()).unwrap();
+    assert_eq!(result.name(), customer.name());
+    assert_eq!(result.phone(), customer.phone());
+}
+
+#[tokio::test]
+#[should_panic(
+    expected = "Error saving new customer: DatabaseError(UniqueViolation, \"duplicate key value violates unique constraint \\\"customer_phone_unique\\\"\")"
+)]
+async fn save_new_instance_but_already_exists_with_the_same_phone() {
+    let db = TestDb::new().await;
+    let mut conn = db.conn();
+
+    conn.run_pending_migrations(MIGRATIONS).unwrap();
+
+    let mut repository =
+        PostgresCustomerRepository::new(conn, AM::new_am(MockCustomerEventPublisher::default()));
+
+    let first = rnd_new_customer().await;
+    let mut second = rnd_new_customer().await;
+    second
+        .update(
+            AM::new_am(TestCustomerAlreadyExists::new()),
+            second.name().clone(),
+            first.phone().clone(),
+            *second.preferences(),
+        )
+        .await
+        .unwrap();
+
+    repository.save(first).await;
+    repository.save(second).await;
+}
+
+#[tokio::test]
+async fn create_new_instance_and_then_add_address() {
+    let db = TestDb::new().await;
+    let mut conn = db.conn();
+
+    conn.run_pending_migrations(MIGRATIONS).unwrap();
+
+    let mut repository =
+        PostgresCustomerRepository::new(conn, AM::new_am(MockCustomerEventPublisher::default()));
+
+    let customer = rnd_new_customer().await;
+    let customer_id = *customer.id();
+    repository.save(customer).await;
+
+    let mut customer = repository.get_by_id(&customer_id).unwrap();
+    let address = rnd_address();
+    let address_id = customer.add_address(address.clone());
+    repository.save(customer).await;
+
+    let customer = repository.get_by_id(&customer_id).unwrap();
+    assert_eq!(customer.saved_address(&address_id), Some(&address));
+}
+
+#[tokio::test]
+#[should_panic(expected = "is outdated")]
+async fn saving_failed_if_version_outdated() {
+    let db = TestDb::new().await;
+    let mut conn = db.conn();
+
+    conn.run_pending_migrations(MIGRATIONS).unwrap();
+
+    let mut repository =
+        PostgresCustomerRepository::new(conn, AM::new_am(MockCustomerEventPublisher::default()));
+
+    let customer = rnd_new_customer().await;
+    let customer_id = *customer.id();
+    repository.save(customer).await;
+
+    let mut first = repository.get_by_id(&customer_id).unwrap();
+    let mut second = first.clone();
+    first.add_address(rnd_address());
+    second.add_address(rnd_address());
+
+    repository.save(first).await;
+    repository.save(second).await;
+}
+
+#[tokio::test]
+async fn get_by_phone__removed_is_not_returned() {
+    let db = TestDb::new().await;
+    let mut conn = db.conn();
+
+    conn.run_pending_migrations(MIGRATIONS).unwrap();
+
+    let mut repository =
+        PostgresCustomerRepository::new(conn, AM::new_am(MockCustomerEventPublisher::default()));
+
+    let customer = rnd_new_customer().await;
+    let customer_id = *customer.id();
+    let phone = customer.phone().clone();
+    repository.save(customer).await;
+    assert!(repository.get_by_phone(&phone).is_some());
+
+    let mut customer = repository.get_by_id(&customer_id).unwrap();
+    customer.remove();
+    repository.save(customer).await;
+
+    assert!(repository.get_by_phone(&phone).is_none());
+}
+
+#[tokio::test]
+async fn get_by_id__not_found() {
+    let db = TestDb::new().await;
+    let mut conn = db.conn();
+
+    conn.run_pending_migrations(MIGRATIONS).unwrap();
+
+    let mut repository =
+        PostgresCustomerRepository::new(conn, AM::new_am(MockCustomerEventPublisher::default()));
+
+    assert!(repository.get_by_id(&rnd_customer_id()).is_none());
+}
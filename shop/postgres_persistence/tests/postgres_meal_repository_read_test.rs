@@ -2,14 +2,25 @@
 
 use common::types::base::{AM, AMTrait};
 use diesel_migrations::MigrationHarness;
-use domain::test_fixtures::*;
+use domain::{
+    menu::value_objects::{
+        allergens::{Allergen, Allergens},
+        meal_id::MealId,
+    },
+    test_fixtures::*,
+};
 use postgres_persistence::{
     database_start::MIGRATIONS, postgres_meal_repository::PostgresMealRepository,
 };
 use tokio::test;
-use usecase::menu::access::{meal_extractor::MealExtractor, meal_persister::MealPersister};
+use usecase::menu::access::{
+    meal_extractor::MealExtractor, meal_persister::MealPersister, meal_searcher::MealSearcher,
+};
 
-use crate::test_fixtures::{MockEventPublisher, TestDb, rnd_new_meal_with_meal_id};
+use crate::test_fixtures::{
+    MockEventPublisher, TestDb, rnd_new_meal_with_allergens, rnd_new_meal_with_meal_id,
+    rnd_new_meal_with_text,
+};
 
 mod test_fixtures;
 
@@ -91,7 +102,7 @@ async fn get_all__table_is_empty() {
     let mut repository =
         PostgresMealRepository::new(conn, AM::new_am(MockEventPublisher::default()));
 
-    let result = repository.get_all();
+    let result = repository.get_all(&MealId::default(), 10, &Allergens::default());
 
     assert!(result.is_empty());
 }
@@ -108,7 +119,7 @@ async fn get_all__table_is_not_empty() {
         PostgresMealRepository::new(conn, AM::new_am(MockEventPublisher::default()));
     repository.save(meal.clone()).await;
 
-    let result = repository.get_all();
+    let result = repository.get_all(&MealId::default(), 10, &Allergens::default());
 
     assert!(!result.is_empty());
     assert_eq!(result.first().unwrap(), &meal);
@@ -127,7 +138,97 @@ async fn get_all__table_is_not_empty_but_removed() {
         PostgresMealRepository::new(conn, AM::new_am(MockEventPublisher::default()));
     repository.save(meal.clone()).await;
 
-    let result = repository.get_all();
+    let result = repository.get_all(&MealId::default(), 10, &Allergens::default());
 
     assert!(result.is_empty());
 }
+
+#[tokio::test]
+async fn get_all__page_starts_at_start_id_ordered_by_id() {
+    let db = TestDb::new().await;
+    let mut conn = db.conn();
+
+    conn.run_pending_migrations(MIGRATIONS).unwrap();
+
+    let mut repository =
+        PostgresMealRepository::new(conn, AM::new_am(MockEventPublisher::default()));
+    for meal_id in [4, 1, 3, 2] {
+        let meal = rnd_new_meal_with_meal_id(MealId::try_from(meal_id).unwrap()).await;
+        repository.save(meal).await;
+    }
+
+    let result = repository.get_all(&MealId::try_from(2).unwrap(), 2, &Allergens::default());
+
+    let ids: Vec<i64> = result.iter().map(|meal| meal.id().to_i64()).collect();
+    assert_eq!(ids, vec![2, 3]);
+}
+
+#[tokio::test]
+async fn get_all__excluded_allergens_are_not_returned() {
+    let db = TestDb::new().await;
+    let mut conn = db.conn();
+
+    conn.run_pending_migrations(MIGRATIONS).unwrap();
+
+    let mut repository =
+        PostgresMealRepository::new(conn, AM::new_am(MockEventPublisher::default()));
+    let with_milk = rnd_new_meal_with_allergens(
+        MealId::try_from(1).unwrap(),
+        &[Allergen::Milk, Allergen::Eggs],
+    )
+    .await;
+    let without_milk =
+        rnd_new_meal_with_allergens(MealId::try_from(2).unwrap(), &[Allergen::Eggs]).await;
+    repository.save(with_milk).await;
+    repository.save(without_milk.clone()).await;
+
+    let excluded = [Allergen::Milk].into_iter().collect();
+    let result = repository.get_all(&MealId::default(), 10, &excluded);
+
+    let ids: Vec<i64> = result.iter().map(|meal| meal.id().to_i64()).collect();
+    assert_eq!(ids, vec![without_milk.id().to_i64()]);
+}
+
+#[test]
+async fn search__ranked_by_relevance() {
+    let db = TestDb::new().await;
+    let mut conn = db.conn();
+
+    conn.run_pending_migrations(MIGRATIONS).unwrap();
+
+    let mut repository =
+        PostgresMealRepository::new(conn, AM::new_am(MockEventPublisher::default()));
+    let in_description = rnd_new_meal_with_text("Burger", "Served with chicken wings").await;
+    let in_name = rnd_new_meal_with_text("Chicken curry", "Spicy").await;
+    let not_matching = rnd_new_meal_with_text("Salad", "Fresh vegetables").await;
+    repository.save(in_description.clone()).await;
+    repository.save(in_name.clone()).await;
+    repository.save(not_matching).await;
+
+    let result = repository.search("chick", 10);
+
+    let ids: Vec<i64> = result.iter().map(|it| it.id().to_i64()).collect();
+    assert_eq!(
+        ids,
+        vec![in_name.id().to_i64(), in_description.id().to_i64()]
+    );
+}
+
+#[test]
+async fn search__removed_is_not_returned() {
+    let db = TestDb::new().await;
+    let mut conn = db.conn();
+
+    conn.run_pending_migrations(MIGRATIONS).unwrap();
+
+    let mut repository =
+        PostgresMealRepository::new(conn, AM::new_am(MockEventPublisher::default()));
+    let meal = rnd_new_meal_with_text("Pancakes", "With maple syrup").await;
+    let meal_id = *meal.id();
+    repository.save(meal).await;
+    let mut meal = repository.get_by_id(&meal_id).unwrap();
+    meal.remove_meal_from_menu();
+    repository.save(meal).await;
+
+    assert!(repository.search("pancakes syrup", 10).is_empty());
+}
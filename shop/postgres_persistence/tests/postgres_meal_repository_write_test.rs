@@ -2,7 +2,10 @@ use common::types::base::{AM, AMTrait};
 use diesel::{RunQueryDsl, sql_query};
 use diesel_migrations::MigrationHarness;
 use domain::{
-    menu::meal_events::{MealAddedToMenuDomainEvent, MealEventEnum},
+    menu::{
+        meal_events::{MealAddedToMenuDomainEvent, MealEventEnum},
+        value_objects::{allergens::Allergens, meal_id::MealId},
+    },
     test_fixtures::*,
 };
 use postgres_persistence::{
@@ -36,7 +39,7 @@ async fn save_new_instance() {
         .await
         .verify_contains(vec![MealAddedToMenuDomainEvent::new(*rnd_meal.id()).into()]);
 
-    let result = repository.get_all();
+    let result = repository.get_all(&MealId::default(), 10, &Allergens::default());
     dbg!(&result);
     assert!(!result.is_empty())
 }
@@ -112,6 +115,30 @@ async fn create_new_instance_and_then_update_it() {
     assert!(!meal_in_db.is_empty())
 }
 
+#[tokio::test]
+async fn availability_schedule_is_saved() {
+    let db = TestDb::new().await;
+    let mut conn = db.conn();
+
+    conn.run_pending_migrations(MIGRATIONS).unwrap();
+
+    let publisher = AM::new_am(MockEventPublisher::default());
+    let mut repository = PostgresMealRepository::new(conn, publisher.clone());
+
+    let rnd_meal = rnd_new_meal_with_meal_id(rnd_meal_id()).await;
+    let meal_id = *rnd_meal.id();
+    repository.save(rnd_meal).await;
+
+    let mut meal = repository.get_by_id(&meal_id).unwrap();
+    assert_eq!(meal.availability_schedule(), &None);
+    let schedule = rnd_availability_schedule();
+    meal.change_availability_schedule(Some(schedule.clone()));
+    repository.save(meal).await;
+
+    let meal = repository.get_by_id(&meal_id).unwrap();
+    assert_eq!(meal.availability_schedule(), &Some(schedule));
+}
+
 #[tokio::test]
 async fn save_again_without_changes() {
     let db = TestDb::new().await;
@@ -0,0 +1,110 @@
+#![allow(non_snake_case)]
+
+use common::types::{
+    base::{AM, AMTrait},
+    common::Count,
+};
+use diesel_migrations::MigrationHarness;
+use domain::{
+    inventory::{
+        meal_stock::MealStock,
+        meal_stock_events::{MealSoldOutDomainEvent, MealStockEventEnum},
+    },
+    test_fixtures::*,
+};
+use postgres_persistence::{
+    database_start::MIGRATIONS, postgres_meal_stock_repository::PostgresMealStockRepository,
+};
+use usecase::inventory::access::{
+    meal_stock_extractor::MealStockExtractor, meal_stock_persister::MealStockPersister,
+};
+
+use crate::test_fixtures::{MockMealStockEventPublisher, TestDb};
+
+mod test_fixtures;
+
+#[tokio::test]
+async fn save_new_instance() {
+    let db = TestDb::new().await;
+    let mut conn = db.conn();
+
+    conn.run_pending_migrations(MIGRATIONS).unwrap();
+
+    let publisher = AM::new_am(MockMealStockEventPublisher::default());
+    let mut repository = PostgresMealStockRepository::new(conn, publisher.clone());
+    let stock = MealStock::create(rnd_meal_id(), Count::one());
+    repository.save(stock.clone()).await;
+
+    assert_eq!(publisher.lock().await.events.len(), 1);
+    let result = repository.get_by_meal_id(stock.meal_id()).unwrap();
+    assert_eq!(result.available(), &Count::one());
+    assert_eq!(result.version(), stock.version());
+}
+
+#[tokio::test]
+async fn reserve_until_sold_out() {
+    let db = TestDb::new().await;
+    let mut conn = db.conn();
+
+    conn.run_pending_migrations(MIGRATIONS).unwrap();
+
+    let publisher = AM::new_am(MockMealStockEventPublisher::default());
+    let mut repository = PostgresMealStockRepository::new(conn, publisher.clone());
+    let meal_id = rnd_meal_id();
+    repository
+        .save(MealStock::create(meal_id, Count::one()))
+        .await;
+
+    let mut stock = repository.get_by_meal_id(&meal_id).unwrap();
+    stock.reserve(Count::one()).unwrap();
+    repository.save(stock).await;
+
+    let stock = repository.get_by_meal_id(&meal_id).unwrap();
+    assert!(stock.is_sold_out());
+    assert!(publisher.lock().await.events.iter().any(|event| matches!(
+        event,
+        MealStockEventEnum::MealSoldOutDomainEvent(MealSoldOutDomainEvent { meal_id: id, .. }) if id == &meal_id
+    )));
+}
+
+#[tokio::test]
+#[should_panic(expected = "is outdated")]
+async fn saving_failed_if_version_outdated() {
+    let db = TestDb::new().await;
+    let mut conn = db.conn();
+
+    conn.run_pending_migrations(MIGRATIONS).unwrap();
+
+    let mut repository =
+        PostgresMealStockRepository::new(conn, AM::new_am(MockMealStockEventPublisher::default()));
+    let meal_id = rnd_meal_id();
+    repository
+        .save(MealStock::create(meal_id, Count::try_from(5).unwrap()))
+        .await;
+
+    let mut first = repository.get_by_meal_id(&meal_id).unwrap();
+    let mut second = first.clone();
+    first.reserve(Count::one()).unwrap();
+    second.reserve(Count::one()).unwrap();
+
+    repository.save(first).await;
+    repository.save(second).await;
+}
+
+#[tokio::test]
+async fn get_by_meal_ids__untracked_meals_skipped() {
+    let db = TestDb::new().await;
+    let mut conn = db.conn();
+
+    conn.run_pending_migrations(MIGRATIONS).unwrap();
+
+    let mut repository =
+        PostgresMealStockRepository::new(conn, AM::new_am(MockMealStockEventPublisher::default()));
+    let stock = MealStock::create(rnd_meal_id(), Count::one());
+    repository.save(stock.clone()).await;
+
+    let stocks = repository.get_by_meal_ids(&[*stock.meal_id(), rnd_meal_id()]);
+
+    assert_eq!(stocks.len(), 1);
+    assert_eq!(stocks[0].meal_id(), stock.meal_id());
+}
@@ -0,0 +1,318 @@
+#![allow(non_snake_case)]
+
+use std::str::FromStr;
+
+use bigdecimal::BigDecimal;
+use common::types::{
+    base::{AM, AMTrait},
+    common::Count,
+};
+use diesel_migrations::MigrationHarness;
+use domain::{
+    menu::value_objects::price::Price,
+    order::{
+        customer_order_events::{ShopOrderCreatedDomainEvent, ShopOrderEventEnum},
+        shop_order::{OrderItem, OrderState},
+        value_objects::{delivery_slot::DeliverySlot, shop_order_id::ShopOrderId},
+    },
+    test_fixtures::*,
+};
+use postgres_persistence::{
+    database_start::MIGRATIONS, postgres_shop_order_repository::PostgresShopOrderRepository,
+};
+use time::{Duration, OffsetDateTime};
+use usecase::order::access::{
+    shop_order_criteria::{ShopOrderCriteria, ShopOrderSortField, SortDirection},
+    shop_order_extractor::ShopOrderExtractor,
+    shop_order_persister::ShopOrderPersister,
+};
+
+use crate::test_fixtures::{MockShopOrderEventPublisher, TestDb, rnd_new_order};
+
+mod test_fixtures;
+
+fn now() -> OffsetDateTime {
+    // Postgres keeps microseconds only
+    let now = OffsetDateTime::now_utc();
+    now.replace_microsecond(now.microsecond()).unwrap()
+}
+
+fn item_with_price(price: &str) -> OrderItem {
+    OrderItem::new(
+        rnd_meal_id(),
+        Price::try_from(BigDecimal::from_str(price).unwrap()).unwrap(),
+        Count::one(),
+    )
+}
+
+fn slot_in_hours(hours: i64) -> DeliverySlot {
+    let start = now() + Duration::hours(hours);
+    DeliverySlot::try_from((start, start + Duration::minutes(30))).unwrap()
+}
+
+#[tokio::test]
+async fn save_new_instance() {
+    let order = rnd_new_order(rnd_customer_id(), now(), [rnd_order_item()].into(), None);
+
+    let db = TestDb::new().await;
+    let mut conn = db.conn();
+
+    conn.run_pending_migrations(MIGRATIONS).unwrap();
+
+    let publisher = AM::new_am(MockShopOrderEventPublisher::default());
+    let mut repository = PostgresShopOrderRepository::new(conn, publisher.clone());
+    repository.save(order.clone()).await;
+
+    let events = &publisher.lock().await.events;
+    assert_eq!(events.len(), 1);
+    assert!(matches!(
+        events.first().unwrap(),
+        ShopOrderEventEnum::ShopOrderCreatedDomainEvent(ShopOrderCreatedDomainEvent { order_id, .. }) if order_id == order.id()
+    ));
+
+    let result = repository.get_by_id(order.id()).unwrap();
+    assert_eq!(result.created(), order.created());
+    assert_eq!(result.for_customer(), order.for_customer());
+    assert_eq!(result.fulfilment(), order.fulfilment());
+    assert_eq!(result.order_items(), order.order_items());
+    assert_eq!(result.state(), order.state());
+    assert_eq!(result.version(), order.version());
+}
+
+#[tokio::test]
+async fn save_new_instance_and_then_pay() {
+    let db = TestDb::new().await;
+    let mut conn = db.conn();
+
+    conn.run_pending_migrations(MIGRATIONS).unwrap();
+
+    let mut repository =
+        PostgresShopOrderRepository::new(conn, AM::new_am(MockShopOrderEventPublisher::default()));
+
+    let order = rnd_new_order(rnd_customer_id(), now(), [rnd_order_item()].into(), None);
+    let order_id = *order.id();
+    repository.save(order).await;
+
+    let mut order = repository.get_by_id(&order_id).unwrap();
+    order.pay().unwrap();
+    repository.save(order).await;
+
+    let order = repository.get_by_id(&order_id).unwrap();
+    assert_eq!(order.state(), &OrderState::new_paid());
+}
+
+#[tokio::test]
+#[should_panic(expected = "is outdated")]
+async fn saving_failed_if_version_outdated() {
+    let db = TestDb::new().await;
+    let mut conn = db.conn();
+
+    conn.run_pending_migrations(MIGRATIONS).unwrap();
+
+    let mut repository =
+        PostgresShopOrderRepository::new(conn, AM::new_am(MockShopOrderEventPublisher::default()));
+
+    let order = rnd_new_order(rnd_customer_id(), now(), [rnd_order_item()].into(), None);
+    let order_id = *order.id();
+    repository.save(order).await;
+
+    let mut first = repository.get_by_id(&order_id).unwrap();
+    let mut second = first.clone();
+    first.pay().unwrap();
+    second.pay().unwrap();
+
+    repository.save(first).await;
+    repository.save(second).await;
+}
+
+#[tokio::test]
+async fn get_by_id__not_found() {
+    let db = TestDb::new().await;
+    let mut conn = db.conn();
+
+    conn.run_pending_migrations(MIGRATIONS).unwrap();
+
+    let mut repository =
+        PostgresShopOrderRepository::new(conn, AM::new_am(MockShopOrderEventPublisher::default()));
+
+    assert!(repository.get_by_id(&rnd_order_id()).is_none());
+}
+
+#[tokio::test]
+async fn get_last_order__latest_created_is_returned() {
+    let db = TestDb::new().await;
+    let mut conn = db.conn();
+
+    conn.run_pending_migrations(MIGRATIONS).unwrap();
+
+    let mut repository =
+        PostgresShopOrderRepository::new(conn, AM::new_am(MockShopOrderEventPublisher::default()));
+
+    let customer_id = rnd_customer_id();
+    let older = rnd_new_order(
+        customer_id,
+        now() - Duration::hours(1),
+        [rnd_order_item()].into(),
+        None,
+    );
+    let latest = rnd_new_order(customer_id, now(), [rnd_order_item()].into(), None);
+    let latest_id = *latest.id();
+    repository.save(latest).await;
+    repository.save(older).await;
+
+    let result = repository.get_last_order(&customer_id).unwrap();
+    assert_eq!(result.id(), &latest_id);
+}
+
+#[tokio::test]
+async fn get_all__filtered_by_customer_and_min_total_sorted_by_total_desc() {
+    let db = TestDb::new().await;
+    let mut conn = db.conn();
+
+    conn.run_pending_migrations(MIGRATIONS).unwrap();
+
+    let mut repository =
+        PostgresShopOrderRepository::new(conn, AM::new_am(MockShopOrderEventPublisher::default()));
+
+    let customer_id = rnd_customer_id();
+    let mut expected: Vec<ShopOrderId> = vec![];
+    for price in ["30.00", "20.00", "10.00"] {
+        let order = rnd_new_order(customer_id, now(), [item_with_price(price)].into(), None);
+        expected.push(*order.id());
+        repository.save(order).await;
+    }
+    let cheap = rnd_new_order(customer_id, now(), [item_with_price("0.50")].into(), None);
+    repository.save(cheap).await;
+    let other = rnd_new_order(
+        rnd_customer_id(),
+        now(),
+        [item_with_price("40.00")].into(),
+        None,
+    );
+    repository.save(other).await;
+
+    let criteria = ShopOrderCriteria {
+        customer_id: Some(customer_id),
+        min_total: Some(Price::try_from(BigDecimal::from(1)).unwrap()),
+        sort_by: ShopOrderSortField::Total,
+        direction: SortDirection::Desc,
+        ..Default::default()
+    };
+    let first_page = repository.get_all(&criteria, &rnd_order_id(), 2);
+    let next_page = repository.get_all(&criteria, first_page[1].id(), 2);
+
+    let first_ids: Vec<ShopOrderId> = first_page.iter().map(|it| *it.id()).collect();
+    assert_eq!(first_ids, expected[..2].to_vec());
+    let next_ids: Vec<ShopOrderId> = next_page.iter().map(|it| *it.id()).collect();
+    assert_eq!(next_ids, expected[1..].to_vec());
+}
+
+#[tokio::test]
+async fn get_all__filtered_by_state_and_created_range() {
+    let db = TestDb::new().await;
+    let mut conn = db.conn();
+
+    conn.run_pending_migrations(MIGRATIONS).unwrap();
+
+    let mut repository =
+        PostgresShopOrderRepository::new(conn, AM::new_am(MockShopOrderEventPublisher::default()));
+
+    let created = now();
+    let in_range = rnd_new_order(rnd_customer_id(), created, [rnd_order_item()].into(), None);
+    let in_range_id = *in_range.id();
+    repository.save(in_range).await;
+    let too_late = rnd_new_order(
+        rnd_customer_id(),
+        created + Duration::days(1),
+        [rnd_order_item()].into(),
+        None,
+    );
+    repository.save(too_late).await;
+    let paid = rnd_new_order(rnd_customer_id(), created, [rnd_order_item()].into(), None);
+    let paid_id = *paid.id();
+    repository.save(paid).await;
+    let mut paid = repository.get_by_id(&paid_id).unwrap();
+    paid.pay().unwrap();
+    repository.save(paid).await;
+
+    let criteria = ShopOrderCriteria {
+        state: Some(OrderState::new_waiting_for_payment()),
+        created_from: Some(created - Duration::hours(1)),
+        created_to: Some(created + Duration::hours(1)),
+        ..Default::default()
+    };
+    let result = repository.get_all(&criteria, &ShopOrderId::try_from(0).unwrap(), 10);
+
+    let result_ids: Vec<ShopOrderId> = result.iter().map(|it| *it.id()).collect();
+    assert_eq!(result_ids, vec![in_range_id]);
+}
+
+#[tokio::test]
+async fn count_active_by_delivery_slot() {
+    let db = TestDb::new().await;
+    let mut conn = db.conn();
+
+    conn.run_pending_migrations(MIGRATIONS).unwrap();
+
+    let mut repository =
+        PostgresShopOrderRepository::new(conn, AM::new_am(MockShopOrderEventPublisher::default()));
+
+    let slot = slot_in_hours(1);
+    let active = rnd_new_order(
+        rnd_customer_id(),
+        now(),
+        [rnd_order_item()].into(),
+        Some(slot),
+    );
+    repository.save(active).await;
+    let cancelled = rnd_new_order(
+        rnd_customer_id(),
+        now(),
+        [rnd_order_item()].into(),
+        Some(slot),
+    );
+    let cancelled_id = *cancelled.id();
+    repository.save(cancelled).await;
+    let mut cancelled = repository.get_by_id(&cancelled_id).unwrap();
+    cancelled.pay().unwrap();
+    cancelled.cancel().unwrap();
+    repository.save(cancelled).await;
+    let another_slot = rnd_new_order(
+        rnd_customer_id(),
+        now(),
+        [rnd_order_item()].into(),
+        Some(slot_in_hours(2)),
+    );
+    repository.save(another_slot).await;
+
+    assert_eq!(repository.count_active_by_delivery_slot(&slot), 1);
+}
+
+#[tokio::test]
+async fn get_all_active__finished_orders_are_not_returned() {
+    let db = TestDb::new().await;
+    let mut conn = db.conn();
+
+    conn.run_pending_migrations(MIGRATIONS).unwrap();
+
+    let mut repository =
+        PostgresShopOrderRepository::new(conn, AM::new_am(MockShopOrderEventPublisher::default()));
+
+    let active = rnd_new_order(rnd_customer_id(), now(), [rnd_order_item()].into(), None);
+    let active_id = *active.id();
+    repository.save(active).await;
+    let cancelled = rnd_new_order(rnd_customer_id(), now(), [rnd_order_item()].into(), None);
+    let cancelled_id = *cancelled.id();
+    repository.save(cancelled).await;
+    let mut cancelled = repository.get_by_id(&cancelled_id).unwrap();
+    cancelled.pay().unwrap();
+    cancelled.cancel().unwrap();
+    repository.save(cancelled).await;
+
+    let result_ids: Vec<ShopOrderId> = repository
+        .get_all_active()
+        .iter()
+        .map(|it| *it.id())
+        .collect();
+    assert_eq!(result_ids, vec![active_id]);
+}
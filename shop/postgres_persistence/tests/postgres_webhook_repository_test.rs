@@ -0,0 +1,164 @@
+#![allow(non_snake_case)]
+
+use common::types::base::{AM, AMTrait, EventId};
+use diesel_migrations::MigrationHarness;
+use domain::{
+    test_fixtures::*,
+    webhook::{
+        value_objects::webhook_event_type::WebhookEventType,
+        webhook_subscription_events::{
+            WebhookSubscriptionCreatedDomainEvent, WebhookSubscriptionEventEnum,
+        },
+    },
+};
+use postgres_persistence::{
+    database_start::MIGRATIONS,
+    postgres_failed_webhook_delivery_repository::PostgresFailedWebhookDeliveryRepository,
+    postgres_webhook_subscription_repository::PostgresWebhookSubscriptionRepository,
+};
+use time::{Duration, OffsetDateTime};
+use usecase::webhook::{
+    access::{
+        failed_webhook_delivery_extractor::FailedWebhookDeliveryExtractor,
+        failed_webhook_delivery_persister::FailedWebhookDeliveryPersister,
+        webhook_subscription_extractor::WebhookSubscriptionExtractor,
+        webhook_subscription_persister::WebhookSubscriptionPersister,
+    },
+    dto::failed_webhook_delivery::FailedWebhookDelivery,
+};
+
+use crate::test_fixtures::{
+    MockWebhookSubscriptionEventPublisher, TestDb, rnd_new_webhook_subscription,
+};
+
+mod test_fixtures;
+
+#[tokio::test]
+async fn save_new_subscription() {
+    let subscription = rnd_new_webhook_subscription(vec![
+        WebhookEventType::OrderPaid,
+        WebhookEventType::OrderCompleted,
+    ])
+    .await;
+
+    let db = TestDb::new().await;
+    let mut conn = db.conn();
+
+    conn.run_pending_migrations(MIGRATIONS).unwrap();
+
+    let publisher = AM::new_am(MockWebhookSubscriptionEventPublisher::default());
+    let mut repository = PostgresWebhookSubscriptionRepository::new(conn, publisher.clone());
+    repository.save(subscription.clone()).await;
+
+    let events = &publisher.lock().await.events;
+    assert_eq!(events.len(), 1);
+    assert!(matches!(
+        events.first().unwrap(),
+        WebhookSubscriptionEventEnum::WebhookSubscriptionCreatedDomainEvent(WebhookSubscriptionCreatedDomainEvent { subscription_id, .. }) if subscription_id == subscription.id()
+    ));
+
+    let result = repository.get_by_id(subscription.id()).unwrap();
+    assert_eq!(result.url(), subscription.url());
+    assert_eq!(result.secret(), subscription.secret());
+    assert_eq!(result.event_types(), subscription.event_types());
+    assert_eq!(repository.get_all().len(), 1);
+}
+
+#[tokio::test]
+async fn removed_subscription_is_not_listed() {
+    let db = TestDb::new().await;
+    let mut conn = db.conn();
+
+    conn.run_pending_migrations(MIGRATIONS).unwrap();
+
+    let mut repository = PostgresWebhookSubscriptionRepository::new(
+        conn,
+        AM::new_am(MockWebhookSubscriptionEventPublisher::default()),
+    );
+
+    let subscription = rnd_new_webhook_subscription(vec![WebhookEventType::OrderCreated]).await;
+    let subscription_id = *subscription.id();
+    repository.save(subscription).await;
+
+    let mut subscription = repository.get_by_id(&subscription_id).unwrap();
+    subscription.remove();
+    repository.save(subscription).await;
+
+    assert!(repository.get_all().is_empty());
+    assert!(*repository.get_by_id(&subscription_id).unwrap().removed());
+}
+
+#[tokio::test]
+#[should_panic(expected = "is outdated")]
+async fn saving_failed_if_version_outdated() {
+    let db = TestDb::new().await;
+    let mut conn = db.conn();
+
+    conn.run_pending_migrations(MIGRATIONS).unwrap();
+
+    let mut repository = PostgresWebhookSubscriptionRepository::new(
+        conn,
+        AM::new_am(MockWebhookSubscriptionEventPublisher::default()),
+    );
+
+    let subscription = rnd_new_webhook_subscription(vec![WebhookEventType::OrderCreated]).await;
+    let subscription_id = *subscription.id();
+    repository.save(subscription).await;
+
+    let mut first = repository.get_by_id(&subscription_id).unwrap();
+    let mut second = first.clone();
+    first.remove();
+    second.remove();
+
+    repository.save(first).await;
+    repository.save(second).await;
+}
+
+#[tokio::test]
+async fn get_by_id__not_found() {
+    let db = TestDb::new().await;
+    let mut conn = db.conn();
+
+    conn.run_pending_migrations(MIGRATIONS).unwrap();
+
+    let mut repository = PostgresWebhookSubscriptionRepository::new(
+        conn,
+        AM::new_am(MockWebhookSubscriptionEventPublisher::default()),
+    );
+
+    assert!(
+        repository
+            .get_by_id(&rnd_webhook_subscription_id())
+            .is_none()
+    );
+}
+
+#[tokio::test]
+async fn failed_deliveries_newest_first() {
+    let db = TestDb::new().await;
+    let mut conn = db.conn();
+
+    conn.run_pending_migrations(MIGRATIONS).unwrap();
+
+    let mut repository = PostgresFailedWebhookDeliveryRepository::new(conn);
+
+    let failed_at = OffsetDateTime::now_utc().replace_nanosecond(0).unwrap();
+    let delivery = |failed_at| {
+        FailedWebhookDelivery::new(
+            rnd_webhook_subscription_id(),
+            EventId::new(),
+            WebhookEventType::OrderPaid,
+            rnd_webhook_url(),
+            "{}".to_string(),
+            6,
+            "Unexpected response status 500".to_string(),
+            failed_at,
+        )
+    };
+    let older = delivery(failed_at - Duration::minutes(1));
+    let newer = delivery(failed_at);
+    repository.save(older.clone()).await;
+    repository.save(newer.clone()).await;
+
+    assert_eq!(repository.get_all(), vec![newer, older]);
+}
@@ -1,26 +1,43 @@
 #![allow(dead_code)]
-use std::sync::atomic::AtomicU32;
+use std::{collections::HashSet, sync::atomic::AtomicU32};
 
 use async_trait::async_trait;
 use common::{
     events::DomainEventPublisher,
-    types::base::{AM, AMTrait},
+    types::base::{AM, AMTrait, DomainEntity, DomainEntityTrait},
 };
 use derive_new::new;
 use diesel::{Connection, PgConnection, RunQueryDsl, sql_query};
 use domain::{
+    cart::value_objects::customer_id::CustomerId,
+    customer::{customer::Customer, customer_events::CustomerEventEnum},
+    inventory::meal_stock_events::MealStockEventEnum,
     menu::{
         meal::Meal,
         meal_events::MealEventEnum,
         value_objects::{
+            allergens::Allergen,
+            meal_description::MealDescription,
             meal_id::{MealId, MealIdGenerator},
             meal_name::MealName,
+            price::Price,
         },
     },
+    order::{
+        customer_order_events::{ShopOrderCreatedDomainEvent, ShopOrderEventEnum},
+        shop_order::{OrderItem, OrderState, ShopOrder},
+        value_objects::delivery_slot::DeliverySlot,
+    },
     test_fixtures::*,
+    webhook::{
+        value_objects::webhook_event_type::WebhookEventType,
+        webhook_subscription::WebhookSubscription,
+        webhook_subscription_events::WebhookSubscriptionEventEnum,
+    },
 };
 use log::warn;
 use testcontainers::{ContainerAsync, GenericImage, ImageExt, core::WaitFor, runners::AsyncRunner};
+use time::OffsetDateTime;
 use url::Url;
 
 static TEST_DB_COUNTER: AtomicU32 = AtomicU32::new(0);
@@ -162,6 +179,8 @@ pub async fn rnd_new_meal_with_meal_id(meal_id: MealId) -> Meal {
         meal_name,
         meal_description,
         meal_price,
+        rnd_allergens(),
+        rnd_nutrition_facts(),
     )
     .await
     .unwrap()
@@ -180,7 +199,150 @@ pub async fn rnd_new_meal_with_name(meal_name: &MealName) -> Meal {
         meal_name,
         meal_description,
         meal_price,
+        rnd_allergens(),
+        rnd_nutrition_facts(),
+    )
+    .await
+    .unwrap()
+}
+
+pub async fn rnd_new_meal_with_text(meal_name: &str, meal_description: &str) -> Meal {
+    let id_generator = AM::new_am(TestMealIdGenerator::new(rnd_meal_id()));
+
+    Meal::add_meal_to_menu(
+        id_generator,
+        AM::new_am(TestMealAlreadyExists { value: false }),
+        MealName::try_from(meal_name).unwrap(),
+        MealDescription::try_from(meal_description).unwrap(),
+        rnd_price(),
+        rnd_allergens(),
+        rnd_nutrition_facts(),
+    )
+    .await
+    .unwrap()
+}
+
+pub async fn rnd_new_meal_with_allergens(meal_id: MealId, allergens: &[Allergen]) -> Meal {
+    let id_generator = AM::new_am(TestMealIdGenerator::new(meal_id));
+
+    Meal::add_meal_to_menu(
+        id_generator,
+        AM::new_am(TestMealAlreadyExists { value: false }),
+        rnd_meal_name(),
+        rnd_meal_description(),
+        rnd_price(),
+        allergens.iter().copied().collect(),
+        rnd_nutrition_facts(),
+    )
+    .await
+    .unwrap()
+}
+
+#[derive(new, Debug, Default)]
+pub struct MockCustomerEventPublisher {
+    pub events: Vec<CustomerEventEnum>,
+}
+
+#[async_trait]
+impl DomainEventPublisher<CustomerEventEnum> for MockCustomerEventPublisher {
+    async fn publish(&mut self, events: &[CustomerEventEnum]) {
+        self.events.extend_from_slice(events)
+    }
+}
+
+#[derive(new, Debug, Default)]
+pub struct MockMealStockEventPublisher {
+    pub events: Vec<MealStockEventEnum>,
+}
+
+#[async_trait]
+impl DomainEventPublisher<MealStockEventEnum> for MockMealStockEventPublisher {
+    async fn publish(&mut self, events: &[MealStockEventEnum]) {
+        self.events.extend_from_slice(events)
+    }
+}
+
+pub async fn rnd_new_customer() -> Customer {
+    Customer::register(
+        AM::new_am(TestCustomerIdGenerator::new()),
+        AM::new_am(TestCustomerAlreadyExists::new()),
+        rnd_customer_name(),
+        rnd_phone_number(),
+    )
+    .await
+    .unwrap()
+}
+
+#[derive(new, Debug, Default)]
+pub struct MockShopOrderEventPublisher {
+    pub events: Vec<ShopOrderEventEnum>,
+}
+
+#[async_trait]
+impl DomainEventPublisher<ShopOrderEventEnum> for MockShopOrderEventPublisher {
+    async fn publish(&mut self, events: &[ShopOrderEventEnum]) {
+        self.events.extend_from_slice(events)
+    }
+}
+
+#[derive(new, Debug, Default)]
+pub struct MockWebhookSubscriptionEventPublisher {
+    pub events: Vec<WebhookSubscriptionEventEnum>,
+}
+
+#[async_trait]
+impl DomainEventPublisher<WebhookSubscriptionEventEnum> for MockWebhookSubscriptionEventPublisher {
+    async fn publish(&mut self, events: &[WebhookSubscriptionEventEnum]) {
+        self.events.extend_from_slice(events)
+    }
+}
+
+pub async fn rnd_new_webhook_subscription(
+    event_types: Vec<WebhookEventType>,
+) -> WebhookSubscription {
+    WebhookSubscription::subscribe(
+        AM::new_am(TestWebhookSubscriptionIdGenerator::new()),
+        rnd_webhook_url(),
+        rnd_webhook_secret(),
+        event_types,
     )
     .await
     .unwrap()
 }
+
+/// Order as it comes out of checkout, carrying the creation event
+pub fn rnd_new_order(
+    customer_id: CustomerId,
+    created: OffsetDateTime,
+    order_items: HashSet<OrderItem>,
+    delivery_slot: Option<DeliverySlot>,
+) -> ShopOrder {
+    let order_id = rnd_order_id();
+    let fulfilment = rnd_fulfilment();
+    let new_order = |entity_params| {
+        ShopOrder::new(
+            entity_params,
+            created,
+            customer_id,
+            fulfilment.clone(),
+            Price::zero(),
+            delivery_slot,
+            order_items.clone(),
+            OrderState::new_waiting_for_payment(),
+        )
+    };
+    let total_price = new_order(DomainEntity::new(order_id, Default::default())).total_price();
+
+    let mut entity_params = DomainEntity::new(order_id, Default::default());
+    entity_params.add_event(
+        ShopOrderCreatedDomainEvent::new(
+            order_id,
+            customer_id,
+            total_price,
+            fulfilment.clone(),
+            delivery_slot,
+        )
+        .into(),
+    );
+    new_order(entity_params)
+}
@@ -0,0 +1,64 @@
+use std::future::{Ready, ready};
+
+use actix_web::{FromRequest, HttpMessage, HttpRequest, dev::Payload};
+use derive_new::new;
+use domain::cart::value_objects::customer_id::CustomerId;
+use usecase::auth::role::Role;
+
+use crate::auth::jwt_authenticator::AuthError;
+
+/// Caller identified by the bearer token of the request
+#[derive(new, Debug, Clone, Copy, PartialEq)]
+pub struct AuthenticatedUser {
+    pub customer_id: CustomerId,
+    pub role: Role,
+}
+
+impl AuthenticatedUser {
+    pub fn has_any_role(&self, roles: &[Role]) -> bool {
+        roles.contains(&self.role)
+    }
+
+    /// Customers may only access their own resources, staff may access everything
+    pub fn can_access(&self, owner: &CustomerId) -> bool {
+        self.role.is_staff() || &self.customer_id == owner
+    }
+}
+
+impl FromRequest for AuthenticatedUser {
+    type Error = AuthError;
+    type Future = Ready<Result<Self, Self::Error>>;
+
+    fn from_request(req: &HttpRequest, _: &mut Payload) -> Self::Future {
+        ready(
+            req.extensions()
+                .get::<AuthenticatedUser>()
+                .copied()
+                .ok_or(AuthError::MissingToken),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::test_fixtures::rnd_customer_id;
+
+    use super::*;
+
+    #[test]
+    fn customer_can_access_own_resources_only() {
+        let user = AuthenticatedUser::new(rnd_customer_id(), Role::Customer);
+
+        assert!(user.can_access(&user.customer_id));
+        assert!(!user.can_access(&rnd_customer_id()));
+    }
+
+    #[test]
+    fn staff_can_access_any_resource() {
+        for role in [Role::KitchenStaff, Role::Admin] {
+            let user = AuthenticatedUser::new(rnd_customer_id(), role);
+
+            assert!(user.can_access(&rnd_customer_id()));
+        }
+    }
+}
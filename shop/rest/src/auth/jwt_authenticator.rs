@@ -0,0 +1,199 @@
+use std::fmt::{Debug, Formatter};
+
+use actix_web::{HttpResponse, ResponseError, http::StatusCode};
+use common::common_rest::{forbidden, unauthorized};
+use domain::cart::value_objects::customer_id::CustomerId;
+use jsonwebtoken::{Algorithm, DecodingKey, EncodingKey, Header, Validation, decode, encode};
+use thiserror::Error;
+
+use crate::auth::{authenticated_user::AuthenticatedUser, token_claims::TokenClaims};
+
+/// Validates HS256 signed bearer tokens with the configured key
+#[derive(Clone)]
+pub struct JwtAuthenticator {
+    encoding_key: EncodingKey,
+    decoding_key: DecodingKey,
+    validation: Validation,
+}
+
+impl JwtAuthenticator {
+    /// Shorter HS256 keys can be brute forced
+    pub const MIN_SECRET_LENGTH: usize = 32;
+
+    pub fn new(secret: &[u8]) -> Result<Self, JwtSecretError> {
+        if secret.len() < Self::MIN_SECRET_LENGTH {
+            return Err(JwtSecretError::TooShort);
+        }
+        Ok(Self {
+            encoding_key: EncodingKey::from_secret(secret),
+            decoding_key: DecodingKey::from_secret(secret),
+            validation: Validation::new(Algorithm::HS256),
+        })
+    }
+
+    pub fn authenticate(&self, token: &str) -> Result<AuthenticatedUser, AuthError> {
+        let claims = decode::<TokenClaims>(token, &self.decoding_key, &self.validation)
+            .map_err(|_| AuthError::InvalidToken)?
+            .claims;
+        CustomerId::try_from(claims.sub.as_str())
+            .map(|customer_id| AuthenticatedUser::new(customer_id, claims.role))
+            .map_err(|_| AuthError::InvalidSubject)
+    }
+
+    /// Sign the claims with the configured key
+    pub fn issue(&self, claims: &TokenClaims) -> String {
+        encode(&Header::new(Algorithm::HS256), claims, &self.encoding_key).unwrap()
+    }
+}
+
+impl Debug for JwtAuthenticator {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("JwtAuthenticator")
+            .field("validation", &self.validation)
+            .finish()
+    }
+}
+
+#[derive(Error, Debug, Clone, Copy, PartialEq)]
+pub enum JwtSecretError {
+    #[error(
+        "JWT secret must be at least {} bytes long",
+        JwtAuthenticator::MIN_SECRET_LENGTH
+    )]
+    TooShort,
+}
+
+#[derive(Error, Debug, Clone, Copy, PartialEq)]
+pub enum AuthError {
+    #[error("Bearer token is missing")]
+    MissingToken,
+    #[error("Bearer token is invalid or expired")]
+    InvalidToken,
+    #[error("Token subject is not a customer id")]
+    InvalidSubject,
+    #[error("Role of the caller is not allowed")]
+    Forbidden,
+}
+
+impl ResponseError for AuthError {
+    fn status_code(&self) -> StatusCode {
+        match self {
+            AuthError::Forbidden => StatusCode::FORBIDDEN,
+            _ => StatusCode::UNAUTHORIZED,
+        }
+    }
+
+    fn error_response(&self) -> HttpResponse {
+        match self {
+            AuthError::Forbidden => forbidden(),
+            _ => unauthorized(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::{SystemTime, UNIX_EPOCH};
+
+    use domain::test_fixtures::rnd_customer_id;
+    use usecase::auth::role::Role;
+
+    use super::*;
+
+    const SECRET: &[u8] = b"0123456789abcdef0123456789abcdef";
+
+    #[test]
+    fn valid_token() {
+        let authenticator = JwtAuthenticator::new(SECRET).unwrap();
+        let customer_id = rnd_customer_id();
+        let token = authenticator.issue(&TokenClaims::new(
+            customer_id.to_string(),
+            Role::KitchenStaff,
+            in_an_hour(),
+        ));
+
+        let result = authenticator.authenticate(&token);
+
+        assert_eq!(
+            result,
+            Ok(AuthenticatedUser::new(customer_id, Role::KitchenStaff))
+        );
+    }
+
+    #[test]
+    fn token_without_role_belongs_to_customer() {
+        let authenticator = JwtAuthenticator::new(SECRET).unwrap();
+        let customer_id = rnd_customer_id();
+        let token = encode(
+            &Header::new(Algorithm::HS256),
+            &serde_json::json!({"sub": customer_id.to_string(), "exp": in_an_hour()}),
+            &EncodingKey::from_secret(SECRET),
+        )
+        .unwrap();
+
+        let result = authenticator.authenticate(&token);
+
+        assert_eq!(
+            result,
+            Ok(AuthenticatedUser::new(customer_id, Role::Customer))
+        );
+    }
+
+    #[test]
+    fn signed_with_another_key() {
+        let token = JwtAuthenticator::new(b"fedcba9876543210fedcba9876543210")
+            .unwrap()
+            .issue(&TokenClaims::new(
+                rnd_customer_id().to_string(),
+                Role::Customer,
+                in_an_hour(),
+            ));
+
+        let result = JwtAuthenticator::new(SECRET).unwrap().authenticate(&token);
+
+        assert_eq!(result, Err(AuthError::InvalidToken));
+    }
+
+    #[test]
+    fn expired_token() {
+        let authenticator = JwtAuthenticator::new(SECRET).unwrap();
+        let token = authenticator.issue(&TokenClaims::new(
+            rnd_customer_id().to_string(),
+            Role::Customer,
+            1,
+        ));
+
+        let result = authenticator.authenticate(&token);
+
+        assert_eq!(result, Err(AuthError::InvalidToken));
+    }
+
+    #[test]
+    fn subject_is_not_customer_id() {
+        let authenticator = JwtAuthenticator::new(SECRET).unwrap();
+        let token = authenticator.issue(&TokenClaims::new(
+            "admin".to_string(),
+            Role::Customer,
+            in_an_hour(),
+        ));
+
+        let result = authenticator.authenticate(&token);
+
+        assert_eq!(result, Err(AuthError::InvalidSubject));
+    }
+
+    #[test]
+    fn short_secret() {
+        let result = JwtAuthenticator::new(b"change-me-in-production");
+
+        assert!(matches!(result, Err(JwtSecretError::TooShort)));
+    }
+
+    fn in_an_hour() -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .unwrap()
+            .as_secs()
+            + 3600
+    }
+}
@@ -0,0 +1,318 @@
+use actix_web::{
+    Error, HttpMessage,
+    body::{BoxBody, MessageBody},
+    dev::{ServiceRequest, ServiceResponse},
+    http::header,
+    middleware::Next,
+    web::Data,
+};
+use domain::cart::value_objects::customer_id::CustomerId;
+use usecase::auth::role::Role;
+
+use crate::auth::{
+    authenticated_user::AuthenticatedUser,
+    jwt_authenticator::{AuthError, JwtAuthenticator},
+};
+
+/// Validate the bearer token if the request carries one and remember the authenticated user.
+/// Requests without a token pass through, routes opt in with [require_authentication].
+pub async fn jwt_authentication(
+    req: ServiceRequest,
+    next: Next<impl MessageBody + 'static>,
+) -> Result<ServiceResponse<BoxBody>, Error> {
+    if let Some(value) = req.headers().get(header::AUTHORIZATION) {
+        let authenticator = req
+            .app_data::<Data<JwtAuthenticator>>()
+            .expect("JwtAuthenticator is not configured");
+        let result = value
+            .to_str()
+            .ok()
+            .and_then(|it| it.strip_prefix("Bearer "))
+            .ok_or(AuthError::InvalidToken)
+            .and_then(|token| authenticator.authenticate(token.trim()));
+        match result {
+            Ok(user) => {
+                req.extensions_mut().insert(user);
+            }
+            Err(e) => return Ok(req.error_response(e)),
+        }
+    }
+    next.call(req)
+        .await
+        .map(ServiceResponse::map_into_boxed_body)
+}
+
+/// Reject the request unless [jwt_authentication] identified the caller
+pub async fn require_authentication(
+    req: ServiceRequest,
+    next: Next<impl MessageBody + 'static>,
+) -> Result<ServiceResponse<BoxBody>, Error> {
+    require_any_role(
+        &[Role::Customer, Role::KitchenStaff, Role::Admin],
+        req,
+        next,
+    )
+    .await
+}
+
+/// Allow only kitchen staff and admins
+pub async fn require_kitchen_staff(
+    req: ServiceRequest,
+    next: Next<impl MessageBody + 'static>,
+) -> Result<ServiceResponse<BoxBody>, Error> {
+    require_any_role(&[Role::KitchenStaff, Role::Admin], req, next).await
+}
+
+/// Allow only admins
+pub async fn require_admin(
+    req: ServiceRequest,
+    next: Next<impl MessageBody + 'static>,
+) -> Result<ServiceResponse<BoxBody>, Error> {
+    require_any_role(&[Role::Admin], req, next).await
+}
+
+/// Let customers through only for their own `{id}` path, staff may access any customer
+pub async fn require_owner_or_staff(
+    req: ServiceRequest,
+    next: Next<impl MessageBody + 'static>,
+) -> Result<ServiceResponse<BoxBody>, Error> {
+    let user = req.extensions().get::<AuthenticatedUser>().copied();
+    let owner = req
+        .match_info()
+        .get("id")
+        .and_then(|id| CustomerId::try_from(id).ok());
+    match (user, owner) {
+        (None, _) => Ok(req.error_response(AuthError::MissingToken)),
+        (Some(user), Some(owner)) if !user.can_access(&owner) => {
+            Ok(req.error_response(AuthError::Forbidden))
+        }
+        _ => next
+            .call(req)
+            .await
+            .map(ServiceResponse::map_into_boxed_body),
+    }
+}
+
+async fn require_any_role(
+    roles: &[Role],
+    req: ServiceRequest,
+    next: Next<impl MessageBody + 'static>,
+) -> Result<ServiceResponse<BoxBody>, Error> {
+    let user = req.extensions().get::<AuthenticatedUser>().copied();
+    match user {
+        None => Ok(req.error_response(AuthError::MissingToken)),
+        Some(user) if !user.has_any_role(roles) => Ok(req.error_response(AuthError::Forbidden)),
+        Some(_) => next
+            .call(req)
+            .await
+            .map(ServiceResponse::map_into_boxed_body),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{
+        App, HttpResponse,
+        http::StatusCode,
+        middleware::from_fn,
+        test::{TestRequest, call_service, init_service, read_body_json},
+        web,
+    };
+    use common::common_rest::{GenericErrorResponse, forbidden_type_url, unauthorized_type_url};
+    use domain::test_fixtures::rnd_customer_id;
+    use dotenvy::dotenv;
+
+    use super::*;
+    use crate::auth::token_claims::TokenClaims;
+
+    const SECRET: &[u8] = b"0123456789abcdef0123456789abcdef";
+
+    async fn whoami(user: AuthenticatedUser) -> HttpResponse {
+        HttpResponse::Ok().body(user.customer_id.to_string())
+    }
+
+    macro_rules! test_app {
+        () => {
+            init_service(
+                App::new()
+                    .app_data(Data::new(JwtAuthenticator::new(SECRET).unwrap()))
+                    .route("/public", web::get().to(HttpResponse::Ok))
+                    .route(
+                        "/private",
+                        web::get().to(whoami).wrap(from_fn(require_authentication)),
+                    )
+                    .route(
+                        "/customers/{id}",
+                        web::get()
+                            .to(HttpResponse::Ok)
+                            .wrap(from_fn(require_owner_or_staff)),
+                    )
+                    .route(
+                        "/admin",
+                        web::get().to(HttpResponse::Ok).wrap(from_fn(require_admin)),
+                    )
+                    .wrap(from_fn(jwt_authentication)),
+            )
+            .await
+        };
+    }
+
+    fn token(role: Role) -> (String, String) {
+        let customer_id = rnd_customer_id();
+        let token = JwtAuthenticator::new(SECRET)
+            .unwrap()
+            .issue(&TokenClaims::new(
+                customer_id.to_string(),
+                role,
+                u64::MAX / 2,
+            ));
+        (customer_id.to_string(), format!("Bearer {token}"))
+    }
+
+    #[actix_web::test]
+    async fn public_route_without_token() {
+        let app = test_app!();
+
+        let resp = call_service(&app, TestRequest::get().uri("/public").to_request()).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn private_route_without_token() {
+        dotenv().ok();
+        let app = test_app!();
+
+        let resp = call_service(&app, TestRequest::get().uri("/private").to_request()).await;
+
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+        let body: GenericErrorResponse = read_body_json(resp).await;
+        assert_eq!(body.response_type, unauthorized_type_url());
+        assert_eq!(body.response_status, StatusCode::UNAUTHORIZED.as_u16());
+    }
+
+    #[actix_web::test]
+    async fn private_route_with_token() {
+        let app = test_app!();
+        let (customer_id, token) = token(Role::Customer);
+
+        let resp = call_service(
+            &app,
+            TestRequest::get()
+                .uri("/private")
+                .insert_header((header::AUTHORIZATION, token))
+                .to_request(),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body = actix_web::test::read_body(resp).await;
+        assert_eq!(std::str::from_utf8(&body).unwrap(), customer_id);
+    }
+
+    #[actix_web::test]
+    async fn invalid_token_is_rejected_on_public_route() {
+        dotenv().ok();
+        let app = test_app!();
+
+        let resp = call_service(
+            &app,
+            TestRequest::get()
+                .uri("/public")
+                .insert_header((header::AUTHORIZATION, "Bearer not.a.token"))
+                .to_request(),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::UNAUTHORIZED);
+    }
+
+    #[actix_web::test]
+    async fn admin_route_with_customer_token() {
+        dotenv().ok();
+        let app = test_app!();
+        let (_, token) = token(Role::Customer);
+
+        let resp = call_service(
+            &app,
+            TestRequest::get()
+                .uri("/admin")
+                .insert_header((header::AUTHORIZATION, token))
+                .to_request(),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+        let body: GenericErrorResponse = read_body_json(resp).await;
+        assert_eq!(body.response_type, forbidden_type_url());
+    }
+
+    #[actix_web::test]
+    async fn admin_route_with_admin_token() {
+        let app = test_app!();
+        let (_, token) = token(Role::Admin);
+
+        let resp = call_service(
+            &app,
+            TestRequest::get()
+                .uri("/admin")
+                .insert_header((header::AUTHORIZATION, token))
+                .to_request(),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn customer_route_of_another_customer() {
+        dotenv().ok();
+        let app = test_app!();
+        let (_, token) = token(Role::Customer);
+
+        let resp = call_service(
+            &app,
+            TestRequest::get()
+                .uri(&format!("/customers/{}", rnd_customer_id()))
+                .insert_header((header::AUTHORIZATION, token))
+                .to_request(),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+    }
+
+    #[actix_web::test]
+    async fn customer_route_of_own_customer() {
+        let app = test_app!();
+        let (customer_id, token) = token(Role::Customer);
+
+        let resp = call_service(
+            &app,
+            TestRequest::get()
+                .uri(&format!("/customers/{customer_id}"))
+                .insert_header((header::AUTHORIZATION, token))
+                .to_request(),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn customer_route_for_kitchen_staff() {
+        let app = test_app!();
+        let (_, token) = token(Role::KitchenStaff);
+
+        let resp = call_service(
+            &app,
+            TestRequest::get()
+                .uri(&format!("/customers/{}", rnd_customer_id()))
+                .insert_header((header::AUTHORIZATION, token))
+                .to_request(),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+}
@@ -0,0 +1,5 @@
+pub mod authenticated_user;
+pub mod jwt_authenticator;
+pub mod jwt_middleware;
+pub mod role_model;
+pub mod token_claims;
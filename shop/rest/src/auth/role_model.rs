@@ -0,0 +1,12 @@
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+/// Role of the caller, carried in the `role` claim of the bearer token
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+#[schema(as = Role)]
+pub enum RoleModel {
+    Customer,
+    KitchenStaff,
+    Admin,
+}
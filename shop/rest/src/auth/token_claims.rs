@@ -0,0 +1,22 @@
+use derive_new::new;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+use usecase::auth::role::Role;
+
+use crate::auth::role_model::RoleModel;
+
+/// Payload of a bearer token, the subject is the id of the customer or staff member
+#[derive(new, Serialize, Deserialize, Debug, Clone, PartialEq, ToSchema)]
+pub struct TokenClaims {
+    /// Customer id
+    #[schema(example = "0e3d5e0f-2f4c-4a43-9d7f-6f1d2c3b4a59")]
+    pub sub: String,
+    /// Role of the subject, tokens without it belong to customers
+    #[serde(default)]
+    #[schema(value_type = RoleModel)]
+    pub role: Role,
+    /// Expiration time as a unix timestamp
+    #[schema(example = 1893456000)]
+    pub exp: u64,
+}
@@ -0,0 +1,249 @@
+use std::fmt::Debug;
+
+use actix_web::{HttpRequest, HttpResponse, http::Uri, middleware::from_fn, web};
+use common::{
+    common_rest::{
+        GenericErrorResponse, created, get_json_from_http_response, resource_not_found,
+        to_invalid_param_bad_request,
+    },
+    types::{
+        base::{AM, RCell, RcRefCellTrait},
+        common::Address,
+    },
+};
+use domain::cart::value_objects::customer_id::CustomerId;
+use usecase::customer::{AddCustomerAddress, AddCustomerAddressUseCaseError};
+
+use crate::{
+    auth::jwt_middleware::require_owner_or_staff,
+    endpoint_url::{API_V1_CUSTOMER_ADD_ADDRESS, API_V1_CUSTOMER_DELETE_ADDRESS},
+    order::order_model::AddressModel,
+    to_error::ToRestError,
+    validated::Validated,
+};
+
+/// Save an address for the customer
+///
+/// Customers may only access their own profile, staff may access any
+#[utoipa::path(
+    post,
+    path = API_V1_CUSTOMER_ADD_ADDRESS,
+    tag = "Customer",
+    security(("bearerAuth" = [])),
+    params(
+        ("id" = String, Path, description = "Customer id")
+    ),
+    request_body(
+        content = AddressModel,
+    ),
+    responses(
+        (
+            status = UNAUTHORIZED,
+            description = "Missing or invalid bearer token",
+            body = GenericErrorResponse,
+            example = json!({"type":"http://0.0.0.0:8080/unauthorized","title":"Unauthorized","status":401})
+        ),
+        (
+            status = FORBIDDEN,
+            description = "Caller is not allowed to perform the operation",
+            body = GenericErrorResponse,
+            example = json!({"type":"http://0.0.0.0:8080/forbidden","title":"Forbidden","status":403})
+        ),
+        (
+            status = CREATED,
+            description = "Created",
+            headers(
+                ("location" = String, description = "Location of the saved address")
+            )
+        ),
+        (
+            status = BAD_REQUEST,
+            description = "Bad request",
+            body = GenericErrorResponse,
+            example = json!(
+                {
+                    "type":"http://0.0.0.0:8080/bad_request",
+                    "title":"Bad request",
+                    "status":400,
+                    "invalid_params":
+                    [
+                        {"message":"Street is empty"}
+                    ]
+                }
+            )
+        ),
+        (
+            status = NOT_FOUND,
+            description = "Customer not found",
+            body = GenericErrorResponse,
+            example = json!(&(get_json_from_http_response(resource_not_found())))
+        ),
+    ))]
+pub async fn add_customer_address_endpoint<T>(
+    shared_state: web::Data<AM<T>>,
+    req: HttpRequest,
+    request: web::Json<AddressModel>,
+) -> HttpResponse
+where
+    T: AddCustomerAddress + Send + Debug,
+{
+    let error_list = RCell::new_rc(vec![]);
+
+    let customer_id = req
+        .match_info()
+        .get("id")
+        .and_then(|id| CustomerId::validated(id, error_list.clone()));
+    let address = Address::validated(&request, error_list.clone());
+
+    match (customer_id, address) {
+        (Some(customer_id), Some(address)) => {
+            match shared_state
+                .lock()
+                .await
+                .execute(&customer_id, &address)
+                .await
+            {
+                Ok(address_id) => created(
+                    API_V1_CUSTOMER_DELETE_ADDRESS
+                        .replace("{id}", &customer_id.to_string())
+                        .replace("{address_id}", &address_id.to_string())
+                        .parse::<Uri>()
+                        .unwrap(),
+                ),
+                Err(e) => e.to_rest_error(),
+            }
+        }
+        _ => to_invalid_param_bad_request(error_list),
+    }
+}
+
+impl ToRestError for AddCustomerAddressUseCaseError {
+    fn to_rest_error(self) -> HttpResponse {
+        resource_not_found()
+    }
+}
+
+pub fn add_customer_address_endpoint_config<T>(cfg: &mut web::ServiceConfig)
+where
+    T: AddCustomerAddress + Send + Debug + 'static,
+{
+    cfg.route(
+        API_V1_CUSTOMER_ADD_ADDRESS,
+        web::post()
+            .to(add_customer_address_endpoint::<T>)
+            .wrap(from_fn(require_owner_or_staff)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{
+        body::MessageBody,
+        http::{StatusCode, header},
+        test::TestRequest,
+        web::{Data, Json},
+    };
+    use common::{
+        common_rest::{GenericErrorResponse, bad_request_type_url, not_found_type_url},
+        types::base::AMTrait,
+    };
+    use domain::{customer::value_objects::saved_address::SavedAddressId, test_fixtures::*};
+    use dotenvy::dotenv;
+
+    use super::*;
+    use crate::{order::order_model::ToModel, test_fixtures::MockAddCustomerAddress};
+
+    #[actix_web::test]
+    async fn created_successfully() {
+        dotenv().ok();
+        let customer_id = rnd_customer_id();
+        let address = rnd_address();
+        let address_id = SavedAddressId::new();
+
+        let mock_add_customer_address = AM::new_am(MockAddCustomerAddress::default());
+        mock_add_customer_address.lock().await.response = Ok(address_id);
+        let mock_shared_state = Data::new(mock_add_customer_address.clone());
+
+        let req = TestRequest::default()
+            .param("id", customer_id.to_string())
+            .to_http_request();
+
+        let resp =
+            add_customer_address_endpoint(mock_shared_state, req, Json(address.clone().to_model()))
+                .await;
+
+        mock_add_customer_address
+            .lock()
+            .await
+            .verify_invoked(&customer_id, &address);
+
+        let header = resp
+            .headers()
+            .get(header::LOCATION)
+            .unwrap()
+            .to_str()
+            .unwrap();
+
+        assert_eq!(&resp.status(), &StatusCode::CREATED);
+        assert_eq!(
+            header,
+            API_V1_CUSTOMER_DELETE_ADDRESS
+                .replace("{id}", &customer_id.to_string())
+                .replace("{address_id}", &address_id.to_string())
+        );
+    }
+
+    #[actix_web::test]
+    async fn validation_error() {
+        dotenv().ok();
+        let mock_add_customer_address = AM::new_am(MockAddCustomerAddress::default());
+        let mock_shared_state = Data::new(mock_add_customer_address.clone());
+
+        let mut address = rnd_address().to_model();
+        address.street = " ".to_string();
+        address.latitude = None;
+        address.longitude = Some(13.4);
+
+        let req = TestRequest::default()
+            .param("id", rnd_customer_id().to_string())
+            .to_http_request();
+
+        let resp = add_customer_address_endpoint(mock_shared_state, req, Json(address)).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+        let body = resp.into_body().try_into_bytes().unwrap();
+        let body_text = std::str::from_utf8(&body).unwrap();
+
+        let response_dto: GenericErrorResponse = serde_json::from_str(body_text).unwrap();
+
+        assert_eq!(&response_dto.response_type, &bad_request_type_url());
+        assert_eq!(response_dto.invalid_params.len(), 1);
+    }
+
+    #[actix_web::test]
+    async fn customer_not_found() {
+        dotenv().ok();
+        let mock_add_customer_address = AM::new_am(MockAddCustomerAddress::default());
+        mock_add_customer_address.lock().await.response =
+            Err(AddCustomerAddressUseCaseError::CustomerNotFound);
+        let mock_shared_state = Data::new(mock_add_customer_address.clone());
+
+        let req = TestRequest::default()
+            .param("id", rnd_customer_id().to_string())
+            .to_http_request();
+
+        let resp =
+            add_customer_address_endpoint(mock_shared_state, req, Json(rnd_address().to_model()))
+                .await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+        let body = resp.into_body().try_into_bytes().unwrap();
+        let body_text = std::str::from_utf8(&body).unwrap();
+
+        let response_dto: GenericErrorResponse = serde_json::from_str(body_text).unwrap();
+
+        assert_eq!(&response_dto.response_type, &not_found_type_url());
+    }
+}
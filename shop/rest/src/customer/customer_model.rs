@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+use usecase::customer::dto::customer_info::CustomerInfo;
+use utoipa::{ToResponse, ToSchema};
+
+use crate::order::order_model::{AddressModel, ToModel};
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema, ToResponse)]
+pub struct CustomerModel {
+    /// ID of the customer
+    #[schema(example = "0e3d5e0f-2f4c-4a43-9d7f-6f1d2c3b4a59")]
+    pub id: String,
+    /// Name of the customer
+    #[schema(example = "Ivan Petrov")]
+    pub name: String,
+    /// Phone number of the customer in international format
+    #[schema(example = "+79161234567")]
+    pub phone: String,
+    /// Addresses saved by the customer
+    pub addresses: Vec<SavedAddressModel>,
+    /// Customer preferences
+    pub preferences: CustomerPreferencesModel,
+    /// Version of the customer
+    #[schema(example = 2)]
+    pub version: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct SavedAddressModel {
+    /// ID of the saved address
+    #[schema(example = "5b0a4d1c-7c1e-4a36-8f1a-9c8d7e6f5a4b")]
+    pub id: String,
+    /// The address itself
+    pub address: AddressModel,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct CustomerPreferencesModel {
+    /// Saved address used by default for delivery
+    #[schema(example = "5b0a4d1c-7c1e-4a36-8f1a-9c8d7e6f5a4b")]
+    pub default_address_id: Option<String>,
+    /// Whether the customer agreed to receive promotions
+    #[schema(example = true)]
+    pub receive_promotions: bool,
+}
+
+impl CustomerModel {
+    pub fn from(customer_info: CustomerInfo) -> Self {
+        Self {
+            id: customer_info.id.to_string(),
+            name: customer_info.name.to_string(),
+            phone: customer_info.phone.to_string(),
+            addresses: customer_info
+                .addresses
+                .into_iter()
+                .map(|it| SavedAddressModel {
+                    id: it.id.to_string(),
+                    address: it.address.to_model(),
+                })
+                .collect(),
+            preferences: CustomerPreferencesModel {
+                default_address_id: customer_info
+                    .preferences
+                    .default_address_id
+                    .map(|it| it.to_string()),
+                receive_promotions: customer_info.preferences.receive_promotions,
+            },
+            version: customer_info.version.to_i64(),
+        }
+    }
+}
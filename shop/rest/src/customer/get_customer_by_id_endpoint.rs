@@ -0,0 +1,207 @@
+use std::fmt::Debug;
+
+use actix_web::{HttpRequest, HttpResponse, http::header::ContentType, middleware::from_fn, web};
+use common::{
+    common_rest::{
+        GenericErrorResponse, get_json_from_http_response, resource_not_found,
+        to_invalid_param_bad_request,
+    },
+    types::base::{AM, RCell, RcRefCellTrait},
+};
+use domain::cart::value_objects::customer_id::CustomerId;
+use usecase::customer::{GetCustomerById, GetCustomerByIdUseCaseError};
+
+use crate::{
+    auth::jwt_middleware::require_owner_or_staff, customer::customer_model::CustomerModel,
+    endpoint_url::API_V1_CUSTOMER_GET_BY_ID, to_error::ToRestError, validated::Validated,
+};
+
+/// Get a customer by id
+///
+/// Customers may only access their own profile, staff may access any
+#[utoipa::path(
+    get,
+    path = API_V1_CUSTOMER_GET_BY_ID,
+    tag = "Customer",
+    security(("bearerAuth" = [])),
+    params(
+        (
+            "id" = String,
+            Path,
+            description = "Customer id"
+        )
+    ),
+    responses(
+        (
+            status = UNAUTHORIZED,
+            description = "Missing or invalid bearer token",
+            body = GenericErrorResponse,
+            example = json!({"type":"http://0.0.0.0:8080/unauthorized","title":"Unauthorized","status":401})
+        ),
+        (
+            status = FORBIDDEN,
+            description = "Caller is not allowed to perform the operation",
+            body = GenericErrorResponse,
+            example = json!({"type":"http://0.0.0.0:8080/forbidden","title":"Forbidden","status":403})
+        ),
+        (
+            status = OK,
+            description = "Customer found",
+            body = CustomerModel
+        ),
+        (
+            status = NOT_FOUND,
+            description = "Customer not found",
+            body = GenericErrorResponse,
+            example = json!(&(get_json_from_http_response(resource_not_found())))
+        ),
+        (
+            status = BAD_REQUEST,
+            description = "Invalid id",
+            body = GenericErrorResponse,
+            example = json!(
+                {
+                    "type": "http://0.0.0.0:8080/bad_request",
+                    "title": "Bad request",
+                    "status": 400,
+                    "invalid_params":
+                    [
+                        {"message": "Wrong Customer Id"}
+                    ]
+                }
+            )
+        ),
+    )
+)]
+pub async fn get_customer_by_id_endpoint<T>(
+    shared_state: web::Data<AM<T>>,
+    req: HttpRequest,
+) -> HttpResponse
+where
+    T: GetCustomerById + Send + Debug,
+{
+    let error_list = RCell::new_rc(vec![]);
+
+    let maybe_customer_id = req
+        .match_info()
+        .get("id")
+        .and_then(|id| CustomerId::validated(id, error_list.clone()));
+
+    if let Some(customer_id) = maybe_customer_id {
+        match shared_state.lock().await.execute(&customer_id).await {
+            Ok(customer_info) => HttpResponse::Ok()
+                .content_type(ContentType::json())
+                .body(serde_json::to_string(&CustomerModel::from(customer_info)).unwrap()),
+            Err(e) => e.to_rest_error(),
+        }
+    } else {
+        to_invalid_param_bad_request(error_list)
+    }
+}
+
+impl ToRestError for GetCustomerByIdUseCaseError {
+    fn to_rest_error(self) -> HttpResponse {
+        resource_not_found()
+    }
+}
+
+pub fn get_customer_by_id_endpoint_config<T>(cfg: &mut web::ServiceConfig)
+where
+    T: GetCustomerById + Send + Debug + 'static,
+{
+    cfg.route(
+        API_V1_CUSTOMER_GET_BY_ID,
+        web::get()
+            .to(get_customer_by_id_endpoint::<T>)
+            .wrap(from_fn(require_owner_or_staff)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{body::MessageBody, http::StatusCode, test::TestRequest, web::Data};
+    use common::{
+        common_rest::{GenericErrorResponse, bad_request_type_url, not_found_type_url},
+        types::base::AMTrait,
+    };
+    use domain::test_fixtures::*;
+    use dotenvy::dotenv;
+
+    use super::*;
+    use crate::test_fixtures::{MockGetCustomerById, rnd_customer_info};
+
+    #[actix_web::test]
+    async fn returned_successfully() {
+        let customer_info = rnd_customer_info();
+
+        let mock_get_customer_by_id = AM::new_am(MockGetCustomerById::default());
+        mock_get_customer_by_id.lock().await.response = Ok(customer_info.clone());
+        let mock_shared_state = Data::new(mock_get_customer_by_id.clone());
+
+        let req = TestRequest::default()
+            .param("id", customer_info.id.to_string())
+            .to_http_request();
+
+        let resp = get_customer_by_id_endpoint(mock_shared_state, req).await;
+
+        let body = resp.into_body().try_into_bytes().unwrap();
+        let body_json = std::str::from_utf8(&body).unwrap();
+
+        let customer_model_json =
+            serde_json::to_string(&CustomerModel::from(customer_info.clone())).unwrap();
+        assert_eq!(body_json, &customer_model_json);
+
+        mock_get_customer_by_id
+            .lock()
+            .await
+            .verify_invoked(&customer_info.id);
+    }
+
+    #[actix_web::test]
+    async fn customer_not_found() {
+        dotenv().ok();
+        let mock_get_customer_by_id = AM::new_am(MockGetCustomerById::default());
+        mock_get_customer_by_id.lock().await.response =
+            Err(GetCustomerByIdUseCaseError::CustomerNotFound);
+        let mock_shared_state = Data::new(mock_get_customer_by_id.clone());
+
+        let req = TestRequest::default()
+            .param("id", rnd_customer_id().to_string())
+            .to_http_request();
+
+        let resp = get_customer_by_id_endpoint(mock_shared_state, req).await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+        let body = resp.into_body().try_into_bytes().unwrap();
+        let body_text = std::str::from_utf8(&body).unwrap();
+
+        let response_dto: GenericErrorResponse = serde_json::from_str(body_text).unwrap();
+
+        assert_eq!(&response_dto.response_type, &not_found_type_url());
+        assert_eq!(&response_dto.response_title, "Resource not found");
+    }
+
+    #[actix_web::test]
+    async fn invalid_id() {
+        dotenv().ok();
+        let mock_get_customer_by_id = AM::new_am(MockGetCustomerById::default());
+        let mock_shared_state = Data::new(mock_get_customer_by_id.clone());
+
+        let req = TestRequest::default()
+            .param("id", "not-a-uuid")
+            .to_http_request();
+
+        let resp = get_customer_by_id_endpoint(mock_shared_state, req).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+        let body = resp.into_body().try_into_bytes().unwrap();
+        let body_text = std::str::from_utf8(&body).unwrap();
+
+        let response_dto: GenericErrorResponse = serde_json::from_str(body_text).unwrap();
+
+        assert_eq!(&response_dto.response_type, &bad_request_type_url());
+        assert_eq!(response_dto.invalid_params.len(), 1);
+    }
+}
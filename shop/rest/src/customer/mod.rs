@@ -0,0 +1,8 @@
+pub mod add_customer_address_endpoint;
+pub mod customer_model;
+pub mod get_customer_by_id_endpoint;
+pub mod register_customer_endpoint;
+pub mod remove_customer_address_endpoint;
+pub mod remove_customer_endpoint;
+pub mod update_customer_endpoint;
+pub mod validation;
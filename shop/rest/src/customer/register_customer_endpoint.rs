@@ -0,0 +1,229 @@
+use std::fmt::Debug;
+
+use actix_web::{HttpResponse, http, web};
+use common::{
+    common_rest::{
+        GenericErrorResponse, created, rest_business_error, to_invalid_param_bad_request,
+    },
+    types::base::{AM, RCell, RcRefCellTrait},
+};
+use derive_new::new;
+use domain::customer::value_objects::{customer_name::CustomerName, phone_number::PhoneNumber};
+use http::Uri;
+use serde::{Deserialize, Serialize};
+use usecase::customer::{RegisterCustomer, RegisterCustomerUseCaseError};
+use utoipa::ToSchema;
+
+use crate::{
+    endpoint_url::{API_V1_CUSTOMER_GET_BY_ID, API_V1_CUSTOMER_REGISTER},
+    to_error::ToRestError,
+    validated::Validated,
+};
+
+#[derive(new, Serialize, Deserialize, Debug, ToSchema)]
+pub struct RegisterCustomerRestRequest {
+    /// Name of the customer
+    #[schema(example = "Ivan Petrov", required = true)]
+    name: String,
+    /// Phone number of the customer
+    #[schema(example = "+7 (916) 123-45-67", required = true)]
+    phone: String,
+}
+
+/// Register a new customer
+#[utoipa::path(
+    post,
+    path = API_V1_CUSTOMER_REGISTER,
+    tag = "Customer",
+    request_body(
+        content = RegisterCustomerRestRequest,
+    ),
+    responses(
+        (
+            status = CREATED,
+            description = "Created",
+            headers(
+                ("location" = String, description = "Location of new Customer")
+            )
+        ),
+        (
+            status = BAD_REQUEST,
+            description = "Bad request",
+            body = GenericErrorResponse,
+            example = json!(
+                {
+                    "type":"http://0.0.0.0:8080/bad_request",
+                    "title":"Bad request",
+                    "status":400,
+                    "invalid_params":
+                    [
+                        {"message":"Customer name is empty"},
+                        {"message":"Phone number is invalid"}
+                    ]
+                }
+            )
+        ),
+        (
+            status = UNPROCESSABLE_ENTITY,
+            description = "Customer already exists",
+            body = GenericErrorResponse,
+            example = json!({"type":"http://0.0.0.0:8080/already_exists","title":"Customer already exists","status":422})
+        ),
+    ))]
+pub async fn register_customer_endpoint<T>(
+    shared_state: web::Data<AM<T>>,
+    request: web::Json<RegisterCustomerRestRequest>,
+) -> HttpResponse
+where
+    T: RegisterCustomer + Send + Debug,
+{
+    let error_list = RCell::new_rc(vec![]);
+
+    let name = CustomerName::validated(&request.name, error_list.clone());
+    let phone = PhoneNumber::validated(&request.phone, error_list.clone());
+
+    match (name, phone) {
+        (Some(name), Some(phone)) => match shared_state.lock().await.execute(&name, &phone).await {
+            Ok(customer_id) => created(
+                API_V1_CUSTOMER_GET_BY_ID
+                    .replace("{id}", &customer_id.to_string())
+                    .parse::<Uri>()
+                    .unwrap(),
+            ),
+            Err(e) => e.to_rest_error(),
+        },
+        _ => to_invalid_param_bad_request(error_list),
+    }
+}
+
+impl ToRestError for RegisterCustomerUseCaseError {
+    fn to_rest_error(self) -> HttpResponse {
+        match self {
+            RegisterCustomerUseCaseError::AlreadyExists => {
+                rest_business_error("Customer already exists", "already_exists")
+            }
+            RegisterCustomerUseCaseError::UnknownError => {
+                HttpResponse::InternalServerError().finish()
+            }
+        }
+    }
+}
+
+pub fn register_customer_endpoint_config<T>(cfg: &mut web::ServiceConfig)
+where
+    T: RegisterCustomer + Send + Debug + 'static,
+{
+    cfg.route(
+        API_V1_CUSTOMER_REGISTER,
+        web::post().to(register_customer_endpoint::<T>),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{
+        body::MessageBody,
+        http::{StatusCode, header},
+        web::{Data, Json},
+    };
+    use common::{
+        common_rest::{bad_request_type_url, error_type_url},
+        types::base::AMTrait,
+    };
+    use domain::test_fixtures::*;
+    use dotenvy::dotenv;
+
+    use super::*;
+    use crate::test_fixtures::MockRegisterCustomer;
+
+    #[actix_web::test]
+    async fn created_successfully() {
+        dotenv().ok();
+        let customer_id = rnd_customer_id();
+        let name = rnd_customer_name();
+        let phone = rnd_phone_number();
+
+        let mock_register_customer = AM::new_am(MockRegisterCustomer::default());
+        mock_register_customer.lock().await.response = Ok(customer_id);
+        let mock_shared_state = Data::new(mock_register_customer.clone());
+
+        let request = Json(RegisterCustomerRestRequest::new(
+            name.to_string(),
+            phone.to_string(),
+        ));
+
+        let resp = register_customer_endpoint(mock_shared_state, request).await;
+
+        mock_register_customer
+            .lock()
+            .await
+            .verify_invoked(&name, &phone);
+
+        let header = resp
+            .headers()
+            .get(header::LOCATION)
+            .unwrap()
+            .to_str()
+            .unwrap();
+
+        assert_eq!(&resp.status(), &StatusCode::CREATED);
+        assert_eq!(
+            header,
+            API_V1_CUSTOMER_GET_BY_ID.replace("{id}", &customer_id.to_string())
+        );
+    }
+
+    #[actix_web::test]
+    async fn validation_error() {
+        dotenv().ok();
+        let mock_register_customer = AM::new_am(MockRegisterCustomer::default());
+        let mock_shared_state = Data::new(mock_register_customer.clone());
+
+        let request = Json(RegisterCustomerRestRequest::new(
+            "".to_string(),
+            "12".to_string(),
+        ));
+
+        let resp = register_customer_endpoint(mock_shared_state, request).await;
+        let body = resp.into_body().try_into_bytes().unwrap();
+        let body_text = std::str::from_utf8(&body).unwrap();
+
+        let response_dto: GenericErrorResponse = serde_json::from_str(body_text).unwrap();
+
+        assert_eq!(
+            &response_dto.response_status,
+            &StatusCode::BAD_REQUEST.as_u16()
+        );
+        assert_eq!(&response_dto.response_type, &bad_request_type_url());
+        assert_eq!(response_dto.invalid_params.len(), 2);
+    }
+
+    #[actix_web::test]
+    async fn customer_already_exists() {
+        dotenv().ok();
+        let mock_register_customer = AM::new_am(MockRegisterCustomer::default());
+        mock_register_customer.lock().await.response =
+            Err(RegisterCustomerUseCaseError::AlreadyExists);
+        let mock_shared_state = Data::new(mock_register_customer.clone());
+
+        let request = Json(RegisterCustomerRestRequest::new(
+            rnd_customer_name().to_string(),
+            rnd_phone_number().to_string(),
+        ));
+
+        let resp = register_customer_endpoint(mock_shared_state, request).await;
+
+        assert_eq!(resp.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let body = resp.into_body().try_into_bytes().unwrap();
+        let body_text = std::str::from_utf8(&body).unwrap();
+
+        let response_dto: GenericErrorResponse = serde_json::from_str(body_text).unwrap();
+
+        assert_eq!(
+            &response_dto.response_type,
+            &error_type_url("already_exists")
+        );
+        assert_eq!(&response_dto.response_title, "Customer already exists");
+    }
+}
@@ -0,0 +1,207 @@
+use std::fmt::Debug;
+
+use actix_web::{HttpRequest, HttpResponse, http::StatusCode, middleware::from_fn, web};
+use common::{
+    common_rest::{
+        GenericErrorResponse, get_json_from_http_response, resource_not_found,
+        to_invalid_param_bad_request,
+    },
+    types::base::{AM, RCell, RcRefCellTrait},
+};
+use domain::{
+    cart::value_objects::customer_id::CustomerId,
+    customer::value_objects::saved_address::SavedAddressId,
+};
+use usecase::customer::{RemoveCustomerAddress, RemoveCustomerAddressUseCaseError};
+
+use crate::{
+    auth::jwt_middleware::require_owner_or_staff, endpoint_url::API_V1_CUSTOMER_DELETE_ADDRESS,
+    to_error::ToRestError, validated::Validated,
+};
+
+/// Remove a saved address of the customer
+///
+/// Customers may only access their own profile, staff may access any
+#[utoipa::path(
+    delete,
+    path = API_V1_CUSTOMER_DELETE_ADDRESS,
+    tag = "Customer",
+    security(("bearerAuth" = [])),
+    params(
+        ("id" = String, Path, description = "Customer id"),
+        ("address_id" = String, Path, description = "Saved address id")
+    ),
+    responses(
+        (
+            status = UNAUTHORIZED,
+            description = "Missing or invalid bearer token",
+            body = GenericErrorResponse,
+            example = json!({"type":"http://0.0.0.0:8080/unauthorized","title":"Unauthorized","status":401})
+        ),
+        (
+            status = FORBIDDEN,
+            description = "Caller is not allowed to perform the operation",
+            body = GenericErrorResponse,
+            example = json!({"type":"http://0.0.0.0:8080/forbidden","title":"Forbidden","status":403})
+        ),
+        (
+            status = NO_CONTENT,
+            description = "Address successfully removed"
+        ),
+        (
+            status = BAD_REQUEST,
+            description = "Invalid id",
+            body = GenericErrorResponse,
+            example = json!(
+                {
+                    "type": "http://0.0.0.0:8080/bad_request",
+                    "title": "Bad request",
+                    "status": 400,
+                    "invalid_params":
+                    [
+                        {"message": "Wrong Saved Address Id"}
+                    ]
+                }
+            )
+        ),
+        (
+            status = NOT_FOUND,
+            description = "Customer or address not found",
+            body = GenericErrorResponse,
+            example = json!(&(get_json_from_http_response(resource_not_found())))
+        )
+    ))]
+pub async fn remove_customer_address_endpoint<T>(
+    shared_state: web::Data<AM<T>>,
+    req: HttpRequest,
+) -> HttpResponse
+where
+    T: RemoveCustomerAddress + Send + Debug,
+{
+    let error_list = RCell::new_rc(vec![]);
+
+    let customer_id = req
+        .match_info()
+        .get("id")
+        .and_then(|id| CustomerId::validated(id, error_list.clone()));
+    let address_id = req
+        .match_info()
+        .get("address_id")
+        .and_then(|id| SavedAddressId::validated(id, error_list.clone()));
+
+    match (customer_id, address_id) {
+        (Some(customer_id), Some(address_id)) => {
+            match shared_state
+                .lock()
+                .await
+                .execute(&customer_id, &address_id)
+                .await
+            {
+                Ok(_) => HttpResponse::new(StatusCode::NO_CONTENT),
+                Err(e) => e.to_rest_error(),
+            }
+        }
+        _ => to_invalid_param_bad_request(error_list),
+    }
+}
+
+impl ToRestError for RemoveCustomerAddressUseCaseError {
+    fn to_rest_error(self) -> HttpResponse {
+        resource_not_found()
+    }
+}
+
+pub fn remove_customer_address_endpoint_config<T>(cfg: &mut web::ServiceConfig)
+where
+    T: RemoveCustomerAddress + Send + Debug + 'static,
+{
+    cfg.route(
+        API_V1_CUSTOMER_DELETE_ADDRESS,
+        web::delete()
+            .to(remove_customer_address_endpoint::<T>)
+            .wrap(from_fn(require_owner_or_staff)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{body::MessageBody, test::TestRequest, web::Data};
+    use common::{
+        common_rest::{GenericErrorResponse, bad_request_type_url, not_found_type_url},
+        types::base::AMTrait,
+    };
+    use domain::test_fixtures::*;
+    use dotenvy::dotenv;
+
+    use super::*;
+    use crate::test_fixtures::MockRemoveCustomerAddress;
+
+    #[actix_web::test]
+    async fn removed_successfully() {
+        let customer_id = rnd_customer_id();
+        let address_id = SavedAddressId::new();
+        let mock_remove_address = AM::new_am(MockRemoveCustomerAddress::default());
+        let mock_shared_state = Data::new(mock_remove_address.clone());
+
+        let req = TestRequest::default()
+            .param("id", customer_id.to_string())
+            .param("address_id", address_id.to_string())
+            .to_http_request();
+
+        let resp = remove_customer_address_endpoint(mock_shared_state, req).await;
+
+        assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+        mock_remove_address
+            .lock()
+            .await
+            .verify_invoked(&customer_id, &address_id);
+    }
+
+    #[actix_web::test]
+    async fn address_not_found() {
+        dotenv().ok();
+        let mock_remove_address = AM::new_am(MockRemoveCustomerAddress::default());
+        mock_remove_address.lock().await.response =
+            Err(RemoveCustomerAddressUseCaseError::AddressNotFound);
+        let mock_shared_state = Data::new(mock_remove_address.clone());
+
+        let req = TestRequest::default()
+            .param("id", rnd_customer_id().to_string())
+            .param("address_id", SavedAddressId::new().to_string())
+            .to_http_request();
+
+        let resp = remove_customer_address_endpoint(mock_shared_state, req).await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+        let body = resp.into_body().try_into_bytes().unwrap();
+        let body_text = std::str::from_utf8(&body).unwrap();
+
+        let response_dto: GenericErrorResponse = serde_json::from_str(body_text).unwrap();
+
+        assert_eq!(&response_dto.response_type, &not_found_type_url());
+    }
+
+    #[actix_web::test]
+    async fn invalid_address_id() {
+        dotenv().ok();
+        let mock_remove_address = AM::new_am(MockRemoveCustomerAddress::default());
+        let mock_shared_state = Data::new(mock_remove_address.clone());
+
+        let req = TestRequest::default()
+            .param("id", rnd_customer_id().to_string())
+            .param("address_id", "42")
+            .to_http_request();
+
+        let resp = remove_customer_address_endpoint(mock_shared_state, req).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+        let body = resp.into_body().try_into_bytes().unwrap();
+        let body_text = std::str::from_utf8(&body).unwrap();
+
+        let response_dto: GenericErrorResponse = serde_json::from_str(body_text).unwrap();
+
+        assert_eq!(&response_dto.response_type, &bad_request_type_url());
+    }
+}
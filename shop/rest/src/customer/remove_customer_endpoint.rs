@@ -0,0 +1,164 @@
+use std::fmt::Debug;
+
+use actix_web::{HttpRequest, HttpResponse, http::StatusCode, middleware::from_fn, web};
+use common::{
+    common_rest::{
+        GenericErrorResponse, get_json_from_http_response, resource_not_found,
+        to_invalid_param_bad_request,
+    },
+    types::base::{AM, RCell, RcRefCellTrait},
+};
+use domain::cart::value_objects::customer_id::CustomerId;
+use usecase::customer::{RemoveCustomer, RemoveCustomerUseCaseError};
+
+use crate::{
+    auth::jwt_middleware::require_owner_or_staff, endpoint_url::API_V1_CUSTOMER_DELETE_BY_ID,
+    to_error::ToRestError, validated::Validated,
+};
+
+/// Remove a customer
+///
+/// Customers may only access their own profile, staff may access any
+#[utoipa::path(
+    delete,
+    path = API_V1_CUSTOMER_DELETE_BY_ID,
+    tag = "Customer",
+    security(("bearerAuth" = [])),
+    params(
+        ("id" = String, Path, description = "Customer id")
+    ),
+    responses(
+        (
+            status = UNAUTHORIZED,
+            description = "Missing or invalid bearer token",
+            body = GenericErrorResponse,
+            example = json!({"type":"http://0.0.0.0:8080/unauthorized","title":"Unauthorized","status":401})
+        ),
+        (
+            status = FORBIDDEN,
+            description = "Caller is not allowed to perform the operation",
+            body = GenericErrorResponse,
+            example = json!({"type":"http://0.0.0.0:8080/forbidden","title":"Forbidden","status":403})
+        ),
+        (
+            status = NO_CONTENT,
+            description = "Customer successfully removed"
+        ),
+        (
+            status = BAD_REQUEST,
+            description = "Invalid id",
+            body = GenericErrorResponse,
+            example = json!(
+                {
+                    "type": "http://0.0.0.0:8080/bad_request",
+                    "title": "Bad request",
+                    "status": 400,
+                    "invalid_params":
+                    [
+                        {"message": "Wrong Customer Id"}
+                    ]
+                }
+            )
+        ),
+        (
+            status = NOT_FOUND,
+            description = "Customer not found",
+            body = GenericErrorResponse,
+            example = json!(&(get_json_from_http_response(resource_not_found())))
+        )
+    ))]
+pub async fn remove_customer_endpoint<T>(
+    shared_state: web::Data<AM<T>>,
+    req: HttpRequest,
+) -> HttpResponse
+where
+    T: RemoveCustomer + Send + Debug,
+{
+    let error_list = RCell::new_rc(vec![]);
+
+    let maybe_customer_id = req
+        .match_info()
+        .get("id")
+        .and_then(|id| CustomerId::validated(id, error_list.clone()));
+
+    if let Some(customer_id) = maybe_customer_id {
+        match shared_state.lock().await.execute(&customer_id).await {
+            Ok(_) => HttpResponse::new(StatusCode::NO_CONTENT),
+            Err(e) => e.to_rest_error(),
+        }
+    } else {
+        to_invalid_param_bad_request(error_list)
+    }
+}
+
+impl ToRestError for RemoveCustomerUseCaseError {
+    fn to_rest_error(self) -> HttpResponse {
+        resource_not_found()
+    }
+}
+
+pub fn remove_customer_endpoint_config<T>(cfg: &mut web::ServiceConfig)
+where
+    T: RemoveCustomer + Send + Debug + 'static,
+{
+    cfg.route(
+        API_V1_CUSTOMER_DELETE_BY_ID,
+        web::delete()
+            .to(remove_customer_endpoint::<T>)
+            .wrap(from_fn(require_owner_or_staff)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{body::MessageBody, test::TestRequest, web::Data};
+    use common::{
+        common_rest::{GenericErrorResponse, not_found_type_url},
+        types::base::AMTrait,
+    };
+    use domain::test_fixtures::*;
+    use dotenvy::dotenv;
+
+    use super::*;
+    use crate::test_fixtures::MockRemoveCustomer;
+
+    #[actix_web::test]
+    async fn removed_successfully() {
+        let customer_id = rnd_customer_id();
+        let mock_remove_customer = AM::new_am(MockRemoveCustomer::default());
+        let mock_shared_state = Data::new(mock_remove_customer.clone());
+
+        let req = TestRequest::default()
+            .param("id", customer_id.to_string())
+            .to_http_request();
+
+        let resp = remove_customer_endpoint(mock_shared_state, req).await;
+
+        assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+        assert_eq!(mock_remove_customer.lock().await.id, customer_id);
+    }
+
+    #[actix_web::test]
+    async fn customer_not_found() {
+        dotenv().ok();
+        let mock_remove_customer = AM::new_am(MockRemoveCustomer::default());
+        mock_remove_customer.lock().await.response =
+            Err(RemoveCustomerUseCaseError::CustomerNotFound);
+        let mock_shared_state = Data::new(mock_remove_customer.clone());
+
+        let req = TestRequest::default()
+            .param("id", rnd_customer_id().to_string())
+            .to_http_request();
+
+        let resp = remove_customer_endpoint(mock_shared_state, req).await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+        let body = resp.into_body().try_into_bytes().unwrap();
+        let body_text = std::str::from_utf8(&body).unwrap();
+
+        let response_dto: GenericErrorResponse = serde_json::from_str(body_text).unwrap();
+
+        assert_eq!(&response_dto.response_type, &not_found_type_url());
+    }
+}
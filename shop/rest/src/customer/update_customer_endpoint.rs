@@ -0,0 +1,282 @@
+use std::fmt::Debug;
+
+use actix_web::{HttpRequest, HttpResponse, http::StatusCode, middleware::from_fn, web};
+use common::{
+    common_rest::{
+        GenericErrorResponse, get_json_from_http_response, resource_not_found, rest_business_error,
+        to_invalid_param_bad_request,
+    },
+    types::base::{AM, RCell, RcRefCellTrait},
+};
+use derive_new::new;
+use domain::{
+    cart::value_objects::customer_id::CustomerId,
+    customer::value_objects::{
+        customer_name::CustomerName, customer_preferences::CustomerPreferences,
+        phone_number::PhoneNumber, saved_address::SavedAddressId,
+    },
+};
+use serde::{Deserialize, Serialize};
+use usecase::customer::{UpdateCustomer, UpdateCustomerRequest, UpdateCustomerUseCaseError};
+use utoipa::ToSchema;
+
+use crate::{
+    auth::jwt_middleware::require_owner_or_staff, endpoint_url::API_V1_CUSTOMER_UPDATE_BY_ID,
+    to_error::ToRestError, validated::Validated,
+};
+
+#[derive(new, Serialize, Deserialize, Debug, ToSchema)]
+pub struct UpdateCustomerRestRequest {
+    /// Name of the customer
+    #[schema(example = "Ivan Petrov", required = true)]
+    name: String,
+    /// Phone number of the customer
+    #[schema(example = "+79161234567", required = true)]
+    phone: String,
+    /// Saved address used by default for delivery
+    #[schema(example = "5b0a4d1c-7c1e-4a36-8f1a-9c8d7e6f5a4b")]
+    default_address_id: Option<String>,
+    /// Whether the customer agrees to receive promotions
+    #[schema(example = false)]
+    #[serde(default)]
+    receive_promotions: bool,
+}
+
+/// Update contact details and preferences of a customer
+///
+/// Customers may only access their own profile, staff may access any
+#[utoipa::path(
+    put,
+    path = API_V1_CUSTOMER_UPDATE_BY_ID,
+    tag = "Customer",
+    security(("bearerAuth" = [])),
+    params(
+        ("id" = String, Path, description = "Customer id")
+    ),
+    request_body(
+        content = UpdateCustomerRestRequest,
+    ),
+    responses(
+        (
+            status = UNAUTHORIZED,
+            description = "Missing or invalid bearer token",
+            body = GenericErrorResponse,
+            example = json!({"type":"http://0.0.0.0:8080/unauthorized","title":"Unauthorized","status":401})
+        ),
+        (
+            status = FORBIDDEN,
+            description = "Caller is not allowed to perform the operation",
+            body = GenericErrorResponse,
+            example = json!({"type":"http://0.0.0.0:8080/forbidden","title":"Forbidden","status":403})
+        ),
+        (
+            status = NO_CONTENT,
+            description = "Customer successfully updated"
+        ),
+        (
+            status = BAD_REQUEST,
+            description = "Bad request",
+            body = GenericErrorResponse,
+            example = json!(
+                {
+                    "type":"http://0.0.0.0:8080/bad_request",
+                    "title":"Bad request",
+                    "status":400,
+                    "invalid_params":
+                    [
+                        {"message":"Customer name is empty"},
+                        {"message":"Phone number is invalid"}
+                    ]
+                }
+            )
+        ),
+        (
+            status = NOT_FOUND,
+            description = "Customer not found",
+            body = GenericErrorResponse,
+            example = json!(&(get_json_from_http_response(resource_not_found())))
+        ),
+        (
+            status = UNPROCESSABLE_ENTITY,
+            description = "Phone number is taken or default address is unknown",
+            body = GenericErrorResponse,
+            example = json!({"type":"http://0.0.0.0:8080/already_exists","title":"Customer already exists","status":422})
+        ),
+    ))]
+pub async fn update_customer_endpoint<T>(
+    shared_state: web::Data<AM<T>>,
+    req: HttpRequest,
+    request: web::Json<UpdateCustomerRestRequest>,
+) -> HttpResponse
+where
+    T: UpdateCustomer + Send + Debug,
+{
+    let error_list = RCell::new_rc(vec![]);
+
+    let customer_id = req
+        .match_info()
+        .get("id")
+        .and_then(|id| CustomerId::validated(id, error_list.clone()));
+    let name = CustomerName::validated(&request.name, error_list.clone());
+    let phone = PhoneNumber::validated(&request.phone, error_list.clone());
+    let default_address_id = match &request.default_address_id {
+        Some(id) => SavedAddressId::validated(id, error_list.clone()).map(Some),
+        None => Some(None),
+    };
+
+    match (customer_id, name, phone, default_address_id) {
+        (Some(customer_id), Some(name), Some(phone), Some(default_address_id)) => {
+            let request = UpdateCustomerRequest::new(
+                customer_id,
+                name,
+                phone,
+                CustomerPreferences::new(default_address_id, request.receive_promotions),
+            );
+            match shared_state.lock().await.execute(&request).await {
+                Ok(_) => HttpResponse::new(StatusCode::NO_CONTENT),
+                Err(e) => e.to_rest_error(),
+            }
+        }
+        _ => to_invalid_param_bad_request(error_list),
+    }
+}
+
+impl ToRestError for UpdateCustomerUseCaseError {
+    fn to_rest_error(self) -> HttpResponse {
+        match self {
+            UpdateCustomerUseCaseError::CustomerNotFound => resource_not_found(),
+            UpdateCustomerUseCaseError::AlreadyExists => {
+                rest_business_error("Customer already exists", "already_exists")
+            }
+            UpdateCustomerUseCaseError::SavedAddressNotFound => {
+                rest_business_error("Saved address not found", "saved_address_not_found")
+            }
+        }
+    }
+}
+
+pub fn update_customer_endpoint_config<T>(cfg: &mut web::ServiceConfig)
+where
+    T: UpdateCustomer + Send + Debug + 'static,
+{
+    cfg.route(
+        API_V1_CUSTOMER_UPDATE_BY_ID,
+        web::put()
+            .to(update_customer_endpoint::<T>)
+            .wrap(from_fn(require_owner_or_staff)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{
+        body::MessageBody,
+        test::TestRequest,
+        web::{Data, Json},
+    };
+    use common::{
+        common_rest::{GenericErrorResponse, bad_request_type_url, error_type_url},
+        types::base::AMTrait,
+    };
+    use domain::test_fixtures::*;
+    use dotenvy::dotenv;
+
+    use super::*;
+    use crate::test_fixtures::MockUpdateCustomer;
+
+    #[actix_web::test]
+    async fn updated_successfully() {
+        let customer_id = rnd_customer_id();
+        let name = rnd_customer_name();
+        let phone = rnd_phone_number();
+        let address_id = SavedAddressId::new();
+
+        let mock_update_customer = AM::new_am(MockUpdateCustomer::default());
+        let mock_shared_state = Data::new(mock_update_customer.clone());
+
+        let req = TestRequest::default()
+            .param("id", customer_id.to_string())
+            .to_http_request();
+        let request = Json(UpdateCustomerRestRequest::new(
+            name.to_string(),
+            phone.to_string(),
+            Some(address_id.to_string()),
+            true,
+        ));
+
+        let resp = update_customer_endpoint(mock_shared_state, req, request).await;
+
+        assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+        mock_update_customer
+            .lock()
+            .await
+            .verify_invoked(&UpdateCustomerRequest::new(
+                customer_id,
+                name,
+                phone,
+                CustomerPreferences::new(Some(address_id), true),
+            ));
+    }
+
+    #[actix_web::test]
+    async fn validation_error() {
+        dotenv().ok();
+        let mock_update_customer = AM::new_am(MockUpdateCustomer::default());
+        let mock_shared_state = Data::new(mock_update_customer.clone());
+
+        let req = TestRequest::default()
+            .param("id", rnd_customer_id().to_string())
+            .to_http_request();
+        let request = Json(UpdateCustomerRestRequest::new(
+            rnd_customer_name().to_string(),
+            rnd_phone_number().to_string(),
+            Some("not-a-uuid".to_string()),
+            false,
+        ));
+
+        let resp = update_customer_endpoint(mock_shared_state, req, request).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+        let body = resp.into_body().try_into_bytes().unwrap();
+        let body_text = std::str::from_utf8(&body).unwrap();
+
+        let response_dto: GenericErrorResponse = serde_json::from_str(body_text).unwrap();
+
+        assert_eq!(&response_dto.response_type, &bad_request_type_url());
+        assert_eq!(response_dto.invalid_params.len(), 1);
+        mock_update_customer.lock().await.verify_empty();
+    }
+
+    #[actix_web::test]
+    async fn phone_already_taken() {
+        dotenv().ok();
+        let mock_update_customer = AM::new_am(MockUpdateCustomer::default());
+        mock_update_customer.lock().await.response = Err(UpdateCustomerUseCaseError::AlreadyExists);
+        let mock_shared_state = Data::new(mock_update_customer.clone());
+
+        let req = TestRequest::default()
+            .param("id", rnd_customer_id().to_string())
+            .to_http_request();
+        let request = Json(UpdateCustomerRestRequest::new(
+            rnd_customer_name().to_string(),
+            rnd_phone_number().to_string(),
+            None,
+            false,
+        ));
+
+        let resp = update_customer_endpoint(mock_shared_state, req, request).await;
+
+        assert_eq!(resp.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let body = resp.into_body().try_into_bytes().unwrap();
+        let body_text = std::str::from_utf8(&body).unwrap();
+
+        let response_dto: GenericErrorResponse = serde_json::from_str(body_text).unwrap();
+
+        assert_eq!(
+            &response_dto.response_type,
+            &error_type_url("already_exists")
+        );
+    }
+}
@@ -0,0 +1,74 @@
+use common::{common_rest::ValidationError, types::base::RCell};
+use domain::{
+    cart::value_objects::customer_id::{CustomerId, CustomerIdError},
+    customer::value_objects::{
+        customer_name::{CreateCustomerNameError, CustomerName},
+        phone_number::{CreatePhoneNumberError, PhoneNumber},
+        saved_address::{SavedAddressId, SavedAddressIdError},
+    },
+};
+
+use crate::validated::Validated;
+
+impl Validated<&str> for CustomerName {
+    fn validated(val: &str, error_list: RCell<Vec<ValidationError>>) -> Option<Self> {
+        match Self::try_from(val) {
+            Ok(name) => Some(name),
+            Err(CreateCustomerNameError::EmptyCustomerNameError) => {
+                error_list
+                    .borrow_mut()
+                    .push(ValidationError::new("Customer name is empty"));
+                None
+            }
+            Err(CreateCustomerNameError::TooLongCustomerNameError) => {
+                error_list.borrow_mut().push(ValidationError::new(&format!(
+                    "Customer name must not be longer than {} characters",
+                    CustomerName::MAX_LENGTH
+                )));
+                None
+            }
+        }
+    }
+}
+
+impl Validated<&str> for PhoneNumber {
+    fn validated(val: &str, error_list: RCell<Vec<ValidationError>>) -> Option<Self> {
+        match Self::try_from(val) {
+            Ok(phone) => Some(phone),
+            Err(CreatePhoneNumberError::InvalidPhoneNumberError) => {
+                error_list
+                    .borrow_mut()
+                    .push(ValidationError::new("Phone number is invalid"));
+                None
+            }
+        }
+    }
+}
+
+impl Validated<&str> for CustomerId {
+    fn validated(val: &str, error_list: RCell<Vec<ValidationError>>) -> Option<Self> {
+        match Self::try_from(val) {
+            Ok(id) => Some(id),
+            Err(CustomerIdError::IdGenerationError) => {
+                error_list
+                    .borrow_mut()
+                    .push(ValidationError::new("Wrong Customer Id"));
+                None
+            }
+        }
+    }
+}
+
+impl Validated<&str> for SavedAddressId {
+    fn validated(val: &str, error_list: RCell<Vec<ValidationError>>) -> Option<Self> {
+        match Self::try_from(val) {
+            Ok(id) => Some(id),
+            Err(SavedAddressIdError::IdGenerationError) => {
+                error_list
+                    .borrow_mut()
+                    .push(ValidationError::new("Wrong Saved Address Id"));
+                None
+            }
+        }
+    }
+}
@@ -6,12 +6,46 @@ pub const API_V1_MENU: &str = formatcp!("{API_V1}/menu");
 pub const API_V1_MENU_ADD_TO_MENU: &str = formatcp!("{API_V1_MENU}/add");
 pub const API_V1_MENU_GET_BY_ID: &str = formatcp!("{API_V1_MENU}/{{id}}");
 pub const API_V1_MENU_GET_ALL: &str = formatcp!("{API_V1_MENU}");
+pub const API_V1_MENU_SEARCH: &str = formatcp!("{API_V1_MENU}/search");
+pub const API_V1_MENU_IMPORT: &str = formatcp!("{API_V1_MENU}/import");
+pub const API_V1_MENU_EXPORT: &str = formatcp!("{API_V1_MENU}/export");
 pub const API_V1_MENU_DELETE_BY_ID: &str = formatcp!("{API_V1_MENU}/{{id}}");
+pub const API_V1_MENU_SET_PORTIONS: &str = formatcp!("{API_V1_MENU}/{{id}}/portions");
+pub const API_V1_MENU_UPLOAD_IMAGE: &str = formatcp!("{API_V1_MENU}/{{id}}/images");
+pub const API_V1_MENU_CHANGE_AVAILABILITY: &str = formatcp!("{API_V1_MENU}/{{id}}/availability");
 
 pub const API_V1_ORDER: &str = formatcp!("{API_V1}/orders");
 pub const API_V1_ORDER_CANCEL_BY_ID: &str = formatcp!("{API_V1_ORDER}/{{id}}/cancel");
 pub const API_V1_ORDER_CONFIRM_BY_ID: &str = formatcp!("{API_V1_ORDER}/{{id}}/confirm");
 pub const API_V1_ORDER_GET_BY_ID: &str = formatcp!("{API_V1_ORDER}/{{id}}");
+pub const API_V1_ORDER_EVENTS_BY_ID: &str = formatcp!("{API_V1_ORDER}/{{id}}/events");
 pub const API_V1_ORDER_GET_ALL: &str = formatcp!("{API_V1_ORDER}");
 pub const API_V1_ORDER_GET_WITH_PAGINATION: &str =
     formatcp!("{API_V1_ORDER}/?startId={{startId}}&limit={{limit}}");
+
+pub const API_V1_KITCHEN: &str = formatcp!("{API_V1}/kitchen");
+pub const API_V1_KITCHEN_DISPLAY: &str = formatcp!("{API_V1_KITCHEN}/display");
+
+pub const API_V1_CUSTOMER: &str = formatcp!("{API_V1}/customers");
+pub const API_V1_CUSTOMER_REGISTER: &str = formatcp!("{API_V1_CUSTOMER}");
+pub const API_V1_CUSTOMER_GET_BY_ID: &str = formatcp!("{API_V1_CUSTOMER}/{{id}}");
+pub const API_V1_CUSTOMER_UPDATE_BY_ID: &str = formatcp!("{API_V1_CUSTOMER}/{{id}}");
+pub const API_V1_CUSTOMER_DELETE_BY_ID: &str = formatcp!("{API_V1_CUSTOMER}/{{id}}");
+pub const API_V1_CUSTOMER_ADD_ADDRESS: &str = formatcp!("{API_V1_CUSTOMER}/{{id}}/addresses");
+pub const API_V1_CUSTOMER_DELETE_ADDRESS: &str =
+    formatcp!("{API_V1_CUSTOMER}/{{id}}/addresses/{{address_id}}");
+pub const API_V1_CUSTOMER_ORDERS: &str = formatcp!("{API_V1_CUSTOMER}/{{id}}/orders");
+pub const API_V1_CUSTOMER_LAST_ORDER_STATE: &str =
+    formatcp!("{API_V1_CUSTOMER}/{{id}}/orders/last/state");
+
+pub const API_V1_WEBHOOKS: &str = formatcp!("{API_V1}/webhooks");
+pub const API_V1_WEBHOOKS_SUBSCRIBE: &str = formatcp!("{API_V1_WEBHOOKS}");
+pub const API_V1_WEBHOOKS_GET_ALL: &str = formatcp!("{API_V1_WEBHOOKS}");
+pub const API_V1_WEBHOOKS_DELETE_BY_ID: &str = formatcp!("{API_V1_WEBHOOKS}/{{id}}");
+pub const API_V1_WEBHOOKS_FAILED: &str = formatcp!("{API_V1_WEBHOOKS}/failed");
+
+pub const API_V1_DELIVERY_SLOTS: &str = formatcp!("{API_V1}/delivery_slots");
+pub const API_V1_DELIVERY_SLOTS_GET_AVAILABLE: &str = formatcp!("{API_V1_DELIVERY_SLOTS}");
+
+/// Meal images are served as static files under this path
+pub const MEDIA: &str = "/media";
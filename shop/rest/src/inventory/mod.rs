@@ -0,0 +1,2 @@
+pub mod set_meal_portions_endpoint;
+pub mod validation;
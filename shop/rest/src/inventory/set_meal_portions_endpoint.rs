@@ -0,0 +1,227 @@
+use std::fmt::Debug;
+
+use actix_web::{HttpRequest, HttpResponse, http::StatusCode, middleware::from_fn, web};
+use common::{
+    common_rest::{
+        GenericErrorResponse, get_json_from_http_response, resource_not_found,
+        to_invalid_param_bad_request,
+    },
+    types::{
+        base::{AM, RCell, RcRefCellTrait},
+        common::Count,
+    },
+};
+use derive_new::new;
+use domain::menu::value_objects::meal_id::MealId;
+use serde::{Deserialize, Serialize};
+use usecase::inventory::{SetMealPortions, SetMealPortionsUseCaseError};
+use utoipa::ToSchema;
+
+use crate::{
+    auth::jwt_middleware::require_admin, endpoint_url::API_V1_MENU_SET_PORTIONS,
+    to_error::ToRestError, validated::Validated,
+};
+
+#[derive(new, Serialize, Deserialize, Debug, ToSchema)]
+pub struct SetMealPortionsRestRequest {
+    /// Portions of the meal which can be ordered, `0` marks the meal as sold out
+    #[schema(example = 20, required = true)]
+    portions: i32,
+}
+
+/// Set the number of available portions of the meal
+///
+/// Requires the `admin` role. A meal without portions set is not limited
+#[utoipa::path(
+    put,
+    path = API_V1_MENU_SET_PORTIONS,
+    tag = "Meal",
+    security(("bearerAuth" = ["admin"])),
+    params(
+        ("id" = i64, Path, description = "Meal id")
+    ),
+    request_body(
+        content = SetMealPortionsRestRequest,
+    ),
+    responses(
+        (
+            status = UNAUTHORIZED,
+            description = "Missing or invalid bearer token",
+            body = GenericErrorResponse,
+            example = json!({"type":"http://0.0.0.0:8080/unauthorized","title":"Unauthorized","status":401})
+        ),
+        (
+            status = FORBIDDEN,
+            description = "Caller is not allowed to perform the operation",
+            body = GenericErrorResponse,
+            example = json!({"type":"http://0.0.0.0:8080/forbidden","title":"Forbidden","status":403})
+        ),
+        (
+            status = NO_CONTENT,
+            description = "Portions successfully set"
+        ),
+        (
+            status = BAD_REQUEST,
+            description = "Bad request",
+            body = GenericErrorResponse,
+            example = json!(
+                {
+                    "type": "http://0.0.0.0:8080/bad_request",
+                    "title": "Bad request",
+                    "status": 400,
+                    "invalid_params":
+                    [
+                        {"message": "Portions must be >= 0"}
+                    ]
+                }
+            )
+        ),
+        (
+            status = NOT_FOUND,
+            description = "Meal not found",
+            body = GenericErrorResponse,
+            example = json!(&(get_json_from_http_response(resource_not_found())))
+        )
+    ))]
+pub async fn set_meal_portions_endpoint<T>(
+    shared_state: web::Data<AM<T>>,
+    req: HttpRequest,
+    request: web::Json<SetMealPortionsRestRequest>,
+) -> HttpResponse
+where
+    T: SetMealPortions + Send + Debug,
+{
+    let error_list = RCell::new_rc(vec![]);
+
+    let meal_id = req
+        .match_info()
+        .get("id")
+        .and_then(|v| v.parse::<i64>().ok())
+        .and_then(|id| MealId::validated(id, error_list.clone()));
+    let portions = Count::validated(request.portions, error_list.clone());
+
+    match (meal_id, portions) {
+        (Some(meal_id), Some(portions)) => {
+            match shared_state.lock().await.execute(&meal_id, portions).await {
+                Ok(_) => HttpResponse::new(StatusCode::NO_CONTENT),
+                Err(e) => e.to_rest_error(),
+            }
+        }
+        _ => to_invalid_param_bad_request(error_list),
+    }
+}
+
+impl ToRestError for SetMealPortionsUseCaseError {
+    fn to_rest_error(self) -> HttpResponse {
+        resource_not_found()
+    }
+}
+
+pub fn set_meal_portions_endpoint_config<T>(cfg: &mut web::ServiceConfig)
+where
+    T: SetMealPortions + Send + Debug + 'static,
+{
+    cfg.route(
+        API_V1_MENU_SET_PORTIONS,
+        web::put()
+            .to(set_meal_portions_endpoint::<T>)
+            .wrap(from_fn(require_admin)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{
+        body::MessageBody,
+        test::TestRequest,
+        web::{Data, Json},
+    };
+    use common::{
+        common_rest::{bad_request_type_url, not_found_type_url},
+        types::base::AMTrait,
+    };
+    use domain::test_fixtures::*;
+    use dotenvy::dotenv;
+
+    use super::*;
+    use crate::test_fixtures::MockSetMealPortions;
+
+    #[actix_web::test]
+    async fn portions_set() {
+        let meal_id = rnd_meal_id();
+        let mock_set_meal_portions = AM::new_am(MockSetMealPortions::default());
+        let mock_shared_state = Data::new(mock_set_meal_portions.clone());
+
+        let req = TestRequest::default()
+            .param("id", meal_id.to_i64().to_string())
+            .to_http_request();
+
+        let resp = set_meal_portions_endpoint(
+            mock_shared_state,
+            req,
+            Json(SetMealPortionsRestRequest::new(5)),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+        mock_set_meal_portions
+            .lock()
+            .await
+            .verify_invoked(&meal_id, &Count::try_from(5).unwrap());
+    }
+
+    #[actix_web::test]
+    async fn negative_portions() {
+        dotenv().ok();
+        let mock_set_meal_portions = AM::new_am(MockSetMealPortions::default());
+        let mock_shared_state = Data::new(mock_set_meal_portions.clone());
+
+        let req = TestRequest::default()
+            .param("id", rnd_meal_id().to_i64().to_string())
+            .to_http_request();
+
+        let resp = set_meal_portions_endpoint(
+            mock_shared_state,
+            req,
+            Json(SetMealPortionsRestRequest::new(-1)),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let body = resp.into_body().try_into_bytes().unwrap();
+        let response_dto: GenericErrorResponse =
+            serde_json::from_str(std::str::from_utf8(&body).unwrap()).unwrap();
+        assert_eq!(&response_dto.response_type, &bad_request_type_url());
+        assert_eq!(
+            &response_dto.invalid_params.first().unwrap().message,
+            "Portions must be >= 0"
+        );
+        mock_set_meal_portions.lock().await.verify_empty();
+    }
+
+    #[actix_web::test]
+    async fn meal_not_found() {
+        dotenv().ok();
+        let mock_set_meal_portions = AM::new_am(MockSetMealPortions::default());
+        mock_set_meal_portions.lock().await.response =
+            Err(SetMealPortionsUseCaseError::MealNotFound);
+        let mock_shared_state = Data::new(mock_set_meal_portions.clone());
+
+        let req = TestRequest::default()
+            .param("id", rnd_meal_id().to_i64().to_string())
+            .to_http_request();
+
+        let resp = set_meal_portions_endpoint(
+            mock_shared_state,
+            req,
+            Json(SetMealPortionsRestRequest::new(5)),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+        let body = resp.into_body().try_into_bytes().unwrap();
+        let response_dto: GenericErrorResponse =
+            serde_json::from_str(std::str::from_utf8(&body).unwrap()).unwrap();
+        assert_eq!(&response_dto.response_type, &not_found_type_url());
+    }
+}
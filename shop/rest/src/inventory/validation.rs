@@ -0,0 +1,29 @@
+use common::{
+    common_rest::ValidationError,
+    types::{
+        base::RCell,
+        common::{Count, CountError},
+    },
+};
+
+use crate::validated::Validated;
+
+impl Validated<i32> for Count {
+    fn validated(val: i32, error_list: RCell<Vec<ValidationError>>) -> Option<Self> {
+        match Self::try_from(val) {
+            Ok(count) => Some(count),
+            Err(CountError::NegativeValueError) => {
+                error_list
+                    .borrow_mut()
+                    .push(ValidationError::new("Portions must be >= 0"));
+                None
+            }
+            Err(_) => {
+                error_list
+                    .borrow_mut()
+                    .push(ValidationError::new("Portions are out of range"));
+                None
+            }
+        }
+    }
+}
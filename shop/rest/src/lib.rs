@@ -1,10 +1,14 @@
 #![allow(special_module_name)]
 
+pub mod auth;
+pub mod customer;
 pub mod endpoint_url;
+pub mod inventory;
 pub mod menu;
 pub mod order;
 #[cfg(test)]
 pub mod test_fixtures;
+pub mod validated;
+pub mod webhook;
 
 mod to_error;
-mod validated;
@@ -1,6 +1,6 @@
 use std::fmt::Debug;
 
-use actix_web::{HttpResponse, http, web};
+use actix_web::{HttpResponse, http, middleware::from_fn, web};
 use bigdecimal::BigDecimal;
 use common::{
     common_rest::{
@@ -10,7 +10,8 @@ use common::{
 };
 use derive_new::new;
 use domain::menu::value_objects::{
-    meal_description::MealDescription, meal_name::MealName, price::Price,
+    allergens::Allergens, meal_description::MealDescription, meal_name::MealName,
+    nutrition_facts::NutritionFacts, price::Price,
 };
 use http::Uri;
 use serde::{Deserialize, Serialize};
@@ -18,7 +19,9 @@ use usecase::menu::{AddMealToMenu, AddMealToMenuUseCaseError};
 use utoipa::ToSchema;
 
 use crate::{
+    auth::jwt_middleware::require_admin,
     endpoint_url::{API_V1_MENU_ADD_TO_MENU, API_V1_MENU_GET_BY_ID},
+    menu::meal_model::NutritionFactsModel,
     to_error::ToRestError,
     validated::Validated,
 };
@@ -34,17 +37,38 @@ pub struct AddMealToMenuRestRequest {
     /// Price of the meal
     #[schema(example = 200, required = true)]
     price: f64,
+    /// Allergens contained in the meal
+    #[schema(example = json!(["gluten", "milk"]), required = true)]
+    allergens: Vec<String>,
+    /// Nutrition facts of one portion
+    #[schema(required = true)]
+    nutrition_facts: NutritionFactsModel,
 }
 
 /// Add a meal to the menu
+///
+/// Requires the `admin` role
 #[utoipa::path(
     post,
     path = API_V1_MENU_ADD_TO_MENU,
     tag = "Meal",
+    security(("bearerAuth" = ["admin"])),
     request_body(
         content = AddMealToMenuRestRequest,
     ),
     responses(
+        (
+            status = UNAUTHORIZED,
+            description = "Missing or invalid bearer token",
+            body = GenericErrorResponse,
+            example = json!({"type":"http://0.0.0.0:8080/unauthorized","title":"Unauthorized","status":401})
+        ),
+        (
+            status = FORBIDDEN,
+            description = "Caller is not allowed to perform the operation",
+            body = GenericErrorResponse,
+            example = json!({"type":"http://0.0.0.0:8080/forbidden","title":"Forbidden","status":403})
+        ),
         (
             status = CREATED,
             description = "Created",
@@ -65,7 +89,9 @@ pub struct AddMealToMenuRestRequest {
                     [
                         {"message":"Meal name is empty."},
                         {"message":"Meal description is empty"},
-                        {"message":"Price scale must not be > 2"}
+                        {"message":"Price scale must not be > 2"},
+                        {"message":"Unknown allergen 'honey'"},
+                        {"message":"Nutrition facts must not be negative"}
                     ]
                 }
             )
@@ -96,13 +122,33 @@ where
         .parse::<BigDecimal>()
         .ok()
         .and_then(|p| Price::validated(p, error_list.clone()));
-
-    match (meal_name, meal_description, price) {
-        (Some(meal_name), Some(meal_description), Some(price)) => {
+    let allergens = Allergens::validated(request.allergens.as_slice(), error_list.clone());
+    let nutrition_facts = NutritionFacts::validated(&request.nutrition_facts, error_list.clone());
+
+    match (
+        meal_name,
+        meal_description,
+        price,
+        allergens,
+        nutrition_facts,
+    ) {
+        (
+            Some(meal_name),
+            Some(meal_description),
+            Some(price),
+            Some(allergens),
+            Some(nutrition_facts),
+        ) => {
             match shared_state
                 .lock()
                 .await
-                .execute(&meal_name, &meal_description, &price)
+                .execute(
+                    &meal_name,
+                    &meal_description,
+                    &price,
+                    &allergens,
+                    &nutrition_facts,
+                )
                 .await
             {
                 Ok(meal_id) => created(
@@ -130,7 +176,9 @@ where
 {
     cfg.route(
         API_V1_MENU_ADD_TO_MENU,
-        web::post().to(add_meal_to_menu_endpoint::<T>),
+        web::post()
+            .to(add_meal_to_menu_endpoint::<T>)
+            .wrap(from_fn(require_admin)),
     );
 }
 
@@ -159,6 +207,8 @@ mod tests {
         let meal_name = rnd_meal_name();
         let meal_description = rnd_meal_description();
         let price = rnd_price();
+        let allergens = rnd_allergens();
+        let nutrition_facts = rnd_nutrition_facts();
 
         let mock_add_meal_to_menu = mock_add_meal_to_menu();
         mock_add_meal_to_menu.lock().await.response = Ok(meal_id);
@@ -169,14 +219,19 @@ mod tests {
             meal_name.clone().to_string(),
             meal_description.clone().to_string(),
             price.to_bigdecimal().to_f64().unwrap(),
+            allergens.to_codes(),
+            NutritionFactsModel::from(&nutrition_facts),
         ));
 
         let resp = add_meal_to_menu_endpoint(mock_shared_state, meal).await;
 
-        mock_add_meal_to_menu
-            .lock()
-            .await
-            .verify_invoked(&meal_name, &meal_description, &price);
+        mock_add_meal_to_menu.lock().await.verify_invoked(
+            &meal_name,
+            &meal_description,
+            &price,
+            &allergens,
+            &nutrition_facts,
+        );
 
         let header = resp
             .headers()
@@ -202,6 +257,13 @@ mod tests {
             "".to_string(),
             "".to_string(),
             BigDecimal::new(BigInt::from(1), 20).to_f64().unwrap(),
+            vec!["honey".to_string()],
+            NutritionFactsModel {
+                calories: -1,
+                proteins: 0.0,
+                fats: 0.0,
+                carbohydrates: 0.0,
+            },
         ));
 
         let resp = add_meal_to_menu_endpoint(mock_shared_state, meal).await;
@@ -220,7 +282,7 @@ mod tests {
             &StatusCode::BAD_REQUEST.as_u16()
         );
         assert_eq!(&response_dto.response_title, &"Bad request");
-        assert_eq!(response_dto.invalid_params.len(), 3);
+        assert_eq!(response_dto.invalid_params.len(), 5);
     }
 
     #[actix_web::test]
@@ -233,6 +295,8 @@ mod tests {
             rnd_meal_name().to_string(),
             rnd_meal_description().to_string(),
             rnd_price().to_f64(),
+            rnd_allergens().to_codes(),
+            NutritionFactsModel::from(&rnd_nutrition_facts()),
         ));
 
         let resp = add_meal_to_menu_endpoint(mock_shared_state, meal).await;
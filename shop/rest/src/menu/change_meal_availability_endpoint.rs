@@ -0,0 +1,256 @@
+use std::fmt::Debug;
+
+use actix_web::{HttpRequest, HttpResponse, http::StatusCode, middleware::from_fn, web};
+use common::{
+    common_rest::{
+        GenericErrorResponse, get_json_from_http_response, resource_not_found,
+        to_invalid_param_bad_request,
+    },
+    types::base::{AM, RCell, RcRefCellTrait},
+};
+use derive_new::new;
+use domain::menu::value_objects::{availability_schedule::AvailabilitySchedule, meal_id::MealId};
+use serde::{Deserialize, Serialize};
+use usecase::menu::{ChangeMealAvailability, ChangeMealAvailabilityUseCaseError};
+use utoipa::ToSchema;
+
+use crate::{
+    auth::jwt_middleware::require_admin, endpoint_url::API_V1_MENU_CHANGE_AVAILABILITY,
+    menu::meal_model::AvailabilityScheduleModel, to_error::ToRestError, validated::Validated,
+};
+
+#[derive(new, Serialize, Deserialize, Debug, ToSchema)]
+pub struct ChangeMealAvailabilityRestRequest {
+    /// When the meal can be ordered, `null` makes it available at any time
+    schedule: Option<AvailabilityScheduleModel>,
+}
+
+/// Change when the meal can be ordered
+///
+/// Requires the `admin` role. Days and times of the windows are in the local time of the
+/// time zone, a window closing before it opens lasts until the next day
+#[utoipa::path(
+    put,
+    path = API_V1_MENU_CHANGE_AVAILABILITY,
+    tag = "Meal",
+    security(("bearerAuth" = ["admin"])),
+    params(
+        ("id" = i64, Path, description = "Meal id")
+    ),
+    request_body(
+        content = ChangeMealAvailabilityRestRequest,
+        example = json!({"schedule":{"time_zone":"Europe/Berlin","windows":[{"days":["monday","tuesday","wednesday","thursday","friday"],"from":"07:00","to":"11:00"}]}})
+    ),
+    responses(
+        (
+            status = UNAUTHORIZED,
+            description = "Missing or invalid bearer token",
+            body = GenericErrorResponse,
+            example = json!({"type":"http://0.0.0.0:8080/unauthorized","title":"Unauthorized","status":401})
+        ),
+        (
+            status = FORBIDDEN,
+            description = "Caller is not allowed to perform the operation",
+            body = GenericErrorResponse,
+            example = json!({"type":"http://0.0.0.0:8080/forbidden","title":"Forbidden","status":403})
+        ),
+        (
+            status = NO_CONTENT,
+            description = "Availability successfully changed"
+        ),
+        (
+            status = BAD_REQUEST,
+            description = "Bad request",
+            body = GenericErrorResponse,
+            example = json!(
+                {
+                    "type": "http://0.0.0.0:8080/bad_request",
+                    "title": "Bad request",
+                    "status": 400,
+                    "invalid_params":
+                    [
+                        {"message": "Unknown time zone 'Mars/Olympus'"}
+                    ]
+                }
+            )
+        ),
+        (
+            status = NOT_FOUND,
+            description = "Meal not found",
+            body = GenericErrorResponse,
+            example = json!(&(get_json_from_http_response(resource_not_found())))
+        )
+    ))]
+pub async fn change_meal_availability_endpoint<T>(
+    shared_state: web::Data<AM<T>>,
+    req: HttpRequest,
+    request: web::Json<ChangeMealAvailabilityRestRequest>,
+) -> HttpResponse
+where
+    T: ChangeMealAvailability + Send + Debug,
+{
+    let error_list = RCell::new_rc(vec![]);
+
+    let meal_id = req
+        .match_info()
+        .get("id")
+        .and_then(|v| v.parse::<i64>().ok())
+        .and_then(|id| MealId::validated(id, error_list.clone()));
+    let schedule = match &request.schedule {
+        None => Some(None),
+        Some(model) => AvailabilitySchedule::validated(model, error_list.clone()).map(Some),
+    };
+
+    match (meal_id, schedule) {
+        (Some(meal_id), Some(schedule)) => {
+            match shared_state.lock().await.execute(&meal_id, schedule).await {
+                Ok(_) => HttpResponse::new(StatusCode::NO_CONTENT),
+                Err(e) => e.to_rest_error(),
+            }
+        }
+        _ => to_invalid_param_bad_request(error_list),
+    }
+}
+
+impl ToRestError for ChangeMealAvailabilityUseCaseError {
+    fn to_rest_error(self) -> HttpResponse {
+        resource_not_found()
+    }
+}
+
+pub fn change_meal_availability_endpoint_config<T>(cfg: &mut web::ServiceConfig)
+where
+    T: ChangeMealAvailability + Send + Debug + 'static,
+{
+    cfg.route(
+        API_V1_MENU_CHANGE_AVAILABILITY,
+        web::put()
+            .to(change_meal_availability_endpoint::<T>)
+            .wrap(from_fn(require_admin)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{
+        body::MessageBody,
+        test::TestRequest,
+        web::{Data, Json},
+    };
+    use common::{common_rest::not_found_type_url, types::base::AMTrait};
+    use domain::test_fixtures::*;
+    use dotenvy::dotenv;
+
+    use super::*;
+    use crate::{
+        menu::meal_model::AvailabilityWindowModel, test_fixtures::MockChangeMealAvailability,
+    };
+
+    fn request_for(meal_id: &MealId) -> HttpRequest {
+        TestRequest::default()
+            .param("id", meal_id.to_i64().to_string())
+            .to_http_request()
+    }
+
+    #[actix_web::test]
+    async fn schedule_changed() {
+        let meal_id = rnd_meal_id();
+        let schedule = rnd_availability_schedule();
+        let mock_change_availability = AM::new_am(MockChangeMealAvailability::default());
+
+        let resp = change_meal_availability_endpoint(
+            Data::new(mock_change_availability.clone()),
+            request_for(&meal_id),
+            Json(ChangeMealAvailabilityRestRequest::new(Some(
+                AvailabilityScheduleModel::from(&schedule),
+            ))),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+        mock_change_availability
+            .lock()
+            .await
+            .verify_invoked(&meal_id, &Some(schedule));
+    }
+
+    #[actix_web::test]
+    async fn schedule_removed() {
+        let meal_id = rnd_meal_id();
+        let mock_change_availability = AM::new_am(MockChangeMealAvailability::default());
+
+        let resp = change_meal_availability_endpoint(
+            Data::new(mock_change_availability.clone()),
+            request_for(&meal_id),
+            Json(ChangeMealAvailabilityRestRequest::new(None)),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+        mock_change_availability
+            .lock()
+            .await
+            .verify_invoked(&meal_id, &None);
+    }
+
+    #[actix_web::test]
+    async fn invalid_schedule() {
+        dotenv().ok();
+        let mock_change_availability = AM::new_am(MockChangeMealAvailability::default());
+        let schedule = AvailabilityScheduleModel {
+            time_zone: "Mars/Olympus".to_string(),
+            windows: vec![AvailabilityWindowModel {
+                days: vec!["someday".to_string()],
+                from: "7 am".to_string(),
+                to: "11:00".to_string(),
+            }],
+        };
+
+        let resp = change_meal_availability_endpoint(
+            Data::new(mock_change_availability.clone()),
+            request_for(&rnd_meal_id()),
+            Json(ChangeMealAvailabilityRestRequest::new(Some(schedule))),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let body = resp.into_body().try_into_bytes().unwrap();
+        let response_dto: GenericErrorResponse =
+            serde_json::from_str(std::str::from_utf8(&body).unwrap()).unwrap();
+        let messages: Vec<&str> = response_dto
+            .invalid_params
+            .iter()
+            .map(|it| it.message.as_str())
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                "Unknown day 'someday'",
+                "Time '7 am' must be in format HH:MM"
+            ]
+        );
+        mock_change_availability.lock().await.verify_empty();
+    }
+
+    #[actix_web::test]
+    async fn meal_not_found() {
+        dotenv().ok();
+        let mock_change_availability = AM::new_am(MockChangeMealAvailability {
+            response: Err(ChangeMealAvailabilityUseCaseError::MealNotFound),
+            ..Default::default()
+        });
+
+        let resp = change_meal_availability_endpoint(
+            Data::new(mock_change_availability),
+            request_for(&rnd_meal_id()),
+            Json(ChangeMealAvailabilityRestRequest::new(None)),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+        let body = resp.into_body().try_into_bytes().unwrap();
+        let response_dto: GenericErrorResponse =
+            serde_json::from_str(std::str::from_utf8(&body).unwrap()).unwrap();
+        assert_eq!(&response_dto.response_type, &not_found_type_url());
+    }
+}
@@ -0,0 +1,213 @@
+use std::{collections::HashMap, fmt::Debug};
+
+use actix_web::{
+    HttpRequest, HttpResponse,
+    http::header::{self, ContentDisposition, DispositionParam, DispositionType},
+    middleware::from_fn,
+    web::{self, Query},
+};
+use common::{
+    common_rest::{GenericErrorResponse, ValidationError, to_invalid_param_bad_request},
+    types::base::{AM, RCell, RcRefCellTrait},
+};
+use usecase::menu::ExportMenu;
+
+use crate::{
+    auth::jwt_middleware::require_admin,
+    endpoint_url::API_V1_MENU_EXPORT,
+    menu::meal_row_model::{MealRowModel, MenuFileFormat},
+};
+
+/// Export the menu
+///
+/// Requires the `admin` role. The file has the format accepted by the menu import
+#[utoipa::path(
+    get,
+    path = API_V1_MENU_EXPORT,
+    tag = "Meal",
+    security(("bearerAuth" = ["admin"])),
+    params(
+        ("format" = Option<String>, Query, description = "'json' (default) or 'csv'")
+    ),
+    responses(
+        (
+            status = UNAUTHORIZED,
+            description = "Missing or invalid bearer token",
+            body = GenericErrorResponse,
+            example = json!({"type":"http://0.0.0.0:8080/unauthorized","title":"Unauthorized","status":401})
+        ),
+        (
+            status = FORBIDDEN,
+            description = "Caller is not allowed to perform the operation",
+            body = GenericErrorResponse,
+            example = json!({"type":"http://0.0.0.0:8080/forbidden","title":"Forbidden","status":403})
+        ),
+        (
+            status = OK,
+            description = "All meals of the menu",
+            content(
+                (Vec<MealRowModel> = "application/json"),
+                (String = "text/csv")
+            )
+        ),
+        (
+            status = BAD_REQUEST,
+            description = "Bad request",
+            body = GenericErrorResponse,
+            example = json!(
+                {
+                    "type": "http://0.0.0.0:8080/bad_request",
+                    "title": "Bad request",
+                    "status": 400,
+                    "invalid_params":
+                    [
+                        {"message": "Format must be 'csv' or 'json'"}
+                    ]
+                }
+            )
+        ),
+    ))]
+pub async fn export_menu_endpoint<T>(
+    shared_state: web::Data<AM<T>>,
+    req: HttpRequest,
+) -> HttpResponse
+where
+    T: ExportMenu + Send + Debug,
+{
+    let error_list = RCell::new_rc(vec![]);
+
+    match validate_format(&req, error_list.clone()) {
+        Some(format) => {
+            let rows = shared_state.lock().await.execute().await;
+            HttpResponse::Ok()
+                .content_type(format.content_type())
+                .insert_header((
+                    header::CONTENT_DISPOSITION,
+                    ContentDisposition {
+                        disposition: DispositionType::Attachment,
+                        parameters: vec![DispositionParam::Filename(format!(
+                            "menu.{}",
+                            format.extension()
+                        ))],
+                    },
+                ))
+                .body(format.write(rows))
+        }
+        None => to_invalid_param_bad_request(error_list),
+    }
+}
+
+fn validate_format(
+    req: &HttpRequest,
+    error_list: RCell<Vec<ValidationError>>,
+) -> Option<MenuFileFormat> {
+    let query_params = Query::<HashMap<String, String>>::from_query(req.query_string()).unwrap();
+    match query_params.get("format") {
+        None => Some(MenuFileFormat::Json),
+        Some(name) => {
+            let format = MenuFileFormat::from_name(name);
+            if format.is_none() {
+                error_list
+                    .borrow_mut()
+                    .push(ValidationError::new("Format must be 'csv' or 'json'"));
+            }
+            format
+        }
+    }
+}
+
+pub fn export_menu_endpoint_config<T>(cfg: &mut web::ServiceConfig)
+where
+    T: ExportMenu + Send + Debug + 'static,
+{
+    cfg.route(
+        API_V1_MENU_EXPORT,
+        web::get()
+            .to(export_menu_endpoint::<T>)
+            .wrap(from_fn(require_admin)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{body::MessageBody, http::StatusCode, test::TestRequest, web::Data};
+    use common::types::base::AMTrait;
+    use domain::test_fixtures::*;
+    use dotenvy::dotenv;
+    use usecase::menu::dto::meal_row::MealRow;
+
+    use super::*;
+    use crate::test_fixtures::MockExportMenu;
+
+    fn mock_export_menu(rows: Vec<MealRow>) -> AM<MockExportMenu> {
+        AM::new_am(MockExportMenu {
+            response: rows,
+            ..Default::default()
+        })
+    }
+
+    #[actix_web::test]
+    async fn exported_as_json_by_default() {
+        let rows = vec![MealRow::from(&rnd_meal())];
+        let mock_export_menu = mock_export_menu(rows.clone());
+
+        let resp = export_menu_endpoint(
+            Data::new(mock_export_menu.clone()),
+            TestRequest::default().to_http_request(),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(
+            resp.headers().get(header::CONTENT_TYPE).unwrap(),
+            "application/json"
+        );
+        assert_eq!(
+            resp.headers().get(header::CONTENT_DISPOSITION).unwrap(),
+            "attachment; filename=\"menu.json\""
+        );
+        let body = resp.into_body().try_into_bytes().unwrap();
+        let models: Vec<MealRowModel> =
+            serde_json::from_str(std::str::from_utf8(&body).unwrap()).unwrap();
+        let exported: Vec<MealRow> = models.into_iter().map(MealRow::from).collect();
+        assert_eq!(exported, rows);
+        mock_export_menu.lock().await.verify_invoked();
+    }
+
+    #[actix_web::test]
+    async fn exported_as_csv() {
+        let rows = vec![MealRow::from(&rnd_meal())];
+
+        let resp = export_menu_endpoint(
+            Data::new(mock_export_menu(rows.clone())),
+            TestRequest::default().uri("/?format=csv").to_http_request(),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(
+            resp.headers().get(header::CONTENT_TYPE).unwrap(),
+            "text/csv"
+        );
+        let body = resp.into_body().try_into_bytes().unwrap();
+        assert_eq!(
+            std::str::from_utf8(&body).unwrap(),
+            MenuFileFormat::Csv.write(rows)
+        );
+    }
+
+    #[actix_web::test]
+    async fn unknown_format() {
+        dotenv().ok();
+        let mock_export_menu = mock_export_menu(vec![]);
+
+        let resp = export_menu_endpoint(
+            Data::new(mock_export_menu.clone()),
+            TestRequest::default().uri("/?format=xml").to_http_request(),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        mock_export_menu.lock().await.verify_empty();
+    }
+}
@@ -1,40 +1,210 @@
-use std::fmt::Debug;
+use std::{collections::HashMap, fmt::Debug, time::SystemTime};
 
-use actix_web::{HttpResponse, http::header::ContentType, web};
-use common::types::base::AM;
-use usecase::menu::GetMenu;
+use actix_web::{
+    HttpRequest, HttpResponse,
+    http::header::{ContentType, ETag, EntityTag, Header, HttpDate, IfNoneMatch, LastModified},
+    web::{self, Query},
+};
+use common::{
+    common_rest::{
+        CursorPagedModel, GenericErrorResponse, ValidationError, to_invalid_param_bad_request,
+    },
+    types::base::{AM, RCell, RcRefCellTrait},
+};
+use domain::menu::value_objects::{allergens::Allergens, meal_id::MealId};
+use sha2::{Digest, Sha256};
+use time::{OffsetDateTime, format_description::well_known::Rfc3339};
+use usecase::menu::{GetMenu, GetMenuUseCaseError, MenuAvailability, dto::meal_info::MealInfo};
 
-use crate::{endpoint_url::API_V1_MENU_GET_ALL, menu::meal_model::MealModel};
+use crate::{
+    endpoint_url::API_V1_MENU_GET_ALL, menu::meal_model::MealModel,
+    order::validated::validate_query_string, to_error::ToRestError, validated::Validated,
+};
 
-/// Get the menu
+/// Get the menu with pagination
+///
+/// Meals containing any of `excludeAllergens` are left out. Only meals which can be ordered now
+/// are listed, `availableAt` asks for another moment or `any` for all meals. The next page
+/// starts at the `next` meal. The `ETag` changes whenever a meal of the page changes its
+/// version, a request with a matching `If-None-Match` gets `304 Not Modified`
 #[utoipa::path(
     get,
     path = API_V1_MENU_GET_ALL,
     tag = "Meal",
+    params(
+        ("limit" = usize, Query, description = "Pagination limit"),
+        ("startId" = i64, Query, description = "Pagination start ID"),
+        ("excludeAllergens" = Option<String>, Query, description = "Comma separated allergens, e.g. 'milk,gluten'"),
+        ("availableAt" = Option<String>, Query, description = "RFC 3339 date-time, e.g. '2024-06-03T08:00:00Z', or 'any', now by default"),
+        ("If-None-Match" = Option<String>, Header, description = "ETag of the page known to the client")
+    ),
     responses(
         (
             status = OK,
             body = Vec<MealModel>,
-            description = "OK" 
-        )
+            description = "OK",
+            headers(
+                ("ETag" = String, description = "Version of the page"),
+                ("Last-Modified" = String, description = "Last time the menu was changed")
+            )
+        ),
+        (
+            status = NOT_MODIFIED,
+            description = "The page is not changed since the ETag from `If-None-Match`"
+        ),
+        (
+            status = BAD_REQUEST,
+            description = "Bad request",
+            body = GenericErrorResponse,
+            example = json!(
+                {
+                    "type": "http://0.0.0.0:8080/bad_request",
+                    "title": "Bad request",
+                    "status": 400,
+                    "invalid_params":
+                    [
+                        {"message": "Mandatory parameter 'startId' in query is absent"},
+                        {"message": "Mandatory parameter 'limit' in query is absent"}
+                    ]
+                }
+            )
+        ),
     )
 )]
-
 pub async fn get_menu_endpoint<T: GetMenu + Send + Debug>(
     shared_state: web::Data<AM<T>>,
+    req: HttpRequest,
 ) -> HttpResponse {
-    let meal_model_list: Vec<MealModel> = shared_state
-        .lock()
-        .await
-        .execute()
-        .await
-        .into_iter()
-        .map(MealModel::from)
-        .collect();
-
-    HttpResponse::Ok()
-        .content_type(ContentType::json())
-        .body(serde_json::to_string(&meal_model_list).unwrap())
+    let error_list = RCell::new_rc(vec![]);
+
+    match (
+        match validate_query_string::<i64>(req.clone(), "startId", error_list.clone()) {
+            Ok(id) => MealId::validated(id, error_list.clone()),
+            Err(_) => None,
+        },
+        validate_query_string::<usize>(req.clone(), "limit", error_list.clone()),
+        validate_excluded_allergens(&req, error_list.clone()),
+        validate_availability(&req, error_list.clone()),
+    ) {
+        (Some(start_id), Ok(limit), Some(excluded_allergens), Some(availability)) => {
+            match shared_state
+                .lock()
+                .await
+                .execute(&start_id, limit + 1, &excluded_allergens, &availability)
+                .await
+            {
+                Ok(page) => {
+                    let etag = ETag(page_etag(&page.meals));
+                    let last_modified =
+                        LastModified(HttpDate::from(SystemTime::from(page.last_modified)));
+                    if is_not_modified(&req, &etag) {
+                        return HttpResponse::NotModified()
+                            .insert_header(etag)
+                            .insert_header(last_modified)
+                            .finish();
+                    }
+
+                    let list: Vec<MealModel> =
+                        page.meals.into_iter().map(MealModel::from).collect();
+                    let model = if list.len() > limit {
+                        let next_id = list[limit].id;
+                        CursorPagedModel::new(list[..limit].to_vec(), Some(next_id))
+                    } else {
+                        CursorPagedModel::new(list, Option::<i64>::None)
+                    };
+                    HttpResponse::Ok()
+                        .content_type(ContentType::json())
+                        .insert_header(etag)
+                        .insert_header(last_modified)
+                        .body(serde_json::to_string(&model).unwrap())
+                }
+                Err(e) => e.to_rest_error(),
+            }
+        }
+        _ => to_invalid_param_bad_request(error_list),
+    }
+}
+
+/// Allergens are optional, no parameter means nothing is excluded
+fn validate_excluded_allergens(
+    req: &HttpRequest,
+    error_list: RCell<Vec<ValidationError>>,
+) -> Option<Allergens> {
+    let query_params = Query::<HashMap<String, String>>::from_query(req.query_string()).unwrap();
+    match query_params.get("excludeAllergens") {
+        Some(codes) => {
+            let codes: Vec<&str> = codes
+                .split(',')
+                .map(str::trim)
+                .filter(|it| !it.is_empty())
+                .collect();
+            Allergens::validated(codes.as_slice(), error_list)
+        }
+        None => Some(Allergens::default()),
+    }
+}
+
+/// No parameter means meals which can be ordered now
+fn validate_availability(
+    req: &HttpRequest,
+    error_list: RCell<Vec<ValidationError>>,
+) -> Option<MenuAvailability> {
+    let query_params = Query::<HashMap<String, String>>::from_query(req.query_string()).unwrap();
+    match query_params.get("availableAt").map(String::as_str) {
+        None => Some(MenuAvailability::Now),
+        Some("any") => Some(MenuAvailability::Any),
+        Some(value) => match OffsetDateTime::parse(value, &Rfc3339) {
+            Ok(at) => Some(MenuAvailability::At(at)),
+            Err(_) => {
+                error_list.borrow_mut().push(ValidationError::new(
+                    "availableAt must be an RFC 3339 date-time or 'any'",
+                ));
+                None
+            }
+        },
+    }
+}
+
+/// Built from ids, versions, availability and images of the loaded meals, the look-ahead meal
+/// included, so the tag also changes when the next page moves, a meal is sold out or gets
+/// a new image. SHA-256 keeps the tag the same across builds and instances.
+fn page_etag(meals: &[MealInfo]) -> EntityTag {
+    let mut hasher = Sha256::new();
+    for meal in meals {
+        hasher.update(meal.id.to_i64().to_be_bytes());
+        hasher.update(meal.version.to_i64().to_be_bytes());
+        hasher.update([u8::from(meal.available)]);
+        hasher.update((meal.image_urls.len() as u64).to_be_bytes());
+        for url in &meal.image_urls {
+            let url = url.to_string();
+            hasher.update((url.len() as u64).to_be_bytes());
+            hasher.update(url.as_bytes());
+        }
+    }
+    EntityTag::new_strong(hex::encode(hasher.finalize()))
+}
+
+fn is_not_modified(req: &HttpRequest, etag: &ETag) -> bool {
+    match IfNoneMatch::parse(req) {
+        Ok(IfNoneMatch::Any) => true,
+        Ok(IfNoneMatch::Items(tags)) => tags.iter().any(|tag| tag.weak_eq(etag)),
+        Err(_) => false,
+    }
+}
+
+impl ToRestError for GetMenuUseCaseError {
+    fn to_rest_error(self) -> HttpResponse {
+        match self {
+            GetMenuUseCaseError::LimitExceed(max_size) => {
+                let error_list = RCell::new_rc(vec![]);
+                error_list.borrow_mut().push(ValidationError::new(&format!(
+                    "Max limit is {}",
+                    max_size - 1
+                )));
+                to_invalid_param_bad_request(error_list)
+            }
+        }
+    }
 }
 
 pub fn get_menu_endpoint_config<T: GetMenu + Send + Debug + 'static>(cfg: &mut web::ServiceConfig) {
@@ -43,46 +213,317 @@ pub fn get_menu_endpoint_config<T: GetMenu + Send + Debug + 'static>(cfg: &mut w
 
 #[cfg(test)]
 mod tests {
-    use actix_web::body::MessageBody;
-    use common::types::base::AMTrait;
+    use actix_web::{
+        body::MessageBody,
+        http::{StatusCode, header},
+        test::TestRequest,
+    };
+    use common::types::base::{AMTrait, Version};
+    use domain::menu::value_objects::allergens::Allergen;
+    use dotenvy::dotenv;
+    use usecase::menu::dto::menu_page::MenuPage;
 
     use super::*;
     use crate::test_fixtures::{MockGetMenu, rnd_meal_info};
 
+    fn mock_get_menu(meals: Vec<MealInfo>) -> AM<MockGetMenu> {
+        AM::new_am(MockGetMenu {
+            response: Ok(MenuPage {
+                meals,
+                last_modified: SystemTime::UNIX_EPOCH.into(),
+            }),
+            ..Default::default()
+        })
+    }
+
     #[actix_web::test]
     async fn get_menu() {
         let meal_info = rnd_meal_info();
-        let mock_get_menu = AM::new_am(MockGetMenu::default());
-        mock_get_menu.lock().await.meal_info = meal_info.clone();
+        let mock_get_menu = mock_get_menu(vec![meal_info.clone()]);
         let mock_shared_state = web::Data::new(mock_get_menu.clone());
+        let req = TestRequest::default()
+            .uri(&format!("/?startId={}&limit=10", meal_info.id.to_i64()))
+            .to_http_request();
+
+        let resp = get_menu_endpoint(mock_shared_state, req).await;
 
-        let resp = get_menu_endpoint(mock_shared_state).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert!(resp.headers().contains_key(header::ETAG));
+        assert_eq!(
+            resp.headers().get(header::LAST_MODIFIED).unwrap(),
+            "Thu, 01 Jan 1970 00:00:00 GMT"
+        );
 
         let body = resp.into_body().try_into_bytes().unwrap();
         let body_text = std::str::from_utf8(&body).unwrap();
 
-        let list_of_meal_model_from_resp: Vec<MealModel> = serde_json::from_str(body_text).unwrap();
+        let response_dto: CursorPagedModel<MealModel, i64> =
+            serde_json::from_str(body_text).unwrap();
 
-        assert_eq!(list_of_meal_model_from_resp.len(), 1);
-        assert_eq!(
-            list_of_meal_model_from_resp.first().unwrap().id,
-            meal_info.id.to_i64()
+        assert_eq!(response_dto.next, None);
+        assert_eq!(response_dto.list.len(), 1);
+        let meal_model = response_dto.list.first().unwrap();
+        assert_eq!(meal_model.id, meal_info.id.to_i64());
+        assert_eq!(meal_model.name, meal_info.name.to_string());
+        assert_eq!(meal_model.description, meal_info.description.to_string());
+        assert_eq!(meal_model.price, meal_info.price.to_bigdecimal());
+        assert_eq!(meal_model.version, meal_info.version.to_i64());
+        assert!(meal_model.available);
+        mock_get_menu.lock().await.verify_invoked(
+            &meal_info.id,
+            11,
+            &Allergens::default(),
+            &MenuAvailability::Now,
         );
+    }
+
+    #[actix_web::test]
+    async fn get_menu_with_next_page() {
+        let first = rnd_meal_info();
+        let second = rnd_meal_info();
+        let mock_shared_state = web::Data::new(mock_get_menu(vec![first.clone(), second.clone()]));
+        let req = TestRequest::default()
+            .uri(&format!("/?startId={}&limit=1", first.id.to_i64()))
+            .to_http_request();
+
+        let resp = get_menu_endpoint(mock_shared_state, req).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body = resp.into_body().try_into_bytes().unwrap();
+        let response_dto: CursorPagedModel<MealModel, i64> =
+            serde_json::from_str(std::str::from_utf8(&body).unwrap()).unwrap();
+
+        assert_eq!(response_dto.list.len(), 1);
+        assert_eq!(response_dto.list[0].id, first.id.to_i64());
+        assert_eq!(response_dto.next, Some(second.id.to_i64()));
+    }
+
+    #[actix_web::test]
+    async fn not_modified_if_etag_matches() {
+        let meal_info = rnd_meal_info();
+        let mock_shared_state = web::Data::new(mock_get_menu(vec![meal_info.clone()]));
+        let uri = format!("/?startId={}&limit=10", meal_info.id.to_i64());
+
+        let resp = get_menu_endpoint(
+            mock_shared_state.clone(),
+            TestRequest::default().uri(&uri).to_http_request(),
+        )
+        .await;
+        let etag = resp.headers().get(header::ETAG).unwrap().clone();
+
+        let req = TestRequest::default()
+            .uri(&uri)
+            .insert_header((header::IF_NONE_MATCH, etag.clone()))
+            .to_http_request();
+        let resp = get_menu_endpoint(mock_shared_state, req).await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_MODIFIED);
+        assert_eq!(resp.headers().get(header::ETAG).unwrap(), &etag);
+        assert!(resp.into_body().try_into_bytes().unwrap().is_empty());
+    }
+
+    #[test]
+    fn etag_changes_with_meal_version() {
+        let meal_info = rnd_meal_info();
+        let mut changed = meal_info.clone();
+        changed.version = changed.version.next();
+
+        assert_ne!(
+            page_etag(std::slice::from_ref(&meal_info)),
+            page_etag(&[changed])
+        );
+    }
+
+    #[test]
+    fn etag_is_stable() {
+        let mut meal_info = rnd_meal_info();
+        meal_info.id = MealId::try_from(7).unwrap();
+        meal_info.version = Version::from(3);
+        meal_info.available = true;
+        meal_info.image_urls = vec!["/media/meals/7/0.png".parse().unwrap()];
+
         assert_eq!(
-            list_of_meal_model_from_resp.first().unwrap().name,
-            meal_info.name.to_string()
+            page_etag(&[meal_info]),
+            EntityTag::new_strong(
+                "0cc30b81c2bc575c958a54354c44a42fc4af39e62a9b192f8672c4ade950e139".to_string()
+            )
+        );
+    }
+
+    #[test]
+    fn etag_changes_with_meal_availability() {
+        let meal_info = rnd_meal_info();
+        let mut sold_out = meal_info.clone();
+        sold_out.available = false;
+
+        assert_ne!(
+            page_etag(std::slice::from_ref(&meal_info)),
+            page_etag(&[sold_out])
         );
+    }
+
+    #[actix_web::test]
+    async fn modified_if_etag_differs() {
+        let meal_info = rnd_meal_info();
+        let mock_shared_state = web::Data::new(mock_get_menu(vec![meal_info.clone()]));
+        let req = TestRequest::default()
+            .uri(&format!("/?startId={}&limit=10", meal_info.id.to_i64()))
+            .insert_header((header::IF_NONE_MATCH, "\"outdated\""))
+            .to_http_request();
+
+        let resp = get_menu_endpoint(mock_shared_state, req).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn limit_reached() {
+        let start_id = rnd_meal_info().id;
+        let mock_get_menu = AM::new_am(MockGetMenu {
+            response: Err(GetMenuUseCaseError::new_limit_exceed(11)),
+            ..Default::default()
+        });
+        let req = TestRequest::default()
+            .uri(&format!("/?startId={}&limit=20", start_id.to_i64()))
+            .to_http_request();
+
+        let resp = get_menu_endpoint(web::Data::new(mock_get_menu.clone()), req).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let body = resp.into_body().try_into_bytes().unwrap();
+        let response_dto: GenericErrorResponse =
+            serde_json::from_str(std::str::from_utf8(&body).unwrap()).unwrap();
         assert_eq!(
-            list_of_meal_model_from_resp.first().unwrap().description,
-            meal_info.description.to_string()
+            &response_dto.invalid_params.first().unwrap().message,
+            "Max limit is 10"
+        );
+        mock_get_menu.lock().await.verify_invoked(
+            &start_id,
+            21,
+            &Allergens::default(),
+            &MenuAvailability::Now,
+        );
+    }
+
+    #[actix_web::test]
+    async fn excluded_allergens_are_passed() {
+        let start_id = rnd_meal_info().id;
+        let mock_get_menu = mock_get_menu(vec![]);
+        let req = TestRequest::default()
+            .uri(&format!(
+                "/?startId={}&limit=10&excludeAllergens=milk,%20gluten",
+                start_id.to_i64()
+            ))
+            .to_http_request();
+
+        let resp = get_menu_endpoint(web::Data::new(mock_get_menu.clone()), req).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let excluded = Allergens::from_iter([Allergen::Milk, Allergen::Gluten]);
+        mock_get_menu
+            .lock()
+            .await
+            .verify_invoked(&start_id, 11, &excluded, &MenuAvailability::Now);
+    }
+
+    #[actix_web::test]
+    async fn availability_is_passed() {
+        let start_id = rnd_meal_info().id;
+        let mock_get_menu = mock_get_menu(vec![]);
+        let req = TestRequest::default()
+            .uri(&format!(
+                "/?startId={}&limit=10&availableAt=2024-06-03T08:30:00Z",
+                start_id.to_i64()
+            ))
+            .to_http_request();
+
+        let resp = get_menu_endpoint(web::Data::new(mock_get_menu.clone()), req).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let at = OffsetDateTime::parse("2024-06-03T08:30:00Z", &Rfc3339).unwrap();
+        mock_get_menu.lock().await.verify_invoked(
+            &start_id,
+            11,
+            &Allergens::default(),
+            &MenuAvailability::At(at),
         );
+    }
+
+    #[actix_web::test]
+    async fn all_meals_regardless_of_availability() {
+        let start_id = rnd_meal_info().id;
+        let mock_get_menu = mock_get_menu(vec![]);
+        let req = TestRequest::default()
+            .uri(&format!(
+                "/?startId={}&limit=10&availableAt=any",
+                start_id.to_i64()
+            ))
+            .to_http_request();
+
+        let resp = get_menu_endpoint(web::Data::new(mock_get_menu.clone()), req).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        mock_get_menu.lock().await.verify_invoked(
+            &start_id,
+            11,
+            &Allergens::default(),
+            &MenuAvailability::Any,
+        );
+    }
+
+    #[actix_web::test]
+    async fn invalid_availability() {
+        dotenv().ok();
+        let mock_get_menu = AM::new_am(MockGetMenu::default());
+        let req = TestRequest::default()
+            .uri("/?startId=1&limit=10&availableAt=tomorrow")
+            .to_http_request();
+
+        let resp = get_menu_endpoint(web::Data::new(mock_get_menu.clone()), req).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let body = resp.into_body().try_into_bytes().unwrap();
+        let response_dto: GenericErrorResponse =
+            serde_json::from_str(std::str::from_utf8(&body).unwrap()).unwrap();
         assert_eq!(
-            list_of_meal_model_from_resp.first().unwrap().price,
-            meal_info.price.to_bigdecimal()
+            &response_dto.invalid_params.first().unwrap().message,
+            "availableAt must be an RFC 3339 date-time or 'any'"
         );
+        mock_get_menu.lock().await.verify_empty();
+    }
+
+    #[actix_web::test]
+    async fn unknown_excluded_allergen() {
+        let mock_get_menu = AM::new_am(MockGetMenu::default());
+        let req = TestRequest::default()
+            .uri("/?startId=1&limit=10&excludeAllergens=milk,honey")
+            .to_http_request();
+
+        let resp = get_menu_endpoint(web::Data::new(mock_get_menu.clone()), req).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let body = resp.into_body().try_into_bytes().unwrap();
+        let response_dto: GenericErrorResponse =
+            serde_json::from_str(std::str::from_utf8(&body).unwrap()).unwrap();
         assert_eq!(
-            list_of_meal_model_from_resp.first().unwrap().version,
-            meal_info.version.to_i64()
+            &response_dto.invalid_params.first().unwrap().message,
+            "Unknown allergen 'honey'"
         );
+        mock_get_menu.lock().await.verify_empty();
+    }
+
+    #[actix_web::test]
+    async fn paging_parameters_are_mandatory() {
+        let mock_get_menu = AM::new_am(MockGetMenu::default());
+        let req = TestRequest::default().uri("/").to_http_request();
+
+        let resp = get_menu_endpoint(web::Data::new(mock_get_menu.clone()), req).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let body = resp.into_body().try_into_bytes().unwrap();
+        let response_dto: GenericErrorResponse =
+            serde_json::from_str(std::str::from_utf8(&body).unwrap()).unwrap();
+        assert_eq!(response_dto.invalid_params.len(), 2);
+        mock_get_menu.lock().await.verify_empty();
     }
 }
@@ -0,0 +1,323 @@
+use std::{collections::HashMap, fmt::Debug};
+
+use actix_web::{
+    HttpRequest, HttpResponse,
+    http::{
+        StatusCode,
+        header::{self, ContentType},
+    },
+    middleware::from_fn,
+    web::{self, Bytes, Query},
+};
+use common::{
+    common_rest::{GenericErrorResponse, ValidationError, to_invalid_param_bad_request},
+    types::base::{AM, RCell, RcRefCellTrait},
+};
+use usecase::menu::{ImportMenu, ImportMenuUseCaseError};
+
+use crate::{
+    auth::jwt_middleware::require_admin,
+    endpoint_url::API_V1_MENU_IMPORT,
+    menu::meal_row_model::{MealRowModel, MenuFileFormat, MenuImportReportModel},
+    to_error::ToRestError,
+};
+
+/// Maximal size of an imported file in bytes
+pub const MAX_MENU_IMPORT_SIZE: usize = 1024 * 1024;
+
+/// Import meals to the menu
+///
+/// Requires the `admin` role. The body is a JSON array of meals or a CSV file with a header,
+/// CSV allergens are separated by `;`. Rows are validated like a single added meal, when
+/// any row is rejected nothing is added. With `dryRun=true` the rows are only validated
+#[utoipa::path(
+    post,
+    path = API_V1_MENU_IMPORT,
+    tag = "Meal",
+    security(("bearerAuth" = ["admin"])),
+    params(
+        ("dryRun" = Option<bool>, Query, description = "Only validate the rows, `false` by default")
+    ),
+    request_body(
+        content(
+            (Vec<MealRowModel> = "application/json"),
+            (String = "text/csv", example = "name,description,price,allergens,calories,proteins,fats,carbohydrates\nSoup,Hot,9.99,milk;celery,120,1.5,2.0,3.2")
+        )
+    ),
+    responses(
+        (
+            status = UNAUTHORIZED,
+            description = "Missing or invalid bearer token",
+            body = GenericErrorResponse,
+            example = json!({"type":"http://0.0.0.0:8080/unauthorized","title":"Unauthorized","status":401})
+        ),
+        (
+            status = FORBIDDEN,
+            description = "Caller is not allowed to perform the operation",
+            body = GenericErrorResponse,
+            example = json!({"type":"http://0.0.0.0:8080/forbidden","title":"Forbidden","status":403})
+        ),
+        (
+            status = OK,
+            description = "All rows are valid and added unless it is a dry run",
+            body = MenuImportReportModel
+        ),
+        (
+            status = BAD_REQUEST,
+            description = "Bad request",
+            body = GenericErrorResponse,
+            example = json!(
+                {
+                    "type": "http://0.0.0.0:8080/bad_request",
+                    "title": "Bad request",
+                    "status": 400,
+                    "invalid_params":
+                    [
+                        {"message": "Row 3: price is not a number"}
+                    ]
+                }
+            )
+        ),
+        (
+            status = UNPROCESSABLE_ENTITY,
+            description = "Some rows are rejected, nothing is added",
+            body = MenuImportReportModel
+        ),
+    ))]
+pub async fn import_menu_endpoint<T>(
+    shared_state: web::Data<AM<T>>,
+    req: HttpRequest,
+    body: Bytes,
+) -> HttpResponse
+where
+    T: ImportMenu + Send + Debug,
+{
+    let error_list = RCell::new_rc(vec![]);
+
+    let dry_run = validate_dry_run(&req, error_list.clone());
+    let rows = validate_format(&req, error_list.clone())
+        .and_then(|format| format.read(&body, error_list.clone()));
+
+    match (dry_run, rows) {
+        (Some(dry_run), Some(rows)) => {
+            match shared_state.lock().await.execute(&rows, dry_run).await {
+                Ok(report) => {
+                    let status = if report.rejected.is_empty() {
+                        StatusCode::OK
+                    } else {
+                        StatusCode::UNPROCESSABLE_ENTITY
+                    };
+                    HttpResponse::build(status)
+                        .content_type(ContentType::json())
+                        .body(serde_json::to_string(&MenuImportReportModel::from(report)).unwrap())
+                }
+                Err(e) => e.to_rest_error(),
+            }
+        }
+        _ => to_invalid_param_bad_request(error_list),
+    }
+}
+
+fn validate_dry_run(req: &HttpRequest, error_list: RCell<Vec<ValidationError>>) -> Option<bool> {
+    let query_params = Query::<HashMap<String, String>>::from_query(req.query_string()).unwrap();
+    match query_params.get("dryRun").map(String::as_str) {
+        None | Some("false") => Some(false),
+        Some("true") => Some(true),
+        Some(_) => {
+            error_list
+                .borrow_mut()
+                .push(ValidationError::new("dryRun must be 'true' or 'false'"));
+            None
+        }
+    }
+}
+
+fn validate_format(
+    req: &HttpRequest,
+    error_list: RCell<Vec<ValidationError>>,
+) -> Option<MenuFileFormat> {
+    let format = req
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.split(';').next())
+        .and_then(|essence| MenuFileFormat::from_content_type(essence.trim()));
+    if format.is_none() {
+        error_list.borrow_mut().push(ValidationError::new(
+            "Content type must be 'text/csv' or 'application/json'",
+        ));
+    }
+    format
+}
+
+impl ToRestError for ImportMenuUseCaseError {
+    fn to_rest_error(self) -> HttpResponse {
+        let error_list = RCell::new_rc(vec![]);
+        error_list
+            .borrow_mut()
+            .push(ValidationError::new(&self.to_string()));
+        to_invalid_param_bad_request(error_list)
+    }
+}
+
+pub fn import_menu_endpoint_config<T>(cfg: &mut web::ServiceConfig)
+where
+    T: ImportMenu + Send + Debug + 'static,
+{
+    cfg.service(
+        web::resource(API_V1_MENU_IMPORT)
+            .app_data(web::PayloadConfig::new(MAX_MENU_IMPORT_SIZE))
+            .route(
+                web::post()
+                    .to(import_menu_endpoint::<T>)
+                    .wrap(from_fn(require_admin)),
+            ),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{body::MessageBody, test::TestRequest, web::Data};
+    use common::{common_rest::bad_request_type_url, types::base::AMTrait};
+    use domain::test_fixtures::*;
+    use dotenvy::dotenv;
+    use usecase::menu::{
+        MealRowError,
+        dto::{
+            meal_row::MealRow,
+            menu_import_report::{MenuImportReport, RejectedMealRow},
+        },
+    };
+
+    use super::*;
+    use crate::test_fixtures::MockImportMenu;
+
+    fn request(content_type: &str, query: &str) -> HttpRequest {
+        TestRequest::default()
+            .uri(&format!("/{query}"))
+            .insert_header((header::CONTENT_TYPE, content_type))
+            .to_http_request()
+    }
+
+    fn body_of<B: serde::de::DeserializeOwned>(resp: HttpResponse) -> B {
+        let body = resp.into_body().try_into_bytes().unwrap();
+        serde_json::from_str(std::str::from_utf8(&body).unwrap()).unwrap()
+    }
+
+    #[actix_web::test]
+    async fn json_is_imported() {
+        let rows = vec![MealRow::from(&rnd_meal())];
+        let meal_id = rnd_meal_id();
+        let mock_import_menu = AM::new_am(MockImportMenu {
+            response: Ok(MenuImportReport {
+                dry_run: false,
+                added: vec![meal_id],
+                rejected: vec![],
+            }),
+            ..Default::default()
+        });
+        let body = MenuFileFormat::Json.write(rows.clone());
+
+        let resp = import_menu_endpoint(
+            Data::new(mock_import_menu.clone()),
+            request("application/json", ""),
+            Bytes::from(body),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let report: MenuImportReportModel = body_of(resp);
+        assert_eq!(report.added, vec![meal_id.to_i64()]);
+        mock_import_menu.lock().await.verify_invoked(&rows, false);
+    }
+
+    #[actix_web::test]
+    async fn csv_dry_run() {
+        let rows = vec![MealRow::from(&rnd_meal())];
+        let mock_import_menu = AM::new_am(MockImportMenu::default());
+        let body = MenuFileFormat::Csv.write(rows.clone());
+
+        let resp = import_menu_endpoint(
+            Data::new(mock_import_menu.clone()),
+            request("text/csv; charset=utf-8", "?dryRun=true"),
+            Bytes::from(body),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        mock_import_menu.lock().await.verify_invoked(&rows, true);
+    }
+
+    #[actix_web::test]
+    async fn rejected_rows_are_reported() {
+        let mock_import_menu = AM::new_am(MockImportMenu {
+            response: Ok(MenuImportReport {
+                dry_run: false,
+                added: vec![],
+                rejected: vec![RejectedMealRow {
+                    row: 1,
+                    errors: vec![MealRowError::EmptyName, MealRowError::NegativePrice],
+                }],
+            }),
+            ..Default::default()
+        });
+
+        let resp = import_menu_endpoint(
+            Data::new(mock_import_menu),
+            request("application/json", ""),
+            Bytes::from(MenuFileFormat::Json.write(vec![MealRow::default()])),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::UNPROCESSABLE_ENTITY);
+        let report: MenuImportReportModel = body_of(resp);
+        assert_eq!(report.rejected.len(), 1);
+        assert_eq!(report.rejected[0].row, 1);
+        assert_eq!(
+            report.rejected[0].errors,
+            vec!["Meal name is empty", "Price must be > 0"]
+        );
+    }
+
+    #[actix_web::test]
+    async fn unsupported_content_type() {
+        dotenv().ok();
+        let mock_import_menu = AM::new_am(MockImportMenu::default());
+
+        let resp = import_menu_endpoint(
+            Data::new(mock_import_menu.clone()),
+            request("text/plain", "?dryRun=maybe"),
+            Bytes::from_static(b"meals"),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let response_dto: GenericErrorResponse = body_of(resp);
+        assert_eq!(&response_dto.response_type, &bad_request_type_url());
+        assert_eq!(response_dto.invalid_params.len(), 2);
+        mock_import_menu.lock().await.verify_empty();
+    }
+
+    #[actix_web::test]
+    async fn too_many_rows() {
+        dotenv().ok();
+        let mock_import_menu = AM::new_am(MockImportMenu {
+            response: Err(ImportMenuUseCaseError::TooManyRows(1000)),
+            ..Default::default()
+        });
+
+        let resp = import_menu_endpoint(
+            Data::new(mock_import_menu),
+            request("application/json", ""),
+            Bytes::from_static(b"[]"),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let response_dto: GenericErrorResponse = body_of(resp);
+        assert_eq!(
+            &response_dto.invalid_params.first().unwrap().message,
+            "Import must not have more than 1000 rows"
+        );
+    }
+}
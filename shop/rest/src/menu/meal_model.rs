@@ -1,4 +1,8 @@
-use bigdecimal::BigDecimal;
+use bigdecimal::{BigDecimal, ToPrimitive};
+use domain::menu::value_objects::{
+    availability_schedule::{AvailabilitySchedule, AvailabilityWindow},
+    nutrition_facts::NutritionFacts,
+};
 use serde::Deserialize;
 use serde_derive::Serialize;
 use usecase::menu::dto::meal_info::MealInfo;
@@ -21,6 +25,98 @@ pub struct MealModel {
     /// Version of the meal
     #[schema(example = 1)]
     pub version: i64,
+    /// Whether the meal can be ordered, `false` when it is sold out
+    #[schema(example = true)]
+    pub available: bool,
+    /// Urls of the meal images in the order they were uploaded
+    #[schema(example = json!(["http://0.0.0.0:8080/media/1/1760882400000000000.jpg"]))]
+    pub image_urls: Vec<String>,
+    /// Allergens contained in the meal
+    #[schema(example = json!(["gluten", "milk"]))]
+    pub allergens: Vec<String>,
+    /// Nutrition facts of one portion
+    pub nutrition_facts: NutritionFactsModel,
+    /// When the meal can be ordered, `null` means at any time
+    pub availability_schedule: Option<AvailabilityScheduleModel>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct NutritionFactsModel {
+    /// Kilocalories
+    #[schema(example = 540)]
+    pub calories: i32,
+    /// Grams of proteins
+    #[schema(example = 24.5)]
+    pub proteins: f64,
+    /// Grams of fats
+    #[schema(example = 21.0)]
+    pub fats: f64,
+    /// Grams of carbohydrates
+    #[schema(example = 62.3)]
+    pub carbohydrates: f64,
+}
+
+impl From<&NutritionFacts> for NutritionFactsModel {
+    fn from(nutrition_facts: &NutritionFacts) -> Self {
+        Self {
+            calories: *nutrition_facts.calories(),
+            proteins: nutrition_facts.proteins().to_f64().unwrap_or_default(),
+            fats: nutrition_facts.fats().to_f64().unwrap_or_default(),
+            carbohydrates: nutrition_facts.carbohydrates().to_f64().unwrap_or_default(),
+        }
+    }
+}
+
+/// Weekly windows in the local time of the time zone, the meal can be ordered when any of
+/// them is open
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct AvailabilityScheduleModel {
+    /// IANA name of the time zone
+    #[schema(example = "Europe/Berlin")]
+    pub time_zone: String,
+    pub windows: Vec<AvailabilityWindowModel>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct AvailabilityWindowModel {
+    /// Days the window opens on
+    #[schema(example = json!(["monday", "tuesday", "wednesday", "thursday", "friday"]))]
+    pub days: Vec<String>,
+    /// Opening time, `HH:MM`
+    #[schema(example = "07:00")]
+    pub from: String,
+    /// Closing time, `HH:MM`. A window closing before it opens lasts until the next day
+    #[schema(example = "11:00")]
+    pub to: String,
+}
+
+impl From<&AvailabilitySchedule> for AvailabilityScheduleModel {
+    fn from(schedule: &AvailabilitySchedule) -> Self {
+        Self {
+            time_zone: schedule.time_zone().to_string(),
+            windows: schedule
+                .windows()
+                .iter()
+                .map(AvailabilityWindowModel::from)
+                .collect(),
+        }
+    }
+}
+
+impl From<&AvailabilityWindow> for AvailabilityWindowModel {
+    fn from(window: &AvailabilityWindow) -> Self {
+        let hours_and_minutes =
+            |time: &time::Time| format!("{:02}:{:02}", time.hour(), time.minute());
+        Self {
+            days: window
+                .days()
+                .iter()
+                .map(|day| day.to_string().to_lowercase())
+                .collect(),
+            from: hours_and_minutes(window.from()),
+            to: hours_and_minutes(window.to()),
+        }
+    }
 }
 
 impl MealModel {
@@ -31,6 +127,18 @@ impl MealModel {
             description: meal_info.description.to_string(),
             price: meal_info.price.to_bigdecimal(),
             version: meal_info.version.to_i64(),
+            available: meal_info.available,
+            image_urls: meal_info
+                .image_urls
+                .iter()
+                .map(ToString::to_string)
+                .collect(),
+            allergens: meal_info.allergens.to_codes(),
+            nutrition_facts: NutritionFactsModel::from(&meal_info.nutrition_facts),
+            availability_schedule: meal_info
+                .availability_schedule
+                .as_ref()
+                .map(AvailabilityScheduleModel::from),
         }
     }
 }
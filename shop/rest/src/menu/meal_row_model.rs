@@ -0,0 +1,385 @@
+use std::str::FromStr;
+
+use bigdecimal::BigDecimal;
+use common::{common_rest::ValidationError, types::base::RCell};
+use serde::{Deserialize, Serialize};
+use usecase::menu::dto::{
+    meal_row::MealRow,
+    menu_import_report::{MenuImportReport, RejectedMealRow},
+};
+use utoipa::ToSchema;
+
+/// A meal of the bulk import and export
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct MealRowModel {
+    /// Name of the meal
+    #[schema(example = "Шаурма средняя")]
+    pub name: String,
+    /// Description of the meal
+    #[schema(example = "Средний размер, средняя цена")]
+    pub description: String,
+    /// Price of the meal
+    #[schema(value_type = String, example = "200.00")]
+    pub price: BigDecimal,
+    /// Allergens contained in the meal
+    #[serde(default)]
+    #[schema(example = json!(["gluten", "milk"]))]
+    pub allergens: Vec<String>,
+    /// Kilocalories of one portion
+    #[schema(example = 540)]
+    pub calories: i32,
+    /// Grams of proteins in one portion
+    #[schema(value_type = String, example = "24.5")]
+    pub proteins: BigDecimal,
+    /// Grams of fats in one portion
+    #[schema(value_type = String, example = "21.0")]
+    pub fats: BigDecimal,
+    /// Grams of carbohydrates in one portion
+    #[schema(value_type = String, example = "62.3")]
+    pub carbohydrates: BigDecimal,
+}
+
+impl From<MealRow> for MealRowModel {
+    fn from(row: MealRow) -> Self {
+        Self {
+            name: row.name,
+            description: row.description,
+            price: row.price,
+            allergens: row.allergens,
+            calories: row.calories,
+            proteins: row.proteins,
+            fats: row.fats,
+            carbohydrates: row.carbohydrates,
+        }
+    }
+}
+
+impl From<MealRowModel> for MealRow {
+    fn from(model: MealRowModel) -> Self {
+        Self {
+            name: model.name,
+            description: model.description,
+            price: model.price,
+            allergens: model.allergens,
+            calories: model.calories,
+            proteins: model.proteins,
+            fats: model.fats,
+            carbohydrates: model.carbohydrates,
+        }
+    }
+}
+
+/// Outcome of a menu import
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct MenuImportReportModel {
+    /// The rows were only validated
+    #[schema(example = false)]
+    pub dry_run: bool,
+    /// Ids of the added meals in the order of the rows
+    #[schema(example = json!([]))]
+    pub added: Vec<i64>,
+    /// Rows which can't be added, nothing is added when there is any
+    pub rejected: Vec<RejectedMealRowModel>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct RejectedMealRowModel {
+    /// Position of the row, starting at 1 and not counting the CSV header
+    #[schema(example = 2)]
+    pub row: usize,
+    #[schema(example = json!(["Meal name is empty", "Price scale must not be > 2"]))]
+    pub errors: Vec<String>,
+}
+
+impl From<MenuImportReport> for MenuImportReportModel {
+    fn from(report: MenuImportReport) -> Self {
+        Self {
+            dry_run: report.dry_run,
+            added: report.added.iter().map(|id| id.to_i64()).collect(),
+            rejected: report
+                .rejected
+                .into_iter()
+                .map(RejectedMealRowModel::from)
+                .collect(),
+        }
+    }
+}
+
+impl From<RejectedMealRow> for RejectedMealRowModel {
+    fn from(rejected: RejectedMealRow) -> Self {
+        Self {
+            row: rejected.row,
+            errors: rejected.errors.iter().map(ToString::to_string).collect(),
+        }
+    }
+}
+
+/// Allergens share one CSV column
+const CSV_ALLERGEN_SEPARATOR: char = ';';
+
+const CSV_HEADER: [&str; 8] = [
+    "name",
+    "description",
+    "price",
+    "allergens",
+    "calories",
+    "proteins",
+    "fats",
+    "carbohydrates",
+];
+
+/// Numbers are kept as text, otherwise the CSV reader guesses them to be floats
+/// and a price like 0.1 loses its scale
+#[derive(Serialize, Deserialize)]
+struct MealCsvRecord {
+    name: String,
+    description: String,
+    price: String,
+    allergens: String,
+    calories: String,
+    proteins: String,
+    fats: String,
+    carbohydrates: String,
+}
+
+/// File format of the menu import and export
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MenuFileFormat {
+    Csv,
+    Json,
+}
+
+impl MenuFileFormat {
+    pub fn from_content_type(content_type: &str) -> Option<Self> {
+        match content_type {
+            "text/csv" => Some(Self::Csv),
+            "application/json" => Some(Self::Json),
+            _ => None,
+        }
+    }
+
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name {
+            "csv" => Some(Self::Csv),
+            "json" => Some(Self::Json),
+            _ => None,
+        }
+    }
+
+    pub fn content_type(&self) -> &'static str {
+        match self {
+            Self::Csv => "text/csv",
+            Self::Json => "application/json",
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Json => "json",
+        }
+    }
+
+    /// Rows of the file, every malformed row is reported to `error_list`
+    pub fn read(
+        &self,
+        content: &[u8],
+        error_list: RCell<Vec<ValidationError>>,
+    ) -> Option<Vec<MealRow>> {
+        match self {
+            Self::Csv => read_csv(content, error_list),
+            Self::Json => match serde_json::from_slice::<Vec<MealRowModel>>(content) {
+                Ok(models) => Some(models.into_iter().map(MealRow::from).collect()),
+                Err(e) => {
+                    error_list
+                        .borrow_mut()
+                        .push(ValidationError::new(&format!("Malformed JSON: {e}")));
+                    None
+                }
+            },
+        }
+    }
+
+    pub fn write(&self, rows: Vec<MealRow>) -> String {
+        match self {
+            Self::Csv => write_csv(rows),
+            Self::Json => {
+                let models: Vec<MealRowModel> = rows.into_iter().map(MealRowModel::from).collect();
+                serde_json::to_string(&models).unwrap()
+            }
+        }
+    }
+}
+
+fn read_csv(content: &[u8], error_list: RCell<Vec<ValidationError>>) -> Option<Vec<MealRow>> {
+    let errors_before = error_list.borrow().len();
+    let mut rows = vec![];
+    for (index, record) in csv::Reader::from_reader(content)
+        .deserialize::<MealCsvRecord>()
+        .enumerate()
+    {
+        let row = index + 1;
+        let record = match record {
+            Ok(record) => record,
+            Err(e) => {
+                error_list
+                    .borrow_mut()
+                    .push(ValidationError::new(&format!("Row {row}: {e}")));
+                continue;
+            }
+        };
+        let number = |value: &str, column: &str| {
+            let parsed = BigDecimal::from_str(value.trim()).ok();
+            if parsed.is_none() {
+                error_list.borrow_mut().push(ValidationError::new(&format!(
+                    "Row {row}: {column} is not a number"
+                )));
+            }
+            parsed
+        };
+        let price = number(&record.price, "price");
+        let proteins = number(&record.proteins, "proteins");
+        let fats = number(&record.fats, "fats");
+        let carbohydrates = number(&record.carbohydrates, "carbohydrates");
+        let calories = record.calories.trim().parse::<i32>().ok();
+        if calories.is_none() {
+            error_list.borrow_mut().push(ValidationError::new(&format!(
+                "Row {row}: calories is not an integer"
+            )));
+        }
+
+        if let (Some(price), Some(calories), Some(proteins), Some(fats), Some(carbohydrates)) =
+            (price, calories, proteins, fats, carbohydrates)
+        {
+            rows.push(MealRow {
+                name: record.name,
+                description: record.description,
+                price,
+                allergens: record
+                    .allergens
+                    .split(CSV_ALLERGEN_SEPARATOR)
+                    .map(str::trim)
+                    .filter(|it| !it.is_empty())
+                    .map(ToString::to_string)
+                    .collect(),
+                calories,
+                proteins,
+                fats,
+                carbohydrates,
+            });
+        }
+    }
+    (error_list.borrow().len() == errors_before).then_some(rows)
+}
+
+fn write_csv(rows: Vec<MealRow>) -> String {
+    // The header is written explicitly, so an empty menu still gets one
+    let mut writer = csv::WriterBuilder::new()
+        .has_headers(false)
+        .from_writer(vec![]);
+    writer.write_record(CSV_HEADER).unwrap();
+    for row in rows {
+        writer
+            .serialize(MealCsvRecord {
+                name: row.name,
+                description: row.description,
+                price: row.price.to_string(),
+                allergens: row.allergens.join(&CSV_ALLERGEN_SEPARATOR.to_string()),
+                calories: row.calories.to_string(),
+                proteins: row.proteins.to_string(),
+                fats: row.fats.to_string(),
+                carbohydrates: row.carbohydrates.to_string(),
+            })
+            .unwrap();
+    }
+    String::from_utf8(writer.into_inner().unwrap()).unwrap()
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::test_fixtures::rnd_meal;
+
+    use super::*;
+    use common::types::base::RcRefCellTrait;
+
+    #[test]
+    fn csv_is_read_back() {
+        let rows = vec![MealRow::from(&rnd_meal()), MealRow::from(&rnd_meal())];
+        let error_list = RCell::new_rc(vec![]);
+
+        let csv = MenuFileFormat::Csv.write(rows.clone());
+        let read = MenuFileFormat::Csv.read(csv.as_bytes(), error_list.clone());
+
+        assert_eq!(read, Some(rows));
+        assert!(error_list.borrow().is_empty());
+    }
+
+    #[test]
+    fn json_is_read_back() {
+        let rows = vec![MealRow::from(&rnd_meal())];
+        let error_list = RCell::new_rc(vec![]);
+
+        let json = MenuFileFormat::Json.write(rows.clone());
+        let read = MenuFileFormat::Json.read(json.as_bytes(), error_list.clone());
+
+        assert_eq!(read, Some(rows));
+    }
+
+    #[test]
+    fn empty_csv_has_header() {
+        assert_eq!(
+            MenuFileFormat::Csv.write(vec![]),
+            "name,description,price,allergens,calories,proteins,fats,carbohydrates\n"
+        );
+    }
+
+    #[test]
+    fn csv_keeps_scale_of_numbers() {
+        let csv = "name,description,price,allergens,calories,proteins,fats,carbohydrates\n\
+                   Soup,Hot,0.125,milk; gluten,120,1.5,2,3.25\n";
+        let error_list = RCell::new_rc(vec![]);
+
+        let rows = MenuFileFormat::Csv
+            .read(csv.as_bytes(), error_list)
+            .unwrap();
+
+        let row = rows.first().unwrap();
+        assert_eq!(row.price.to_string(), "0.125");
+        assert_eq!(row.carbohydrates.to_string(), "3.25");
+        assert_eq!(row.allergens, vec!["milk", "gluten"]);
+    }
+
+    #[test]
+    fn malformed_csv_rows_are_reported() {
+        let csv = "name,description,price,allergens,calories,proteins,fats,carbohydrates\n\
+                   Soup,Hot,cheap,,120,1.5,2,3\n\
+                   Salad,Fresh,10,,many,1,1,1\n";
+        let error_list = RCell::new_rc(vec![]);
+
+        let rows = MenuFileFormat::Csv.read(csv.as_bytes(), error_list.clone());
+
+        assert!(rows.is_none());
+        let messages: Vec<String> = error_list
+            .borrow()
+            .iter()
+            .map(|e| e.message.clone())
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                "Row 1: price is not a number",
+                "Row 2: calories is not an integer"
+            ]
+        );
+    }
+
+    #[test]
+    fn malformed_json_is_reported() {
+        let error_list = RCell::new_rc(vec![]);
+
+        let rows = MenuFileFormat::Json.read(b"{", error_list.clone());
+
+        assert!(rows.is_none());
+        assert_eq!(error_list.borrow().len(), 1);
+    }
+}
@@ -1,7 +1,13 @@
 pub mod add_meal_to_menu_endpoint;
+pub mod change_meal_availability_endpoint;
+pub mod export_menu_endpoint;
 pub mod get_health_status;
 pub mod get_meal_by_id_endpoint;
 pub mod get_menu_endpoint;
+pub mod import_menu_endpoint;
 pub mod meal_model;
+pub mod meal_row_model;
 pub mod remove_meal_from_menu_endpoint;
+pub mod search_menu_endpoint;
+pub mod upload_meal_image_endpoint;
 pub mod validation;
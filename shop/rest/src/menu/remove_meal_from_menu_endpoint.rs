@@ -1,6 +1,6 @@
 use std::fmt::Debug;
 
-use actix_web::{HttpRequest, HttpResponse, http::StatusCode, web};
+use actix_web::{HttpRequest, HttpResponse, http::StatusCode, middleware::from_fn, web};
 use common::{
     common_rest::{
         GenericErrorResponse, get_json_from_http_response, resource_not_found,
@@ -11,17 +11,35 @@ use common::{
 use domain::menu::value_objects::meal_id::MealId;
 use usecase::menu::{RemoveMealFromMenu, RemoveMealFromMenuUseCaseError};
 
-use crate::{endpoint_url::API_V1_MENU_DELETE_BY_ID, to_error::ToRestError, validated::Validated};
+use crate::{
+    auth::jwt_middleware::require_admin, endpoint_url::API_V1_MENU_DELETE_BY_ID,
+    to_error::ToRestError, validated::Validated,
+};
 
 /// Remove the meal from the menu
+///
+/// Requires the `admin` role
 #[utoipa::path(
     delete,
     path = API_V1_MENU_DELETE_BY_ID,
     tag = "Meal",
+    security(("bearerAuth" = ["admin"])),
     params(
         ("id" = i64, Path,  description = "Meal id")
     ),
     responses(
+        (
+            status = UNAUTHORIZED,
+            description = "Missing or invalid bearer token",
+            body = GenericErrorResponse,
+            example = json!({"type":"http://0.0.0.0:8080/unauthorized","title":"Unauthorized","status":401})
+        ),
+        (
+            status = FORBIDDEN,
+            description = "Caller is not allowed to perform the operation",
+            body = GenericErrorResponse,
+            example = json!({"type":"http://0.0.0.0:8080/forbidden","title":"Forbidden","status":403})
+        ),
         (
             status = NO_CONTENT,
             description = "Meal successfully removed" 
@@ -86,7 +104,9 @@ where
 {
     cfg.route(
         API_V1_MENU_DELETE_BY_ID,
-        web::delete().to(remove_meal_from_menu_endpoint::<T>),
+        web::delete()
+            .to(remove_meal_from_menu_endpoint::<T>)
+            .wrap(from_fn(require_admin)),
     );
 }
 
@@ -0,0 +1,165 @@
+use std::fmt::Debug;
+
+use actix_web::{HttpRequest, HttpResponse, http::header::ContentType, web};
+use common::{
+    common_rest::{GenericErrorResponse, ValidationError, to_invalid_param_bad_request},
+    types::base::{AM, RCell, RcRefCellTrait},
+};
+use usecase::menu::{SearchMenu, SearchMenuUseCaseError};
+
+use crate::{
+    endpoint_url::API_V1_MENU_SEARCH, menu::meal_model::MealModel,
+    order::validated::validate_query_string, to_error::ToRestError,
+};
+
+/// Search the menu by words of meal names and descriptions
+///
+/// Every word matches the beginning of a word, meals matching by name come first
+#[utoipa::path(
+    get,
+    path = API_V1_MENU_SEARCH,
+    tag = "Meal",
+    params(
+        ("q" = String, Query, description = "Search text", example = "chicken wi")
+    ),
+    responses(
+        (
+            status = OK,
+            body = Vec<MealModel>,
+            description = "Found meals, most relevant first"
+        ),
+        (
+            status = BAD_REQUEST,
+            description = "Bad request",
+            body = GenericErrorResponse,
+            example = json!(
+                {
+                    "type": "http://0.0.0.0:8080/bad_request",
+                    "title": "Bad request",
+                    "status": 400,
+                    "invalid_params":
+                    [
+                        {"message": "Search text has no words"}
+                    ]
+                }
+            )
+        ),
+    )
+)]
+pub async fn search_menu_endpoint<T: SearchMenu + Send + Debug>(
+    shared_state: web::Data<AM<T>>,
+    req: HttpRequest,
+) -> HttpResponse {
+    let error_list = RCell::new_rc(vec![]);
+
+    match validate_query_string::<String>(req, "q", error_list.clone()) {
+        Ok(text) => match shared_state.lock().await.execute(&text).await {
+            Ok(meals) => {
+                let meal_model_list: Vec<MealModel> =
+                    meals.into_iter().map(MealModel::from).collect();
+                HttpResponse::Ok()
+                    .content_type(ContentType::json())
+                    .body(serde_json::to_string(&meal_model_list).unwrap())
+            }
+            Err(e) => e.to_rest_error(),
+        },
+        Err(_) => to_invalid_param_bad_request(error_list),
+    }
+}
+
+impl ToRestError for SearchMenuUseCaseError {
+    fn to_rest_error(self) -> HttpResponse {
+        let error_list = RCell::new_rc(vec![]);
+        error_list
+            .borrow_mut()
+            .push(ValidationError::new(&self.to_string()));
+        to_invalid_param_bad_request(error_list)
+    }
+}
+
+pub fn search_menu_endpoint_config<T: SearchMenu + Send + Debug + 'static>(
+    cfg: &mut web::ServiceConfig,
+) {
+    cfg.route(API_V1_MENU_SEARCH, web::get().to(search_menu_endpoint::<T>));
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{body::MessageBody, http::StatusCode, test::TestRequest, web::Data};
+    use common::{
+        common_rest::{GenericErrorResponse, bad_request_type_url},
+        types::base::AMTrait,
+    };
+    use dotenvy::dotenv;
+
+    use super::*;
+    use crate::test_fixtures::{MockSearchMenu, rnd_meal_info};
+
+    #[actix_web::test]
+    async fn meals_found() {
+        let meal_info = rnd_meal_info();
+        let mock_search_menu = AM::new_am(MockSearchMenu {
+            response: Ok(vec![meal_info.clone()]),
+            ..Default::default()
+        });
+        let mock_shared_state = Data::new(mock_search_menu.clone());
+        let req = TestRequest::default()
+            .uri("/?q=chicken%20wi")
+            .to_http_request();
+
+        let resp = search_menu_endpoint(mock_shared_state, req).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body = resp.into_body().try_into_bytes().unwrap();
+        let list: Vec<MealModel> =
+            serde_json::from_str(std::str::from_utf8(&body).unwrap()).unwrap();
+        assert_eq!(list.len(), 1);
+        assert_eq!(list[0].id, meal_info.id.to_i64());
+        assert_eq!(list[0].name, meal_info.name.to_string());
+        mock_search_menu.lock().await.verify_invoked("chicken wi");
+    }
+
+    #[actix_web::test]
+    async fn search_text_is_absent() {
+        dotenv().ok();
+        let mock_search_menu = AM::new_am(MockSearchMenu::default());
+        let mock_shared_state = Data::new(mock_search_menu.clone());
+        let req = TestRequest::default().to_http_request();
+
+        let resp = search_menu_endpoint(mock_shared_state, req).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let body = resp.into_body().try_into_bytes().unwrap();
+        let response_dto: GenericErrorResponse =
+            serde_json::from_str(std::str::from_utf8(&body).unwrap()).unwrap();
+        assert_eq!(&response_dto.response_type, &bad_request_type_url());
+        assert_eq!(
+            response_dto.invalid_params.first().unwrap().message,
+            "Mandatory parameter 'q' in query is absent"
+        );
+        mock_search_menu.lock().await.verify_empty();
+    }
+
+    #[actix_web::test]
+    async fn search_text_has_no_words() {
+        dotenv().ok();
+        let mock_search_menu = AM::new_am(MockSearchMenu {
+            response: Err(SearchMenuUseCaseError::EmptySearchText),
+            ..Default::default()
+        });
+        let mock_shared_state = Data::new(mock_search_menu.clone());
+        let req = TestRequest::default().uri("/?q=%20-%20").to_http_request();
+
+        let resp = search_menu_endpoint(mock_shared_state, req).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let body = resp.into_body().try_into_bytes().unwrap();
+        let response_dto: GenericErrorResponse =
+            serde_json::from_str(std::str::from_utf8(&body).unwrap()).unwrap();
+        assert_eq!(
+            response_dto.invalid_params.first().unwrap().message,
+            "Search text has no words"
+        );
+        mock_search_menu.lock().await.verify_invoked(" - ");
+    }
+}
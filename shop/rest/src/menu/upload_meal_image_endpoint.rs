@@ -0,0 +1,388 @@
+use std::fmt::Debug;
+
+use actix_multipart::Multipart;
+use actix_web::{HttpRequest, HttpResponse, middleware::from_fn, web};
+use common::{
+    common_rest::{
+        GenericErrorResponse, ValidationError, created, get_json_from_http_response,
+        resource_not_found, to_invalid_param_bad_request,
+    },
+    types::base::{AM, RCell, RcRefCellTrait},
+};
+use domain::menu::value_objects::meal_id::MealId;
+use futures_util::StreamExt;
+use usecase::menu::{MAX_MEAL_IMAGE_SIZE, UploadMealImage, UploadMealImageUseCaseError};
+use utoipa::ToSchema;
+
+use crate::{
+    auth::jwt_middleware::require_admin, endpoint_url::API_V1_MENU_UPLOAD_IMAGE,
+    to_error::ToRestError, validated::Validated,
+};
+
+/// Name of the multipart field with the image
+const IMAGE_FIELD: &str = "image";
+
+#[derive(ToSchema)]
+#[allow(dead_code)]
+pub struct UploadMealImageRestRequest {
+    /// JPEG, PNG or WebP image, up to 5 MiB
+    #[schema(value_type = String, format = Binary, required = true)]
+    image: Vec<u8>,
+}
+
+/// Upload an image of the meal
+///
+/// Requires the `admin` role. The image is added to the images of the meal. The content must
+/// start with the signature of the declared image type
+#[utoipa::path(
+    post,
+    path = API_V1_MENU_UPLOAD_IMAGE,
+    tag = "Meal",
+    security(("bearerAuth" = ["admin"])),
+    params(
+        ("id" = i64, Path, description = "Meal id")
+    ),
+    request_body(
+        content = UploadMealImageRestRequest,
+        content_type = "multipart/form-data",
+    ),
+    responses(
+        (
+            status = UNAUTHORIZED,
+            description = "Missing or invalid bearer token",
+            body = GenericErrorResponse,
+            example = json!({"type":"http://0.0.0.0:8080/unauthorized","title":"Unauthorized","status":401})
+        ),
+        (
+            status = FORBIDDEN,
+            description = "Caller is not allowed to perform the operation",
+            body = GenericErrorResponse,
+            example = json!({"type":"http://0.0.0.0:8080/forbidden","title":"Forbidden","status":403})
+        ),
+        (
+            status = CREATED,
+            description = "Created",
+            headers(
+                ("location" = String, description = "Url of the uploaded image")
+            )
+        ),
+        (
+            status = BAD_REQUEST,
+            description = "Bad request",
+            body = GenericErrorResponse,
+            example = json!(
+                {
+                    "type": "http://0.0.0.0:8080/bad_request",
+                    "title": "Bad request",
+                    "status": 400,
+                    "invalid_params":
+                    [
+                        {"message": "Unsupported image content type 'image/gif'"}
+                    ]
+                }
+            )
+        ),
+        (
+            status = NOT_FOUND,
+            description = "Meal not found",
+            body = GenericErrorResponse,
+            example = json!(&(get_json_from_http_response(resource_not_found())))
+        ),
+        (
+            status = INTERNAL_SERVER_ERROR,
+            description = "The image couldn't be stored"
+        )
+    ))]
+pub async fn upload_meal_image_endpoint<T>(
+    shared_state: web::Data<AM<T>>,
+    req: HttpRequest,
+    mut payload: Multipart,
+) -> HttpResponse
+where
+    T: UploadMealImage + Send + Debug,
+{
+    let error_list = RCell::new_rc(vec![]);
+
+    let meal_id = req
+        .match_info()
+        .get("id")
+        .and_then(|v| v.parse::<i64>().ok())
+        .and_then(|id| MealId::validated(id, error_list.clone()));
+    let image = read_image(&mut payload).await;
+    if image.is_none() {
+        error_list
+            .borrow_mut()
+            .push(ValidationError::new("Image is missing"));
+    }
+
+    match (meal_id, image) {
+        (Some(meal_id), Some((content_type, content))) => {
+            match shared_state
+                .lock()
+                .await
+                .execute(&meal_id, &content_type, content)
+                .await
+            {
+                Ok(url) => created(url),
+                Err(e) => e.to_rest_error(),
+            }
+        }
+        _ => to_invalid_param_bad_request(error_list),
+    }
+}
+
+/// Content type and content of the image field. Reading stops as soon as the content exceeds
+/// the allowed size, the truncated content is still rejected by the use case
+async fn read_image(payload: &mut Multipart) -> Option<(String, Vec<u8>)> {
+    while let Some(Ok(mut field)) = payload.next().await {
+        if field.name() != Some(IMAGE_FIELD) {
+            continue;
+        }
+        let content_type = field
+            .content_type()
+            .map(|mime| mime.essence_str().to_string())
+            .unwrap_or_default();
+        let mut content = Vec::new();
+        while let Some(chunk) = field.next().await {
+            content.extend_from_slice(&chunk.ok()?);
+            if content.len() > MAX_MEAL_IMAGE_SIZE {
+                break;
+            }
+        }
+        return Some((content_type, content));
+    }
+    None
+}
+
+impl ToRestError for UploadMealImageUseCaseError {
+    fn to_rest_error(self) -> HttpResponse {
+        match self {
+            UploadMealImageUseCaseError::MealNotFound => resource_not_found(),
+            UploadMealImageUseCaseError::UnsupportedContentType(_)
+            | UploadMealImageUseCaseError::TooLarge(_)
+            | UploadMealImageUseCaseError::ContentMismatch(_) => to_invalid_param_bad_request(
+                RCell::new_rc(vec![ValidationError::new(&self.to_string())]),
+            ),
+            UploadMealImageUseCaseError::StorageFailed(_) => {
+                HttpResponse::InternalServerError().finish()
+            }
+        }
+    }
+}
+
+pub fn upload_meal_image_endpoint_config<T>(cfg: &mut web::ServiceConfig)
+where
+    T: UploadMealImage + Send + Debug + 'static,
+{
+    cfg.route(
+        API_V1_MENU_UPLOAD_IMAGE,
+        web::post()
+            .to(upload_meal_image_endpoint::<T>)
+            .wrap(from_fn(require_admin)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_multipart::test::create_form_data_payload_and_headers;
+    use actix_web::{
+        body::MessageBody,
+        http::StatusCode,
+        test::TestRequest,
+        web::{Bytes, Data},
+    };
+    use common::{
+        common_rest::{bad_request_type_url, not_found_type_url},
+        types::base::AMTrait,
+    };
+    use domain::test_fixtures::*;
+    use dotenvy::dotenv;
+    use futures_util::stream;
+    use usecase::menu::access::media_storage::MediaStorageError;
+
+    use super::*;
+    use crate::test_fixtures::MockUploadMealImage;
+
+    fn multipart(field_name: &str, content_type: &str, content: &[u8]) -> Multipart {
+        let (body, headers) = create_form_data_payload_and_headers(
+            field_name,
+            Some("meal.png".to_string()),
+            Some(content_type.parse().unwrap()),
+            Bytes::copy_from_slice(content),
+        );
+        Multipart::new(&headers, stream::once(async { Ok(body) }))
+    }
+
+    fn request_for(meal_id: &MealId) -> HttpRequest {
+        TestRequest::default()
+            .param("id", meal_id.to_i64().to_string())
+            .to_http_request()
+    }
+
+    fn error_response(resp: HttpResponse) -> GenericErrorResponse {
+        let body = resp.into_body().try_into_bytes().unwrap();
+        serde_json::from_str(std::str::from_utf8(&body).unwrap()).unwrap()
+    }
+
+    #[actix_web::test]
+    async fn image_uploaded() {
+        let meal_id = rnd_meal_id();
+        let mock_upload_meal_image = AM::new_am(MockUploadMealImage::default());
+        let mock_shared_state = Data::new(mock_upload_meal_image.clone());
+
+        let resp = upload_meal_image_endpoint(
+            mock_shared_state,
+            request_for(&meal_id),
+            multipart(IMAGE_FIELD, "image/png", &[1, 2, 3]),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::CREATED);
+        let url = mock_upload_meal_image
+            .lock()
+            .await
+            .response
+            .clone()
+            .unwrap();
+        assert_eq!(
+            resp.headers().get("Location").unwrap().to_str().unwrap(),
+            url.to_string()
+        );
+        mock_upload_meal_image
+            .lock()
+            .await
+            .verify_invoked(&meal_id, "image/png", &[1, 2, 3]);
+    }
+
+    #[actix_web::test]
+    async fn image_is_missing() {
+        dotenv().ok();
+        let mock_upload_meal_image = AM::new_am(MockUploadMealImage::default());
+        let mock_shared_state = Data::new(mock_upload_meal_image.clone());
+
+        let resp = upload_meal_image_endpoint(
+            mock_shared_state,
+            request_for(&rnd_meal_id()),
+            multipart("photo", "image/png", &[1, 2, 3]),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let response_dto = error_response(resp);
+        assert_eq!(&response_dto.response_type, &bad_request_type_url());
+        assert_eq!(
+            &response_dto.invalid_params.first().unwrap().message,
+            "Image is missing"
+        );
+        mock_upload_meal_image.lock().await.verify_empty();
+    }
+
+    #[actix_web::test]
+    async fn unsupported_content_type() {
+        dotenv().ok();
+        let mock_upload_meal_image = AM::new_am(MockUploadMealImage::default());
+        mock_upload_meal_image.lock().await.response = Err(
+            UploadMealImageUseCaseError::UnsupportedContentType("text/plain".to_string()),
+        );
+        let mock_shared_state = Data::new(mock_upload_meal_image.clone());
+
+        let resp = upload_meal_image_endpoint(
+            mock_shared_state,
+            request_for(&rnd_meal_id()),
+            multipart(IMAGE_FIELD, "text/plain", &[1, 2, 3]),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let response_dto = error_response(resp);
+        assert_eq!(
+            &response_dto.invalid_params.first().unwrap().message,
+            "Unsupported image content type 'text/plain'"
+        );
+    }
+
+    #[actix_web::test]
+    async fn storage_failed() {
+        let mock_upload_meal_image = AM::new_am(MockUploadMealImage::default());
+        mock_upload_meal_image.lock().await.response =
+            Err(UploadMealImageUseCaseError::StorageFailed(
+                MediaStorageError::StorageFailed("disk full".to_string()),
+            ));
+        let mock_shared_state = Data::new(mock_upload_meal_image.clone());
+
+        let resp = upload_meal_image_endpoint(
+            mock_shared_state,
+            request_for(&rnd_meal_id()),
+            multipart(IMAGE_FIELD, "image/png", &[1]),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::INTERNAL_SERVER_ERROR);
+    }
+
+    #[actix_web::test]
+    async fn content_not_matching_content_type() {
+        dotenv().ok();
+        let mock_upload_meal_image = AM::new_am(MockUploadMealImage::default());
+        mock_upload_meal_image.lock().await.response = Err(
+            UploadMealImageUseCaseError::ContentMismatch("image/png".to_string()),
+        );
+        let mock_shared_state = Data::new(mock_upload_meal_image.clone());
+
+        let resp = upload_meal_image_endpoint(
+            mock_shared_state,
+            request_for(&rnd_meal_id()),
+            multipart(IMAGE_FIELD, "image/png", b"<svg/>"),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let response_dto = error_response(resp);
+        assert_eq!(
+            &response_dto.invalid_params.first().unwrap().message,
+            "Image content doesn't match the content type 'image/png'"
+        );
+    }
+
+    #[actix_web::test]
+    async fn reading_stops_after_max_size() {
+        let meal_id = rnd_meal_id();
+        let mock_upload_meal_image = AM::new_am(MockUploadMealImage::default());
+        mock_upload_meal_image.lock().await.response =
+            Err(UploadMealImageUseCaseError::TooLarge(MAX_MEAL_IMAGE_SIZE));
+        let mock_shared_state = Data::new(mock_upload_meal_image.clone());
+
+        let resp = upload_meal_image_endpoint(
+            mock_shared_state,
+            request_for(&meal_id),
+            multipart(IMAGE_FIELD, "image/png", &vec![0; MAX_MEAL_IMAGE_SIZE * 2]),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let invoked_with = mock_upload_meal_image.lock().await.invoked_with.clone();
+        let (_, _, content) = invoked_with.unwrap();
+        assert!(content.len() > MAX_MEAL_IMAGE_SIZE);
+        assert!(content.len() < MAX_MEAL_IMAGE_SIZE * 2);
+    }
+
+    #[actix_web::test]
+    async fn meal_not_found() {
+        dotenv().ok();
+        let mock_upload_meal_image = AM::new_am(MockUploadMealImage::default());
+        mock_upload_meal_image.lock().await.response =
+            Err(UploadMealImageUseCaseError::MealNotFound);
+        let mock_shared_state = Data::new(mock_upload_meal_image.clone());
+
+        let resp = upload_meal_image_endpoint(
+            mock_shared_state,
+            request_for(&rnd_meal_id()),
+            multipart(IMAGE_FIELD, "image/png", &[1]),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+        let response_dto = error_response(resp);
+        assert_eq!(&response_dto.response_type, &not_found_type_url());
+    }
+}
@@ -1,13 +1,23 @@
 use bigdecimal::*;
 use common::{common_rest::ValidationError, types::base::RCell};
 use domain::menu::value_objects::{
+    allergens::{Allergens, CreateAllergensError},
+    availability_schedule::{
+        AvailabilitySchedule, AvailabilityWindow, CreateAvailabilityScheduleError,
+    },
     meal_description::{CreateMealDescriptionError, MealDescription},
     meal_id::{MealId, MealIdError},
     meal_name::{CreateMealNameError, MealName},
+    nutrition_facts::{CreateNutritionFactsError, NutritionFacts},
     price::{CreatePriceError, Price},
 };
 
-use crate::validated::Validated;
+use time::{Time, Weekday};
+
+use crate::{
+    menu::meal_model::{AvailabilityScheduleModel, AvailabilityWindowModel, NutritionFactsModel},
+    validated::Validated,
+};
 
 impl Validated<&str> for MealName {
     fn validated(val: &str, error_list: RCell<Vec<ValidationError>>) -> Option<Self> {
@@ -58,6 +68,150 @@ impl Validated<BigDecimal> for Price {
     }
 }
 
+impl<S: AsRef<str>> Validated<&[S]> for Allergens {
+    fn validated(val: &[S], error_list: RCell<Vec<ValidationError>>) -> Option<Self> {
+        match Self::try_from(val) {
+            Ok(allergens) => Some(allergens),
+            Err(CreateAllergensError::UnknownAllergen(code)) => {
+                error_list
+                    .borrow_mut()
+                    .push(ValidationError::new(&format!("Unknown allergen '{code}'")));
+                None
+            }
+        }
+    }
+}
+
+impl Validated<&NutritionFactsModel> for NutritionFacts {
+    fn validated(
+        val: &NutritionFactsModel,
+        error_list: RCell<Vec<ValidationError>>,
+    ) -> Option<Self> {
+        let grams = |value: f64| value.to_string().parse::<BigDecimal>().unwrap_or_default();
+        match Self::try_new(
+            val.calories,
+            grams(val.proteins),
+            grams(val.fats),
+            grams(val.carbohydrates),
+        ) {
+            Ok(nutrition_facts) => Some(nutrition_facts),
+            Err(CreateNutritionFactsError::InvalidScale) => {
+                error_list.borrow_mut().push(ValidationError::new(&format!(
+                    "Nutrition facts scale must not be > {}",
+                    NutritionFacts::SCALE
+                )));
+                None
+            }
+            Err(CreateNutritionFactsError::NegativeValue) => {
+                error_list
+                    .borrow_mut()
+                    .push(ValidationError::new("Nutrition facts must not be negative"));
+                None
+            }
+        }
+    }
+}
+
+impl Validated<&AvailabilityScheduleModel> for AvailabilitySchedule {
+    fn validated(
+        val: &AvailabilityScheduleModel,
+        error_list: RCell<Vec<ValidationError>>,
+    ) -> Option<Self> {
+        let windows: Vec<Option<AvailabilityWindow>> = val
+            .windows
+            .iter()
+            .map(|window| AvailabilityWindow::validated(window, error_list.clone()))
+            .collect();
+        let windows: Vec<AvailabilityWindow> = windows.into_iter().collect::<Option<_>>()?;
+        match Self::try_new(&val.time_zone, windows) {
+            Ok(schedule) => Some(schedule),
+            Err(e) => {
+                push_availability_error(e, error_list);
+                None
+            }
+        }
+    }
+}
+
+impl Validated<&AvailabilityWindowModel> for AvailabilityWindow {
+    fn validated(
+        val: &AvailabilityWindowModel,
+        error_list: RCell<Vec<ValidationError>>,
+    ) -> Option<Self> {
+        let days: Vec<Option<Weekday>> = val
+            .days
+            .iter()
+            .map(|day| {
+                let weekday = parse_weekday(day);
+                if weekday.is_none() {
+                    error_list
+                        .borrow_mut()
+                        .push(ValidationError::new(&format!("Unknown day '{day}'")));
+                }
+                weekday
+            })
+            .collect();
+        let time = |value: &str| {
+            let time = parse_hours_and_minutes(value);
+            if time.is_none() {
+                error_list.borrow_mut().push(ValidationError::new(&format!(
+                    "Time '{value}' must be in format HH:MM"
+                )));
+            }
+            time
+        };
+        let (from, to) = (time(&val.from), time(&val.to));
+        let days: Vec<Weekday> = days.into_iter().collect::<Option<_>>()?;
+        match Self::try_new(days, from?, to?) {
+            Ok(window) => Some(window),
+            Err(e) => {
+                push_availability_error(e, error_list);
+                None
+            }
+        }
+    }
+}
+
+fn push_availability_error(
+    error: CreateAvailabilityScheduleError,
+    error_list: RCell<Vec<ValidationError>>,
+) {
+    let message = match error {
+        CreateAvailabilityScheduleError::UnknownTimeZone(time_zone) => {
+            format!("Unknown time zone '{time_zone}'")
+        }
+        CreateAvailabilityScheduleError::NoWindows => {
+            "Availability schedule must have windows".to_string()
+        }
+        CreateAvailabilityScheduleError::NoDays => "Availability window must have days".to_string(),
+        CreateAvailabilityScheduleError::EmptyWindow => {
+            "Availability window must not open and close at the same time".to_string()
+        }
+    };
+    error_list.borrow_mut().push(ValidationError::new(&message));
+}
+
+fn parse_weekday(day: &str) -> Option<Weekday> {
+    match day.to_lowercase().as_str() {
+        "monday" => Some(Weekday::Monday),
+        "tuesday" => Some(Weekday::Tuesday),
+        "wednesday" => Some(Weekday::Wednesday),
+        "thursday" => Some(Weekday::Thursday),
+        "friday" => Some(Weekday::Friday),
+        "saturday" => Some(Weekday::Saturday),
+        "sunday" => Some(Weekday::Sunday),
+        _ => None,
+    }
+}
+
+fn parse_hours_and_minutes(value: &str) -> Option<Time> {
+    let (hours, minutes) = value.split_once(':')?;
+    if hours.len() != 2 || minutes.len() != 2 {
+        return None;
+    }
+    Time::from_hms(hours.parse().ok()?, minutes.parse().ok()?, 0).ok()
+}
+
 impl Validated<i64> for MealId {
     fn validated(val: i64, error_list: RCell<Vec<ValidationError>>) -> Option<Self> {
         match Self::try_from(val) {
@@ -1,4 +1,4 @@
-use actix_web::{HttpRequest, HttpResponse, http::StatusCode, web};
+use actix_web::{HttpRequest, HttpResponse, http::StatusCode, middleware::from_fn, web};
 use common::{
     common_rest::{
         GenericErrorResponse, get_json_from_http_response, resource_not_found, rest_business_error,
@@ -9,14 +9,32 @@ use common::{
 use domain::order::value_objects::shop_order_id::ShopOrderId;
 use usecase::order::{CancelOrder, CancelOrderUseCaseError};
 
-use crate::{endpoint_url::API_V1_ORDER_CANCEL_BY_ID, to_error::ToRestError, validated::Validated};
+use crate::{
+    auth::jwt_middleware::require_kitchen_staff, endpoint_url::API_V1_ORDER_CANCEL_BY_ID,
+    to_error::ToRestError, validated::Validated,
+};
 
 /// Cancel an order by id
+///
+/// Requires the `kitchen_staff` or `admin` role
 #[utoipa::path(
     put,
     path = API_V1_ORDER_CANCEL_BY_ID,
     tag = "Order",
+    security(("bearerAuth" = ["kitchen_staff", "admin"])),
     responses(
+        (
+            status = UNAUTHORIZED,
+            description = "Missing or invalid bearer token",
+            body = GenericErrorResponse,
+            example = json!({"type":"http://0.0.0.0:8080/unauthorized","title":"Unauthorized","status":401})
+        ),
+        (
+            status = FORBIDDEN,
+            description = "Caller is not allowed to perform the operation",
+            body = GenericErrorResponse,
+            example = json!({"type":"http://0.0.0.0:8080/forbidden","title":"Forbidden","status":403})
+        ),
         (
             status = NO_CONTENT,
             description = "Sucessfully cancelled" 
@@ -79,7 +97,9 @@ impl ToRestError for CancelOrderUseCaseError {
 pub fn cancel_order_endpoint_config<T: CancelOrder + 'static>(cfg: &mut web::ServiceConfig) {
     cfg.route(
         API_V1_ORDER_CANCEL_BY_ID,
-        web::put().to(cancel_order_endpoint::<T>),
+        web::put()
+            .to(cancel_order_endpoint::<T>)
+            .wrap(from_fn(require_kitchen_staff)),
     );
 }
 
@@ -1,6 +1,6 @@
 use std::fmt::Debug;
 
-use actix_web::{HttpRequest, HttpResponse, http::StatusCode, web};
+use actix_web::{HttpRequest, HttpResponse, http::StatusCode, middleware::from_fn, web};
 use common::{
     common_rest::{
         GenericErrorResponse, get_json_from_http_response, resource_not_found, rest_business_error,
@@ -12,15 +12,31 @@ use domain::order::value_objects::shop_order_id::ShopOrderId;
 use usecase::order::{ConfirmOrder, ConfirmOrderUseCaseError};
 
 use crate::{
-    endpoint_url::API_V1_ORDER_CONFIRM_BY_ID, to_error::ToRestError, validated::Validated,
+    auth::jwt_middleware::require_kitchen_staff, endpoint_url::API_V1_ORDER_CONFIRM_BY_ID,
+    to_error::ToRestError, validated::Validated,
 };
 
 /// Confirm an order by id
+///
+/// Requires the `kitchen_staff` or `admin` role
 #[utoipa::path(
     put,
     path = API_V1_ORDER_CONFIRM_BY_ID,
     tag = "Order",
+    security(("bearerAuth" = ["kitchen_staff", "admin"])),
     responses(
+        (
+            status = UNAUTHORIZED,
+            description = "Missing or invalid bearer token",
+            body = GenericErrorResponse,
+            example = json!({"type":"http://0.0.0.0:8080/unauthorized","title":"Unauthorized","status":401})
+        ),
+        (
+            status = FORBIDDEN,
+            description = "Caller is not allowed to perform the operation",
+            body = GenericErrorResponse,
+            example = json!({"type":"http://0.0.0.0:8080/forbidden","title":"Forbidden","status":403})
+        ),
         (
             status = NO_CONTENT,
             description = "Successfully confirmed" 
@@ -89,7 +105,9 @@ where
 {
     cfg.route(
         API_V1_ORDER_CONFIRM_BY_ID,
-        web::put().to(confirm_order_endpoint::<T>),
+        web::put()
+            .to(confirm_order_endpoint::<T>)
+            .wrap(from_fn(require_kitchen_staff)),
     );
 }
 
@@ -0,0 +1,178 @@
+use std::fmt::Debug;
+
+use actix_web::{HttpRequest, HttpResponse, http::header::ContentType, web};
+use common::{
+    common_rest::{GenericErrorResponse, to_invalid_param_bad_request},
+    types::base::{AM, RCell, RcRefCellTrait},
+};
+use time::Date;
+use usecase::order::GetAvailableDeliverySlots;
+
+use super::{
+    order_model::{AvailableDeliverySlotModel, ToModel},
+    validated::validate_query_string,
+};
+use crate::{endpoint_url::API_V1_DELIVERY_SLOTS_GET_AVAILABLE, validated::Validated};
+
+/// Get delivery slots of the day which can still be booked
+#[utoipa::path(
+    get,
+    path = API_V1_DELIVERY_SLOTS_GET_AVAILABLE,
+    tag = "Order",
+    responses(
+        (
+            status = OK,
+            body = Vec<AvailableDeliverySlotModel>,
+            description = "OK"
+        ),
+        (
+            status = BAD_REQUEST,
+            description = "Bad request",
+            body = GenericErrorResponse,
+            example = json!(
+                {
+                    "type": "http://0.0.0.0:8080/bad_request",
+                    "title": "Bad request",
+                    "status": 400,
+                    "invalid_params":
+                    [
+                        {"message": "Date must be in YYYY-MM-DD format"}
+                    ]
+                }
+            )
+        ),
+    ),
+    params(
+        ("date" = String, Query, description = "Day of delivery, YYYY-MM-DD", example = "2030-03-15")
+    )
+)]
+pub async fn get_available_delivery_slots_endpoint<T: GetAvailableDeliverySlots + Send + Debug>(
+    shared_state: web::Data<AM<T>>,
+    req: HttpRequest,
+) -> HttpResponse {
+    let error_list = RCell::new_rc(vec![]);
+
+    match validate_query_string::<String>(req, "date", error_list.clone())
+        .ok()
+        .and_then(|date| Date::validated(date, error_list.clone()))
+    {
+        Some(date) => {
+            let list: Vec<AvailableDeliverySlotModel> = shared_state
+                .lock()
+                .await
+                .execute(date)
+                .await
+                .into_iter()
+                .map(|it| it.to_model())
+                .collect();
+            HttpResponse::Ok()
+                .content_type(ContentType::json())
+                .body(serde_json::to_string(&list).unwrap())
+        }
+        None => to_invalid_param_bad_request(error_list),
+    }
+}
+
+pub fn get_available_delivery_slots_endpoint_config<T>(cfg: &mut web::ServiceConfig)
+where
+    T: GetAvailableDeliverySlots + 'static,
+{
+    cfg.route(
+        API_V1_DELIVERY_SLOTS_GET_AVAILABLE,
+        web::get().to(get_available_delivery_slots_endpoint::<T>),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{body::MessageBody, http::StatusCode, test::TestRequest, web::Data};
+    use common::{
+        common_rest::{GenericErrorResponse, bad_request_type_url},
+        types::base::AMTrait,
+    };
+    use domain::test_fixtures::*;
+    use dotenvy::dotenv;
+    use time::Month;
+    use usecase::order::dto::available_delivery_slot::AvailableDeliverySlot;
+
+    use super::*;
+    use crate::test_fixtures::MockGetAvailableDeliverySlots;
+
+    #[actix_web::test]
+    async fn returned_successfully() {
+        let slot = AvailableDeliverySlot::new(rnd_delivery_slot(), 3);
+        let mock_get_slots = AM::new_am(MockGetAvailableDeliverySlots::new(vec![slot]));
+
+        let mock_shared_state = Data::new(mock_get_slots.clone());
+        let req = TestRequest::default()
+            .uri("/?date=2030-03-15")
+            .to_http_request();
+
+        let resp = get_available_delivery_slots_endpoint(mock_shared_state, req).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let body = resp.into_body().try_into_bytes().unwrap();
+        let body_text = std::str::from_utf8(&body).unwrap();
+
+        let response_dto: Vec<AvailableDeliverySlotModel> =
+            serde_json::from_str(body_text).unwrap();
+
+        assert_eq!(response_dto, vec![slot.to_model()]);
+        mock_get_slots
+            .lock()
+            .await
+            .verify_invoked(&Date::from_calendar_date(2030, Month::March, 15).unwrap());
+    }
+
+    #[actix_web::test]
+    async fn wrong_date_format() {
+        dotenv().ok();
+        let mock_get_slots = AM::new_am(MockGetAvailableDeliverySlots::new(vec![]));
+
+        let mock_shared_state = Data::new(mock_get_slots.clone());
+        let req = TestRequest::default()
+            .uri("/?date=15.03.2030")
+            .to_http_request();
+
+        let resp = get_available_delivery_slots_endpoint(mock_shared_state, req).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+        let body = resp.into_body().try_into_bytes().unwrap();
+        let body_text = std::str::from_utf8(&body).unwrap();
+
+        let response_dto: GenericErrorResponse = serde_json::from_str(body_text).unwrap();
+
+        assert_eq!(&response_dto.response_type, &bad_request_type_url());
+        assert_eq!(
+            &response_dto.invalid_params.first().unwrap().message,
+            "Date must be in YYYY-MM-DD format"
+        );
+        mock_get_slots.lock().await.verify_empty();
+    }
+
+    #[actix_web::test]
+    async fn date_is_absent() {
+        dotenv().ok();
+        let mock_get_slots = AM::new_am(MockGetAvailableDeliverySlots::new(vec![]));
+
+        let mock_shared_state = Data::new(mock_get_slots.clone());
+        let req = TestRequest::default().uri("/").to_http_request();
+
+        let resp = get_available_delivery_slots_endpoint(mock_shared_state, req).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+        let body = resp.into_body().try_into_bytes().unwrap();
+        let body_text = std::str::from_utf8(&body).unwrap();
+
+        let response_dto: GenericErrorResponse = serde_json::from_str(body_text).unwrap();
+
+        assert_eq!(
+            &response_dto.invalid_params.first().unwrap().message,
+            "Mandatory parameter 'date' in query is absent"
+        );
+        mock_get_slots.lock().await.verify_empty();
+    }
+}
@@ -0,0 +1,256 @@
+use std::fmt::Debug;
+
+use actix_web::{HttpRequest, HttpResponse, http::header::ContentType, middleware::from_fn, web};
+use common::{
+    common_rest::{
+        CursorPagedModel, GenericErrorResponse, ValidationError, to_invalid_param_bad_request,
+    },
+    types::base::{AM, RCell, RcRefCellTrait},
+};
+use domain::{
+    cart::value_objects::customer_id::CustomerId, order::value_objects::shop_order_id::ShopOrderId,
+};
+use usecase::order::{GetCustomerOrders, GetCustomerOrdersUseCaseError};
+
+use super::{
+    order_model::{OrderModel, ToModel},
+    validated::validate_query_string,
+};
+use crate::{
+    auth::jwt_middleware::require_owner_or_staff, endpoint_url::API_V1_CUSTOMER_ORDERS,
+    to_error::ToRestError, validated::Validated,
+};
+
+/// Get order history of the customer with pagination
+///
+/// Customers may only read their own history, staff may read any
+#[utoipa::path(
+    get,
+    path = API_V1_CUSTOMER_ORDERS,
+    tag = "Order",
+    security(("bearerAuth" = [])),
+    responses(
+        (
+            status = UNAUTHORIZED,
+            description = "Missing or invalid bearer token",
+            body = GenericErrorResponse,
+            example = json!({"type":"http://0.0.0.0:8080/unauthorized","title":"Unauthorized","status":401})
+        ),
+        (
+            status = FORBIDDEN,
+            description = "Caller is not allowed to perform the operation",
+            body = GenericErrorResponse,
+            example = json!({"type":"http://0.0.0.0:8080/forbidden","title":"Forbidden","status":403})
+        ),
+        (
+            status = OK,
+            body = Vec<OrderModel>,
+            description = "OK"
+        ),
+        (
+            status = BAD_REQUEST,
+            description = "Bad request",
+            body = GenericErrorResponse,
+            example = json!(
+                {
+                    "type": "http://0.0.0.0:8080/bad_request",
+                    "title": "Bad request",
+                    "status": 400,
+                    "invalid_params":
+                    [
+                        {"message": "Wrong Customer Id"},
+                        {"message": "Mandatory parameter 'limit' in query is absent"}
+                    ]
+                }
+            )
+        ),
+    ),
+    params(
+        ("id" = String, Path, description = "Customer id"),
+        ("limit" = usize, Query, description = "Pagination limit"),
+        ("startId" = i64, Query, description = "Pagination start ID")
+    )
+)]
+pub async fn get_customer_orders_endpoint<T: GetCustomerOrders + Send + Debug>(
+    shared_state: web::Data<AM<T>>,
+    req: HttpRequest,
+) -> HttpResponse {
+    let error_list = RCell::new_rc(vec![]);
+
+    let customer_id = req
+        .match_info()
+        .get("id")
+        .and_then(|id| CustomerId::validated(id, error_list.clone()));
+    let start_id = match validate_query_string::<i64>(req.clone(), "startId", error_list.clone()) {
+        Ok(id) => ShopOrderId::validated(id, error_list.clone()),
+        Err(_) => None,
+    };
+    let limit = validate_query_string::<usize>(req, "limit", error_list.clone());
+
+    match (customer_id, start_id, limit) {
+        (Some(customer_id), Some(start_id), Ok(limit)) => {
+            match shared_state
+                .lock()
+                .await
+                .execute(&customer_id, &start_id, limit + 1)
+                .await
+            {
+                Ok(order_details_list) => {
+                    let list: Vec<OrderModel> = order_details_list
+                        .into_iter()
+                        .map(|it| it.to_model())
+                        .collect();
+                    let model = if list.len() > limit {
+                        let next_id = list[limit].id;
+                        CursorPagedModel::new(list[..limit].to_vec(), Some(next_id))
+                    } else {
+                        CursorPagedModel::new(list, Option::<i64>::None)
+                    };
+                    HttpResponse::Ok()
+                        .content_type(ContentType::json())
+                        .body(serde_json::to_string(&model).unwrap())
+                }
+                Err(e) => e.to_rest_error(),
+            }
+        }
+        _ => to_invalid_param_bad_request(error_list),
+    }
+}
+
+impl ToRestError for GetCustomerOrdersUseCaseError {
+    fn to_rest_error(self) -> HttpResponse {
+        match self {
+            GetCustomerOrdersUseCaseError::LimitExceed(max_size) => {
+                let error_list = RCell::new_rc(vec![]);
+                error_list.borrow_mut().push(ValidationError::new(&format!(
+                    "Max limit is {}",
+                    max_size - 1
+                )));
+                to_invalid_param_bad_request(error_list)
+            }
+        }
+    }
+}
+
+pub fn get_customer_orders_endpoint_config<T>(cfg: &mut web::ServiceConfig)
+where
+    T: GetCustomerOrders + 'static,
+{
+    cfg.route(
+        API_V1_CUSTOMER_ORDERS,
+        web::get()
+            .to(get_customer_orders_endpoint::<T>)
+            .wrap(from_fn(require_owner_or_staff)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{body::MessageBody, http::StatusCode, test::TestRequest, web::Data};
+    use common::{
+        common_rest::{GenericErrorResponse, bad_request_type_url},
+        types::base::AMTrait,
+    };
+    use domain::test_fixtures::*;
+    use dotenvy::dotenv;
+
+    use super::*;
+    use crate::test_fixtures::{MockGetCustomerOrders, rnd_order_details};
+
+    #[actix_web::test]
+    async fn wrong_customer_id() {
+        dotenv().ok();
+        let mock_get_customer_orders = AM::new_am(MockGetCustomerOrders::default());
+        let mock_shared_state = Data::new(mock_get_customer_orders.clone());
+
+        let req = TestRequest::default()
+            .uri("/?startId=1&limit=10")
+            .param("id", "not-a-uuid")
+            .to_http_request();
+
+        let resp = get_customer_orders_endpoint(mock_shared_state, req).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+        let body = resp.into_body().try_into_bytes().unwrap();
+        let response_dto: GenericErrorResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(&response_dto.response_type, &bad_request_type_url());
+        assert_eq!(
+            &response_dto.invalid_params.first().unwrap().message,
+            "Wrong Customer Id"
+        );
+        mock_get_customer_orders.lock().await.verify_empty();
+    }
+
+    #[actix_web::test]
+    async fn limit_reached() {
+        dotenv().ok();
+        let customer_id = rnd_customer_id();
+        let start_id = rnd_order_id();
+        let limit = 10;
+
+        let mock_get_customer_orders = AM::new_am(MockGetCustomerOrders {
+            response: Err(GetCustomerOrdersUseCaseError::LimitExceed(limit + 1)),
+            ..Default::default()
+        });
+        let mock_shared_state = Data::new(mock_get_customer_orders.clone());
+
+        let req = TestRequest::default()
+            .uri(&format!("/?startId={}&limit={}", start_id.to_i64(), limit))
+            .param("id", customer_id.to_string())
+            .to_http_request();
+
+        let resp = get_customer_orders_endpoint(mock_shared_state, req).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+        let body = resp.into_body().try_into_bytes().unwrap();
+        let response_dto: GenericErrorResponse = serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(
+            &response_dto.invalid_params.first().unwrap().message,
+            "Max limit is 10"
+        );
+        mock_get_customer_orders
+            .lock()
+            .await
+            .verify_invoked(&customer_id, &start_id, &(limit + 1));
+    }
+
+    #[actix_web::test]
+    async fn returned_successfully_with_next_page() {
+        let customer_id = rnd_customer_id();
+        let limit = 1;
+
+        let first = rnd_order_details(Default::default());
+        let second = rnd_order_details(Default::default());
+
+        let mock_get_customer_orders = AM::new_am(MockGetCustomerOrders {
+            response: Ok(vec![first.clone(), second.clone()]),
+            ..Default::default()
+        });
+        let mock_shared_state = Data::new(mock_get_customer_orders.clone());
+
+        let req = TestRequest::default()
+            .uri(&format!("/?startId={}&limit={}", first.id.to_i64(), limit))
+            .param("id", customer_id.to_string())
+            .to_http_request();
+
+        let resp = get_customer_orders_endpoint(mock_shared_state, req).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let body = resp.into_body().try_into_bytes().unwrap();
+        let response_dto: CursorPagedModel<OrderModel, i64> =
+            serde_json::from_slice(&body).unwrap();
+
+        assert_eq!(response_dto.count, limit);
+        assert_eq!(response_dto.list[0].id, first.id.to_i64());
+        assert_eq!(response_dto.next, Some(second.id.to_i64()));
+        mock_get_customer_orders
+            .lock()
+            .await
+            .verify_invoked(&customer_id, &first.id, &(limit + 1));
+    }
+}
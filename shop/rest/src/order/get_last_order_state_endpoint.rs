@@ -0,0 +1,189 @@
+use std::fmt::Debug;
+
+use actix_web::{HttpRequest, HttpResponse, http::header::ContentType, middleware::from_fn, web};
+use common::{
+    common_rest::{
+        GenericErrorResponse, get_json_from_http_response, resource_not_found,
+        to_invalid_param_bad_request,
+    },
+    types::base::{AM, RCell, RcRefCellTrait},
+};
+use domain::cart::value_objects::customer_id::CustomerId;
+use usecase::order::{GetLastOrderState, GetLastOrderStateUseCaseError};
+
+use super::order_model::{LastOrderStateModel, ToModel};
+use crate::{
+    auth::jwt_middleware::require_owner_or_staff, endpoint_url::API_V1_CUSTOMER_LAST_ORDER_STATE,
+    to_error::ToRestError, validated::Validated,
+};
+
+/// Get the state of the most recent order of the customer
+///
+/// Customers may only read their own orders, staff may read any
+#[utoipa::path(
+    get,
+    path = API_V1_CUSTOMER_LAST_ORDER_STATE,
+    tag = "Order",
+    security(("bearerAuth" = [])),
+    responses(
+        (
+            status = UNAUTHORIZED,
+            description = "Missing or invalid bearer token",
+            body = GenericErrorResponse,
+            example = json!({"type":"http://0.0.0.0:8080/unauthorized","title":"Unauthorized","status":401})
+        ),
+        (
+            status = FORBIDDEN,
+            description = "Caller is not allowed to perform the operation",
+            body = GenericErrorResponse,
+            example = json!({"type":"http://0.0.0.0:8080/forbidden","title":"Forbidden","status":403})
+        ),
+        (
+            status = OK,
+            body = LastOrderStateModel,
+            description = "OK"
+        ),
+        (
+            status = BAD_REQUEST,
+            description = "Bad request",
+            body = GenericErrorResponse,
+            example = json!(
+                {
+                    "type": "http://0.0.0.0:8080/bad_request",
+                    "title": "Bad request",
+                    "status": 400,
+                    "invalid_params":
+                    [
+                        {"message": "Wrong Customer Id"}
+                    ]
+                }
+            )
+        ),
+        (
+            status = NOT_FOUND,
+            description = "Customer has no orders",
+            body = GenericErrorResponse,
+            example = json!(&(get_json_from_http_response(resource_not_found())))
+        ),
+    ),
+    params(
+        ("id" = String, Path, description = "Customer id"),
+    )
+)]
+pub async fn get_last_order_state_endpoint<T>(
+    shared_state: web::Data<AM<T>>,
+    req: HttpRequest,
+) -> HttpResponse
+where
+    T: GetLastOrderState + Send + Debug,
+{
+    let error_list = RCell::new_rc(vec![]);
+
+    let maybe_customer_id = req
+        .match_info()
+        .get("id")
+        .and_then(|id| CustomerId::validated(id, error_list.clone()));
+
+    match maybe_customer_id {
+        Some(customer_id) => match shared_state.lock().await.execute(&customer_id).await {
+            Ok(state) => HttpResponse::Ok().content_type(ContentType::json()).body(
+                serde_json::to_string(&LastOrderStateModel {
+                    state: state.to_model(),
+                })
+                .unwrap(),
+            ),
+            Err(e) => e.to_rest_error(),
+        },
+        None => to_invalid_param_bad_request(error_list),
+    }
+}
+
+impl ToRestError for GetLastOrderStateUseCaseError {
+    fn to_rest_error(self) -> HttpResponse {
+        match self {
+            GetLastOrderStateUseCaseError::OrderNotFound => resource_not_found(),
+        }
+    }
+}
+
+pub fn get_last_order_state_endpoint_config<T>(cfg: &mut web::ServiceConfig)
+where
+    T: GetLastOrderState + 'static,
+{
+    cfg.route(
+        API_V1_CUSTOMER_LAST_ORDER_STATE,
+        web::get()
+            .to(get_last_order_state_endpoint::<T>)
+            .wrap(from_fn(require_owner_or_staff)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{body::MessageBody, http::StatusCode, test::TestRequest, web::Data};
+    use common::{
+        common_rest::{GenericErrorResponse, not_found_type_url},
+        types::base::AMTrait,
+    };
+    use domain::{order::shop_order::OrderState, test_fixtures::*};
+    use dotenvy::dotenv;
+
+    use super::*;
+    use crate::{order::order_model::OrderStateModel, test_fixtures::MockGetLastOrderState};
+
+    #[actix_web::test]
+    async fn order_not_found() {
+        dotenv().ok();
+        let customer_id = rnd_customer_id();
+        let mock_get_last_order_state = AM::new_am(MockGetLastOrderState {
+            response: Err(GetLastOrderStateUseCaseError::OrderNotFound),
+            for_customer: None,
+        });
+        let mock_shared_state = Data::new(mock_get_last_order_state.clone());
+
+        let req = TestRequest::default()
+            .param("id", customer_id.to_string())
+            .to_http_request();
+
+        let resp = get_last_order_state_endpoint(mock_shared_state, req).await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+        let body = resp.into_body().try_into_bytes().unwrap();
+        let response_dto: GenericErrorResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(&response_dto.response_type, &not_found_type_url());
+        mock_get_last_order_state
+            .lock()
+            .await
+            .verify_invoked(&customer_id);
+    }
+
+    #[actix_web::test]
+    async fn returned_successfully() {
+        let customer_id = rnd_customer_id();
+        let mock_get_last_order_state = AM::new_am(MockGetLastOrderState {
+            response: Ok(OrderState::new_ready_for_pickup()),
+            for_customer: None,
+        });
+        let mock_shared_state = Data::new(mock_get_last_order_state.clone());
+
+        let req = TestRequest::default()
+            .param("id", customer_id.to_string())
+            .to_http_request();
+
+        let resp = get_last_order_state_endpoint(mock_shared_state, req).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let body = resp.into_body().try_into_bytes().unwrap();
+        let response_json: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        assert_eq!(response_json["state"], "ready_for_pickup");
+
+        let response_dto: LastOrderStateModel = serde_json::from_slice(&body).unwrap();
+        assert_eq!(response_dto.state, OrderStateModel::ReadyForPickup);
+        mock_get_last_order_state
+            .lock()
+            .await
+            .verify_invoked(&customer_id);
+    }
+}
@@ -1,9 +1,9 @@
 use std::fmt::Debug;
 
-use actix_web::{HttpRequest, HttpResponse, http::header::ContentType, web};
+use actix_web::{HttpRequest, HttpResponse, http::header::ContentType, middleware::from_fn, web};
 use common::{
     common_rest::{
-        GenericErrorResponse, get_json_from_http_response, resource_not_found,
+        GenericErrorResponse, forbidden, get_json_from_http_response, resource_not_found,
         to_invalid_param_bad_request,
     },
     types::base::{AM, RCell, RcRefCellTrait},
@@ -12,6 +12,7 @@ use domain::order::value_objects::shop_order_id::ShopOrderId;
 use usecase::order::{GetOrderById, GetOrderByIdUseCaseError};
 
 use crate::{
+    auth::{authenticated_user::AuthenticatedUser, jwt_middleware::require_authentication},
     endpoint_url::API_V1_ORDER_GET_BY_ID,
     order::order_model::{OrderModel, ToModel},
     to_error::ToRestError,
@@ -19,11 +20,26 @@ use crate::{
 };
 
 /// Get an order by id
+///
+/// Customers may only read their own orders, staff may read any
 #[utoipa::path(
     get,
     path = API_V1_ORDER_GET_BY_ID,
     tag = "Order",
+    security(("bearerAuth" = [])),
     responses(
+        (
+            status = UNAUTHORIZED,
+            description = "Missing or invalid bearer token",
+            body = GenericErrorResponse,
+            example = json!({"type":"http://0.0.0.0:8080/unauthorized","title":"Unauthorized","status":401})
+        ),
+        (
+            status = FORBIDDEN,
+            description = "Caller is not allowed to perform the operation",
+            body = GenericErrorResponse,
+            example = json!({"type":"http://0.0.0.0:8080/forbidden","title":"Forbidden","status":403})
+        ),
         (
             status = OK,
             body = OrderModel,
@@ -59,6 +75,7 @@ use crate::{
 pub async fn get_order_by_id_endpoint<T>(
     shared_state: web::Data<AM<T>>,
     req: HttpRequest,
+    user: AuthenticatedUser,
 ) -> HttpResponse
 where
     T: GetOrderById + Send + Debug,
@@ -69,6 +86,7 @@ where
 
     match ShopOrderId::validated(id, error_list.clone()) {
         Some(order_id) => match shared_state.lock().await.execute(&order_id).await {
+            Ok(it) if !user.can_access(&it.customer_id) => forbidden(),
             Ok(it) => HttpResponse::Ok()
                 .content_type(ContentType::json())
                 .body(serde_json::to_string(&ToModel::<OrderModel>::to_model(it)).unwrap()),
@@ -92,7 +110,9 @@ where
 {
     cfg.route(
         API_V1_ORDER_GET_BY_ID,
-        web::get().to(get_order_by_id_endpoint::<T>),
+        web::get()
+            .to(get_order_by_id_endpoint::<T>)
+            .wrap(from_fn(require_authentication)),
     );
 }
 
@@ -100,14 +120,22 @@ where
 mod tests {
     use actix_web::{body::MessageBody, http::StatusCode, test::TestRequest, web::Data};
     use common::{
-        common_rest::{GenericErrorResponse, not_found_type_url},
+        common_rest::{GenericErrorResponse, forbidden_type_url, not_found_type_url},
         types::base::AMTrait,
     };
-    use domain::{order::shop_order::OrderState, test_fixtures::*};
+    use domain::{
+        order::{shop_order::OrderState, value_objects::fulfilment::Fulfilment},
+        test_fixtures::*,
+    };
     use dotenvy::dotenv;
+    use time::{OffsetDateTime, format_description::well_known::Rfc3339};
+    use usecase::{auth::role::Role, order::dto::order_details::AsDetails};
 
     use super::*;
-    use crate::test_fixtures::{MockGetOrderById, rnd_order_details};
+    use crate::{
+        order::order_model::FulfilmentModel,
+        test_fixtures::{MockGetOrderById, kitchen_staff, rnd_order_details},
+    };
 
     #[actix_web::test]
     async fn order_not_found() {
@@ -124,7 +152,7 @@ mod tests {
             .param("id", order_id.to_i64().to_string())
             .to_http_request();
 
-        let resp = get_order_by_id_endpoint(mock_shared_state, req).await;
+        let resp = get_order_by_id_endpoint(mock_shared_state, req, kitchen_staff()).await;
 
         assert_eq!(resp.status(), StatusCode::NOT_FOUND);
 
@@ -158,7 +186,7 @@ mod tests {
             .param("id", details.id.to_i64().to_string())
             .to_http_request();
 
-        let resp = get_order_by_id_endpoint(mock_shared_state, req).await;
+        let resp = get_order_by_id_endpoint(mock_shared_state, req, kitchen_staff()).await;
 
         assert_eq!(resp.status(), StatusCode::OK);
 
@@ -169,12 +197,12 @@ mod tests {
 
         assert_eq!(response_dto.id, details.id.to_i64());
         assert_eq!(
-            response_dto.address.street,
-            details.address.street_to_string()
+            response_dto.fulfilment,
+            details.fulfilment.clone().to_model()
         );
         assert_eq!(
-            response_dto.address.building,
-            details.address.building_to_i16()
+            response_dto.delivery_fee,
+            details.delivery_fee.to_string_value()
         );
         assert_eq!(response_dto.total_price, details.total.to_string_value());
         assert_eq!(response_dto.items.len(), 1);
@@ -210,7 +238,7 @@ mod tests {
             .param("id", details.id.to_i64().to_string())
             .to_http_request();
 
-        let resp = get_order_by_id_endpoint(mock_shared_state, req).await;
+        let resp = get_order_by_id_endpoint(mock_shared_state, req, kitchen_staff()).await;
 
         assert_eq!(resp.status(), StatusCode::OK);
 
@@ -221,12 +249,12 @@ mod tests {
 
         assert_eq!(response_dto.id, details.id.to_i64());
         assert_eq!(
-            response_dto.address.street,
-            details.address.street_to_string()
+            response_dto.fulfilment,
+            details.fulfilment.clone().to_model()
         );
         assert_eq!(
-            response_dto.address.building,
-            details.address.building_to_i16()
+            response_dto.delivery_fee,
+            details.delivery_fee.to_string_value()
         );
         assert_eq!(response_dto.total_price, details.total.to_string_value());
         assert_eq!(response_dto.items.len(), 1);
@@ -245,4 +273,130 @@ mod tests {
             .await
             .verify_invoked(&details.id);
     }
+
+    #[actix_web::test]
+    async fn returned_successfully_pickup_order_without_address() {
+        let pickup_point_id = rnd_pickup_point_id();
+        let details = order_with_fulfilment_and_state(
+            Fulfilment::Pickup(pickup_point_id),
+            OrderState::new_paid(),
+        )
+        .as_details();
+
+        let mock_get_order_by_id = AM::new_am(MockGetOrderById {
+            id: rnd_order_id(),
+            response: Ok(details.clone()),
+        });
+
+        let mock_shared_state = Data::new(mock_get_order_by_id.clone());
+
+        let req = TestRequest::default()
+            .param("id", details.id.to_i64().to_string())
+            .to_http_request();
+
+        let resp = get_order_by_id_endpoint(mock_shared_state, req, kitchen_staff()).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let body = resp.into_body().try_into_bytes().unwrap();
+        let body_text = std::str::from_utf8(&body).unwrap();
+
+        let response_json: serde_json::Value = serde_json::from_str(body_text).unwrap();
+        assert_eq!(response_json["fulfilment"]["type"], "pickup");
+        assert_eq!(
+            response_json["fulfilment"]["pickup_point_id"],
+            pickup_point_id.to_i64()
+        );
+        assert!(response_json["fulfilment"].get("address").is_none());
+
+        let response_dto: OrderModel = serde_json::from_str(body_text).unwrap();
+        assert_eq!(
+            response_dto.fulfilment,
+            FulfilmentModel::Pickup {
+                pickup_point_id: pickup_point_id.to_i64()
+            }
+        );
+        assert_eq!(
+            response_dto.delivery_fee,
+            details.delivery_fee.to_string_value()
+        );
+    }
+
+    #[actix_web::test]
+    async fn returned_successfully_scheduled_order() {
+        let slot = rnd_delivery_slot();
+        let details = order_with_delivery_slot_and_state(slot, OrderState::new_paid()).as_details();
+
+        let mock_get_order_by_id = AM::new_am(MockGetOrderById {
+            id: rnd_order_id(),
+            response: Ok(details.clone()),
+        });
+
+        let mock_shared_state = Data::new(mock_get_order_by_id.clone());
+
+        let req = TestRequest::default()
+            .param("id", details.id.to_i64().to_string())
+            .to_http_request();
+
+        let resp = get_order_by_id_endpoint(mock_shared_state, req, kitchen_staff()).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let body = resp.into_body().try_into_bytes().unwrap();
+        let body_text = std::str::from_utf8(&body).unwrap();
+
+        let response_dto: OrderModel = serde_json::from_str(body_text).unwrap();
+        assert_eq!(response_dto.delivery_slot, Some(slot.to_model()));
+        assert_eq!(
+            OffsetDateTime::parse(&response_dto.delivery_slot.unwrap().start, &Rfc3339).unwrap(),
+            *slot.start()
+        );
+    }
+
+    #[actix_web::test]
+    async fn returned_successfully_to_customer_owning_order() {
+        let details = rnd_order_details(OrderState::new_paid());
+
+        let mock_get_order_by_id = AM::new_am(MockGetOrderById {
+            id: rnd_order_id(),
+            response: Ok(details.clone()),
+        });
+
+        let mock_shared_state = Data::new(mock_get_order_by_id.clone());
+
+        let req = TestRequest::default()
+            .param("id", details.id.to_i64().to_string())
+            .to_http_request();
+
+        let user = AuthenticatedUser::new(details.customer_id, Role::Customer);
+        let resp = get_order_by_id_endpoint(mock_shared_state, req, user).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+    }
+
+    #[actix_web::test]
+    async fn order_of_another_customer_is_forbidden() {
+        dotenv().ok();
+        let details = rnd_order_details(OrderState::new_paid());
+
+        let mock_get_order_by_id = AM::new_am(MockGetOrderById {
+            id: rnd_order_id(),
+            response: Ok(details.clone()),
+        });
+
+        let mock_shared_state = Data::new(mock_get_order_by_id.clone());
+
+        let req = TestRequest::default()
+            .param("id", details.id.to_i64().to_string())
+            .to_http_request();
+
+        let user = AuthenticatedUser::new(rnd_customer_id(), Role::Customer);
+        let resp = get_order_by_id_endpoint(mock_shared_state, req, user).await;
+
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+
+        let body = resp.into_body().try_into_bytes().unwrap();
+        let response_dto: GenericErrorResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(&response_dto.response_type, &forbidden_type_url());
+    }
 }
@@ -0,0 +1,413 @@
+use std::{collections::VecDeque, convert::Infallible, fmt::Debug, time::Duration};
+
+use actix_web::{
+    HttpRequest, HttpResponse,
+    http::header::{CACHE_CONTROL, CONTENT_TYPE},
+    middleware::from_fn,
+    web::{self, Bytes},
+};
+use common::{
+    common_rest::{
+        GenericErrorResponse, ValidationError, forbidden, get_json_from_http_response,
+        resource_not_found, to_invalid_param_bad_request,
+    },
+    types::base::{AM, EventId, RCell, RcRefCellTrait},
+};
+use domain::order::{
+    customer_order_events::ShopOrderEventEnum, shop_order::OrderState,
+    value_objects::shop_order_id::ShopOrderId,
+};
+use futures_util::{Stream, stream};
+use tokio::{
+    sync::broadcast::Receiver,
+    time::{Instant, Interval, interval_at},
+};
+use usecase::order::{GetOrderById, order_event_feed::OrderEventFeed};
+
+use crate::{
+    auth::{authenticated_user::AuthenticatedUser, jwt_middleware::require_authentication},
+    endpoint_url::API_V1_ORDER_EVENTS_BY_ID,
+    order::order_model::{OrderStateChangedModel, ToModel},
+    to_error::ToRestError,
+    validated::Validated,
+};
+
+/// Sent by the browser on reconnect with the id of the last received event
+const LAST_EVENT_ID: &str = "Last-Event-ID";
+
+/// Comments are sent this often to keep idle connections open
+const KEEP_ALIVE_INTERVAL: Duration = Duration::from_secs(15);
+
+/// Stream the state changes of an order
+///
+/// Server-Sent Events with `OrderStateChangedModel` data, the stream ends after the order is
+/// completed or cancelled. Events missed since `Last-Event-ID` are sent first while they're still
+/// kept. Customers may only follow their own orders, staff may follow any
+#[utoipa::path(
+    get,
+    path = API_V1_ORDER_EVENTS_BY_ID,
+    tag = "Order",
+    security(("bearerAuth" = [])),
+    responses(
+        (
+            status = UNAUTHORIZED,
+            description = "Missing or invalid bearer token",
+            body = GenericErrorResponse,
+            example = json!({"type":"http://0.0.0.0:8080/unauthorized","title":"Unauthorized","status":401})
+        ),
+        (
+            status = FORBIDDEN,
+            description = "Caller is not allowed to perform the operation",
+            body = GenericErrorResponse,
+            example = json!({"type":"http://0.0.0.0:8080/forbidden","title":"Forbidden","status":403})
+        ),
+        (
+            status = OK,
+            content_type = "text/event-stream",
+            body = OrderStateChangedModel,
+            description = "Stream of the state changes"
+        ),
+        (
+            status = BAD_REQUEST,
+            description = "Bad request",
+            body = GenericErrorResponse,
+            example = json!(
+                {
+                    "type":"http://0.0.0.0:8080/bad_request",
+                    "title":"Bad request",
+                    "status":400,
+                    "invalid_params":
+                    [
+                        {"message": "Wrong Last-Event-ID"}
+                    ]
+                }
+            )
+        ),
+        (
+            status = NOT_FOUND,
+            description = "Order not found",
+            body = GenericErrorResponse,
+            example = json!(&(get_json_from_http_response(resource_not_found())))
+        ),
+    ),
+    params(
+        ("id" = i64, description = "id"),
+        ("Last-Event-ID" = Option<String>, Header, description = "Id of the last received event"),
+    )
+)]
+pub async fn get_order_events_endpoint<T>(
+    shared_state: web::Data<AM<T>>,
+    feed: web::Data<OrderEventFeed>,
+    req: HttpRequest,
+    user: AuthenticatedUser,
+) -> HttpResponse
+where
+    T: GetOrderById + Send + Debug,
+{
+    let id: i64 = req.match_info().get("id").unwrap().parse().unwrap();
+
+    let error_list = RCell::new_rc(vec![]);
+
+    match (
+        ShopOrderId::validated(id, error_list.clone()),
+        last_event_id(&req, error_list.clone()),
+    ) {
+        (Some(order_id), Ok(last_event_id)) => {
+            // Subscribed before reading the order, so no change can slip in between
+            let (missed, receiver) = match last_event_id {
+                Some(last_event_id) => feed.subscribe_after(&last_event_id),
+                None => (vec![], feed.subscribe()),
+            };
+            match shared_state.lock().await.execute(&order_id).await {
+                Ok(it) if !user.can_access(&it.customer_id) => forbidden(),
+                Ok(it) => {
+                    let finished = matches!(
+                        it.state,
+                        OrderState::Completed(_) | OrderState::Cancelled(_)
+                    );
+                    HttpResponse::Ok()
+                        .insert_header((CONTENT_TYPE, "text/event-stream"))
+                        .insert_header((CACHE_CONTROL, "no-cache"))
+                        .streaming(order_events(
+                            order_id,
+                            missed,
+                            receiver,
+                            finished,
+                            KEEP_ALIVE_INTERVAL,
+                        ))
+                }
+                Err(e) => e.to_rest_error(),
+            }
+        }
+        _ => to_invalid_param_bad_request(error_list),
+    }
+}
+
+/// `Ok(None)` when the header is absent
+fn last_event_id(
+    req: &HttpRequest,
+    error_list: RCell<Vec<ValidationError>>,
+) -> Result<Option<EventId>, ()> {
+    match req.headers().get(LAST_EVENT_ID) {
+        Some(value) => match value.to_str().ok().and_then(|it| it.parse().ok()) {
+            Some(event_id) => Ok(Some(event_id)),
+            None => {
+                error_list
+                    .borrow_mut()
+                    .push(ValidationError::new("Wrong Last-Event-ID"));
+                Err(())
+            }
+        },
+        None => Ok(None),
+    }
+}
+
+struct OrderEvents {
+    order_id: ShopOrderId,
+    pending: VecDeque<ShopOrderEventEnum>,
+    receiver: Receiver<ShopOrderEventEnum>,
+    keep_alive: Interval,
+    /// The stream ends once the pending events are sent
+    finished: bool,
+}
+
+/// The missed events followed by the received ones, only the events of the order are sent
+fn order_events(
+    order_id: ShopOrderId,
+    missed: Vec<ShopOrderEventEnum>,
+    receiver: Receiver<ShopOrderEventEnum>,
+    finished: bool,
+    keep_alive_interval: Duration,
+) -> impl Stream<Item = Result<Bytes, Infallible>> {
+    let state = OrderEvents {
+        order_id,
+        pending: missed.into(),
+        receiver,
+        keep_alive: interval_at(Instant::now() + keep_alive_interval, keep_alive_interval),
+        finished,
+    };
+    stream::unfold(state, |mut state| async move {
+        loop {
+            if let Some(event) = state.pending.pop_front() {
+                if event.order_id() != state.order_id {
+                    continue;
+                }
+                state.finished |= matches!(
+                    event,
+                    ShopOrderEventEnum::ShopOrderCompletedDomainEvent(_)
+                        | ShopOrderEventEnum::ShopOrderCancelledDomainEvent(_)
+                );
+                return Some((Ok(event_message(&event)), state));
+            }
+            if state.finished {
+                return None;
+            }
+            tokio::select! {
+                event = state.receiver.recv() => match event {
+                    Ok(event) => state.pending.push_back(event),
+                    // A client left behind reconnects and catches up with Last-Event-ID
+                    Err(_) => return None,
+                },
+                _ = state.keep_alive.tick() => {
+                    return Some((Ok(Bytes::from_static(b": keep-alive\n\n")), state));
+                }
+            }
+        }
+    })
+}
+
+fn event_message(event: &ShopOrderEventEnum) -> Bytes {
+    let data: OrderStateChangedModel = event.to_model();
+    Bytes::from(format!(
+        "id: {}\ndata: {}\n\n",
+        event.event_id(),
+        serde_json::to_string(&data).unwrap()
+    ))
+}
+
+pub fn get_order_events_endpoint_config<T>(cfg: &mut web::ServiceConfig)
+where
+    T: GetOrderById + 'static,
+{
+    cfg.route(
+        API_V1_ORDER_EVENTS_BY_ID,
+        web::get()
+            .to(get_order_events_endpoint::<T>)
+            .wrap(from_fn(require_authentication)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use std::pin::pin;
+
+    use actix_web::{
+        body::{MessageBody, to_bytes},
+        http::StatusCode,
+        test::TestRequest,
+        web::Data,
+    };
+    use common::types::base::AMTrait;
+    use domain::{
+        order::customer_order_events::{
+            ShopOrderCancelledDomainEvent, ShopOrderConfirmedDomainEvent, ShopOrderPaidDomainEvent,
+        },
+        test_fixtures::*,
+    };
+    use dotenvy::dotenv;
+    use futures_util::StreamExt;
+    use usecase::{auth::role::Role, order::dto::order_details::OrderDetails};
+
+    use super::*;
+    use crate::{
+        order::order_model::OrderStateModel,
+        test_fixtures::{MockGetOrderById, rnd_order_details},
+    };
+
+    fn shared_state(details: &OrderDetails) -> Data<AM<MockGetOrderById>> {
+        Data::new(AM::new_am(MockGetOrderById {
+            id: rnd_order_id(),
+            response: Ok(details.clone()),
+        }))
+    }
+
+    fn owner(details: &OrderDetails) -> AuthenticatedUser {
+        AuthenticatedUser::new(details.customer_id, Role::Customer)
+    }
+
+    fn data_lines(body: &str) -> Vec<OrderStateChangedModel> {
+        body.lines()
+            .filter_map(|line| line.strip_prefix("data: "))
+            .map(|data| serde_json::from_str(data).unwrap())
+            .collect()
+    }
+
+    #[actix_web::test]
+    async fn state_changes_are_streamed_until_order_is_cancelled() {
+        let details = rnd_order_details(OrderState::new_paid());
+        let feed = Data::new(OrderEventFeed::default());
+        let req = TestRequest::default()
+            .param("id", details.id.to_i64().to_string())
+            .to_http_request();
+
+        let resp =
+            get_order_events_endpoint(shared_state(&details), feed.clone(), req, owner(&details))
+                .await;
+        assert_eq!(resp.status(), StatusCode::OK);
+        assert_eq!(
+            resp.headers().get(CONTENT_TYPE).unwrap(),
+            "text/event-stream"
+        );
+
+        let confirmed: ShopOrderEventEnum = ShopOrderConfirmedDomainEvent::new(details.id).into();
+        feed.publish(&confirmed);
+        feed.publish(&ShopOrderPaidDomainEvent::new(rnd_order_id()).into());
+        feed.publish(&ShopOrderCancelledDomainEvent::new(details.id).into());
+
+        let body = to_bytes(resp.into_body()).await.unwrap();
+        let body = std::str::from_utf8(&body).unwrap();
+        assert!(body.starts_with(&format!("id: {}\n", confirmed.event_id())));
+        assert_eq!(
+            data_lines(body),
+            vec![
+                OrderStateChangedModel {
+                    order_id: details.id.to_i64(),
+                    state: OrderStateModel::Confirmed
+                },
+                OrderStateChangedModel {
+                    order_id: details.id.to_i64(),
+                    state: OrderStateModel::Cancelled
+                },
+            ]
+        );
+    }
+
+    #[actix_web::test]
+    async fn missed_events_are_resumed_after_last_event_id() {
+        let details = rnd_order_details(OrderState::new_cancelled());
+        let feed = Data::new(OrderEventFeed::default());
+        let paid: ShopOrderEventEnum = ShopOrderPaidDomainEvent::new(details.id).into();
+        feed.publish(&paid);
+        feed.publish(&ShopOrderConfirmedDomainEvent::new(details.id).into());
+        feed.publish(&ShopOrderCancelledDomainEvent::new(details.id).into());
+
+        let req = TestRequest::default()
+            .param("id", details.id.to_i64().to_string())
+            .insert_header((LAST_EVENT_ID, paid.event_id().to_string()))
+            .to_http_request();
+
+        let resp =
+            get_order_events_endpoint(shared_state(&details), feed, req, owner(&details)).await;
+        assert_eq!(resp.status(), StatusCode::OK);
+
+        let body = to_bytes(resp.into_body()).await.unwrap();
+        let states: Vec<OrderStateModel> = data_lines(std::str::from_utf8(&body).unwrap())
+            .into_iter()
+            .map(|it| it.state)
+            .collect();
+        assert_eq!(
+            states,
+            vec![OrderStateModel::Confirmed, OrderStateModel::Cancelled]
+        );
+    }
+
+    #[actix_web::test]
+    async fn wrong_last_event_id() {
+        dotenv().ok();
+        let details = rnd_order_details(OrderState::new_paid());
+        let req = TestRequest::default()
+            .param("id", details.id.to_i64().to_string())
+            .insert_header((LAST_EVENT_ID, "last"))
+            .to_http_request();
+
+        let resp = get_order_events_endpoint(
+            shared_state(&details),
+            Data::new(OrderEventFeed::default()),
+            req,
+            owner(&details),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+
+    #[actix_web::test]
+    async fn events_of_another_customer_are_forbidden() {
+        dotenv().ok();
+        let details = rnd_order_details(OrderState::new_paid());
+        let req = TestRequest::default()
+            .param("id", details.id.to_i64().to_string())
+            .to_http_request();
+
+        let user = AuthenticatedUser::new(rnd_customer_id(), Role::Customer);
+        let resp = get_order_events_endpoint(
+            shared_state(&details),
+            Data::new(OrderEventFeed::default()),
+            req,
+            user,
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::FORBIDDEN);
+        let body = resp.into_body().try_into_bytes().unwrap();
+        let response_dto: GenericErrorResponse = serde_json::from_slice(&body).unwrap();
+        assert_eq!(response_dto.response_status, 403);
+    }
+
+    #[actix_web::test]
+    async fn idle_stream_is_kept_alive() {
+        let feed = OrderEventFeed::default();
+        let mut events = pin!(order_events(
+            rnd_order_id(),
+            vec![],
+            feed.subscribe(),
+            false,
+            Duration::from_millis(10),
+        ));
+
+        assert_eq!(
+            events.next().await.unwrap().unwrap(),
+            Bytes::from_static(b": keep-alive\n\n")
+        );
+    }
+}
@@ -1,27 +1,60 @@
-use std::fmt::Debug;
+use std::{collections::HashMap, fmt::Debug, str::FromStr};
 
-use actix_web::{HttpRequest, HttpResponse, http::header::ContentType, web};
+use actix_web::{
+    HttpRequest, HttpResponse,
+    http::header::ContentType,
+    middleware::from_fn,
+    web::{self, Query},
+};
+use bigdecimal::BigDecimal;
 use common::{
     common_rest::{
         CursorPagedModel, GenericErrorResponse, ValidationError, to_invalid_param_bad_request,
     },
     types::base::{AM, RCell, RcRefCellTrait},
 };
-use domain::order::value_objects::shop_order_id::ShopOrderId;
-use usecase::order::{GetOrders, GetOrdersUseCaseError};
+use domain::{
+    cart::value_objects::customer_id::CustomerId,
+    menu::value_objects::price::Price,
+    order::{shop_order::OrderState, value_objects::shop_order_id::ShopOrderId},
+};
+use time::OffsetDateTime;
+use usecase::order::{
+    GetOrders, GetOrdersUseCaseError,
+    access::shop_order_criteria::{ShopOrderCriteria, ShopOrderSortField, SortDirection},
+};
 
 use super::{
-    order_model::{OrderModel, ToModel},
+    order_model::{OrderModel, OrderStateModel, ToModel},
     validated::validate_query_string,
 };
-use crate::{endpoint_url::API_V1_ORDER_GET_ALL, to_error::ToRestError, validated::Validated};
+use crate::{
+    auth::jwt_middleware::require_kitchen_staff, endpoint_url::API_V1_ORDER_GET_ALL,
+    to_error::ToRestError, validated::Validated,
+};
 
-/// Get orders with pagination
+/// Get orders with pagination, filtering and sorting
+///
+/// The next page starts at `next_page_id` with the same filters and sorting.
+/// Requires the `kitchen_staff` or `admin` role
 #[utoipa::path(
     get,
     path = API_V1_ORDER_GET_ALL,
     tag = "Order",
+    security(("bearerAuth" = ["kitchen_staff", "admin"])),
     responses(
+        (
+            status = UNAUTHORIZED,
+            description = "Missing or invalid bearer token",
+            body = GenericErrorResponse,
+            example = json!({"type":"http://0.0.0.0:8080/unauthorized","title":"Unauthorized","status":401})
+        ),
+        (
+            status = FORBIDDEN,
+            description = "Caller is not allowed to perform the operation",
+            body = GenericErrorResponse,
+            example = json!({"type":"http://0.0.0.0:8080/forbidden","title":"Forbidden","status":403})
+        ),
         (
             status = OK,
             body = Vec<OrderModel>,
@@ -47,7 +80,14 @@ use crate::{endpoint_url::API_V1_ORDER_GET_ALL, to_error::ToRestError, validated
     ),
     params(
         ("limit" = usize, Query, description = "Pagination limit"),
-        ("startId" = i64, Query, description = "Pagination start ID")
+        ("startId" = i64, Query, description = "Pagination start ID"),
+        ("state" = Option<OrderStateModel>, Query, description = "Only orders in the state"),
+        ("customerId" = Option<String>, Query, description = "Only orders of the customer"),
+        ("createdFrom" = Option<String>, Query, description = "Created at or after, RFC 3339"),
+        ("createdTo" = Option<String>, Query, description = "Created before, RFC 3339"),
+        ("minTotal" = Option<String>, Query, description = "Minimal order total"),
+        ("sort" = Option<String>, Query, description = "Sort by 'id' (default), 'created' or 'total'"),
+        ("direction" = Option<String>, Query, description = "Sort direction 'asc' (default) or 'desc'")
     )
 )]
 pub async fn get_orders_endpoint<T: GetOrders + Send + Debug>(
@@ -61,13 +101,14 @@ pub async fn get_orders_endpoint<T: GetOrders + Send + Debug>(
             Ok(id) => ShopOrderId::validated(id, error_list.clone()),
             Err(_) => None,
         },
-        validate_query_string::<usize>(req, "limit", error_list.clone()),
+        validate_query_string::<usize>(req.clone(), "limit", error_list.clone()),
+        validate_criteria(&req, error_list.clone()),
     ) {
-        (Some(start_id), Ok(limit)) => {
+        (Some(start_id), Ok(limit), Some(criteria)) => {
             match shared_state
                 .lock()
                 .await
-                .execute(&start_id, limit + 1)
+                .execute(&criteria, &start_id, limit + 1)
                 .await
             {
                 Ok(order_details_list) => {
@@ -88,10 +129,54 @@ pub async fn get_orders_endpoint<T: GetOrders + Send + Debug>(
                 Err(e) => e.to_rest_error(),
             }
         }
-        (_, _) => to_invalid_param_bad_request(error_list),
+        (_, _, _) => to_invalid_param_bad_request(error_list),
     }
 }
 
+fn validate_criteria(
+    req: &HttpRequest,
+    error_list: RCell<Vec<ValidationError>>,
+) -> Option<ShopOrderCriteria> {
+    let query_params = Query::<HashMap<String, String>>::from_query(req.query_string()).unwrap();
+    let param = |name: &str| query_params.get(name).map(String::as_str);
+    let errors_before = error_list.borrow().len();
+
+    let criteria = ShopOrderCriteria {
+        state: param("state").and_then(|it| OrderState::validated(it, error_list.clone())),
+        customer_id: param("customerId")
+            .and_then(|it| CustomerId::validated(it, error_list.clone())),
+        created_from: param("createdFrom")
+            .and_then(|it| OffsetDateTime::validated(it, error_list.clone())),
+        created_to: param("createdTo")
+            .and_then(|it| OffsetDateTime::validated(it, error_list.clone())),
+        min_total: param("minTotal").and_then(|it| match BigDecimal::from_str(it) {
+            Ok(value) => Price::validated(value, error_list.clone()),
+            Err(_) => {
+                error_list
+                    .borrow_mut()
+                    .push(ValidationError::new("Min total must be a decimal number"));
+                None
+            }
+        }),
+        sort_by: param("sort")
+            .and_then(|it| ShopOrderSortField::validated(it, error_list.clone()))
+            .unwrap_or_default(),
+        direction: param("direction")
+            .and_then(|it| SortDirection::validated(it, error_list.clone()))
+            .unwrap_or_default(),
+    };
+
+    if let (Some(from), Some(to)) = (criteria.created_from, criteria.created_to)
+        && from >= to
+    {
+        error_list
+            .borrow_mut()
+            .push(ValidationError::new("createdFrom must be before createdTo"));
+    }
+
+    (error_list.borrow().len() == errors_before).then_some(criteria)
+}
+
 impl ToRestError for GetOrdersUseCaseError {
     fn to_rest_error(self) -> HttpResponse {
         match self {
@@ -113,7 +198,9 @@ where
 {
     cfg.route(
         API_V1_ORDER_GET_ALL,
-        web::get().to(get_orders_endpoint::<T>),
+        web::get()
+            .to(get_orders_endpoint::<T>)
+            .wrap(from_fn(require_kitchen_staff)),
     );
 }
 
@@ -126,6 +213,7 @@ mod tests {
     };
     use domain::test_fixtures::*;
     use dotenvy::dotenv;
+    use time::format_description::well_known::Rfc3339;
 
     use super::*;
     use crate::test_fixtures::{MockGetOrders, rnd_order_details};
@@ -138,6 +226,7 @@ mod tests {
 
         let mock_get_orders = AM::new_am(MockGetOrders {
             response: Err(GetOrdersUseCaseError::new_limit_exceed(limit + 1)),
+            criteria: Default::default(),
             start_id,
             limit,
         });
@@ -184,6 +273,7 @@ mod tests {
 
         let mock_get_orders = AM::new_am(MockGetOrders {
             response: Ok(vec![single.clone()]),
+            criteria: Default::default(),
             start_id: single.id,
             limit,
         });
@@ -211,12 +301,12 @@ mod tests {
         );
         assert_eq!(response_dto.list[0].version, single.version.to_i64());
         assert_eq!(
-            response_dto.list[0].address.street,
-            single.address.street_to_string()
+            response_dto.list[0].fulfilment,
+            single.fulfilment.clone().to_model()
         );
         assert_eq!(
-            response_dto.list[0].address.building,
-            single.address.building_to_i16()
+            response_dto.list[0].delivery_fee,
+            single.delivery_fee.to_string_value()
         );
         assert_eq!(response_dto.list[0].items.len(), 1);
         assert_eq!(
@@ -244,6 +334,7 @@ mod tests {
 
         let mock_get_orders = AM::new_am(MockGetOrders {
             response: Ok(vec![first.clone(), second]),
+            criteria: Default::default(),
             start_id: first.id,
             limit,
         });
@@ -273,12 +364,12 @@ mod tests {
         );
         assert_eq!(response_dto.list[0].version, first.version.to_i64());
         assert_eq!(
-            response_dto.list[0].address.street,
-            first.address.street_to_string()
+            response_dto.list[0].fulfilment,
+            first.fulfilment.clone().to_model()
         );
         assert_eq!(
-            response_dto.list[0].address.building,
-            first.address.building_to_i16()
+            response_dto.list[0].delivery_fee,
+            first.delivery_fee.to_string_value()
         );
         assert_eq!(response_dto.list[0].items.len(), 1);
         assert_eq!(
@@ -294,4 +385,128 @@ mod tests {
             .await
             .verify_invoked(&first.id, &(limit + 1));
     }
+
+    #[actix_web::test]
+    async fn filters_and_sorting_passed_to_use_case() {
+        dotenv().ok();
+        let start_id = rnd_order_id();
+        let customer_id = rnd_customer_id();
+        let limit = 5;
+
+        let mock_get_orders = AM::new_am(MockGetOrders {
+            response: Ok(vec![]),
+            criteria: Default::default(),
+            start_id,
+            limit,
+        });
+
+        let mock_shared_state = Data::new(mock_get_orders.clone());
+        let req = TestRequest::default()
+            .uri(&format!(
+                "/?startId={}&limit={}&state=paid&customerId={}\
+                 &createdFrom=2024-05-01T00:00:00Z&createdTo=2024-06-01T00:00:00Z\
+                 &minTotal=10.5&sort=total&direction=desc",
+                start_id.to_i64(),
+                limit,
+                customer_id
+            ))
+            .to_http_request();
+
+        let resp = get_orders_endpoint(mock_shared_state, req).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let expected_criteria = ShopOrderCriteria {
+            state: Some(OrderState::new_paid()),
+            customer_id: Some(customer_id),
+            created_from: Some(OffsetDateTime::parse("2024-05-01T00:00:00Z", &Rfc3339).unwrap()),
+            created_to: Some(OffsetDateTime::parse("2024-06-01T00:00:00Z", &Rfc3339).unwrap()),
+            min_total: Some(Price::try_from(BigDecimal::from_str("10.5").unwrap()).unwrap()),
+            sort_by: ShopOrderSortField::Total,
+            direction: SortDirection::Desc,
+        };
+        mock_get_orders.lock().await.verify_invoked_with_criteria(
+            &expected_criteria,
+            &start_id,
+            &(limit + 1),
+        );
+    }
+
+    #[actix_web::test]
+    async fn invalid_filters() {
+        dotenv().ok();
+        let start_id = rnd_order_id();
+        let limit = 5;
+
+        let mock_get_orders = AM::new_am(MockGetOrders {
+            response: Ok(vec![]),
+            criteria: Default::default(),
+            start_id,
+            limit,
+        });
+
+        let mock_shared_state = Data::new(mock_get_orders.clone());
+        let req = TestRequest::default()
+            .uri(&format!(
+                "/?startId={}&limit={}&state=lost&createdFrom=yesterday\
+                 &minTotal=abc&sort=name&direction=up",
+                start_id.to_i64(),
+                limit
+            ))
+            .to_http_request();
+
+        let resp = get_orders_endpoint(mock_shared_state, req).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+        let body = resp.into_body().try_into_bytes().unwrap();
+        let response_dto: GenericErrorResponse =
+            serde_json::from_str(std::str::from_utf8(&body).unwrap()).unwrap();
+        let messages: Vec<&str> = response_dto
+            .invalid_params
+            .iter()
+            .map(|it| it.message.as_str())
+            .collect();
+        assert_eq!(
+            messages,
+            vec![
+                "Unknown order state 'lost'",
+                "Date and time must be in RFC 3339 format",
+                "Min total must be a decimal number",
+                "Sort must be one of 'id', 'created', 'total'",
+                "Direction must be 'asc' or 'desc'",
+            ]
+        );
+    }
+
+    #[actix_web::test]
+    async fn created_range_is_empty() {
+        dotenv().ok();
+        let start_id = rnd_order_id();
+
+        let mock_get_orders = AM::new_am(MockGetOrders {
+            response: Ok(vec![]),
+            criteria: Default::default(),
+            start_id,
+            limit: 1,
+        });
+
+        let mock_shared_state = Data::new(mock_get_orders.clone());
+        let req = TestRequest::default()
+            .uri(&format!(
+                "/?startId={}&limit=1&createdFrom=2024-06-01T00:00:00Z&createdTo=2024-05-01T00:00:00Z",
+                start_id.to_i64()
+            ))
+            .to_http_request();
+
+        let resp = get_orders_endpoint(mock_shared_state, req).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        let body = resp.into_body().try_into_bytes().unwrap();
+        let response_dto: GenericErrorResponse =
+            serde_json::from_str(std::str::from_utf8(&body).unwrap()).unwrap();
+        assert_eq!(
+            response_dto.invalid_params.first().unwrap().message,
+            "createdFrom must be before createdTo"
+        );
+    }
 }
@@ -0,0 +1,445 @@
+use actix_web::{HttpRequest, HttpResponse, middleware::from_fn, rt, web};
+use actix_ws::{AggregatedMessage, AggregatedMessageStream, Session};
+use common::{common_rest::GenericErrorResponse, types::base::AM};
+use domain::order::{
+    customer_order_events::ShopOrderEventEnum, value_objects::shop_order_id::ShopOrderId,
+};
+use tokio::sync::broadcast::{Receiver, error::RecvError};
+use usecase::order::{
+    CompleteOrder, ConfirmOrder, GetActiveOrders, order_event_feed::OrderEventFeed,
+};
+
+use crate::{
+    auth::jwt_middleware::require_kitchen_staff,
+    endpoint_url::API_V1_KITCHEN_DISPLAY,
+    order::{
+        kitchen_display_model::{
+            KitchenCommandAckModel, KitchenCommandModel, KitchenCommandTypeModel,
+            KitchenDisplayMessage,
+        },
+        order_model::ToModel,
+    },
+};
+
+/// Live feed of the active orders for the kitchen display
+///
+/// WebSocket with `KitchenDisplayMessage` text messages. A snapshot of the paid and not yet
+/// completed orders is sent on connect, followed by their state changes. The display may send
+/// `KitchenCommandModel` messages to start preparing orders or mark them ready, each one is
+/// answered with an acknowledgement.
+///
+/// Requires the `kitchen_staff` or `admin` role
+#[utoipa::path(
+    get,
+    path = API_V1_KITCHEN_DISPLAY,
+    tag = "Order",
+    security(("bearerAuth" = ["kitchen_staff", "admin"])),
+    responses(
+        (
+            status = UNAUTHORIZED,
+            description = "Missing or invalid bearer token",
+            body = GenericErrorResponse,
+            example = json!({"type":"http://0.0.0.0:8080/unauthorized","title":"Unauthorized","status":401})
+        ),
+        (
+            status = FORBIDDEN,
+            description = "Caller is not allowed to perform the operation",
+            body = GenericErrorResponse,
+            example = json!({"type":"http://0.0.0.0:8080/forbidden","title":"Forbidden","status":403})
+        ),
+        (
+            status = SWITCHING_PROTOCOLS,
+            body = KitchenDisplayMessage,
+            description = "WebSocket connection is established"
+        ),
+        (
+            status = BAD_REQUEST,
+            description = "Not a WebSocket handshake"
+        ),
+    )
+)]
+pub async fn kitchen_display_endpoint<A, C, P>(
+    active_orders: web::Data<AM<A>>,
+    confirm_order: web::Data<AM<C>>,
+    complete_order: web::Data<AM<P>>,
+    feed: web::Data<OrderEventFeed>,
+    req: HttpRequest,
+    body: web::Payload,
+) -> HttpResponse
+where
+    A: GetActiveOrders + 'static,
+    C: ConfirmOrder + 'static,
+    P: CompleteOrder + 'static,
+{
+    match actix_ws::handle(&req, body) {
+        Ok((response, session, messages)) => {
+            // Subscribed before the snapshot is taken, so no change can slip in between
+            let receiver = feed.subscribe();
+            rt::spawn(kitchen_display_session(
+                active_orders,
+                confirm_order,
+                complete_order,
+                receiver,
+                session,
+                messages.aggregate_continuations(),
+            ));
+            response
+        }
+        Err(e) => HttpResponse::from_error(e),
+    }
+}
+
+async fn kitchen_display_session<A, C, P>(
+    active_orders: web::Data<AM<A>>,
+    confirm_order: web::Data<AM<C>>,
+    complete_order: web::Data<AM<P>>,
+    mut receiver: Receiver<ShopOrderEventEnum>,
+    mut session: Session,
+    mut messages: AggregatedMessageStream,
+) where
+    A: GetActiveOrders,
+    C: ConfirmOrder,
+    P: CompleteOrder,
+{
+    let mut reply = snapshot(&active_orders).await;
+    loop {
+        if send(&mut session, &reply).await.is_err() {
+            return;
+        }
+        reply = tokio::select! {
+            event = receiver.recv() => match event {
+                Ok(event) => match delta(&event) {
+                    Some(message) => message,
+                    None => continue,
+                },
+                // The display missed some changes, so it gets the whole picture again
+                Err(RecvError::Lagged(_)) => snapshot(&active_orders).await,
+                Err(RecvError::Closed) => break,
+            },
+            message = messages.recv() => match message {
+                Some(Ok(AggregatedMessage::Text(text))) => KitchenDisplayMessage::Ack(
+                    handle_command(&text, &confirm_order, &complete_order).await,
+                ),
+                Some(Ok(AggregatedMessage::Ping(bytes))) => {
+                    if session.pong(&bytes).await.is_err() {
+                        return;
+                    }
+                    continue;
+                }
+                Some(Ok(AggregatedMessage::Binary(_) | AggregatedMessage::Pong(_))) => continue,
+                Some(Ok(AggregatedMessage::Close(_)) | Err(_)) | None => break,
+            },
+        };
+    }
+    let _ = session.close(None).await;
+}
+
+async fn send(
+    session: &mut Session,
+    message: &KitchenDisplayMessage,
+) -> Result<(), actix_ws::Closed> {
+    session.text(serde_json::to_string(message).unwrap()).await
+}
+
+/// The kitchen can't act on an order before it's paid, so its creation isn't shown
+fn delta(event: &ShopOrderEventEnum) -> Option<KitchenDisplayMessage> {
+    match event {
+        ShopOrderEventEnum::ShopOrderCreatedDomainEvent(_) => None,
+        _ => Some(KitchenDisplayMessage::OrderChanged(event.to_model())),
+    }
+}
+
+async fn snapshot<A: GetActiveOrders>(active_orders: &AM<A>) -> KitchenDisplayMessage {
+    let orders = active_orders.lock().await.execute().await;
+    KitchenDisplayMessage::Snapshot {
+        orders: orders.into_iter().map(|it| it.to_model()).collect(),
+    }
+}
+
+async fn handle_command<C, P>(
+    text: &str,
+    confirm_order: &AM<C>,
+    complete_order: &AM<P>,
+) -> KitchenCommandAckModel
+where
+    C: ConfirmOrder,
+    P: CompleteOrder,
+{
+    let command: KitchenCommandModel = match serde_json::from_str(text) {
+        Ok(it) => it,
+        Err(e) => {
+            return KitchenCommandAckModel {
+                request_id: None,
+                order_id: None,
+                success: false,
+                error: Some(format!("Malformed command: {e}")),
+            };
+        }
+    };
+    let result = match ShopOrderId::try_from(command.order_id) {
+        Ok(order_id) => match command.command {
+            KitchenCommandTypeModel::StartPreparing => confirm_order
+                .lock()
+                .await
+                .execute(&order_id)
+                .await
+                .map_err(|e| e.to_string()),
+            KitchenCommandTypeModel::Ready => complete_order
+                .lock()
+                .await
+                .execute(&order_id)
+                .await
+                .map_err(|e| e.to_string()),
+        },
+        Err(_) => Err("Wrong Shop Order Id".to_string()),
+    };
+    KitchenCommandAckModel {
+        request_id: command.request_id,
+        order_id: Some(command.order_id),
+        success: result.is_ok(),
+        error: result.err(),
+    }
+}
+
+pub fn kitchen_display_endpoint_config<A, C, P>(cfg: &mut web::ServiceConfig)
+where
+    A: GetActiveOrders + 'static,
+    C: ConfirmOrder + 'static,
+    P: CompleteOrder + 'static,
+{
+    cfg.route(
+        API_V1_KITCHEN_DISPLAY,
+        web::get()
+            .to(kitchen_display_endpoint::<A, C, P>)
+            .wrap(from_fn(require_kitchen_staff)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{
+        FromRequest,
+        http::{
+            StatusCode,
+            header::{CONNECTION, SEC_WEBSOCKET_KEY, SEC_WEBSOCKET_VERSION, UPGRADE},
+        },
+        test::TestRequest,
+        web::Data,
+    };
+    use common::types::base::AMTrait;
+    use domain::{
+        order::{
+            customer_order_events::{ShopOrderCreatedDomainEvent, ShopOrderPaidDomainEvent},
+            shop_order::OrderState,
+        },
+        test_fixtures::*,
+    };
+    use usecase::order::{CompleteOrderUseCaseError, ConfirmOrderUseCaseError};
+
+    use super::*;
+    use crate::{
+        order::order_model::OrderStateModel,
+        test_fixtures::{
+            MockCompleteOrder, MockConfirmOrder, MockGetActiveOrders, rnd_order_details,
+        },
+    };
+
+    #[actix_web::test]
+    async fn snapshot_contains_active_orders() {
+        let paid = rnd_order_details(OrderState::new_paid());
+        let confirmed = rnd_order_details(OrderState::new_confirmed());
+        let active_orders = AM::new_am(MockGetActiveOrders {
+            response: vec![paid.clone(), confirmed.clone()],
+            ..Default::default()
+        });
+
+        let KitchenDisplayMessage::Snapshot { orders } = snapshot(&active_orders).await else {
+            panic!("Snapshot expected")
+        };
+
+        active_orders.lock().await.verify_invoked();
+        let orders: Vec<(i64, OrderStateModel)> =
+            orders.iter().map(|it| (it.id, it.state)).collect();
+        assert_eq!(
+            orders,
+            vec![
+                (paid.id.to_i64(), OrderStateModel::Paid),
+                (confirmed.id.to_i64(), OrderStateModel::Confirmed),
+            ]
+        );
+    }
+
+    #[actix_web::test]
+    async fn order_creation_is_not_shown() {
+        let order_id = rnd_order_id();
+
+        assert!(
+            delta(
+                &ShopOrderCreatedDomainEvent::new(
+                    order_id,
+                    rnd_customer_id(),
+                    rnd_price(),
+                    rnd_fulfilment(),
+                    None,
+                )
+                .into()
+            )
+            .is_none()
+        );
+        let Some(KitchenDisplayMessage::OrderChanged(change)) =
+            delta(&ShopOrderPaidDomainEvent::new(order_id).into())
+        else {
+            panic!("Order change expected")
+        };
+        assert_eq!(change.order_id, order_id.to_i64());
+        assert_eq!(change.state, OrderStateModel::Paid);
+    }
+
+    #[actix_web::test]
+    async fn start_preparing_command_is_acknowledged() {
+        let order_id = rnd_order_id();
+        let confirm_order = AM::new_am(MockConfirmOrder::default());
+        let complete_order = AM::new_am(MockCompleteOrder::default());
+
+        let ack = handle_command(
+            &format!(
+                r#"{{"request_id":"1","command":"start_preparing","order_id":{}}}"#,
+                order_id.to_i64()
+            ),
+            &confirm_order,
+            &complete_order,
+        )
+        .await;
+
+        confirm_order.lock().await.verify_invoked(&order_id);
+        assert_eq!(
+            ack,
+            KitchenCommandAckModel {
+                request_id: Some("1".to_string()),
+                order_id: Some(order_id.to_i64()),
+                success: true,
+                error: None,
+            }
+        );
+    }
+
+    #[actix_web::test]
+    async fn ready_command_is_acknowledged() {
+        let order_id = rnd_order_id();
+        let confirm_order = AM::new_am(MockConfirmOrder::default());
+        let complete_order = AM::new_am(MockCompleteOrder::default());
+
+        let ack = handle_command(
+            &format!(r#"{{"command":"ready","order_id":{}}}"#, order_id.to_i64()),
+            &confirm_order,
+            &complete_order,
+        )
+        .await;
+
+        complete_order.lock().await.verify_invoked(&order_id);
+        assert!(ack.success);
+        assert_eq!(ack.request_id, None);
+    }
+
+    #[actix_web::test]
+    async fn failed_command_is_acknowledged_with_error() {
+        let order_id = rnd_order_id();
+        let confirm_order = AM::new_am(MockConfirmOrder {
+            response: Err(ConfirmOrderUseCaseError::InvalidOrderState),
+            ..Default::default()
+        });
+        let complete_order = AM::new_am(MockCompleteOrder {
+            response: Err(CompleteOrderUseCaseError::OrderNotFound),
+            ..Default::default()
+        });
+
+        let confirm_ack = handle_command(
+            &format!(
+                r#"{{"request_id":"2","command":"start_preparing","order_id":{}}}"#,
+                order_id.to_i64()
+            ),
+            &confirm_order,
+            &complete_order,
+        )
+        .await;
+        let complete_ack = handle_command(
+            &format!(
+                r#"{{"request_id":"3","command":"ready","order_id":{}}}"#,
+                order_id.to_i64()
+            ),
+            &confirm_order,
+            &complete_order,
+        )
+        .await;
+
+        assert!(!confirm_ack.success);
+        assert_eq!(confirm_ack.error, Some("Invalid order state".to_string()));
+        assert!(!complete_ack.success);
+        assert_eq!(complete_ack.error, Some("Order not found".to_string()));
+    }
+
+    #[actix_web::test]
+    async fn wrong_order_id() {
+        let ack = handle_command(
+            r#"{"request_id":"4","command":"start_preparing","order_id":-1}"#,
+            &AM::new_am(MockConfirmOrder::default()),
+            &AM::new_am(MockCompleteOrder::default()),
+        )
+        .await;
+
+        assert_eq!(ack.request_id, Some("4".to_string()));
+        assert!(!ack.success);
+        assert_eq!(ack.error, Some("Wrong Shop Order Id".to_string()));
+    }
+
+    #[actix_web::test]
+    async fn malformed_command() {
+        let ack = handle_command(
+            r#"{"command":"confirm","order_id":1}"#,
+            &AM::new_am(MockConfirmOrder::default()),
+            &AM::new_am(MockCompleteOrder::default()),
+        )
+        .await;
+
+        assert_eq!(ack.order_id, None);
+        assert!(!ack.success);
+        assert!(ack.error.unwrap().starts_with("Malformed command"));
+    }
+
+    async fn call_endpoint(request: TestRequest) -> HttpResponse {
+        let (req, mut payload) = request.to_http_parts();
+        let body = web::Payload::from_request(&req, &mut payload)
+            .await
+            .unwrap();
+        kitchen_display_endpoint(
+            Data::new(AM::new_am(MockGetActiveOrders::default())),
+            Data::new(AM::new_am(MockConfirmOrder::default())),
+            Data::new(AM::new_am(MockCompleteOrder::default())),
+            Data::new(OrderEventFeed::default()),
+            req,
+            body,
+        )
+        .await
+    }
+
+    #[actix_web::test]
+    async fn websocket_handshake() {
+        let resp = call_endpoint(
+            TestRequest::default()
+                .insert_header((UPGRADE, "websocket"))
+                .insert_header((CONNECTION, "upgrade"))
+                .insert_header((SEC_WEBSOCKET_VERSION, "13"))
+                .insert_header((SEC_WEBSOCKET_KEY, "dGhlIHNhbXBsZSBub25jZQ==")),
+        )
+        .await;
+
+        assert_eq!(resp.status(), StatusCode::SWITCHING_PROTOCOLS);
+    }
+
+    #[actix_web::test]
+    async fn plain_request_is_rejected() {
+        let resp = call_endpoint(TestRequest::default()).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+    }
+}
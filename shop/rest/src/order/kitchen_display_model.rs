@@ -0,0 +1,87 @@
+use serde::Serialize;
+use serde_derive::Deserialize;
+use usecase::order::dto::order_details::OrderDetails;
+use utoipa::ToSchema;
+
+use crate::order::order_model::{
+    DeliverySlotModel, FulfilmentModel, OrderItemModel, OrderStateChangedModel, OrderStateModel,
+    ToModel,
+};
+
+/// Message sent to the kitchen display
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum KitchenDisplayMessage {
+    /// All active orders, replaces whatever the display shows
+    Snapshot { orders: Vec<KitchenOrderModel> },
+    /// An order has changed its state
+    OrderChanged(OrderStateChangedModel),
+    /// Outcome of a command sent by the display
+    Ack(KitchenCommandAckModel),
+}
+
+/// Active order as shown on the kitchen display
+#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
+pub struct KitchenOrderModel {
+    /// ID of the order
+    #[schema(example = 54389)]
+    pub id: i64,
+    /// Current state of the order
+    #[schema(example = "paid")]
+    pub state: OrderStateModel,
+    /// Delivery or pickup details of the order
+    pub fulfilment: FulfilmentModel,
+    /// Requested delivery time slot, absent for ASAP orders
+    pub delivery_slot: Option<DeliverySlotModel>,
+    /// Meals to prepare
+    pub items: Vec<OrderItemModel>,
+}
+
+/// Command sent by the kitchen display
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, ToSchema)]
+pub struct KitchenCommandModel {
+    /// Chosen by the display and echoed in the acknowledgement
+    #[schema(example = "42")]
+    pub request_id: Option<String>,
+    pub command: KitchenCommandTypeModel,
+    /// ID of the order
+    #[schema(example = 54389)]
+    pub order_id: i64,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum KitchenCommandTypeModel {
+    /// Start preparing a paid order, it becomes confirmed
+    StartPreparing,
+    /// The meals of a confirmed or ready for pickup order are ready, it becomes completed
+    Ready,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, ToSchema)]
+pub struct KitchenCommandAckModel {
+    /// Request ID of the command, absent when the command couldn't be read
+    #[schema(example = "42")]
+    pub request_id: Option<String>,
+    /// ID of the order, absent when the command couldn't be read
+    #[schema(example = 54389)]
+    pub order_id: Option<i64>,
+    /// Whether the command was executed
+    #[schema(example = true)]
+    pub success: bool,
+    /// Reason of the failure
+    #[schema(example = "Invalid order state")]
+    pub error: Option<String>,
+}
+
+impl ToModel<KitchenOrderModel> for OrderDetails {
+    fn to_model(self) -> KitchenOrderModel {
+        KitchenOrderModel {
+            id: self.id.to_i64(),
+            state: self.state.to_model(),
+            fulfilment: self.fulfilment.to_model(),
+            delivery_slot: self.delivery_slot.map(|it| it.to_model()),
+            items: self.items.to_model(),
+        }
+    }
+}
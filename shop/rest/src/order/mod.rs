@@ -1,6 +1,12 @@
 pub mod cancel_order_endpoint;
 pub mod confirm_order_endpoint;
+pub mod get_available_delivery_slots_endpoint;
+pub mod get_customer_orders_endpoint;
+pub mod get_last_order_state_endpoint;
 pub mod get_order_by_id_endpoint;
+pub mod get_order_events_endpoint;
 pub mod get_orders_endpoint;
+pub mod kitchen_display_endpoint;
+pub mod kitchen_display_model;
 pub mod order_model;
 pub mod validated;
@@ -1,9 +1,18 @@
 use actix_web::{HttpResponse, http::header::ContentType};
 use common::types::common::Address;
 use derive_new::new;
+use domain::order::{
+    customer_order_events::ShopOrderEventEnum,
+    shop_order::OrderState,
+    value_objects::{delivery_slot::DeliverySlot, fulfilment::Fulfilment},
+};
 use serde::Serialize;
 use serde_derive::Deserialize;
-use usecase::order::dto::order_details::{OrderDetails, OrderItemDetails};
+use time::format_description::well_known::Rfc3339;
+use usecase::order::dto::{
+    available_delivery_slot::AvailableDeliverySlot,
+    order_details::{OrderDetails, OrderItemDetails},
+};
 use utoipa::{ToResponse, ToSchema};
 
 #[derive(Serialize, Deserialize, Debug, Clone, ToSchema, ToResponse)]
@@ -11,10 +20,15 @@ pub struct OrderModel {
     /// ID of the order
     #[schema(example = 54389)]
     pub id: i64,
-    /// Address of the order
-    pub address: AddressModel,
+    /// Delivery or pickup details of the order
+    pub fulfilment: FulfilmentModel,
+    /// Requested delivery time slot, absent for ASAP orders
+    pub delivery_slot: Option<DeliverySlotModel>,
     /// A list of orders item
     pub items: Vec<OrderItemModel>,
+    /// Delivery fee included in the total price
+    #[schema(example = "150.00")]
+    pub delivery_fee: String,
     /// Total price of the order
     #[schema(example = "556.45")]
     pub total_price: String,
@@ -36,7 +50,19 @@ pub struct OrderItemModel {
     pub count: i32,
 }
 
-#[derive(Serialize, Deserialize, Debug, Clone, ToSchema)]
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, ToSchema)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum FulfilmentModel {
+    /// Courier delivery to the address
+    Delivery { address: AddressModel },
+    /// Customer collects the order at the pickup point
+    Pickup {
+        #[schema(example = 12)]
+        pickup_point_id: i64,
+    },
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, ToSchema)]
 pub struct AddressModel {
     /// Street name
     #[schema(example = "Palm str.")]
@@ -44,6 +70,78 @@ pub struct AddressModel {
     /// Building number
     #[schema(example = "5")]
     pub building: i16,
+    /// City
+    #[schema(example = "Berlin")]
+    pub city: String,
+    /// Postal code
+    #[schema(example = "10115")]
+    pub postal_code: String,
+    /// Apartment or office
+    #[schema(example = "12B")]
+    pub apartment: Option<String>,
+    /// Floor, negative for underground floors
+    #[schema(example = 3)]
+    pub floor: Option<i16>,
+    /// Instructions for the courier
+    #[schema(example = "Ring twice")]
+    pub delivery_instructions: Option<String>,
+    /// Latitude in degrees
+    #[schema(example = 52.52)]
+    pub latitude: Option<f64>,
+    /// Longitude in degrees
+    #[schema(example = 13.405)]
+    pub longitude: Option<f64>,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, ToSchema)]
+pub struct DeliverySlotModel {
+    /// Start of the slot, RFC 3339
+    #[schema(example = "2030-03-15T12:00:00Z")]
+    pub start: String,
+    /// End of the slot, RFC 3339
+    #[schema(example = "2030-03-15T12:30:00Z")]
+    pub end: String,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, ToSchema)]
+pub struct AvailableDeliverySlotModel {
+    /// Start of the slot, RFC 3339
+    #[schema(example = "2030-03-15T12:00:00Z")]
+    pub start: String,
+    /// End of the slot, RFC 3339
+    #[schema(example = "2030-03-15T12:30:00Z")]
+    pub end: String,
+    /// How many more orders can be scheduled for the slot
+    #[schema(example = 4)]
+    pub remaining_capacity: usize,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, ToSchema)]
+#[serde(rename_all = "snake_case")]
+pub enum OrderStateModel {
+    WaitingForPayment,
+    Paid,
+    Confirmed,
+    ReadyForPickup,
+    Completed,
+    Cancelled,
+}
+
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, ToSchema)]
+pub struct LastOrderStateModel {
+    /// State of the most recent order of the customer
+    #[schema(example = "paid")]
+    pub state: OrderStateModel,
+}
+
+/// Data of the order events stream
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, ToSchema)]
+pub struct OrderStateChangedModel {
+    #[schema(example = 1)]
+    pub order_id: i64,
+    /// State the order has moved to
+    #[schema(example = "paid")]
+    pub state: OrderStateModel,
 }
 
 /// Add conversion from usecase to rest objects
@@ -56,6 +154,59 @@ impl ToModel<AddressModel> for Address {
         AddressModel {
             street: self.street_to_string(),
             building: self.building_to_i16(),
+            city: self.city_to_string(),
+            postal_code: self.postal_code_to_string(),
+            apartment: self.apartment_to_string(),
+            floor: self.floor_to_i16(),
+            delivery_instructions: self.delivery_instructions_to_string(),
+            latitude: self.location().map(|it| it.latitude_to_f64()),
+            longitude: self.location().map(|it| it.longitude_to_f64()),
+        }
+    }
+}
+
+impl ToModel<FulfilmentModel> for Fulfilment {
+    fn to_model(self) -> FulfilmentModel {
+        match self {
+            Fulfilment::Delivery(address) => FulfilmentModel::Delivery {
+                address: address.to_model(),
+            },
+            Fulfilment::Pickup(pickup_point_id) => FulfilmentModel::Pickup {
+                pickup_point_id: pickup_point_id.to_i64(),
+            },
+        }
+    }
+}
+
+impl ToModel<DeliverySlotModel> for DeliverySlot {
+    fn to_model(self) -> DeliverySlotModel {
+        DeliverySlotModel {
+            start: self.start().format(&Rfc3339).unwrap(),
+            end: self.end().format(&Rfc3339).unwrap(),
+        }
+    }
+}
+
+impl ToModel<AvailableDeliverySlotModel> for AvailableDeliverySlot {
+    fn to_model(self) -> AvailableDeliverySlotModel {
+        let slot: DeliverySlotModel = self.slot.to_model();
+        AvailableDeliverySlotModel {
+            start: slot.start,
+            end: slot.end,
+            remaining_capacity: self.remaining_capacity,
+        }
+    }
+}
+
+impl ToModel<OrderStateModel> for OrderState {
+    fn to_model(self) -> OrderStateModel {
+        match self {
+            OrderState::WaitingForPayment(_) => OrderStateModel::WaitingForPayment,
+            OrderState::Paid(_) => OrderStateModel::Paid,
+            OrderState::Confirmed(_) => OrderStateModel::Confirmed,
+            OrderState::ReadyForPickup(_) => OrderStateModel::ReadyForPickup,
+            OrderState::Completed(_) => OrderStateModel::Completed,
+            OrderState::Cancelled(_) => OrderStateModel::Cancelled,
         }
     }
 }
@@ -72,8 +223,10 @@ impl ToModel<OrderModel> for OrderDetails {
     fn to_model(self) -> OrderModel {
         OrderModel {
             id: self.id.to_i64(),
-            address: self.address.to_model(),
+            fulfilment: self.fulfilment.to_model(),
+            delivery_slot: self.delivery_slot.map(|it| it.to_model()),
             items: self.items.to_model(),
+            delivery_fee: self.delivery_fee.to_string_value(),
             total_price: self.total.to_string_value(),
             version: self.version.to_i64(),
             ready_for_confirm_or_cancel: self.ready_for_confirm_or_cancel,
@@ -81,6 +234,27 @@ impl ToModel<OrderModel> for OrderDetails {
     }
 }
 
+impl ToModel<OrderStateChangedModel> for &ShopOrderEventEnum {
+    fn to_model(self) -> OrderStateChangedModel {
+        let state = match self {
+            ShopOrderEventEnum::ShopOrderCreatedDomainEvent(_) => {
+                OrderStateModel::WaitingForPayment
+            }
+            ShopOrderEventEnum::ShopOrderPaidDomainEvent(_) => OrderStateModel::Paid,
+            ShopOrderEventEnum::ShopOrderConfirmedDomainEvent(_) => OrderStateModel::Confirmed,
+            ShopOrderEventEnum::ShopOrderReadyForPickupDomainEvent(_) => {
+                OrderStateModel::ReadyForPickup
+            }
+            ShopOrderEventEnum::ShopOrderCompletedDomainEvent(_) => OrderStateModel::Completed,
+            ShopOrderEventEnum::ShopOrderCancelledDomainEvent(_) => OrderStateModel::Cancelled,
+        };
+        OrderStateChangedModel {
+            order_id: self.order_id().to_i64(),
+            state,
+        }
+    }
+}
+
 impl ToModel<HttpResponse> for OrderDetails {
     fn to_model(self) -> HttpResponse {
         HttpResponse::Ok()
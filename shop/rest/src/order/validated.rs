@@ -1,10 +1,24 @@
 use std::{collections::HashMap, fmt::Display, str::FromStr};
 
 use actix_web::{HttpRequest, web::Query};
-use common::{common_rest::ValidationError, types::base::RCell};
-use domain::order::{shop_order::ShopOrderError, value_objects::shop_order_id::ShopOrderId};
+use common::{
+    common_rest::ValidationError,
+    types::{
+        base::RCell,
+        common::{Address, CreateAddressError, GeoLocation},
+    },
+};
+use domain::order::{
+    shop_order::{OrderState, ShopOrderError},
+    value_objects::shop_order_id::ShopOrderId,
+};
+use time::{
+    Date, OffsetDateTime,
+    format_description::well_known::{Iso8601, Rfc3339},
+};
+use usecase::order::access::shop_order_criteria::{ShopOrderSortField, SortDirection};
 
-use crate::validated::Validated;
+use crate::{order::order_model::AddressModel, validated::Validated};
 
 impl Validated<i64> for ShopOrderId {
     fn validated(val: i64, error_list: RCell<Vec<ValidationError>>) -> Option<Self> {
@@ -20,6 +34,148 @@ impl Validated<i64> for ShopOrderId {
     }
 }
 
+impl Validated<String> for Date {
+    fn validated(val: String, error_list: RCell<Vec<ValidationError>>) -> Option<Self> {
+        match Date::parse(&val, &Iso8601::DATE) {
+            Ok(date) => Some(date),
+            Err(_) => {
+                error_list
+                    .borrow_mut()
+                    .push(ValidationError::new("Date must be in YYYY-MM-DD format"));
+                None
+            }
+        }
+    }
+}
+
+impl Validated<&str> for OffsetDateTime {
+    fn validated(val: &str, error_list: RCell<Vec<ValidationError>>) -> Option<Self> {
+        match OffsetDateTime::parse(val, &Rfc3339) {
+            Ok(date_time) => Some(date_time),
+            Err(_) => {
+                error_list.borrow_mut().push(ValidationError::new(
+                    "Date and time must be in RFC 3339 format",
+                ));
+                None
+            }
+        }
+    }
+}
+
+impl Validated<&str> for OrderState {
+    fn validated(val: &str, error_list: RCell<Vec<ValidationError>>) -> Option<Self> {
+        match val {
+            "waiting_for_payment" => Some(OrderState::new_waiting_for_payment()),
+            "paid" => Some(OrderState::new_paid()),
+            "confirmed" => Some(OrderState::new_confirmed()),
+            "ready_for_pickup" => Some(OrderState::new_ready_for_pickup()),
+            "completed" => Some(OrderState::new_completed()),
+            "cancelled" => Some(OrderState::new_cancelled()),
+            _ => {
+                error_list.borrow_mut().push(ValidationError::new(&format!(
+                    "Unknown order state '{val}'"
+                )));
+                None
+            }
+        }
+    }
+}
+
+impl Validated<&str> for ShopOrderSortField {
+    fn validated(val: &str, error_list: RCell<Vec<ValidationError>>) -> Option<Self> {
+        match val {
+            "id" => Some(ShopOrderSortField::Id),
+            "created" => Some(ShopOrderSortField::Created),
+            "total" => Some(ShopOrderSortField::Total),
+            _ => {
+                error_list.borrow_mut().push(ValidationError::new(
+                    "Sort must be one of 'id', 'created', 'total'",
+                ));
+                None
+            }
+        }
+    }
+}
+
+impl Validated<&str> for SortDirection {
+    fn validated(val: &str, error_list: RCell<Vec<ValidationError>>) -> Option<Self> {
+        match val {
+            "asc" => Some(SortDirection::Asc),
+            "desc" => Some(SortDirection::Desc),
+            _ => {
+                error_list
+                    .borrow_mut()
+                    .push(ValidationError::new("Direction must be 'asc' or 'desc'"));
+                None
+            }
+        }
+    }
+}
+
+impl Validated<&AddressModel> for Address {
+    fn validated(val: &AddressModel, error_list: RCell<Vec<ValidationError>>) -> Option<Self> {
+        let address = Self::try_from((
+            val.street.as_str(),
+            val.building,
+            val.city.as_str(),
+            val.postal_code.as_str(),
+        ))
+        .and_then(|it| match &val.apartment {
+            Some(apartment) => it.with_apartment(apartment),
+            None => Ok(it),
+        })
+        .and_then(|it| match &val.delivery_instructions {
+            Some(instructions) => it.with_delivery_instructions(instructions),
+            None => Ok(it),
+        })
+        .map(|it| match val.floor {
+            Some(floor) => it.with_floor(floor),
+            None => it,
+        });
+        let address = match address {
+            Ok(address) => address,
+            Err(e) => {
+                error_list
+                    .borrow_mut()
+                    .push(ValidationError::new(address_error_message(e)));
+                return None;
+            }
+        };
+
+        match (val.latitude, val.longitude) {
+            (None, None) => Some(address),
+            (Some(latitude), Some(longitude)) => match GeoLocation::try_from((latitude, longitude))
+            {
+                Ok(location) => Some(address.with_location(location)),
+                Err(_) => {
+                    error_list
+                        .borrow_mut()
+                        .push(ValidationError::new("Coordinates are out of range"));
+                    None
+                }
+            },
+            _ => {
+                error_list.borrow_mut().push(ValidationError::new(
+                    "Latitude and longitude must be given together",
+                ));
+                None
+            }
+        }
+    }
+}
+
+fn address_error_message(error: CreateAddressError) -> &'static str {
+    match error {
+        CreateAddressError::EmptyStreet => "Street is empty",
+        CreateAddressError::NonPositiveBuilding => "Building number must be > 0",
+        CreateAddressError::EmptyCity => "City is empty",
+        CreateAddressError::InvalidPostalCode => "Postal code is invalid",
+        CreateAddressError::EmptyApartment => "Apartment is empty",
+        CreateAddressError::EmptyDeliveryInstructions => "Delivery instructions are empty",
+        CreateAddressError::DeliveryInstructionsTooLong => "Delivery instructions are too long",
+    }
+}
+
 #[allow(clippy::result_unit_err)]
 pub fn validate_query_string<T>(
     req: HttpRequest,
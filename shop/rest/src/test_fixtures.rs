@@ -1,40 +1,152 @@
+use std::sync::Mutex;
+
+use actix_web::http::Uri;
 use async_trait::async_trait;
+use common::types::common::{Address, Count};
+use derive_new::new;
 use domain::{
+    cart::value_objects::customer_id::CustomerId,
+    customer::value_objects::{
+        customer_name::CustomerName, phone_number::PhoneNumber, saved_address::SavedAddressId,
+    },
     menu::value_objects::{
-        meal_description::MealDescription, meal_id::MealId, meal_name::MealName, price::Price,
+        allergens::Allergens, availability_schedule::AvailabilitySchedule,
+        meal_description::MealDescription, meal_id::MealId, meal_name::MealName,
+        nutrition_facts::NutritionFacts, price::Price,
     },
     order::{shop_order::OrderState, value_objects::shop_order_id::ShopOrderId},
     test_fixtures::*,
+    webhook::value_objects::{
+        webhook_event_type::WebhookEventType, webhook_secret::WebhookSecret,
+        webhook_subscription_id::WebhookSubscriptionId, webhook_url::WebhookUrl,
+    },
 };
 use smart_default::SmartDefault;
+use time::{Date, OffsetDateTime};
 use usecase::{
+    auth::role::Role,
+    customer::{
+        AddCustomerAddress, AddCustomerAddressUseCaseError, GetCustomerById,
+        GetCustomerByIdUseCaseError, RegisterCustomer, RegisterCustomerUseCaseError,
+        RemoveCustomer, RemoveCustomerAddress, RemoveCustomerAddressUseCaseError,
+        RemoveCustomerUseCaseError, UpdateCustomer, UpdateCustomerRequest,
+        UpdateCustomerUseCaseError, dto::customer_info::CustomerInfo,
+    },
+    inventory::{SetMealPortions, SetMealPortionsUseCaseError},
     menu::{
-        AddMealToMenu, AddMealToMenuUseCaseError, GetMealById, GetMealByIdUseCaseError, GetMenu,
-        RemoveMealFromMenu, RemoveMealFromMenuUseCaseError, dto::meal_info::MealInfo,
+        AddMealToMenu, AddMealToMenuUseCaseError, ChangeMealAvailability,
+        ChangeMealAvailabilityUseCaseError, ExportMenu, GetMealById, GetMealByIdUseCaseError,
+        GetMenu, GetMenuUseCaseError, ImportMenu, ImportMenuUseCaseError, MenuAvailability,
+        RemoveMealFromMenu, RemoveMealFromMenuUseCaseError, SearchMenu, SearchMenuUseCaseError,
+        UploadMealImage, UploadMealImageUseCaseError,
+        dto::{
+            meal_info::MealInfo, meal_row::MealRow, menu_import_report::MenuImportReport,
+            menu_page::MenuPage,
+        },
     },
     order::{
-        CancelOrder, CancelOrderUseCaseError, ConfirmOrder, ConfirmOrderUseCaseError, GetOrderById,
-        GetOrderByIdUseCaseError, GetOrders, GetOrdersUseCaseError,
-        dto::order_details::{AsDetails, OrderDetails},
+        CancelOrder, CancelOrderUseCaseError, CompleteOrder, CompleteOrderUseCaseError,
+        ConfirmOrder, ConfirmOrderUseCaseError, GetActiveOrders, GetAvailableDeliverySlots,
+        GetCustomerOrders, GetCustomerOrdersUseCaseError, GetLastOrderState,
+        GetLastOrderStateUseCaseError, GetOrderById, GetOrderByIdUseCaseError, GetOrders,
+        GetOrdersUseCaseError,
+        access::shop_order_criteria::ShopOrderCriteria,
+        dto::{
+            available_delivery_slot::AvailableDeliverySlot,
+            order_details::{AsDetails, OrderDetails},
+        },
+    },
+    webhook::{
+        GetFailedWebhookDeliveries, GetWebhookSubscriptions, RemoveWebhookSubscription,
+        RemoveWebhookSubscriptionUseCaseError, SubscribeWebhook, SubscribeWebhookUseCaseError,
+        dto::{
+            failed_webhook_delivery::FailedWebhookDelivery,
+            webhook_subscription_info::WebhookSubscriptionInfo,
+        },
     },
 };
 
+use crate::auth::authenticated_user::AuthenticatedUser;
+
 const API_V1_TYPE_BASE_URL: &str = "http://localhost";
 
+pub fn kitchen_staff() -> AuthenticatedUser {
+    AuthenticatedUser::new(rnd_customer_id(), Role::KitchenStaff)
+}
+
 pub fn rnd_order_details(order_state: OrderState) -> OrderDetails {
     order_with_state(order_state).as_details()
 }
 
 #[derive(SmartDefault, Debug)]
 pub struct MockGetMenu {
-    #[default(MealInfo::default())]
-    pub meal_info: MealInfo,
+    #[default(_code = "Ok(MenuPage { meals: vec![], last_modified: OffsetDateTime::now_utc() })")]
+    pub response: Result<MenuPage, GetMenuUseCaseError>,
+    pub start_id: Option<MealId>,
+    pub limit: Option<usize>,
+    pub excluded_allergens: Option<Allergens>,
+    pub availability: Option<MenuAvailability>,
 }
 
 #[async_trait]
 impl GetMenu for MockGetMenu {
-    async fn execute(&self) -> Vec<MealInfo> {
-        vec![self.meal_info.clone()]
+    async fn execute(
+        &mut self,
+        start_id: &MealId,
+        limit: usize,
+        excluded_allergens: &Allergens,
+        availability: &MenuAvailability,
+    ) -> Result<MenuPage, GetMenuUseCaseError> {
+        self.start_id = Some(*start_id);
+        self.limit = Some(limit);
+        self.excluded_allergens = Some(excluded_allergens.clone());
+        self.availability = Some(*availability);
+        self.response.clone()
+    }
+}
+
+impl MockGetMenu {
+    pub fn verify_invoked(
+        &self,
+        start_id: &MealId,
+        limit: usize,
+        excluded_allergens: &Allergens,
+        availability: &MenuAvailability,
+    ) {
+        assert_eq!(self.start_id, Some(*start_id));
+        assert_eq!(self.limit, Some(limit));
+        assert_eq!(self.excluded_allergens.as_ref(), Some(excluded_allergens));
+        assert_eq!(self.availability, Some(*availability));
+    }
+
+    pub fn verify_empty(&self) {
+        assert!(self.start_id.is_none());
+        assert!(self.limit.is_none());
+    }
+}
+
+#[derive(SmartDefault, Debug)]
+pub struct MockSearchMenu {
+    #[default(Ok(vec![]))]
+    pub response: Result<Vec<MealInfo>, SearchMenuUseCaseError>,
+    pub text: Option<String>,
+}
+
+#[async_trait]
+impl SearchMenu for MockSearchMenu {
+    async fn execute(&mut self, text: &str) -> Result<Vec<MealInfo>, SearchMenuUseCaseError> {
+        self.text = Some(text.to_string());
+        self.response.clone()
+    }
+}
+
+impl MockSearchMenu {
+    pub fn verify_invoked(&self, text: &str) {
+        assert_eq!(self.text.as_deref(), Some(text));
+    }
+
+    pub fn verify_empty(&self) {
+        assert!(self.text.is_none());
     }
 }
 
@@ -44,6 +156,8 @@ pub struct MockAddMealToMenu {
     name: MealName,
     pub description: MealDescription,
     pub price: Price,
+    pub allergens: Allergens,
+    pub nutrition_facts: NutritionFacts,
 }
 
 impl Default for MockAddMealToMenu {
@@ -53,6 +167,8 @@ impl Default for MockAddMealToMenu {
             name: rnd_meal_name(),
             description: rnd_meal_description(),
             price: rnd_price(),
+            allergens: rnd_allergens(),
+            nutrition_facts: rnd_nutrition_facts(),
         }
     }
 }
@@ -64,19 +180,89 @@ impl AddMealToMenu for MockAddMealToMenu {
         name: &MealName,
         description: &MealDescription,
         price: &Price,
+        allergens: &Allergens,
+        nutrition_facts: &NutritionFacts,
     ) -> Result<MealId, AddMealToMenuUseCaseError> {
         self.name = name.clone();
         self.description = description.clone();
         self.price = price.clone();
+        self.allergens = allergens.clone();
+        self.nutrition_facts = nutrition_facts.clone();
         self.response.to_owned()
     }
 }
 
 impl MockAddMealToMenu {
-    pub fn verify_invoked(&self, name: &MealName, description: &MealDescription, price: &Price) {
+    pub fn verify_invoked(
+        &self,
+        name: &MealName,
+        description: &MealDescription,
+        price: &Price,
+        allergens: &Allergens,
+        nutrition_facts: &NutritionFacts,
+    ) {
         assert_eq!(name, &self.name);
         assert_eq!(description, &self.description);
         assert_eq!(price, &self.price);
+        assert_eq!(allergens, &self.allergens);
+        assert_eq!(nutrition_facts, &self.nutrition_facts);
+    }
+}
+
+#[derive(SmartDefault, Debug)]
+pub struct MockImportMenu {
+    #[default(Ok(MenuImportReport::default()))]
+    pub response: Result<MenuImportReport, ImportMenuUseCaseError>,
+    pub rows: Option<Vec<MealRow>>,
+    pub dry_run: Option<bool>,
+}
+
+#[async_trait]
+impl ImportMenu for MockImportMenu {
+    async fn execute(
+        &mut self,
+        rows: &[MealRow],
+        dry_run: bool,
+    ) -> Result<MenuImportReport, ImportMenuUseCaseError> {
+        self.rows = Some(rows.to_vec());
+        self.dry_run = Some(dry_run);
+        self.response.clone()
+    }
+}
+
+impl MockImportMenu {
+    pub fn verify_invoked(&self, rows: &[MealRow], dry_run: bool) {
+        assert_eq!(self.rows.as_deref(), Some(rows));
+        assert_eq!(self.dry_run, Some(dry_run));
+    }
+
+    pub fn verify_empty(&self) {
+        assert!(self.rows.is_none());
+        assert!(self.dry_run.is_none());
+    }
+}
+
+#[derive(Default, Debug)]
+pub struct MockExportMenu {
+    pub response: Vec<MealRow>,
+    pub invoked: bool,
+}
+
+#[async_trait]
+impl ExportMenu for MockExportMenu {
+    async fn execute(&mut self) -> Vec<MealRow> {
+        self.invoked = true;
+        self.response.clone()
+    }
+}
+
+impl MockExportMenu {
+    pub fn verify_invoked(&self) {
+        assert!(self.invoked);
+    }
+
+    pub fn verify_empty(&self) {
+        assert!(!self.invoked);
     }
 }
 
@@ -109,6 +295,11 @@ pub fn rnd_meal_info() -> MealInfo {
         description: meal.description().clone(),
         price: meal.price().clone(),
         version: *meal.version(),
+        available: true,
+        image_urls: vec![],
+        allergens: meal.allergens().clone(),
+        nutrition_facts: meal.nutrition_facts().clone(),
+        availability_schedule: Some(rnd_availability_schedule()),
     }
 }
 
@@ -149,6 +340,97 @@ impl RemoveMealFromMenu for MockRemoveMealFromMenu {
     }
 }
 
+#[derive(Debug, Clone, PartialEq, SmartDefault)]
+pub struct MockSetMealPortions {
+    #[default(Ok(()))]
+    pub response: Result<(), SetMealPortionsUseCaseError>,
+    pub invoked_with: Option<(MealId, Count)>,
+}
+
+impl MockSetMealPortions {
+    pub fn verify_invoked(&self, meal_id: &MealId, portions: &Count) {
+        assert_eq!(self.invoked_with, Some((*meal_id, *portions)));
+    }
+
+    pub fn verify_empty(&self) {
+        assert!(self.invoked_with.is_none());
+    }
+}
+
+#[async_trait]
+impl SetMealPortions for MockSetMealPortions {
+    async fn execute(
+        &mut self,
+        meal_id: &MealId,
+        portions: Count,
+    ) -> Result<(), SetMealPortionsUseCaseError> {
+        self.invoked_with = Some((*meal_id, portions));
+        self.response
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, SmartDefault)]
+pub struct MockChangeMealAvailability {
+    #[default(Ok(()))]
+    pub response: Result<(), ChangeMealAvailabilityUseCaseError>,
+    pub invoked_with: Option<(MealId, Option<AvailabilitySchedule>)>,
+}
+
+impl MockChangeMealAvailability {
+    pub fn verify_invoked(&self, meal_id: &MealId, schedule: &Option<AvailabilitySchedule>) {
+        assert_eq!(self.invoked_with, Some((*meal_id, schedule.clone())));
+    }
+
+    pub fn verify_empty(&self) {
+        assert!(self.invoked_with.is_none());
+    }
+}
+
+#[async_trait]
+impl ChangeMealAvailability for MockChangeMealAvailability {
+    async fn execute(
+        &mut self,
+        meal_id: &MealId,
+        schedule: Option<AvailabilitySchedule>,
+    ) -> Result<(), ChangeMealAvailabilityUseCaseError> {
+        self.invoked_with = Some((*meal_id, schedule));
+        self.response
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, SmartDefault)]
+pub struct MockUploadMealImage {
+    #[default(_code = r#"Ok(Uri::from_static("http://localhost/media/1/1.png"))"#)]
+    pub response: Result<Uri, UploadMealImageUseCaseError>,
+    pub invoked_with: Option<(MealId, String, Vec<u8>)>,
+}
+
+impl MockUploadMealImage {
+    pub fn verify_invoked(&self, meal_id: &MealId, content_type: &str, content: &[u8]) {
+        assert_eq!(
+            self.invoked_with,
+            Some((*meal_id, content_type.to_string(), content.to_vec()))
+        );
+    }
+
+    pub fn verify_empty(&self) {
+        assert!(self.invoked_with.is_none());
+    }
+}
+
+#[async_trait]
+impl UploadMealImage for MockUploadMealImage {
+    async fn execute(
+        &mut self,
+        meal_id: &MealId,
+        content_type: &str,
+        content: Vec<u8>,
+    ) -> Result<Uri, UploadMealImageUseCaseError> {
+        self.invoked_with = Some((*meal_id, content_type.to_string(), content));
+        self.response.clone()
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, SmartDefault)]
 pub struct MockCancelOrder {
     #[default(Ok(()))]
@@ -185,6 +467,47 @@ impl ConfirmOrder for MockConfirmOrder {
     }
 }
 
+#[derive(Debug, SmartDefault)]
+pub struct MockCompleteOrder {
+    #[default(Ok(()))]
+    pub response: Result<(), CompleteOrderUseCaseError>,
+    pub id: Mutex<ShopOrderId>,
+}
+
+impl MockCompleteOrder {
+    pub fn verify_invoked(&self, id: &ShopOrderId) {
+        assert_eq!(&*self.id.lock().unwrap(), id);
+    }
+}
+
+#[async_trait]
+impl CompleteOrder for MockCompleteOrder {
+    async fn execute(&self, order_id: &ShopOrderId) -> Result<(), CompleteOrderUseCaseError> {
+        *self.id.lock().unwrap() = *order_id;
+        self.response.clone()
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct MockGetActiveOrders {
+    pub response: Vec<OrderDetails>,
+    pub invoked: bool,
+}
+
+impl MockGetActiveOrders {
+    pub fn verify_invoked(&self) {
+        assert!(self.invoked);
+    }
+}
+
+#[async_trait]
+impl GetActiveOrders for MockGetActiveOrders {
+    async fn execute(&mut self) -> Vec<OrderDetails> {
+        self.invoked = true;
+        self.response.clone()
+    }
+}
+
 #[derive(Debug, Clone, PartialEq)]
 pub struct MockGetOrderById {
     pub response: Result<OrderDetails, GetOrderByIdUseCaseError>,
@@ -211,6 +534,7 @@ impl GetOrderById for MockGetOrderById {
 #[derive(Debug, Clone, PartialEq)]
 pub struct MockGetOrders {
     pub response: Result<Vec<OrderDetails>, GetOrdersUseCaseError>,
+    pub criteria: ShopOrderCriteria,
     pub start_id: ShopOrderId,
     pub limit: usize,
 }
@@ -219,9 +543,11 @@ pub struct MockGetOrders {
 impl GetOrders for MockGetOrders {
     async fn execute(
         &mut self,
+        criteria: &ShopOrderCriteria,
         start_id: &ShopOrderId,
         limit: usize,
     ) -> Result<Vec<OrderDetails>, GetOrdersUseCaseError> {
+        self.criteria = criteria.clone();
         self.start_id = *start_id;
         self.limit = limit;
         self.response.clone()
@@ -230,7 +556,332 @@ impl GetOrders for MockGetOrders {
 
 impl MockGetOrders {
     pub fn verify_invoked(&self, start_id: &ShopOrderId, limit: &usize) {
+        self.verify_invoked_with_criteria(&ShopOrderCriteria::default(), start_id, limit);
+    }
+
+    pub fn verify_invoked_with_criteria(
+        &self,
+        criteria: &ShopOrderCriteria,
+        start_id: &ShopOrderId,
+        limit: &usize,
+    ) {
+        assert_eq!(&self.criteria, criteria);
         assert_eq!(&self.start_id, start_id);
         assert_eq!(&self.limit, limit);
     }
 }
+
+#[derive(SmartDefault, Debug, Clone, PartialEq)]
+pub struct MockGetCustomerOrders {
+    #[default(Ok(vec![]))]
+    pub response: Result<Vec<OrderDetails>, GetCustomerOrdersUseCaseError>,
+    pub invoked_with: Option<(CustomerId, ShopOrderId, usize)>,
+}
+
+#[async_trait]
+impl GetCustomerOrders for MockGetCustomerOrders {
+    async fn execute(
+        &mut self,
+        for_customer: &CustomerId,
+        start_id: &ShopOrderId,
+        limit: usize,
+    ) -> Result<Vec<OrderDetails>, GetCustomerOrdersUseCaseError> {
+        self.invoked_with = Some((*for_customer, *start_id, limit));
+        self.response.clone()
+    }
+}
+
+impl MockGetCustomerOrders {
+    pub fn verify_invoked(&self, for_customer: &CustomerId, start_id: &ShopOrderId, limit: &usize) {
+        assert_eq!(self.invoked_with, Some((*for_customer, *start_id, *limit)));
+    }
+
+    pub fn verify_empty(&self) {
+        assert!(self.invoked_with.is_none());
+    }
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct MockGetLastOrderState {
+    pub response: Result<OrderState, GetLastOrderStateUseCaseError>,
+    pub for_customer: Option<CustomerId>,
+}
+
+#[async_trait]
+impl GetLastOrderState for MockGetLastOrderState {
+    async fn execute(
+        &mut self,
+        for_customer: &CustomerId,
+    ) -> Result<OrderState, GetLastOrderStateUseCaseError> {
+        self.for_customer = Some(*for_customer);
+        self.response.clone()
+    }
+}
+
+impl MockGetLastOrderState {
+    pub fn verify_invoked(&self, for_customer: &CustomerId) {
+        assert_eq!(self.for_customer, Some(*for_customer));
+    }
+}
+
+#[derive(new, Debug)]
+pub struct MockGetAvailableDeliverySlots {
+    pub response: Vec<AvailableDeliverySlot>,
+    #[new(default)]
+    pub date: Option<Date>,
+}
+
+#[async_trait]
+impl GetAvailableDeliverySlots for MockGetAvailableDeliverySlots {
+    async fn execute(&mut self, date: Date) -> Vec<AvailableDeliverySlot> {
+        self.date = Some(date);
+        self.response.clone()
+    }
+}
+
+impl MockGetAvailableDeliverySlots {
+    pub fn verify_invoked(&self, date: &Date) {
+        assert_eq!(self.date, Some(*date));
+    }
+
+    pub fn verify_empty(&self) {
+        assert!(self.date.is_none());
+    }
+}
+
+pub fn rnd_customer_info() -> CustomerInfo {
+    let mut customer = rnd_customer();
+    customer.add_address(rnd_address());
+    CustomerInfo::from(customer)
+}
+
+#[derive(Debug, SmartDefault)]
+pub struct MockRegisterCustomer {
+    #[default(Ok(rnd_customer_id()))]
+    pub response: Result<CustomerId, RegisterCustomerUseCaseError>,
+    pub name: CustomerName,
+    pub phone: PhoneNumber,
+}
+
+#[async_trait]
+impl RegisterCustomer for MockRegisterCustomer {
+    async fn execute(
+        &mut self,
+        name: &CustomerName,
+        phone: &PhoneNumber,
+    ) -> Result<CustomerId, RegisterCustomerUseCaseError> {
+        self.name = name.clone();
+        self.phone = phone.clone();
+        self.response
+    }
+}
+
+impl MockRegisterCustomer {
+    pub fn verify_invoked(&self, name: &CustomerName, phone: &PhoneNumber) {
+        assert_eq!(&self.name, name);
+        assert_eq!(&self.phone, phone);
+    }
+}
+
+#[derive(Debug, Clone, SmartDefault)]
+pub struct MockGetCustomerById {
+    #[default(Ok(rnd_customer_info()))]
+    pub response: Result<CustomerInfo, GetCustomerByIdUseCaseError>,
+    pub id: CustomerId,
+}
+
+#[async_trait]
+impl GetCustomerById for MockGetCustomerById {
+    async fn execute(
+        &mut self,
+        id: &CustomerId,
+    ) -> Result<CustomerInfo, GetCustomerByIdUseCaseError> {
+        self.id = *id;
+        self.response.clone()
+    }
+}
+
+impl MockGetCustomerById {
+    pub fn verify_invoked(&self, id: &CustomerId) {
+        assert_eq!(&self.id, id);
+    }
+}
+
+#[derive(Debug, SmartDefault)]
+pub struct MockUpdateCustomer {
+    #[default(Ok(()))]
+    pub response: Result<(), UpdateCustomerUseCaseError>,
+    pub request: Option<UpdateCustomerRequest>,
+}
+
+#[async_trait]
+impl UpdateCustomer for MockUpdateCustomer {
+    async fn execute(
+        &mut self,
+        request: &UpdateCustomerRequest,
+    ) -> Result<(), UpdateCustomerUseCaseError> {
+        self.request = Some(request.clone());
+        self.response
+    }
+}
+
+impl MockUpdateCustomer {
+    pub fn verify_invoked(&self, request: &UpdateCustomerRequest) {
+        assert_eq!(self.request.as_ref(), Some(request));
+    }
+
+    pub fn verify_empty(&self) {
+        assert!(self.request.is_none());
+    }
+}
+
+#[derive(Debug, SmartDefault)]
+pub struct MockRemoveCustomer {
+    #[default(Ok(()))]
+    pub response: Result<(), RemoveCustomerUseCaseError>,
+    pub id: CustomerId,
+}
+
+#[async_trait]
+impl RemoveCustomer for MockRemoveCustomer {
+    async fn execute(&mut self, id: &CustomerId) -> Result<(), RemoveCustomerUseCaseError> {
+        self.id = *id;
+        self.response
+    }
+}
+
+#[derive(Debug, SmartDefault)]
+pub struct MockAddCustomerAddress {
+    #[default(Ok(SavedAddressId::new()))]
+    pub response: Result<SavedAddressId, AddCustomerAddressUseCaseError>,
+    pub id: CustomerId,
+    pub address: Option<Address>,
+}
+
+#[async_trait]
+impl AddCustomerAddress for MockAddCustomerAddress {
+    async fn execute(
+        &mut self,
+        id: &CustomerId,
+        address: &Address,
+    ) -> Result<SavedAddressId, AddCustomerAddressUseCaseError> {
+        self.id = *id;
+        self.address = Some(address.clone());
+        self.response
+    }
+}
+
+impl MockAddCustomerAddress {
+    pub fn verify_invoked(&self, id: &CustomerId, address: &Address) {
+        assert_eq!(&self.id, id);
+        assert_eq!(self.address.as_ref(), Some(address));
+    }
+}
+
+#[derive(Debug, SmartDefault)]
+pub struct MockRemoveCustomerAddress {
+    #[default(Ok(()))]
+    pub response: Result<(), RemoveCustomerAddressUseCaseError>,
+    pub id: CustomerId,
+    pub address_id: SavedAddressId,
+}
+
+#[async_trait]
+impl RemoveCustomerAddress for MockRemoveCustomerAddress {
+    async fn execute(
+        &mut self,
+        id: &CustomerId,
+        address_id: &SavedAddressId,
+    ) -> Result<(), RemoveCustomerAddressUseCaseError> {
+        self.id = *id;
+        self.address_id = *address_id;
+        self.response
+    }
+}
+
+impl MockRemoveCustomerAddress {
+    pub fn verify_invoked(&self, id: &CustomerId, address_id: &SavedAddressId) {
+        assert_eq!(&self.id, id);
+        assert_eq!(&self.address_id, address_id);
+    }
+}
+
+#[derive(Debug, SmartDefault)]
+pub struct MockSubscribeWebhook {
+    #[default(Ok(rnd_webhook_subscription_id()))]
+    pub response: Result<WebhookSubscriptionId, SubscribeWebhookUseCaseError>,
+    pub request: Option<(WebhookUrl, WebhookSecret, Vec<WebhookEventType>)>,
+}
+
+#[async_trait]
+impl SubscribeWebhook for MockSubscribeWebhook {
+    async fn execute(
+        &mut self,
+        url: &WebhookUrl,
+        secret: &WebhookSecret,
+        event_types: &[WebhookEventType],
+    ) -> Result<WebhookSubscriptionId, SubscribeWebhookUseCaseError> {
+        self.request = Some((url.clone(), secret.clone(), event_types.to_vec()));
+        self.response
+    }
+}
+
+impl MockSubscribeWebhook {
+    pub fn verify_invoked(
+        &self,
+        url: &WebhookUrl,
+        secret: &WebhookSecret,
+        event_types: &[WebhookEventType],
+    ) {
+        assert_eq!(
+            self.request,
+            Some((url.clone(), secret.clone(), event_types.to_vec()))
+        );
+    }
+
+    pub fn verify_not_invoked(&self) {
+        assert!(self.request.is_none());
+    }
+}
+
+#[derive(new, Debug)]
+pub struct MockGetWebhookSubscriptions {
+    pub response: Vec<WebhookSubscriptionInfo>,
+}
+
+#[async_trait]
+impl GetWebhookSubscriptions for MockGetWebhookSubscriptions {
+    async fn execute(&mut self) -> Vec<WebhookSubscriptionInfo> {
+        self.response.clone()
+    }
+}
+
+#[derive(Debug, SmartDefault)]
+pub struct MockRemoveWebhookSubscription {
+    #[default(Ok(()))]
+    pub response: Result<(), RemoveWebhookSubscriptionUseCaseError>,
+    pub id: Option<WebhookSubscriptionId>,
+}
+
+#[async_trait]
+impl RemoveWebhookSubscription for MockRemoveWebhookSubscription {
+    async fn execute(
+        &mut self,
+        id: &WebhookSubscriptionId,
+    ) -> Result<(), RemoveWebhookSubscriptionUseCaseError> {
+        self.id = Some(*id);
+        self.response
+    }
+}
+
+#[derive(new, Debug)]
+pub struct MockGetFailedWebhookDeliveries {
+    pub response: Vec<FailedWebhookDelivery>,
+}
+
+#[async_trait]
+impl GetFailedWebhookDeliveries for MockGetFailedWebhookDeliveries {
+    async fn execute(&mut self) -> Vec<FailedWebhookDelivery> {
+        self.response.clone()
+    }
+}
@@ -0,0 +1,96 @@
+use std::fmt::Debug;
+
+use actix_web::{HttpResponse, http::header::ContentType, middleware::from_fn, web};
+use common::{common_rest::GenericErrorResponse, types::base::AM};
+use usecase::webhook::GetFailedWebhookDeliveries;
+
+use crate::{
+    auth::jwt_middleware::require_admin, endpoint_url::API_V1_WEBHOOKS_FAILED,
+    order::order_model::ToModel, webhook::webhook_model::FailedWebhookDeliveryModel,
+};
+
+/// Get the webhooks which couldn't be delivered after all the attempts, the most recent first
+///
+/// Requires the `admin` role
+#[utoipa::path(
+    get,
+    path = API_V1_WEBHOOKS_FAILED,
+    tag = "Webhook",
+    security(("bearerAuth" = ["admin"])),
+    responses(
+        (
+            status = UNAUTHORIZED,
+            description = "Missing or invalid bearer token",
+            body = GenericErrorResponse,
+            example = json!({"type":"http://0.0.0.0:8080/unauthorized","title":"Unauthorized","status":401})
+        ),
+        (
+            status = FORBIDDEN,
+            description = "Caller is not allowed to perform the operation",
+            body = GenericErrorResponse,
+            example = json!({"type":"http://0.0.0.0:8080/forbidden","title":"Forbidden","status":403})
+        ),
+        (
+            status = OK,
+            body = Vec<FailedWebhookDeliveryModel>,
+            description = "OK"
+        ),
+    )
+)]
+pub async fn get_failed_webhook_deliveries_endpoint<
+    T: GetFailedWebhookDeliveries + Send + Debug,
+>(
+    shared_state: web::Data<AM<T>>,
+) -> HttpResponse {
+    let list: Vec<FailedWebhookDeliveryModel> = shared_state
+        .lock()
+        .await
+        .execute()
+        .await
+        .into_iter()
+        .map(|it| it.to_model())
+        .collect();
+    HttpResponse::Ok()
+        .content_type(ContentType::json())
+        .body(serde_json::to_string(&list).unwrap())
+}
+
+pub fn get_failed_webhook_deliveries_endpoint_config<T>(cfg: &mut web::ServiceConfig)
+where
+    T: GetFailedWebhookDeliveries + Send + Debug + 'static,
+{
+    cfg.route(
+        API_V1_WEBHOOKS_FAILED,
+        web::get()
+            .to(get_failed_webhook_deliveries_endpoint::<T>)
+            .wrap(from_fn(require_admin)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{body::MessageBody, http::StatusCode, web::Data};
+    use common::types::base::AMTrait;
+    use usecase::test_fixtures::rnd_failed_webhook_delivery;
+
+    use super::*;
+    use crate::test_fixtures::MockGetFailedWebhookDeliveries;
+
+    #[actix_web::test]
+    async fn returned_successfully() {
+        let first = rnd_failed_webhook_delivery();
+        let second = rnd_failed_webhook_delivery();
+        let mock_get_deliveries = AM::new_am(MockGetFailedWebhookDeliveries::new(vec![
+            first.clone(),
+            second.clone(),
+        ]));
+        let mock_shared_state = Data::new(mock_get_deliveries.clone());
+
+        let resp = get_failed_webhook_deliveries_endpoint(mock_shared_state).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body = resp.into_body().try_into_bytes().unwrap();
+        let list: Vec<FailedWebhookDeliveryModel> = serde_json::from_slice(&body).unwrap();
+        assert_eq!(list, vec![first.to_model(), second.to_model()]);
+    }
+}
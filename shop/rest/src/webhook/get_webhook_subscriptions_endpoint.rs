@@ -0,0 +1,96 @@
+use std::fmt::Debug;
+
+use actix_web::{HttpResponse, http::header::ContentType, middleware::from_fn, web};
+use common::{common_rest::GenericErrorResponse, types::base::AM};
+use usecase::webhook::GetWebhookSubscriptions;
+
+use crate::{
+    auth::jwt_middleware::require_admin, endpoint_url::API_V1_WEBHOOKS_GET_ALL,
+    order::order_model::ToModel, webhook::webhook_model::WebhookSubscriptionModel,
+};
+
+/// Get the webhook subscriptions, their secrets are not shown
+///
+/// Requires the `admin` role
+#[utoipa::path(
+    get,
+    path = API_V1_WEBHOOKS_GET_ALL,
+    tag = "Webhook",
+    security(("bearerAuth" = ["admin"])),
+    responses(
+        (
+            status = UNAUTHORIZED,
+            description = "Missing or invalid bearer token",
+            body = GenericErrorResponse,
+            example = json!({"type":"http://0.0.0.0:8080/unauthorized","title":"Unauthorized","status":401})
+        ),
+        (
+            status = FORBIDDEN,
+            description = "Caller is not allowed to perform the operation",
+            body = GenericErrorResponse,
+            example = json!({"type":"http://0.0.0.0:8080/forbidden","title":"Forbidden","status":403})
+        ),
+        (
+            status = OK,
+            body = Vec<WebhookSubscriptionModel>,
+            description = "OK"
+        ),
+    )
+)]
+pub async fn get_webhook_subscriptions_endpoint<T: GetWebhookSubscriptions + Send + Debug>(
+    shared_state: web::Data<AM<T>>,
+) -> HttpResponse {
+    let list: Vec<WebhookSubscriptionModel> = shared_state
+        .lock()
+        .await
+        .execute()
+        .await
+        .into_iter()
+        .map(|it| it.to_model())
+        .collect();
+    HttpResponse::Ok()
+        .content_type(ContentType::json())
+        .body(serde_json::to_string(&list).unwrap())
+}
+
+pub fn get_webhook_subscriptions_endpoint_config<T>(cfg: &mut web::ServiceConfig)
+where
+    T: GetWebhookSubscriptions + Send + Debug + 'static,
+{
+    cfg.route(
+        API_V1_WEBHOOKS_GET_ALL,
+        web::get()
+            .to(get_webhook_subscriptions_endpoint::<T>)
+            .wrap(from_fn(require_admin)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{body::MessageBody, http::StatusCode, web::Data};
+    use common::types::base::AMTrait;
+    use domain::{test_fixtures::*, webhook::value_objects::webhook_event_type::WebhookEventType};
+    use usecase::webhook::dto::webhook_subscription_info::WebhookSubscriptionInfo;
+
+    use super::*;
+    use crate::test_fixtures::MockGetWebhookSubscriptions;
+
+    #[actix_web::test]
+    async fn returned_successfully() {
+        let subscription = WebhookSubscriptionInfo::from(rnd_webhook_subscription(vec![
+            WebhookEventType::OrderReadyForPickup,
+        ]));
+        let mock_get_subscriptions =
+            AM::new_am(MockGetWebhookSubscriptions::new(vec![subscription.clone()]));
+        let mock_shared_state = Data::new(mock_get_subscriptions.clone());
+
+        let resp = get_webhook_subscriptions_endpoint(mock_shared_state).await;
+
+        assert_eq!(resp.status(), StatusCode::OK);
+        let body = resp.into_body().try_into_bytes().unwrap();
+        let list: Vec<WebhookSubscriptionModel> = serde_json::from_slice(&body).unwrap();
+        assert_eq!(list, vec![subscription.to_model()]);
+        assert_eq!(list[0].event_types, vec!["order.ready_for_pickup"]);
+        assert!(!std::str::from_utf8(&body).unwrap().contains("secret"));
+    }
+}
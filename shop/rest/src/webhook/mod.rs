@@ -0,0 +1,6 @@
+pub mod get_failed_webhook_deliveries_endpoint;
+pub mod get_webhook_subscriptions_endpoint;
+pub mod remove_webhook_subscription_endpoint;
+pub mod subscribe_webhook_endpoint;
+pub mod validation;
+pub mod webhook_model;
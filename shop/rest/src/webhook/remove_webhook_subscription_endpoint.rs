@@ -0,0 +1,187 @@
+use std::fmt::Debug;
+
+use actix_web::{HttpRequest, HttpResponse, http::StatusCode, middleware::from_fn, web};
+use common::{
+    common_rest::{
+        GenericErrorResponse, get_json_from_http_response, resource_not_found,
+        to_invalid_param_bad_request,
+    },
+    types::base::{AM, RCell, RcRefCellTrait},
+};
+use domain::webhook::value_objects::webhook_subscription_id::WebhookSubscriptionId;
+use usecase::webhook::{RemoveWebhookSubscription, RemoveWebhookSubscriptionUseCaseError};
+
+use crate::{
+    auth::jwt_middleware::require_admin, endpoint_url::API_V1_WEBHOOKS_DELETE_BY_ID,
+    to_error::ToRestError, validated::Validated,
+};
+
+/// Remove a webhook subscription
+///
+/// Requires the `admin` role
+#[utoipa::path(
+    delete,
+    path = API_V1_WEBHOOKS_DELETE_BY_ID,
+    tag = "Webhook",
+    security(("bearerAuth" = ["admin"])),
+    params(
+        ("id" = String, Path, description = "Webhook subscription id")
+    ),
+    responses(
+        (
+            status = UNAUTHORIZED,
+            description = "Missing or invalid bearer token",
+            body = GenericErrorResponse,
+            example = json!({"type":"http://0.0.0.0:8080/unauthorized","title":"Unauthorized","status":401})
+        ),
+        (
+            status = FORBIDDEN,
+            description = "Caller is not allowed to perform the operation",
+            body = GenericErrorResponse,
+            example = json!({"type":"http://0.0.0.0:8080/forbidden","title":"Forbidden","status":403})
+        ),
+        (
+            status = NO_CONTENT,
+            description = "Webhook subscription successfully removed"
+        ),
+        (
+            status = BAD_REQUEST,
+            description = "Invalid id",
+            body = GenericErrorResponse,
+            example = json!(
+                {
+                    "type": "http://0.0.0.0:8080/bad_request",
+                    "title": "Bad request",
+                    "status": 400,
+                    "invalid_params":
+                    [
+                        {"message": "Wrong Webhook Subscription Id"}
+                    ]
+                }
+            )
+        ),
+        (
+            status = NOT_FOUND,
+            description = "Webhook subscription not found",
+            body = GenericErrorResponse,
+            example = json!(&(get_json_from_http_response(resource_not_found())))
+        )
+    ))]
+pub async fn remove_webhook_subscription_endpoint<T>(
+    shared_state: web::Data<AM<T>>,
+    req: HttpRequest,
+) -> HttpResponse
+where
+    T: RemoveWebhookSubscription + Send + Debug,
+{
+    let error_list = RCell::new_rc(vec![]);
+
+    let maybe_subscription_id = req
+        .match_info()
+        .get("id")
+        .and_then(|id| WebhookSubscriptionId::validated(id, error_list.clone()));
+
+    if let Some(subscription_id) = maybe_subscription_id {
+        match shared_state.lock().await.execute(&subscription_id).await {
+            Ok(_) => HttpResponse::new(StatusCode::NO_CONTENT),
+            Err(e) => e.to_rest_error(),
+        }
+    } else {
+        to_invalid_param_bad_request(error_list)
+    }
+}
+
+impl ToRestError for RemoveWebhookSubscriptionUseCaseError {
+    fn to_rest_error(self) -> HttpResponse {
+        resource_not_found()
+    }
+}
+
+pub fn remove_webhook_subscription_endpoint_config<T>(cfg: &mut web::ServiceConfig)
+where
+    T: RemoveWebhookSubscription + Send + Debug + 'static,
+{
+    cfg.route(
+        API_V1_WEBHOOKS_DELETE_BY_ID,
+        web::delete()
+            .to(remove_webhook_subscription_endpoint::<T>)
+            .wrap(from_fn(require_admin)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{body::MessageBody, test::TestRequest, web::Data};
+    use common::{
+        common_rest::{GenericErrorResponse, bad_request_type_url, not_found_type_url},
+        types::base::AMTrait,
+    };
+    use domain::test_fixtures::*;
+    use dotenvy::dotenv;
+
+    use super::*;
+    use crate::test_fixtures::MockRemoveWebhookSubscription;
+
+    #[actix_web::test]
+    async fn removed_successfully() {
+        let subscription_id = rnd_webhook_subscription_id();
+        let mock_remove = AM::new_am(MockRemoveWebhookSubscription::default());
+        let mock_shared_state = Data::new(mock_remove.clone());
+
+        let req = TestRequest::default()
+            .param("id", subscription_id.to_string())
+            .to_http_request();
+
+        let resp = remove_webhook_subscription_endpoint(mock_shared_state, req).await;
+
+        assert_eq!(resp.status(), StatusCode::NO_CONTENT);
+        assert_eq!(mock_remove.lock().await.id, Some(subscription_id));
+    }
+
+    #[actix_web::test]
+    async fn subscription_not_found() {
+        dotenv().ok();
+        let mock_remove = AM::new_am(MockRemoveWebhookSubscription::default());
+        mock_remove.lock().await.response =
+            Err(RemoveWebhookSubscriptionUseCaseError::SubscriptionNotFound);
+        let mock_shared_state = Data::new(mock_remove.clone());
+
+        let req = TestRequest::default()
+            .param("id", rnd_webhook_subscription_id().to_string())
+            .to_http_request();
+
+        let resp = remove_webhook_subscription_endpoint(mock_shared_state, req).await;
+
+        assert_eq!(resp.status(), StatusCode::NOT_FOUND);
+
+        let body = resp.into_body().try_into_bytes().unwrap();
+        let body_text = std::str::from_utf8(&body).unwrap();
+
+        let response_dto: GenericErrorResponse = serde_json::from_str(body_text).unwrap();
+
+        assert_eq!(&response_dto.response_type, &not_found_type_url());
+    }
+
+    #[actix_web::test]
+    async fn invalid_id() {
+        dotenv().ok();
+        let mock_remove = AM::new_am(MockRemoveWebhookSubscription::default());
+        let mock_shared_state = Data::new(mock_remove.clone());
+
+        let req = TestRequest::default()
+            .param("id", "not-a-uuid")
+            .to_http_request();
+
+        let resp = remove_webhook_subscription_endpoint(mock_shared_state, req).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+
+        let body = resp.into_body().try_into_bytes().unwrap();
+        let body_text = std::str::from_utf8(&body).unwrap();
+
+        let response_dto: GenericErrorResponse = serde_json::from_str(body_text).unwrap();
+
+        assert_eq!(&response_dto.response_type, &bad_request_type_url());
+        assert_eq!(mock_remove.lock().await.id, None);
+    }
+}
@@ -0,0 +1,286 @@
+use std::fmt::Debug;
+
+use actix_web::{HttpResponse, http, middleware::from_fn, web};
+use common::{
+    common_rest::{
+        GenericErrorResponse, created, rest_business_error, to_invalid_param_bad_request,
+    },
+    types::base::{AM, RCell, RcRefCellTrait},
+};
+use derive_new::new;
+use domain::webhook::value_objects::{
+    webhook_event_type::WebhookEventType, webhook_secret::WebhookSecret, webhook_url::WebhookUrl,
+};
+use http::Uri;
+use serde::{Deserialize, Serialize};
+use usecase::webhook::{SubscribeWebhook, SubscribeWebhookUseCaseError};
+use utoipa::ToSchema;
+
+use crate::{
+    auth::jwt_middleware::require_admin,
+    endpoint_url::{API_V1_WEBHOOKS_DELETE_BY_ID, API_V1_WEBHOOKS_SUBSCRIBE},
+    to_error::ToRestError,
+    validated::Validated,
+};
+
+#[derive(new, Serialize, Deserialize, Debug, ToSchema)]
+pub struct SubscribeWebhookRestRequest {
+    /// Address the webhooks are posted to
+    #[schema(example = "https://partner.example.com/hooks", required = true)]
+    url: String,
+    /// Key the payloads are signed with, at least 16 characters
+    #[schema(example = "4f1c9a7e2b6d8e3f", required = true)]
+    secret: String,
+    /// Events to notify about
+    #[schema(example = json!(["order.ready_for_pickup", "order.completed"]), required = true)]
+    event_types: Vec<String>,
+}
+
+/// Subscribe a partner to the order webhooks
+///
+/// Requires the `admin` role. Each webhook is a `POST` of a JSON payload, the `X-Webhook-Signature`
+/// header carries `sha256=` and the hex HMAC-SHA256 of the body keyed with the secret.
+/// Known event types are `order.created`, `order.paid`, `order.confirmed`, `order.ready_for_pickup`,
+/// `order.completed` and `order.cancelled`
+#[utoipa::path(
+    post,
+    path = API_V1_WEBHOOKS_SUBSCRIBE,
+    tag = "Webhook",
+    security(("bearerAuth" = ["admin"])),
+    request_body(
+        content = SubscribeWebhookRestRequest,
+    ),
+    responses(
+        (
+            status = UNAUTHORIZED,
+            description = "Missing or invalid bearer token",
+            body = GenericErrorResponse,
+            example = json!({"type":"http://0.0.0.0:8080/unauthorized","title":"Unauthorized","status":401})
+        ),
+        (
+            status = FORBIDDEN,
+            description = "Caller is not allowed to perform the operation",
+            body = GenericErrorResponse,
+            example = json!({"type":"http://0.0.0.0:8080/forbidden","title":"Forbidden","status":403})
+        ),
+        (
+            status = CREATED,
+            description = "Created",
+            headers(
+                ("location" = String, description = "Location of new Webhook Subscription")
+            )
+        ),
+        (
+            status = BAD_REQUEST,
+            description = "Bad request",
+            body = GenericErrorResponse,
+            example = json!(
+                {
+                    "type":"http://0.0.0.0:8080/bad_request",
+                    "title":"Bad request",
+                    "status":400,
+                    "invalid_params":
+                    [
+                        {"message":"Webhook url must be an absolute http or https url"},
+                        {"message":"Unknown event type 'order.eaten'"}
+                    ]
+                }
+            )
+        ),
+        (
+            status = UNPROCESSABLE_ENTITY,
+            description = "No event types",
+            body = GenericErrorResponse,
+            example = json!({"type":"http://0.0.0.0:8080/no_event_types","title":"At least one event type is required","status":422})
+        ),
+    ))]
+pub async fn subscribe_webhook_endpoint<T>(
+    shared_state: web::Data<AM<T>>,
+    request: web::Json<SubscribeWebhookRestRequest>,
+) -> HttpResponse
+where
+    T: SubscribeWebhook + Send + Debug,
+{
+    let error_list = RCell::new_rc(vec![]);
+
+    let url = WebhookUrl::validated(&request.url, error_list.clone());
+    let secret = WebhookSecret::validated(&request.secret, error_list.clone());
+    let event_types =
+        Vec::<WebhookEventType>::validated(request.event_types.as_slice(), error_list.clone());
+
+    match (url, secret, event_types) {
+        (Some(url), Some(secret), Some(event_types)) => {
+            match shared_state
+                .lock()
+                .await
+                .execute(&url, &secret, &event_types)
+                .await
+            {
+                Ok(subscription_id) => created(
+                    API_V1_WEBHOOKS_DELETE_BY_ID
+                        .replace("{id}", &subscription_id.to_string())
+                        .parse::<Uri>()
+                        .unwrap(),
+                ),
+                Err(e) => e.to_rest_error(),
+            }
+        }
+        _ => to_invalid_param_bad_request(error_list),
+    }
+}
+
+impl ToRestError for SubscribeWebhookUseCaseError {
+    fn to_rest_error(self) -> HttpResponse {
+        match self {
+            SubscribeWebhookUseCaseError::NoEventTypes => {
+                rest_business_error("At least one event type is required", "no_event_types")
+            }
+        }
+    }
+}
+
+pub fn subscribe_webhook_endpoint_config<T>(cfg: &mut web::ServiceConfig)
+where
+    T: SubscribeWebhook + Send + Debug + 'static,
+{
+    cfg.route(
+        API_V1_WEBHOOKS_SUBSCRIBE,
+        web::post()
+            .to(subscribe_webhook_endpoint::<T>)
+            .wrap(from_fn(require_admin)),
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use actix_web::{
+        body::MessageBody,
+        http::{StatusCode, header},
+        web::{Data, Json},
+    };
+    use common::{
+        common_rest::{bad_request_type_url, error_type_url},
+        types::base::AMTrait,
+    };
+    use domain::test_fixtures::*;
+    use dotenvy::dotenv;
+
+    use super::*;
+    use crate::test_fixtures::MockSubscribeWebhook;
+
+    #[actix_web::test]
+    async fn created_successfully() {
+        dotenv().ok();
+        let subscription_id = rnd_webhook_subscription_id();
+        let url = rnd_webhook_url();
+        let secret = rnd_webhook_secret();
+
+        let mock_subscribe_webhook = AM::new_am(MockSubscribeWebhook::default());
+        mock_subscribe_webhook.lock().await.response = Ok(subscription_id);
+        let mock_shared_state = Data::new(mock_subscribe_webhook.clone());
+
+        let request = Json(SubscribeWebhookRestRequest::new(
+            url.to_string(),
+            secret.to_string_value(),
+            vec!["order.paid".to_string(), "order.completed".to_string()],
+        ));
+
+        let resp = subscribe_webhook_endpoint(mock_shared_state, request).await;
+
+        mock_subscribe_webhook.lock().await.verify_invoked(
+            &url,
+            &secret,
+            &[
+                WebhookEventType::OrderPaid,
+                WebhookEventType::OrderCompleted,
+            ],
+        );
+
+        let header = resp
+            .headers()
+            .get(header::LOCATION)
+            .unwrap()
+            .to_str()
+            .unwrap();
+
+        assert_eq!(&resp.status(), &StatusCode::CREATED);
+        assert_eq!(
+            header,
+            API_V1_WEBHOOKS_DELETE_BY_ID.replace("{id}", &subscription_id.to_string())
+        );
+    }
+
+    #[actix_web::test]
+    async fn validation_error() {
+        dotenv().ok();
+        let mock_subscribe_webhook = AM::new_am(MockSubscribeWebhook::default());
+        let mock_shared_state = Data::new(mock_subscribe_webhook.clone());
+
+        let request = Json(SubscribeWebhookRestRequest::new(
+            "ftp://partner.example.com".to_string(),
+            "short".to_string(),
+            vec!["order.paid".to_string(), "order.eaten".to_string()],
+        ));
+
+        let resp = subscribe_webhook_endpoint(mock_shared_state, request).await;
+        let body = resp.into_body().try_into_bytes().unwrap();
+        let body_text = std::str::from_utf8(&body).unwrap();
+
+        let response_dto: GenericErrorResponse = serde_json::from_str(body_text).unwrap();
+
+        assert_eq!(
+            &response_dto.response_status,
+            &StatusCode::BAD_REQUEST.as_u16()
+        );
+        assert_eq!(&response_dto.response_type, &bad_request_type_url());
+        assert_eq!(response_dto.invalid_params.len(), 3);
+        mock_subscribe_webhook.lock().await.verify_not_invoked();
+    }
+
+    #[actix_web::test]
+    async fn empty_event_types() {
+        dotenv().ok();
+        let mock_subscribe_webhook = AM::new_am(MockSubscribeWebhook::default());
+        let mock_shared_state = Data::new(mock_subscribe_webhook.clone());
+
+        let request = Json(SubscribeWebhookRestRequest::new(
+            rnd_webhook_url().to_string(),
+            rnd_webhook_secret().to_string_value(),
+            vec![],
+        ));
+
+        let resp = subscribe_webhook_endpoint(mock_shared_state, request).await;
+
+        assert_eq!(resp.status(), StatusCode::BAD_REQUEST);
+        mock_subscribe_webhook.lock().await.verify_not_invoked();
+    }
+
+    #[actix_web::test]
+    async fn use_case_error() {
+        dotenv().ok();
+        let mock_subscribe_webhook = AM::new_am(MockSubscribeWebhook::default());
+        mock_subscribe_webhook.lock().await.response =
+            Err(SubscribeWebhookUseCaseError::NoEventTypes);
+        let mock_shared_state = Data::new(mock_subscribe_webhook.clone());
+
+        let request = Json(SubscribeWebhookRestRequest::new(
+            rnd_webhook_url().to_string(),
+            rnd_webhook_secret().to_string_value(),
+            vec!["order.paid".to_string()],
+        ));
+
+        let resp = subscribe_webhook_endpoint(mock_shared_state, request).await;
+
+        assert_eq!(resp.status(), StatusCode::UNPROCESSABLE_ENTITY);
+
+        let body = resp.into_body().try_into_bytes().unwrap();
+        let body_text = std::str::from_utf8(&body).unwrap();
+
+        let response_dto: GenericErrorResponse = serde_json::from_str(body_text).unwrap();
+
+        assert_eq!(
+            &response_dto.response_type,
+            &error_type_url("no_event_types")
+        );
+    }
+}
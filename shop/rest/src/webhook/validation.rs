@@ -0,0 +1,83 @@
+use common::{common_rest::ValidationError, types::base::RCell};
+use domain::webhook::value_objects::{
+    webhook_event_type::{CreateWebhookEventTypeError, WebhookEventType},
+    webhook_secret::{CreateWebhookSecretError, WebhookSecret},
+    webhook_subscription_id::{WebhookSubscriptionId, WebhookSubscriptionIdError},
+    webhook_url::{CreateWebhookUrlError, WebhookUrl},
+};
+
+use crate::validated::Validated;
+
+impl Validated<&str> for WebhookUrl {
+    fn validated(val: &str, error_list: RCell<Vec<ValidationError>>) -> Option<Self> {
+        match Self::try_from(val) {
+            Ok(url) => Some(url),
+            Err(CreateWebhookUrlError::InvalidWebhookUrlError) => {
+                error_list.borrow_mut().push(ValidationError::new(
+                    "Webhook url must be an absolute http or https url",
+                ));
+                None
+            }
+        }
+    }
+}
+
+impl Validated<&str> for WebhookSecret {
+    fn validated(val: &str, error_list: RCell<Vec<ValidationError>>) -> Option<Self> {
+        match Self::try_from(val) {
+            Ok(secret) => Some(secret),
+            Err(CreateWebhookSecretError::TooShortSecretError) => {
+                error_list.borrow_mut().push(ValidationError::new(&format!(
+                    "Webhook secret must be at least {} characters long",
+                    WebhookSecret::MIN_LENGTH
+                )));
+                None
+            }
+            Err(CreateWebhookSecretError::InvalidSecretError) => {
+                error_list.borrow_mut().push(ValidationError::new(
+                    "Webhook secret must not contain spaces",
+                ));
+                None
+            }
+        }
+    }
+}
+
+impl Validated<&[String]> for Vec<WebhookEventType> {
+    fn validated(val: &[String], error_list: RCell<Vec<ValidationError>>) -> Option<Self> {
+        if val.is_empty() {
+            error_list
+                .borrow_mut()
+                .push(ValidationError::new("At least one event type is required"));
+            return None;
+        }
+        let mut event_types = vec![];
+        let mut valid = true;
+        for value in val {
+            match WebhookEventType::try_from(value.as_str()) {
+                Ok(event_type) => event_types.push(event_type),
+                Err(CreateWebhookEventTypeError::UnknownEventTypeError) => {
+                    error_list.borrow_mut().push(ValidationError::new(&format!(
+                        "Unknown event type '{value}'"
+                    )));
+                    valid = false;
+                }
+            }
+        }
+        valid.then_some(event_types)
+    }
+}
+
+impl Validated<&str> for WebhookSubscriptionId {
+    fn validated(val: &str, error_list: RCell<Vec<ValidationError>>) -> Option<Self> {
+        match Self::try_from(val) {
+            Ok(id) => Some(id),
+            Err(WebhookSubscriptionIdError::InvalidIdError) => {
+                error_list
+                    .borrow_mut()
+                    .push(ValidationError::new("Wrong Webhook Subscription Id"));
+                None
+            }
+        }
+    }
+}
@@ -0,0 +1,79 @@
+use serde::{Deserialize, Serialize};
+use time::format_description::well_known::Rfc3339;
+use usecase::webhook::dto::{
+    failed_webhook_delivery::FailedWebhookDelivery,
+    webhook_subscription_info::WebhookSubscriptionInfo,
+};
+use utoipa::ToSchema;
+
+use crate::order::order_model::ToModel;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct WebhookSubscriptionModel {
+    /// ID of the subscription
+    #[schema(example = "3f2b8c1e-6a0d-4d7e-9b5a-1c2d3e4f5a6b")]
+    pub id: String,
+    /// Address the webhooks are posted to
+    #[schema(example = "https://partner.example.com/hooks")]
+    pub url: String,
+    /// Events the partner is notified about
+    #[schema(example = json!(["order.ready_for_pickup", "order.completed"]))]
+    pub event_types: Vec<String>,
+    /// Version of the subscription
+    #[schema(example = 1)]
+    pub version: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, ToSchema)]
+pub struct FailedWebhookDeliveryModel {
+    /// ID of the subscription the webhook was sent for
+    #[schema(example = "3f2b8c1e-6a0d-4d7e-9b5a-1c2d3e4f5a6b")]
+    pub subscription_id: String,
+    /// ID of the event, sent in the `X-Webhook-Delivery` header
+    #[schema(example = "9d8c7b6a-5f4e-4d3c-2b1a-0f9e8d7c6b5a")]
+    pub event_id: String,
+    #[schema(example = "order.completed")]
+    pub event_type: String,
+    #[schema(example = "https://partner.example.com/hooks")]
+    pub url: String,
+    /// Body of the webhook
+    #[schema(
+        example = r#"{"id":"9d8c7b6a-5f4e-4d3c-2b1a-0f9e8d7c6b5a","type":"order.completed","order_id":54389}"#
+    )]
+    pub payload: String,
+    /// Number of attempts made
+    #[schema(example = 6)]
+    pub attempts: u32,
+    /// Error of the last attempt
+    #[schema(example = "Unexpected response status 503")]
+    pub last_error: String,
+    /// Time of the last attempt in RFC 3339
+    #[schema(example = "2024-06-03T08:15:00Z")]
+    pub failed_at: String,
+}
+
+impl ToModel<WebhookSubscriptionModel> for WebhookSubscriptionInfo {
+    fn to_model(self) -> WebhookSubscriptionModel {
+        WebhookSubscriptionModel {
+            id: self.id.to_string(),
+            url: self.url.to_string(),
+            event_types: self.event_types.iter().map(|it| it.to_string()).collect(),
+            version: self.version.to_i64(),
+        }
+    }
+}
+
+impl ToModel<FailedWebhookDeliveryModel> for FailedWebhookDelivery {
+    fn to_model(self) -> FailedWebhookDeliveryModel {
+        FailedWebhookDeliveryModel {
+            subscription_id: self.subscription_id.to_string(),
+            event_id: self.event_id.to_string(),
+            event_type: self.event_type.to_string(),
+            url: self.url.to_string(),
+            payload: self.payload,
+            attempts: self.attempts,
+            last_error: self.last_error,
+            failed_at: self.failed_at.format(&Rfc3339).unwrap(),
+        }
+    }
+}
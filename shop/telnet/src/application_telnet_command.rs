@@ -1 +1,592 @@
+use std::{error::Error, str::FromStr};
 
+use async_trait::async_trait;
+use futures_util::SinkExt;
+use log::info;
+use nectar::{TelnetCodec, event::TelnetEvent};
+use prettytable::{Table, row};
+use thiserror::Error;
+use tokio::net::TcpStream;
+use tokio_util::codec::Framed;
+use usecase::auth::role::Role;
+
+use crate::{long_parameter_extractor::extract_parameters, session::telnet_session::TelnetSession};
+
+pub type TelnetFrame = Framed<TcpStream, TelnetCodec>;
+
+/// Lists the registered commands, it can't be used as a command name
+pub const HELP_COMMAND: &str = "help";
+
+/// A command of the operator console
+#[async_trait]
+pub trait ApplicationTelnetCommand: Send + Sync {
+    /// Words which select the command, e.g. `get menu`
+    fn name(&self) -> &'static str;
+
+    fn description(&self) -> &'static str;
+
+    fn arguments(&self) -> &'static [CommandArgument] {
+        &[]
+    }
+
+    /// Staff can run any command unless it's restricted further
+    fn permitted(&self, role: Role) -> bool {
+        role.is_staff()
+    }
+
+    /// Question the user has to confirm before a destructive command runs
+    fn confirmation(&self, _arguments: &[String]) -> Option<String> {
+        None
+    }
+
+    /// `arguments` are already checked against [`ApplicationTelnetCommand::arguments`], an
+    /// omitted optional argument is missing at the end
+    async fn execute(
+        &self,
+        arguments: &[String],
+        frame: &mut TelnetFrame,
+    ) -> Result<(), Box<dyn Error + Send + Sync>>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CommandArgument {
+    Required(&'static str),
+    Optional(&'static str),
+    /// Takes the rest of the line, so it can only be the last argument
+    Remainder(&'static str),
+}
+
+impl CommandArgument {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Required(name) | Self::Optional(name) | Self::Remainder(name) => name,
+        }
+    }
+
+    fn usage(&self) -> String {
+        match self {
+            Self::Required(name) => format!("<{name}>"),
+            Self::Optional(name) => format!("[{name}]"),
+            Self::Remainder(name) => format!("<{name}...>"),
+        }
+    }
+}
+
+#[derive(Error, Debug, PartialEq, Eq, Clone)]
+pub enum CommandParseError {
+    #[error("Closing quote is missing")]
+    UnterminatedQuote,
+    #[error("Unknown command '{0}', type 'help' to list the commands")]
+    UnknownCommand(String),
+    #[error("Argument <{argument}> is missing, usage: {usage}")]
+    MissingArgument {
+        argument: &'static str,
+        usage: String,
+    },
+    #[error("Too many arguments, usage: {usage}")]
+    TooManyArguments { usage: String },
+    #[error("Invalid value '{value}' of argument <{argument}>")]
+    InvalidArgument {
+        argument: &'static str,
+        value: String,
+    },
+    /// Messages of the validation shared with the REST API
+    #[error("{}", .0.join("\n"))]
+    ValidationFailed(Vec<String>),
+}
+
+/// Parses the argument at `index`, `None` when it's omitted
+pub fn parse_argument<T: FromStr>(
+    arguments: &[String],
+    index: usize,
+    argument: &'static str,
+) -> Result<Option<T>, CommandParseError> {
+    arguments
+        .get(index)
+        .map(|value| {
+            value
+                .parse::<T>()
+                .map_err(|_| CommandParseError::InvalidArgument {
+                    argument,
+                    value: value.clone(),
+                })
+        })
+        .transpose()
+}
+
+pub fn usage(command: &dyn ApplicationTelnetCommand) -> String {
+    command
+        .arguments()
+        .iter()
+        .fold(command.name().to_string(), |usage, argument| {
+            format!("{usage} {}", argument.usage())
+        })
+}
+
+pub enum ParsedCommand<'a> {
+    Help,
+    Command {
+        command: &'a dyn ApplicationTelnetCommand,
+        arguments: Vec<String>,
+    },
+}
+
+/// Commands of the operator console, a line selects the command with the longest matching name
+#[derive(Default)]
+pub struct TelnetCommandRegistry {
+    commands: Vec<Box<dyn ApplicationTelnetCommand>>,
+}
+
+impl TelnetCommandRegistry {
+    pub fn register(mut self, command: impl ApplicationTelnetCommand + 'static) -> Self {
+        self.commands.push(Box::new(command));
+        self
+    }
+
+    pub fn parse(&self, line: &str) -> Result<ParsedCommand<'_>, CommandParseError> {
+        let words = extract_parameters(line)?;
+        if words.len() == 1 && words[0].eq_ignore_ascii_case(HELP_COMMAND) {
+            return Ok(ParsedCommand::Help);
+        }
+
+        let (command, name_length) = self
+            .commands
+            .iter()
+            .filter_map(|command| {
+                let name: Vec<&str> = command.name().split_whitespace().collect();
+                let matches = name.len() <= words.len()
+                    && name
+                        .iter()
+                        .zip(&words)
+                        .all(|(expected, word)| expected.eq_ignore_ascii_case(word));
+                matches.then_some((command.as_ref(), name.len()))
+            })
+            .max_by_key(|(_, name_length)| *name_length)
+            .ok_or_else(|| {
+                CommandParseError::UnknownCommand(words.first().cloned().unwrap_or_default())
+            })?;
+
+        let arguments = Self::check_arguments(command, words[name_length..].to_vec())?;
+        Ok(ParsedCommand::Command { command, arguments })
+    }
+
+    fn check_arguments(
+        command: &dyn ApplicationTelnetCommand,
+        mut arguments: Vec<String>,
+    ) -> Result<Vec<String>, CommandParseError> {
+        let expected = command.arguments();
+        for (index, argument) in expected.iter().enumerate() {
+            match argument {
+                CommandArgument::Required(name) | CommandArgument::Remainder(name)
+                    if arguments.len() <= index =>
+                {
+                    return Err(CommandParseError::MissingArgument {
+                        argument: name,
+                        usage: usage(command),
+                    });
+                }
+                CommandArgument::Remainder(_) => {
+                    let rest = arguments.split_off(index).join(" ");
+                    arguments.push(rest);
+                }
+                _ => {}
+            }
+        }
+        if arguments.len() > expected.len() {
+            return Err(CommandParseError::TooManyArguments {
+                usage: usage(command),
+            });
+        }
+        Ok(arguments)
+    }
+
+    /// Lists the commands the role is permitted to run
+    pub fn help(&self, role: Role) -> String {
+        let mut table = Table::new();
+        table.add_row(row!["Command", "Description"]);
+        for command in self
+            .commands
+            .iter()
+            .filter(|command| command.permitted(role))
+        {
+            table.add_row(row![usage(command.as_ref()), command.description()]);
+        }
+        table.add_row(row![HELP_COMMAND, "List the commands"]);
+        table.to_string()
+    }
+
+    /// Runs the command of the line on behalf of the session user, parse errors are reported to
+    /// the client
+    pub async fn execute(
+        &self,
+        line: &str,
+        session: &mut TelnetSession,
+        frame: &mut TelnetFrame,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let role = session.role();
+        let message = match self.parse(line) {
+            Ok(ParsedCommand::Help) => self.help(role),
+            Ok(ParsedCommand::Command { command, .. }) if !command.permitted(role) => {
+                format!("You are not permitted to run '{}'\n", command.name())
+            }
+            Ok(ParsedCommand::Command { command, arguments }) => {
+                if let Some(question) = command.confirmation(&arguments) {
+                    match Self::confirmed(&question, session, frame).await? {
+                        Some(true) => {}
+                        Some(false) => {
+                            frame
+                                .send(TelnetEvent::Message("Not confirmed\n".to_string()))
+                                .await?;
+                            return Ok(());
+                        }
+                        // The session is over
+                        None => return Ok(()),
+                    }
+                }
+                info!("Executing '{}' by Telnet", command.name());
+                match command.execute(&arguments, frame).await {
+                    Ok(()) => return Ok(()),
+                    // Commands parse the values of their arguments themselves
+                    Err(e) => match e.downcast::<CommandParseError>() {
+                        Ok(parse_error) => format!("{parse_error}\n"),
+                        Err(e) => return Err(e),
+                    },
+                }
+            }
+            Err(e) => format!("{e}\n"),
+        };
+
+        frame.send(TelnetEvent::Message(message)).await?;
+        Ok(())
+    }
+
+    /// Only `yes` confirms, `None` when the client is gone or idle for too long
+    async fn confirmed(
+        question: &str,
+        session: &mut TelnetSession,
+        frame: &mut TelnetFrame,
+    ) -> Result<Option<bool>, Box<dyn Error + Send + Sync>> {
+        frame
+            .send(TelnetEvent::Message(format!(
+                "{question} Type 'yes' to confirm\n"
+            )))
+            .await?;
+        Ok(session
+            .next_line(frame)
+            .await?
+            .map(|answer| answer.trim().eq_ignore_ascii_case("yes")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        sync::{
+            Arc,
+            atomic::{AtomicUsize, Ordering},
+        },
+        time::Duration,
+    };
+
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+
+    use super::*;
+    use crate::session::{telnet_session::TelnetSessionSettings, telnet_user_store::TelnetUser};
+
+    struct TestCommand {
+        name: &'static str,
+        arguments: &'static [CommandArgument],
+    }
+
+    #[async_trait]
+    impl ApplicationTelnetCommand for TestCommand {
+        fn name(&self) -> &'static str {
+            self.name
+        }
+
+        fn description(&self) -> &'static str {
+            "Test command"
+        }
+
+        fn arguments(&self) -> &'static [CommandArgument] {
+            self.arguments
+        }
+
+        async fn execute(
+            &self,
+            _arguments: &[String],
+            _frame: &mut TelnetFrame,
+        ) -> Result<(), Box<dyn Error + Send + Sync>> {
+            Ok(())
+        }
+    }
+
+    #[derive(Default)]
+    struct DestructiveCommand {
+        executed: Arc<AtomicUsize>,
+    }
+
+    #[async_trait]
+    impl ApplicationTelnetCommand for DestructiveCommand {
+        fn name(&self) -> &'static str {
+            "drop"
+        }
+
+        fn description(&self) -> &'static str {
+            "Destructive command"
+        }
+
+        fn confirmation(&self, _arguments: &[String]) -> Option<String> {
+            Some("Drop it?".to_string())
+        }
+
+        async fn execute(
+            &self,
+            _arguments: &[String],
+            _frame: &mut TelnetFrame,
+        ) -> Result<(), Box<dyn Error + Send + Sync>> {
+            self.executed.fetch_add(1, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    /// Server side frame and a plain client socket
+    async fn connect() -> (TelnetFrame, TcpStream) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let client = TcpStream::connect(listener.local_addr().unwrap())
+            .await
+            .unwrap();
+        let (server, _) = listener.accept().await.unwrap();
+        (Framed::new(server, TelnetCodec::new(1024)), client)
+    }
+
+    fn admin_session(settings: TelnetSessionSettings) -> TelnetSession {
+        TelnetSession::logged_in(
+            settings,
+            TelnetUser {
+                login: "boss".to_string(),
+                role: Role::Admin,
+            },
+        )
+    }
+
+    fn registry() -> TelnetCommandRegistry {
+        TelnetCommandRegistry::default()
+            .register(TestCommand {
+                name: "orders list",
+                arguments: &[
+                    CommandArgument::Optional("startId"),
+                    CommandArgument::Optional("limit"),
+                ],
+            })
+            .register(TestCommand {
+                name: "order",
+                arguments: &[CommandArgument::Required("id")],
+            })
+            .register(TestCommand {
+                name: "order confirm",
+                arguments: &[CommandArgument::Required("id")],
+            })
+            .register(TestCommand {
+                name: "search menu",
+                arguments: &[CommandArgument::Remainder("text")],
+            })
+    }
+
+    fn parsed(line: &str) -> (&'static str, Vec<String>) {
+        match registry().parse(line).unwrap() {
+            ParsedCommand::Command { command, arguments } => (command.name(), arguments),
+            ParsedCommand::Help => panic!("Help is not expected"),
+        }
+    }
+
+    #[test]
+    fn command_with_arguments() {
+        assert_eq!(
+            parsed("orders list 5 10"),
+            ("orders list", vec!["5".to_string(), "10".to_string()])
+        );
+    }
+
+    #[test]
+    fn optional_arguments_are_omitted() {
+        assert_eq!(parsed("ORDERS List"), ("orders list", vec![]));
+    }
+
+    #[test]
+    fn longest_name_wins() {
+        assert_eq!(
+            parsed("order confirm 7"),
+            ("order confirm", vec!["7".to_string()])
+        );
+        assert_eq!(parsed("order 7"), ("order", vec!["7".to_string()]));
+    }
+
+    #[test]
+    fn remainder_takes_rest_of_line() {
+        assert_eq!(
+            parsed("search menu  chicken   soup"),
+            ("search menu", vec!["chicken soup".to_string()])
+        );
+        assert_eq!(
+            parsed(r#"search menu "chicken   soup""#),
+            ("search menu", vec!["chicken   soup".to_string()])
+        );
+    }
+
+    #[test]
+    fn help() {
+        assert!(matches!(
+            registry().parse(" help ").unwrap(),
+            ParsedCommand::Help
+        ));
+        let help = registry().help(Role::KitchenStaff);
+        assert!(help.contains("orders list [startId] [limit]"));
+        assert!(help.contains("search menu <text...>"));
+        assert!(!registry().help(Role::Customer).contains("orders list"));
+    }
+
+    #[test]
+    fn unknown_command() {
+        assert_eq!(
+            registry().parse("get menu").err(),
+            Some(CommandParseError::UnknownCommand("get".to_string()))
+        );
+    }
+
+    #[test]
+    fn missing_argument() {
+        assert_eq!(
+            registry().parse("order confirm").err(),
+            Some(CommandParseError::MissingArgument {
+                argument: "id",
+                usage: "order confirm <id>".to_string()
+            })
+        );
+        assert_eq!(
+            registry().parse("search menu").err(),
+            Some(CommandParseError::MissingArgument {
+                argument: "text",
+                usage: "search menu <text...>".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn too_many_arguments() {
+        assert_eq!(
+            registry().parse("orders list 1 2 3").err(),
+            Some(CommandParseError::TooManyArguments {
+                usage: "orders list [startId] [limit]".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn unterminated_quote() {
+        assert_eq!(
+            registry().parse(r#"search menu "soup"#).err(),
+            Some(CommandParseError::UnterminatedQuote)
+        );
+    }
+
+    #[tokio::test]
+    async fn destructive_command_asks_for_confirmation() {
+        for (answer, expected_executions) in [("yes", 1), ("no", 0)] {
+            let command = DestructiveCommand::default();
+            let executed = command.executed.clone();
+            let registry = TelnetCommandRegistry::default().register(command);
+            let (mut server, mut client) = connect().await;
+
+            client
+                .write_all(format!("{answer}\r\n").as_bytes())
+                .await
+                .unwrap();
+            registry
+                .execute(
+                    "drop",
+                    &mut admin_session(TelnetSessionSettings::default()),
+                    &mut server,
+                )
+                .await
+                .unwrap();
+            drop(server);
+
+            assert_eq!(executed.load(Ordering::SeqCst), expected_executions);
+            let mut received = String::new();
+            client.read_to_string(&mut received).await.unwrap();
+            assert!(received.starts_with("Drop it? Type 'yes' to confirm"));
+        }
+    }
+
+    #[tokio::test]
+    async fn command_is_not_permitted() {
+        let command = DestructiveCommand::default();
+        let executed = command.executed.clone();
+        let registry = TelnetCommandRegistry::default().register(command);
+        let (mut server, mut client) = connect().await;
+
+        registry
+            .execute(
+                "drop",
+                &mut TelnetSession::new(TelnetSessionSettings::default()),
+                &mut server,
+            )
+            .await
+            .unwrap();
+        drop(server);
+
+        assert_eq!(executed.load(Ordering::SeqCst), 0);
+        let mut received = String::new();
+        client.read_to_string(&mut received).await.unwrap();
+        assert!(received.starts_with("You are not permitted to run 'drop'"));
+    }
+
+    #[tokio::test]
+    async fn unanswered_confirmation_times_out() {
+        let command = DestructiveCommand::default();
+        let executed = command.executed.clone();
+        let registry = TelnetCommandRegistry::default().register(command);
+        let mut session = admin_session(TelnetSessionSettings {
+            idle_timeout: Duration::from_millis(50),
+            ..Default::default()
+        });
+        let (mut server, mut client) = connect().await;
+
+        registry
+            .execute("drop", &mut session, &mut server)
+            .await
+            .unwrap();
+        let mut received = String::new();
+        while !received.contains("Session timed out") {
+            let mut buffer = [0; 1024];
+            let read = client.read(&mut buffer).await.unwrap();
+            assert_ne!(read, 0);
+            received.push_str(&String::from_utf8_lossy(&buffer[..read]));
+        }
+        assert!(!received.contains("Not confirmed"));
+
+        // A late answer neither confirms nor revives the session
+        client.write_all(b"yes\r\n").await.unwrap();
+        assert_eq!(session.next_line(&mut server).await.unwrap(), None);
+        assert_eq!(executed.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn argument_is_parsed() {
+        let arguments = vec!["5".to_string(), "many".to_string()];
+
+        assert_eq!(parse_argument::<i64>(&arguments, 0, "startId"), Ok(Some(5)));
+        assert_eq!(parse_argument::<i64>(&arguments, 2, "other"), Ok(None));
+        assert_eq!(
+            parse_argument::<usize>(&arguments, 1, "limit"),
+            Err(CommandParseError::InvalidArgument {
+                argument: "limit",
+                value: "many".to_string()
+            })
+        );
+    }
+}
@@ -3,4 +3,6 @@
 pub mod application_telnet_command;
 pub mod long_parameter_extractor;
 pub mod menu;
+pub mod order;
 pub mod server;
+pub mod session;
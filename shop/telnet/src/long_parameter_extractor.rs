@@ -1 +1,103 @@
+use crate::application_telnet_command::CommandParseError;
 
+/// Splits a command line into words. A parameter with spaces is put in double quotes,
+/// inside of them `\"` and `\\` stand for a quote and a backslash
+pub fn extract_parameters(line: &str) -> Result<Vec<String>, CommandParseError> {
+    let mut parameters = vec![];
+    let mut current = String::new();
+    // An empty quoted parameter is still a parameter
+    let mut has_parameter = false;
+    let mut in_quotes = false;
+    let mut chars = line.chars();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => {
+                in_quotes = !in_quotes;
+                has_parameter = true;
+            }
+            '\\' if in_quotes => match chars.next() {
+                Some(escaped @ ('"' | '\\')) => current.push(escaped),
+                Some(other) => {
+                    current.push('\\');
+                    current.push(other);
+                }
+                None => return Err(CommandParseError::UnterminatedQuote),
+            },
+            c if c.is_whitespace() && !in_quotes => {
+                if has_parameter {
+                    parameters.push(std::mem::take(&mut current));
+                    has_parameter = false;
+                }
+            }
+            c => {
+                current.push(c);
+                has_parameter = true;
+            }
+        }
+    }
+
+    if in_quotes {
+        return Err(CommandParseError::UnterminatedQuote);
+    }
+    if has_parameter {
+        parameters.push(current);
+    }
+    Ok(parameters)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn words_are_split_by_whitespace() {
+        assert_eq!(
+            extract_parameters("  orders\tlist 1   10 ").unwrap(),
+            vec!["orders", "list", "1", "10"]
+        );
+    }
+
+    #[test]
+    fn quoted_parameter_keeps_spaces() {
+        assert_eq!(
+            extract_parameters(r#"menu add "Chicken soup" "With \"fresh\" herbs" 9.99"#).unwrap(),
+            vec![
+                "menu",
+                "add",
+                "Chicken soup",
+                r#"With "fresh" herbs"#,
+                "9.99"
+            ]
+        );
+    }
+
+    #[test]
+    fn empty_quoted_parameter() {
+        assert_eq!(
+            extract_parameters(r#"menu add "" x"#).unwrap(),
+            vec!["menu", "add", "", "x"]
+        );
+    }
+
+    #[test]
+    fn unknown_escape_is_kept() {
+        assert_eq!(
+            extract_parameters(r#""C:\menu""#).unwrap(),
+            vec![r"C:\menu"]
+        );
+    }
+
+    #[test]
+    fn empty_line() {
+        assert!(extract_parameters("   ").unwrap().is_empty());
+    }
+
+    #[test]
+    fn unterminated_quote() {
+        assert_eq!(
+            extract_parameters(r#"search menu "soup"#),
+            Err(CommandParseError::UnterminatedQuote)
+        );
+    }
+}
@@ -0,0 +1,151 @@
+use std::{error::Error, fmt::Debug, str::FromStr, sync::Arc};
+
+use actix_web::web::Data;
+use async_trait::async_trait;
+use bigdecimal::BigDecimal;
+use common::types::base::{RCell, RcRefCellTrait};
+use domain::menu::value_objects::{
+    allergens::Allergens, meal_description::MealDescription, meal_name::MealName,
+    nutrition_facts::NutritionFacts, price::Price,
+};
+use futures_util::SinkExt;
+use nectar::event::TelnetEvent;
+use rest::validated::Validated;
+use tokio::sync::Mutex;
+use usecase::{auth::role::Role, menu::AddMealToMenu};
+
+use crate::{
+    application_telnet_command::{
+        ApplicationTelnetCommand, CommandArgument, CommandParseError, TelnetFrame,
+    },
+    menu::meal_view::validation_failed,
+};
+
+#[derive(Debug)]
+pub struct AddMealToMenuCommand<T> {
+    usecase: Data<Arc<Mutex<T>>>,
+}
+
+impl<T> AddMealToMenuCommand<T> {
+    pub fn new(usecase: Data<Arc<Mutex<T>>>) -> Self {
+        Self { usecase }
+    }
+}
+
+#[async_trait]
+impl<T> ApplicationTelnetCommand for AddMealToMenuCommand<T>
+where
+    T: AddMealToMenu + Send + Debug,
+{
+    fn name(&self) -> &'static str {
+        "menu add"
+    }
+
+    fn description(&self) -> &'static str {
+        "Add a meal without allergens and nutrition facts, quote words with spaces"
+    }
+
+    fn arguments(&self) -> &'static [CommandArgument] {
+        &[
+            CommandArgument::Required("name"),
+            CommandArgument::Required("description"),
+            CommandArgument::Required("price"),
+        ]
+    }
+
+    fn permitted(&self, role: Role) -> bool {
+        role == Role::Admin
+    }
+
+    async fn execute(
+        &self,
+        arguments: &[String],
+        frame: &mut TelnetFrame,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let (name, description, price) = validate_meal(arguments)?;
+
+        let result = self
+            .usecase
+            .lock()
+            .await
+            .execute(
+                &name,
+                &description,
+                &price,
+                &Allergens::default(),
+                &NutritionFacts::default(),
+            )
+            .await;
+
+        let message = match result {
+            Ok(meal_id) => format!("Meal {} is added to the menu\n", meal_id.to_i64()),
+            Err(e) => format!("{e}\n"),
+        };
+
+        frame.send(TelnetEvent::Message(message)).await?;
+        Ok(())
+    }
+}
+
+/// Same validation as a meal added by the REST API
+fn validate_meal(
+    arguments: &[String],
+) -> Result<(MealName, MealDescription, Price), CommandParseError> {
+    let price =
+        BigDecimal::from_str(&arguments[2]).map_err(|_| CommandParseError::InvalidArgument {
+            argument: "price",
+            value: arguments[2].clone(),
+        })?;
+
+    let error_list = RCell::new_rc(vec![]);
+    match (
+        MealName::validated(arguments[0].as_str(), error_list.clone()),
+        MealDescription::validated(arguments[1].as_str(), error_list.clone()),
+        Price::validated(price, error_list.clone()),
+    ) {
+        (Some(name), Some(description), Some(price)) => Ok((name, description, price)),
+        _ => Err(validation_failed(error_list)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn arguments(name: &str, description: &str, price: &str) -> Vec<String> {
+        vec![name.to_string(), description.to_string(), price.to_string()]
+    }
+
+    #[test]
+    fn meal_is_valid() {
+        let (name, description, price) =
+            validate_meal(&arguments("Soup", "Hot soup", "9.99")).unwrap();
+
+        assert_eq!(name.to_string(), "Soup");
+        assert_eq!(description.to_string(), "Hot soup");
+        assert_eq!(price.to_string_value(), "9.99");
+    }
+
+    #[test]
+    fn all_errors_are_reported() {
+        assert_eq!(
+            validate_meal(&arguments("", "", "9.999")),
+            Err(CommandParseError::ValidationFailed(vec![
+                "Meal name is empty.".to_string(),
+                "Meal description is empty".to_string(),
+                "Price scale must not be > 2".to_string(),
+            ]))
+        );
+    }
+
+    #[test]
+    fn price_is_not_a_number() {
+        assert_eq!(
+            validate_meal(&arguments("Soup", "Hot soup", "cheap")),
+            Err(CommandParseError::InvalidArgument {
+                argument: "price",
+                value: "cheap".to_string()
+            })
+        );
+    }
+}
@@ -1,15 +1,40 @@
 use std::error::Error;
 
+use async_trait::async_trait;
 use futures_util::SinkExt;
 use nectar::{TelnetCodec, event::TelnetEvent};
 use tokio::net::TcpStream;
 use tokio_util::codec::Framed;
 
+use crate::application_telnet_command::{ApplicationTelnetCommand, TelnetFrame};
+
 pub async fn get_health_command(
     frame: &mut Framed<TcpStream, TelnetCodec>,
-) -> Result<(), Box<dyn Error>> {
+) -> Result<(), Box<dyn Error + Send + Sync>> {
     frame
         .send(TelnetEvent::Message("Healthy!\n".to_string()))
         .await?;
     Ok(())
 }
+
+#[derive(Debug)]
+pub struct GetHealthCommand;
+
+#[async_trait]
+impl ApplicationTelnetCommand for GetHealthCommand {
+    fn name(&self) -> &'static str {
+        "check health"
+    }
+
+    fn description(&self) -> &'static str {
+        "Check that the application is running"
+    }
+
+    async fn execute(
+        &self,
+        _arguments: &[String],
+        frame: &mut TelnetFrame,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        get_health_command(frame).await
+    }
+}
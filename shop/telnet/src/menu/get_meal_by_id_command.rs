@@ -0,0 +1,58 @@
+use std::{error::Error, fmt::Debug, sync::Arc};
+
+use actix_web::web::Data;
+use async_trait::async_trait;
+use futures_util::SinkExt;
+use nectar::event::TelnetEvent;
+use tokio::sync::Mutex;
+use usecase::menu::GetMealById;
+
+use crate::{
+    application_telnet_command::{ApplicationTelnetCommand, CommandArgument, TelnetFrame},
+    menu::meal_view::{meal_details_text, meal_id_argument},
+};
+
+#[derive(Debug)]
+pub struct GetMealByIdCommand<T> {
+    usecase: Data<Arc<Mutex<T>>>,
+}
+
+impl<T> GetMealByIdCommand<T> {
+    pub fn new(usecase: Data<Arc<Mutex<T>>>) -> Self {
+        Self { usecase }
+    }
+}
+
+#[async_trait]
+impl<T> ApplicationTelnetCommand for GetMealByIdCommand<T>
+where
+    T: GetMealById + Send + Debug,
+{
+    fn name(&self) -> &'static str {
+        "menu show"
+    }
+
+    fn description(&self) -> &'static str {
+        "Show the meal"
+    }
+
+    fn arguments(&self) -> &'static [CommandArgument] {
+        &[CommandArgument::Required("id")]
+    }
+
+    async fn execute(
+        &self,
+        arguments: &[String],
+        frame: &mut TelnetFrame,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let meal_id = meal_id_argument(arguments)?;
+
+        let message = match self.usecase.lock().await.execute(&meal_id).await {
+            Ok(meal_info) => meal_details_text(&meal_info),
+            Err(e) => format!("{e}\n"),
+        };
+
+        frame.send(TelnetEvent::Message(message)).await?;
+        Ok(())
+    }
+}
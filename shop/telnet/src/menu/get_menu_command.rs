@@ -1,32 +1,64 @@
 use std::{error::Error, fmt::Debug, sync::Arc};
 
 use actix_web::web::Data;
+use async_trait::async_trait;
+use domain::menu::value_objects::{allergens::Allergens, meal_id::MealId};
 use futures_util::SinkExt;
 use nectar::{TelnetCodec, event::TelnetEvent};
 use prettytable::{Table, row};
 use tokio::{net::TcpStream, sync::Mutex};
 use tokio_util::codec::Framed;
-use usecase::menu::GetMenu;
+use usecase::menu::{GetMenu, MenuAvailability};
+
+use crate::application_telnet_command::{ApplicationTelnetCommand, TelnetFrame};
+
+/// The menu is read page by page, the page has to fit the use case limit
+const PAGE_SIZE: usize = 10;
 
 pub async fn get_menu_command<T>(
     usecase: Data<Arc<Mutex<T>>>,
     frame: &mut Framed<TcpStream, TelnetCodec>,
-) -> Result<(), Box<dyn Error>>
+) -> Result<(), Box<dyn Error + Send + Sync>>
 where
     T: GetMenu + Send + Debug,
 {
-    let menu = usecase.lock().await.execute().await;
-
     let mut table = Table::new();
-    table.add_row(row!["Id", "Name", "Description", "Price"]);
-
-    for meal_info in menu {
-        table.add_row(row![
-            meal_info.id.to_i64(),
-            meal_info.name.to_string(),
-            meal_info.description.to_string(),
-            meal_info.price.to_string_value()
-        ]);
+    table.add_row(row!["Id", "Name", "Description", "Price", "Available"]);
+
+    let mut start_id = MealId::default();
+    loop {
+        let mut page = usecase
+            .lock()
+            .await
+            .execute(
+                &start_id,
+                PAGE_SIZE + 1,
+                &Allergens::default(),
+                &MenuAvailability::Any,
+            )
+            .await?
+            .meals;
+        let next = (page.len() > PAGE_SIZE).then(|| page[PAGE_SIZE].id);
+        page.truncate(PAGE_SIZE);
+
+        for meal_info in page {
+            table.add_row(row![
+                meal_info.id.to_i64(),
+                meal_info.name.to_string(),
+                meal_info.description.to_string(),
+                meal_info.price.to_string_value(),
+                if meal_info.available {
+                    "yes"
+                } else {
+                    "sold out"
+                }
+            ]);
+        }
+
+        match next {
+            Some(next_id) => start_id = next_id,
+            None => break,
+        }
     }
 
     let table_string = table.to_string();
@@ -34,3 +66,36 @@ where
     frame.send(TelnetEvent::Message(table_string)).await?;
     Ok(())
 }
+
+#[derive(Debug)]
+pub struct GetMenuCommand<T> {
+    usecase: Data<Arc<Mutex<T>>>,
+}
+
+impl<T> GetMenuCommand<T> {
+    pub fn new(usecase: Data<Arc<Mutex<T>>>) -> Self {
+        Self { usecase }
+    }
+}
+
+#[async_trait]
+impl<T> ApplicationTelnetCommand for GetMenuCommand<T>
+where
+    T: GetMenu + Send + Debug,
+{
+    fn name(&self) -> &'static str {
+        "get menu"
+    }
+
+    fn description(&self) -> &'static str {
+        "List all meals of the menu"
+    }
+
+    async fn execute(
+        &self,
+        _arguments: &[String],
+        frame: &mut TelnetFrame,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        get_menu_command(self.usecase.clone(), frame).await
+    }
+}
@@ -0,0 +1,86 @@
+use common::{
+    common_rest::ValidationError,
+    types::base::{RCell, RcRefCellTrait},
+};
+use domain::menu::value_objects::meal_id::MealId;
+use prettytable::{Table, row};
+use rest::validated::Validated;
+use usecase::menu::dto::meal_info::MealInfo;
+
+use crate::application_telnet_command::{CommandParseError, parse_argument};
+
+/// The meal id, which is the first argument of the meal commands
+pub fn meal_id_argument(arguments: &[String]) -> Result<MealId, CommandParseError> {
+    let id = parse_argument::<i64>(arguments, 0, "id")?.unwrap_or_default();
+    let error_list = RCell::new_rc(vec![]);
+    MealId::validated(id, error_list.clone()).ok_or_else(|| validation_failed(error_list))
+}
+
+pub fn validation_failed(error_list: RCell<Vec<ValidationError>>) -> CommandParseError {
+    CommandParseError::ValidationFailed(
+        error_list
+            .borrow()
+            .iter()
+            .map(|error| error.message.clone())
+            .collect(),
+    )
+}
+
+pub fn meal_details_text(meal_info: &MealInfo) -> String {
+    let nutrition_facts = &meal_info.nutrition_facts;
+    let mut table = Table::new();
+    table.add_row(row!["Id", meal_info.id.to_i64()]);
+    table.add_row(row!["Name", meal_info.name.to_string()]);
+    table.add_row(row!["Description", meal_info.description.to_string()]);
+    table.add_row(row!["Price", meal_info.price.to_string_value()]);
+    table.add_row(row!["Allergens", meal_info.allergens.to_codes().join(", ")]);
+    table.add_row(row![
+        "Nutrition facts",
+        format!(
+            "{} kcal, proteins {} g, fats {} g, carbohydrates {} g",
+            nutrition_facts.calories(),
+            nutrition_facts.proteins(),
+            nutrition_facts.fats(),
+            nutrition_facts.carbohydrates()
+        )
+    ]);
+    table.add_row(row![
+        "Available",
+        if meal_info.available {
+            "yes"
+        } else {
+            "sold out"
+        }
+    ]);
+    table.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn meal_id_is_parsed() {
+        assert_eq!(
+            meal_id_argument(&["5".to_string()]),
+            Ok(MealId::try_from(5).unwrap())
+        );
+    }
+
+    #[test]
+    fn meal_id_is_validated_like_rest() {
+        assert_eq!(
+            meal_id_argument(&["-5".to_string()]),
+            Err(CommandParseError::ValidationFailed(vec![
+                "Meal Id must be > 0".to_string()
+            ]))
+        );
+        assert_eq!(
+            meal_id_argument(&["five".to_string()]),
+            Err(CommandParseError::InvalidArgument {
+                argument: "id",
+                value: "five".to_string()
+            })
+        );
+    }
+}
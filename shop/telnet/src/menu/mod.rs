@@ -1,2 +1,7 @@
+pub mod add_meal_to_menu_command;
 pub mod get_health_command;
+pub mod get_meal_by_id_command;
 pub mod get_menu_command;
+pub mod meal_view;
+pub mod remove_meal_from_menu_command;
+pub mod search_menu_command;
@@ -0,0 +1,66 @@
+use std::{error::Error, fmt::Debug, sync::Arc};
+
+use actix_web::web::Data;
+use async_trait::async_trait;
+use futures_util::SinkExt;
+use nectar::event::TelnetEvent;
+use tokio::sync::Mutex;
+use usecase::{auth::role::Role, menu::RemoveMealFromMenu};
+
+use crate::{
+    application_telnet_command::{ApplicationTelnetCommand, CommandArgument, TelnetFrame},
+    menu::meal_view::meal_id_argument,
+};
+
+#[derive(Debug)]
+pub struct RemoveMealFromMenuCommand<T> {
+    usecase: Data<Arc<Mutex<T>>>,
+}
+
+impl<T> RemoveMealFromMenuCommand<T> {
+    pub fn new(usecase: Data<Arc<Mutex<T>>>) -> Self {
+        Self { usecase }
+    }
+}
+
+#[async_trait]
+impl<T> ApplicationTelnetCommand for RemoveMealFromMenuCommand<T>
+where
+    T: RemoveMealFromMenu + Send + Debug,
+{
+    fn name(&self) -> &'static str {
+        "menu remove"
+    }
+
+    fn description(&self) -> &'static str {
+        "Remove the meal from the menu"
+    }
+
+    fn arguments(&self) -> &'static [CommandArgument] {
+        &[CommandArgument::Required("id")]
+    }
+
+    fn permitted(&self, role: Role) -> bool {
+        role == Role::Admin
+    }
+
+    fn confirmation(&self, arguments: &[String]) -> Option<String> {
+        Some(format!("Remove meal {} from the menu?", arguments[0]))
+    }
+
+    async fn execute(
+        &self,
+        arguments: &[String],
+        frame: &mut TelnetFrame,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let meal_id = meal_id_argument(arguments)?;
+
+        let message = match self.usecase.lock().await.execute(&meal_id).await {
+            Ok(()) => format!("Meal {} is removed from the menu\n", meal_id.to_i64()),
+            Err(e) => format!("{e}\n"),
+        };
+
+        frame.send(TelnetEvent::Message(message)).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,80 @@
+use std::{error::Error, fmt::Debug, sync::Arc};
+
+use actix_web::web::Data;
+use async_trait::async_trait;
+use futures_util::SinkExt;
+use nectar::{TelnetCodec, event::TelnetEvent};
+use prettytable::{Table, row};
+use tokio::{net::TcpStream, sync::Mutex};
+use tokio_util::codec::Framed;
+use usecase::menu::SearchMenu;
+
+use crate::application_telnet_command::{ApplicationTelnetCommand, CommandArgument, TelnetFrame};
+
+pub async fn search_menu_command<T>(
+    usecase: Data<Arc<Mutex<T>>>,
+    text: &str,
+    frame: &mut Framed<TcpStream, TelnetCodec>,
+) -> Result<(), Box<dyn Error + Send + Sync>>
+where
+    T: SearchMenu + Send + Debug,
+{
+    let message = match usecase.lock().await.execute(text).await {
+        Ok(menu) if menu.is_empty() => "Nothing found\n".to_string(),
+        Ok(menu) => {
+            let mut table = Table::new();
+            table.add_row(row!["Id", "Name", "Description", "Price"]);
+
+            for meal_info in menu {
+                table.add_row(row![
+                    meal_info.id.to_i64(),
+                    meal_info.name.to_string(),
+                    meal_info.description.to_string(),
+                    meal_info.price.to_string_value()
+                ]);
+            }
+            table.to_string()
+        }
+        Err(e) => format!("{e}\n"),
+    };
+
+    frame.send(TelnetEvent::Message(message)).await?;
+    Ok(())
+}
+
+#[derive(Debug)]
+pub struct SearchMenuCommand<T> {
+    usecase: Data<Arc<Mutex<T>>>,
+}
+
+impl<T> SearchMenuCommand<T> {
+    pub fn new(usecase: Data<Arc<Mutex<T>>>) -> Self {
+        Self { usecase }
+    }
+}
+
+#[async_trait]
+impl<T> ApplicationTelnetCommand for SearchMenuCommand<T>
+where
+    T: SearchMenu + Send + Debug,
+{
+    fn name(&self) -> &'static str {
+        "search menu"
+    }
+
+    fn description(&self) -> &'static str {
+        "Find meals by name or description"
+    }
+
+    fn arguments(&self) -> &'static [CommandArgument] {
+        &[CommandArgument::Remainder("text")]
+    }
+
+    async fn execute(
+        &self,
+        arguments: &[String],
+        frame: &mut TelnetFrame,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        search_menu_command(self.usecase.clone(), &arguments[0], frame).await
+    }
+}
@@ -0,0 +1,62 @@
+use std::{error::Error, fmt::Debug, sync::Arc};
+
+use actix_web::web::Data;
+use async_trait::async_trait;
+use futures_util::SinkExt;
+use nectar::event::TelnetEvent;
+use tokio::sync::Mutex;
+use usecase::order::CancelOrder;
+
+use crate::{
+    application_telnet_command::{ApplicationTelnetCommand, CommandArgument, TelnetFrame},
+    order::order_view::order_id_argument,
+};
+
+#[derive(Debug)]
+pub struct CancelOrderCommand<T> {
+    usecase: Data<Arc<Mutex<T>>>,
+}
+
+impl<T> CancelOrderCommand<T> {
+    pub fn new(usecase: Data<Arc<Mutex<T>>>) -> Self {
+        Self { usecase }
+    }
+}
+
+#[async_trait]
+impl<T> ApplicationTelnetCommand for CancelOrderCommand<T>
+where
+    T: CancelOrder + Send + Debug,
+{
+    fn name(&self) -> &'static str {
+        "order cancel"
+    }
+
+    fn description(&self) -> &'static str {
+        "Cancel the order"
+    }
+
+    fn arguments(&self) -> &'static [CommandArgument] {
+        &[CommandArgument::Required("id")]
+    }
+
+    fn confirmation(&self, arguments: &[String]) -> Option<String> {
+        Some(format!("Cancel order {}?", arguments[0]))
+    }
+
+    async fn execute(
+        &self,
+        arguments: &[String],
+        frame: &mut TelnetFrame,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let order_id = order_id_argument(arguments)?;
+
+        let message = match self.usecase.lock().await.execute(&order_id).await {
+            Ok(()) => format!("Order {} is cancelled\n", order_id.to_i64()),
+            Err(e) => format!("{e}\n"),
+        };
+
+        frame.send(TelnetEvent::Message(message)).await?;
+        Ok(())
+    }
+}
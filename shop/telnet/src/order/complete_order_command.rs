@@ -0,0 +1,58 @@
+use std::{error::Error, fmt::Debug, sync::Arc};
+
+use actix_web::web::Data;
+use async_trait::async_trait;
+use futures_util::SinkExt;
+use nectar::event::TelnetEvent;
+use tokio::sync::Mutex;
+use usecase::order::CompleteOrder;
+
+use crate::{
+    application_telnet_command::{ApplicationTelnetCommand, CommandArgument, TelnetFrame},
+    order::order_view::order_id_argument,
+};
+
+#[derive(Debug)]
+pub struct CompleteOrderCommand<T> {
+    usecase: Data<Arc<Mutex<T>>>,
+}
+
+impl<T> CompleteOrderCommand<T> {
+    pub fn new(usecase: Data<Arc<Mutex<T>>>) -> Self {
+        Self { usecase }
+    }
+}
+
+#[async_trait]
+impl<T> ApplicationTelnetCommand for CompleteOrderCommand<T>
+where
+    T: CompleteOrder + Send + Debug,
+{
+    fn name(&self) -> &'static str {
+        "order complete"
+    }
+
+    fn description(&self) -> &'static str {
+        "Mark the handed over order as completed"
+    }
+
+    fn arguments(&self) -> &'static [CommandArgument] {
+        &[CommandArgument::Required("id")]
+    }
+
+    async fn execute(
+        &self,
+        arguments: &[String],
+        frame: &mut TelnetFrame,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let order_id = order_id_argument(arguments)?;
+
+        let message = match self.usecase.lock().await.execute(&order_id).await {
+            Ok(()) => format!("Order {} is completed\n", order_id.to_i64()),
+            Err(e) => format!("{e}\n"),
+        };
+
+        frame.send(TelnetEvent::Message(message)).await?;
+        Ok(())
+    }
+}
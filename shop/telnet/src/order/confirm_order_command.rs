@@ -0,0 +1,58 @@
+use std::{error::Error, fmt::Debug, sync::Arc};
+
+use actix_web::web::Data;
+use async_trait::async_trait;
+use futures_util::SinkExt;
+use nectar::event::TelnetEvent;
+use tokio::sync::Mutex;
+use usecase::order::ConfirmOrder;
+
+use crate::{
+    application_telnet_command::{ApplicationTelnetCommand, CommandArgument, TelnetFrame},
+    order::order_view::order_id_argument,
+};
+
+#[derive(Debug)]
+pub struct ConfirmOrderCommand<T> {
+    usecase: Data<Arc<Mutex<T>>>,
+}
+
+impl<T> ConfirmOrderCommand<T> {
+    pub fn new(usecase: Data<Arc<Mutex<T>>>) -> Self {
+        Self { usecase }
+    }
+}
+
+#[async_trait]
+impl<T> ApplicationTelnetCommand for ConfirmOrderCommand<T>
+where
+    T: ConfirmOrder + Send + Debug,
+{
+    fn name(&self) -> &'static str {
+        "order confirm"
+    }
+
+    fn description(&self) -> &'static str {
+        "Confirm the paid order"
+    }
+
+    fn arguments(&self) -> &'static [CommandArgument] {
+        &[CommandArgument::Required("id")]
+    }
+
+    async fn execute(
+        &self,
+        arguments: &[String],
+        frame: &mut TelnetFrame,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let order_id = order_id_argument(arguments)?;
+
+        let message = match self.usecase.lock().await.execute(&order_id).await {
+            Ok(()) => format!("Order {} is confirmed\n", order_id.to_i64()),
+            Err(e) => format!("{e}\n"),
+        };
+
+        frame.send(TelnetEvent::Message(message)).await?;
+        Ok(())
+    }
+}
@@ -0,0 +1,99 @@
+use std::{error::Error, fmt::Debug, sync::Arc};
+
+use actix_web::web::Data;
+use async_trait::async_trait;
+use domain::order::value_objects::shop_order_id::ShopOrderId;
+use futures_util::SinkExt;
+use nectar::event::TelnetEvent;
+use tokio::sync::Mutex;
+use usecase::order::{
+    GetOrders, GetOrdersUseCaseError, access::shop_order_criteria::ShopOrderCriteria,
+};
+
+use crate::{
+    application_telnet_command::{
+        ApplicationTelnetCommand, CommandArgument, CommandParseError, TelnetFrame, parse_argument,
+    },
+    order::order_view::orders_table,
+};
+
+/// Page size when no limit is given
+const DEFAULT_LIMIT: usize = 10;
+
+#[derive(Debug)]
+pub struct GetOrdersCommand<T> {
+    usecase: Data<Arc<Mutex<T>>>,
+}
+
+impl<T> GetOrdersCommand<T> {
+    pub fn new(usecase: Data<Arc<Mutex<T>>>) -> Self {
+        Self { usecase }
+    }
+}
+
+#[async_trait]
+impl<T> ApplicationTelnetCommand for GetOrdersCommand<T>
+where
+    T: GetOrders + Send + Debug,
+{
+    fn name(&self) -> &'static str {
+        "orders list"
+    }
+
+    fn description(&self) -> &'static str {
+        "List orders by id starting at startId"
+    }
+
+    fn arguments(&self) -> &'static [CommandArgument] {
+        &[
+            CommandArgument::Optional("startId"),
+            CommandArgument::Optional("limit"),
+        ]
+    }
+
+    async fn execute(
+        &self,
+        arguments: &[String],
+        frame: &mut TelnetFrame,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let start_id = match parse_argument::<i64>(arguments, 0, "startId")? {
+            Some(id) => {
+                ShopOrderId::try_from(id).map_err(|_| CommandParseError::InvalidArgument {
+                    argument: "startId",
+                    value: id.to_string(),
+                })?
+            }
+            None => ShopOrderId::default(),
+        };
+        let limit = parse_argument::<usize>(arguments, 1, "limit")?.unwrap_or(DEFAULT_LIMIT);
+
+        let result = self
+            .usecase
+            .lock()
+            .await
+            .execute(&ShopOrderCriteria::default(), &start_id, limit + 1)
+            .await;
+
+        let message = match result {
+            Ok(orders) if orders.is_empty() => "No orders found\n".to_string(),
+            Ok(mut orders) => {
+                let next = (orders.len() > limit).then(|| orders[limit].id);
+                orders.truncate(limit);
+                let mut message = orders_table(&orders).to_string();
+                if let Some(next_id) = next {
+                    message.push_str(&format!(
+                        "More orders: orders list {} {limit}\n",
+                        next_id.to_i64()
+                    ));
+                }
+                message
+            }
+            Err(GetOrdersUseCaseError::LimitExceed(max_size)) => {
+                format!("Max limit is {}\n", max_size - 1)
+            }
+        };
+
+        frame.send(TelnetEvent::Message(message)).await?;
+        Ok(())
+    }
+}
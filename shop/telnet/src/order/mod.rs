@@ -0,0 +1,7 @@
+pub mod cancel_order_command;
+pub mod complete_order_command;
+pub mod confirm_order_command;
+pub mod get_order_by_id_command;
+pub mod get_orders_command;
+pub mod order_view;
+pub mod watch_orders_command;
@@ -0,0 +1,191 @@
+use domain::order::{
+    customer_order_events::ShopOrderEventEnum, shop_order::OrderState,
+    value_objects::fulfilment::Fulfilment, value_objects::shop_order_id::ShopOrderId,
+};
+use prettytable::{Table, row};
+use time::format_description::well_known::Rfc3339;
+use usecase::order::dto::order_details::OrderDetails;
+
+use crate::application_telnet_command::{CommandParseError, parse_argument};
+
+/// The order id, which is the first argument of the order commands
+pub fn order_id_argument(arguments: &[String]) -> Result<ShopOrderId, CommandParseError> {
+    parse_argument::<i64>(arguments, 0, "id")?
+        .and_then(|id| ShopOrderId::try_from(id).ok())
+        .ok_or_else(|| CommandParseError::InvalidArgument {
+            argument: "id",
+            value: arguments.first().cloned().unwrap_or_default(),
+        })
+}
+
+pub fn state_name(state: &OrderState) -> &'static str {
+    match state {
+        OrderState::WaitingForPayment(_) => "waiting for payment",
+        OrderState::Paid(_) => "paid",
+        OrderState::Confirmed(_) => "confirmed",
+        OrderState::ReadyForPickup(_) => "ready for pickup",
+        OrderState::Completed(_) => "completed",
+        OrderState::Cancelled(_) => "cancelled",
+    }
+}
+
+pub fn fulfilment_text(fulfilment: &Fulfilment) -> String {
+    match fulfilment {
+        Fulfilment::Delivery(address) => format!(
+            "delivery to {}, {} {}",
+            address.city_to_string(),
+            address.street_to_string(),
+            address.building_to_i16()
+        ),
+        Fulfilment::Pickup(pickup_point_id) => {
+            format!("pickup at point {}", pickup_point_id.to_i64())
+        }
+    }
+}
+
+pub fn orders_table(orders: &[OrderDetails]) -> Table {
+    let mut table = Table::new();
+    table.add_row(row![
+        "Id",
+        "Customer",
+        "State",
+        "Fulfilment",
+        "Items",
+        "Total"
+    ]);
+    for order in orders {
+        table.add_row(row![
+            order.id.to_i64(),
+            order.customer_id.to_uuid(),
+            state_name(&order.state),
+            fulfilment_text(&order.fulfilment),
+            order.items.len(),
+            order.total.to_string_value()
+        ]);
+    }
+    table
+}
+
+/// Line of the live order feed, `None` for the events the feed doesn't show
+pub fn order_event_text(event: &ShopOrderEventEnum) -> Option<String> {
+    match event {
+        ShopOrderEventEnum::ShopOrderCreatedDomainEvent(e) => {
+            let slot = e
+                .delivery_slot
+                .map(|slot| format!(", slot from {}", slot.start().format(&Rfc3339).unwrap()))
+                .unwrap_or_default();
+            Some(format!(
+                "Order {} created: {}, {}{slot}",
+                e.order_id.to_i64(),
+                e.total_price.to_string_value(),
+                fulfilment_text(&e.fulfilment)
+            ))
+        }
+        ShopOrderEventEnum::ShopOrderPaidDomainEvent(e) => {
+            Some(format!("Order {} paid", e.order_id.to_i64()))
+        }
+        ShopOrderEventEnum::ShopOrderConfirmedDomainEvent(e) => {
+            Some(format!("Order {} confirmed", e.order_id.to_i64()))
+        }
+        ShopOrderEventEnum::ShopOrderCancelledDomainEvent(e) => {
+            Some(format!("Order {} cancelled", e.order_id.to_i64()))
+        }
+        _ => None,
+    }
+}
+
+/// Summary of the order followed by its items
+pub fn order_details_text(order: &OrderDetails) -> String {
+    let mut summary = Table::new();
+    summary.add_row(row!["Id", order.id.to_i64()]);
+    summary.add_row(row!["Customer", order.customer_id.to_uuid()]);
+    summary.add_row(row!["State", state_name(&order.state)]);
+    summary.add_row(row!["Fulfilment", fulfilment_text(&order.fulfilment)]);
+    if let Some(slot) = order.delivery_slot {
+        summary.add_row(row![
+            "Delivery slot",
+            format!(
+                "{} - {}",
+                slot.start().format(&Rfc3339).unwrap(),
+                slot.end().format(&Rfc3339).unwrap()
+            )
+        ]);
+    }
+    summary.add_row(row!["Delivery fee", order.delivery_fee.to_string_value()]);
+    summary.add_row(row!["Total", order.total.to_string_value()]);
+
+    let mut items = Table::new();
+    items.add_row(row!["Meal id", "Count"]);
+    for item in &order.items {
+        items.add_row(row![item.meal_id.to_i64(), item.count.to_i32()]);
+    }
+
+    format!("{summary}{items}")
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::order::customer_order_events::{
+        ShopOrderCreatedDomainEvent, ShopOrderPaidDomainEvent, ShopOrderReadyForPickupDomainEvent,
+    };
+
+    use super::*;
+
+    fn arguments(id: &str) -> Vec<String> {
+        vec![id.to_string()]
+    }
+
+    #[test]
+    fn order_id_is_parsed() {
+        assert_eq!(
+            order_id_argument(&arguments("7")),
+            Ok(ShopOrderId::try_from(7).unwrap())
+        );
+    }
+
+    #[test]
+    fn invalid_order_id() {
+        for id in ["-1", "seven"] {
+            assert_eq!(
+                order_id_argument(&arguments(id)),
+                Err(CommandParseError::InvalidArgument {
+                    argument: "id",
+                    value: id.to_string()
+                })
+            );
+        }
+    }
+
+    #[test]
+    fn order_is_rendered() {
+        let order = OrderDetails {
+            state: OrderState::new_paid(),
+            ..Default::default()
+        };
+
+        let text = order_details_text(&order);
+
+        assert!(text.contains("paid"));
+        assert!(text.contains("Meal id"));
+        assert_eq!(orders_table(&[order]).len(), 2);
+    }
+
+    #[test]
+    fn order_event_is_rendered() {
+        let order_id = ShopOrderId::try_from(7).unwrap();
+
+        assert_eq!(
+            order_event_text(&ShopOrderPaidDomainEvent::new(order_id).into()),
+            Some("Order 7 paid".to_string())
+        );
+        assert!(
+            order_event_text(&ShopOrderCreatedDomainEvent::default().into())
+                .unwrap()
+                .contains("created")
+        );
+        assert_eq!(
+            order_event_text(&ShopOrderReadyForPickupDomainEvent::new(order_id).into()),
+            None
+        );
+    }
+}
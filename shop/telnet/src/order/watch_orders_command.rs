@@ -0,0 +1,161 @@
+use std::{error::Error, sync::Arc};
+
+use async_trait::async_trait;
+use futures_util::{SinkExt, StreamExt};
+use log::warn;
+use nectar::event::TelnetEvent;
+use tokio::sync::broadcast::error::RecvError;
+use usecase::order::order_event_feed::OrderEventFeed;
+
+use crate::{
+    application_telnet_command::{ApplicationTelnetCommand, TelnetFrame},
+    order::order_view::order_event_text,
+};
+
+/// Typed by the user to leave the live feed
+const STOP_COMMAND: &str = "stop";
+
+/// Streams the order events until the user types `stop`, a client too slow for the feed skips
+/// the events it missed
+#[derive(Debug)]
+pub struct WatchOrdersCommand {
+    feed: Arc<OrderEventFeed>,
+}
+
+impl WatchOrdersCommand {
+    pub fn new(feed: Arc<OrderEventFeed>) -> Self {
+        Self { feed }
+    }
+}
+
+#[async_trait]
+impl ApplicationTelnetCommand for WatchOrdersCommand {
+    fn name(&self) -> &'static str {
+        "watch orders"
+    }
+
+    fn description(&self) -> &'static str {
+        "Show created, paid, confirmed and cancelled orders as they happen"
+    }
+
+    async fn execute(
+        &self,
+        _arguments: &[String],
+        frame: &mut TelnetFrame,
+    ) -> Result<(), Box<dyn Error + Send + Sync>> {
+        let mut events = self.feed.subscribe();
+        frame
+            .send(TelnetEvent::Message(format!(
+                "Watching orders, type '{STOP_COMMAND}' to finish\n"
+            )))
+            .await?;
+
+        loop {
+            tokio::select! {
+                // The events received before `stop` are shown first
+                biased;
+                event = events.recv() => {
+                    let line = match event {
+                        Ok(event) => order_event_text(&event),
+                        Err(RecvError::Lagged(skipped)) => {
+                            warn!("Telnet order feed skipped {skipped} events");
+                            Some(format!("{skipped} events skipped"))
+                        }
+                        Err(RecvError::Closed) => break,
+                    };
+                    if let Some(line) = line {
+                        frame.send(TelnetEvent::Message(format!("{line}\n"))).await?;
+                    }
+                }
+                input = frame.next() => match input {
+                    Some(Ok(TelnetEvent::Message(line))) => {
+                        if line.trim().eq_ignore_ascii_case(STOP_COMMAND) {
+                            break;
+                        }
+                        frame
+                            .send(TelnetEvent::Message(format!(
+                                "Type '{STOP_COMMAND}' to finish watching\n"
+                            )))
+                            .await?;
+                    }
+                    // Option negotiation replies
+                    Some(Ok(_)) => {}
+                    Some(Err(e)) => return Err(e.into()),
+                    None => return Ok(()),
+                },
+            }
+        }
+
+        frame
+            .send(TelnetEvent::Message(
+                "Stopped watching orders\n".to_string(),
+            ))
+            .await?;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::order::{
+        customer_order_events::{ShopOrderCancelledDomainEvent, ShopOrderPaidDomainEvent},
+        value_objects::shop_order_id::ShopOrderId,
+    };
+    use nectar::TelnetCodec;
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::{TcpListener, TcpStream},
+    };
+    use tokio_util::codec::Framed;
+
+    use super::*;
+
+    async fn connect() -> (TelnetFrame, TcpStream) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let client = TcpStream::connect(listener.local_addr().unwrap())
+            .await
+            .unwrap();
+        let (server, _) = listener.accept().await.unwrap();
+        (Framed::new(server, TelnetCodec::new(1024)), client)
+    }
+
+    #[tokio::test]
+    async fn events_are_streamed_until_stop() {
+        let feed = Arc::new(OrderEventFeed::default());
+        let command = WatchOrdersCommand::new(feed.clone());
+        let (mut server, mut client) = connect().await;
+        let watching = tokio::spawn(async move {
+            command.execute(&[], &mut server).await.unwrap();
+        });
+
+        let mut greeting = [0; 64];
+        let read = client.read(&mut greeting).await.unwrap();
+        assert!(String::from_utf8_lossy(&greeting[..read]).starts_with("Watching orders"));
+
+        let order_id = ShopOrderId::try_from(7).unwrap();
+        feed.publish(&ShopOrderPaidDomainEvent::new(order_id).into());
+        feed.publish(&ShopOrderCancelledDomainEvent::new(order_id).into());
+        client.write_all(b"stop\r\n").await.unwrap();
+        watching.await.unwrap();
+
+        let mut received = String::new();
+        client.read_to_string(&mut received).await.unwrap();
+        let paid = received.find("Order 7 paid").unwrap();
+        let cancelled = received.find("Order 7 cancelled").unwrap();
+        let stopped = received.find("Stopped watching orders").unwrap();
+        assert!(paid < cancelled && cancelled < stopped);
+    }
+
+    #[tokio::test]
+    async fn disconnected_client_stops_watching() {
+        let command = WatchOrdersCommand::new(Arc::new(OrderEventFeed::default()));
+        let (mut server, mut client) = connect().await;
+        let watching = tokio::spawn(async move { command.execute(&[], &mut server).await.is_ok() });
+
+        let mut greeting = [0; 64];
+        assert!(client.read(&mut greeting).await.unwrap() > 0);
+        drop(client);
+
+        assert!(watching.await.unwrap());
+    }
+}
@@ -0,0 +1,2 @@
+pub mod telnet_session;
+pub mod telnet_user_store;
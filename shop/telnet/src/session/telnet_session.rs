@@ -0,0 +1,231 @@
+use std::{error::Error, time::Duration};
+
+use futures_util::{SinkExt, StreamExt};
+use log::{info, warn};
+use nectar::{event::TelnetEvent, option::TelnetOption};
+use tokio::time::timeout;
+use usecase::auth::role::Role;
+
+use crate::{
+    application_telnet_command::TelnetFrame,
+    session::telnet_user_store::{TelnetUser, TelnetUserStore},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct TelnetSessionSettings {
+    /// The connection is closed when the client sends nothing for this long
+    pub idle_timeout: Duration,
+    /// The connection is closed after this many wrong logins in a row
+    pub max_login_attempts: u32,
+}
+
+impl Default for TelnetSessionSettings {
+    fn default() -> Self {
+        Self {
+            idle_timeout: Duration::from_secs(300),
+            max_login_attempts: 3,
+        }
+    }
+}
+
+/// State of a single console connection
+#[derive(Debug)]
+pub struct TelnetSession {
+    settings: TelnetSessionSettings,
+    user: Option<TelnetUser>,
+    ended: bool,
+}
+
+impl TelnetSession {
+    pub fn new(settings: TelnetSessionSettings) -> Self {
+        Self {
+            settings,
+            user: None,
+            ended: false,
+        }
+    }
+
+    #[cfg(test)]
+    pub(crate) fn logged_in(settings: TelnetSessionSettings, user: TelnetUser) -> Self {
+        Self {
+            user: Some(user),
+            ..Self::new(settings)
+        }
+    }
+
+    pub fn user(&self) -> Option<&TelnetUser> {
+        self.user.as_ref()
+    }
+
+    /// Role of the logged in user, commands are run with the customer role before the login
+    pub fn role(&self) -> Role {
+        self.user.as_ref().map(|user| user.role).unwrap_or_default()
+    }
+
+    /// Asks for the credentials until they are right, `false` when the attempts are exhausted
+    /// or the client is gone
+    pub async fn login(
+        &mut self,
+        store: &dyn TelnetUserStore,
+        frame: &mut TelnetFrame,
+    ) -> Result<bool, Box<dyn Error + Send + Sync>> {
+        for _ in 0..self.settings.max_login_attempts {
+            let Some(login) = self.prompt("Login: ", frame).await? else {
+                return Ok(false);
+            };
+            // The client stops echoing the password while the server claims to echo it
+            frame.send(TelnetEvent::Will(TelnetOption::Echo)).await?;
+            let password = self.prompt("Password: ", frame).await?;
+            frame.send(TelnetEvent::Wont(TelnetOption::Echo)).await?;
+            let Some(password) = password else {
+                return Ok(false);
+            };
+
+            match store
+                .authenticate(login.trim(), password.trim_end_matches(['\r', '\n']))
+                .await
+            {
+                Some(user) => {
+                    info!("'{}' logged in by Telnet", user.login);
+                    frame
+                        .send(TelnetEvent::Message(format!("\nHello, {}!\n", user.login)))
+                        .await?;
+                    self.user = Some(user);
+                    return Ok(true);
+                }
+                None => {
+                    warn!("Failed Telnet login of '{}'", login.trim());
+                    frame
+                        .send(TelnetEvent::Message("\nLogin incorrect\n".to_string()))
+                        .await?;
+                }
+            }
+        }
+        frame
+            .send(TelnetEvent::Message(
+                "Too many failed login attempts\n".to_string(),
+            ))
+            .await?;
+        Ok(false)
+    }
+
+    async fn prompt(
+        &mut self,
+        prompt: &str,
+        frame: &mut TelnetFrame,
+    ) -> Result<Option<String>, Box<dyn Error + Send + Sync>> {
+        frame
+            .send(TelnetEvent::RawMessage(prompt.to_string()))
+            .await?;
+        self.next_line(frame).await
+    }
+
+    /// Next line of the client, `None` when the connection is closed or idle for too long, and
+    /// from then on
+    pub async fn next_line(
+        &mut self,
+        frame: &mut TelnetFrame,
+    ) -> Result<Option<String>, Box<dyn Error + Send + Sync>> {
+        while !self.ended {
+            match timeout(self.settings.idle_timeout, frame.next()).await {
+                Ok(Some(Ok(TelnetEvent::Message(line)))) => return Ok(Some(line)),
+                // Option negotiation replies
+                Ok(Some(Ok(_))) => continue,
+                Ok(Some(Err(e))) => return Err(e.into()),
+                Ok(None) => self.ended = true,
+                Err(_) => {
+                    self.ended = true;
+                    frame
+                        .send(TelnetEvent::Message("\nSession timed out\n".to_string()))
+                        .await?;
+                }
+            }
+        }
+        Ok(None)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use nectar::TelnetCodec;
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::{TcpListener, TcpStream},
+    };
+    use tokio_util::codec::Framed;
+
+    use super::*;
+    use crate::session::telnet_user_store::ConfiguredTelnetUserStore;
+
+    // Argon2id of "secret" with the smallest cost, to keep the tests fast
+    const SECRET_HASH: &str = "$argon2id$v=19$m=8,t=1,p=1$dGVsbmV0LXRlc3Qtc2FsdA$2UkZBWDwnlzyc10CGoeMj6igN1lhxrkqsv/nRJaEAv4";
+
+    async fn connect() -> (TelnetFrame, TcpStream) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let client = TcpStream::connect(listener.local_addr().unwrap())
+            .await
+            .unwrap();
+        let (server, _) = listener.accept().await.unwrap();
+        (Framed::new(server, TelnetCodec::new(1024)), client)
+    }
+
+    fn store() -> ConfiguredTelnetUserStore {
+        format!("boss:{SECRET_HASH}:admin").parse().unwrap()
+    }
+
+    #[tokio::test]
+    async fn user_logs_in() {
+        let mut session = TelnetSession::new(TelnetSessionSettings::default());
+        let (mut server, mut client) = connect().await;
+
+        client
+            .write_all(b"boss\r\nwrong\r\nboss\r\nsecret\r\n")
+            .await
+            .unwrap();
+
+        assert!(session.login(&store(), &mut server).await.unwrap());
+        assert_eq!(session.role(), Role::Admin);
+        drop(server);
+        let mut received = Vec::new();
+        client.read_to_end(&mut received).await.unwrap();
+        let received = String::from_utf8_lossy(&received);
+        assert!(received.contains("Login incorrect"));
+        assert!(received.contains("Hello, boss!"));
+    }
+
+    #[tokio::test]
+    async fn attempts_are_limited() {
+        let mut session = TelnetSession::new(TelnetSessionSettings {
+            max_login_attempts: 2,
+            ..Default::default()
+        });
+        let (mut server, mut client) = connect().await;
+
+        client
+            .write_all(b"boss\r\nwrong\r\nboss\r\nwrong\r\nboss\r\nsecret\r\n")
+            .await
+            .unwrap();
+
+        assert!(!session.login(&store(), &mut server).await.unwrap());
+        assert_eq!(session.user(), None);
+        assert_eq!(session.role(), Role::Customer);
+    }
+
+    #[tokio::test]
+    async fn idle_session_times_out() {
+        let mut session = TelnetSession::new(TelnetSessionSettings {
+            idle_timeout: Duration::from_millis(50),
+            ..Default::default()
+        });
+        let (mut server, mut client) = connect().await;
+
+        assert_eq!(session.next_line(&mut server).await.unwrap(), None);
+        let mut received = [0; 1024];
+        let read = client.read(&mut received).await.unwrap();
+        assert!(String::from_utf8_lossy(&received[..read]).contains("Session timed out"));
+
+        // The session stays over even when the client speaks again
+        client.write_all(b"late\r\n").await.unwrap();
+        assert_eq!(session.next_line(&mut server).await.unwrap(), None);
+    }
+}
@@ -0,0 +1,197 @@
+use std::{collections::HashMap, fmt::Debug, str::FromStr};
+
+use argon2::{
+    Algorithm, Argon2, PasswordVerifier,
+    password_hash::{Ident, PasswordHashString},
+};
+use async_trait::async_trait;
+use thiserror::Error;
+use tokio::task;
+use usecase::auth::role::Role;
+
+/// Operator logged in to the console
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TelnetUser {
+    pub login: String,
+    pub role: Role,
+}
+
+#[async_trait]
+pub trait TelnetUserStore: Debug + Send + Sync {
+    /// `None` when the login is unknown or the password doesn't match
+    async fn authenticate(&self, login: &str, password: &str) -> Option<TelnetUser>;
+}
+
+#[derive(Error, Debug, PartialEq, Eq)]
+pub enum TelnetUserStoreError {
+    #[error("Entry '{0}' is not in the form login:argon2-hash:role")]
+    MalformedEntry(String),
+    #[error("Password of '{0}' is not an Argon2 hash in the PHC string format")]
+    InvalidPasswordHash(String),
+    #[error("Unknown role '{role}' of '{login}'")]
+    UnknownRole { login: String, role: String },
+}
+
+#[derive(Debug)]
+struct StoredUser {
+    password_hash: PasswordHashString,
+    role: Role,
+}
+
+/// Users configured one `login:argon2-hash:role` entry per line, the password is stored as its
+/// salted Argon2 hash in the PHC string format and the role is `customer`, `kitchen_staff` or
+/// `admin`. Empty lines and lines starting with `#` are skipped.
+#[derive(Debug, Default)]
+pub struct ConfiguredTelnetUserStore {
+    users: HashMap<String, StoredUser>,
+}
+
+impl FromStr for ConfiguredTelnetUserStore {
+    type Err = TelnetUserStoreError;
+
+    fn from_str(configuration: &str) -> Result<Self, Self::Err> {
+        let mut users = HashMap::new();
+        for entry in configuration
+            .lines()
+            .map(str::trim)
+            .filter(|e| !e.is_empty() && !e.starts_with('#'))
+        {
+            let [login, password_hash, role] = entry.split(':').collect::<Vec<_>>()[..] else {
+                return Err(TelnetUserStoreError::MalformedEntry(entry.to_string()));
+            };
+            let password_hash = PasswordHashString::new(password_hash)
+                .ok()
+                .filter(|hash| is_argon2(hash.algorithm()))
+                .ok_or_else(|| TelnetUserStoreError::InvalidPasswordHash(login.to_string()))?;
+            let role = parse_role(role).ok_or_else(|| TelnetUserStoreError::UnknownRole {
+                login: login.to_string(),
+                role: role.to_string(),
+            })?;
+            users.insert(
+                login.to_string(),
+                StoredUser {
+                    password_hash,
+                    role,
+                },
+            );
+        }
+        Ok(Self { users })
+    }
+}
+
+fn is_argon2(algorithm: Ident) -> bool {
+    Algorithm::try_from(algorithm).is_ok()
+}
+
+fn parse_role(role: &str) -> Option<Role> {
+    match role {
+        "customer" => Some(Role::Customer),
+        "kitchen_staff" => Some(Role::KitchenStaff),
+        "admin" => Some(Role::Admin),
+        _ => None,
+    }
+}
+
+#[async_trait]
+impl TelnetUserStore for ConfiguredTelnetUserStore {
+    async fn authenticate(&self, login: &str, password: &str) -> Option<TelnetUser> {
+        let user = self.users.get(login)?;
+        let password_hash = user.password_hash.clone();
+        let password = password.to_string();
+        // Argon2 is slow on purpose, so it runs off the executor to keep the other sessions going.
+        // The parameters and the salt come from the stored hash, the comparison is constant time.
+        let verified = task::spawn_blocking(move || {
+            Argon2::default()
+                .verify_password(password.as_bytes(), &password_hash.password_hash())
+                .is_ok()
+        })
+        .await
+        .unwrap_or(false);
+        verified.then(|| TelnetUser {
+            login: login.to_string(),
+            role: user.role,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Argon2id of "secret" with the smallest cost, to keep the tests fast
+    const SECRET_HASH: &str = "$argon2id$v=19$m=8,t=1,p=1$dGVsbmV0LXRlc3Qtc2FsdA$2UkZBWDwnlzyc10CGoeMj6igN1lhxrkqsv/nRJaEAv4";
+
+    fn store() -> ConfiguredTelnetUserStore {
+        format!("# Operators\nchef:{SECRET_HASH}:kitchen_staff\n\n  boss:{SECRET_HASH}:admin\n")
+            .parse()
+            .unwrap()
+    }
+
+    #[tokio::test]
+    async fn user_is_authenticated() {
+        assert_eq!(
+            store().authenticate("boss", "secret").await,
+            Some(TelnetUser {
+                login: "boss".to_string(),
+                role: Role::Admin
+            })
+        );
+        assert_eq!(
+            store()
+                .authenticate("chef", "secret")
+                .await
+                .map(|user| user.role),
+            Some(Role::KitchenStaff)
+        );
+    }
+
+    #[tokio::test]
+    async fn wrong_credentials_are_rejected() {
+        assert_eq!(store().authenticate("boss", "Secret").await, None);
+        assert_eq!(store().authenticate("nobody", "secret").await, None);
+    }
+
+    #[test]
+    fn wrong_configuration() {
+        assert_eq!(
+            "boss:admin".parse::<ConfiguredTelnetUserStore>().err(),
+            Some(TelnetUserStoreError::MalformedEntry(
+                "boss:admin".to_string()
+            ))
+        );
+        assert_eq!(
+            "boss:secret:admin"
+                .parse::<ConfiguredTelnetUserStore>()
+                .err(),
+            Some(TelnetUserStoreError::InvalidPasswordHash(
+                "boss".to_string()
+            ))
+        );
+        // Unsalted digests are no longer accepted
+        assert_eq!(
+            "boss:2bb80d537b1da3e38bd30361aa855686bde0eacd7162fef6a25fe97bf527a25b:admin"
+                .parse::<ConfiguredTelnetUserStore>()
+                .err(),
+            Some(TelnetUserStoreError::InvalidPasswordHash(
+                "boss".to_string()
+            ))
+        );
+        assert_eq!(
+            "boss:$pbkdf2-sha256$i=1000$c2FsdA$aGFzaA:admin"
+                .parse::<ConfiguredTelnetUserStore>()
+                .err(),
+            Some(TelnetUserStoreError::InvalidPasswordHash(
+                "boss".to_string()
+            ))
+        );
+        assert_eq!(
+            format!("boss:{SECRET_HASH}:owner")
+                .parse::<ConfiguredTelnetUserStore>()
+                .err(),
+            Some(TelnetUserStoreError::UnknownRole {
+                login: "boss".to_string(),
+                role: "owner".to_string()
+            })
+        );
+    }
+}
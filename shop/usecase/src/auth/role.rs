@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+/// Role of the caller, the REST API takes it from the bearer token and the telnet console from
+/// the logged in user
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum Role {
+    #[default]
+    Customer,
+    KitchenStaff,
+    Admin,
+}
+
+impl Role {
+    /// Staff members act on behalf of any customer
+    pub fn is_staff(&self) -> bool {
+        !matches!(self, Role::Customer)
+    }
+}
@@ -17,4 +17,8 @@ pub trait AddMealToCart: Debug + Send {
 pub enum AddMealToCartUseCaseError {
     #[error("Meal Not Found")]
     MealNotFound,
+    #[error("Meal is sold out")]
+    MealUnavailable,
+    #[error("Meal is not available at this time")]
+    MealOutsideAvailabilityWindow,
 }
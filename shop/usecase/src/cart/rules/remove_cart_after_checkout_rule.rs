@@ -83,6 +83,8 @@ mod tests {
             rnd_order_id(),
             *cart.clone().for_customer(),
             rnd_price(),
+            rnd_fulfilment(),
+            None,
         )
         .into();
 
@@ -105,8 +107,14 @@ mod tests {
         let mut rule =
             RemoveCartAfterCheckoutRule::new(cart_extractor.clone(), cart_remover.clone());
         let customer_id = rnd_customer_id();
-        let event: ShopOrderEventEnum =
-            ShopOrderCreatedDomainEvent::new(rnd_order_id(), customer_id, rnd_price()).into();
+        let event: ShopOrderEventEnum = ShopOrderCreatedDomainEvent::new(
+            rnd_order_id(),
+            customer_id,
+            rnd_price(),
+            rnd_fulfilment(),
+            None,
+        )
+        .into();
 
         rule.handle(&event).await;
 
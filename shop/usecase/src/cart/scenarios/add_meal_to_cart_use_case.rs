@@ -1,5 +1,5 @@
 use async_trait::async_trait;
-use common::types::base::AM;
+use common::types::{base::AM, common::Count};
 use derive_new::new;
 use domain::{
     cart::{
@@ -8,36 +8,41 @@ use domain::{
     },
     menu::value_objects::meal_id::MealId,
 };
+use time::OffsetDateTime;
 
 use crate::{
     cart::{
         access::{cart_extractor::CartExtractor, cart_persister::CartPersister},
         add_meal_to_cart::{AddMealToCart, AddMealToCartUseCaseError},
     },
+    inventory::access::meal_stock_extractor::MealStockExtractor,
     menu::access::meal_extractor::MealExtractor,
 };
 
 #[derive(new, Debug)]
-pub struct AddMealToCartUseCase<CExtractor, CIdGenerator, MExtractor, CPersister>
+pub struct AddMealToCartUseCase<CExtractor, CIdGenerator, MExtractor, MSExtractor, CPersister>
 where
     CExtractor: CartExtractor,
     CIdGenerator: CartIdGenerator,
     MExtractor: MealExtractor,
+    MSExtractor: MealStockExtractor,
     CPersister: CartPersister,
 {
     cart_extractor: AM<CExtractor>,
     id_generator: AM<CIdGenerator>,
     meal_extractor: AM<MExtractor>,
+    meal_stock_extractor: AM<MSExtractor>,
     cart_persister: AM<CPersister>,
 }
 
 #[async_trait]
-impl<CExtractor, CIdGenerator, MExtractor, CPersister> AddMealToCart
-    for AddMealToCartUseCase<CExtractor, CIdGenerator, MExtractor, CPersister>
+impl<CExtractor, CIdGenerator, MExtractor, MSExtractor, CPersister> AddMealToCart
+    for AddMealToCartUseCase<CExtractor, CIdGenerator, MExtractor, MSExtractor, CPersister>
 where
     CExtractor: CartExtractor,
     CIdGenerator: CartIdGenerator + 'static,
     MExtractor: MealExtractor,
+    MSExtractor: MealStockExtractor,
     CPersister: CartPersister,
 {
     async fn execute(
@@ -52,10 +57,25 @@ where
             .await
             .get_by_id(meal_id)
             .ok_or(AddMealToCartUseCaseError::MealNotFound)?;
+        if !meal.is_available_at(&OffsetDateTime::now_utc()) {
+            return Err(AddMealToCartUseCaseError::MealOutsideAvailabilityWindow);
+        }
 
         // Get or create cart
         let mut cart = self.get_or_create_cart(for_customer).await;
 
+        // Check the stock can supply one more portion
+        let in_cart = cart.meals().get(meal_id).map_or(0, Count::to_i32);
+        if let Some(stock) = self
+            .meal_stock_extractor
+            .lock()
+            .await
+            .get_by_meal_id(meal_id)
+            && !stock.has_portions(&Count::try_from(in_cart + 1).unwrap())
+        {
+            return Err(AddMealToCartUseCaseError::MealUnavailable);
+        }
+
         // Add meal to cart
         cart.add_meal(meal);
 
@@ -66,12 +86,13 @@ where
     }
 }
 
-impl<CExtractor, CIdGenerator, MExtractor, CPersister>
-    AddMealToCartUseCase<CExtractor, CIdGenerator, MExtractor, CPersister>
+impl<CExtractor, CIdGenerator, MExtractor, MSExtractor, CPersister>
+    AddMealToCartUseCase<CExtractor, CIdGenerator, MExtractor, MSExtractor, CPersister>
 where
     CExtractor: CartExtractor,
     CIdGenerator: CartIdGenerator + 'static,
     MExtractor: MealExtractor,
+    MSExtractor: MealStockExtractor,
     CPersister: CartPersister,
 {
     async fn get_or_create_cart(&self, for_customer: CustomerId) -> Cart {
@@ -90,11 +111,17 @@ where
 
 #[cfg(test)]
 mod tests {
+    use std::collections::HashMap;
+
     use common::types::base::AMTrait;
-    use domain::{cart::value_objects::cart_id::CartId, test_fixtures::*};
+    use domain::{
+        cart::value_objects::cart_id::CartId, inventory::meal_stock::MealStock, test_fixtures::*,
+    };
 
     use super::*;
-    use crate::test_fixtures::{MockCartExtractor, MockCartPersister, MockMealExtractor};
+    use crate::test_fixtures::{
+        MockCartExtractor, MockCartPersister, MockMealExtractor, MockMealStockExtractor,
+    };
 
     #[tokio::test]
     async fn cart_doesnt_exist_successfully_added() {
@@ -109,6 +136,7 @@ mod tests {
             cart_extractor.clone(),
             id_generator.clone(),
             meal_extractor.clone(),
+            AM::new_am(MockMealStockExtractor::default()),
             cart_persister.clone(),
         );
 
@@ -146,6 +174,7 @@ mod tests {
             cart_extractor.clone(),
             id_generator.clone(),
             meal_extractor.clone(),
+            AM::new_am(MockMealStockExtractor::default()),
             cart_persister.clone(),
         );
 
@@ -182,6 +211,7 @@ mod tests {
             cart_extractor.clone(),
             id_generator.clone(),
             meal_extractor.clone(),
+            AM::new_am(MockMealStockExtractor::default()),
             cart_persister.clone(),
         );
 
@@ -196,6 +226,101 @@ mod tests {
         assert_eq!(result.unwrap_err(), AddMealToCartUseCaseError::MealNotFound);
     }
 
+    #[tokio::test]
+    async fn meal_outside_availability_window() {
+        let mut meal = rnd_meal();
+        meal.change_availability_schedule(Some(availability_schedule_closed_at(
+            &OffsetDateTime::now_utc(),
+        )));
+        let cart_persister = AM::new_am(MockCartPersister::default());
+        let meal_extractor = AM::new_am(MockMealExtractor::new());
+        meal_extractor.lock().await.meal = Some(meal.clone());
+
+        let mut use_case = AddMealToCartUseCase::new(
+            AM::new_am(MockCartExtractor::default()),
+            AM::new_am(TestCartIdGenerator::default()),
+            meal_extractor,
+            AM::new_am(MockMealStockExtractor::default()),
+            cart_persister.clone(),
+        );
+
+        let result = use_case.execute(rnd_customer_id(), meal.id()).await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            AddMealToCartUseCaseError::MealOutsideAvailabilityWindow
+        );
+        cart_persister.lock().await.verify_empty();
+    }
+
+    #[tokio::test]
+    async fn meal_sold_out() {
+        let meal = rnd_meal();
+        let customer_id = rnd_customer_id();
+        let cart_persister = AM::new_am(MockCartPersister::default());
+        let meal_extractor = AM::new_am(MockMealExtractor::new());
+        meal_extractor.lock().await.meal = Some(meal.clone());
+        let meal_stock_extractor =
+            AM::new_am(MockMealStockExtractor::new(vec![MealStock::create(
+                *meal.id(),
+                Count::try_from(0).unwrap(),
+            )]));
+
+        let mut use_case = AddMealToCartUseCase::new(
+            AM::new_am(MockCartExtractor::default()),
+            AM::new_am(TestCartIdGenerator::default()),
+            meal_extractor.clone(),
+            meal_stock_extractor.clone(),
+            cart_persister.clone(),
+        );
+
+        let result = use_case.execute(customer_id, meal.id()).await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            AddMealToCartUseCaseError::MealUnavailable
+        );
+        meal_stock_extractor
+            .lock()
+            .await
+            .verify_invoked(&[*meal.id()]);
+        cart_persister.lock().await.verify_empty();
+    }
+
+    #[tokio::test]
+    async fn cart_cannot_exceed_stock() {
+        let meal = rnd_meal();
+        let customer_id = rnd_customer_id();
+        let existing_cart = rnd_cart_with_customer_id_and_meals(
+            customer_id,
+            HashMap::from([(*meal.id(), Count::one())]),
+        );
+        let cart_extractor = AM::new_am(MockCartExtractor::default());
+        cart_extractor.lock().await.cart = Some(existing_cart);
+        let cart_persister = AM::new_am(MockCartPersister::default());
+        let meal_extractor = AM::new_am(MockMealExtractor::new());
+        meal_extractor.lock().await.meal = Some(meal.clone());
+
+        let mut use_case = AddMealToCartUseCase::new(
+            cart_extractor,
+            AM::new_am(TestCartIdGenerator::default()),
+            meal_extractor,
+            AM::new_am(MockMealStockExtractor::new(vec![MealStock::create(
+                *meal.id(),
+                Count::one(),
+            )])),
+            cart_persister.clone(),
+        );
+
+        let result = use_case.execute(customer_id, meal.id()).await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            AddMealToCartUseCaseError::MealUnavailable
+        );
+        cart_persister.lock().await.verify_empty();
+    }
+
     #[derive(new, Debug, Default)]
     struct TestCartIdGenerator {
         id: CartId,
@@ -0,0 +1,12 @@
+use std::fmt::Debug;
+
+use domain::{
+    cart::value_objects::customer_id::CustomerId,
+    customer::{customer::Customer, value_objects::phone_number::PhoneNumber},
+};
+
+pub trait CustomerExtractor: Debug + Send {
+    fn get_by_id(&mut self, id: &CustomerId) -> Option<Customer>;
+
+    fn get_by_phone(&mut self, phone: &PhoneNumber) -> Option<Customer>;
+}
@@ -0,0 +1,9 @@
+use std::fmt::Debug;
+
+use async_trait::async_trait;
+use domain::customer::customer::Customer;
+
+#[async_trait]
+pub trait CustomerPersister: Debug + Send {
+    async fn save(&mut self, customer: Customer);
+}
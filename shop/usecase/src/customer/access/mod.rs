@@ -0,0 +1,2 @@
+pub mod customer_extractor;
+pub mod customer_persister;
@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+use common::types::common::Address;
+use domain::{
+    cart::value_objects::customer_id::CustomerId,
+    customer::value_objects::saved_address::SavedAddressId,
+};
+
+#[async_trait]
+pub trait AddCustomerAddress {
+    async fn execute(
+        &mut self,
+        id: &CustomerId,
+        address: &Address,
+    ) -> Result<SavedAddressId, AddCustomerAddressUseCaseError>;
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum AddCustomerAddressUseCaseError {
+    CustomerNotFound,
+}
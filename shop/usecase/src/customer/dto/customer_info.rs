@@ -0,0 +1,34 @@
+use common::types::base::Version;
+use domain::{
+    cart::value_objects::customer_id::CustomerId,
+    customer::{
+        customer::Customer,
+        value_objects::{
+            customer_name::CustomerName, customer_preferences::CustomerPreferences,
+            phone_number::PhoneNumber, saved_address::SavedAddress,
+        },
+    },
+};
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct CustomerInfo {
+    pub id: CustomerId,
+    pub name: CustomerName,
+    pub phone: PhoneNumber,
+    pub addresses: Vec<SavedAddress>,
+    pub preferences: CustomerPreferences,
+    pub version: Version,
+}
+
+impl From<Customer> for CustomerInfo {
+    fn from(value: Customer) -> Self {
+        Self {
+            id: *value.id(),
+            name: value.name().to_owned(),
+            phone: value.phone().to_owned(),
+            addresses: value.addresses().to_owned(),
+            preferences: *value.preferences(),
+            version: *value.version(),
+        }
+    }
+}
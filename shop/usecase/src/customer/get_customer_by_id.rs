@@ -0,0 +1,17 @@
+use async_trait::async_trait;
+use domain::cart::value_objects::customer_id::CustomerId;
+
+use crate::customer::dto::customer_info::CustomerInfo;
+
+#[async_trait]
+pub trait GetCustomerById {
+    async fn execute(
+        &mut self,
+        id: &CustomerId,
+    ) -> Result<CustomerInfo, GetCustomerByIdUseCaseError>;
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum GetCustomerByIdUseCaseError {
+    CustomerNotFound,
+}
@@ -0,0 +1,76 @@
+use async_trait::async_trait;
+use common::types::base::AM;
+use derive_new::new;
+use domain::customer::{
+    customer_already_exists::CustomerAlreadyExists, value_objects::phone_number::PhoneNumber,
+};
+
+use crate::customer::access::customer_extractor::CustomerExtractor;
+
+#[derive(new, Debug, Clone)]
+pub struct CustomerAlreadyExistsUsesCustomerExtractor {
+    pub extractor: AM<dyn CustomerExtractor>,
+}
+
+#[async_trait]
+impl CustomerAlreadyExists for CustomerAlreadyExistsUsesCustomerExtractor {
+    async fn invoke(&mut self, phone: &PhoneNumber) -> bool {
+        self.extractor
+            .lock()
+            .await
+            .get_by_phone(phone)
+            .is_some_and(|customer| customer.visible())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::types::base::AMTrait;
+    use domain::test_fixtures::{rnd_customer, rnd_phone_number, rnd_removed_customer};
+    use tokio::test;
+
+    use super::*;
+    use crate::test_fixtures::MockCustomerExtractor;
+
+    #[test]
+    async fn customer_already_exists() {
+        let customer = rnd_customer();
+        let extractor = AM::new_am(MockCustomerExtractor::new(Some(customer.clone())));
+        let mut rule = CustomerAlreadyExistsUsesCustomerExtractor::new(extractor.clone());
+
+        let result = rule.invoke(customer.phone()).await;
+
+        assert!(result);
+        extractor
+            .lock()
+            .await
+            .verify_invoked_get_by_phone(customer.phone());
+    }
+
+    #[test]
+    async fn customer_already_exists_but_removed() {
+        let customer = rnd_removed_customer();
+        let extractor = AM::new_am(MockCustomerExtractor::new(Some(customer.clone())));
+        let mut rule = CustomerAlreadyExistsUsesCustomerExtractor::new(extractor.clone());
+
+        let result = rule.invoke(customer.phone()).await;
+
+        assert!(!result);
+        extractor
+            .lock()
+            .await
+            .verify_invoked_get_by_phone(customer.phone());
+    }
+
+    #[test]
+    async fn customer_doesnt_exist() {
+        let extractor = AM::new_am(MockCustomerExtractor::new(None));
+        let mut rule = CustomerAlreadyExistsUsesCustomerExtractor::new(extractor.clone());
+        let phone = rnd_phone_number();
+
+        let result = rule.invoke(&phone).await;
+
+        assert!(!result);
+        extractor.lock().await.verify_invoked_get_by_phone(&phone);
+    }
+}
@@ -0,0 +1 @@
+pub mod customer_already_exists_uses_customer_extractor;
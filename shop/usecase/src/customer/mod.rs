@@ -0,0 +1,18 @@
+pub mod access;
+pub mod dto;
+pub mod invariant;
+pub mod scenario;
+
+mod add_customer_address;
+mod get_customer_by_id;
+mod register_customer;
+mod remove_customer;
+mod remove_customer_address;
+mod update_customer;
+
+pub use add_customer_address::*;
+pub use get_customer_by_id::*;
+pub use register_customer::*;
+pub use remove_customer::*;
+pub use remove_customer_address::*;
+pub use update_customer::*;
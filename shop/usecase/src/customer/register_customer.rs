@@ -0,0 +1,34 @@
+use std::fmt::Debug;
+
+use async_trait::async_trait;
+use domain::{
+    cart::value_objects::customer_id::CustomerId,
+    customer::{
+        customer::CustomerError,
+        value_objects::{customer_name::CustomerName, phone_number::PhoneNumber},
+    },
+};
+
+#[async_trait]
+pub trait RegisterCustomer: Debug + Send {
+    async fn execute(
+        &mut self,
+        name: &CustomerName,
+        phone: &PhoneNumber,
+    ) -> Result<CustomerId, RegisterCustomerUseCaseError>;
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum RegisterCustomerUseCaseError {
+    AlreadyExists,
+    UnknownError,
+}
+
+impl From<CustomerError> for RegisterCustomerUseCaseError {
+    fn from(value: CustomerError) -> Self {
+        match value {
+            CustomerError::AlreadyExistsWithSamePhoneError => Self::AlreadyExists,
+            CustomerError::SavedAddressNotFound => Self::UnknownError,
+        }
+    }
+}
@@ -0,0 +1,12 @@
+use async_trait::async_trait;
+use domain::cart::value_objects::customer_id::CustomerId;
+
+#[async_trait]
+pub trait RemoveCustomer {
+    async fn execute(&mut self, id: &CustomerId) -> Result<(), RemoveCustomerUseCaseError>;
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum RemoveCustomerUseCaseError {
+    CustomerNotFound,
+}
@@ -0,0 +1,20 @@
+use async_trait::async_trait;
+use domain::{
+    cart::value_objects::customer_id::CustomerId,
+    customer::value_objects::saved_address::SavedAddressId,
+};
+
+#[async_trait]
+pub trait RemoveCustomerAddress {
+    async fn execute(
+        &mut self,
+        id: &CustomerId,
+        address_id: &SavedAddressId,
+    ) -> Result<(), RemoveCustomerAddressUseCaseError>;
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum RemoveCustomerAddressUseCaseError {
+    CustomerNotFound,
+    AddressNotFound,
+}
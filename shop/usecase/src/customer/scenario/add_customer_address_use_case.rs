@@ -0,0 +1,83 @@
+use async_trait::async_trait;
+use common::types::{base::AM, common::Address};
+use derive_new::new;
+use domain::{
+    cart::value_objects::customer_id::CustomerId,
+    customer::value_objects::saved_address::SavedAddressId,
+};
+
+use crate::customer::{
+    access::{customer_extractor::CustomerExtractor, customer_persister::CustomerPersister},
+    add_customer_address::{AddCustomerAddress, AddCustomerAddressUseCaseError},
+};
+
+#[derive(new, Debug)]
+pub struct AddCustomerAddressUseCase {
+    pub customer_extractor: AM<dyn CustomerExtractor>,
+    pub customer_persister: AM<dyn CustomerPersister>,
+}
+
+#[async_trait]
+impl AddCustomerAddress for AddCustomerAddressUseCase {
+    async fn execute(
+        &mut self,
+        id: &CustomerId,
+        address: &Address,
+    ) -> Result<SavedAddressId, AddCustomerAddressUseCaseError> {
+        let mut customer = self
+            .customer_extractor
+            .lock()
+            .await
+            .get_by_id(id)
+            .filter(|customer| customer.visible())
+            .ok_or(AddCustomerAddressUseCaseError::CustomerNotFound)?;
+        let address_id = customer.add_address(address.clone());
+        self.customer_persister.lock().await.save(customer).await;
+        Ok(address_id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::types::base::AMTrait;
+    use domain::test_fixtures::*;
+
+    use super::*;
+    use crate::test_fixtures::{MockCustomerExtractor, MockCustomerPersister};
+
+    #[tokio::test]
+    async fn successfully_added() {
+        let customer = rnd_customer();
+        let address = rnd_address();
+        let extractor = AM::new_am(MockCustomerExtractor::new(Some(customer.clone())));
+        let persister = AM::new_am(MockCustomerPersister::new());
+
+        let mut use_case = AddCustomerAddressUseCase::new(extractor.clone(), persister.clone());
+        let result = use_case.execute(customer.id(), &address).await;
+
+        let address_id = result.unwrap();
+        extractor
+            .lock()
+            .await
+            .verify_invoked_get_by_id(customer.id());
+        let saved = persister.lock().await.customer.clone().unwrap();
+        assert_eq!(saved.saved_address(&address_id), Some(&address));
+    }
+
+    #[tokio::test]
+    async fn customer_not_found() {
+        let persister = AM::new_am(MockCustomerPersister::new());
+
+        let mut use_case = AddCustomerAddressUseCase::new(
+            AM::new_am(MockCustomerExtractor::new(None)),
+            persister.clone(),
+        );
+        let result = use_case.execute(&rnd_customer_id(), &rnd_address()).await;
+
+        assert_eq!(
+            result,
+            Err(AddCustomerAddressUseCaseError::CustomerNotFound)
+        );
+        persister.lock().await.verify_empty();
+    }
+}
@@ -0,0 +1,79 @@
+use async_trait::async_trait;
+use common::types::base::AM;
+use derive_new::new;
+use domain::cart::value_objects::customer_id::CustomerId;
+
+use crate::customer::{
+    access::customer_extractor::CustomerExtractor,
+    dto::customer_info::CustomerInfo,
+    get_customer_by_id::{GetCustomerById, GetCustomerByIdUseCaseError},
+};
+
+#[derive(new, Debug)]
+pub struct GetCustomerByIdUseCase {
+    pub customer_extractor: AM<dyn CustomerExtractor>,
+}
+
+#[async_trait]
+impl GetCustomerById for GetCustomerByIdUseCase {
+    async fn execute(
+        &mut self,
+        id: &CustomerId,
+    ) -> Result<CustomerInfo, GetCustomerByIdUseCaseError> {
+        self.customer_extractor
+            .lock()
+            .await
+            .get_by_id(id)
+            .filter(|customer| customer.visible())
+            .map(CustomerInfo::from)
+            .ok_or(GetCustomerByIdUseCaseError::CustomerNotFound)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::types::base::AMTrait;
+    use domain::test_fixtures::*;
+    use tokio::test;
+
+    use super::*;
+    use crate::test_fixtures::MockCustomerExtractor;
+
+    #[test]
+    async fn customer_found() {
+        let customer = rnd_customer();
+        let extractor = AM::new_am(MockCustomerExtractor::new(Some(customer.clone())));
+        let mut use_case = GetCustomerByIdUseCase::new(extractor.clone());
+
+        let result = use_case.execute(customer.id()).await;
+
+        assert_eq!(result, Ok(CustomerInfo::from(customer.clone())));
+        extractor
+            .lock()
+            .await
+            .verify_invoked_get_by_id(customer.id());
+    }
+
+    #[test]
+    async fn customer_not_found() {
+        let extractor = AM::new_am(MockCustomerExtractor::new(None));
+        let mut use_case = GetCustomerByIdUseCase::new(extractor.clone());
+        let id = rnd_customer_id();
+
+        let result = use_case.execute(&id).await;
+
+        assert_eq!(result, Err(GetCustomerByIdUseCaseError::CustomerNotFound));
+        extractor.lock().await.verify_invoked_get_by_id(&id);
+    }
+
+    #[test]
+    async fn customer_removed() {
+        let customer = rnd_removed_customer();
+        let extractor = AM::new_am(MockCustomerExtractor::new(Some(customer.clone())));
+        let mut use_case = GetCustomerByIdUseCase::new(extractor);
+
+        let result = use_case.execute(customer.id()).await;
+
+        assert_eq!(result, Err(GetCustomerByIdUseCaseError::CustomerNotFound));
+    }
+}
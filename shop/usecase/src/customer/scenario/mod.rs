@@ -0,0 +1,13 @@
+mod add_customer_address_use_case;
+mod get_customer_by_id_use_case;
+mod register_customer_use_case;
+mod remove_customer_address_use_case;
+mod remove_customer_use_case;
+mod update_customer_use_case;
+
+pub use add_customer_address_use_case::*;
+pub use get_customer_by_id_use_case::*;
+pub use register_customer_use_case::*;
+pub use remove_customer_address_use_case::*;
+pub use remove_customer_use_case::*;
+pub use update_customer_use_case::*;
@@ -0,0 +1,91 @@
+use async_trait::async_trait;
+use common::types::base::AM;
+use derive_new::new;
+use domain::{
+    cart::value_objects::customer_id::{CustomerId, CustomerIdGenerator},
+    customer::{
+        customer::Customer,
+        customer_already_exists::CustomerAlreadyExists,
+        value_objects::{customer_name::CustomerName, phone_number::PhoneNumber},
+    },
+};
+
+use crate::customer::{
+    access::customer_persister::CustomerPersister,
+    register_customer::{RegisterCustomer, RegisterCustomerUseCaseError},
+};
+
+#[derive(new, Debug)]
+pub struct RegisterCustomerUseCase {
+    pub customer_persister: AM<dyn CustomerPersister>,
+    pub id_generator: AM<dyn CustomerIdGenerator>,
+    pub customer_exists: AM<dyn CustomerAlreadyExists>,
+}
+
+#[async_trait]
+impl RegisterCustomer for RegisterCustomerUseCase {
+    async fn execute(
+        &mut self,
+        name: &CustomerName,
+        phone: &PhoneNumber,
+    ) -> Result<CustomerId, RegisterCustomerUseCaseError> {
+        let customer = Customer::register(
+            self.id_generator.clone(),
+            self.customer_exists.clone(),
+            name.clone(),
+            phone.clone(),
+        )
+        .await?;
+        let id = *customer.id();
+        self.customer_persister.lock().await.save(customer).await;
+        Ok(id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::types::base::AMTrait;
+    use domain::test_fixtures::*;
+
+    use super::*;
+    use crate::test_fixtures::MockCustomerPersister;
+
+    #[tokio::test]
+    async fn successfully_registered() {
+        let name = rnd_customer_name();
+        let phone = rnd_phone_number();
+        let id_generator = AM::new_am(TestCustomerIdGenerator::new());
+        let persister = AM::new_am(MockCustomerPersister::new());
+
+        let mut use_case = RegisterCustomerUseCase::new(
+            persister.clone(),
+            id_generator.clone(),
+            AM::new_am(TestCustomerAlreadyExists::new()),
+        );
+        let result = use_case.execute(&name, &phone).await;
+
+        let id = id_generator.lock().await.customer_id;
+        assert_eq!(result.unwrap(), id);
+        let customer = persister.lock().await.customer.clone().unwrap();
+        assert_eq!(customer.id(), &id);
+        assert_eq!(customer.name(), &name);
+        assert_eq!(customer.phone(), &phone);
+    }
+
+    #[tokio::test]
+    async fn customer_already_exists() {
+        let persister = AM::new_am(MockCustomerPersister::new());
+
+        let mut use_case = RegisterCustomerUseCase::new(
+            persister.clone(),
+            AM::new_am(TestCustomerIdGenerator::new()),
+            AM::new_am(TestCustomerAlreadyExists { value: true }),
+        );
+        let result = use_case
+            .execute(&rnd_customer_name(), &rnd_phone_number())
+            .await;
+
+        assert_eq!(result, Err(RegisterCustomerUseCaseError::AlreadyExists));
+        persister.lock().await.verify_empty();
+    }
+}
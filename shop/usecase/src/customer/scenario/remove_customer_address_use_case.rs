@@ -0,0 +1,104 @@
+use async_trait::async_trait;
+use common::types::base::AM;
+use derive_new::new;
+use domain::{
+    cart::value_objects::customer_id::CustomerId,
+    customer::value_objects::saved_address::SavedAddressId,
+};
+
+use crate::customer::{
+    access::{customer_extractor::CustomerExtractor, customer_persister::CustomerPersister},
+    remove_customer_address::{RemoveCustomerAddress, RemoveCustomerAddressUseCaseError},
+};
+
+#[derive(new, Debug)]
+pub struct RemoveCustomerAddressUseCase {
+    pub customer_extractor: AM<dyn CustomerExtractor>,
+    pub customer_persister: AM<dyn CustomerPersister>,
+}
+
+#[async_trait]
+impl RemoveCustomerAddress for RemoveCustomerAddressUseCase {
+    async fn execute(
+        &mut self,
+        id: &CustomerId,
+        address_id: &SavedAddressId,
+    ) -> Result<(), RemoveCustomerAddressUseCaseError> {
+        let mut customer = self
+            .customer_extractor
+            .lock()
+            .await
+            .get_by_id(id)
+            .filter(|customer| customer.visible())
+            .ok_or(RemoveCustomerAddressUseCaseError::CustomerNotFound)?;
+        customer
+            .remove_address(address_id)
+            .map_err(|_| RemoveCustomerAddressUseCaseError::AddressNotFound)?;
+        self.customer_persister.lock().await.save(customer).await;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::types::base::AMTrait;
+    use domain::test_fixtures::*;
+
+    use super::*;
+    use crate::test_fixtures::{MockCustomerExtractor, MockCustomerPersister};
+
+    #[tokio::test]
+    async fn successfully_removed() {
+        let mut customer = rnd_customer();
+        let address_id = customer.add_address(rnd_address());
+        customer.pop_events();
+        let extractor = AM::new_am(MockCustomerExtractor::new(Some(customer.clone())));
+        let persister = AM::new_am(MockCustomerPersister::new());
+
+        let mut use_case = RemoveCustomerAddressUseCase::new(extractor.clone(), persister.clone());
+        let result = use_case.execute(customer.id(), &address_id).await;
+
+        assert!(result.is_ok());
+        let saved = persister.lock().await.customer.clone().unwrap();
+        assert_eq!(saved.saved_address(&address_id), None);
+    }
+
+    #[tokio::test]
+    async fn address_not_found() {
+        let customer = rnd_customer();
+        let persister = AM::new_am(MockCustomerPersister::new());
+
+        let mut use_case = RemoveCustomerAddressUseCase::new(
+            AM::new_am(MockCustomerExtractor::new(Some(customer.clone()))),
+            persister.clone(),
+        );
+        let result = use_case
+            .execute(customer.id(), &SavedAddressId::new())
+            .await;
+
+        assert_eq!(
+            result,
+            Err(RemoveCustomerAddressUseCaseError::AddressNotFound)
+        );
+        persister.lock().await.verify_empty();
+    }
+
+    #[tokio::test]
+    async fn customer_not_found() {
+        let persister = AM::new_am(MockCustomerPersister::new());
+
+        let mut use_case = RemoveCustomerAddressUseCase::new(
+            AM::new_am(MockCustomerExtractor::new(None)),
+            persister.clone(),
+        );
+        let result = use_case
+            .execute(&rnd_customer_id(), &SavedAddressId::new())
+            .await;
+
+        assert_eq!(
+            result,
+            Err(RemoveCustomerAddressUseCaseError::CustomerNotFound)
+        );
+        persister.lock().await.verify_empty();
+    }
+}
@@ -0,0 +1,71 @@
+use async_trait::async_trait;
+use common::types::base::AM;
+use derive_new::new;
+use domain::cart::value_objects::customer_id::CustomerId;
+
+use crate::customer::{
+    access::{customer_extractor::CustomerExtractor, customer_persister::CustomerPersister},
+    remove_customer::{RemoveCustomer, RemoveCustomerUseCaseError},
+};
+
+#[derive(new, Debug)]
+pub struct RemoveCustomerUseCase {
+    pub customer_extractor: AM<dyn CustomerExtractor>,
+    pub customer_persister: AM<dyn CustomerPersister>,
+}
+
+#[async_trait]
+impl RemoveCustomer for RemoveCustomerUseCase {
+    async fn execute(&mut self, id: &CustomerId) -> Result<(), RemoveCustomerUseCaseError> {
+        let mut customer = self
+            .customer_extractor
+            .lock()
+            .await
+            .get_by_id(id)
+            .ok_or(RemoveCustomerUseCaseError::CustomerNotFound)?;
+        customer.remove();
+        self.customer_persister.lock().await.save(customer).await;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::types::base::AMTrait;
+    use domain::test_fixtures::*;
+
+    use super::*;
+    use crate::test_fixtures::{MockCustomerExtractor, MockCustomerPersister};
+
+    #[tokio::test]
+    async fn successfully_removed() {
+        let customer = rnd_customer();
+        let extractor = AM::new_am(MockCustomerExtractor::new(Some(customer.clone())));
+        let persister = AM::new_am(MockCustomerPersister::new());
+
+        let mut use_case = RemoveCustomerUseCase::new(extractor.clone(), persister.clone());
+        let result = use_case.execute(customer.id()).await;
+
+        assert!(result.is_ok());
+        extractor
+            .lock()
+            .await
+            .verify_invoked_get_by_id(customer.id());
+        let saved = persister.lock().await.customer.clone().unwrap();
+        assert!(saved.removed());
+    }
+
+    #[tokio::test]
+    async fn customer_not_found() {
+        let persister = AM::new_am(MockCustomerPersister::new());
+
+        let mut use_case = RemoveCustomerUseCase::new(
+            AM::new_am(MockCustomerExtractor::new(None)),
+            persister.clone(),
+        );
+        let result = use_case.execute(&rnd_customer_id()).await;
+
+        assert_eq!(result, Err(RemoveCustomerUseCaseError::CustomerNotFound));
+        persister.lock().await.verify_empty();
+    }
+}
@@ -0,0 +1,126 @@
+use async_trait::async_trait;
+use common::types::base::AM;
+use derive_new::new;
+use domain::customer::customer_already_exists::CustomerAlreadyExists;
+
+use crate::customer::{
+    access::{customer_extractor::CustomerExtractor, customer_persister::CustomerPersister},
+    update_customer::{UpdateCustomer, UpdateCustomerRequest, UpdateCustomerUseCaseError},
+};
+
+#[derive(new, Debug)]
+pub struct UpdateCustomerUseCase {
+    pub customer_extractor: AM<dyn CustomerExtractor>,
+    pub customer_persister: AM<dyn CustomerPersister>,
+    pub customer_exists: AM<dyn CustomerAlreadyExists>,
+}
+
+#[async_trait]
+impl UpdateCustomer for UpdateCustomerUseCase {
+    async fn execute(
+        &mut self,
+        request: &UpdateCustomerRequest,
+    ) -> Result<(), UpdateCustomerUseCaseError> {
+        let mut customer = self
+            .customer_extractor
+            .lock()
+            .await
+            .get_by_id(&request.id)
+            .filter(|customer| customer.visible())
+            .ok_or(UpdateCustomerUseCaseError::CustomerNotFound)?;
+        customer
+            .update(
+                self.customer_exists.clone(),
+                request.name.clone(),
+                request.phone.clone(),
+                request.preferences,
+            )
+            .await?;
+        self.customer_persister.lock().await.save(customer).await;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::types::base::AMTrait;
+    use domain::{
+        customer::value_objects::customer_preferences::CustomerPreferences, test_fixtures::*,
+    };
+
+    use super::*;
+    use crate::test_fixtures::{MockCustomerExtractor, MockCustomerPersister};
+
+    #[tokio::test]
+    async fn successfully_updated() {
+        let customer = rnd_customer();
+        let extractor = AM::new_am(MockCustomerExtractor::new(Some(customer.clone())));
+        let persister = AM::new_am(MockCustomerPersister::new());
+        let request = UpdateCustomerRequest::new(
+            *customer.id(),
+            rnd_customer_name(),
+            rnd_phone_number(),
+            CustomerPreferences::new(None, true),
+        );
+
+        let mut use_case = UpdateCustomerUseCase::new(
+            extractor.clone(),
+            persister.clone(),
+            AM::new_am(TestCustomerAlreadyExists::new()),
+        );
+        let result = use_case.execute(&request).await;
+
+        assert!(result.is_ok());
+        extractor
+            .lock()
+            .await
+            .verify_invoked_get_by_id(customer.id());
+        let saved = persister.lock().await.customer.clone().unwrap();
+        assert_eq!(saved.name(), &request.name);
+        assert_eq!(saved.phone(), &request.phone);
+        assert_eq!(saved.preferences(), &request.preferences);
+    }
+
+    #[tokio::test]
+    async fn customer_not_found() {
+        let persister = AM::new_am(MockCustomerPersister::new());
+        let request = UpdateCustomerRequest::new(
+            rnd_customer_id(),
+            rnd_customer_name(),
+            rnd_phone_number(),
+            CustomerPreferences::default(),
+        );
+
+        let mut use_case = UpdateCustomerUseCase::new(
+            AM::new_am(MockCustomerExtractor::new(None)),
+            persister.clone(),
+            AM::new_am(TestCustomerAlreadyExists::new()),
+        );
+        let result = use_case.execute(&request).await;
+
+        assert_eq!(result, Err(UpdateCustomerUseCaseError::CustomerNotFound));
+        persister.lock().await.verify_empty();
+    }
+
+    #[tokio::test]
+    async fn phone_already_taken() {
+        let customer = rnd_customer();
+        let persister = AM::new_am(MockCustomerPersister::new());
+        let request = UpdateCustomerRequest::new(
+            *customer.id(),
+            customer.name().clone(),
+            rnd_phone_number(),
+            CustomerPreferences::default(),
+        );
+
+        let mut use_case = UpdateCustomerUseCase::new(
+            AM::new_am(MockCustomerExtractor::new(Some(customer))),
+            persister.clone(),
+            AM::new_am(TestCustomerAlreadyExists { value: true }),
+        );
+        let result = use_case.execute(&request).await;
+
+        assert_eq!(result, Err(UpdateCustomerUseCaseError::AlreadyExists));
+        persister.lock().await.verify_empty();
+    }
+}
@@ -0,0 +1,44 @@
+use async_trait::async_trait;
+use derive_new::new;
+use domain::{
+    cart::value_objects::customer_id::CustomerId,
+    customer::{
+        customer::CustomerError,
+        value_objects::{
+            customer_name::CustomerName, customer_preferences::CustomerPreferences,
+            phone_number::PhoneNumber,
+        },
+    },
+};
+
+#[async_trait]
+pub trait UpdateCustomer {
+    async fn execute(
+        &mut self,
+        request: &UpdateCustomerRequest,
+    ) -> Result<(), UpdateCustomerUseCaseError>;
+}
+
+#[derive(new, Debug, Clone, PartialEq)]
+pub struct UpdateCustomerRequest {
+    pub id: CustomerId,
+    pub name: CustomerName,
+    pub phone: PhoneNumber,
+    pub preferences: CustomerPreferences,
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum UpdateCustomerUseCaseError {
+    CustomerNotFound,
+    AlreadyExists,
+    SavedAddressNotFound,
+}
+
+impl From<CustomerError> for UpdateCustomerUseCaseError {
+    fn from(value: CustomerError) -> Self {
+        match value {
+            CustomerError::AlreadyExistsWithSamePhoneError => Self::AlreadyExists,
+            CustomerError::SavedAddressNotFound => Self::SavedAddressNotFound,
+        }
+    }
+}
@@ -0,0 +1,10 @@
+use std::fmt::Debug;
+
+use domain::{inventory::meal_stock::MealStock, menu::value_objects::meal_id::MealId};
+
+pub trait MealStockExtractor: Debug + Send {
+    fn get_by_meal_id(&mut self, meal_id: &MealId) -> Option<MealStock>;
+
+    /// Stocks of the given meals, meals without a stock are skipped
+    fn get_by_meal_ids(&mut self, meal_ids: &[MealId]) -> Vec<MealStock>;
+}
@@ -0,0 +1,9 @@
+use std::fmt::Debug;
+
+use async_trait::async_trait;
+use domain::inventory::meal_stock::MealStock;
+
+#[async_trait]
+pub trait MealStockPersister: Debug + Send {
+    async fn save(&mut self, stock: MealStock);
+}
@@ -0,0 +1,2 @@
+pub mod meal_stock_extractor;
+pub mod meal_stock_persister;
@@ -0,0 +1,7 @@
+pub mod access;
+pub mod rules;
+pub mod scenarios;
+
+mod set_meal_portions;
+
+pub use set_meal_portions::*;
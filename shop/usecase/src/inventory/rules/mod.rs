@@ -0,0 +1 @@
+pub mod release_portions_after_cancel_rule;
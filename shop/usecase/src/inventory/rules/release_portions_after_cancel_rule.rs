@@ -0,0 +1,146 @@
+use std::mem::{Discriminant, discriminant};
+
+use async_trait::async_trait;
+use common::{events::DomainEventListener, types::base::AM};
+use derive_new::new;
+use domain::order::customer_order_events::{ShopOrderCancelledDomainEvent, ShopOrderEventEnum};
+use tracing::info;
+
+use crate::{
+    inventory::access::{
+        meal_stock_extractor::MealStockExtractor, meal_stock_persister::MealStockPersister,
+    },
+    order::access::shop_order_extractor::ShopOrderExtractor,
+};
+
+#[derive(new, Debug)]
+pub struct ReleasePortionsAfterCancelRule<ShOExtractor, MSExtractor, MSPersister>
+where
+    ShOExtractor: ShopOrderExtractor,
+    MSExtractor: MealStockExtractor,
+    MSPersister: MealStockPersister,
+{
+    shop_order_extractor: AM<ShOExtractor>,
+    meal_stock_extractor: AM<MSExtractor>,
+    meal_stock_persister: AM<MSPersister>,
+}
+
+#[async_trait]
+impl<ShOExtractor, MSExtractor, MSPersister> DomainEventListener<ShopOrderEventEnum>
+    for ReleasePortionsAfterCancelRule<ShOExtractor, MSExtractor, MSPersister>
+where
+    ShOExtractor: ShopOrderExtractor,
+    MSExtractor: MealStockExtractor,
+    MSPersister: MealStockPersister,
+{
+    fn event_type(&self) -> Discriminant<ShopOrderEventEnum> {
+        let event: ShopOrderEventEnum = ShopOrderCancelledDomainEvent::default().into();
+        discriminant(&event)
+    }
+
+    async fn handle(&mut self, event: &ShopOrderEventEnum) {
+        let event_struct: ShopOrderCancelledDomainEvent =
+            event.clone().try_into().expect("Wrong type of event");
+
+        let Some(order) = self
+            .shop_order_extractor
+            .lock()
+            .await
+            .get_by_id(&event_struct.order_id)
+        else {
+            let _ = tracing_subscriber::fmt::try_init();
+            info!("Order #{} is not found", event_struct.order_id.to_i64());
+            return;
+        };
+
+        let meal_ids: Vec<_> = order.order_items().iter().map(|it| it.meal_id).collect();
+        let stocks = self
+            .meal_stock_extractor
+            .lock()
+            .await
+            .get_by_meal_ids(&meal_ids);
+        for mut stock in stocks {
+            let meal_id = *stock.meal_id();
+            order
+                .order_items()
+                .iter()
+                .filter(|it| it.meal_id == meal_id)
+                .for_each(|it| stock.release(it.count));
+            self.meal_stock_persister.lock().await.save(stock).await;
+        }
+    }
+
+    fn get_events(&self) -> &Vec<ShopOrderEventEnum> {
+        todo!()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashSet;
+
+    use common::types::{base::AMTrait, common::Count};
+    use domain::{
+        inventory::meal_stock::MealStock, order::shop_order::OrderItem, test_fixtures::*,
+    };
+    use tracing_test::traced_test;
+
+    use super::*;
+    use crate::test_fixtures::{
+        MockMealStockExtractor, MockMealStockPersister, MockShopOrderExtractor,
+    };
+
+    fn count(value: i32) -> Count {
+        Count::try_from(value).unwrap()
+    }
+
+    #[tokio::test]
+    async fn portions_released() {
+        let tracked = OrderItem::new(rnd_meal_id(), rnd_price(), count(2));
+        let untracked = OrderItem::new(rnd_meal_id(), rnd_price(), count(3));
+        let order = rnd_order(HashSet::from([tracked.clone(), untracked]));
+        let stock = MealStock::create(tracked.meal_id, count(1));
+
+        let order_extractor = AM::new_am(MockShopOrderExtractor::default());
+        order_extractor.lock().await.order = Some(order.clone());
+        let stock_persister = AM::new_am(MockMealStockPersister::default());
+        let mut rule = ReleasePortionsAfterCancelRule::new(
+            order_extractor.clone(),
+            AM::new_am(MockMealStockExtractor::new(vec![stock])),
+            stock_persister.clone(),
+        );
+        let event: ShopOrderEventEnum = ShopOrderCancelledDomainEvent::new(*order.id()).into();
+
+        rule.handle(&event).await;
+
+        order_extractor
+            .lock()
+            .await
+            .verify_invoked_get_by_id(order.id());
+        let saved = stock_persister.lock().await.saved.clone();
+        assert_eq!(saved.len(), 1);
+        assert_eq!(saved[0].meal_id(), &tracked.meal_id);
+        assert_eq!(saved[0].available(), &count(3));
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn order_not_found() {
+        let order_id = rnd_order_id();
+        let stock_persister = AM::new_am(MockMealStockPersister::default());
+        let mut rule = ReleasePortionsAfterCancelRule::new(
+            AM::new_am(MockShopOrderExtractor::default()),
+            AM::new_am(MockMealStockExtractor::default()),
+            stock_persister.clone(),
+        );
+        let event: ShopOrderEventEnum = ShopOrderCancelledDomainEvent::new(order_id).into();
+
+        rule.handle(&event).await;
+
+        stock_persister.lock().await.verify_empty();
+        assert!(logs_contain(&format!(
+            "Order #{} is not found",
+            order_id.to_i64()
+        )));
+    }
+}
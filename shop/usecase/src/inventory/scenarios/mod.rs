@@ -0,0 +1,3 @@
+mod set_meal_portions_use_case;
+
+pub use set_meal_portions_use_case::*;
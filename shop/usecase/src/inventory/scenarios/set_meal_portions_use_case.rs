@@ -0,0 +1,141 @@
+use async_trait::async_trait;
+use common::types::{base::AM, common::Count};
+use derive_new::new;
+use domain::{inventory::meal_stock::MealStock, menu::value_objects::meal_id::MealId};
+
+use crate::{
+    inventory::{
+        access::{
+            meal_stock_extractor::MealStockExtractor, meal_stock_persister::MealStockPersister,
+        },
+        set_meal_portions::{SetMealPortions, SetMealPortionsUseCaseError},
+    },
+    menu::access::meal_extractor::MealExtractor,
+};
+
+#[derive(Debug, new)]
+pub struct SetMealPortionsUseCase {
+    meal_extractor: AM<dyn MealExtractor>,
+    meal_stock_extractor: AM<dyn MealStockExtractor>,
+    meal_stock_persister: AM<dyn MealStockPersister>,
+}
+
+#[async_trait]
+impl SetMealPortions for SetMealPortionsUseCase {
+    async fn execute(
+        &mut self,
+        meal_id: &MealId,
+        portions: Count,
+    ) -> Result<(), SetMealPortionsUseCaseError> {
+        self.meal_extractor
+            .lock()
+            .await
+            .get_by_id(meal_id)
+            .filter(|meal| meal.visible())
+            .ok_or(SetMealPortionsUseCaseError::MealNotFound)?;
+
+        let stock = match self
+            .meal_stock_extractor
+            .lock()
+            .await
+            .get_by_meal_id(meal_id)
+        {
+            Some(mut stock) => {
+                stock.replenish(portions);
+                stock
+            }
+            None => MealStock::create(*meal_id, portions),
+        };
+        self.meal_stock_persister.lock().await.save(stock).await;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::types::base::AMTrait;
+    use domain::test_fixtures::*;
+
+    use super::*;
+    use crate::test_fixtures::{MockMealExtractor, MockMealStockExtractor, MockMealStockPersister};
+
+    fn count(value: i32) -> Count {
+        Count::try_from(value).unwrap()
+    }
+
+    #[tokio::test]
+    async fn stock_created_for_untracked_meal() {
+        let meal = rnd_meal();
+        let meal_extractor = AM::new_am(MockMealExtractor::new());
+        meal_extractor.lock().await.meal = Some(meal.clone());
+        let persister = AM::new_am(MockMealStockPersister::default());
+
+        let mut use_case = SetMealPortionsUseCase::new(
+            meal_extractor,
+            AM::new_am(MockMealStockExtractor::default()),
+            persister.clone(),
+        );
+        let result = use_case.execute(meal.id(), count(10)).await;
+
+        assert!(result.is_ok());
+        let saved = persister.lock().await.saved.clone();
+        assert_eq!(saved.len(), 1);
+        assert_eq!(saved[0].meal_id(), meal.id());
+        assert_eq!(saved[0].available(), &count(10));
+    }
+
+    #[tokio::test]
+    async fn existing_stock_replenished() {
+        let meal = rnd_meal();
+        let mut stock = MealStock::create(*meal.id(), count(0));
+        stock.pop_events();
+        let meal_extractor = AM::new_am(MockMealExtractor::new());
+        meal_extractor.lock().await.meal = Some(meal.clone());
+        let persister = AM::new_am(MockMealStockPersister::default());
+
+        let mut use_case = SetMealPortionsUseCase::new(
+            meal_extractor,
+            AM::new_am(MockMealStockExtractor::new(vec![stock.clone()])),
+            persister.clone(),
+        );
+        let result = use_case.execute(meal.id(), count(3)).await;
+
+        assert!(result.is_ok());
+        let saved = persister.lock().await.saved.clone();
+        assert_eq!(saved[0].available(), &count(3));
+        assert_eq!(saved[0].version(), &stock.version().next());
+    }
+
+    #[tokio::test]
+    async fn meal_not_found() {
+        let persister = AM::new_am(MockMealStockPersister::default());
+
+        let mut use_case = SetMealPortionsUseCase::new(
+            AM::new_am(MockMealExtractor::new()),
+            AM::new_am(MockMealStockExtractor::default()),
+            persister.clone(),
+        );
+        let result = use_case.execute(&rnd_meal_id(), count(3)).await;
+
+        assert_eq!(result, Err(SetMealPortionsUseCaseError::MealNotFound));
+        persister.lock().await.verify_empty();
+    }
+
+    #[tokio::test]
+    async fn removed_meal_not_found() {
+        let meal = rnd_removed_meal();
+        let meal_extractor = AM::new_am(MockMealExtractor::new());
+        meal_extractor.lock().await.meal = Some(meal.clone());
+        let persister = AM::new_am(MockMealStockPersister::default());
+
+        let mut use_case = SetMealPortionsUseCase::new(
+            meal_extractor,
+            AM::new_am(MockMealStockExtractor::default()),
+            persister.clone(),
+        );
+        let result = use_case.execute(meal.id(), count(3)).await;
+
+        assert_eq!(result, Err(SetMealPortionsUseCaseError::MealNotFound));
+        persister.lock().await.verify_empty();
+    }
+}
@@ -0,0 +1,21 @@
+use std::fmt::Debug;
+
+use async_trait::async_trait;
+use common::types::common::Count;
+use domain::menu::value_objects::meal_id::MealId;
+use thiserror::Error;
+
+#[async_trait]
+pub trait SetMealPortions: Debug + Send {
+    async fn execute(
+        &mut self,
+        meal_id: &MealId,
+        portions: Count,
+    ) -> Result<(), SetMealPortionsUseCaseError>;
+}
+
+#[derive(Error, Debug, PartialEq, Clone, Copy)]
+pub enum SetMealPortionsUseCaseError {
+    #[error("Meal not found")]
+    MealNotFound,
+}
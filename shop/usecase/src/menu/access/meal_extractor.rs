@@ -2,7 +2,7 @@ use std::fmt::Debug;
 
 use domain::menu::{
     meal::Meal,
-    value_objects::{meal_id::MealId, meal_name::MealName},
+    value_objects::{allergens::Allergens, meal_id::MealId, meal_name::MealName},
 };
 
 pub trait MealExtractor: Debug + Send {
@@ -10,7 +10,14 @@ pub trait MealExtractor: Debug + Send {
 
     fn get_by_name(&mut self, name: &MealName) -> Option<Meal>;
 
-    fn get_all(&mut self) -> Vec<Meal>;
+    /// Meals which are not removed and contain none of `excluded_allergens`,
+    /// ordered by id and starting at `start_id`
+    fn get_all(
+        &mut self,
+        start_id: &MealId,
+        limit: usize,
+        excluded_allergens: &Allergens,
+    ) -> Vec<Meal>;
 }
 
 // Opportunity to downcast. Not used in rest of the code.
@@ -0,0 +1,36 @@
+use std::fmt::Debug;
+
+use domain::menu::meal::Meal;
+
+/// Full-text search over the meals which are on the menu
+pub trait MealSearcher: Debug + Send {
+    /// Meals whose name or description has a word starting with each of the searched words,
+    /// most relevant first
+    fn search(&mut self, text: &str, limit: usize) -> Vec<Meal>;
+}
+
+/// Lowercase alphanumeric words of a search text, punctuation separates words
+pub fn search_words(text: &str) -> Vec<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(str::to_lowercase)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn words_are_lowercased_and_split_by_punctuation() {
+        assert_eq!(
+            search_words("  Spicy,chicken-WINGS! "),
+            vec!["spicy", "chicken", "wings"]
+        );
+    }
+
+    #[test]
+    fn no_words_in_punctuation() {
+        assert!(search_words(" & | ! ").is_empty());
+    }
+}
@@ -0,0 +1,114 @@
+use std::fmt::Debug;
+
+use actix_web::http::Uri;
+use async_trait::async_trait;
+use domain::menu::value_objects::meal_id::MealId;
+use thiserror::Error;
+
+/// Image formats accepted for the meal images
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageFormat {
+    Jpeg,
+    Png,
+    Webp,
+}
+
+impl ImageFormat {
+    pub fn from_content_type(content_type: &str) -> Option<Self> {
+        match content_type {
+            "image/jpeg" => Some(Self::Jpeg),
+            "image/png" => Some(Self::Png),
+            "image/webp" => Some(Self::Webp),
+            _ => None,
+        }
+    }
+
+    /// Format recognized by the signature at the start of the content
+    pub fn from_magic_bytes(content: &[u8]) -> Option<Self> {
+        match content {
+            [0xFF, 0xD8, 0xFF, ..] => Some(Self::Jpeg),
+            [0x89, b'P', b'N', b'G', b'\r', b'\n', 0x1A, b'\n', ..] => Some(Self::Png),
+            [
+                b'R',
+                b'I',
+                b'F',
+                b'F',
+                _,
+                _,
+                _,
+                _,
+                b'W',
+                b'E',
+                b'B',
+                b'P',
+                ..,
+            ] => Some(Self::Webp),
+            _ => None,
+        }
+    }
+
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Jpeg => "jpg",
+            Self::Png => "png",
+            Self::Webp => "webp",
+        }
+    }
+}
+
+/// Storage of the meal images, the images are downloaded by their urls bypassing the application
+#[async_trait]
+pub trait MediaStorage: Debug + Send {
+    /// Stores the image and returns its url
+    async fn store(
+        &mut self,
+        meal_id: &MealId,
+        format: ImageFormat,
+        content: &[u8],
+    ) -> Result<Uri, MediaStorageError>;
+
+    /// Urls of the meal images in the order they were stored. Called for every meal of the menu,
+    /// so it is answered without reaching the storage
+    fn get_urls(&mut self, meal_id: &MealId) -> Vec<Uri>;
+
+    async fn remove_all(&mut self, meal_id: &MealId) -> Result<(), MediaStorageError>;
+}
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum MediaStorageError {
+    #[error("Media storage failed: {0}")]
+    StorageFailed(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn format_is_taken_from_content_type() {
+        assert_eq!(
+            ImageFormat::from_content_type("image/png"),
+            Some(ImageFormat::Png)
+        );
+        assert_eq!(ImageFormat::from_content_type("image/gif"), None);
+    }
+
+    #[test]
+    fn format_is_taken_from_magic_bytes() {
+        assert_eq!(
+            ImageFormat::from_magic_bytes(&[0xFF, 0xD8, 0xFF, 0xE0, 0x00]),
+            Some(ImageFormat::Jpeg)
+        );
+        assert_eq!(
+            ImageFormat::from_magic_bytes(b"\x89PNG\r\n\x1a\n\x00"),
+            Some(ImageFormat::Png)
+        );
+        assert_eq!(
+            ImageFormat::from_magic_bytes(b"RIFF\x24\x00\x00\x00WEBPVP8 "),
+            Some(ImageFormat::Webp)
+        );
+        assert_eq!(ImageFormat::from_magic_bytes(b"GIF89a"), None);
+        assert_eq!(ImageFormat::from_magic_bytes(b"<svg"), None);
+        assert_eq!(ImageFormat::from_magic_bytes(&[]), None);
+    }
+}
@@ -1,2 +1,4 @@
 pub mod meal_extractor;
 pub mod meal_persister;
+pub mod meal_searcher;
+pub mod media_storage;
@@ -4,9 +4,11 @@ use async_trait::async_trait;
 use domain::menu::{
     meal::MealError,
     value_objects::{
-        meal_description::MealDescription, meal_id::MealId, meal_name::MealName, price::Price,
+        allergens::Allergens, meal_description::MealDescription, meal_id::MealId,
+        meal_name::MealName, nutrition_facts::NutritionFacts, price::Price,
     },
 };
+use thiserror::Error;
 
 #[async_trait]
 pub trait AddMealToMenu: Debug + Send {
@@ -15,13 +17,18 @@ pub trait AddMealToMenu: Debug + Send {
         name: &MealName,
         description: &MealDescription,
         price: &Price,
+        allergens: &Allergens,
+        nutrition_facts: &NutritionFacts,
     ) -> Result<MealId, AddMealToMenuUseCaseError>;
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Error, Debug, Clone, PartialEq)]
 pub enum AddMealToMenuUseCaseError {
+    #[error("Invalid parameters")]
     InvalidParameters,
+    #[error("Meal already exists")]
     AlreadyExists,
+    #[error("Unknown error")]
     UnknownError,
 }
 
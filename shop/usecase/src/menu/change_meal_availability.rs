@@ -0,0 +1,19 @@
+use async_trait::async_trait;
+use domain::menu::value_objects::{availability_schedule::AvailabilitySchedule, meal_id::MealId};
+use thiserror::Error;
+
+#[async_trait]
+pub trait ChangeMealAvailability {
+    /// `None` makes the meal available at any time
+    async fn execute(
+        &mut self,
+        id: &MealId,
+        schedule: Option<AvailabilitySchedule>,
+    ) -> Result<(), ChangeMealAvailabilityUseCaseError>;
+}
+
+#[derive(Error, Debug, PartialEq, Clone, Copy)]
+pub enum ChangeMealAvailabilityUseCaseError {
+    #[error("Meal not found")]
+    MealNotFound,
+}
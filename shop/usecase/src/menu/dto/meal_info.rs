@@ -1,8 +1,11 @@
+use actix_web::http::Uri;
 use common::types::base::Version;
 use domain::menu::{
     meal::Meal,
     value_objects::{
-        meal_description::MealDescription, meal_id::MealId, meal_name::MealName, price::Price,
+        allergens::Allergens, availability_schedule::AvailabilitySchedule,
+        meal_description::MealDescription, meal_id::MealId, meal_name::MealName,
+        nutrition_facts::NutritionFacts, price::Price,
     },
 };
 
@@ -15,7 +18,14 @@ pub struct MealInfo {
     pub name: MealName,
     pub description: MealDescription,
     pub price: Price,
+    pub allergens: Allergens,
+    pub nutrition_facts: NutritionFacts,
+    /// `None` when the meal can be ordered at any time
+    pub availability_schedule: Option<AvailabilitySchedule>,
     pub version: Version,
+    /// `false` when the meal is sold out
+    pub available: bool,
+    pub image_urls: Vec<Uri>,
 }
 
 impl From<Meal> for MealInfo {
@@ -25,7 +35,12 @@ impl From<Meal> for MealInfo {
             name: value.name().to_owned(),
             description: value.description().to_owned(),
             price: value.price().to_owned(),
+            allergens: value.allergens().to_owned(),
+            nutrition_facts: value.nutrition_facts().to_owned(),
+            availability_schedule: value.availability_schedule().to_owned(),
             version: *value.version(),
+            available: true,
+            image_urls: vec![],
         }
     }
 }
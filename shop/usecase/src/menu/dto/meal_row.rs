@@ -0,0 +1,32 @@
+use bigdecimal::BigDecimal;
+use domain::menu::meal::Meal;
+
+/// A meal as it is imported and exported in bulk. The values are raw, an imported row is
+/// validated by the use case so every broken row can be reported
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct MealRow {
+    pub name: String,
+    pub description: String,
+    pub price: BigDecimal,
+    pub allergens: Vec<String>,
+    pub calories: i32,
+    pub proteins: BigDecimal,
+    pub fats: BigDecimal,
+    pub carbohydrates: BigDecimal,
+}
+
+impl From<&Meal> for MealRow {
+    fn from(meal: &Meal) -> Self {
+        let nutrition_facts = meal.nutrition_facts();
+        Self {
+            name: meal.name().to_string(),
+            description: meal.description().to_string(),
+            price: meal.price().to_bigdecimal(),
+            allergens: meal.allergens().to_codes(),
+            calories: *nutrition_facts.calories(),
+            proteins: nutrition_facts.proteins().clone(),
+            fats: nutrition_facts.fats().clone(),
+            carbohydrates: nutrition_facts.carbohydrates().clone(),
+        }
+    }
+}
@@ -0,0 +1,18 @@
+use domain::menu::value_objects::meal_id::MealId;
+
+use crate::menu::MealRowError;
+
+/// Outcome of a menu import. Nothing is added when at least one row is rejected
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct MenuImportReport {
+    pub dry_run: bool,
+    pub added: Vec<MealId>,
+    pub rejected: Vec<RejectedMealRow>,
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct RejectedMealRow {
+    /// Position of the row among the imported rows, starting at 1
+    pub row: usize,
+    pub errors: Vec<MealRowError>,
+}
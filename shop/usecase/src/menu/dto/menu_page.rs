@@ -0,0 +1,10 @@
+use time::OffsetDateTime;
+
+use crate::menu::dto::meal_info::MealInfo;
+
+/// A page of the menu together with the moment the menu was changed last time
+#[derive(Debug, PartialEq, Clone)]
+pub struct MenuPage {
+    pub meals: Vec<MealInfo>,
+    pub last_modified: OffsetDateTime,
+}
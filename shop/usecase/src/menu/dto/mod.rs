@@ -1 +1,4 @@
 pub mod meal_info;
+pub mod meal_row;
+pub mod menu_import_report;
+pub mod menu_page;
@@ -0,0 +1,9 @@
+use async_trait::async_trait;
+
+use crate::menu::dto::meal_row::MealRow;
+
+#[async_trait]
+pub trait ExportMenu {
+    /// All meals of the menu ordered by id, in the format accepted by the import
+    async fn execute(&mut self) -> Vec<MealRow>;
+}
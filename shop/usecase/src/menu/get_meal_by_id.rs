@@ -2,6 +2,7 @@ use std::fmt::Debug;
 
 use async_trait::async_trait;
 use domain::menu::value_objects::meal_id::MealId;
+use thiserror::Error;
 
 use crate::menu::dto::meal_info::MealInfo;
 
@@ -10,7 +11,8 @@ pub trait GetMealById {
     async fn execute(&mut self, id: &MealId) -> Result<MealInfo, GetMealByIdUseCaseError>;
 }
 
-#[derive(Debug, PartialEq, Clone, Copy)]
+#[derive(Error, Debug, PartialEq, Clone, Copy)]
 pub enum GetMealByIdUseCaseError {
+    #[error("Meal not found")]
     MealNotFound,
 }
@@ -1,8 +1,47 @@
 use async_trait::async_trait;
+use derive_new::new;
+use domain::menu::value_objects::{allergens::Allergens, meal_id::MealId};
+use thiserror::Error;
+use time::OffsetDateTime;
 
-use crate::menu::dto::meal_info::MealInfo;
+use crate::menu::dto::menu_page::MenuPage;
 
 #[async_trait]
 pub trait GetMenu {
-    async fn execute(&self) -> Vec<MealInfo>;
+    async fn execute(
+        &mut self,
+        start_id: &MealId,
+        limit: usize,
+        excluded_allergens: &Allergens,
+        availability: &MenuAvailability,
+    ) -> Result<MenuPage, GetMenuUseCaseError>;
+}
+
+/// Which meals of the menu are returned according to their availability schedules
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub enum MenuAvailability {
+    /// Meals which can be ordered right now
+    #[default]
+    Now,
+    /// Meals which can be ordered at the given moment
+    At(OffsetDateTime),
+    /// All meals regardless of their schedules
+    Any,
+}
+
+impl MenuAvailability {
+    /// Moment the schedules are checked at, `None` when they are ignored
+    pub fn moment(&self) -> Option<OffsetDateTime> {
+        match self {
+            Self::Now => Some(OffsetDateTime::now_utc()),
+            Self::At(at) => Some(*at),
+            Self::Any => None,
+        }
+    }
+}
+
+#[derive(new, Error, Debug, Clone, Copy, PartialEq)]
+pub enum GetMenuUseCaseError {
+    #[error("Limit is exceeded")]
+    LimitExceed(usize),
 }
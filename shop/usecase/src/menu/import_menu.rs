@@ -0,0 +1,47 @@
+use async_trait::async_trait;
+use thiserror::Error;
+
+use crate::menu::dto::{meal_row::MealRow, menu_import_report::MenuImportReport};
+
+/// Maximal number of rows in one import
+pub const MAX_MENU_IMPORT_ROWS: usize = 1000;
+
+#[async_trait]
+pub trait ImportMenu {
+    /// Adds every row as a meal to the menu. With `dry_run` the rows are only validated
+    async fn execute(
+        &mut self,
+        rows: &[MealRow],
+        dry_run: bool,
+    ) -> Result<MenuImportReport, ImportMenuUseCaseError>;
+}
+
+#[derive(Error, Debug, PartialEq, Clone)]
+pub enum ImportMenuUseCaseError {
+    #[error("Nothing to import")]
+    Empty,
+    #[error("Import must not have more than {0} rows")]
+    TooManyRows(usize),
+}
+
+#[derive(Error, Debug, PartialEq, Clone)]
+pub enum MealRowError {
+    #[error("Meal name is empty")]
+    EmptyName,
+    #[error("Meal description is empty")]
+    EmptyDescription,
+    #[error("Price scale must not be > {0}")]
+    InvalidPriceScale(i64),
+    #[error("Price must be > 0")]
+    NegativePrice,
+    #[error("Unknown allergen '{0}'")]
+    UnknownAllergen(String),
+    #[error("Nutrition facts scale must not be > {0}")]
+    InvalidNutritionFactsScale(i64),
+    #[error("Nutrition facts must not be negative")]
+    NegativeNutritionFacts,
+    #[error("Meal '{0}' is imported more than once")]
+    DuplicateName(String),
+    #[error("Meal '{0}' already exists")]
+    AlreadyExists(String),
+}
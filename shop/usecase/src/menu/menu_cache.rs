@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+
+use domain::menu::{
+    meal::Meal,
+    value_objects::{allergens::Allergens, meal_id::MealId},
+};
+use smart_default::SmartDefault;
+use time::OffsetDateTime;
+
+/// Pages kept at once, the whole cache is dropped when the limit is reached
+const MAX_CACHED_PAGES: usize = 100;
+
+/// Read-through cache of the menu pages, keyed by the page start, size and excluded allergens.
+/// Has to be invalidated whenever a meal is added to or removed from the menu
+#[derive(Debug, SmartDefault)]
+pub struct MenuCache {
+    pages: HashMap<(MealId, usize, Allergens), Vec<Meal>>,
+    #[default(_code = "OffsetDateTime::now_utc()")]
+    last_modified: OffsetDateTime,
+}
+
+impl MenuCache {
+    pub fn get(
+        &self,
+        start_id: &MealId,
+        limit: usize,
+        excluded_allergens: &Allergens,
+    ) -> Option<Vec<Meal>> {
+        self.pages
+            .get(&(*start_id, limit, excluded_allergens.clone()))
+            .cloned()
+    }
+
+    pub fn put(
+        &mut self,
+        start_id: &MealId,
+        limit: usize,
+        excluded_allergens: &Allergens,
+        meals: Vec<Meal>,
+    ) {
+        if self.pages.len() >= MAX_CACHED_PAGES {
+            self.pages.clear();
+        }
+        self.pages
+            .insert((*start_id, limit, excluded_allergens.clone()), meals);
+    }
+
+    pub fn invalidate(&mut self, changed_at: OffsetDateTime) {
+        self.pages.clear();
+        self.last_modified = self.last_modified.max(changed_at);
+    }
+
+    pub fn last_modified(&self) -> OffsetDateTime {
+        self.last_modified
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::{menu::value_objects::allergens::Allergen, test_fixtures::*};
+    use time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn page_is_cached_by_start_limit_and_allergens() {
+        let meal = rnd_meal();
+        let no_allergens = Allergens::default();
+        let mut cache = MenuCache::default();
+        cache.put(meal.id(), 10, &no_allergens, vec![meal.clone()]);
+
+        assert_eq!(
+            cache.get(meal.id(), 10, &no_allergens),
+            Some(vec![meal.clone()])
+        );
+        assert_eq!(cache.get(meal.id(), 5, &no_allergens), None);
+        let milk = [Allergen::Milk].into_iter().collect();
+        assert_eq!(cache.get(meal.id(), 10, &milk), None);
+    }
+
+    #[test]
+    fn invalidate_drops_pages_and_moves_last_modified() {
+        let meal = rnd_meal();
+        let mut cache = MenuCache::default();
+        cache.put(meal.id(), 10, &Allergens::default(), vec![meal.clone()]);
+        let changed_at = cache.last_modified() + Duration::seconds(1);
+
+        cache.invalidate(changed_at);
+
+        assert_eq!(cache.get(meal.id(), 10, &Allergens::default()), None);
+        assert_eq!(cache.last_modified(), changed_at);
+    }
+
+    #[test]
+    fn invalidate_by_older_event_keeps_last_modified() {
+        let mut cache = MenuCache::default();
+        let last_modified = cache.last_modified();
+
+        cache.invalidate(last_modified - Duration::hours(1));
+
+        assert_eq!(cache.last_modified(), last_modified);
+    }
+}
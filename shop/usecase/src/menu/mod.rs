@@ -1,14 +1,26 @@
 pub mod access;
 pub mod dto;
 pub mod invariant;
+pub mod menu_cache;
+pub mod rules;
 pub mod scenario;
 
 mod add_meal_to_menu;
+mod change_meal_availability;
+mod export_menu;
 mod get_meal_by_id;
 mod get_menu;
+mod import_menu;
 mod remove_meal_from_menu;
+mod search_menu;
+mod upload_meal_image;
 
 pub use add_meal_to_menu::*;
+pub use change_meal_availability::*;
+pub use export_menu::*;
 pub use get_meal_by_id::*;
 pub use get_menu::*;
+pub use import_menu::*;
 pub use remove_meal_from_menu::*;
+pub use search_menu::*;
+pub use upload_meal_image::*;
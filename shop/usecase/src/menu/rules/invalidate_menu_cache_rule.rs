@@ -0,0 +1,134 @@
+use std::mem::{Discriminant, discriminant};
+
+use async_trait::async_trait;
+use common::{events::DomainEventListener, types::base::AM};
+use domain::menu::meal_events::{
+    MealAddedToMenuDomainEvent, MealAvailabilityChangedDomainEvent, MealEventEnum,
+    MealRemovedFromMenuDomainEvent,
+};
+
+use crate::menu::menu_cache::MenuCache;
+
+/// Listens to one kind of meal events, so it has to be registered for each event changing the menu
+#[derive(Debug)]
+pub struct InvalidateMenuCacheRule {
+    menu_cache: AM<MenuCache>,
+    event_type: Discriminant<MealEventEnum>,
+}
+
+impl InvalidateMenuCacheRule {
+    pub fn after_meal_added(menu_cache: AM<MenuCache>) -> Self {
+        let event: MealEventEnum = MealAddedToMenuDomainEvent::default().into();
+        Self {
+            menu_cache,
+            event_type: discriminant(&event),
+        }
+    }
+
+    pub fn after_meal_removed(menu_cache: AM<MenuCache>) -> Self {
+        let event: MealEventEnum = MealRemovedFromMenuDomainEvent::default().into();
+        Self {
+            menu_cache,
+            event_type: discriminant(&event),
+        }
+    }
+
+    pub fn after_meal_availability_changed(menu_cache: AM<MenuCache>) -> Self {
+        let event: MealEventEnum = MealAvailabilityChangedDomainEvent::default().into();
+        Self {
+            menu_cache,
+            event_type: discriminant(&event),
+        }
+    }
+}
+
+#[async_trait]
+impl DomainEventListener<MealEventEnum> for InvalidateMenuCacheRule {
+    fn event_type(&self) -> Discriminant<MealEventEnum> {
+        self.event_type
+    }
+
+    async fn handle(&mut self, event: &MealEventEnum) {
+        let changed_at = match event {
+            MealEventEnum::MealAddedToMenuDomainEvent(e) => e.created,
+            MealEventEnum::MealRemovedFromMenuDomainEvent(e) => e.created,
+            MealEventEnum::MealAvailabilityChangedDomainEvent(e) => e.created,
+        };
+        self.menu_cache.lock().await.invalidate(changed_at);
+    }
+
+    fn get_events(&self) -> &Vec<MealEventEnum> {
+        todo!()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::types::base::AMTrait;
+    use domain::{menu::value_objects::allergens::Allergens, test_fixtures::*};
+    use time::Duration;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn cache_is_invalidated_after_meal_added() {
+        let meal = rnd_meal();
+        let cache = AM::new_am(MenuCache::default());
+        cache
+            .lock()
+            .await
+            .put(meal.id(), 10, &Allergens::default(), vec![meal.clone()]);
+        let mut rule = InvalidateMenuCacheRule::after_meal_added(cache.clone());
+
+        let mut event = MealAddedToMenuDomainEvent::new(*meal.id());
+        event.created += Duration::seconds(1);
+        let changed_at = event.created;
+        let event: MealEventEnum = event.into();
+        assert_eq!(rule.event_type(), discriminant(&event));
+        rule.handle(&event).await;
+
+        let cache = cache.lock().await;
+        assert_eq!(cache.get(meal.id(), 10, &Allergens::default()), None);
+        assert_eq!(cache.last_modified(), changed_at);
+    }
+
+    #[tokio::test]
+    async fn cache_is_invalidated_after_meal_removed() {
+        let meal = rnd_meal();
+        let cache = AM::new_am(MenuCache::default());
+        cache
+            .lock()
+            .await
+            .put(meal.id(), 10, &Allergens::default(), vec![meal.clone()]);
+        let mut rule = InvalidateMenuCacheRule::after_meal_removed(cache.clone());
+
+        let event: MealEventEnum = MealRemovedFromMenuDomainEvent::new(*meal.id()).into();
+        assert_eq!(rule.event_type(), discriminant(&event));
+        rule.handle(&event).await;
+
+        assert_eq!(
+            cache.lock().await.get(meal.id(), 10, &Allergens::default()),
+            None
+        );
+    }
+
+    #[tokio::test]
+    async fn cache_is_invalidated_after_meal_availability_changed() {
+        let meal = rnd_meal();
+        let cache = AM::new_am(MenuCache::default());
+        cache
+            .lock()
+            .await
+            .put(meal.id(), 10, &Allergens::default(), vec![meal.clone()]);
+        let mut rule = InvalidateMenuCacheRule::after_meal_availability_changed(cache.clone());
+
+        let event: MealEventEnum = MealAvailabilityChangedDomainEvent::new(*meal.id()).into();
+        assert_eq!(rule.event_type(), discriminant(&event));
+        rule.handle(&event).await;
+
+        assert_eq!(
+            cache.lock().await.get(meal.id(), 10, &Allergens::default()),
+            None
+        );
+    }
+}
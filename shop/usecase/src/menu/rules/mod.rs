@@ -0,0 +1,2 @@
+pub mod invalidate_menu_cache_rule;
+pub mod remove_meal_media_rule;
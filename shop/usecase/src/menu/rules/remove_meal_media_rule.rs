@@ -0,0 +1,75 @@
+use std::mem::{Discriminant, discriminant};
+
+use async_trait::async_trait;
+use common::{events::DomainEventListener, types::base::AM};
+use derive_new::new;
+use domain::menu::meal_events::{MealEventEnum, MealRemovedFromMenuDomainEvent};
+use tracing::error;
+
+use crate::menu::access::media_storage::MediaStorage;
+
+/// Images of a removed meal are never shown again, so they are deleted with the meal
+#[derive(Debug, new)]
+pub struct RemoveMealMediaRule {
+    media_storage: AM<dyn MediaStorage>,
+}
+
+#[async_trait]
+impl DomainEventListener<MealEventEnum> for RemoveMealMediaRule {
+    fn event_type(&self) -> Discriminant<MealEventEnum> {
+        let event: MealEventEnum = MealRemovedFromMenuDomainEvent::default().into();
+        discriminant(&event)
+    }
+
+    async fn handle(&mut self, event: &MealEventEnum) {
+        if let MealEventEnum::MealRemovedFromMenuDomainEvent(e) = event
+            && let Err(err) = self.media_storage.lock().await.remove_all(&e.meal_id).await
+        {
+            error!("Images of meal #{} are not removed: {err}", e.meal_id);
+        }
+    }
+
+    fn get_events(&self) -> &Vec<MealEventEnum> {
+        todo!()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::types::base::AMTrait;
+    use domain::test_fixtures::*;
+    use tracing_test::traced_test;
+
+    use super::*;
+    use crate::{menu::access::media_storage::MediaStorageError, test_fixtures::MockMediaStorage};
+
+    #[tokio::test]
+    async fn media_removed_after_meal_removed() {
+        let meal_id = rnd_meal_id();
+        let media_storage = AM::new_am(MockMediaStorage::new());
+        let mut rule = RemoveMealMediaRule::new(media_storage.clone());
+
+        let event: MealEventEnum = MealRemovedFromMenuDomainEvent::new(meal_id).into();
+        assert_eq!(rule.event_type(), discriminant(&event));
+        rule.handle(&event).await;
+
+        media_storage.lock().await.verify_removed(&meal_id);
+    }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn removal_failure_is_logged() {
+        let meal_id = rnd_meal_id();
+        let media_storage = AM::new_am(MockMediaStorage::new());
+        media_storage.lock().await.failure =
+            Some(MediaStorageError::StorageFailed("read-only".to_string()));
+        let mut rule = RemoveMealMediaRule::new(media_storage.clone());
+
+        rule.handle(&MealRemovedFromMenuDomainEvent::new(meal_id).into())
+            .await;
+
+        assert!(logs_contain(&format!(
+            "Images of meal #{meal_id} are not removed: Media storage failed: read-only"
+        )));
+    }
+}
@@ -7,9 +7,11 @@ use domain::menu::{
     meal::Meal,
     meal_already_exists::MealAlreadyExists,
     value_objects::{
+        allergens::Allergens,
         meal_description::MealDescription,
         meal_id::{MealId, MealIdGenerator},
         meal_name::MealName,
+        nutrition_facts::NutritionFacts,
         price::Price,
     },
 };
@@ -33,6 +35,8 @@ impl AddMealToMenu for AddMealToMenuUseCase {
         name: &MealName,
         description: &MealDescription,
         price: &Price,
+        allergens: &Allergens,
+        nutrition_facts: &NutritionFacts,
     ) -> Result<MealId, AddMealToMenuUseCaseError> {
         let new_meal_in_menu = Meal::add_meal_to_menu(
             self.id_generator.clone(),
@@ -40,6 +44,8 @@ impl AddMealToMenu for AddMealToMenuUseCase {
             name.clone(),
             description.clone(),
             price.clone(),
+            allergens.clone(),
+            nutrition_facts.clone(),
         )
         .await?;
         self.meal_persister
@@ -66,6 +72,8 @@ mod tests {
         let name = rnd_meal_name();
         let description = rnd_meal_description();
         let price = rnd_price();
+        let allergens = rnd_allergens();
+        let nutrition_facts = rnd_nutrition_facts();
         let id_generator = AM::new_am(TestMealIdGenerator::new());
         let meal_persister = AM::new_am(MockMealPersister::new());
 
@@ -75,7 +83,7 @@ mod tests {
             AM::new_am(TestMealAlreadyExists { value: false }),
         );
         let result = add_to_menu_use_case
-            .execute(&name, &description, &price)
+            .execute(&name, &description, &price, &allergens, &nutrition_facts)
             .await;
 
         let id = id_generator.lock().await.id;
@@ -88,6 +96,9 @@ mod tests {
             Some(&description),
             Some(&price),
         );
+        let saved_meal = meal_persister.lock().await.meal.clone().unwrap();
+        assert_eq!(saved_meal.allergens(), &allergens);
+        assert_eq!(saved_meal.nutrition_facts(), &nutrition_facts);
     }
 
     #[tokio::test]
@@ -105,7 +116,13 @@ mod tests {
             AM::new_am(TestMealAlreadyExists { value: true }),
         );
         let result = add_to_menu_use_case
-            .execute(&name, &description, &price)
+            .execute(
+                &name,
+                &description,
+                &price,
+                &rnd_allergens(),
+                &rnd_nutrition_facts(),
+            )
             .await;
 
         assert_eq!(result, Err(AddMealToMenuUseCaseError::AlreadyExists));
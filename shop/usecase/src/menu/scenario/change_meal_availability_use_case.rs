@@ -0,0 +1,102 @@
+use async_trait::async_trait;
+use common::types::base::AM;
+use derive_new::new;
+use domain::menu::value_objects::{availability_schedule::AvailabilitySchedule, meal_id::MealId};
+
+use crate::menu::{
+    access::{meal_extractor::MealExtractor, meal_persister::MealPersister},
+    change_meal_availability::{ChangeMealAvailability, ChangeMealAvailabilityUseCaseError},
+};
+
+#[derive(Debug, new)]
+pub struct ChangeMealAvailabilityUseCase {
+    pub meal_extractor: AM<dyn MealExtractor>,
+    pub meal_persister: AM<dyn MealPersister>,
+}
+
+#[async_trait]
+impl ChangeMealAvailability for ChangeMealAvailabilityUseCase {
+    async fn execute(
+        &mut self,
+        id: &MealId,
+        schedule: Option<AvailabilitySchedule>,
+    ) -> Result<(), ChangeMealAvailabilityUseCaseError> {
+        let mut meal = self
+            .meal_extractor
+            .lock()
+            .await
+            .get_by_id(id)
+            .filter(|meal| meal.visible())
+            .ok_or(ChangeMealAvailabilityUseCaseError::MealNotFound)?;
+        meal.change_availability_schedule(schedule);
+        self.meal_persister.lock().await.save(meal).await;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::types::base::AMTrait;
+    use domain::test_fixtures::*;
+
+    use super::*;
+    use crate::test_fixtures::{MockMealExtractor, MockMealPersister, removed_meal};
+
+    #[tokio::test]
+    async fn successfully_changed() {
+        let meal = rnd_meal();
+        let schedule = rnd_availability_schedule();
+        let meal_persister = AM::new_am(MockMealPersister::new());
+        let meal_extractor = AM::new_am(MockMealExtractor::new());
+        meal_extractor.lock().await.meal = Some(meal.clone());
+
+        let mut use_case =
+            ChangeMealAvailabilityUseCase::new(meal_extractor.clone(), meal_persister.clone());
+        let result = use_case.execute(meal.id(), Some(schedule.clone())).await;
+
+        assert!(result.is_ok());
+        let saved = meal_persister.lock().await.meal.clone().unwrap();
+        assert_eq!(saved.id(), meal.id());
+        assert_eq!(saved.availability_schedule(), &Some(schedule));
+        meal_extractor
+            .lock()
+            .await
+            .verify_invoked_get_by_id(meal.id());
+    }
+
+    #[tokio::test]
+    async fn meal_not_found() {
+        let meal_persister = AM::new_am(MockMealPersister::new());
+        let meal_extractor = AM::new_am(MockMealExtractor::new());
+        let mut use_case =
+            ChangeMealAvailabilityUseCase::new(meal_extractor.clone(), meal_persister.clone());
+
+        let result = use_case.execute(&rnd_meal_id(), None).await;
+
+        assert_eq!(
+            result,
+            Err(ChangeMealAvailabilityUseCaseError::MealNotFound)
+        );
+        meal_persister.lock().await.verify_empty();
+    }
+
+    #[tokio::test]
+    async fn removed_meal_not_found() {
+        let meal = removed_meal();
+        let meal_persister = AM::new_am(MockMealPersister::new());
+        let meal_extractor = AM::new_am(MockMealExtractor::new());
+        meal_extractor.lock().await.meal = Some(meal.clone());
+        let mut use_case =
+            ChangeMealAvailabilityUseCase::new(meal_extractor, meal_persister.clone());
+
+        let result = use_case
+            .execute(meal.id(), Some(rnd_availability_schedule()))
+            .await;
+
+        assert_eq!(
+            result,
+            Err(ChangeMealAvailabilityUseCaseError::MealNotFound)
+        );
+        meal_persister.lock().await.verify_empty();
+    }
+}
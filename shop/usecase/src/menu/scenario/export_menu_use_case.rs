@@ -0,0 +1,74 @@
+use async_trait::async_trait;
+use common::types::base::AM;
+use derive_new::new;
+use domain::menu::value_objects::{allergens::Allergens, meal_id::MealId};
+
+use crate::menu::{
+    access::meal_extractor::MealExtractor, dto::meal_row::MealRow, export_menu::ExportMenu,
+};
+
+/// The menu is read page by page to keep the queries small
+const PAGE_SIZE: usize = 100;
+
+#[derive(new, Debug)]
+pub struct ExportMenuUseCase {
+    pub meal_extractor: AM<dyn MealExtractor>,
+}
+
+#[async_trait]
+impl ExportMenu for ExportMenuUseCase {
+    async fn execute(&mut self) -> Vec<MealRow> {
+        let mut meal_extractor = self.meal_extractor.lock().await;
+        let mut rows = vec![];
+        let mut start_id = MealId::default();
+        loop {
+            let mut page = meal_extractor.get_all(&start_id, PAGE_SIZE + 1, &Allergens::default());
+            let next = (page.len() > PAGE_SIZE).then(|| *page[PAGE_SIZE].id());
+            page.truncate(PAGE_SIZE);
+            rows.extend(page.iter().map(MealRow::from));
+
+            match next {
+                Some(next) => start_id = next,
+                None => return rows,
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::types::base::AMTrait;
+    use domain::test_fixtures::*;
+
+    use super::*;
+    use crate::test_fixtures::MockMealExtractor;
+
+    #[tokio::test]
+    async fn menu_is_exported() {
+        let meal = rnd_meal();
+        let meal_extractor = AM::new_am(MockMealExtractor {
+            meal: Some(meal.clone()),
+            ..MockMealExtractor::new()
+        });
+
+        let rows = ExportMenuUseCase::new(meal_extractor.clone())
+            .execute()
+            .await;
+
+        assert_eq!(rows, vec![MealRow::from(&meal)]);
+        meal_extractor.lock().await.verify_invoked_get_all(
+            &MealId::default(),
+            PAGE_SIZE + 1,
+            &Allergens::default(),
+        );
+    }
+
+    #[tokio::test]
+    async fn empty_menu_is_exported() {
+        let meal_extractor = AM::new_am(MockMealExtractor::new());
+
+        let rows = ExportMenuUseCase::new(meal_extractor).execute().await;
+
+        assert!(rows.is_empty());
+    }
+}
@@ -4,7 +4,7 @@ use derive_new::new;
 use domain::menu::value_objects::meal_id::MealId;
 
 use crate::menu::{
-    access::meal_extractor::MealExtractor,
+    access::{meal_extractor::MealExtractor, media_storage::MediaStorage},
     dto::meal_info::MealInfo,
     get_meal_by_id::{GetMealById, GetMealByIdUseCaseError},
 };
@@ -12,6 +12,7 @@ use crate::menu::{
 #[derive(new, Debug)]
 pub struct GetMealByIdUseCase {
     pub meal_extractor: AM<dyn MealExtractor>,
+    pub media_storage: AM<dyn MediaStorage>,
 }
 
 #[async_trait]
@@ -20,7 +21,11 @@ impl GetMealById for GetMealByIdUseCase {
         match self.meal_extractor.lock().await.get_by_id(id) {
             res if res.is_some() && res.clone().unwrap().visible() => {
                 let res = res.unwrap();
-                Ok(MealInfo::from(res))
+                let image_urls = self.media_storage.lock().await.get_urls(res.id());
+                Ok(MealInfo {
+                    image_urls,
+                    ..MealInfo::from(res)
+                })
             }
             _ => Err(GetMealByIdUseCaseError::MealNotFound),
         }
@@ -34,12 +39,16 @@ mod tests {
     use tokio::test;
 
     use super::*;
-    use crate::test_fixtures::{MockMealExtractor, removed_meal};
+    use crate::{
+        menu::access::media_storage::ImageFormat,
+        test_fixtures::{MockMealExtractor, MockMediaStorage, removed_meal},
+    };
 
     #[test]
     async fn meal_not_found() {
         let meal_extractor = AM::new_am(MockMealExtractor::new());
-        let mut use_case = GetMealByIdUseCase::new(meal_extractor);
+        let mut use_case =
+            GetMealByIdUseCase::new(meal_extractor, AM::new_am(MockMediaStorage::new()));
 
         let meal_id = &rnd_meal_id();
         let result = use_case.execute(meal_id).await;
@@ -62,7 +71,8 @@ mod tests {
             ..MockMealExtractor::default()
         });
 
-        let mut use_case = GetMealByIdUseCase::new(meal_extractor);
+        let mut use_case =
+            GetMealByIdUseCase::new(meal_extractor, AM::new_am(MockMediaStorage::new()));
         let result = use_case.execute(meal.id()).await;
 
         assert_eq!(result, Err(GetMealByIdUseCaseError::MealNotFound));
@@ -82,7 +92,14 @@ mod tests {
             meal: Option::from(meal.to_owned()),
             ..MockMealExtractor::default()
         });
-        let mut use_case = GetMealByIdUseCase::new(meal_extractor);
+        let media_storage = AM::new_am(MockMediaStorage::new());
+        media_storage
+            .lock()
+            .await
+            .store(meal.id(), ImageFormat::Png, &[1])
+            .await
+            .unwrap();
+        let mut use_case = GetMealByIdUseCase::new(meal_extractor, media_storage);
 
         let result = use_case.execute(meal.id()).await;
         let meal_info = result;
@@ -94,7 +111,12 @@ mod tests {
                 name: meal.name().to_owned(),
                 description: meal.description().to_owned(),
                 price: meal.price().to_owned(),
+                allergens: meal.allergens().to_owned(),
+                nutrition_facts: meal.nutrition_facts().to_owned(),
+                availability_schedule: None,
                 version: *meal.version(),
+                available: true,
+                image_urls: vec![MockMediaStorage::url(meal.id(), 0)],
             }
         );
         use_case
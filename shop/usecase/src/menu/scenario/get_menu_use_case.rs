@@ -1,53 +1,161 @@
 use async_trait::async_trait;
 use common::types::base::AM;
 use derive_new::new;
+use domain::menu::{
+    meal::Meal,
+    value_objects::{allergens::Allergens, meal_id::MealId},
+};
 
-use crate::menu::{
-    access::meal_extractor::MealExtractor, dto::meal_info::MealInfo, get_menu::GetMenu,
+use crate::{
+    inventory::access::meal_stock_extractor::MealStockExtractor,
+    menu::{
+        access::{meal_extractor::MealExtractor, media_storage::MediaStorage},
+        dto::{meal_info::MealInfo, menu_page::MenuPage},
+        get_menu::{GetMenu, GetMenuUseCaseError, MenuAvailability},
+        menu_cache::MenuCache,
+    },
 };
 
 #[derive(Debug, new)]
 pub struct GetMenuUseCase {
     pub(crate) meal_extractor: AM<dyn MealExtractor>,
+    pub(crate) menu_cache: AM<MenuCache>,
+    pub(crate) meal_stock_extractor: AM<dyn MealStockExtractor>,
+    pub(crate) media_storage: AM<dyn MediaStorage>,
+    limit: fn() -> usize,
 }
 
 #[async_trait]
 impl GetMenu for GetMenuUseCase {
-    async fn execute(&self) -> Vec<MealInfo> {
-        self.meal_extractor
+    async fn execute(
+        &mut self,
+        start_id: &MealId,
+        limit: usize,
+        excluded_allergens: &Allergens,
+        availability: &MenuAvailability,
+    ) -> Result<MenuPage, GetMenuUseCaseError> {
+        let max_size = (self.limit)();
+        if max_size < limit {
+            return Err(GetMenuUseCaseError::LimitExceed(max_size));
+        }
+
+        // Cached pages hold all meals, schedules are checked on every request. Unavailable
+        // meals leave gaps, so the following pages are loaded until the page is full
+        let moment = availability.moment();
+        let mut menu_cache = self.menu_cache.lock().await;
+        let mut meals = vec![];
+        let mut page_start = *start_id;
+        loop {
+            let page = self
+                .load_page(&mut menu_cache, &page_start, limit, excluded_allergens)
+                .await;
+            let exhausted = page.len() < limit;
+            let next_start = page
+                .last()
+                .and_then(|meal| meal.id().to_i64().checked_add(1))
+                .and_then(|id| MealId::try_from(id).ok());
+            meals.extend(
+                page.into_iter()
+                    .filter(|meal| moment.is_none_or(|at| meal.is_available_at(&at))),
+            );
+            match next_start {
+                Some(next_start) if !exhausted && meals.len() < limit => page_start = next_start,
+                _ => break,
+            }
+        }
+        meals.truncate(limit);
+
+        // Stocks change on every order, so availability is not cached
+        let meal_ids: Vec<MealId> = meals.iter().map(|meal| *meal.id()).collect();
+        let sold_out: Vec<MealId> = self
+            .meal_stock_extractor
             .lock()
             .await
-            .get_all()
-            .into_iter()
-            .map(MealInfo::from)
-            .collect()
+            .get_by_meal_ids(&meal_ids)
+            .iter()
+            .filter(|stock| stock.is_sold_out())
+            .map(|stock| *stock.meal_id())
+            .collect();
+
+        let mut media_storage = self.media_storage.lock().await;
+        Ok(MenuPage {
+            meals: meals
+                .into_iter()
+                .map(|meal| MealInfo {
+                    available: !sold_out.contains(meal.id()),
+                    image_urls: media_storage.get_urls(meal.id()),
+                    ..MealInfo::from(meal)
+                })
+                .collect(),
+            last_modified: menu_cache.last_modified(),
+        })
+    }
+}
+
+impl GetMenuUseCase {
+    async fn load_page(
+        &self,
+        menu_cache: &mut MenuCache,
+        start_id: &MealId,
+        limit: usize,
+        excluded_allergens: &Allergens,
+    ) -> Vec<Meal> {
+        match menu_cache.get(start_id, limit, excluded_allergens) {
+            Some(meals) => meals,
+            None => {
+                let meals =
+                    self.meal_extractor
+                        .lock()
+                        .await
+                        .get_all(start_id, limit, excluded_allergens);
+                menu_cache.put(start_id, limit, excluded_allergens, meals.clone());
+                meals
+            }
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use common::types::base::AMTrait;
-    use domain::test_fixtures::*;
+    use common::types::{base::AMTrait, common::Count};
+    use domain::{
+        inventory::meal_stock::MealStock, menu::value_objects::allergens::Allergen,
+        test_fixtures::*,
+    };
+    use time::{Duration, OffsetDateTime};
     use tokio::test;
 
     use super::*;
-    use crate::test_fixtures::MockMealExtractor;
+    use crate::test_fixtures::{MockMealExtractor, MockMealStockExtractor, MockMediaStorage};
+
+    fn use_case(meal_extractor: MockMealExtractor) -> GetMenuUseCase {
+        GetMenuUseCase::new(
+            AM::new_am(meal_extractor),
+            AM::new_am(MenuCache::default()),
+            AM::new_am(MockMealStockExtractor::default()),
+            AM::new_am(MockMediaStorage::new()),
+            || 10,
+        )
+    }
 
     #[test]
     #[allow(non_snake_case)]
     async fn get_menu__menu_is_empty() {
-        let meal_extractor = MockMealExtractor::new();
-        let use_case = GetMenuUseCase::new(AM::new_am(meal_extractor));
-        let menu = use_case.execute().await;
+        let start_id = rnd_meal_id();
+        let mut use_case = use_case(MockMealExtractor::new());
+        let menu = use_case
+            .execute(&start_id, 10, &Allergens::default(), &MenuAvailability::Now)
+            .await
+            .unwrap();
 
-        assert!(menu.is_empty());
+        assert!(menu.meals.is_empty());
         use_case
             .meal_extractor
             .lock()
             .await
             .downcast_ref::<MockMealExtractor>()
             .unwrap()
-            .verify_invoked_get_all();
+            .verify_invoked_get_all(&start_id, 10, &Allergens::default());
     }
 
     #[test]
@@ -57,25 +165,235 @@ mod tests {
             meal: Option::from(meal.to_owned()),
             ..MockMealExtractor::default()
         };
-        let use_case = GetMenuUseCase::new(AM::new_am(meal_extractor));
-        let menu = use_case.execute().await;
+        let mut use_case = use_case(meal_extractor);
+        let menu = use_case
+            .execute(meal.id(), 10, &Allergens::default(), &MenuAvailability::Now)
+            .await
+            .unwrap();
 
         assert_eq!(
-            menu,
+            menu.meals,
             vec![MealInfo {
                 id: *meal.id(),
                 name: meal.name().to_owned(),
                 description: meal.description().to_owned(),
                 price: meal.price().to_owned(),
+                allergens: meal.allergens().to_owned(),
+                nutrition_facts: meal.nutrition_facts().to_owned(),
+                availability_schedule: None,
                 version: *meal.version(),
+                available: true,
+                image_urls: vec![],
             }]
         );
+        assert_eq!(
+            menu.last_modified,
+            use_case.menu_cache.lock().await.last_modified()
+        );
+        use_case
+            .meal_extractor
+            .lock()
+            .await
+            .downcast_ref::<MockMealExtractor>()
+            .unwrap()
+            .verify_invoked_get_all(meal.id(), 10, &Allergens::default());
+    }
+
+    #[test]
+    async fn meals_with_excluded_allergens_are_filtered_out() {
+        let meal = rnd_meal();
+        let meal_extractor = MockMealExtractor {
+            meal: Option::from(meal.to_owned()),
+            ..MockMealExtractor::default()
+        };
+        let mut use_case = use_case(meal_extractor);
+        let excluded: Allergens = Allergen::ALL.into_iter().collect();
+
+        let menu = use_case
+            .execute(meal.id(), 10, &excluded, &MenuAvailability::Now)
+            .await
+            .unwrap();
+
+        assert_eq!(menu.meals.is_empty(), !meal.allergens().is_empty());
+        use_case
+            .meal_extractor
+            .lock()
+            .await
+            .downcast_ref::<MockMealExtractor>()
+            .unwrap()
+            .verify_invoked_get_all(meal.id(), 10, &excluded);
+    }
+
+    #[test]
+    async fn page_is_served_from_cache() {
+        let meal = rnd_meal();
+        let mut use_case = use_case(MockMealExtractor::new());
+        use_case.menu_cache.lock().await.put(
+            meal.id(),
+            10,
+            &Allergens::default(),
+            vec![meal.clone()],
+        );
+
+        let menu = use_case
+            .execute(meal.id(), 10, &Allergens::default(), &MenuAvailability::Now)
+            .await
+            .unwrap();
+
+        assert_eq!(menu.meals, vec![MealInfo::from(meal)]);
+        use_case
+            .meal_extractor
+            .lock()
+            .await
+            .downcast_ref::<MockMealExtractor>()
+            .unwrap()
+            .verify_empty();
+    }
+
+    #[test]
+    async fn loaded_page_is_put_to_cache() {
+        let meal = rnd_meal();
+        let meal_extractor = MockMealExtractor {
+            meal: Option::from(meal.to_owned()),
+            ..MockMealExtractor::default()
+        };
+        let mut use_case = use_case(meal_extractor);
+
+        use_case
+            .execute(meal.id(), 10, &Allergens::default(), &MenuAvailability::Now)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            use_case
+                .menu_cache
+                .lock()
+                .await
+                .get(meal.id(), 10, &Allergens::default()),
+            Some(vec![meal])
+        );
+    }
+
+    #[test]
+    async fn sold_out_meal_is_unavailable() {
+        let meal = rnd_meal();
+        let mut use_case = use_case(MockMealExtractor::new());
+        use_case.menu_cache.lock().await.put(
+            meal.id(),
+            10,
+            &Allergens::default(),
+            vec![meal.clone()],
+        );
+        use_case.meal_stock_extractor =
+            AM::new_am(MockMealStockExtractor::new(vec![MealStock::create(
+                *meal.id(),
+                Count::try_from(0).unwrap(),
+            )]));
+
+        let menu = use_case
+            .execute(meal.id(), 10, &Allergens::default(), &MenuAvailability::Now)
+            .await
+            .unwrap();
+
+        assert!(!menu.meals[0].available);
+    }
+
+    #[test]
+    async fn meals_outside_availability_window_are_filtered_out() {
+        let mut meal = rnd_meal();
+        let now = OffsetDateTime::now_utc();
+        meal.change_availability_schedule(Some(availability_schedule_closed_at(&now)));
+        let meal_extractor = MockMealExtractor {
+            meal: Option::from(meal.to_owned()),
+            ..MockMealExtractor::default()
+        };
+        let mut use_case = use_case(meal_extractor);
+
+        let menu = use_case
+            .execute(meal.id(), 10, &Allergens::default(), &MenuAvailability::Now)
+            .await
+            .unwrap();
+        assert!(menu.meals.is_empty());
+
+        let menu = use_case
+            .execute(meal.id(), 10, &Allergens::default(), &MenuAvailability::Any)
+            .await
+            .unwrap();
+        assert_eq!(menu.meals.len(), 1);
+
+        let tomorrow = now + Duration::days(1);
+        let menu = use_case
+            .execute(
+                meal.id(),
+                10,
+                &Allergens::default(),
+                &MenuAvailability::At(tomorrow),
+            )
+            .await
+            .unwrap();
+        assert_eq!(
+            menu.meals.len(),
+            usize::from(meal.is_available_at(&tomorrow))
+        );
+    }
+
+    #[test]
+    async fn following_pages_fill_gaps_of_unavailable_meals() {
+        let mut unavailable = rnd_meal();
+        unavailable.change_availability_schedule(Some(availability_schedule_closed_at(
+            &OffsetDateTime::now_utc(),
+        )));
+        let available = rnd_meal();
+        let next_start = MealId::try_from(unavailable.id().to_i64() + 1).unwrap();
+        let mut use_case = use_case(MockMealExtractor::new());
+        {
+            let mut menu_cache = use_case.menu_cache.lock().await;
+            menu_cache.put(
+                unavailable.id(),
+                1,
+                &Allergens::default(),
+                vec![unavailable.clone()],
+            );
+            menu_cache.put(
+                &next_start,
+                1,
+                &Allergens::default(),
+                vec![available.clone()],
+            );
+        }
+
+        let menu = use_case
+            .execute(
+                unavailable.id(),
+                1,
+                &Allergens::default(),
+                &MenuAvailability::Now,
+            )
+            .await
+            .unwrap();
+
+        assert_eq!(menu.meals, vec![MealInfo::from(available)]);
+    }
+
+    #[test]
+    async fn limit_exceed() {
+        let mut use_case = use_case(MockMealExtractor::new());
+        let result = use_case
+            .execute(
+                &rnd_meal_id(),
+                11,
+                &Allergens::default(),
+                &MenuAvailability::Now,
+            )
+            .await;
+
+        assert_eq!(result.unwrap_err(), GetMenuUseCaseError::LimitExceed(10));
         use_case
             .meal_extractor
             .lock()
             .await
             .downcast_ref::<MockMealExtractor>()
             .unwrap()
-            .verify_invoked_get_all();
+            .verify_empty();
     }
 }
@@ -0,0 +1,342 @@
+use std::collections::HashSet;
+
+use async_trait::async_trait;
+use common::types::base::AM;
+use derive_new::new;
+use domain::menu::{
+    meal::Meal,
+    meal_already_exists::MealAlreadyExists,
+    value_objects::{
+        allergens::{Allergens, CreateAllergensError},
+        meal_description::MealDescription,
+        meal_id::MealIdGenerator,
+        meal_name::MealName,
+        nutrition_facts::{CreateNutritionFactsError, NutritionFacts},
+        price::{CreatePriceError, Price},
+    },
+};
+
+use crate::menu::{
+    access::meal_persister::MealPersister,
+    dto::{
+        meal_row::MealRow,
+        menu_import_report::{MenuImportReport, RejectedMealRow},
+    },
+    import_menu::{ImportMenu, ImportMenuUseCaseError, MAX_MENU_IMPORT_ROWS, MealRowError},
+};
+
+/// Adds meals the same way as `AddMealToMenuUseCase`, but validates all rows first
+/// so a broken import leaves the menu untouched
+#[derive(new, Debug)]
+pub struct ImportMenuUseCase {
+    pub meal_persister: AM<dyn MealPersister>,
+    pub id_generator: AM<dyn MealIdGenerator>,
+    pub meal_exists: AM<dyn MealAlreadyExists>,
+}
+
+struct ValidMealRow {
+    name: MealName,
+    description: MealDescription,
+    price: Price,
+    allergens: Allergens,
+    nutrition_facts: NutritionFacts,
+}
+
+#[async_trait]
+impl ImportMenu for ImportMenuUseCase {
+    async fn execute(
+        &mut self,
+        rows: &[MealRow],
+        dry_run: bool,
+    ) -> Result<MenuImportReport, ImportMenuUseCaseError> {
+        if rows.is_empty() {
+            return Err(ImportMenuUseCaseError::Empty);
+        }
+        if rows.len() > MAX_MENU_IMPORT_ROWS {
+            return Err(ImportMenuUseCaseError::TooManyRows(MAX_MENU_IMPORT_ROWS));
+        }
+
+        let mut names = HashSet::new();
+        let mut valid_rows = vec![];
+        let mut rejected = vec![];
+        for (index, row) in rows.iter().enumerate() {
+            match self.validate(row, &mut names).await {
+                Ok(valid_row) => valid_rows.push((index + 1, valid_row)),
+                Err(errors) => rejected.push(RejectedMealRow {
+                    row: index + 1,
+                    errors,
+                }),
+            }
+        }
+
+        let mut report = MenuImportReport {
+            dry_run,
+            added: vec![],
+            rejected,
+        };
+        if dry_run || !report.rejected.is_empty() {
+            return Ok(report);
+        }
+
+        for (row, valid_row) in valid_rows {
+            // A meal with the same name may be added while the import is running
+            match Meal::add_meal_to_menu(
+                self.id_generator.clone(),
+                self.meal_exists.clone(),
+                valid_row.name.clone(),
+                valid_row.description,
+                valid_row.price,
+                valid_row.allergens,
+                valid_row.nutrition_facts,
+            )
+            .await
+            {
+                Ok(meal) => {
+                    report.added.push(*meal.id());
+                    self.meal_persister.lock().await.save(meal).await;
+                }
+                Err(_) => report.rejected.push(RejectedMealRow {
+                    row,
+                    errors: vec![MealRowError::AlreadyExists(valid_row.name.to_string())],
+                }),
+            }
+        }
+        Ok(report)
+    }
+}
+
+impl ImportMenuUseCase {
+    async fn validate(
+        &mut self,
+        row: &MealRow,
+        names: &mut HashSet<String>,
+    ) -> Result<ValidMealRow, Vec<MealRowError>> {
+        let mut errors = vec![];
+
+        let name = MealName::try_from(row.name.as_str())
+            .map_err(|_| errors.push(MealRowError::EmptyName))
+            .ok();
+        let description = MealDescription::try_from(row.description.as_str())
+            .map_err(|_| errors.push(MealRowError::EmptyDescription))
+            .ok();
+        let price = Price::try_from(row.price.clone())
+            .map_err(|e| {
+                errors.push(match e {
+                    CreatePriceError::InvalidScale => MealRowError::InvalidPriceScale(Price::SCALE),
+                    CreatePriceError::NegativeValue => MealRowError::NegativePrice,
+                })
+            })
+            .ok();
+        let allergens = Allergens::try_from(row.allergens.as_slice())
+            .map_err(|CreateAllergensError::UnknownAllergen(code)| {
+                errors.push(MealRowError::UnknownAllergen(code))
+            })
+            .ok();
+        let nutrition_facts = NutritionFacts::try_new(
+            row.calories,
+            row.proteins.clone(),
+            row.fats.clone(),
+            row.carbohydrates.clone(),
+        )
+        .map_err(|e| {
+            errors.push(match e {
+                CreateNutritionFactsError::InvalidScale => {
+                    MealRowError::InvalidNutritionFactsScale(NutritionFacts::SCALE)
+                }
+                CreateNutritionFactsError::NegativeValue => MealRowError::NegativeNutritionFacts,
+            })
+        })
+        .ok();
+
+        if let Some(name) = &name {
+            if !names.insert(name.to_string()) {
+                errors.push(MealRowError::DuplicateName(name.to_string()));
+            } else if self.meal_exists.lock().await.invoke(name).await {
+                errors.push(MealRowError::AlreadyExists(name.to_string()));
+            }
+        }
+
+        match (name, description, price, allergens, nutrition_facts) {
+            (
+                Some(name),
+                Some(description),
+                Some(price),
+                Some(allergens),
+                Some(nutrition_facts),
+            ) if errors.is_empty() => Ok(ValidMealRow {
+                name,
+                description,
+                price,
+                allergens,
+                nutrition_facts,
+            }),
+            _ => Err(errors),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bigdecimal::BigDecimal;
+    use common::types::base::AMTrait;
+    use domain::{menu::value_objects::meal_id::MealId, test_fixtures::*};
+
+    use super::*;
+    use crate::test_fixtures::MockMealPersister;
+
+    fn use_case(meal_persister: AM<MockMealPersister>, meal_exists: bool) -> ImportMenuUseCase {
+        ImportMenuUseCase::new(
+            meal_persister,
+            AM::new_am(SequentialMealIdGenerator::default()),
+            AM::new_am(TestMealAlreadyExists { value: meal_exists }),
+        )
+    }
+
+    fn rnd_meal_row() -> MealRow {
+        MealRow::from(&rnd_meal())
+    }
+
+    #[tokio::test]
+    async fn rows_are_added() {
+        let rows = vec![rnd_meal_row(), rnd_meal_row()];
+        let meal_persister = AM::new_am(MockMealPersister::new());
+
+        let report = use_case(meal_persister.clone(), false)
+            .execute(&rows, false)
+            .await
+            .unwrap();
+
+        assert!(!report.dry_run);
+        assert!(report.rejected.is_empty());
+        assert_eq!(report.added.len(), 2);
+        let saved = meal_persister.lock().await.saved.clone();
+        let saved_rows: Vec<MealRow> = saved.iter().map(MealRow::from).collect();
+        assert_eq!(saved_rows, rows);
+        let saved_ids: Vec<MealId> = saved.iter().map(|meal| *meal.id()).collect();
+        assert_eq!(saved_ids, report.added);
+    }
+
+    #[tokio::test]
+    async fn dry_run_adds_nothing() {
+        let meal_persister = AM::new_am(MockMealPersister::new());
+
+        let report = use_case(meal_persister.clone(), false)
+            .execute(&[rnd_meal_row()], true)
+            .await
+            .unwrap();
+
+        assert!(report.dry_run);
+        assert!(report.added.is_empty());
+        assert!(report.rejected.is_empty());
+        meal_persister.lock().await.verify_empty();
+    }
+
+    #[tokio::test]
+    async fn invalid_row_is_reported_and_nothing_is_added() {
+        let invalid_row = MealRow {
+            name: "".to_string(),
+            price: BigDecimal::new(1.into(), 3),
+            allergens: vec!["honey".to_string()],
+            calories: -1,
+            ..rnd_meal_row()
+        };
+        let meal_persister = AM::new_am(MockMealPersister::new());
+
+        let report = use_case(meal_persister.clone(), false)
+            .execute(&[rnd_meal_row(), invalid_row], false)
+            .await
+            .unwrap();
+
+        assert!(report.added.is_empty());
+        assert_eq!(
+            report.rejected,
+            vec![RejectedMealRow {
+                row: 2,
+                errors: vec![
+                    MealRowError::EmptyName,
+                    MealRowError::InvalidPriceScale(Price::SCALE),
+                    MealRowError::UnknownAllergen("honey".to_string()),
+                    MealRowError::NegativeNutritionFacts,
+                ],
+            }]
+        );
+        meal_persister.lock().await.verify_empty();
+    }
+
+    #[tokio::test]
+    async fn duplicate_name_is_reported() {
+        let row = rnd_meal_row();
+        let duplicate = MealRow {
+            description: "Another description".to_string(),
+            ..row.clone()
+        };
+
+        let report = use_case(AM::new_am(MockMealPersister::new()), false)
+            .execute(&[row.clone(), duplicate], true)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            report.rejected,
+            vec![RejectedMealRow {
+                row: 2,
+                errors: vec![MealRowError::DuplicateName(row.name)],
+            }]
+        );
+    }
+
+    #[tokio::test]
+    async fn existing_meal_is_reported() {
+        let row = rnd_meal_row();
+        let meal_persister = AM::new_am(MockMealPersister::new());
+
+        let report = use_case(meal_persister.clone(), true)
+            .execute(std::slice::from_ref(&row), false)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            report.rejected,
+            vec![RejectedMealRow {
+                row: 1,
+                errors: vec![MealRowError::AlreadyExists(row.name)],
+            }]
+        );
+        meal_persister.lock().await.verify_empty();
+    }
+
+    #[tokio::test]
+    async fn empty_import() {
+        let result = use_case(AM::new_am(MockMealPersister::new()), false)
+            .execute(&[], false)
+            .await;
+
+        assert_eq!(result, Err(ImportMenuUseCaseError::Empty));
+    }
+
+    #[tokio::test]
+    async fn too_many_rows() {
+        let rows = vec![rnd_meal_row(); MAX_MENU_IMPORT_ROWS + 1];
+
+        let result = use_case(AM::new_am(MockMealPersister::new()), false)
+            .execute(&rows, true)
+            .await;
+
+        assert_eq!(
+            result,
+            Err(ImportMenuUseCaseError::TooManyRows(MAX_MENU_IMPORT_ROWS))
+        );
+    }
+
+    #[derive(Default, Debug)]
+    struct SequentialMealIdGenerator {
+        last: i64,
+    }
+
+    impl MealIdGenerator for SequentialMealIdGenerator {
+        fn generate(&mut self) -> MealId {
+            self.last += 1;
+            MealId::try_from(self.last).unwrap()
+        }
+    }
+}
@@ -1,9 +1,19 @@
 mod add_meal_to_menu_use_case;
+mod change_meal_availability_use_case;
+mod export_menu_use_case;
 mod get_meal_by_id_use_case;
 mod get_menu_use_case;
+mod import_menu_use_case;
 mod remove_meal_from_menu_use_case;
+mod search_menu_use_case;
+mod upload_meal_image_use_case;
 
 pub use add_meal_to_menu_use_case::*;
+pub use change_meal_availability_use_case::*;
+pub use export_menu_use_case::*;
 pub use get_meal_by_id_use_case::*;
 pub use get_menu_use_case::*;
+pub use import_menu_use_case::*;
 pub use remove_meal_from_menu_use_case::*;
+pub use search_menu_use_case::*;
+pub use upload_meal_image_use_case::*;
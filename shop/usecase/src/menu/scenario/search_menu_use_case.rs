@@ -0,0 +1,82 @@
+use async_trait::async_trait;
+use common::types::base::AM;
+use derive_new::new;
+
+use crate::menu::{
+    access::{
+        meal_searcher::{MealSearcher, search_words},
+        media_storage::MediaStorage,
+    },
+    dto::meal_info::MealInfo,
+    search_menu::{SearchMenu, SearchMenuUseCaseError},
+};
+
+#[derive(Debug, new)]
+pub struct SearchMenuUseCase {
+    pub(crate) meal_searcher: AM<dyn MealSearcher>,
+    pub(crate) media_storage: AM<dyn MediaStorage>,
+}
+
+impl SearchMenuUseCase {
+    /// Maximal number of meals in the search result
+    pub const MAX_RESULTS: usize = 50;
+}
+
+#[async_trait]
+impl SearchMenu for SearchMenuUseCase {
+    async fn execute(&mut self, text: &str) -> Result<Vec<MealInfo>, SearchMenuUseCaseError> {
+        if search_words(text).is_empty() {
+            return Err(SearchMenuUseCaseError::EmptySearchText);
+        }
+        let mut media_storage = self.media_storage.lock().await;
+        Ok(self
+            .meal_searcher
+            .lock()
+            .await
+            .search(text, Self::MAX_RESULTS)
+            .into_iter()
+            .map(|meal| MealInfo {
+                image_urls: media_storage.get_urls(meal.id()),
+                ..MealInfo::from(meal)
+            })
+            .collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::types::base::AMTrait;
+    use domain::test_fixtures::*;
+    use tokio::test;
+
+    use super::*;
+    use crate::test_fixtures::{MockMealSearcher, MockMediaStorage};
+
+    #[test]
+    async fn meals_found() {
+        let meal = rnd_meal();
+        let meal_searcher = AM::new_am(MockMealSearcher::new(vec![meal.clone()]));
+        let mut use_case =
+            SearchMenuUseCase::new(meal_searcher.clone(), AM::new_am(MockMediaStorage::new()));
+
+        let result = use_case.execute("pizza").await;
+
+        assert_eq!(result, Ok(vec![MealInfo::from(meal)]));
+        meal_searcher
+            .lock()
+            .await
+            .verify_invoked("pizza", SearchMenuUseCase::MAX_RESULTS);
+    }
+
+    #[test]
+    async fn search_text_has_no_words() {
+        let meal_searcher = AM::new_am(MockMealSearcher::new(vec![rnd_meal()]));
+        let mut use_case =
+            SearchMenuUseCase::new(meal_searcher.clone(), AM::new_am(MockMediaStorage::new()));
+
+        let result = use_case.execute(" -&- ").await;
+
+        assert_eq!(result, Err(SearchMenuUseCaseError::EmptySearchText));
+        meal_searcher.lock().await.verify_empty();
+    }
+}
@@ -0,0 +1,211 @@
+use actix_web::http::Uri;
+use async_trait::async_trait;
+use common::types::base::AM;
+use derive_new::new;
+use domain::menu::value_objects::meal_id::MealId;
+
+use crate::menu::{
+    access::{
+        meal_extractor::MealExtractor,
+        media_storage::{ImageFormat, MediaStorage},
+    },
+    upload_meal_image::{MAX_MEAL_IMAGE_SIZE, UploadMealImage, UploadMealImageUseCaseError},
+};
+
+#[derive(Debug, new)]
+pub struct UploadMealImageUseCase {
+    meal_extractor: AM<dyn MealExtractor>,
+    media_storage: AM<dyn MediaStorage>,
+}
+
+#[async_trait]
+impl UploadMealImage for UploadMealImageUseCase {
+    async fn execute(
+        &mut self,
+        meal_id: &MealId,
+        content_type: &str,
+        content: Vec<u8>,
+    ) -> Result<Uri, UploadMealImageUseCaseError> {
+        let format = ImageFormat::from_content_type(content_type).ok_or_else(|| {
+            UploadMealImageUseCaseError::UnsupportedContentType(content_type.to_string())
+        })?;
+        if content.len() > MAX_MEAL_IMAGE_SIZE {
+            return Err(UploadMealImageUseCaseError::TooLarge(MAX_MEAL_IMAGE_SIZE));
+        }
+        // The stored file is served with the extension of the format, whatever the client declared
+        if ImageFormat::from_magic_bytes(&content) != Some(format) {
+            return Err(UploadMealImageUseCaseError::ContentMismatch(
+                content_type.to_string(),
+            ));
+        }
+        self.meal_extractor
+            .lock()
+            .await
+            .get_by_id(meal_id)
+            .filter(|meal| meal.visible())
+            .ok_or(UploadMealImageUseCaseError::MealNotFound)?;
+
+        Ok(self
+            .media_storage
+            .lock()
+            .await
+            .store(meal_id, format, &content)
+            .await?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::types::base::AMTrait;
+    use domain::test_fixtures::*;
+
+    use super::*;
+    use crate::{
+        menu::access::media_storage::MediaStorageError,
+        test_fixtures::{
+            JPEG_CONTENT, MockMealExtractor, MockMediaStorage, PNG_CONTENT, removed_meal,
+        },
+    };
+
+    async fn use_case_for(
+        meal: Option<domain::menu::meal::Meal>,
+    ) -> (UploadMealImageUseCase, AM<MockMediaStorage>) {
+        let meal_extractor = AM::new_am(MockMealExtractor::new());
+        meal_extractor.lock().await.meal = meal;
+        let media_storage = AM::new_am(MockMediaStorage::new());
+        (
+            UploadMealImageUseCase::new(meal_extractor, media_storage.clone()),
+            media_storage,
+        )
+    }
+
+    #[tokio::test]
+    async fn image_stored() {
+        let meal = rnd_meal();
+        let (mut use_case, media_storage) = use_case_for(Some(meal.clone())).await;
+
+        let result = use_case
+            .execute(meal.id(), "image/png", PNG_CONTENT.to_vec())
+            .await;
+
+        assert_eq!(result, Ok(MockMediaStorage::url(meal.id(), 0)));
+        media_storage
+            .lock()
+            .await
+            .verify_stored(meal.id(), ImageFormat::Png, PNG_CONTENT);
+    }
+
+    #[tokio::test]
+    async fn meal_not_found() {
+        let (mut use_case, media_storage) = use_case_for(None).await;
+
+        let result = use_case
+            .execute(&rnd_meal_id(), "image/jpeg", JPEG_CONTENT.to_vec())
+            .await;
+
+        assert_eq!(result, Err(UploadMealImageUseCaseError::MealNotFound));
+        media_storage.lock().await.verify_empty();
+    }
+
+    #[tokio::test]
+    async fn removed_meal_not_found() {
+        let meal = removed_meal();
+        let (mut use_case, media_storage) = use_case_for(Some(meal.clone())).await;
+
+        let result = use_case
+            .execute(meal.id(), "image/jpeg", JPEG_CONTENT.to_vec())
+            .await;
+
+        assert_eq!(result, Err(UploadMealImageUseCaseError::MealNotFound));
+        media_storage.lock().await.verify_empty();
+    }
+
+    #[tokio::test]
+    async fn unsupported_content_type() {
+        let meal = rnd_meal();
+        let (mut use_case, media_storage) = use_case_for(Some(meal.clone())).await;
+
+        let result = use_case.execute(meal.id(), "text/plain", vec![1]).await;
+
+        assert_eq!(
+            result,
+            Err(UploadMealImageUseCaseError::UnsupportedContentType(
+                "text/plain".to_string()
+            ))
+        );
+        media_storage.lock().await.verify_empty();
+    }
+
+    #[tokio::test]
+    async fn image_too_large() {
+        let meal = rnd_meal();
+        let (mut use_case, media_storage) = use_case_for(Some(meal.clone())).await;
+
+        let result = use_case
+            .execute(meal.id(), "image/webp", vec![0; MAX_MEAL_IMAGE_SIZE + 1])
+            .await;
+
+        assert_eq!(
+            result,
+            Err(UploadMealImageUseCaseError::TooLarge(MAX_MEAL_IMAGE_SIZE))
+        );
+        media_storage.lock().await.verify_empty();
+    }
+
+    #[tokio::test]
+    async fn storage_failed() {
+        let meal = rnd_meal();
+        let (mut use_case, media_storage) = use_case_for(Some(meal.clone())).await;
+        let error = MediaStorageError::StorageFailed("disk full".to_string());
+        media_storage.lock().await.failure = Some(error.clone());
+
+        let result = use_case
+            .execute(meal.id(), "image/png", PNG_CONTENT.to_vec())
+            .await;
+
+        assert_eq!(
+            result,
+            Err(UploadMealImageUseCaseError::StorageFailed(error))
+        );
+    }
+
+    #[tokio::test]
+    async fn content_not_matching_content_type() {
+        let meal = rnd_meal();
+        let (mut use_case, media_storage) = use_case_for(Some(meal.clone())).await;
+
+        let result = use_case
+            .execute(meal.id(), "image/png", JPEG_CONTENT.to_vec())
+            .await;
+
+        assert_eq!(
+            result,
+            Err(UploadMealImageUseCaseError::ContentMismatch(
+                "image/png".to_string()
+            ))
+        );
+        media_storage.lock().await.verify_empty();
+    }
+
+    #[tokio::test]
+    async fn content_is_not_an_image() {
+        let meal = rnd_meal();
+        let (mut use_case, media_storage) = use_case_for(Some(meal.clone())).await;
+
+        let result = use_case
+            .execute(
+                meal.id(),
+                "image/webp",
+                b"<script>alert(1)</script>".to_vec(),
+            )
+            .await;
+
+        assert_eq!(
+            result,
+            Err(UploadMealImageUseCaseError::ContentMismatch(
+                "image/webp".to_string()
+            ))
+        );
+        media_storage.lock().await.verify_empty();
+    }
+}
@@ -0,0 +1,15 @@
+use async_trait::async_trait;
+use thiserror::Error;
+
+use crate::menu::dto::meal_info::MealInfo;
+
+#[async_trait]
+pub trait SearchMenu {
+    async fn execute(&mut self, text: &str) -> Result<Vec<MealInfo>, SearchMenuUseCaseError>;
+}
+
+#[derive(Error, Debug, PartialEq, Clone, Copy)]
+pub enum SearchMenuUseCaseError {
+    #[error("Search text has no words")]
+    EmptySearchText,
+}
@@ -0,0 +1,33 @@
+use actix_web::http::Uri;
+use async_trait::async_trait;
+use domain::menu::value_objects::meal_id::MealId;
+use thiserror::Error;
+
+use crate::menu::access::media_storage::MediaStorageError;
+
+/// Maximal size of a meal image in bytes
+pub const MAX_MEAL_IMAGE_SIZE: usize = 5 * 1024 * 1024;
+
+#[async_trait]
+pub trait UploadMealImage {
+    async fn execute(
+        &mut self,
+        meal_id: &MealId,
+        content_type: &str,
+        content: Vec<u8>,
+    ) -> Result<Uri, UploadMealImageUseCaseError>;
+}
+
+#[derive(Error, Debug, PartialEq, Clone)]
+pub enum UploadMealImageUseCaseError {
+    #[error("Meal not found")]
+    MealNotFound,
+    #[error("Unsupported image content type '{0}'")]
+    UnsupportedContentType(String),
+    #[error("Image is larger than {0} bytes")]
+    TooLarge(usize),
+    #[error("Image content doesn't match the content type '{0}'")]
+    ContentMismatch(String),
+    #[error(transparent)]
+    StorageFailed(#[from] MediaStorageError),
+}
@@ -1,2 +1,3 @@
+pub mod shop_order_criteria;
 pub mod shop_order_extractor;
 pub mod shop_order_persister;
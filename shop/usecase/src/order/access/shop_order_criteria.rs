@@ -0,0 +1,217 @@
+use std::{cmp::Ordering, mem::discriminant};
+
+use derive_new::new;
+use domain::{
+    cart::value_objects::customer_id::CustomerId,
+    menu::value_objects::price::Price,
+    order::{
+        shop_order::{OrderState, ShopOrder},
+        value_objects::shop_order_id::ShopOrderId,
+    },
+};
+use time::OffsetDateTime;
+
+/// Field an order list is sorted by, ties are broken by the order id
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ShopOrderSortField {
+    #[default]
+    Id,
+    Created,
+    Total,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SortDirection {
+    #[default]
+    Asc,
+    Desc,
+}
+
+impl SortDirection {
+    pub fn apply(&self, ordering: Ordering) -> Ordering {
+        match self {
+            SortDirection::Asc => ordering,
+            SortDirection::Desc => ordering.reverse(),
+        }
+    }
+}
+
+/// Filters and ordering of an order list, unset filters match every order
+#[derive(new, Debug, Clone, PartialEq, Default)]
+pub struct ShopOrderCriteria {
+    pub state: Option<OrderState>,
+    pub customer_id: Option<CustomerId>,
+    /// Inclusive lower bound of the creation time
+    pub created_from: Option<OffsetDateTime>,
+    /// Exclusive upper bound of the creation time
+    pub created_to: Option<OffsetDateTime>,
+    pub min_total: Option<Price>,
+    pub sort_by: ShopOrderSortField,
+    pub direction: SortDirection,
+}
+
+impl ShopOrderCriteria {
+    pub fn for_customer(customer_id: CustomerId) -> Self {
+        Self {
+            customer_id: Some(customer_id),
+            ..Default::default()
+        }
+    }
+
+    pub fn matches(&self, order: &ShopOrder) -> bool {
+        self.state
+            .as_ref()
+            .is_none_or(|state| discriminant(state) == discriminant(order.state()))
+            && self
+                .customer_id
+                .is_none_or(|customer_id| &customer_id == order.for_customer())
+            && self
+                .created_from
+                .is_none_or(|from| order.created() >= &from)
+            && self.created_to.is_none_or(|to| order.created() < &to)
+            && self.min_total.as_ref().is_none_or(|min_total| {
+                order.total_price().to_bigdecimal() >= min_total.to_bigdecimal()
+            })
+    }
+
+    pub fn compare(&self, o1: &ShopOrder, o2: &ShopOrder) -> Ordering {
+        let by_field = match self.sort_by {
+            ShopOrderSortField::Id => Ordering::Equal,
+            ShopOrderSortField::Created => o1.created().cmp(o2.created()),
+            ShopOrderSortField::Total => o1
+                .total_price()
+                .to_bigdecimal()
+                .cmp(&o2.total_price().to_bigdecimal()),
+        };
+        self.direction.apply(by_field.then(o1.id().cmp(o2.id())))
+    }
+
+    /// Whether the order belongs to the page starting at `start_id`.
+    /// The page starts at the `cursor` order when it exists. Otherwise sorting by id
+    /// starts at `start_id` itself and other orderings start from the beginning.
+    pub fn is_from_cursor(
+        &self,
+        order: &ShopOrder,
+        start_id: &ShopOrderId,
+        cursor: Option<&ShopOrder>,
+    ) -> bool {
+        let ordering = match (cursor, self.sort_by) {
+            (Some(cursor), _) => self.compare(order, cursor),
+            (None, ShopOrderSortField::Id) => self.direction.apply(order.id().cmp(start_id)),
+            (None, _) => Ordering::Greater,
+        };
+        ordering != Ordering::Less
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::test_fixtures::*;
+    use time::Duration;
+
+    use super::*;
+
+    #[test]
+    fn default_criteria_matches_every_order() {
+        let criteria = ShopOrderCriteria::default();
+
+        assert!(criteria.matches(&order_with_state(OrderState::new_paid())));
+        assert!(criteria.matches(&order_with_state(OrderState::new_cancelled())));
+    }
+
+    #[test]
+    fn filter_by_state() {
+        let criteria = ShopOrderCriteria {
+            state: Some(OrderState::new_paid()),
+            ..Default::default()
+        };
+
+        assert!(criteria.matches(&order_with_state(OrderState::new_paid())));
+        assert!(!criteria.matches(&order_with_state(OrderState::new_confirmed())));
+    }
+
+    #[test]
+    fn filter_by_customer() {
+        let customer_id = rnd_customer_id();
+        let criteria = ShopOrderCriteria::for_customer(customer_id);
+
+        assert!(criteria.matches(&rnd_order_with_customer_id(customer_id)));
+        assert!(!criteria.matches(&rnd_order_with_customer_id(rnd_customer_id())));
+    }
+
+    #[test]
+    fn filter_by_created_range() {
+        let order = rnd_order(Default::default());
+        let created = *order.created();
+
+        let inside = ShopOrderCriteria {
+            created_from: Some(created),
+            created_to: Some(created + Duration::seconds(1)),
+            ..Default::default()
+        };
+        let before = ShopOrderCriteria {
+            created_to: Some(created),
+            ..Default::default()
+        };
+        let after = ShopOrderCriteria {
+            created_from: Some(created + Duration::seconds(1)),
+            ..Default::default()
+        };
+
+        assert!(inside.matches(&order));
+        assert!(!before.matches(&order));
+        assert!(!after.matches(&order));
+    }
+
+    #[test]
+    fn filter_by_min_total() {
+        let order = rnd_order(Default::default());
+        let total = order.total_price();
+
+        let reached = ShopOrderCriteria {
+            min_total: Some(total.clone()),
+            ..Default::default()
+        };
+        let not_reached = ShopOrderCriteria {
+            min_total: Some(total.add(rnd_price())),
+            ..Default::default()
+        };
+
+        assert!(reached.matches(&order));
+        assert!(!not_reached.matches(&order));
+    }
+
+    #[test]
+    fn sort_by_created_desc_breaks_ties_by_id() {
+        let first = rnd_order_with_id(ShopOrderId::try_from(1).unwrap());
+        let second = rnd_order_with_id(ShopOrderId::try_from(2).unwrap());
+        let criteria = ShopOrderCriteria {
+            sort_by: ShopOrderSortField::Created,
+            direction: SortDirection::Desc,
+            ..Default::default()
+        };
+
+        assert_eq!(criteria.compare(&second, &first), Ordering::Less);
+    }
+
+    #[test]
+    fn cursor_by_id_without_existing_order() {
+        let criteria = ShopOrderCriteria::default();
+        let order = rnd_order_with_id(ShopOrderId::try_from(5).unwrap());
+
+        assert!(criteria.is_from_cursor(&order, &ShopOrderId::try_from(5).unwrap(), None));
+        assert!(!criteria.is_from_cursor(&order, &ShopOrderId::try_from(6).unwrap(), None));
+    }
+
+    #[test]
+    fn cursor_by_total_starts_at_cursor_order() {
+        let cheap = rnd_order_with_id(ShopOrderId::try_from(1).unwrap());
+        let criteria = ShopOrderCriteria {
+            sort_by: ShopOrderSortField::Total,
+            ..Default::default()
+        };
+
+        assert!(criteria.is_from_cursor(&cheap, cheap.id(), Some(&cheap)));
+        assert!(criteria.is_from_cursor(&cheap, &rnd_order_id(), None));
+    }
+}
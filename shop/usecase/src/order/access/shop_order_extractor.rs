@@ -2,11 +2,25 @@ use std::fmt::Debug;
 
 use domain::{
     cart::value_objects::customer_id::CustomerId,
-    order::{shop_order::ShopOrder, value_objects::shop_order_id::ShopOrderId},
+    order::{
+        shop_order::ShopOrder,
+        value_objects::{delivery_slot::DeliverySlot, shop_order_id::ShopOrderId},
+    },
 };
 
+use crate::order::access::shop_order_criteria::ShopOrderCriteria;
+
 pub trait ShopOrderExtractor: Debug + Send {
     fn get_by_id(&mut self, order_id: &ShopOrderId) -> Option<ShopOrder>;
     fn get_last_order(&mut self, for_customer: &CustomerId) -> Option<ShopOrder>;
-    fn get_all(&mut self, start_id: &ShopOrderId, limit: usize) -> Vec<ShopOrder>;
+    /// Orders matching the criteria in its ordering, the page starts at `start_id`
+    fn get_all(
+        &mut self,
+        criteria: &ShopOrderCriteria,
+        start_id: &ShopOrderId,
+        limit: usize,
+    ) -> Vec<ShopOrder>;
+    /// Orders which are neither completed nor cancelled, ordered by id
+    fn get_all_active(&mut self) -> Vec<ShopOrder>;
+    fn count_active_by_delivery_slot(&mut self, slot: &DeliverySlot) -> usize;
 }
@@ -4,8 +4,16 @@ use common::types::common::Address;
 use derive_new::new;
 use domain::{
     cart::value_objects::customer_id::CustomerId,
-    menu::value_objects::price::Price,
-    order::{shop_order::CheckoutError, value_objects::shop_order_id::ShopOrderId},
+    customer::value_objects::saved_address::SavedAddressId,
+    menu::value_objects::{meal_id::MealId, price::Price},
+    order::{
+        check_delivery_slot::DeliverySlotError,
+        shop_order::CheckoutError,
+        value_objects::{
+            delivery_slot::DeliverySlot, fulfilment::Fulfilment, pickup_point_id::PickupPointId,
+            shop_order_id::ShopOrderId,
+        },
+    },
 };
 use thiserror::Error;
 
@@ -25,19 +33,53 @@ pub struct PaymentInfo {
 #[derive(new, Debug, Clone)]
 pub struct CheckoutRequest {
     pub for_customer: CustomerId,
-    pub delivery_to: Address,
+    pub fulfilment: CheckoutFulfilment,
+    pub delivery_slot: Option<DeliverySlot>,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum CheckoutFulfilment {
+    Delivery(Address),
+    /// Delivery to an address from the customer profile
+    SavedAddress(SavedAddressId),
+    Pickup(PickupPointId),
+}
+
+impl From<Fulfilment> for CheckoutFulfilment {
+    fn from(value: Fulfilment) -> Self {
+        match value {
+            Fulfilment::Delivery(address) => Self::Delivery(address),
+            Fulfilment::Pickup(pickup_point_id) => Self::Pickup(pickup_point_id),
+        }
+    }
 }
 
 #[derive(Error, Debug, Clone, PartialEq)]
 pub enum CheckoutUseCaseError {
     #[error("Cart not found")]
     CartNotFound,
+    #[error("Customer not found")]
+    CustomerNotFound,
+    #[error("Saved address not found")]
+    SavedAddressNotFound,
     #[error("Empty cart")]
     EmptyCart,
     #[error("Already has active order")]
     AlreadyHasActiveOrder,
+    #[error("Address is outside of delivery zone")]
+    AddressOutsideDeliveryZone,
     #[error("TODO")]
     InvalidAddress,
+    #[error("Delivery slot has already started")]
+    DeliverySlotAlreadyStarted,
+    #[error("Delivery slot is outside of opening hours")]
+    DeliverySlotOutsideOpeningHours,
+    #[error("Delivery slot is fully booked")]
+    DeliverySlotFullyBooked,
+    #[error("Meal is sold out")]
+    MealUnavailable(MealId),
+    #[error("Meal is not available at this time")]
+    MealOutsideAvailabilityWindow(MealId),
 }
 
 impl From<CheckoutError> for CheckoutUseCaseError {
@@ -45,6 +87,16 @@ impl From<CheckoutError> for CheckoutUseCaseError {
         match value {
             CheckoutError::EmptyCart => Self::EmptyCart,
             CheckoutError::AlreadyHasActiveOrder => Self::AlreadyHasActiveOrder,
+            CheckoutError::AddressOutsideDeliveryZone => Self::AddressOutsideDeliveryZone,
+            CheckoutError::DeliverySlotUnavailable(error) => match error {
+                DeliverySlotError::AlreadyStarted => Self::DeliverySlotAlreadyStarted,
+                DeliverySlotError::OutsideOpeningHours => Self::DeliverySlotOutsideOpeningHours,
+                DeliverySlotError::NoCapacityLeft => Self::DeliverySlotFullyBooked,
+            },
+            CheckoutError::MealUnavailable(meal_id) => Self::MealUnavailable(meal_id),
+            CheckoutError::MealOutsideAvailabilityWindow(meal_id) => {
+                Self::MealOutsideAvailabilityWindow(meal_id)
+            }
         }
     }
 }
@@ -2,14 +2,17 @@ use std::fmt::Debug;
 
 use async_trait::async_trait;
 use domain::order::value_objects::shop_order_id::ShopOrderId;
+use thiserror::Error;
 
 #[async_trait]
 pub trait CompleteOrder: Debug + Send {
     async fn execute(&self, order_id: &ShopOrderId) -> Result<(), CompleteOrderUseCaseError>;
 }
 
-#[derive(Debug, Clone, PartialEq)]
+#[derive(Error, Debug, Clone, PartialEq)]
 pub enum CompleteOrderUseCaseError {
+    #[error("Order not found")]
     OrderNotFound,
+    #[error("Invalid order state")]
     InvalidOrderState,
 }
@@ -0,0 +1,8 @@
+use derive_new::new;
+use domain::order::value_objects::delivery_slot::DeliverySlot;
+
+#[derive(new, Debug, PartialEq, Clone, Copy)]
+pub struct AvailableDeliverySlot {
+    pub slot: DeliverySlot,
+    pub remaining_capacity: usize,
+}
@@ -0,0 +1,51 @@
+use bigdecimal::BigDecimal;
+use domain::{
+    cart::value_objects::customer_id::CustomerId, menu::value_objects::price::Price,
+    order::value_objects::shop_order_id::ShopOrderId,
+};
+use serde::{Deserialize, Serialize};
+
+/// Order as it is handed over by the exporters
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExportedOrder {
+    pub order_id: i64,
+    pub customer_id: String,
+    pub total_price: BigDecimal,
+}
+
+impl ExportedOrder {
+    pub fn new(id: ShopOrderId, customer_id: CustomerId, total_price: Price) -> Self {
+        Self {
+            order_id: id.to_i64(),
+            customer_id: customer_id.to_string(),
+            total_price: total_price.to_bigdecimal(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use domain::test_fixtures::*;
+
+    use super::*;
+
+    #[test]
+    fn serialized_with_plain_values() {
+        let order_id = rnd_order_id();
+        let customer_id = rnd_customer_id();
+        let total_price = Price::try_from(BigDecimal::from_str("12.5").unwrap()).unwrap();
+
+        let json =
+            serde_json::to_string(&ExportedOrder::new(order_id, customer_id, total_price)).unwrap();
+
+        assert_eq!(
+            json,
+            format!(
+                r#"{{"order_id":{},"customer_id":"{customer_id}","total_price":"12.50"}}"#,
+                order_id.to_i64()
+            )
+        );
+    }
+}
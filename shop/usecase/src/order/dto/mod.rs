@@ -1 +1,3 @@
+pub mod available_delivery_slot;
+pub mod exported_order;
 pub mod order_details;
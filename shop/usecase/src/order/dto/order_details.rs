@@ -1,13 +1,13 @@
-use common::types::{
-    base::Version,
-    common::{Address, Count},
-};
+use common::types::{base::Version, common::Count};
 use derive_new::new;
 use domain::{
+    cart::value_objects::customer_id::CustomerId,
     menu::value_objects::{meal_id::MealId, price::Price},
     order::{
         shop_order::{OrderState, ShopOrder},
-        value_objects::shop_order_id::ShopOrderId,
+        value_objects::{
+            delivery_slot::DeliverySlot, fulfilment::Fulfilment, shop_order_id::ShopOrderId,
+        },
     },
 };
 use serde::{Deserialize, Serialize};
@@ -15,10 +15,13 @@ use serde::{Deserialize, Serialize};
 #[derive(Debug, PartialEq, Serialize, Deserialize, Clone, Default)]
 pub struct OrderDetails {
     pub id: ShopOrderId,
+    pub customer_id: CustomerId,
     pub state: OrderState,
-    pub address: Address,
+    pub fulfilment: Fulfilment,
+    pub delivery_slot: Option<DeliverySlot>,
     pub ready_for_confirm_or_cancel: bool,
     pub items: Vec<OrderItemDetails>,
+    pub delivery_fee: Price,
     pub total: Price,
     pub version: Version,
 }
@@ -36,10 +39,13 @@ impl AsDetails for ShopOrder {
             .collect();
         OrderDetails {
             id: *self.id(),
+            customer_id: *self.for_customer(),
             state: self.state().clone(),
-            address: self.address().clone(),
+            fulfilment: self.fulfilment().clone(),
+            delivery_slot: *self.delivery_slot(),
             ready_for_confirm_or_cancel: self.ready_for_confirm_or_cancel(),
             items,
+            delivery_fee: self.delivery_fee().clone(),
             total: self.total_price(),
             version: *self.version(),
         }
@@ -0,0 +1,11 @@
+use std::fmt::Debug;
+
+use async_trait::async_trait;
+
+use crate::order::dto::order_details::OrderDetails;
+
+/// Paid orders the kitchen still has to deal with
+#[async_trait]
+pub trait GetActiveOrders: Debug + Send {
+    async fn execute(&mut self) -> Vec<OrderDetails>;
+}
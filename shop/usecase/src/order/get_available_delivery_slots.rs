@@ -0,0 +1,11 @@
+use std::fmt::Debug;
+
+use async_trait::async_trait;
+use time::Date;
+
+use crate::order::dto::available_delivery_slot::AvailableDeliverySlot;
+
+#[async_trait]
+pub trait GetAvailableDeliverySlots: Debug + Send {
+    async fn execute(&mut self, date: Date) -> Vec<AvailableDeliverySlot>;
+}
@@ -0,0 +1,25 @@
+use std::fmt::Debug;
+
+use async_trait::async_trait;
+use domain::{
+    cart::value_objects::customer_id::CustomerId, order::value_objects::shop_order_id::ShopOrderId,
+};
+use thiserror::Error;
+
+use crate::order::dto::order_details::OrderDetails;
+
+#[async_trait]
+pub trait GetCustomerOrders: Debug + Send {
+    async fn execute(
+        &mut self,
+        for_customer: &CustomerId,
+        start_id: &ShopOrderId,
+        limit: usize,
+    ) -> Result<Vec<OrderDetails>, GetCustomerOrdersUseCaseError>;
+}
+
+#[derive(Error, Debug, Clone, Copy, PartialEq)]
+pub enum GetCustomerOrdersUseCaseError {
+    #[error("Limit is exceeded")]
+    LimitExceed(usize),
+}
@@ -7,7 +7,7 @@ use thiserror::Error;
 #[async_trait]
 pub trait GetLastOrderState: Debug + Send {
     async fn execute(
-        &self,
+        &mut self,
         for_customer: &CustomerId,
     ) -> Result<OrderState, GetLastOrderStateUseCaseError>;
 }
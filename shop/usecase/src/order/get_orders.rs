@@ -5,12 +5,15 @@ use derive_new::new;
 use domain::order::value_objects::shop_order_id::ShopOrderId;
 use thiserror::Error;
 
-use crate::order::dto::order_details::OrderDetails;
+use crate::order::{
+    access::shop_order_criteria::ShopOrderCriteria, dto::order_details::OrderDetails,
+};
 
 #[async_trait]
 pub trait GetOrders: Debug + Send {
     async fn execute(
         &mut self,
+        criteria: &ShopOrderCriteria,
         start_id: &ShopOrderId,
         limit: usize,
     ) -> Result<Vec<OrderDetails>, GetOrdersUseCaseError>;
@@ -0,0 +1,54 @@
+use async_trait::async_trait;
+use common::types::common::Address;
+use derive_new::new;
+use domain::order::{
+    address_in_delivery_zone::AddressInDeliveryZone, value_objects::delivery_zone::DeliveryZone,
+};
+
+#[derive(new, Debug)]
+pub struct AddressInDeliveryZoneImpl {
+    zone: DeliveryZone,
+}
+
+#[async_trait]
+impl AddressInDeliveryZone for AddressInDeliveryZoneImpl {
+    async fn invoke(&mut self, address: &Address) -> bool {
+        // Without coordinates there is no way to tell whether a courier can reach the address
+        address
+            .location()
+            .is_some_and(|location| self.zone.contains(&location))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::types::common::GeoLocation;
+    use domain::test_fixtures::rnd_address;
+    use tokio::test;
+
+    use super::*;
+
+    fn rule() -> AddressInDeliveryZoneImpl {
+        let center = GeoLocation::try_from((52.52, 13.405)).unwrap();
+        AddressInDeliveryZoneImpl::new(DeliveryZone::radius(center, 5_000.0).unwrap())
+    }
+
+    #[test]
+    async fn address_inside_zone() {
+        let address = rnd_address().with_location(GeoLocation::try_from((52.53, 13.41)).unwrap());
+
+        assert!(rule().invoke(&address).await);
+    }
+
+    #[test]
+    async fn address_outside_zone() {
+        let address = rnd_address().with_location(GeoLocation::try_from((48.13, 11.58)).unwrap());
+
+        assert!(!rule().invoke(&address).await);
+    }
+
+    #[test]
+    async fn address_without_location() {
+        assert!(!rule().invoke(&rnd_address()).await);
+    }
+}
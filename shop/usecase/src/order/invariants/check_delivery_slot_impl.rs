@@ -0,0 +1,125 @@
+use async_trait::async_trait;
+use common::types::base::AM;
+use derive_new::new;
+use domain::order::{
+    check_delivery_slot::{CheckDeliverySlot, DeliverySlotError},
+    value_objects::{delivery_slot::DeliverySlot, opening_hours::OpeningHours},
+};
+
+use crate::order::access::shop_order_extractor::ShopOrderExtractor;
+
+#[derive(new, Debug)]
+pub struct CheckDeliverySlotImpl {
+    shop_order_extractor: AM<dyn ShopOrderExtractor>,
+    opening_hours: OpeningHours,
+    slot_capacity: usize,
+}
+
+#[async_trait]
+impl CheckDeliverySlot for CheckDeliverySlotImpl {
+    async fn invoke(&mut self, slot: &DeliverySlot) -> Result<(), DeliverySlotError> {
+        if slot.has_started() {
+            Err(DeliverySlotError::AlreadyStarted)
+        } else if !self.opening_hours.has_slot(slot) {
+            Err(DeliverySlotError::OutsideOpeningHours)
+        } else if self
+            .shop_order_extractor
+            .lock()
+            .await
+            .count_active_by_delivery_slot(slot)
+            >= self.slot_capacity
+        {
+            Err(DeliverySlotError::NoCapacityLeft)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::types::base::AMTrait;
+    use domain::{order::shop_order::OrderState, test_fixtures::*};
+    use time::{Duration, OffsetDateTime, Time};
+    use tokio::test;
+
+    use super::*;
+    use crate::test_fixtures::MockShopOrderExtractor;
+
+    fn opening_hours() -> OpeningHours {
+        OpeningHours::try_from((
+            Time::from_hms(10, 0, 0).unwrap(),
+            Time::from_hms(22, 0, 0).unwrap(),
+            Duration::hours(1),
+        ))
+        .unwrap()
+    }
+
+    fn tomorrow_slot() -> DeliverySlot {
+        let start = (OffsetDateTime::now_utc() + Duration::days(1))
+            .replace_time(Time::from_hms(12, 0, 0).unwrap());
+        DeliverySlot::try_from((start, start + Duration::hours(1))).unwrap()
+    }
+
+    #[test]
+    async fn slot_is_available() {
+        let slot = tomorrow_slot();
+        let extractor = AM::new_am(MockShopOrderExtractor::default());
+        let mut rule = CheckDeliverySlotImpl::new(extractor.clone(), opening_hours(), 1);
+
+        let result = rule.invoke(&slot).await;
+
+        assert!(result.is_ok());
+        extractor
+            .lock()
+            .await
+            .verify_invoked_count_active_by_delivery_slot(&slot);
+    }
+
+    #[test]
+    async fn slot_has_no_capacity_left() {
+        let slot = tomorrow_slot();
+        let order = order_with_delivery_slot_and_state(slot, OrderState::new_paid());
+        let extractor = AM::new_am(MockShopOrderExtractor {
+            order: Some(order),
+            ..Default::default()
+        });
+        let mut rule = CheckDeliverySlotImpl::new(extractor.clone(), opening_hours(), 1);
+
+        let result = rule.invoke(&slot).await;
+
+        assert_eq!(result, Err(DeliverySlotError::NoCapacityLeft));
+        extractor
+            .lock()
+            .await
+            .verify_invoked_count_active_by_delivery_slot(&slot);
+    }
+
+    #[test]
+    async fn slot_is_outside_opening_hours() {
+        let start = tomorrow_slot()
+            .start()
+            .replace_time(Time::from_hms(8, 0, 0).unwrap());
+        let slot = DeliverySlot::try_from((start, start + Duration::hours(1))).unwrap();
+        let extractor = AM::new_am(MockShopOrderExtractor::default());
+        let mut rule = CheckDeliverySlotImpl::new(extractor.clone(), opening_hours(), 1);
+
+        let result = rule.invoke(&slot).await;
+
+        assert_eq!(result, Err(DeliverySlotError::OutsideOpeningHours));
+        extractor.lock().await.verify_empty();
+    }
+
+    #[test]
+    async fn slot_has_already_started() {
+        let start = OffsetDateTime::now_utc() - Duration::minutes(10);
+        let slot = DeliverySlot::try_from((start, start + Duration::hours(1))).unwrap();
+        let extractor = AM::new_am(MockShopOrderExtractor::default());
+        let mut rule = CheckDeliverySlotImpl::new(extractor.clone(), opening_hours(), 1);
+
+        let result = rule.invoke(&slot).await;
+
+        assert_eq!(result, Err(DeliverySlotError::AlreadyStarted));
+        extractor.lock().await.verify_empty();
+    }
+}
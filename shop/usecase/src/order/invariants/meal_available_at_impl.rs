@@ -0,0 +1,74 @@
+use async_trait::async_trait;
+use common::types::base::AM;
+use derive_new::new;
+use domain::{menu::value_objects::meal_id::MealId, order::meal_available_at::MealAvailableAt};
+use time::OffsetDateTime;
+
+use crate::menu::access::meal_extractor::MealExtractor;
+
+#[derive(new, Debug)]
+pub struct MealAvailableAtImpl {
+    meal_extractor: AM<dyn MealExtractor>,
+}
+
+#[async_trait]
+impl MealAvailableAt for MealAvailableAtImpl {
+    async fn invoke(&mut self, meal_id: &MealId, at: &OffsetDateTime) -> bool {
+        self.meal_extractor
+            .lock()
+            .await
+            .get_by_id(meal_id)
+            .is_some_and(|meal| meal.is_available_at(at))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::types::base::AMTrait;
+    use domain::test_fixtures::*;
+    use time::Duration;
+    use tokio::test;
+
+    use super::*;
+    use crate::test_fixtures::MockMealExtractor;
+
+    #[test]
+    async fn meal_without_schedule() {
+        let meal = rnd_meal();
+        let extractor = AM::new_am(MockMealExtractor::new());
+        extractor.lock().await.meal = Some(meal.clone());
+        let mut rule = MealAvailableAtImpl::new(extractor.clone());
+
+        let available = rule.invoke(meal.id(), &OffsetDateTime::now_utc()).await;
+
+        assert!(available);
+        extractor.lock().await.verify_invoked_get_by_id(meal.id());
+    }
+
+    #[test]
+    async fn meal_with_schedule() {
+        let mut meal = rnd_meal();
+        let schedule = rnd_availability_schedule();
+        meal.change_availability_schedule(Some(schedule.clone()));
+        let extractor = AM::new_am(MockMealExtractor::new());
+        extractor.lock().await.meal = Some(meal.clone());
+        let mut rule = MealAvailableAtImpl::new(extractor);
+
+        let at = OffsetDateTime::now_utc() + Duration::hours(30);
+        let available = rule.invoke(meal.id(), &at).await;
+
+        assert_eq!(available, schedule.is_available_at(&at));
+    }
+
+    #[test]
+    async fn meal_not_found() {
+        let extractor = AM::new_am(MockMealExtractor::new());
+        let mut rule = MealAvailableAtImpl::new(extractor);
+
+        let available = rule
+            .invoke(&rnd_meal_id(), &OffsetDateTime::now_utc())
+            .await;
+
+        assert!(!available);
+    }
+}
@@ -1 +1,4 @@
+pub mod address_in_delivery_zone_impl;
+pub mod check_delivery_slot_impl;
 pub mod customer_has_active_order_impl;
+pub mod meal_available_at_impl;
@@ -0,0 +1,18 @@
+use std::fmt::Debug;
+
+use async_trait::async_trait;
+use domain::order::value_objects::shop_order_id::ShopOrderId;
+
+#[async_trait]
+pub trait MarkOrderReadyForPickup: Debug + Send {
+    async fn execute(
+        &self,
+        order_id: &ShopOrderId,
+    ) -> Result<(), MarkOrderReadyForPickupUseCaseError>;
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum MarkOrderReadyForPickupUseCaseError {
+    OrderNotFound,
+    InvalidOrderState,
+}
@@ -2,6 +2,7 @@ pub mod access;
 pub mod checkout;
 pub mod dto;
 pub mod invariants;
+pub mod order_event_feed;
 pub mod pay_order;
 pub mod providers;
 pub mod rules;
@@ -10,13 +11,21 @@ pub mod scenarios;
 mod cancel_order;
 mod complete_order;
 mod confirm_order;
+mod get_active_orders;
+mod get_available_delivery_slots;
+mod get_customer_orders;
 mod get_last_order_state;
 mod get_order_by_id;
 mod get_orders;
+mod mark_order_ready_for_pickup;
 
 pub use cancel_order::*;
 pub use complete_order::*;
 pub use confirm_order::*;
+pub use get_active_orders::*;
+pub use get_available_delivery_slots::*;
+pub use get_customer_orders::*;
 pub use get_last_order_state::*;
 pub use get_order_by_id::*;
 pub use get_orders::*;
+pub use mark_order_ready_for_pickup::*;
@@ -0,0 +1,135 @@
+use std::{collections::VecDeque, sync::Mutex};
+
+use common::types::base::EventId;
+use domain::order::customer_order_events::ShopOrderEventEnum;
+use tokio::sync::broadcast::{self, Receiver, Sender};
+
+/// Order events kept for the live feed subscribers, the slower ones skip the overwritten events
+pub const ORDER_FEED_CAPACITY: usize = 256;
+
+/// In-process broadcast of the order events which also keeps the recent ones, so a subscriber
+/// coming back can catch up on what it missed. Publishing never waits for the subscribers.
+#[derive(Debug)]
+pub struct OrderEventFeed {
+    sender: Sender<ShopOrderEventEnum>,
+    recent: Mutex<VecDeque<ShopOrderEventEnum>>,
+    capacity: usize,
+}
+
+impl OrderEventFeed {
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            sender: broadcast::channel(capacity).0,
+            recent: Mutex::new(VecDeque::with_capacity(capacity)),
+            capacity,
+        }
+    }
+
+    pub fn publish(&self, event: &ShopOrderEventEnum) {
+        let mut recent = self.recent.lock().unwrap();
+        if recent.len() == self.capacity {
+            recent.pop_front();
+        }
+        recent.push_back(event.clone());
+        // Fails only when nobody is subscribed
+        let _ = self.sender.send(event.clone());
+    }
+
+    pub fn subscribe(&self) -> Receiver<ShopOrderEventEnum> {
+        self.sender.subscribe()
+    }
+
+    /// Events published after `last_event_id` followed by the upcoming ones, all the kept events
+    /// are returned when `last_event_id` is too old to be known
+    pub fn subscribe_after(
+        &self,
+        last_event_id: &EventId,
+    ) -> (Vec<ShopOrderEventEnum>, Receiver<ShopOrderEventEnum>) {
+        // Nothing is published between the snapshot and the subscription
+        let recent = self.recent.lock().unwrap();
+        let missed = match recent.iter().position(|e| e.event_id() == last_event_id) {
+            Some(index) => recent.iter().skip(index + 1).cloned().collect(),
+            None => recent.iter().cloned().collect(),
+        };
+        (missed, self.sender.subscribe())
+    }
+}
+
+impl Default for OrderEventFeed {
+    fn default() -> Self {
+        Self::new(ORDER_FEED_CAPACITY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::{
+        order::customer_order_events::{
+            ShopOrderCancelledDomainEvent, ShopOrderConfirmedDomainEvent, ShopOrderPaidDomainEvent,
+        },
+        test_fixtures::*,
+    };
+    use tokio::sync::broadcast::error::RecvError;
+
+    use super::*;
+
+    fn paid() -> ShopOrderEventEnum {
+        ShopOrderPaidDomainEvent::new(rnd_order_id()).into()
+    }
+
+    #[tokio::test]
+    async fn event_is_broadcast() {
+        let feed = OrderEventFeed::default();
+        let mut first = feed.subscribe();
+        let mut second = feed.subscribe();
+
+        let event = paid();
+        feed.publish(&event);
+
+        assert_eq!(first.recv().await.unwrap(), event);
+        assert_eq!(second.recv().await.unwrap(), event);
+    }
+
+    #[tokio::test]
+    async fn slow_subscriber_does_not_block() {
+        let feed = OrderEventFeed::new(1);
+        let mut subscriber = feed.subscribe();
+
+        for _ in 0..3 {
+            feed.publish(&paid());
+        }
+
+        assert_eq!(subscriber.recv().await.err(), Some(RecvError::Lagged(2)));
+        assert!(subscriber.recv().await.is_ok());
+    }
+
+    #[tokio::test]
+    async fn missed_events_are_returned() {
+        let feed = OrderEventFeed::default();
+        let order_id = rnd_order_id();
+        let paid: ShopOrderEventEnum = ShopOrderPaidDomainEvent::new(order_id).into();
+        let confirmed: ShopOrderEventEnum = ShopOrderConfirmedDomainEvent::new(order_id).into();
+        feed.publish(&paid);
+        feed.publish(&confirmed);
+
+        let (missed, mut receiver) = feed.subscribe_after(paid.event_id());
+        assert_eq!(missed, vec![confirmed]);
+
+        let cancelled: ShopOrderEventEnum = ShopOrderCancelledDomainEvent::new(order_id).into();
+        feed.publish(&cancelled);
+        assert_eq!(receiver.recv().await.unwrap(), cancelled);
+    }
+
+    #[test]
+    fn all_kept_events_are_returned_after_unknown_event() {
+        let feed = OrderEventFeed::new(2);
+        let events: Vec<ShopOrderEventEnum> = (0..3).map(|_| paid()).collect();
+        for event in &events {
+            feed.publish(event);
+        }
+
+        let (missed, _) = feed.subscribe_after(events[0].event_id());
+
+        assert_eq!(missed, events[1..].to_vec());
+    }
+}
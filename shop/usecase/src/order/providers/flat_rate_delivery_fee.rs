@@ -0,0 +1,34 @@
+use async_trait::async_trait;
+use common::types::common::Address;
+use derive_new::new;
+use domain::{menu::value_objects::price::Price, order::get_delivery_fee::GetDeliveryFee};
+
+/// Charges the same fee for delivery to any address
+#[derive(new, Debug)]
+pub struct FlatRateDeliveryFee {
+    pub fee: Price,
+}
+
+#[async_trait]
+impl GetDeliveryFee for FlatRateDeliveryFee {
+    async fn invoke(&self, _delivery_to: &Address) -> Price {
+        self.fee.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::test_fixtures::*;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn fee_has_been_provided() {
+        let fee = rnd_price();
+        let get_delivery_fee = FlatRateDeliveryFee::new(fee.clone());
+
+        let result = get_delivery_fee.invoke(&rnd_address()).await;
+
+        assert_eq!(result, fee);
+    }
+}
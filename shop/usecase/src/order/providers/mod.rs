@@ -1,3 +1,5 @@
+pub mod flat_rate_delivery_fee;
 pub mod get_meal_price_using_extractor;
 pub mod order_exporter;
 pub mod payment_url_provider;
+pub mod reserve_meal_portions_using_stock;
@@ -1,10 +1,25 @@
 use std::fmt::Debug;
 
+use async_trait::async_trait;
 use domain::{
     cart::value_objects::customer_id::CustomerId, menu::value_objects::price::Price,
     order::value_objects::shop_order_id::ShopOrderId,
 };
+use thiserror::Error;
 
+/// Hands the checked out orders over to the systems outside the shop
+#[async_trait]
 pub trait OrderExporter: Debug + Send {
-    fn export_order(&mut self, id: ShopOrderId, customer_id: CustomerId, total_price: Price);
+    async fn export_order(
+        &mut self,
+        id: ShopOrderId,
+        customer_id: CustomerId,
+        total_price: Price,
+    ) -> Result<(), OrderExportError>;
+}
+
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum OrderExportError {
+    #[error("Order export failed: {0}")]
+    ExportFailed(String),
 }
@@ -0,0 +1,106 @@
+use std::collections::HashMap;
+
+use async_trait::async_trait;
+use common::types::{base::AM, common::Count};
+use derive_new::new;
+use domain::{
+    menu::value_objects::meal_id::MealId,
+    order::reserve_meal_portions::{MealUnavailable, ReserveMealPortions},
+};
+
+use crate::inventory::access::{
+    meal_stock_extractor::MealStockExtractor, meal_stock_persister::MealStockPersister,
+};
+
+#[async_trait]
+impl ReserveMealPortions for ReserveMealPortionsUsingStock {
+    async fn invoke(&mut self, meals: &HashMap<MealId, Count>) -> Result<(), MealUnavailable> {
+        let meal_ids: Vec<MealId> = meals.keys().copied().collect();
+        let stocks = self.extractor.lock().await.get_by_meal_ids(&meal_ids);
+
+        if let Some(stock) = stocks
+            .iter()
+            .find(|stock| !stock.has_portions(&meals[stock.meal_id()]))
+        {
+            return Err(MealUnavailable(*stock.meal_id()));
+        }
+
+        let mut persister = self.persister.lock().await;
+        for mut stock in stocks {
+            let count = meals[stock.meal_id()];
+            stock
+                .reserve(count)
+                .expect("Portions have been checked before");
+            persister.save(stock).await;
+        }
+        Ok(())
+    }
+}
+
+/// Meals without a stock are not limited and are not reserved
+#[derive(new, Debug)]
+pub struct ReserveMealPortionsUsingStock {
+    pub extractor: AM<dyn MealStockExtractor>,
+    pub persister: AM<dyn MealStockPersister>,
+}
+
+#[cfg(test)]
+mod tests {
+    use common::types::base::AMTrait;
+    use domain::{inventory::meal_stock::MealStock, test_fixtures::*};
+
+    use super::*;
+    use crate::test_fixtures::{MockMealStockExtractor, MockMealStockPersister};
+
+    fn count(value: i32) -> Count {
+        Count::try_from(value).unwrap()
+    }
+
+    #[tokio::test]
+    async fn portions_reserved() {
+        let stock = MealStock::create(rnd_meal_id(), count(3));
+        let untracked_meal_id = rnd_meal_id();
+        let persister = AM::new_am(MockMealStockPersister::default());
+        let mut reserve = ReserveMealPortionsUsingStock::new(
+            AM::new_am(MockMealStockExtractor::new(vec![stock.clone()])),
+            persister.clone(),
+        );
+
+        let result = reserve
+            .invoke(&HashMap::from([
+                (*stock.meal_id(), count(2)),
+                (untracked_meal_id, count(100)),
+            ]))
+            .await;
+
+        assert!(result.is_ok());
+        let saved = persister.lock().await.saved.clone();
+        assert_eq!(saved.len(), 1);
+        assert_eq!(saved[0].meal_id(), stock.meal_id());
+        assert_eq!(saved[0].available(), &count(1));
+    }
+
+    #[tokio::test]
+    async fn nothing_reserved_if_one_meal_unavailable() {
+        let available = MealStock::create(rnd_meal_id(), count(3));
+        let sold_out = MealStock::create(rnd_meal_id(), count(1));
+        let persister = AM::new_am(MockMealStockPersister::default());
+        let mut reserve = ReserveMealPortionsUsingStock::new(
+            AM::new_am(MockMealStockExtractor::new(vec![
+                available.clone(),
+                sold_out.clone(),
+            ])),
+            persister.clone(),
+        );
+
+        let result = reserve
+            .invoke(&HashMap::from([
+                (*available.meal_id(), count(1)),
+                (*sold_out.meal_id(), count(2)),
+            ]))
+            .await;
+
+        assert_eq!(result, Err(MealUnavailable(*sold_out.meal_id())));
+        persister.lock().await.verify_empty();
+    }
+}
@@ -0,0 +1,111 @@
+use std::{
+    mem::{Discriminant, discriminant},
+    sync::Arc,
+};
+
+use async_trait::async_trait;
+use common::events::DomainEventListener;
+use domain::order::customer_order_events::{
+    ShopOrderCancelledDomainEvent, ShopOrderCompletedDomainEvent, ShopOrderConfirmedDomainEvent,
+    ShopOrderCreatedDomainEvent, ShopOrderEventEnum, ShopOrderPaidDomainEvent,
+    ShopOrderReadyForPickupDomainEvent,
+};
+
+use crate::order::order_event_feed::OrderEventFeed;
+
+/// Forwards one kind of order events to the live feed, so it has to be registered for each event
+/// the feed shows
+#[derive(Debug)]
+pub struct BroadcastOrderEventsRule {
+    feed: Arc<OrderEventFeed>,
+    event_type: Discriminant<ShopOrderEventEnum>,
+}
+
+impl BroadcastOrderEventsRule {
+    fn for_event(feed: Arc<OrderEventFeed>, event: ShopOrderEventEnum) -> Self {
+        Self {
+            feed,
+            event_type: discriminant(&event),
+        }
+    }
+
+    pub fn after_order_created(feed: Arc<OrderEventFeed>) -> Self {
+        Self::for_event(feed, ShopOrderCreatedDomainEvent::default().into())
+    }
+
+    pub fn after_order_paid(feed: Arc<OrderEventFeed>) -> Self {
+        Self::for_event(feed, ShopOrderPaidDomainEvent::default().into())
+    }
+
+    pub fn after_order_confirmed(feed: Arc<OrderEventFeed>) -> Self {
+        Self::for_event(feed, ShopOrderConfirmedDomainEvent::default().into())
+    }
+
+    pub fn after_order_ready_for_pickup(feed: Arc<OrderEventFeed>) -> Self {
+        Self::for_event(feed, ShopOrderReadyForPickupDomainEvent::default().into())
+    }
+
+    pub fn after_order_completed(feed: Arc<OrderEventFeed>) -> Self {
+        Self::for_event(feed, ShopOrderCompletedDomainEvent::default().into())
+    }
+
+    pub fn after_order_cancelled(feed: Arc<OrderEventFeed>) -> Self {
+        Self::for_event(feed, ShopOrderCancelledDomainEvent::default().into())
+    }
+
+    /// A rule for each state change of the order
+    pub fn for_all_events(feed: Arc<OrderEventFeed>) -> Vec<Self> {
+        vec![
+            Self::after_order_created(feed.clone()),
+            Self::after_order_paid(feed.clone()),
+            Self::after_order_confirmed(feed.clone()),
+            Self::after_order_ready_for_pickup(feed.clone()),
+            Self::after_order_completed(feed.clone()),
+            Self::after_order_cancelled(feed),
+        ]
+    }
+}
+
+#[async_trait]
+impl DomainEventListener<ShopOrderEventEnum> for BroadcastOrderEventsRule {
+    fn event_type(&self) -> Discriminant<ShopOrderEventEnum> {
+        self.event_type
+    }
+
+    async fn handle(&mut self, event: &ShopOrderEventEnum) {
+        self.feed.publish(event);
+    }
+
+    fn get_events(&self) -> &Vec<ShopOrderEventEnum> {
+        todo!()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::test_fixtures::*;
+
+    use super::*;
+
+    #[tokio::test]
+    async fn event_is_published_to_feed() {
+        let feed = Arc::new(OrderEventFeed::default());
+        let mut subscriber = feed.subscribe();
+        let mut rule = BroadcastOrderEventsRule::after_order_paid(feed);
+
+        let event: ShopOrderEventEnum = ShopOrderPaidDomainEvent::new(rnd_order_id()).into();
+        assert_eq!(rule.event_type(), discriminant(&event));
+        rule.handle(&event).await;
+
+        assert_eq!(subscriber.recv().await.unwrap(), event);
+    }
+
+    #[test]
+    fn every_event_is_covered() {
+        let rules = BroadcastOrderEventsRule::for_all_events(Arc::new(OrderEventFeed::default()));
+
+        let event_types: std::collections::HashSet<_> =
+            rules.iter().map(|rule| rule.event_type()).collect();
+        assert_eq!(event_types.len(), 6);
+    }
+}
@@ -4,6 +4,7 @@ use async_trait::async_trait;
 use common::{events::DomainEventListener, types::base::AM};
 use derive_new::new;
 use domain::order::customer_order_events::{ShopOrderCreatedDomainEvent, ShopOrderEventEnum};
+use tracing::error;
 
 use crate::order::providers::order_exporter::OrderExporter;
 
@@ -25,11 +26,22 @@ impl<OExported: OrderExporter> DomainEventListener<ShopOrderEventEnum>
         let event_struct: ShopOrderCreatedDomainEvent =
             event.clone().try_into().expect("Wrong type of event");
 
-        self.order_exporter.lock().await.export_order(
-            event_struct.order_id,
-            event_struct.for_customer,
-            event_struct.total_price,
-        );
+        let result = self
+            .order_exporter
+            .lock()
+            .await
+            .export_order(
+                event_struct.order_id,
+                event_struct.for_customer,
+                event_struct.total_price,
+            )
+            .await;
+        if let Err(e) = result {
+            error!(
+                "Order #{} is not exported: {e}",
+                event_struct.order_id.to_i64()
+            );
+        }
     }
 
     fn get_events(&self) -> &Vec<ShopOrderEventEnum> {
@@ -41,9 +53,12 @@ impl<OExported: OrderExporter> DomainEventListener<ShopOrderEventEnum>
 mod tests {
     use common::types::base::AMTrait;
     use domain::test_fixtures::*;
+    use tracing_test::traced_test;
 
     use super::*;
-    use crate::test_fixtures::MockOrderExporter;
+    use crate::{
+        order::providers::order_exporter::OrderExportError, test_fixtures::MockOrderExporter,
+    };
 
     #[tokio::test]
     async fn order_has_been_exported() {
@@ -54,8 +69,14 @@ mod tests {
         let exporter = AM::new_am(MockOrderExporter::default());
         let mut rule = ExportOrderAfterCheckoutRule::new(exporter.clone());
 
-        let event: ShopOrderEventEnum =
-            ShopOrderCreatedDomainEvent::new(order_id, customer_id, total_price.clone()).into();
+        let event: ShopOrderEventEnum = ShopOrderCreatedDomainEvent::new(
+            order_id,
+            customer_id,
+            total_price.clone(),
+            rnd_fulfilment(),
+            None,
+        )
+        .into();
 
         rule.handle(&event).await;
 
@@ -64,4 +85,32 @@ mod tests {
             .await
             .verify_invoked(order_id, customer_id, total_price);
     }
+
+    #[tokio::test]
+    #[traced_test]
+    async fn export_failure_is_logged() {
+        let order_id = rnd_order_id();
+        let exporter = AM::new_am(MockOrderExporter::default());
+        exporter.lock().await.response = Err(OrderExportError::ExportFailed(
+            "Connection refused".to_string(),
+        ));
+        let mut rule = ExportOrderAfterCheckoutRule::new(exporter.clone());
+
+        let event: ShopOrderEventEnum = ShopOrderCreatedDomainEvent::new(
+            order_id,
+            rnd_customer_id(),
+            rnd_price(),
+            rnd_fulfilment(),
+            None,
+        )
+        .into();
+
+        rule.handle(&event).await;
+
+        assert_eq!(exporter.lock().await.id, order_id);
+        assert!(logs_contain(&format!(
+            "Order #{} is not exported: Order export failed: Connection refused",
+            order_id.to_i64()
+        )));
+    }
 }
@@ -1 +1,2 @@
+pub mod broadcast_order_events_rule;
 pub mod export_order_after_checkout_rule;
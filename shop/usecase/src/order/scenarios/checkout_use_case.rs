@@ -2,59 +2,112 @@ use async_trait::async_trait;
 use common::types::base::AM;
 use derive_new::new;
 use domain::order::{
-    customer_has_active_order::CustomerHasActiveOrder, get_meal_price::GetMealPrice,
-    shop_order::ShopOrder, value_objects::shop_order_id::ShopOrderIdGenerator,
+    address_in_delivery_zone::AddressInDeliveryZone,
+    check_delivery_slot::CheckDeliverySlot,
+    customer_has_active_order::CustomerHasActiveOrder,
+    get_delivery_fee::GetDeliveryFee,
+    get_meal_price::GetMealPrice,
+    meal_available_at::MealAvailableAt,
+    reserve_meal_portions::ReserveMealPortions,
+    shop_order::ShopOrder,
+    value_objects::{fulfilment::Fulfilment, shop_order_id::ShopOrderIdGenerator},
 };
 
 use crate::{
     cart::access::cart_extractor::CartExtractor,
+    customer::access::customer_extractor::CustomerExtractor,
     order::{
         access::shop_order_persister::ShopOrderPersister,
-        checkout::{Checkout, CheckoutRequest, CheckoutUseCaseError, PaymentInfo},
+        checkout::{
+            Checkout, CheckoutFulfilment, CheckoutRequest, CheckoutUseCaseError, PaymentInfo,
+        },
         providers::payment_url_provider::PaymentUrlProvider,
     },
 };
 
 #[derive(new, Debug)]
+#[allow(clippy::too_many_arguments)]
 pub struct CheckoutUseCase<
     ShOIdGenerator,
     CExtractor,
+    CustExtractor,
     CustomerHasActiveO,
     GetMPrice,
+    MAvailableAt,
+    ReserveMPortions,
+    GetDFee,
+    CheckDSlot,
+    AddrInDZone,
     PaymUrlProvider,
     ShOPersister,
 > where
     ShOIdGenerator: ShopOrderIdGenerator,
     CExtractor: CartExtractor,
+    CustExtractor: CustomerExtractor,
     CustomerHasActiveO: CustomerHasActiveOrder,
     GetMPrice: GetMealPrice,
+    MAvailableAt: MealAvailableAt,
+    ReserveMPortions: ReserveMealPortions,
+    GetDFee: GetDeliveryFee,
+    CheckDSlot: CheckDeliverySlot,
+    AddrInDZone: AddressInDeliveryZone,
     PaymUrlProvider: PaymentUrlProvider,
     ShOPersister: ShopOrderPersister,
 {
     id_generator: AM<ShOIdGenerator>,
     cart_extractor: AM<CExtractor>,
+    customer_extractor: AM<CustExtractor>,
     active_order: AM<CustomerHasActiveO>,
     get_meal_price: AM<GetMPrice>,
+    meal_available_at: AM<MAvailableAt>,
+    reserve_meal_portions: AM<ReserveMPortions>,
+    get_delivery_fee: AM<GetDFee>,
+    check_delivery_slot: AM<CheckDSlot>,
+    address_in_delivery_zone: AM<AddrInDZone>,
     payment_url_provider: AM<PaymUrlProvider>,
     shop_order_persister: AM<ShOPersister>,
 }
 
 #[async_trait]
-impl<ShOIdGenerator, CExtractor, CustomerHasActiveO, GetMPrice, PaymUrlProvider, ShOPersister>
-    Checkout
+impl<
+    ShOIdGenerator,
+    CExtractor,
+    CustExtractor,
+    CustomerHasActiveO,
+    GetMPrice,
+    MAvailableAt,
+    ReserveMPortions,
+    GetDFee,
+    CheckDSlot,
+    AddrInDZone,
+    PaymUrlProvider,
+    ShOPersister,
+> Checkout
     for CheckoutUseCase<
         ShOIdGenerator,
         CExtractor,
+        CustExtractor,
         CustomerHasActiveO,
         GetMPrice,
+        MAvailableAt,
+        ReserveMPortions,
+        GetDFee,
+        CheckDSlot,
+        AddrInDZone,
         PaymUrlProvider,
         ShOPersister,
     >
 where
     ShOIdGenerator: ShopOrderIdGenerator + 'static,
     CExtractor: CartExtractor,
+    CustExtractor: CustomerExtractor,
     CustomerHasActiveO: CustomerHasActiveOrder + 'static,
     GetMPrice: GetMealPrice + 'static,
+    MAvailableAt: MealAvailableAt + 'static,
+    ReserveMPortions: ReserveMealPortions + 'static,
+    GetDFee: GetDeliveryFee + 'static,
+    CheckDSlot: CheckDeliverySlot + 'static,
+    AddrInDZone: AddressInDeliveryZone + 'static,
     PaymUrlProvider: PaymentUrlProvider,
     ShOPersister: ShopOrderPersister,
 {
@@ -71,13 +124,37 @@ where
             .await
             .ok_or(CheckoutUseCaseError::CartNotFound)?;
 
+        let fulfilment = match &request.fulfilment {
+            CheckoutFulfilment::Delivery(address) => Fulfilment::Delivery(address.clone()),
+            CheckoutFulfilment::Pickup(pickup_point_id) => Fulfilment::Pickup(*pickup_point_id),
+            CheckoutFulfilment::SavedAddress(address_id) => {
+                let customer = self
+                    .customer_extractor
+                    .lock()
+                    .await
+                    .get_by_id(&request.for_customer)
+                    .filter(|customer| customer.visible())
+                    .ok_or(CheckoutUseCaseError::CustomerNotFound)?;
+                let address = customer
+                    .saved_address(address_id)
+                    .ok_or(CheckoutUseCaseError::SavedAddressNotFound)?;
+                Fulfilment::Delivery(address.clone())
+            }
+        };
+
         // Create shop order
         let order = ShopOrder::checkout(
             cart,
             self.id_generator.clone(),
             self.active_order.clone(),
-            request.delivery_to.clone(),
+            fulfilment,
+            request.delivery_slot,
             self.get_meal_price.clone(),
+            self.meal_available_at.clone(),
+            self.reserve_meal_portions.clone(),
+            self.get_delivery_fee.clone(),
+            self.check_delivery_slot.clone(),
+            self.address_in_delivery_zone.clone(),
         )
         .await?;
 
@@ -112,21 +189,26 @@ mod tests {
     };
     use domain::{
         cart::value_objects::customer_id::CustomerId,
+        customer::value_objects::saved_address::SavedAddressId,
         menu::value_objects::{meal_id::MealId, price::Price},
-        order::value_objects::shop_order_id::ShopOrderId,
+        order::{
+            check_delivery_slot::DeliverySlotError, value_objects::shop_order_id::ShopOrderId,
+        },
         test_fixtures::*,
     };
     use smart_default::SmartDefault;
 
     use super::*;
     use crate::test_fixtures::{
-        MockCartExtractor, MockCustomerHasActiveOrder, MockShopOrderPersister,
+        MockAddressInDeliveryZone, MockCartExtractor, MockCheckDeliverySlot, MockCustomerExtractor,
+        MockCustomerHasActiveOrder, MockMealAvailableAt, MockReserveMealPortions,
+        MockShopOrderPersister,
     };
 
     #[tokio::test]
     async fn order_created_successfully() {
         let meal = rnd_meal();
-        let address = rnd_address();
+        let fulfilment = Fulfilment::Delivery(rnd_address());
         let count = rnd_count();
         let customer_id = rnd_customer_id();
         let cart =
@@ -142,18 +224,26 @@ mod tests {
 
         let price = rnd_price();
         let get_meal_price = AM::new_am(MockGetMealPrice::new(price.clone()));
+        let delivery_fee = rnd_price();
+        let get_delivery_fee = AM::new_am(MockGetDeliveryFee::new(delivery_fee.clone()));
         let payment_url_provider = AM::new_am(TestPaymentUrlProvider::new());
 
         let use_case = CheckoutUseCase::new(
             id_generator.clone(),
             cart_extractor.clone(),
+            AM::new_am(MockCustomerExtractor::new(None)),
             active_order_rule.clone(),
             get_meal_price.clone(),
+            AM::new_am(MockMealAvailableAt::default()),
+            AM::new_am(MockReserveMealPortions::default()),
+            get_delivery_fee.clone(),
+            AM::new_am(MockCheckDeliverySlot::default()),
+            AM::new_am(MockAddressInDeliveryZone::new(true)),
             payment_url_provider.clone(),
             order_persister.clone(),
         );
 
-        let checkout_request = checkout_request(address.clone(), customer_id);
+        let checkout_request = CheckoutRequest::new(customer_id, fulfilment.clone().into(), None);
         let result = use_case.execute(&checkout_request).await;
 
         let order_id = id_generator.lock().await.id;
@@ -168,7 +258,7 @@ mod tests {
             .verify_invoked(cart.for_customer());
         order_persister.lock().await.verify_invoked(
             &order_id,
-            &address,
+            &fulfilment,
             &customer_id,
             meal.id(),
             &count,
@@ -182,6 +272,442 @@ mod tests {
             payment_url_provider.lock().await.payment_url
         );
         order_persister.lock().await.verify_price(&result.price);
+        assert_eq!(result.price, price.multiple(count).add(delivery_fee));
+    }
+
+    #[tokio::test]
+    async fn pickup_order_created_without_address() {
+        let meal = rnd_meal();
+        let fulfilment = Fulfilment::Pickup(rnd_pickup_point_id());
+        let count = rnd_count();
+        let customer_id = rnd_customer_id();
+        let cart =
+            rnd_cart_with_customer_id_and_meals(customer_id, HashMap::from([(*meal.id(), count)]));
+
+        let id_generator = AM::new_am(TestShopOrderIdGenerator::default());
+
+        let cart_extractor = AM::new_am(MockCartExtractor::default());
+        cart_extractor.lock().await.cart = Some(cart.clone());
+
+        let active_order_rule = AM::new_am(MockCustomerHasActiveOrder::new(false));
+        let order_persister = AM::new_am(MockShopOrderPersister::default());
+
+        let price = rnd_price();
+        let get_meal_price = AM::new_am(MockGetMealPrice::new(price.clone()));
+        let get_delivery_fee = AM::new_am(MockGetDeliveryFee::new(rnd_price()));
+        let payment_url_provider = AM::new_am(TestPaymentUrlProvider::new());
+
+        let use_case = CheckoutUseCase::new(
+            id_generator.clone(),
+            cart_extractor.clone(),
+            AM::new_am(MockCustomerExtractor::new(None)),
+            active_order_rule.clone(),
+            get_meal_price.clone(),
+            AM::new_am(MockMealAvailableAt::default()),
+            AM::new_am(MockReserveMealPortions::default()),
+            get_delivery_fee.clone(),
+            AM::new_am(MockCheckDeliverySlot::default()),
+            AM::new_am(MockAddressInDeliveryZone::new(true)),
+            payment_url_provider.clone(),
+            order_persister.clone(),
+        );
+
+        let result = use_case
+            .execute(&CheckoutRequest::new(
+                customer_id,
+                fulfilment.clone().into(),
+                None,
+            ))
+            .await;
+
+        let order_id = id_generator.lock().await.id;
+        order_persister.lock().await.verify_invoked(
+            &order_id,
+            &fulfilment,
+            &customer_id,
+            meal.id(),
+            &count,
+            &price,
+        );
+        assert!(result.is_ok());
+        assert_eq!(result.unwrap().price, price.multiple(count));
+    }
+
+    #[tokio::test]
+    async fn scheduled_order_created_successfully() {
+        let meal = rnd_meal();
+        let customer_id = rnd_customer_id();
+        let cart = rnd_cart_with_customer_id_and_meals(
+            customer_id,
+            HashMap::from([(*meal.id(), rnd_count())]),
+        );
+        let delivery_slot = rnd_delivery_slot();
+
+        let cart_extractor = AM::new_am(MockCartExtractor::default());
+        cart_extractor.lock().await.cart = Some(cart.clone());
+
+        let check_delivery_slot = AM::new_am(MockCheckDeliverySlot::default());
+        let order_persister = AM::new_am(MockShopOrderPersister::default());
+
+        let use_case = CheckoutUseCase::new(
+            AM::new_am(TestShopOrderIdGenerator::default()),
+            cart_extractor.clone(),
+            AM::new_am(MockCustomerExtractor::new(None)),
+            AM::new_am(MockCustomerHasActiveOrder::new(false)),
+            AM::new_am(MockGetMealPrice::new(rnd_price())),
+            AM::new_am(MockMealAvailableAt::default()),
+            AM::new_am(MockReserveMealPortions::default()),
+            AM::new_am(MockGetDeliveryFee::new(rnd_price())),
+            check_delivery_slot.clone(),
+            AM::new_am(MockAddressInDeliveryZone::new(true)),
+            AM::new_am(TestPaymentUrlProvider::new()),
+            order_persister.clone(),
+        );
+
+        let result = use_case
+            .execute(&CheckoutRequest::new(
+                customer_id,
+                rnd_fulfilment().into(),
+                Some(delivery_slot),
+            ))
+            .await;
+
+        assert!(result.is_ok());
+        check_delivery_slot
+            .lock()
+            .await
+            .verify_invoked(&delivery_slot);
+        let order = order_persister.lock().await.order.clone().unwrap();
+        assert_eq!(order.delivery_slot(), &Some(delivery_slot));
+    }
+
+    #[tokio::test]
+    async fn delivery_slot_unavailable() {
+        let meal = rnd_meal();
+        let customer_id = rnd_customer_id();
+        let cart = rnd_cart_with_customer_id_and_meals(
+            customer_id,
+            HashMap::from([(*meal.id(), rnd_count())]),
+        );
+        let delivery_slot = rnd_delivery_slot();
+
+        let cart_extractor = AM::new_am(MockCartExtractor::default());
+        cart_extractor.lock().await.cart = Some(cart.clone());
+
+        let check_delivery_slot = AM::new_am(MockCheckDeliverySlot::new(Some(
+            DeliverySlotError::OutsideOpeningHours,
+        )));
+        let order_persister = AM::new_am(MockShopOrderPersister::default());
+
+        let use_case = CheckoutUseCase::new(
+            AM::new_am(TestShopOrderIdGenerator::default()),
+            cart_extractor.clone(),
+            AM::new_am(MockCustomerExtractor::new(None)),
+            AM::new_am(MockCustomerHasActiveOrder::new(false)),
+            AM::new_am(MockGetMealPrice::new(rnd_price())),
+            AM::new_am(MockMealAvailableAt::default()),
+            AM::new_am(MockReserveMealPortions::default()),
+            AM::new_am(MockGetDeliveryFee::new(rnd_price())),
+            check_delivery_slot.clone(),
+            AM::new_am(MockAddressInDeliveryZone::new(true)),
+            AM::new_am(TestPaymentUrlProvider::new()),
+            order_persister.clone(),
+        );
+
+        let result = use_case
+            .execute(&CheckoutRequest::new(
+                customer_id,
+                rnd_fulfilment().into(),
+                Some(delivery_slot),
+            ))
+            .await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            CheckoutUseCaseError::DeliverySlotOutsideOpeningHours
+        );
+        check_delivery_slot
+            .lock()
+            .await
+            .verify_invoked(&delivery_slot);
+        order_persister.lock().await.verify_empty();
+    }
+
+    #[tokio::test]
+    async fn address_outside_delivery_zone() {
+        let meal = rnd_meal();
+        let customer_id = rnd_customer_id();
+        let cart = rnd_cart_with_customer_id_and_meals(
+            customer_id,
+            HashMap::from([(*meal.id(), rnd_count())]),
+        );
+        let address = rnd_address();
+
+        let cart_extractor = AM::new_am(MockCartExtractor::default());
+        cart_extractor.lock().await.cart = Some(cart.clone());
+
+        let address_in_delivery_zone = AM::new_am(MockAddressInDeliveryZone::new(false));
+        let order_persister = AM::new_am(MockShopOrderPersister::default());
+
+        let use_case = CheckoutUseCase::new(
+            AM::new_am(TestShopOrderIdGenerator::default()),
+            cart_extractor.clone(),
+            AM::new_am(MockCustomerExtractor::new(None)),
+            AM::new_am(MockCustomerHasActiveOrder::new(false)),
+            AM::new_am(MockGetMealPrice::new(rnd_price())),
+            AM::new_am(MockMealAvailableAt::default()),
+            AM::new_am(MockReserveMealPortions::default()),
+            AM::new_am(MockGetDeliveryFee::new(rnd_price())),
+            AM::new_am(MockCheckDeliverySlot::default()),
+            address_in_delivery_zone.clone(),
+            AM::new_am(TestPaymentUrlProvider::new()),
+            order_persister.clone(),
+        );
+
+        let result = use_case
+            .execute(&checkout_request(address.clone(), customer_id))
+            .await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            CheckoutUseCaseError::AddressOutsideDeliveryZone
+        );
+        address_in_delivery_zone
+            .lock()
+            .await
+            .verify_invoked(&address);
+        order_persister.lock().await.verify_empty();
+    }
+
+    #[tokio::test]
+    async fn order_created_with_saved_address() {
+        let meal = rnd_meal();
+        let mut customer = rnd_customer();
+        let address = rnd_address();
+        let address_id = customer.add_address(address.clone());
+        let customer_id = *customer.id();
+        let cart = rnd_cart_with_customer_id_and_meals(
+            customer_id,
+            HashMap::from([(*meal.id(), rnd_count())]),
+        );
+
+        let cart_extractor = AM::new_am(MockCartExtractor::default());
+        cart_extractor.lock().await.cart = Some(cart.clone());
+
+        let customer_extractor = AM::new_am(MockCustomerExtractor::new(Some(customer)));
+        let address_in_delivery_zone = AM::new_am(MockAddressInDeliveryZone::new(true));
+        let order_persister = AM::new_am(MockShopOrderPersister::default());
+
+        let use_case = CheckoutUseCase::new(
+            AM::new_am(TestShopOrderIdGenerator::default()),
+            cart_extractor.clone(),
+            customer_extractor.clone(),
+            AM::new_am(MockCustomerHasActiveOrder::new(false)),
+            AM::new_am(MockGetMealPrice::new(rnd_price())),
+            AM::new_am(MockMealAvailableAt::default()),
+            AM::new_am(MockReserveMealPortions::default()),
+            AM::new_am(MockGetDeliveryFee::new(rnd_price())),
+            AM::new_am(MockCheckDeliverySlot::default()),
+            address_in_delivery_zone.clone(),
+            AM::new_am(TestPaymentUrlProvider::new()),
+            order_persister.clone(),
+        );
+
+        let result = use_case
+            .execute(&CheckoutRequest::new(
+                customer_id,
+                CheckoutFulfilment::SavedAddress(address_id),
+                None,
+            ))
+            .await;
+
+        assert!(result.is_ok());
+        customer_extractor
+            .lock()
+            .await
+            .verify_invoked_get_by_id(&customer_id);
+        address_in_delivery_zone
+            .lock()
+            .await
+            .verify_invoked(&address);
+        let order = order_persister.lock().await.order.clone().unwrap();
+        assert_eq!(order.fulfilment(), &Fulfilment::Delivery(address));
+    }
+
+    #[tokio::test]
+    async fn customer_for_saved_address_not_found() {
+        let meal = rnd_meal();
+        let customer_id = rnd_customer_id();
+        let cart = rnd_cart_with_customer_id_and_meals(
+            customer_id,
+            HashMap::from([(*meal.id(), rnd_count())]),
+        );
+
+        let cart_extractor = AM::new_am(MockCartExtractor::default());
+        cart_extractor.lock().await.cart = Some(cart.clone());
+
+        let customer_extractor = AM::new_am(MockCustomerExtractor::new(None));
+        let order_persister = AM::new_am(MockShopOrderPersister::default());
+
+        let use_case = CheckoutUseCase::new(
+            AM::new_am(TestShopOrderIdGenerator::default()),
+            cart_extractor.clone(),
+            customer_extractor.clone(),
+            AM::new_am(MockCustomerHasActiveOrder::new(false)),
+            AM::new_am(MockGetMealPrice::new(rnd_price())),
+            AM::new_am(MockMealAvailableAt::default()),
+            AM::new_am(MockReserveMealPortions::default()),
+            AM::new_am(MockGetDeliveryFee::new(rnd_price())),
+            AM::new_am(MockCheckDeliverySlot::default()),
+            AM::new_am(MockAddressInDeliveryZone::new(true)),
+            AM::new_am(TestPaymentUrlProvider::new()),
+            order_persister.clone(),
+        );
+
+        let result = use_case
+            .execute(&CheckoutRequest::new(
+                customer_id,
+                CheckoutFulfilment::SavedAddress(SavedAddressId::new()),
+                None,
+            ))
+            .await;
+
+        assert_eq!(result.unwrap_err(), CheckoutUseCaseError::CustomerNotFound);
+        customer_extractor
+            .lock()
+            .await
+            .verify_invoked_get_by_id(&customer_id);
+        order_persister.lock().await.verify_empty();
+    }
+
+    #[tokio::test]
+    async fn saved_address_not_found() {
+        let meal = rnd_meal();
+        let customer = rnd_customer();
+        let customer_id = *customer.id();
+        let cart = rnd_cart_with_customer_id_and_meals(
+            customer_id,
+            HashMap::from([(*meal.id(), rnd_count())]),
+        );
+
+        let cart_extractor = AM::new_am(MockCartExtractor::default());
+        cart_extractor.lock().await.cart = Some(cart.clone());
+
+        let customer_extractor = AM::new_am(MockCustomerExtractor::new(Some(customer)));
+        let order_persister = AM::new_am(MockShopOrderPersister::default());
+
+        let use_case = CheckoutUseCase::new(
+            AM::new_am(TestShopOrderIdGenerator::default()),
+            cart_extractor.clone(),
+            customer_extractor.clone(),
+            AM::new_am(MockCustomerHasActiveOrder::new(false)),
+            AM::new_am(MockGetMealPrice::new(rnd_price())),
+            AM::new_am(MockMealAvailableAt::default()),
+            AM::new_am(MockReserveMealPortions::default()),
+            AM::new_am(MockGetDeliveryFee::new(rnd_price())),
+            AM::new_am(MockCheckDeliverySlot::default()),
+            AM::new_am(MockAddressInDeliveryZone::new(true)),
+            AM::new_am(TestPaymentUrlProvider::new()),
+            order_persister.clone(),
+        );
+
+        let result = use_case
+            .execute(&CheckoutRequest::new(
+                customer_id,
+                CheckoutFulfilment::SavedAddress(SavedAddressId::new()),
+                None,
+            ))
+            .await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            CheckoutUseCaseError::SavedAddressNotFound
+        );
+        order_persister.lock().await.verify_empty();
+    }
+
+    #[tokio::test]
+    async fn meal_sold_out() {
+        let meal = rnd_meal();
+        let count = rnd_count();
+        let customer_id = rnd_customer_id();
+        let cart =
+            rnd_cart_with_customer_id_and_meals(customer_id, HashMap::from([(*meal.id(), count)]));
+
+        let cart_extractor = AM::new_am(MockCartExtractor::default());
+        cart_extractor.lock().await.cart = Some(cart.clone());
+        let reserve_meal_portions = AM::new_am(MockReserveMealPortions::new(Some(*meal.id())));
+        let order_persister = AM::new_am(MockShopOrderPersister::default());
+
+        let use_case = CheckoutUseCase::new(
+            AM::new_am(TestShopOrderIdGenerator::default()),
+            cart_extractor.clone(),
+            AM::new_am(MockCustomerExtractor::new(None)),
+            AM::new_am(MockCustomerHasActiveOrder::new(false)),
+            AM::new_am(MockGetMealPrice::new(rnd_price())),
+            AM::new_am(MockMealAvailableAt::default()),
+            reserve_meal_portions.clone(),
+            AM::new_am(MockGetDeliveryFee::new(rnd_price())),
+            AM::new_am(MockCheckDeliverySlot::default()),
+            AM::new_am(MockAddressInDeliveryZone::new(true)),
+            AM::new_am(TestPaymentUrlProvider::new()),
+            order_persister.clone(),
+        );
+
+        let result = use_case
+            .execute(&checkout_request(rnd_address(), customer_id))
+            .await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            CheckoutUseCaseError::MealUnavailable(*meal.id())
+        );
+        reserve_meal_portions
+            .lock()
+            .await
+            .verify_invoked(&HashMap::from([(*meal.id(), count)]));
+        order_persister.lock().await.verify_empty();
+    }
+
+    #[tokio::test]
+    async fn meal_outside_availability_window() {
+        let meal = rnd_meal();
+        let customer_id = rnd_customer_id();
+        let cart = rnd_cart_with_customer_id_and_meals(
+            customer_id,
+            HashMap::from([(*meal.id(), rnd_count())]),
+        );
+
+        let cart_extractor = AM::new_am(MockCartExtractor::default());
+        cart_extractor.lock().await.cart = Some(cart.clone());
+        let reserve_meal_portions = AM::new_am(MockReserveMealPortions::default());
+        let order_persister = AM::new_am(MockShopOrderPersister::default());
+
+        let use_case = CheckoutUseCase::new(
+            AM::new_am(TestShopOrderIdGenerator::default()),
+            cart_extractor.clone(),
+            AM::new_am(MockCustomerExtractor::new(None)),
+            AM::new_am(MockCustomerHasActiveOrder::new(false)),
+            AM::new_am(MockGetMealPrice::new(rnd_price())),
+            AM::new_am(MockMealAvailableAt::new(Some(*meal.id()))),
+            reserve_meal_portions.clone(),
+            AM::new_am(MockGetDeliveryFee::new(rnd_price())),
+            AM::new_am(MockCheckDeliverySlot::default()),
+            AM::new_am(MockAddressInDeliveryZone::new(true)),
+            AM::new_am(TestPaymentUrlProvider::new()),
+            order_persister.clone(),
+        );
+
+        let result = use_case
+            .execute(&checkout_request(rnd_address(), customer_id))
+            .await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            CheckoutUseCaseError::MealOutsideAvailabilityWindow(*meal.id())
+        );
+        reserve_meal_portions.lock().await.verify_empty();
+        order_persister.lock().await.verify_empty();
     }
 
     #[tokio::test]
@@ -198,8 +724,14 @@ mod tests {
         let use_case = CheckoutUseCase::new(
             id_generator.clone(),
             cart_extractor.clone(),
+            AM::new_am(MockCustomerExtractor::new(None)),
             active_order_rule.clone(),
             get_meal_price.clone(),
+            AM::new_am(MockMealAvailableAt::default()),
+            AM::new_am(MockReserveMealPortions::default()),
+            AM::new_am(MockGetDeliveryFee::default()),
+            AM::new_am(MockCheckDeliverySlot::default()),
+            AM::new_am(MockAddressInDeliveryZone::new(true)),
             payment_url_provider.clone(),
             order_persister.clone(),
         );
@@ -238,8 +770,14 @@ mod tests {
         let use_case = CheckoutUseCase::new(
             id_generator.clone(),
             cart_extractor.clone(),
+            AM::new_am(MockCustomerExtractor::new(None)),
             active_order_rule.clone(),
             get_meal_price.clone(),
+            AM::new_am(MockMealAvailableAt::default()),
+            AM::new_am(MockReserveMealPortions::default()),
+            AM::new_am(MockGetDeliveryFee::default()),
+            AM::new_am(MockCheckDeliverySlot::default()),
+            AM::new_am(MockAddressInDeliveryZone::new(true)),
             payment_url_provider.clone(),
             order_persister.clone(),
         );
@@ -278,8 +816,14 @@ mod tests {
         let use_case = CheckoutUseCase::new(
             id_generator.clone(),
             cart_extractor.clone(),
+            AM::new_am(MockCustomerExtractor::new(None)),
             active_order_rule.clone(),
             get_meal_price.clone(),
+            AM::new_am(MockMealAvailableAt::default()),
+            AM::new_am(MockReserveMealPortions::default()),
+            AM::new_am(MockGetDeliveryFee::default()),
+            AM::new_am(MockCheckDeliverySlot::default()),
+            AM::new_am(MockAddressInDeliveryZone::new(true)),
             payment_url_provider.clone(),
             order_persister.clone(),
         );
@@ -321,6 +865,18 @@ mod tests {
         }
     }
 
+    #[derive(new, Debug, Default)]
+    struct MockGetDeliveryFee {
+        fee: Price,
+    }
+
+    #[async_trait]
+    impl GetDeliveryFee for MockGetDeliveryFee {
+        async fn invoke(&self, _: &Address) -> Price {
+            self.fee.clone()
+        }
+    }
+
     #[derive(new, Debug)]
     struct TestPaymentUrlProvider {
         #[new(value = "\"http://localhost/\".to_string()")]
@@ -334,14 +890,6 @@ mod tests {
     }
 
     fn checkout_request(address: Address, customer_id: CustomerId) -> CheckoutRequest {
-        match Address::try_from((
-            address.street_to_string().as_str(),
-            address.building_to_i16(),
-        ))
-        .map(|addr| CheckoutRequest::new(customer_id, addr))
-        {
-            Ok(request) => request,
-            Err(_) => panic!("Illegal State Exception"),
-        }
+        CheckoutRequest::new(customer_id, CheckoutFulfilment::Delivery(address), None)
     }
 }
@@ -0,0 +1,73 @@
+use async_trait::async_trait;
+use common::types::base::AM;
+use derive_new::new;
+use domain::order::shop_order::OrderState;
+
+use crate::order::{
+    access::shop_order_extractor::ShopOrderExtractor,
+    dto::order_details::{AsDetails, OrderDetails},
+    get_active_orders::GetActiveOrders,
+};
+
+#[derive(new, Debug)]
+pub struct GetActiveOrdersUseCase<ShOExtractor: ShopOrderExtractor> {
+    shop_order_extractor: AM<ShOExtractor>,
+}
+
+#[async_trait]
+impl<ShOExtractor: ShopOrderExtractor> GetActiveOrders for GetActiveOrdersUseCase<ShOExtractor> {
+    async fn execute(&mut self) -> Vec<OrderDetails> {
+        self.shop_order_extractor
+            .lock()
+            .await
+            .get_all_active()
+            .iter()
+            // The kitchen can't act on an order before it's paid
+            .filter(|order| !matches!(order.state(), OrderState::WaitingForPayment(_)))
+            .map(|order| order.as_details())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::types::base::AMTrait;
+    use domain::{order::shop_order::OrderState, test_fixtures::*};
+    use tokio::test;
+
+    use super::*;
+    use crate::test_fixtures::MockShopOrderExtractor;
+
+    #[test]
+    async fn active_orders_returned() {
+        let order = order_with_state(OrderState::new_paid());
+
+        let extractor = AM::new_am(MockShopOrderExtractor::default());
+        extractor.lock().await.order = Some(order.clone());
+        let mut use_case = GetActiveOrdersUseCase::new(extractor.clone());
+
+        assert_eq!(use_case.execute().await, vec![order.as_details()]);
+        extractor.lock().await.verify_invoked_get_all_active();
+    }
+
+    #[test]
+    async fn unpaid_orders_are_not_returned() {
+        let extractor = AM::new_am(MockShopOrderExtractor::default());
+        extractor.lock().await.order =
+            Some(order_with_state(OrderState::new_waiting_for_payment()));
+        let mut use_case = GetActiveOrdersUseCase::new(extractor.clone());
+
+        assert!(use_case.execute().await.is_empty());
+        extractor.lock().await.verify_invoked_get_all_active();
+    }
+
+    #[test]
+    async fn finished_orders_are_not_returned() {
+        let extractor = AM::new_am(MockShopOrderExtractor::default());
+        extractor.lock().await.order = Some(order_with_state(OrderState::new_completed()));
+        let mut use_case = GetActiveOrdersUseCase::new(extractor.clone());
+
+        assert!(use_case.execute().await.is_empty());
+        extractor.lock().await.verify_invoked_get_all_active();
+    }
+}
@@ -0,0 +1,126 @@
+use async_trait::async_trait;
+use common::types::base::AM;
+use derive_new::new;
+use domain::order::value_objects::opening_hours::OpeningHours;
+use time::Date;
+
+use crate::order::{
+    access::shop_order_extractor::ShopOrderExtractor,
+    dto::available_delivery_slot::AvailableDeliverySlot,
+    get_available_delivery_slots::GetAvailableDeliverySlots,
+};
+
+#[derive(new, Debug)]
+pub struct GetAvailableDeliverySlotsUseCase {
+    shop_order_extractor: AM<dyn ShopOrderExtractor>,
+    opening_hours: OpeningHours,
+    slot_capacity: usize,
+}
+
+#[async_trait]
+impl GetAvailableDeliverySlots for GetAvailableDeliverySlotsUseCase {
+    async fn execute(&mut self, date: Date) -> Vec<AvailableDeliverySlot> {
+        let mut extractor = self.shop_order_extractor.lock().await;
+        self.opening_hours
+            .slots_on(date)
+            .into_iter()
+            .filter(|slot| !slot.has_started())
+            .filter_map(|slot| {
+                let booked = extractor.count_active_by_delivery_slot(&slot);
+                (booked < self.slot_capacity)
+                    .then(|| AvailableDeliverySlot::new(slot, self.slot_capacity - booked))
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::types::base::AMTrait;
+    use domain::{order::shop_order::OrderState, test_fixtures::*};
+    use time::{Duration, OffsetDateTime, Time};
+    use tokio::test;
+
+    use super::*;
+    use crate::test_fixtures::MockShopOrderExtractor;
+
+    fn opening_hours() -> OpeningHours {
+        OpeningHours::try_from((
+            Time::from_hms(10, 0, 0).unwrap(),
+            Time::from_hms(12, 0, 0).unwrap(),
+            Duration::hours(1),
+        ))
+        .unwrap()
+    }
+
+    fn tomorrow() -> Date {
+        (OffsetDateTime::now_utc() + Duration::days(1)).date()
+    }
+
+    #[test]
+    async fn all_slots_are_available() {
+        let extractor = AM::new_am(MockShopOrderExtractor::default());
+        let mut use_case =
+            GetAvailableDeliverySlotsUseCase::new(extractor.clone(), opening_hours(), 2);
+
+        let result = use_case.execute(tomorrow()).await;
+
+        let expected: Vec<AvailableDeliverySlot> = opening_hours()
+            .slots_on(tomorrow())
+            .into_iter()
+            .map(|slot| AvailableDeliverySlot::new(slot, 2))
+            .collect();
+        assert_eq!(result, expected);
+    }
+
+    #[test]
+    async fn booked_slot_is_not_available() {
+        let slots = opening_hours().slots_on(tomorrow());
+        let booked_slot = slots[0];
+        let extractor = AM::new_am(MockShopOrderExtractor {
+            order: Some(order_with_delivery_slot_and_state(
+                booked_slot,
+                OrderState::new_paid(),
+            )),
+            ..Default::default()
+        });
+        let mut use_case =
+            GetAvailableDeliverySlotsUseCase::new(extractor.clone(), opening_hours(), 1);
+
+        let result = use_case.execute(tomorrow()).await;
+
+        assert_eq!(result, vec![AvailableDeliverySlot::new(slots[1], 1)]);
+    }
+
+    #[test]
+    async fn cancelled_order_does_not_take_capacity() {
+        let slots = opening_hours().slots_on(tomorrow());
+        let extractor = AM::new_am(MockShopOrderExtractor {
+            order: Some(order_with_delivery_slot_and_state(
+                slots[0],
+                OrderState::new_cancelled(),
+            )),
+            ..Default::default()
+        });
+        let mut use_case =
+            GetAvailableDeliverySlotsUseCase::new(extractor.clone(), opening_hours(), 1);
+
+        let result = use_case.execute(tomorrow()).await;
+
+        assert_eq!(result.len(), 2);
+        assert_eq!(result[0], AvailableDeliverySlot::new(slots[0], 1));
+    }
+
+    #[test]
+    async fn past_day_has_no_slots() {
+        let extractor = AM::new_am(MockShopOrderExtractor::default());
+        let mut use_case =
+            GetAvailableDeliverySlotsUseCase::new(extractor.clone(), opening_hours(), 1);
+
+        let yesterday = (OffsetDateTime::now_utc() - Duration::days(1)).date();
+        let result = use_case.execute(yesterday).await;
+
+        assert!(result.is_empty());
+        extractor.lock().await.verify_empty();
+    }
+}
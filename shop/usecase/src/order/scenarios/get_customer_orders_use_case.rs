@@ -0,0 +1,116 @@
+use async_trait::async_trait;
+use common::types::base::AM;
+use derive_new::new;
+use domain::{
+    cart::value_objects::customer_id::CustomerId, order::value_objects::shop_order_id::ShopOrderId,
+};
+
+use crate::order::{
+    access::{shop_order_criteria::ShopOrderCriteria, shop_order_extractor::ShopOrderExtractor},
+    dto::order_details::{AsDetails, OrderDetails},
+    get_customer_orders::{GetCustomerOrders, GetCustomerOrdersUseCaseError},
+};
+
+#[derive(new, Debug)]
+pub struct GetCustomerOrdersUseCase<ShOExtractor: ShopOrderExtractor> {
+    shop_order_extractor: AM<ShOExtractor>,
+    limit: fn() -> usize,
+}
+
+#[async_trait]
+impl<ShOExtractor: ShopOrderExtractor> GetCustomerOrders
+    for GetCustomerOrdersUseCase<ShOExtractor>
+{
+    async fn execute(
+        &mut self,
+        for_customer: &CustomerId,
+        start_id: &ShopOrderId,
+        limit: usize,
+    ) -> Result<Vec<OrderDetails>, GetCustomerOrdersUseCaseError> {
+        let max_size = (self.limit)();
+        if max_size < limit {
+            Err(GetCustomerOrdersUseCaseError::LimitExceed(max_size))
+        } else {
+            Ok(self
+                .shop_order_extractor
+                .lock()
+                .await
+                .get_all(
+                    &ShopOrderCriteria::for_customer(*for_customer),
+                    start_id,
+                    limit,
+                )
+                .iter()
+                .map(|order| order.as_details())
+                .collect())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::types::base::AMTrait;
+    use domain::test_fixtures::*;
+    use tokio::test;
+
+    use super::*;
+    use crate::test_fixtures::MockShopOrderExtractor;
+
+    #[test]
+    async fn customer_has_no_orders() {
+        let limit: fn() -> usize = || 10;
+        let customer_id = rnd_customer_id();
+
+        let extractor = AM::new_am(MockShopOrderExtractor::default());
+        extractor.lock().await.order = Some(rnd_order(Default::default()));
+        let mut use_case = GetCustomerOrdersUseCase::new(extractor.clone(), limit);
+
+        let result = use_case
+            .execute(&customer_id, &rnd_order_id(), limit())
+            .await;
+
+        assert!(result.unwrap().is_empty());
+        extractor
+            .lock()
+            .await
+            .verify_invoked_get_all(&ShopOrderCriteria::for_customer(customer_id));
+    }
+
+    #[test]
+    async fn customer_orders_returned() {
+        let limit: fn() -> usize = || 10;
+        let order = rnd_order(Default::default());
+
+        let extractor = AM::new_am(MockShopOrderExtractor::default());
+        extractor.lock().await.order = Some(order.clone());
+        let mut use_case = GetCustomerOrdersUseCase::new(extractor.clone(), limit);
+
+        let result = use_case
+            .execute(order.for_customer(), order.id(), limit())
+            .await;
+
+        assert_eq!(result.unwrap(), vec![order.as_details()]);
+        extractor
+            .lock()
+            .await
+            .verify_invoked_get_all(&ShopOrderCriteria::for_customer(*order.for_customer()));
+    }
+
+    #[test]
+    async fn limit_exceed() {
+        let limit: fn() -> usize = || 10;
+
+        let extractor = AM::new_am(MockShopOrderExtractor::default());
+        let mut use_case = GetCustomerOrdersUseCase::new(extractor.clone(), limit);
+
+        let result = use_case
+            .execute(&rnd_customer_id(), &rnd_order_id(), limit() + 1)
+            .await;
+
+        assert_eq!(
+            result.unwrap_err(),
+            GetCustomerOrdersUseCaseError::LimitExceed(10)
+        );
+        extractor.lock().await.verify_empty();
+    }
+}
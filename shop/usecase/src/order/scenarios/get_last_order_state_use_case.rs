@@ -16,7 +16,7 @@ pub struct GetLastOrderStateUseCase {
 #[async_trait]
 impl GetLastOrderState for GetLastOrderStateUseCase {
     async fn execute(
-        &self,
+        &mut self,
         for_customer: &CustomerId,
     ) -> Result<OrderState, GetLastOrderStateUseCaseError> {
         self.shop_order_extractor
@@ -49,7 +49,7 @@ mod tests {
         let extractor = AM::new_am(MockShopOrderExtractor::default());
         extractor.lock().await.order = Some(order.clone());
 
-        let use_case = GetLastOrderStateUseCase::new(extractor.clone());
+        let mut use_case = GetLastOrderStateUseCase::new(extractor.clone());
         let result = use_case.execute(order.for_customer()).await;
 
         extractor
@@ -85,7 +85,8 @@ mod tests {
         let details = result.unwrap();
 
         assert_eq!(&details.id, order.id());
-        assert_eq!(&details.address, order.address());
+        assert_eq!(&details.fulfilment, order.fulfilment());
+        assert_eq!(&details.delivery_fee, order.delivery_fee());
         assert_eq!(&details.state, order.state());
         assert_eq!(details.total, order.total_price());
         assert_eq!(
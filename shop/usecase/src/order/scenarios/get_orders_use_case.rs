@@ -6,7 +6,7 @@ use derive_new::new;
 use domain::order::value_objects::shop_order_id::ShopOrderId;
 
 use crate::order::{
-    access::shop_order_extractor::ShopOrderExtractor,
+    access::{shop_order_criteria::ShopOrderCriteria, shop_order_extractor::ShopOrderExtractor},
     dto::order_details::{AsDetails, OrderDetails},
     get_orders::{GetOrders, GetOrdersUseCaseError},
 };
@@ -21,6 +21,7 @@ pub struct GetOrdersUseCase<ShOExtractor: ShopOrderExtractor> {
 impl<ShOExtractor: ShopOrderExtractor> GetOrders for GetOrdersUseCase<ShOExtractor> {
     async fn execute(
         &mut self,
+        criteria: &ShopOrderCriteria,
         start_id: &ShopOrderId,
         limit: usize,
     ) -> Result<Vec<OrderDetails>, GetOrdersUseCaseError> {
@@ -32,7 +33,7 @@ impl<ShOExtractor: ShopOrderExtractor> GetOrders for GetOrdersUseCase<ShOExtract
                 .shop_order_extractor
                 .lock()
                 .await
-                .get_all(start_id, max_size)
+                .get_all(criteria, start_id, limit)
                 .iter()
                 .map(|order| order.as_details())
                 .collect())
@@ -43,7 +44,7 @@ impl<ShOExtractor: ShopOrderExtractor> GetOrders for GetOrdersUseCase<ShOExtract
 #[cfg(test)]
 mod tests {
     use common::types::base::AMTrait;
-    use domain::test_fixtures::*;
+    use domain::{order::shop_order::OrderState, test_fixtures::*};
     use tokio::test;
 
     use super::*;
@@ -57,11 +58,16 @@ mod tests {
         let extractor = AM::new_am(MockShopOrderExtractor::default());
         let mut use_case = GetOrdersUseCase::new(extractor.clone(), limit);
 
-        let result = use_case.execute(&order_id, limit()).await;
+        let result = use_case
+            .execute(&ShopOrderCriteria::default(), &order_id, limit())
+            .await;
         let list = result.unwrap();
 
         assert!(list.is_empty());
-        extractor.lock().await.verify_invoked_get_all();
+        extractor
+            .lock()
+            .await
+            .verify_invoked_get_all(&ShopOrderCriteria::default());
     }
 
     #[test]
@@ -75,13 +81,37 @@ mod tests {
         extractor.lock().await.order = Some(order.clone());
 
         let mut use_case = GetOrdersUseCase::new(extractor.clone(), limit);
-        let result = use_case.execute(order_id, limit()).await;
+        let result = use_case
+            .execute(&ShopOrderCriteria::default(), order_id, limit())
+            .await;
         let list = result.unwrap();
 
-        extractor.lock().await.verify_invoked_get_all();
+        extractor
+            .lock()
+            .await
+            .verify_invoked_get_all(&ShopOrderCriteria::default());
         assert_eq!(list, vec![order.as_details()]);
     }
 
+    #[test]
+    async fn criteria_passed_to_extractor() {
+        let limit: fn() -> usize = || 10;
+        let order = order_with_state(OrderState::new_paid());
+        let criteria = ShopOrderCriteria {
+            state: Some(OrderState::new_cancelled()),
+            ..Default::default()
+        };
+
+        let extractor = AM::new_am(MockShopOrderExtractor::default());
+        extractor.lock().await.order = Some(order.clone());
+
+        let mut use_case = GetOrdersUseCase::new(extractor.clone(), limit);
+        let result = use_case.execute(&criteria, order.id(), limit()).await;
+
+        assert!(result.unwrap().is_empty());
+        extractor.lock().await.verify_invoked_get_all(&criteria);
+    }
+
     #[test]
     async fn limit_exceed() {
         let limit: fn() -> usize = || 10;
@@ -90,7 +120,9 @@ mod tests {
         let extractor = AM::new_am(MockShopOrderExtractor::default());
 
         let mut use_case = GetOrdersUseCase::new(extractor.clone(), limit);
-        let result = use_case.execute(&order_id, limit() + 1).await;
+        let result = use_case
+            .execute(&ShopOrderCriteria::default(), &order_id, limit() + 1)
+            .await;
 
         assert!(result.is_err());
 
@@ -2,16 +2,24 @@ mod cancel_order_use_case;
 mod checkout_use_case;
 mod complete_order_use_case;
 mod confirm_order_use_case;
+mod get_active_orders_use_case;
+mod get_available_delivery_slots_use_case;
+mod get_customer_orders_use_case;
 mod get_last_order_state_use_case;
 mod get_order_by_id_use_case;
 mod get_orders_use_case;
+mod mark_order_ready_for_pickup_use_case;
 mod pay_order_handler;
 
 pub use cancel_order_use_case::*;
 pub use checkout_use_case::*;
 pub use complete_order_use_case::*;
 pub use confirm_order_use_case::*;
+pub use get_active_orders_use_case::*;
+pub use get_available_delivery_slots_use_case::*;
+pub use get_customer_orders_use_case::*;
 pub use get_last_order_state_use_case::*;
 pub use get_order_by_id_use_case::*;
 pub use get_orders_use_case::*;
+pub use mark_order_ready_for_pickup_use_case::*;
 pub use pay_order_handler::*;
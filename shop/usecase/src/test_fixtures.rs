@@ -1,46 +1,94 @@
 use std::{
     any::{Any, TypeId},
-    collections::HashMap,
+    collections::{HashMap, VecDeque},
     mem::discriminant,
+    sync::Mutex,
 };
 
+use actix_web::http::Uri;
 use async_trait::async_trait;
-use common::types::common::{Address, Count};
+use common::types::{
+    base::EventId,
+    common::{Address, Count},
+};
 use derive_new::new;
 use domain::{
     cart::{
         cart::Cart,
         value_objects::{cart_id::CartId, customer_id::CustomerId},
     },
+    customer::{customer::Customer, value_objects::phone_number::PhoneNumber},
+    inventory::meal_stock::MealStock,
     menu::{
         meal::Meal,
         meal_events::MealEventEnum,
         value_objects::{
-            meal_description::MealDescription, meal_id::MealId, meal_name::MealName, price::Price,
+            allergens::Allergens, meal_description::MealDescription, meal_id::MealId,
+            meal_name::MealName, price::Price,
         },
     },
     order::{
+        address_in_delivery_zone::AddressInDeliveryZone,
+        check_delivery_slot::{CheckDeliverySlot, DeliverySlotError},
         customer_has_active_order::CustomerHasActiveOrder,
         customer_order_events::{
             ShopOrderCancelledDomainEvent, ShopOrderCompletedDomainEvent,
             ShopOrderConfirmedDomainEvent, ShopOrderEventEnum, ShopOrderPaidDomainEvent,
+            ShopOrderReadyForPickupDomainEvent,
         },
+        meal_available_at::MealAvailableAt,
+        reserve_meal_portions::{MealUnavailable, ReserveMealPortions},
         shop_order::{OrderState, ShopOrder},
-        value_objects::shop_order_id::ShopOrderId,
+        value_objects::{
+            delivery_slot::DeliverySlot, fulfilment::Fulfilment, shop_order_id::ShopOrderId,
+        },
+    },
+    test_fixtures::{
+        order_with_fulfilment_and_state, order_with_state, rnd_meal, rnd_order_id,
+        rnd_pickup_point_id, rnd_webhook_subscription_id, rnd_webhook_url,
+    },
+    webhook::{
+        value_objects::{
+            webhook_event_type::WebhookEventType, webhook_subscription_id::WebhookSubscriptionId,
+        },
+        webhook_subscription::WebhookSubscription,
     },
-    test_fixtures::{order_with_state, rnd_meal},
 };
+use smart_default::SmartDefault;
+use time::OffsetDateTime;
 
 use crate::{
     cart::access::{
         cart_extractor::CartExtractor, cart_persister::CartPersister, cart_remover::CartRemover,
     },
-    menu::access::{meal_extractor::MealExtractor, meal_persister::MealPersister},
+    customer::access::{
+        customer_extractor::CustomerExtractor, customer_persister::CustomerPersister,
+    },
+    inventory::access::{
+        meal_stock_extractor::MealStockExtractor, meal_stock_persister::MealStockPersister,
+    },
+    menu::access::{
+        meal_extractor::MealExtractor,
+        meal_persister::MealPersister,
+        meal_searcher::MealSearcher,
+        media_storage::{ImageFormat, MediaStorage, MediaStorageError},
+    },
     order::{
         access::{
-            shop_order_extractor::ShopOrderExtractor, shop_order_persister::ShopOrderPersister,
+            shop_order_criteria::ShopOrderCriteria, shop_order_extractor::ShopOrderExtractor,
+            shop_order_persister::ShopOrderPersister,
         },
-        providers::order_exporter::OrderExporter,
+        providers::order_exporter::{OrderExportError, OrderExporter},
+    },
+    webhook::{
+        access::{
+            failed_webhook_delivery_extractor::FailedWebhookDeliveryExtractor,
+            failed_webhook_delivery_persister::FailedWebhookDeliveryPersister,
+            webhook_sender::{WebhookSendError, WebhookSender},
+            webhook_subscription_extractor::WebhookSubscriptionExtractor,
+            webhook_subscription_persister::WebhookSubscriptionPersister,
+        },
+        dto::{failed_webhook_delivery::FailedWebhookDelivery, webhook_request::WebhookRequest},
     },
 };
 
@@ -82,6 +130,17 @@ pub fn order_not_ready_for_complete() -> ShopOrder {
     order_with_state(OrderState::new_cancelled())
 }
 
+pub fn order_ready_for_pickup() -> ShopOrder {
+    order_with_fulfilment_and_state(
+        Fulfilment::Pickup(rnd_pickup_point_id()),
+        OrderState::new_confirmed(),
+    )
+}
+
+pub fn order_not_ready_for_pickup() -> ShopOrder {
+    order_with_state(OrderState::new_confirmed())
+}
+
 pub fn active_order() -> ShopOrder {
     order_with_state(OrderState::new_confirmed())
 }
@@ -94,6 +153,8 @@ pub fn non_active_order() -> ShopOrder {
 pub struct MockMealPersister {
     #[new(value = "None")]
     pub meal: Option<Meal>,
+    #[new(default)]
+    pub saved: Vec<Meal>,
 }
 
 impl MockMealPersister {
@@ -143,6 +204,7 @@ impl MockMealPersister {
 #[async_trait]
 impl MealPersister for MockMealPersister {
     async fn save(&mut self, meal: Meal) {
+        self.saved.push(meal.clone());
         self.meal = Some(meal);
     }
 }
@@ -156,7 +218,7 @@ pub struct MockMealExtractor {
     #[new(default)]
     pub name: Option<MealName>,
     #[new(default)]
-    pub all: bool,
+    pub all: Option<(MealId, usize, Allergens)>,
 }
 
 impl MealExtractor for MockMealExtractor {
@@ -178,37 +240,75 @@ impl MealExtractor for MockMealExtractor {
         }
     }
 
-    fn get_all(&mut self) -> Vec<Meal> {
-        self.all = true;
-        if self.meal.is_some() {
-            vec![self.to_owned().meal.unwrap().clone()]
-        } else {
-            vec![]
-        }
+    fn get_all(
+        &mut self,
+        start_id: &MealId,
+        limit: usize,
+        excluded_allergens: &Allergens,
+    ) -> Vec<Meal> {
+        self.all = Some((*start_id, limit, excluded_allergens.clone()));
+        self.meal
+            .iter()
+            .filter(|meal| !meal.allergens().contains_any(excluded_allergens))
+            .cloned()
+            .collect()
     }
 }
 
 impl MockMealExtractor {
     pub fn verify_invoked_get_by_id(&self, id: &MealId) {
         assert_eq!(&self.id.unwrap(), id);
-        assert!(!&self.all);
+        assert!(&self.all.is_none());
         assert!(&self.name.is_none());
     }
 
     pub fn verify_invoked_get_by_name(&self, name: &MealName) {
         assert_eq!(&self.clone().name.unwrap(), name);
-        assert!(!&self.all);
+        assert!(&self.all.is_none());
         assert!(&self.id.is_none());
     }
 
-    pub fn verify_invoked_get_all(&self) {
-        assert!(&self.all);
+    pub fn verify_invoked_get_all(
+        &self,
+        start_id: &MealId,
+        limit: usize,
+        excluded_allergens: &Allergens,
+    ) {
+        assert_eq!(
+            self.all,
+            Some((*start_id, limit, excluded_allergens.clone()))
+        );
         assert!(&self.id.is_none());
         assert!(&self.name.is_none());
     }
 
     pub fn verify_empty(&self) {
         assert!(&self.name.is_none());
+        assert!(&self.all.is_none());
+    }
+}
+
+#[derive(new, Debug, Clone, Default)]
+pub struct MockMealSearcher {
+    pub meals: Vec<Meal>,
+    #[new(default)]
+    pub invoked_with: Option<(String, usize)>,
+}
+
+impl MealSearcher for MockMealSearcher {
+    fn search(&mut self, text: &str, limit: usize) -> Vec<Meal> {
+        self.invoked_with = Some((text.to_string(), limit));
+        self.meals.clone()
+    }
+}
+
+impl MockMealSearcher {
+    pub fn verify_invoked(&self, text: &str, limit: usize) {
+        assert_eq!(self.invoked_with, Some((text.to_string(), limit)));
+    }
+
+    pub fn verify_empty(&self) {
+        assert!(self.invoked_with.is_none());
     }
 }
 
@@ -306,7 +406,10 @@ pub struct MockShopOrderExtractor {
     pub order: Option<ShopOrder>,
     pub id: Option<ShopOrderId>,
     pub for_customer: Option<CustomerId>,
+    pub delivery_slot: Option<DeliverySlot>,
+    pub criteria: Option<ShopOrderCriteria>,
     pub all: bool,
+    pub all_active: bool,
 }
 
 impl ShopOrderExtractor for MockShopOrderExtractor {
@@ -328,13 +431,36 @@ impl ShopOrderExtractor for MockShopOrderExtractor {
         }
     }
 
-    fn get_all(&mut self, _start_id: &ShopOrderId, _limit: usize) -> Vec<ShopOrder> {
+    fn get_all(
+        &mut self,
+        criteria: &ShopOrderCriteria,
+        _start_id: &ShopOrderId,
+        _limit: usize,
+    ) -> Vec<ShopOrder> {
         self.all = true;
-        if self.order.is_some() {
-            vec![self.order.clone().unwrap()]
-        } else {
-            vec![]
-        }
+        self.criteria = Some(criteria.clone());
+        self.order
+            .iter()
+            .filter(|order| criteria.matches(order))
+            .cloned()
+            .collect()
+    }
+
+    fn get_all_active(&mut self) -> Vec<ShopOrder> {
+        self.all_active = true;
+        self.order
+            .iter()
+            .filter(|order| order.is_active())
+            .cloned()
+            .collect()
+    }
+
+    fn count_active_by_delivery_slot(&mut self, slot: &DeliverySlot) -> usize {
+        self.delivery_slot = Some(*slot);
+        self.order
+            .iter()
+            .filter(|order| order.is_active() && order.delivery_slot() == &Some(*slot))
+            .count()
     }
 }
 
@@ -351,8 +477,23 @@ impl MockShopOrderExtractor {
         assert!(self.id.is_none());
     }
 
-    pub fn verify_invoked_get_all(&self) {
+    pub fn verify_invoked_get_all(&self, criteria: &ShopOrderCriteria) {
         assert!(self.all);
+        assert_eq!(self.criteria.as_ref(), Some(criteria));
+        assert!(self.id.is_none());
+        assert!(self.for_customer.is_none());
+    }
+
+    pub fn verify_invoked_get_all_active(&self) {
+        assert!(self.all_active);
+        assert!(!self.all);
+        assert!(self.id.is_none());
+        assert!(self.for_customer.is_none());
+    }
+
+    pub fn verify_invoked_count_active_by_delivery_slot(&self, slot: &DeliverySlot) {
+        assert_eq!(self.delivery_slot, Some(*slot));
+        assert!(!self.all);
         assert!(self.id.is_none());
         assert!(self.for_customer.is_none());
     }
@@ -361,6 +502,7 @@ impl MockShopOrderExtractor {
         assert!(!self.all);
         assert!(self.id.is_none());
         assert!(self.for_customer.is_none());
+        assert!(self.delivery_slot.is_none());
     }
 }
 
@@ -384,7 +526,7 @@ impl MockShopOrderPersister {
     pub fn verify_invoked(
         &self,
         order_id: &ShopOrderId,
-        address: &Address,
+        fulfilment: &Fulfilment,
         customer_id: &CustomerId,
         meal_id: &MealId,
         count_items: &Count,
@@ -392,7 +534,7 @@ impl MockShopOrderPersister {
     ) {
         let order = self.order.clone().unwrap();
         assert_eq!(order.id(), order_id);
-        assert_eq!(order.address(), address);
+        assert_eq!(order.fulfilment(), fulfilment);
         assert_eq!(order.for_customer(), customer_id);
         assert_eq!(order.order_items().len(), 1);
 
@@ -426,6 +568,20 @@ impl MockShopOrderPersister {
         assert_eq!(first_event_struct.order_id, *id);
     }
 
+    pub fn verify_events_after_ready_for_pickup(&mut self, id: &ShopOrderId) {
+        let events = self.order.clone().unwrap().pop_events();
+        let first_event = events.first().unwrap().clone();
+        let etalon_event = ShopOrderReadyForPickupDomainEvent::new(*id);
+        assert_eq!(events.len(), 1);
+        assert_eq!(
+            discriminant(&Into::<ShopOrderEventEnum>::into(first_event.clone())),
+            discriminant(&Into::<ShopOrderEventEnum>::into(etalon_event))
+        );
+        let first_event_struct: ShopOrderReadyForPickupDomainEvent =
+            first_event.try_into().unwrap();
+        assert_eq!(first_event_struct.order_id, *id);
+    }
+
     pub fn verify_events_after_confirmation(&mut self, id: &ShopOrderId) {
         let events = self.order.clone().unwrap().pop_events();
         let first_event = events.first().unwrap().clone();
@@ -461,18 +617,28 @@ impl MockShopOrderPersister {
     }
 }
 
-#[derive(new, Clone, Eq, PartialEq, Debug, Default)]
+#[derive(new, Clone, Eq, PartialEq, Debug, SmartDefault)]
 pub struct MockOrderExporter {
     pub id: ShopOrderId,
     pub customer_id: CustomerId,
     pub total_price: Price,
+    #[new(value = "Ok(())")]
+    #[default(Ok(()))]
+    pub response: Result<(), OrderExportError>,
 }
 
+#[async_trait]
 impl OrderExporter for MockOrderExporter {
-    fn export_order(&mut self, id: ShopOrderId, customer_id: CustomerId, total_price: Price) {
+    async fn export_order(
+        &mut self,
+        id: ShopOrderId,
+        customer_id: CustomerId,
+        total_price: Price,
+    ) -> Result<(), OrderExportError> {
         self.id = id;
         self.customer_id = customer_id;
         self.total_price = total_price;
+        self.response.clone()
     }
 }
 
@@ -508,3 +674,414 @@ impl CustomerHasActiveOrder for MockCustomerHasActiveOrder {
         self.has_active
     }
 }
+
+#[derive(new, Debug, Default)]
+pub struct MockCheckDeliverySlot {
+    pub error: Option<DeliverySlotError>,
+    #[new(value = "Default::default()")]
+    pub slot: Option<DeliverySlot>,
+}
+
+impl MockCheckDeliverySlot {
+    pub fn verify_invoked(&self, slot: &DeliverySlot) {
+        assert_eq!(&self.slot.unwrap(), slot);
+    }
+
+    pub fn verify_empty(&self) {
+        assert!(&self.slot.is_none())
+    }
+}
+
+#[async_trait]
+impl CheckDeliverySlot for MockCheckDeliverySlot {
+    async fn invoke(&mut self, slot: &DeliverySlot) -> Result<(), DeliverySlotError> {
+        self.slot = Some(*slot);
+        match self.error {
+            Some(error) => Err(error),
+            None => Ok(()),
+        }
+    }
+}
+
+#[derive(new, Debug)]
+pub struct MockAddressInDeliveryZone {
+    pub inside: bool,
+    #[new(value = "Default::default()")]
+    pub address: Option<Address>,
+}
+
+impl MockAddressInDeliveryZone {
+    pub fn verify_invoked(&self, address: &Address) {
+        assert_eq!(self.address.as_ref().unwrap(), address);
+    }
+}
+
+#[async_trait]
+impl AddressInDeliveryZone for MockAddressInDeliveryZone {
+    async fn invoke(&mut self, address: &Address) -> bool {
+        self.address = Some(address.clone());
+        self.inside
+    }
+}
+
+#[derive(new, Debug, Clone, Default)]
+pub struct MockCustomerPersister {
+    #[new(default)]
+    pub customer: Option<Customer>,
+}
+
+impl MockCustomerPersister {
+    pub fn verify_empty(&self) {
+        assert!(self.customer.is_none())
+    }
+}
+
+#[async_trait]
+impl CustomerPersister for MockCustomerPersister {
+    async fn save(&mut self, customer: Customer) {
+        self.customer = Some(customer);
+    }
+}
+
+#[derive(new, Debug, Clone, Default)]
+pub struct MockCustomerExtractor {
+    pub customer: Option<Customer>,
+    #[new(default)]
+    pub id: Option<CustomerId>,
+    #[new(default)]
+    pub phone: Option<PhoneNumber>,
+}
+
+impl MockCustomerExtractor {
+    pub fn verify_invoked_get_by_id(&self, id: &CustomerId) {
+        assert_eq!(self.id.as_ref(), Some(id));
+        assert!(self.phone.is_none());
+    }
+
+    pub fn verify_invoked_get_by_phone(&self, phone: &PhoneNumber) {
+        assert_eq!(self.phone.as_ref(), Some(phone));
+        assert!(self.id.is_none());
+    }
+
+    pub fn verify_empty(&self) {
+        assert!(self.id.is_none());
+        assert!(self.phone.is_none());
+    }
+}
+
+impl CustomerExtractor for MockCustomerExtractor {
+    fn get_by_id(&mut self, id: &CustomerId) -> Option<Customer> {
+        self.id = Some(*id);
+        self.customer.clone().filter(|it| it.id() == id)
+    }
+
+    fn get_by_phone(&mut self, phone: &PhoneNumber) -> Option<Customer> {
+        self.phone = Some(phone.clone());
+        self.customer.clone().filter(|it| it.phone() == phone)
+    }
+}
+
+#[derive(new, Debug, Default)]
+pub struct MockReserveMealPortions {
+    pub unavailable: Option<MealId>,
+    #[new(default)]
+    pub meals: Option<HashMap<MealId, Count>>,
+}
+
+impl MockReserveMealPortions {
+    pub fn verify_invoked(&self, meals: &HashMap<MealId, Count>) {
+        assert_eq!(self.meals.as_ref(), Some(meals));
+    }
+
+    pub fn verify_empty(&self) {
+        assert!(self.meals.is_none());
+    }
+}
+
+#[async_trait]
+impl ReserveMealPortions for MockReserveMealPortions {
+    async fn invoke(&mut self, meals: &HashMap<MealId, Count>) -> Result<(), MealUnavailable> {
+        self.meals = Some(meals.clone());
+        match self.unavailable {
+            Some(meal_id) => Err(MealUnavailable(meal_id)),
+            None => Ok(()),
+        }
+    }
+}
+
+#[derive(new, Debug, Clone, Default)]
+pub struct MockMealAvailableAt {
+    pub unavailable: Option<MealId>,
+    #[new(default)]
+    pub at: Option<OffsetDateTime>,
+}
+
+#[async_trait]
+impl MealAvailableAt for MockMealAvailableAt {
+    async fn invoke(&mut self, meal_id: &MealId, at: &OffsetDateTime) -> bool {
+        self.at = Some(*at);
+        self.unavailable != Some(*meal_id)
+    }
+}
+
+#[derive(new, Debug, Clone, Default)]
+pub struct MockMealStockExtractor {
+    pub stocks: Vec<MealStock>,
+    #[new(default)]
+    pub meal_ids: Option<Vec<MealId>>,
+}
+
+impl MockMealStockExtractor {
+    pub fn verify_invoked(&self, meal_ids: &[MealId]) {
+        assert_eq!(self.meal_ids.as_deref(), Some(meal_ids));
+    }
+
+    pub fn verify_empty(&self) {
+        assert!(self.meal_ids.is_none());
+    }
+}
+
+impl MealStockExtractor for MockMealStockExtractor {
+    fn get_by_meal_id(&mut self, meal_id: &MealId) -> Option<MealStock> {
+        self.meal_ids = Some(vec![*meal_id]);
+        self.stocks
+            .iter()
+            .find(|it| it.meal_id() == meal_id)
+            .cloned()
+    }
+
+    fn get_by_meal_ids(&mut self, meal_ids: &[MealId]) -> Vec<MealStock> {
+        self.meal_ids = Some(meal_ids.to_vec());
+        self.stocks
+            .iter()
+            .filter(|it| meal_ids.contains(it.meal_id()))
+            .cloned()
+            .collect()
+    }
+}
+
+#[derive(new, Debug, Clone, Default)]
+pub struct MockMealStockPersister {
+    #[new(default)]
+    pub saved: Vec<MealStock>,
+}
+
+impl MockMealStockPersister {
+    pub fn verify_empty(&self) {
+        assert!(self.saved.is_empty());
+    }
+}
+
+#[async_trait]
+impl MealStockPersister for MockMealStockPersister {
+    async fn save(&mut self, stock: MealStock) {
+        self.saved.push(stock);
+    }
+}
+
+/// Smallest contents passing the signature check of the image formats
+pub const JPEG_CONTENT: &[u8] = &[0xFF, 0xD8, 0xFF, 0xE0, 0x00, 0x10];
+pub const PNG_CONTENT: &[u8] = b"\x89PNG\r\n\x1a\n\x00\x00\x00\x0dIHDR";
+
+#[derive(new, Debug, Clone, Default)]
+pub struct MockMediaStorage {
+    #[new(default)]
+    pub stored: Vec<(MealId, ImageFormat, Vec<u8>)>,
+    #[new(default)]
+    pub removed: Option<MealId>,
+    /// Returned by every operation instead of storing or removing
+    #[new(default)]
+    pub failure: Option<MediaStorageError>,
+}
+
+impl MockMediaStorage {
+    pub fn url(meal_id: &MealId, index: usize) -> Uri {
+        format!("http://media.shop/{meal_id}/{index}")
+            .parse()
+            .unwrap()
+    }
+
+    pub fn verify_stored(&self, meal_id: &MealId, format: ImageFormat, content: &[u8]) {
+        assert_eq!(self.stored, vec![(*meal_id, format, content.to_vec())]);
+    }
+
+    pub fn verify_removed(&self, meal_id: &MealId) {
+        assert_eq!(self.removed, Some(*meal_id));
+    }
+
+    pub fn verify_empty(&self) {
+        assert!(self.stored.is_empty());
+        assert!(self.removed.is_none());
+    }
+}
+
+#[async_trait]
+impl MediaStorage for MockMediaStorage {
+    async fn store(
+        &mut self,
+        meal_id: &MealId,
+        format: ImageFormat,
+        content: &[u8],
+    ) -> Result<Uri, MediaStorageError> {
+        if let Some(failure) = &self.failure {
+            return Err(failure.clone());
+        }
+        self.stored.push((*meal_id, format, content.to_vec()));
+        Ok(Self::url(meal_id, self.stored.len() - 1))
+    }
+
+    fn get_urls(&mut self, meal_id: &MealId) -> Vec<Uri> {
+        self.stored
+            .iter()
+            .enumerate()
+            .filter(|(_, (id, _, _))| id == meal_id)
+            .map(|(index, _)| Self::url(meal_id, index))
+            .collect()
+    }
+
+    async fn remove_all(&mut self, meal_id: &MealId) -> Result<(), MediaStorageError> {
+        if let Some(failure) = &self.failure {
+            return Err(failure.clone());
+        }
+        self.removed = Some(*meal_id);
+        self.stored.retain(|(id, _, _)| id != meal_id);
+        Ok(())
+    }
+}
+
+pub fn rnd_webhook_request() -> WebhookRequest {
+    WebhookRequest::new(
+        rnd_webhook_url(),
+        EventId::new(),
+        WebhookEventType::OrderPaid,
+        format!(r#"{{"order_id":{}}}"#, rnd_order_id().to_i64()),
+        "sha256=00".to_string(),
+    )
+}
+
+pub fn rnd_failed_webhook_delivery() -> FailedWebhookDelivery {
+    let request = rnd_webhook_request();
+    FailedWebhookDelivery::new(
+        rnd_webhook_subscription_id(),
+        request.event_id,
+        request.event_type,
+        request.url,
+        request.payload,
+        3,
+        "Unexpected response status 500".to_string(),
+        OffsetDateTime::now_utc(),
+    )
+}
+
+#[derive(new, Debug, Clone, Default)]
+pub struct MockWebhookSubscriptionExtractor {
+    pub subscriptions: Vec<WebhookSubscription>,
+    #[new(default)]
+    pub id: Option<WebhookSubscriptionId>,
+    #[new(default)]
+    pub all: bool,
+}
+
+impl MockWebhookSubscriptionExtractor {
+    pub fn verify_invoked_get_by_id(&self, id: &WebhookSubscriptionId) {
+        assert_eq!(self.id.as_ref(), Some(id));
+        assert!(!self.all);
+    }
+
+    pub fn verify_invoked_get_all(&self) {
+        assert!(self.all);
+        assert!(self.id.is_none());
+    }
+}
+
+impl WebhookSubscriptionExtractor for MockWebhookSubscriptionExtractor {
+    fn get_by_id(&mut self, id: &WebhookSubscriptionId) -> Option<WebhookSubscription> {
+        self.id = Some(*id);
+        self.subscriptions.iter().find(|it| it.id() == id).cloned()
+    }
+
+    fn get_all(&mut self) -> Vec<WebhookSubscription> {
+        self.all = true;
+        self.subscriptions.clone()
+    }
+}
+
+#[derive(new, Debug, Clone, Default)]
+pub struct MockWebhookSubscriptionPersister {
+    #[new(default)]
+    pub subscription: Option<WebhookSubscription>,
+}
+
+impl MockWebhookSubscriptionPersister {
+    pub fn verify_empty(&self) {
+        assert!(self.subscription.is_none())
+    }
+}
+
+#[async_trait]
+impl WebhookSubscriptionPersister for MockWebhookSubscriptionPersister {
+    async fn save(&mut self, subscription: WebhookSubscription) {
+        self.subscription = Some(subscription);
+    }
+}
+
+#[derive(new, Debug, Clone, Default)]
+pub struct MockFailedWebhookDeliveryExtractor {
+    pub deliveries: Vec<FailedWebhookDelivery>,
+    #[new(default)]
+    pub invoked: bool,
+}
+
+impl FailedWebhookDeliveryExtractor for MockFailedWebhookDeliveryExtractor {
+    fn get_all(&mut self) -> Vec<FailedWebhookDelivery> {
+        self.invoked = true;
+        self.deliveries.clone()
+    }
+}
+
+#[derive(new, Debug, Clone, Default)]
+pub struct MockFailedWebhookDeliveryPersister {
+    #[new(default)]
+    pub deliveries: Vec<FailedWebhookDelivery>,
+}
+
+impl MockFailedWebhookDeliveryPersister {
+    pub fn verify_empty(&self) {
+        assert!(self.deliveries.is_empty())
+    }
+}
+
+#[async_trait]
+impl FailedWebhookDeliveryPersister for MockFailedWebhookDeliveryPersister {
+    async fn save(&mut self, delivery: FailedWebhookDelivery) {
+        self.deliveries.push(delivery);
+    }
+}
+
+/// Answers with the given results in turn and accepts everything after them
+#[derive(Debug, Default)]
+pub struct MockWebhookSender {
+    responses: Mutex<VecDeque<Result<(), WebhookSendError>>>,
+    requests: Mutex<Vec<WebhookRequest>>,
+}
+
+impl MockWebhookSender {
+    pub fn new(responses: Vec<Result<(), WebhookSendError>>) -> Self {
+        Self {
+            responses: Mutex::new(responses.into()),
+            requests: Mutex::new(vec![]),
+        }
+    }
+
+    pub fn requests(&self) -> Vec<WebhookRequest> {
+        self.requests.lock().unwrap().clone()
+    }
+}
+
+#[async_trait]
+impl WebhookSender for MockWebhookSender {
+    async fn send(&self, request: &WebhookRequest) -> Result<(), WebhookSendError> {
+        self.requests.lock().unwrap().push(request.clone());
+        self.responses.lock().unwrap().pop_front().unwrap_or(Ok(()))
+    }
+}
@@ -0,0 +1,8 @@
+use std::fmt::Debug;
+
+use crate::webhook::dto::failed_webhook_delivery::FailedWebhookDelivery;
+
+pub trait FailedWebhookDeliveryExtractor: Debug + Send {
+    /// The most recent failures first
+    fn get_all(&mut self) -> Vec<FailedWebhookDelivery>;
+}
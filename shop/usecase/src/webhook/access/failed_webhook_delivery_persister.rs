@@ -0,0 +1,11 @@
+use std::fmt::Debug;
+
+use async_trait::async_trait;
+
+use crate::webhook::dto::failed_webhook_delivery::FailedWebhookDelivery;
+
+/// Dead-letter store of the webhooks which couldn't be delivered
+#[async_trait]
+pub trait FailedWebhookDeliveryPersister: Debug + Send {
+    async fn save(&mut self, delivery: FailedWebhookDelivery);
+}
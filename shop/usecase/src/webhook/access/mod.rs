@@ -0,0 +1,5 @@
+pub mod failed_webhook_delivery_extractor;
+pub mod failed_webhook_delivery_persister;
+pub mod webhook_sender;
+pub mod webhook_subscription_extractor;
+pub mod webhook_subscription_persister;
@@ -0,0 +1,21 @@
+use std::fmt::Debug;
+
+use async_trait::async_trait;
+use thiserror::Error;
+
+use crate::webhook::dto::webhook_request::WebhookRequest;
+
+/// Transport of the webhooks to the partners
+#[async_trait]
+pub trait WebhookSender: Debug + Send + Sync {
+    /// Posts the payload once, any response other than 2xx is an error
+    async fn send(&self, request: &WebhookRequest) -> Result<(), WebhookSendError>;
+}
+
+#[derive(Error, Debug, Clone, PartialEq)]
+pub enum WebhookSendError {
+    #[error("Request failed: {0}")]
+    RequestFailed(String),
+    #[error("Unexpected response status {0}")]
+    UnexpectedStatus(u16),
+}
@@ -0,0 +1,13 @@
+use std::fmt::Debug;
+
+use domain::webhook::{
+    value_objects::webhook_subscription_id::WebhookSubscriptionId,
+    webhook_subscription::WebhookSubscription,
+};
+
+pub trait WebhookSubscriptionExtractor: Debug + Send {
+    fn get_by_id(&mut self, id: &WebhookSubscriptionId) -> Option<WebhookSubscription>;
+
+    /// Subscriptions which are not removed
+    fn get_all(&mut self) -> Vec<WebhookSubscription>;
+}
@@ -0,0 +1,9 @@
+use std::fmt::Debug;
+
+use async_trait::async_trait;
+use domain::webhook::webhook_subscription::WebhookSubscription;
+
+#[async_trait]
+pub trait WebhookSubscriptionPersister: Debug + Send {
+    async fn save(&mut self, subscription: WebhookSubscription);
+}
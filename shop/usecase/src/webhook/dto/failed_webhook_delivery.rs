@@ -0,0 +1,21 @@
+use common::types::base::EventId;
+use derive_new::new;
+use domain::webhook::value_objects::{
+    webhook_event_type::WebhookEventType, webhook_subscription_id::WebhookSubscriptionId,
+    webhook_url::WebhookUrl,
+};
+use time::OffsetDateTime;
+
+/// Webhook which was given up on after all the attempts
+#[allow(clippy::too_many_arguments)]
+#[derive(new, Debug, Clone, PartialEq)]
+pub struct FailedWebhookDelivery {
+    pub subscription_id: WebhookSubscriptionId,
+    pub event_id: EventId,
+    pub event_type: WebhookEventType,
+    pub url: WebhookUrl,
+    pub payload: String,
+    pub attempts: u32,
+    pub last_error: String,
+    pub failed_at: OffsetDateTime,
+}
@@ -0,0 +1,4 @@
+pub mod failed_webhook_delivery;
+pub mod webhook_payload;
+pub mod webhook_request;
+pub mod webhook_subscription_info;
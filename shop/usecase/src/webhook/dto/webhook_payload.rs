@@ -0,0 +1,58 @@
+use common::types::base::EventId;
+use domain::{
+    order::customer_order_events::ShopOrderEventEnum,
+    webhook::value_objects::webhook_event_type::WebhookEventType,
+};
+use serde::Serialize;
+
+/// JSON body of the webhook
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct WebhookPayload {
+    /// Id of the order event, repeated deliveries of the same event share it
+    pub id: EventId,
+    #[serde(rename = "type")]
+    pub event_type: String,
+    pub order_id: i64,
+}
+
+impl From<&ShopOrderEventEnum> for WebhookPayload {
+    fn from(event: &ShopOrderEventEnum) -> Self {
+        Self {
+            id: event.event_id().clone(),
+            event_type: WebhookEventType::of(event).to_string(),
+            order_id: event.order_id().to_i64(),
+        }
+    }
+}
+
+impl WebhookPayload {
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use domain::{
+        order::customer_order_events::ShopOrderPaidDomainEvent, test_fixtures::rnd_order_id,
+    };
+
+    use super::*;
+
+    #[test]
+    fn payload_of_order_event() {
+        let order_id = rnd_order_id();
+        let event: ShopOrderEventEnum = ShopOrderPaidDomainEvent::new(order_id).into();
+
+        let payload = WebhookPayload::from(&event);
+
+        assert_eq!(
+            payload.to_json(),
+            format!(
+                r#"{{"id":"{}","type":"order.paid","order_id":{}}}"#,
+                event.event_id(),
+                order_id.to_i64()
+            )
+        );
+    }
+}
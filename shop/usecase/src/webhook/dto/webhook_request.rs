@@ -0,0 +1,16 @@
+use common::types::base::EventId;
+use derive_new::new;
+use domain::webhook::value_objects::{
+    webhook_event_type::WebhookEventType, webhook_url::WebhookUrl,
+};
+
+/// Signed webhook ready to be posted
+#[derive(new, Debug, Clone, PartialEq)]
+pub struct WebhookRequest {
+    pub url: WebhookUrl,
+    pub event_id: EventId,
+    pub event_type: WebhookEventType,
+    pub payload: String,
+    /// HMAC of the payload, see [crate::webhook::webhook_signature]
+    pub signature: String,
+}
@@ -0,0 +1,28 @@
+use common::types::base::Version;
+use domain::webhook::{
+    value_objects::{
+        webhook_event_type::WebhookEventType, webhook_subscription_id::WebhookSubscriptionId,
+        webhook_url::WebhookUrl,
+    },
+    webhook_subscription::WebhookSubscription,
+};
+
+/// Subscription without its secret
+#[derive(Debug, PartialEq, Clone)]
+pub struct WebhookSubscriptionInfo {
+    pub id: WebhookSubscriptionId,
+    pub url: WebhookUrl,
+    pub event_types: Vec<WebhookEventType>,
+    pub version: Version,
+}
+
+impl From<WebhookSubscription> for WebhookSubscriptionInfo {
+    fn from(value: WebhookSubscription) -> Self {
+        Self {
+            id: *value.id(),
+            url: value.url().to_owned(),
+            event_types: value.event_types().to_owned(),
+            version: *value.version(),
+        }
+    }
+}
@@ -0,0 +1,11 @@
+use std::fmt::Debug;
+
+use async_trait::async_trait;
+
+use crate::webhook::dto::failed_webhook_delivery::FailedWebhookDelivery;
+
+/// Dead letters of the webhooks, for the administrators to look into
+#[async_trait]
+pub trait GetFailedWebhookDeliveries: Debug + Send {
+    async fn execute(&mut self) -> Vec<FailedWebhookDelivery>;
+}
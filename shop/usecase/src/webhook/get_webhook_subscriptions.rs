@@ -0,0 +1,10 @@
+use std::fmt::Debug;
+
+use async_trait::async_trait;
+
+use crate::webhook::dto::webhook_subscription_info::WebhookSubscriptionInfo;
+
+#[async_trait]
+pub trait GetWebhookSubscriptions: Debug + Send {
+    async fn execute(&mut self) -> Vec<WebhookSubscriptionInfo>;
+}
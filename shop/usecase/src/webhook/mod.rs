@@ -0,0 +1,16 @@
+pub mod access;
+pub mod dto;
+pub mod rules;
+pub mod scenarios;
+pub mod webhook_delivery;
+pub mod webhook_signature;
+
+mod get_failed_webhook_deliveries;
+mod get_webhook_subscriptions;
+mod remove_webhook_subscription;
+mod subscribe_webhook;
+
+pub use get_failed_webhook_deliveries::*;
+pub use get_webhook_subscriptions::*;
+pub use remove_webhook_subscription::*;
+pub use subscribe_webhook::*;
@@ -0,0 +1,19 @@
+use std::fmt::Debug;
+
+use async_trait::async_trait;
+use domain::webhook::value_objects::webhook_subscription_id::WebhookSubscriptionId;
+use thiserror::Error;
+
+#[async_trait]
+pub trait RemoveWebhookSubscription: Debug + Send {
+    async fn execute(
+        &mut self,
+        id: &WebhookSubscriptionId,
+    ) -> Result<(), RemoveWebhookSubscriptionUseCaseError>;
+}
+
+#[derive(Error, Debug, Clone, Copy, PartialEq)]
+pub enum RemoveWebhookSubscriptionUseCaseError {
+    #[error("Webhook subscription not found")]
+    SubscriptionNotFound,
+}
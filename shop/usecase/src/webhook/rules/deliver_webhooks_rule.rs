@@ -0,0 +1,235 @@
+use std::{
+    mem::{Discriminant, discriminant},
+    sync::Arc,
+};
+
+use async_trait::async_trait;
+use common::{events::DomainEventListener, types::base::AM};
+use domain::{
+    order::customer_order_events::{
+        ShopOrderCancelledDomainEvent, ShopOrderCompletedDomainEvent,
+        ShopOrderConfirmedDomainEvent, ShopOrderCreatedDomainEvent, ShopOrderEventEnum,
+        ShopOrderPaidDomainEvent, ShopOrderReadyForPickupDomainEvent,
+    },
+    webhook::value_objects::webhook_event_type::WebhookEventType,
+};
+
+use crate::webhook::{
+    access::webhook_subscription_extractor::WebhookSubscriptionExtractor,
+    dto::{webhook_payload::WebhookPayload, webhook_request::WebhookRequest},
+    webhook_delivery::WebhookDelivery,
+    webhook_signature::sign,
+};
+
+/// Notifies the partners subscribed to one kind of order events, so it has to be registered for
+/// each event which can be subscribed to. Webhooks are delivered in the background, the order
+/// isn't held up by slow or unavailable partners
+#[derive(Debug)]
+pub struct DeliverWebhooksRule {
+    subscription_extractor: AM<dyn WebhookSubscriptionExtractor>,
+    delivery: Arc<WebhookDelivery>,
+    event_type: Discriminant<ShopOrderEventEnum>,
+}
+
+impl DeliverWebhooksRule {
+    fn for_event(
+        subscription_extractor: AM<dyn WebhookSubscriptionExtractor>,
+        delivery: Arc<WebhookDelivery>,
+        event: ShopOrderEventEnum,
+    ) -> Self {
+        Self {
+            subscription_extractor,
+            delivery,
+            event_type: discriminant(&event),
+        }
+    }
+
+    pub fn after_order_created(
+        subscription_extractor: AM<dyn WebhookSubscriptionExtractor>,
+        delivery: Arc<WebhookDelivery>,
+    ) -> Self {
+        Self::for_event(
+            subscription_extractor,
+            delivery,
+            ShopOrderCreatedDomainEvent::default().into(),
+        )
+    }
+
+    pub fn after_order_paid(
+        subscription_extractor: AM<dyn WebhookSubscriptionExtractor>,
+        delivery: Arc<WebhookDelivery>,
+    ) -> Self {
+        Self::for_event(
+            subscription_extractor,
+            delivery,
+            ShopOrderPaidDomainEvent::default().into(),
+        )
+    }
+
+    pub fn after_order_confirmed(
+        subscription_extractor: AM<dyn WebhookSubscriptionExtractor>,
+        delivery: Arc<WebhookDelivery>,
+    ) -> Self {
+        Self::for_event(
+            subscription_extractor,
+            delivery,
+            ShopOrderConfirmedDomainEvent::default().into(),
+        )
+    }
+
+    pub fn after_order_ready_for_pickup(
+        subscription_extractor: AM<dyn WebhookSubscriptionExtractor>,
+        delivery: Arc<WebhookDelivery>,
+    ) -> Self {
+        Self::for_event(
+            subscription_extractor,
+            delivery,
+            ShopOrderReadyForPickupDomainEvent::default().into(),
+        )
+    }
+
+    pub fn after_order_completed(
+        subscription_extractor: AM<dyn WebhookSubscriptionExtractor>,
+        delivery: Arc<WebhookDelivery>,
+    ) -> Self {
+        Self::for_event(
+            subscription_extractor,
+            delivery,
+            ShopOrderCompletedDomainEvent::default().into(),
+        )
+    }
+
+    pub fn after_order_cancelled(
+        subscription_extractor: AM<dyn WebhookSubscriptionExtractor>,
+        delivery: Arc<WebhookDelivery>,
+    ) -> Self {
+        Self::for_event(
+            subscription_extractor,
+            delivery,
+            ShopOrderCancelledDomainEvent::default().into(),
+        )
+    }
+
+    /// A rule for each event type a partner can subscribe to
+    pub fn for_all_events(
+        subscription_extractor: AM<dyn WebhookSubscriptionExtractor>,
+        delivery: Arc<WebhookDelivery>,
+    ) -> Vec<Self> {
+        vec![
+            Self::after_order_created(subscription_extractor.clone(), delivery.clone()),
+            Self::after_order_paid(subscription_extractor.clone(), delivery.clone()),
+            Self::after_order_confirmed(subscription_extractor.clone(), delivery.clone()),
+            Self::after_order_ready_for_pickup(subscription_extractor.clone(), delivery.clone()),
+            Self::after_order_completed(subscription_extractor.clone(), delivery.clone()),
+            Self::after_order_cancelled(subscription_extractor, delivery),
+        ]
+    }
+}
+
+#[async_trait]
+impl DomainEventListener<ShopOrderEventEnum> for DeliverWebhooksRule {
+    fn event_type(&self) -> Discriminant<ShopOrderEventEnum> {
+        self.event_type
+    }
+
+    async fn handle(&mut self, event: &ShopOrderEventEnum) {
+        let event_type = WebhookEventType::of(event);
+        let subscriptions = self.subscription_extractor.lock().await.get_all();
+        let payload = WebhookPayload::from(event).to_json();
+        for subscription in subscriptions
+            .into_iter()
+            .filter(|it| it.is_subscribed_to(&event_type))
+        {
+            let request = WebhookRequest::new(
+                subscription.url().clone(),
+                event.event_id().clone(),
+                event_type,
+                payload.clone(),
+                sign(subscription.secret(), &payload),
+            );
+            let delivery = self.delivery.clone();
+            let subscription_id = *subscription.id();
+            tokio::spawn(async move { delivery.deliver(subscription_id, request).await });
+        }
+    }
+
+    fn get_events(&self) -> &Vec<ShopOrderEventEnum> {
+        todo!()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::time::Duration;
+
+    use common::types::base::AMTrait;
+    use domain::test_fixtures::*;
+    use tokio::time::timeout;
+
+    use super::*;
+    use crate::{
+        test_fixtures::{
+            MockFailedWebhookDeliveryPersister, MockWebhookSender, MockWebhookSubscriptionExtractor,
+        },
+        webhook::webhook_delivery::WebhookRetryPolicy,
+    };
+
+    async fn wait_for_requests(sender: &MockWebhookSender, count: usize) {
+        timeout(Duration::from_secs(5), async {
+            while sender.requests().len() < count {
+                tokio::task::yield_now().await;
+            }
+        })
+        .await
+        .expect("Webhooks were not sent");
+    }
+
+    #[tokio::test]
+    async fn subscribed_partners_are_notified() {
+        let paid_subscription = rnd_webhook_subscription(vec![WebhookEventType::OrderPaid]);
+        let created_subscription = rnd_webhook_subscription(vec![WebhookEventType::OrderCreated]);
+        let extractor = AM::new_am(MockWebhookSubscriptionExtractor::new(vec![
+            paid_subscription.clone(),
+            created_subscription,
+        ]));
+        let sender = Arc::new(MockWebhookSender::new(vec![]));
+        let delivery = Arc::new(WebhookDelivery::new(
+            sender.clone(),
+            AM::new_am(MockFailedWebhookDeliveryPersister::new()),
+            WebhookRetryPolicy::default(),
+        ));
+        let mut rule = DeliverWebhooksRule::after_order_paid(extractor, delivery);
+        let event: ShopOrderEventEnum = ShopOrderPaidDomainEvent::new(rnd_order_id()).into();
+
+        rule.handle(&event).await;
+
+        wait_for_requests(&sender, 1).await;
+        let requests = sender.requests();
+        assert_eq!(requests.len(), 1);
+        let payload = WebhookPayload::from(&event).to_json();
+        assert_eq!(
+            requests[0],
+            WebhookRequest::new(
+                paid_subscription.url().clone(),
+                event.event_id().clone(),
+                WebhookEventType::OrderPaid,
+                payload.clone(),
+                sign(paid_subscription.secret(), &payload),
+            )
+        );
+    }
+
+    #[test]
+    fn rule_for_each_event_type() {
+        let rules = DeliverWebhooksRule::for_all_events(
+            AM::new_am(MockWebhookSubscriptionExtractor::new(vec![])),
+            Arc::new(WebhookDelivery::new(
+                Arc::new(MockWebhookSender::new(vec![])),
+                AM::new_am(MockFailedWebhookDeliveryPersister::new()),
+                WebhookRetryPolicy::default(),
+            )),
+        );
+
+        assert_eq!(rules.len(), WebhookEventType::ALL.len());
+    }
+}
@@ -0,0 +1 @@
+pub mod deliver_webhooks_rule;
@@ -0,0 +1,43 @@
+use async_trait::async_trait;
+use common::types::base::AM;
+use derive_new::new;
+
+use crate::webhook::{
+    access::failed_webhook_delivery_extractor::FailedWebhookDeliveryExtractor,
+    dto::failed_webhook_delivery::FailedWebhookDelivery,
+    get_failed_webhook_deliveries::GetFailedWebhookDeliveries,
+};
+
+#[derive(new, Debug)]
+pub struct GetFailedWebhookDeliveriesUseCase {
+    pub delivery_extractor: AM<dyn FailedWebhookDeliveryExtractor>,
+}
+
+#[async_trait]
+impl GetFailedWebhookDeliveries for GetFailedWebhookDeliveriesUseCase {
+    async fn execute(&mut self) -> Vec<FailedWebhookDelivery> {
+        self.delivery_extractor.lock().await.get_all()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::types::base::AMTrait;
+
+    use super::*;
+    use crate::test_fixtures::{MockFailedWebhookDeliveryExtractor, rnd_failed_webhook_delivery};
+
+    #[tokio::test]
+    async fn failed_deliveries_are_returned() {
+        let delivery = rnd_failed_webhook_delivery();
+        let extractor = AM::new_am(MockFailedWebhookDeliveryExtractor::new(vec![
+            delivery.clone(),
+        ]));
+
+        let mut use_case = GetFailedWebhookDeliveriesUseCase::new(extractor.clone());
+        let result = use_case.execute().await;
+
+        assert!(extractor.lock().await.invoked);
+        assert_eq!(result, vec![delivery]);
+    }
+}
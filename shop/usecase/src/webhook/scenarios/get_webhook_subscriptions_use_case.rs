@@ -0,0 +1,50 @@
+use async_trait::async_trait;
+use common::types::base::AM;
+use derive_new::new;
+
+use crate::webhook::{
+    access::webhook_subscription_extractor::WebhookSubscriptionExtractor,
+    dto::webhook_subscription_info::WebhookSubscriptionInfo,
+    get_webhook_subscriptions::GetWebhookSubscriptions,
+};
+
+#[derive(new, Debug)]
+pub struct GetWebhookSubscriptionsUseCase {
+    pub subscription_extractor: AM<dyn WebhookSubscriptionExtractor>,
+}
+
+#[async_trait]
+impl GetWebhookSubscriptions for GetWebhookSubscriptionsUseCase {
+    async fn execute(&mut self) -> Vec<WebhookSubscriptionInfo> {
+        self.subscription_extractor
+            .lock()
+            .await
+            .get_all()
+            .into_iter()
+            .map(WebhookSubscriptionInfo::from)
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::types::base::AMTrait;
+    use domain::{test_fixtures::*, webhook::value_objects::webhook_event_type::WebhookEventType};
+
+    use super::*;
+    use crate::test_fixtures::MockWebhookSubscriptionExtractor;
+
+    #[tokio::test]
+    async fn subscriptions_are_returned() {
+        let subscription = rnd_webhook_subscription(vec![WebhookEventType::OrderPaid]);
+        let extractor = AM::new_am(MockWebhookSubscriptionExtractor::new(vec![
+            subscription.clone(),
+        ]));
+
+        let mut use_case = GetWebhookSubscriptionsUseCase::new(extractor.clone());
+        let result = use_case.execute().await;
+
+        extractor.lock().await.verify_invoked_get_all();
+        assert_eq!(result, vec![WebhookSubscriptionInfo::from(subscription)]);
+    }
+}
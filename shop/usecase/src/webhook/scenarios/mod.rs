@@ -0,0 +1,9 @@
+mod get_failed_webhook_deliveries_use_case;
+mod get_webhook_subscriptions_use_case;
+mod remove_webhook_subscription_use_case;
+mod subscribe_webhook_use_case;
+
+pub use get_failed_webhook_deliveries_use_case::*;
+pub use get_webhook_subscriptions_use_case::*;
+pub use remove_webhook_subscription_use_case::*;
+pub use subscribe_webhook_use_case::*;
@@ -0,0 +1,92 @@
+use async_trait::async_trait;
+use common::types::base::AM;
+use derive_new::new;
+use domain::webhook::value_objects::webhook_subscription_id::WebhookSubscriptionId;
+
+use crate::webhook::{
+    access::{
+        webhook_subscription_extractor::WebhookSubscriptionExtractor,
+        webhook_subscription_persister::WebhookSubscriptionPersister,
+    },
+    remove_webhook_subscription::{
+        RemoveWebhookSubscription, RemoveWebhookSubscriptionUseCaseError,
+    },
+};
+
+#[derive(new, Debug)]
+pub struct RemoveWebhookSubscriptionUseCase {
+    pub subscription_extractor: AM<dyn WebhookSubscriptionExtractor>,
+    pub subscription_persister: AM<dyn WebhookSubscriptionPersister>,
+}
+
+#[async_trait]
+impl RemoveWebhookSubscription for RemoveWebhookSubscriptionUseCase {
+    async fn execute(
+        &mut self,
+        id: &WebhookSubscriptionId,
+    ) -> Result<(), RemoveWebhookSubscriptionUseCaseError> {
+        let mut subscription = self
+            .subscription_extractor
+            .lock()
+            .await
+            .get_by_id(id)
+            .filter(|it| it.visible())
+            .ok_or(RemoveWebhookSubscriptionUseCaseError::SubscriptionNotFound)?;
+        subscription.remove();
+        self.subscription_persister
+            .lock()
+            .await
+            .save(subscription)
+            .await;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::types::base::AMTrait;
+    use domain::{test_fixtures::*, webhook::value_objects::webhook_event_type::WebhookEventType};
+
+    use super::*;
+    use crate::test_fixtures::{
+        MockWebhookSubscriptionExtractor, MockWebhookSubscriptionPersister,
+    };
+
+    #[tokio::test]
+    async fn successfully_removed() {
+        let subscription = rnd_webhook_subscription(vec![WebhookEventType::OrderPaid]);
+        let extractor = AM::new_am(MockWebhookSubscriptionExtractor::new(vec![
+            subscription.clone(),
+        ]));
+        let persister = AM::new_am(MockWebhookSubscriptionPersister::new());
+
+        let mut use_case =
+            RemoveWebhookSubscriptionUseCase::new(extractor.clone(), persister.clone());
+        let result = use_case.execute(subscription.id()).await;
+
+        assert!(result.is_ok());
+        extractor
+            .lock()
+            .await
+            .verify_invoked_get_by_id(subscription.id());
+        let saved = persister.lock().await.subscription.clone().unwrap();
+        assert!(!saved.visible());
+    }
+
+    #[tokio::test]
+    async fn subscription_not_found() {
+        let persister = AM::new_am(MockWebhookSubscriptionPersister::new());
+
+        let mut use_case = RemoveWebhookSubscriptionUseCase::new(
+            AM::new_am(MockWebhookSubscriptionExtractor::new(vec![])),
+            persister.clone(),
+        );
+        let result = use_case.execute(&rnd_webhook_subscription_id()).await;
+
+        assert_eq!(
+            result,
+            Err(RemoveWebhookSubscriptionUseCaseError::SubscriptionNotFound)
+        );
+        persister.lock().await.verify_empty();
+    }
+}
@@ -0,0 +1,94 @@
+use async_trait::async_trait;
+use common::types::base::AM;
+use derive_new::new;
+use domain::webhook::{
+    value_objects::{
+        webhook_event_type::WebhookEventType,
+        webhook_secret::WebhookSecret,
+        webhook_subscription_id::{WebhookSubscriptionId, WebhookSubscriptionIdGenerator},
+        webhook_url::WebhookUrl,
+    },
+    webhook_subscription::WebhookSubscription,
+};
+
+use crate::webhook::{
+    access::webhook_subscription_persister::WebhookSubscriptionPersister,
+    subscribe_webhook::{SubscribeWebhook, SubscribeWebhookUseCaseError},
+};
+
+#[derive(new, Debug)]
+pub struct SubscribeWebhookUseCase {
+    pub subscription_persister: AM<dyn WebhookSubscriptionPersister>,
+    pub id_generator: AM<dyn WebhookSubscriptionIdGenerator>,
+}
+
+#[async_trait]
+impl SubscribeWebhook for SubscribeWebhookUseCase {
+    async fn execute(
+        &mut self,
+        url: &WebhookUrl,
+        secret: &WebhookSecret,
+        event_types: &[WebhookEventType],
+    ) -> Result<WebhookSubscriptionId, SubscribeWebhookUseCaseError> {
+        let subscription = WebhookSubscription::subscribe(
+            self.id_generator.clone(),
+            url.clone(),
+            secret.clone(),
+            event_types.to_vec(),
+        )
+        .await?;
+        let id = *subscription.id();
+        self.subscription_persister
+            .lock()
+            .await
+            .save(subscription)
+            .await;
+        Ok(id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::types::base::AMTrait;
+    use domain::test_fixtures::*;
+
+    use super::*;
+    use crate::test_fixtures::MockWebhookSubscriptionPersister;
+
+    #[tokio::test]
+    async fn successfully_subscribed() {
+        let persister = AM::new_am(MockWebhookSubscriptionPersister::new());
+        let id_generator = AM::new_am(TestWebhookSubscriptionIdGenerator::new());
+        let url = rnd_webhook_url();
+        let secret = rnd_webhook_secret();
+
+        let mut use_case = SubscribeWebhookUseCase::new(persister.clone(), id_generator.clone());
+        let result = use_case
+            .execute(&url, &secret, &[WebhookEventType::OrderCompleted])
+            .await;
+
+        let id = id_generator.lock().await.subscription_id;
+        assert_eq!(result, Ok(id));
+        let saved = persister.lock().await.subscription.clone().unwrap();
+        assert_eq!(saved.id(), &id);
+        assert_eq!(saved.url(), &url);
+        assert_eq!(saved.secret(), &secret);
+        assert_eq!(saved.event_types(), &vec![WebhookEventType::OrderCompleted]);
+    }
+
+    #[tokio::test]
+    async fn no_event_types() {
+        let persister = AM::new_am(MockWebhookSubscriptionPersister::new());
+
+        let mut use_case = SubscribeWebhookUseCase::new(
+            persister.clone(),
+            AM::new_am(TestWebhookSubscriptionIdGenerator::new()),
+        );
+        let result = use_case
+            .execute(&rnd_webhook_url(), &rnd_webhook_secret(), &[])
+            .await;
+
+        assert_eq!(result, Err(SubscribeWebhookUseCaseError::NoEventTypes));
+        persister.lock().await.verify_empty();
+    }
+}
@@ -0,0 +1,35 @@
+use std::fmt::Debug;
+
+use async_trait::async_trait;
+use domain::webhook::{
+    value_objects::{
+        webhook_event_type::WebhookEventType, webhook_secret::WebhookSecret,
+        webhook_subscription_id::WebhookSubscriptionId, webhook_url::WebhookUrl,
+    },
+    webhook_subscription::WebhookSubscriptionError,
+};
+use thiserror::Error;
+
+#[async_trait]
+pub trait SubscribeWebhook: Debug + Send {
+    async fn execute(
+        &mut self,
+        url: &WebhookUrl,
+        secret: &WebhookSecret,
+        event_types: &[WebhookEventType],
+    ) -> Result<WebhookSubscriptionId, SubscribeWebhookUseCaseError>;
+}
+
+#[derive(Error, Debug, Clone, Copy, PartialEq)]
+pub enum SubscribeWebhookUseCaseError {
+    #[error("At least one event type is required")]
+    NoEventTypes,
+}
+
+impl From<WebhookSubscriptionError> for SubscribeWebhookUseCaseError {
+    fn from(value: WebhookSubscriptionError) -> Self {
+        match value {
+            WebhookSubscriptionError::NoEventTypesError => Self::NoEventTypes,
+        }
+    }
+}
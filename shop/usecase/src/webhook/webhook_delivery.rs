@@ -0,0 +1,171 @@
+use std::{sync::Arc, time::Duration};
+
+use common::types::base::AM;
+use derive_new::new;
+use domain::webhook::value_objects::webhook_subscription_id::WebhookSubscriptionId;
+use time::OffsetDateTime;
+use tracing::warn;
+
+use crate::webhook::{
+    access::{
+        failed_webhook_delivery_persister::FailedWebhookDeliveryPersister,
+        webhook_sender::WebhookSender,
+    },
+    dto::{failed_webhook_delivery::FailedWebhookDelivery, webhook_request::WebhookRequest},
+};
+
+/// Attempts of a webhook, the pause before each retry grows exponentially
+#[derive(new, Debug, Clone, Copy, PartialEq)]
+pub struct WebhookRetryPolicy {
+    pub max_attempts: u32,
+    pub initial_backoff: Duration,
+    pub max_backoff: Duration,
+}
+
+impl Default for WebhookRetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 6,
+            initial_backoff: Duration::from_secs(1),
+            max_backoff: Duration::from_secs(60),
+        }
+    }
+}
+
+impl WebhookRetryPolicy {
+    /// Pause after the failed `attempt`, counting from 1
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let factor = 2_u32.saturating_pow(attempt.saturating_sub(1));
+        self.initial_backoff
+            .saturating_mul(factor)
+            .min(self.max_backoff)
+    }
+}
+
+/// Delivers webhooks with retries, the ones which failed every attempt go to the dead-letter store
+#[derive(new, Debug)]
+pub struct WebhookDelivery {
+    sender: Arc<dyn WebhookSender>,
+    failed_delivery_persister: AM<dyn FailedWebhookDeliveryPersister>,
+    retry_policy: WebhookRetryPolicy,
+}
+
+impl WebhookDelivery {
+    /// Returns whether the partner accepted the webhook
+    pub async fn deliver(
+        &self,
+        subscription_id: WebhookSubscriptionId,
+        request: WebhookRequest,
+    ) -> bool {
+        let mut attempt = 1;
+        loop {
+            let error = match self.sender.send(&request).await {
+                Ok(()) => return true,
+                Err(e) => e,
+            };
+            warn!(
+                "Webhook {} to {} failed on attempt {attempt}: {error}",
+                request.event_id, request.url
+            );
+            if attempt >= self.retry_policy.max_attempts {
+                let failed = FailedWebhookDelivery::new(
+                    subscription_id,
+                    request.event_id,
+                    request.event_type,
+                    request.url,
+                    request.payload,
+                    attempt,
+                    error.to_string(),
+                    OffsetDateTime::now_utc(),
+                );
+                self.failed_delivery_persister
+                    .lock()
+                    .await
+                    .save(failed)
+                    .await;
+                return false;
+            }
+            tokio::time::sleep(self.retry_policy.backoff(attempt)).await;
+            attempt += 1;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::types::base::AMTrait;
+    use domain::test_fixtures::rnd_webhook_subscription_id;
+
+    use super::*;
+    use crate::{
+        test_fixtures::{
+            MockFailedWebhookDeliveryPersister, MockWebhookSender, rnd_webhook_request,
+        },
+        webhook::access::webhook_sender::WebhookSendError,
+    };
+
+    fn retry_policy(max_attempts: u32) -> WebhookRetryPolicy {
+        WebhookRetryPolicy::new(
+            max_attempts,
+            Duration::from_millis(1),
+            Duration::from_millis(2),
+        )
+    }
+
+    #[test]
+    fn backoff_grows_exponentially_up_to_max() {
+        let policy = WebhookRetryPolicy::new(10, Duration::from_secs(1), Duration::from_secs(10));
+
+        assert_eq!(policy.backoff(1), Duration::from_secs(1));
+        assert_eq!(policy.backoff(2), Duration::from_secs(2));
+        assert_eq!(policy.backoff(4), Duration::from_secs(8));
+        assert_eq!(policy.backoff(5), Duration::from_secs(10));
+        assert_eq!(policy.backoff(40), Duration::from_secs(10));
+    }
+
+    #[tokio::test]
+    async fn delivered_after_retries() {
+        let sender = Arc::new(MockWebhookSender::new(vec![
+            Err(WebhookSendError::UnexpectedStatus(503)),
+            Err(WebhookSendError::RequestFailed(
+                "connection refused".to_string(),
+            )),
+        ]));
+        let persister = AM::new_am(MockFailedWebhookDeliveryPersister::new());
+        let request = rnd_webhook_request();
+
+        let delivery = WebhookDelivery::new(sender.clone(), persister.clone(), retry_policy(3));
+        let delivered = delivery
+            .deliver(rnd_webhook_subscription_id(), request.clone())
+            .await;
+
+        assert!(delivered);
+        assert_eq!(sender.requests(), vec![request; 3]);
+        persister.lock().await.verify_empty();
+    }
+
+    #[tokio::test]
+    async fn dead_lettered_after_last_attempt() {
+        let sender = Arc::new(MockWebhookSender::new(vec![
+            Err(WebhookSendError::UnexpectedStatus(500)),
+            Err(WebhookSendError::UnexpectedStatus(502)),
+        ]));
+        let persister = AM::new_am(MockFailedWebhookDeliveryPersister::new());
+        let subscription_id = rnd_webhook_subscription_id();
+        let request = rnd_webhook_request();
+
+        let delivery = WebhookDelivery::new(sender.clone(), persister.clone(), retry_policy(2));
+        let delivered = delivery.deliver(subscription_id, request.clone()).await;
+
+        assert!(!delivered);
+        assert_eq!(sender.requests().len(), 2);
+        let failed = persister.lock().await.deliveries.clone();
+        assert_eq!(failed.len(), 1);
+        assert_eq!(failed[0].subscription_id, subscription_id);
+        assert_eq!(failed[0].event_id, request.event_id);
+        assert_eq!(failed[0].url, request.url);
+        assert_eq!(failed[0].payload, request.payload);
+        assert_eq!(failed[0].attempts, 2);
+        assert_eq!(failed[0].last_error, "Unexpected response status 502");
+    }
+}
@@ -0,0 +1,33 @@
+use domain::webhook::value_objects::webhook_secret::WebhookSecret;
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+/// Header the signature is sent in
+pub const SIGNATURE_HEADER: &str = "X-Webhook-Signature";
+
+/// `sha256=` followed by the hex encoded HMAC-SHA256 of the payload keyed with the secret,
+/// partners compute it over the raw request body to check the webhook comes from the shop
+pub fn sign(secret: &WebhookSecret, payload: &str) -> String {
+    let mut mac =
+        Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any size");
+    mac.update(payload.as_bytes());
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn payload_is_signed_with_secret() {
+        let secret = WebhookSecret::try_from("0123456789abcdef").unwrap();
+
+        let signature = sign(&secret, r#"{"id":"1"}"#);
+
+        // openssl dgst -sha256 -hmac 0123456789abcdef
+        assert_eq!(
+            signature,
+            "sha256=4883735ecc1a7c87abe469367b9a53985bc6659fdf34419bf61569c2a176a20e"
+        );
+    }
+}